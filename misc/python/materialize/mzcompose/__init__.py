@@ -95,12 +95,18 @@ def get_minimal_system_parameters(
         "enable_logical_compaction_window": "true",
         "enable_multi_worker_storage_persist_sink": "true",
         "enable_multi_replica_sources": "true",
+        "enable_mysql_sink": "true",
+        "enable_postgres_sink": "true",
         "enable_rbac_checks": "true",
         "enable_reduce_mfp_fusion": "true",
         "enable_refresh_every_mvs": "true",
         "enable_replacement_materialized_views": "true",
         "enable_cluster_schedule_refresh": "true",
+        "enable_sql_server_sink": "true",
         "enable_sql_server_source": "true",
+        "enable_s3_sink": "true",
+        "enable_http_sink": "true",
+        "enable_sink_envelope_changelog": "true",
         "enable_s3_tables_region_check": "false",
         "enable_statement_lifecycle_logging": "true",
         "enable_compute_temporal_bucketing": "true",
@@ -479,6 +485,7 @@ UNINTERESTING_SYSTEM_PARAMETERS = [
     "compute_temporal_bucketing_summary",
     "consolidating_vec_growth_dampener",
     "copy_to_s3_parquet_row_group_file_ratio",
+    "copy_to_s3_orc_stripe_file_ratio",
     "copy_to_s3_arrow_builder_buffer_ratio",
     "copy_to_s3_multipart_part_size_bytes",
     "enable_replica_targeted_materialized_views",
@@ -535,6 +542,8 @@ UNINTERESTING_SYSTEM_PARAMETERS = [
     "persist_expression_cache_force_compaction_wait",
     "persist_blob_cache_mem_limit_bytes",
     "persist_blob_cache_scale_factor_bytes",
+    "persist_blob_cache_disk_enabled",
+    "persist_blob_cache_disk_limit_bytes",
     "persist_claim_unclaimed_compactions",
     "persist_claim_compaction_percent",
     "persist_claim_compaction_min_version",
@@ -590,6 +599,12 @@ UNINTERESTING_SYSTEM_PARAMETERS = [
     "storage_server_maintenance_interval",
     "storage_sink_progress_search",
     "storage_sink_ensure_topic_config",
+    "storage_s3_sink_parquet_row_group_file_ratio",
+    "storage_s3_sink_orc_stripe_file_ratio",
+    "storage_s3_sink_arrow_builder_buffer_ratio",
+    "storage_s3_sink_multipart_part_size_bytes",
+    "storage_http_sink_request_timeout",
+    "storage_http_sink_retry_max_duration",
     "sql_server_max_lsn_wait",
     "sql_server_snapshot_progress_report_interval",
     "sql_server_cdc_cleanup_change_table",
@@ -620,6 +635,15 @@ UNINTERESTING_SYSTEM_PARAMETERS = [
     "oidc_issuer",
     "oidc_audience",
     "oidc_authentication_claim",
+    "cert_authentication_rules",
+    "ldap_url",
+    "ldap_bind_dn_template",
+    "ldap_search_base_dn",
+    "ldap_search_filter",
+    "ldap_search_bind_dn",
+    "ldap_search_bind_password_file",
+    "ldap_group_attribute",
+    "ldap_group_role_mapping",
     "console_oidc_client_id",
     "console_oidc_scopes",
     "enable_mcp_agents",
@@ -1598,6 +1598,7 @@ class FlipFlagsAction(Action):
             "consolidating_vec_growth_dampener",
             "compute_hydration_concurrency",
             "copy_to_s3_parquet_row_group_file_ratio",
+            "copy_to_s3_orc_stripe_file_ratio",
             "copy_to_s3_arrow_builder_buffer_ratio",
             "copy_to_s3_multipart_part_size_bytes",
             "enable_compute_prometheus_metrics",
@@ -1774,6 +1775,15 @@ class FlipFlagsAction(Action):
             "oidc_issuer",
             "oidc_audience",
             "oidc_authentication_claim",
+            "cert_authentication_rules",
+            "ldap_url",
+            "ldap_bind_dn_template",
+            "ldap_search_base_dn",
+            "ldap_search_filter",
+            "ldap_search_bind_dn",
+            "ldap_search_bind_password_file",
+            "ldap_group_attribute",
+            "ldap_group_role_mapping",
             "console_oidc_client_id",
             "console_oidc_scopes",
         ]
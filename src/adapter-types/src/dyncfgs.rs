@@ -139,6 +139,75 @@ pub const OIDC_AUTHENTICATION_CLAIM: Config<&'static str> = Config::new(
     "OIDC authentication claim to use as username.",
 );
 
+/// Rules that map TLS client certificates to roles, for listeners that use
+/// certificate authentication. Each rule matches a regular expression against
+/// the common name (`cn`) or the DNS, email or URI subject alternative names
+/// (`dns_san`, `email_san`, `uri_san`) of a certificate.
+pub const CERT_AUTHENTICATION_RULES: Config<fn() -> serde_json::Value> = Config::new(
+    "cert_authentication_rules",
+    || serde_json::json!([{"field": "cn", "pattern": ".+", "role": "$0"}]),
+    "Rules that map TLS client certificates to roles. A JSON array of objects with a `field`, a `pattern` and a `role`.",
+);
+
+/// URL of the LDAP server, like `ldaps://ldap.example.com`.
+pub const LDAP_URL: Config<Option<&'static str>> =
+    Config::new("ldap_url", None, "LDAP server URL.");
+
+/// Template of the DN to bind as for simple bind LDAP authentication, in which
+/// `{username}` is replaced by the name of the user.
+pub const LDAP_BIND_DN_TEMPLATE: Config<Option<&'static str>> = Config::new(
+    "ldap_bind_dn_template",
+    None,
+    "LDAP DN to bind as, with `{username}` replaced by the user name. When unset, the DN is found by searching the directory.",
+);
+
+/// Base DN under which search+bind LDAP authentication searches for users.
+pub const LDAP_SEARCH_BASE_DN: Config<Option<&'static str>> = Config::new(
+    "ldap_search_base_dn",
+    None,
+    "LDAP base DN to search for users under.",
+);
+
+/// Filter with which search+bind LDAP authentication searches for users, in
+/// which `{username}` is replaced by the name of the user.
+pub const LDAP_SEARCH_FILTER: Config<&'static str> = Config::new(
+    "ldap_search_filter",
+    "(uid={username})",
+    "LDAP filter to search for users with, with `{username}` replaced by the user name.",
+);
+
+/// DN to bind as before searching for users. When unset, searches are
+/// anonymous.
+pub const LDAP_SEARCH_BIND_DN: Config<Option<&'static str>> = Config::new(
+    "ldap_search_bind_dn",
+    None,
+    "LDAP DN to bind as to search for users. When unset, searches are anonymous.",
+);
+
+/// Path to a file that contains the password of `ldap_search_bind_dn`. The
+/// password itself is not a system variable so that it is never exposed.
+pub const LDAP_SEARCH_BIND_PASSWORD_FILE: Config<Option<&'static str>> = Config::new(
+    "ldap_search_bind_password_file",
+    None,
+    "Path to a file that contains the password of the LDAP search bind DN.",
+);
+
+/// Attribute of user entries that lists the DNs of the groups of the user.
+pub const LDAP_GROUP_ATTRIBUTE: Config<&'static str> = Config::new(
+    "ldap_group_attribute",
+    "memberOf",
+    "LDAP attribute of users that lists their groups.",
+);
+
+/// Mapping from LDAP group DNs to role names. The memberships of users in the
+/// mapped roles are synchronized with their group memberships at each login.
+/// When empty, memberships are not synchronized.
+pub const LDAP_GROUP_ROLE_MAPPING: Config<fn() -> serde_json::Value> = Config::new(
+    "ldap_group_role_mapping",
+    || serde_json::json!({}),
+    "Mapping from LDAP group DNs to role names. A JSON object. When empty, role memberships are not synchronized with LDAP groups.",
+);
+
 pub const PERSIST_FAST_PATH_ORDER: Config<bool> = Config::new(
     "persist_fast_path_order",
     false,
@@ -219,6 +288,15 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&OIDC_ISSUER)
         .add(&OIDC_AUDIENCE)
         .add(&OIDC_AUTHENTICATION_CLAIM)
+        .add(&CERT_AUTHENTICATION_RULES)
+        .add(&LDAP_URL)
+        .add(&LDAP_BIND_DN_TEMPLATE)
+        .add(&LDAP_SEARCH_BASE_DN)
+        .add(&LDAP_SEARCH_FILTER)
+        .add(&LDAP_SEARCH_BIND_DN)
+        .add(&LDAP_SEARCH_BIND_PASSWORD_FILE)
+        .add(&LDAP_GROUP_ATTRIBUTE)
+        .add(&LDAP_GROUP_ROLE_MAPPING)
         .add(&PERSIST_FAST_PATH_ORDER)
         .add(&ENABLE_S3_TABLES_REGION_CHECK)
         .add(&ENABLE_MCP_AGENTS)
@@ -35,7 +35,8 @@ use mz_catalog::config::{BuiltinItemMigrationConfig, ClusterReplicaSizeMap, Conf
 #[cfg(test)]
 use mz_catalog::durable::CatalogError;
 use mz_catalog::durable::{
-    BootstrapArgs, DurableCatalogState, TestCatalogStateBuilder, test_bootstrap_args,
+    BootstrapArgs, DurableCatalogState, ReplicationSlot, TestCatalogStateBuilder,
+    test_bootstrap_args,
 };
 use mz_catalog::expr_cache::{ExpressionCacheHandle, GlobalExpressions, LocalExpressions};
 use mz_catalog::memory::error::{Error, ErrorKind};
@@ -72,7 +73,7 @@ use mz_sql::names::{
     PUBLIC_ROLE_NAME, PartialItemName, QualifiedItemName, QualifiedSchemaName,
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier, SystemObjectId,
 };
-use mz_sql::plan::{Plan, PlanNotice, StatementDesc};
+use mz_sql::plan::{Plan, PlanNotice, SequenceId, SequenceOptions, SequenceState, StatementDesc};
 use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
 use mz_sql::session::user::{MZ_SYSTEM_ROLE_ID, SUPPORT_USER, SYSTEM_USER};
@@ -699,6 +700,252 @@ impl Catalog {
             .err_into()
     }
 
+    /// Returns the name and options of the sequence identified by `id`.
+    ///
+    /// Identity columns are named after their table and column, e.g.
+    /// `materialize.public.t.id`.
+    fn sequence(&self, id: SequenceId) -> Result<(String, SequenceOptions), Error> {
+        let unknown = || Error::new(ErrorKind::Sql(SqlCatalogError::UnknownItem(id.to_string())));
+        let entry = self.try_get_entry(&id.item_id()).ok_or_else(unknown)?;
+        let name = self.resolve_full_name(entry.name(), entry.conn_id());
+        match id {
+            SequenceId::Sequence(_) => {
+                let options = entry.sequence_details().ok_or_else(unknown)?;
+                Ok((name.to_string(), options.clone()))
+            }
+            SequenceId::Identity { column, .. } => {
+                let identity = entry
+                    .identity_columns()
+                    .iter()
+                    .find(|identity| identity.column == column)
+                    .ok_or_else(unknown)?;
+                let desc = entry.relation_desc_latest().ok_or_else(unknown)?;
+                let column_name = desc.get_name(column);
+                Ok((format!("{name}.{column_name}"), identity.options.clone()))
+            }
+        }
+    }
+
+    /// Durably allocates the next `amount` values of the sequence identified
+    /// by `id`. See [`DurableCatalogState::allocate_sequence_values`].
+    pub async fn allocate_sequence_values(
+        &self,
+        id: SequenceId,
+        amount: u64,
+        commit_ts: mz_repr::Timestamp,
+    ) -> Result<Vec<i64>, Error> {
+        let (name, options) = self.sequence(id)?;
+        let values = self
+            .storage()
+            .await
+            .allocate_sequence_values(id, &options, amount, commit_ts)
+            .await
+            .maybe_terminate("allocating sequence values")?;
+        values.ok_or_else(|| {
+            let (bound, limit) = if options.increment > 0 {
+                ("maximum", options.max_value)
+            } else {
+                ("minimum", options.min_value)
+            };
+            Error::new(ErrorKind::SequenceExhausted { name, bound, limit })
+        })
+    }
+
+    /// Returns the value most recently produced by the sequence identified by
+    /// `id`, or set by `setval`.
+    pub async fn current_sequence_value(&self, id: SequenceId) -> Result<i64, Error> {
+        let (name, _options) = self.sequence(id)?;
+        let state = self
+            .storage()
+            .await
+            .get_sequence_state(id)
+            .await
+            .maybe_terminate("reading sequence state")?;
+        match state {
+            Some(SequenceState {
+                last_value,
+                is_called: true,
+            }) => Ok(last_value),
+            _ => Err(Error::new(ErrorKind::SequenceValueUndefined { name })),
+        }
+    }
+
+    /// Durably sets the state of the sequence identified by `id`, as `setval`
+    /// does.
+    pub async fn set_sequence_value(
+        &self,
+        id: SequenceId,
+        value: i64,
+        is_called: bool,
+        commit_ts: mz_repr::Timestamp,
+    ) -> Result<(), Error> {
+        let (name, options) = self.sequence(id)?;
+        if !options.contains(value) {
+            return Err(Error::new(ErrorKind::SequenceValueOutOfBounds {
+                name,
+                value,
+                min: options.min_value,
+                max: options.max_value,
+            }));
+        }
+        let state = SequenceState {
+            last_value: value,
+            is_called,
+        };
+        self.storage()
+            .await
+            .set_sequence_state(id, state, commit_ts)
+            .await
+            .maybe_terminate("setting sequence state")
+            .err_into()
+    }
+
+    /// Returns the relations of the publication named `name`.
+    pub async fn publication(&self, name: &str) -> Result<Vec<CatalogItemId>, Error> {
+        self.storage()
+            .await
+            .get_publication(name)
+            .await
+            .maybe_terminate("reading publication")?
+            .ok_or_else(|| Error::new(ErrorKind::UnknownPublication(name.into())))
+    }
+
+    /// Durably creates the publication named `name` over `relations`.
+    pub async fn create_publication(
+        &self,
+        name: &str,
+        relations: &[CatalogItemId],
+        commit_ts: mz_repr::Timestamp,
+    ) -> Result<(), Error> {
+        let created = self
+            .storage()
+            .await
+            .create_publication(name, relations, commit_ts)
+            .await
+            .maybe_terminate("creating publication")?;
+        if !created {
+            return Err(Error::new(ErrorKind::PublicationAlreadyExists(name.into())));
+        }
+        Ok(())
+    }
+
+    /// Durably drops the publication named `name`.
+    pub async fn drop_publication(
+        &self,
+        name: &str,
+        commit_ts: mz_repr::Timestamp,
+    ) -> Result<(), Error> {
+        let dropped = self
+            .storage()
+            .await
+            .drop_publication(name, commit_ts)
+            .await
+            .maybe_terminate("dropping publication")?;
+        if !dropped {
+            return Err(Error::new(ErrorKind::UnknownPublication(name.into())));
+        }
+        Ok(())
+    }
+
+    /// Returns the state of the replication slot named `name`.
+    pub async fn replication_slot(&self, name: &str) -> Result<ReplicationSlot, Error> {
+        self.storage()
+            .await
+            .get_replication_slot(name)
+            .await
+            .maybe_terminate("reading replication slot")?
+            .ok_or_else(|| Error::new(ErrorKind::UnknownReplicationSlot(name.into())))
+    }
+
+    /// Returns the state of every replication slot, by slot name.
+    pub async fn replication_slots(&self) -> Result<BTreeMap<String, ReplicationSlot>, Error> {
+        self.storage()
+            .await
+            .get_replication_slots()
+            .await
+            .maybe_terminate("reading replication slots")
+            .err_into()
+    }
+
+    /// Durably records `relations` as the relations streamed from the
+    /// replication slot named `name`.
+    pub async fn set_replication_slot_relations(
+        &self,
+        name: &str,
+        relations: &[CatalogItemId],
+        commit_ts: mz_repr::Timestamp,
+    ) -> Result<(), Error> {
+        let found = self
+            .storage()
+            .await
+            .set_replication_slot_relations(name, relations, commit_ts)
+            .await
+            .maybe_terminate("recording replication slot relations")?;
+        if !found {
+            return Err(Error::new(ErrorKind::UnknownReplicationSlot(name.into())));
+        }
+        Ok(())
+    }
+
+    /// Durably creates the replication slot named `name` with state `slot`.
+    pub async fn create_replication_slot(
+        &self,
+        name: &str,
+        slot: ReplicationSlot,
+        commit_ts: mz_repr::Timestamp,
+    ) -> Result<(), Error> {
+        let created = self
+            .storage()
+            .await
+            .create_replication_slot(name, slot, commit_ts)
+            .await
+            .maybe_terminate("creating replication slot")?;
+        if !created {
+            return Err(Error::new(ErrorKind::ReplicationSlotAlreadyExists(
+                name.into(),
+            )));
+        }
+        Ok(())
+    }
+
+    /// Durably advances the confirmed flush position of the replication slot
+    /// named `name`.
+    pub async fn advance_replication_slot(
+        &self,
+        name: &str,
+        confirmed_flush: mz_repr::Timestamp,
+        commit_ts: mz_repr::Timestamp,
+    ) -> Result<(), Error> {
+        let advanced = self
+            .storage()
+            .await
+            .advance_replication_slot(name, confirmed_flush, commit_ts)
+            .await
+            .maybe_terminate("advancing replication slot")?;
+        if !advanced {
+            return Err(Error::new(ErrorKind::UnknownReplicationSlot(name.into())));
+        }
+        Ok(())
+    }
+
+    /// Durably drops the replication slot named `name`.
+    pub async fn drop_replication_slot(
+        &self,
+        name: &str,
+        commit_ts: mz_repr::Timestamp,
+    ) -> Result<(), Error> {
+        let dropped = self
+            .storage()
+            .await
+            .drop_replication_slot(name, commit_ts)
+            .await
+            .maybe_terminate("dropping replication slot")?;
+        if !dropped {
+            return Err(Error::new(ErrorKind::UnknownReplicationSlot(name.into())));
+        }
+        Ok(())
+    }
+
     pub async fn allocate_user_id_for_test(&self) -> Result<(CatalogItemId, GlobalId), Error> {
         let commit_ts = self.storage().await.current_upper().await;
         self.allocate_user_id(commit_ts).await
@@ -1571,6 +1818,10 @@ pub(crate) fn system_object_type_to_audit_object_type(
             mz_sql::catalog::ObjectType::Cluster => ObjectType::Cluster,
             mz_sql::catalog::ObjectType::ClusterReplica => ObjectType::ClusterReplica,
             mz_sql::catalog::ObjectType::Secret => ObjectType::Secret,
+            // The audit log does not distinguish sequences and policies from tables.
+            mz_sql::catalog::ObjectType::Sequence | mz_sql::catalog::ObjectType::Policy => {
+                ObjectType::Table
+            }
             mz_sql::catalog::ObjectType::Connection => ObjectType::Connection,
             mz_sql::catalog::ObjectType::Database => ObjectType::Database,
             mz_sql::catalog::ObjectType::Schema => ObjectType::Schema,
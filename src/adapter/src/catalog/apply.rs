@@ -31,8 +31,8 @@ use mz_catalog::durable::objects::{
 use mz_catalog::durable::{CatalogError, SystemObjectMapping};
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
-    CatalogEntry, CatalogItem, Cluster, ClusterReplica, Database, Func, Index, Log, NetworkPolicy,
-    Role, RoleAuth, Schema, Source, StateDiff, StateUpdate, StateUpdateKind, Table,
+    CatalogEntry, CatalogItem, Cluster, ClusterReplica, Database, Func, FuncInner, Index, Log,
+    NetworkPolicy, Role, RoleAuth, Schema, Source, StateDiff, StateUpdate, StateUpdateKind, Table,
     TableDataSource, TemporaryItem, Type, UpdateFrom,
 };
 use mz_compute_types::config::ComputeReplicaConfig;
@@ -759,6 +759,7 @@ impl CatalogState {
                         is_retained_metrics_object: table.is_retained_metrics_object,
                         data_source: TableDataSource::TableWrites {
                             defaults: vec![Expr::null(); table.desc.arity()],
+                            identity_columns: Vec::new(),
                         },
                     }),
                     MZ_SYSTEM_ROLE_ID,
@@ -866,8 +867,10 @@ impl CatalogState {
                     oid,
                     name.clone(),
                     CatalogItem::Func(Func {
-                        inner: func.inner,
+                        create_sql: None,
+                        inner: FuncInner::Builtin(func.inner),
                         global_id,
+                        resolved_ids: ResolvedIds::empty(),
                     }),
                     MZ_SYSTEM_ROLE_ID,
                     PrivilegeMap::default(),
@@ -1861,21 +1864,26 @@ impl CatalogState {
             &metadata.name().qualifiers.schema_spec,
             conn_id,
         );
-        if metadata.item_type() == CatalogItemType::Type {
-            schema
-                .types
-                .remove(&metadata.name().item)
-                .expect("catalog out of sync");
-        } else {
-            // Functions would need special handling, but we don't yet support
-            // dropping functions.
-            assert_ne!(metadata.item_type(), CatalogItemType::Func);
-
-            schema
-                .items
-                .remove(&metadata.name().item)
-                .expect("catalog out of sync");
-        };
+        match metadata.item_type() {
+            CatalogItemType::Type => {
+                schema
+                    .types
+                    .remove(&metadata.name().item)
+                    .expect("catalog out of sync");
+            }
+            CatalogItemType::Func => {
+                schema
+                    .functions
+                    .remove(&metadata.name().item)
+                    .expect("catalog out of sync");
+            }
+            _ => {
+                schema
+                    .items
+                    .remove(&metadata.name().item)
+                    .expect("catalog out of sync");
+            }
+        }
 
         if !id.is_system() {
             if let Some(cluster_id) = metadata.item().cluster_id() {
@@ -2158,9 +2166,6 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         // Partition items into groups s.t. each item in one group has a predefined order with all
         // items in other groups. For example, all sinks are ordered greater than all tables.
         let mut types = Vec::new();
-        // N.B. Functions can depend on system tables, but not user tables.
-        // TODO(udf): This will change when UDFs are supported.
-        let mut funcs = Vec::new();
         let mut secrets = Vec::new();
         let mut connections = Vec::new();
         let mut sources = Vec::new();
@@ -2172,14 +2177,18 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         for update in item_updates {
             match update.0.item_type() {
                 CatalogItemType::Type => types.push(update),
-                CatalogItemType::Func => funcs.push(update),
-                CatalogItemType::Secret => secrets.push(update),
+                // Sequences, like secrets, never depend on other items.
+                CatalogItemType::Secret | CatalogItemType::Sequence => secrets.push(update),
                 CatalogItemType::Connection => connections.push(update),
                 CatalogItemType::Source => sources.push(update),
                 CatalogItemType::Table => tables.push(update),
+                // User-defined functions can depend on views and vice versa. Policies depend
+                // on the tables, views and materialized views they protect.
                 CatalogItemType::View
                 | CatalogItemType::MaterializedView
-                | CatalogItemType::Index => derived_items.push(update),
+                | CatalogItemType::Index
+                | CatalogItemType::Func
+                | CatalogItemType::Policy => derived_items.push(update),
                 CatalogItemType::Sink => sinks.push(update),
                 CatalogItemType::ContinualTask => continual_tasks.push(update),
             }
@@ -2194,7 +2203,6 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         // Other groups we can simply sort by ID.
         for group in [
             &mut types,
-            &mut funcs,
             &mut secrets,
             &mut sources,
             &mut tables,
@@ -2206,7 +2214,6 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
 
         iter::empty()
             .chain(types)
-            .chain(funcs)
             .chain(secrets)
             .chain(connections)
             .chain(sources)
@@ -2229,8 +2236,6 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         // Partition items into groups s.t. each item in one group has a predefined order with all
         // items in other groups. For example, all sinks are ordered greater than all tables.
         let mut types = Vec::new();
-        // N.B. Functions can depend on system tables, but not user tables.
-        let mut funcs = Vec::new();
         let mut secrets = Vec::new();
         let mut connections = Vec::new();
         let mut sources = Vec::new();
@@ -2242,14 +2247,18 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         for update in temp_item_updates {
             match update.0.item_type() {
                 CatalogItemType::Type => types.push(update),
-                CatalogItemType::Func => funcs.push(update),
-                CatalogItemType::Secret => secrets.push(update),
+                // Sequences, like secrets, never depend on other items.
+                CatalogItemType::Secret | CatalogItemType::Sequence => secrets.push(update),
                 CatalogItemType::Connection => connections.push(update),
                 CatalogItemType::Source => sources.push(update),
                 CatalogItemType::Table => tables.push(update),
+                // User-defined functions can depend on views and vice versa. Policies depend
+                // on the tables, views and materialized views they protect.
                 CatalogItemType::View
                 | CatalogItemType::MaterializedView
-                | CatalogItemType::Index => derived_items.push(update),
+                | CatalogItemType::Index
+                | CatalogItemType::Func
+                | CatalogItemType::Policy => derived_items.push(update),
                 CatalogItemType::Sink => sinks.push(update),
                 CatalogItemType::ContinualTask => continual_tasks.push(update),
             }
@@ -2258,7 +2267,6 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
         // Within each group, sort by ID.
         for group in [
             &mut types,
-            &mut funcs,
             &mut secrets,
             &mut connections,
             &mut sources,
@@ -2272,7 +2280,6 @@ fn sort_updates(updates: Vec<StateUpdate>) -> Vec<StateUpdate> {
 
         iter::empty()
             .chain(types)
-            .chain(funcs)
             .chain(secrets)
             .chain(connections)
             .chain(sources)
@@ -19,12 +19,13 @@ use mz_catalog::builtin::{
     MZ_AWS_PRIVATELINK_CONNECTIONS, MZ_BASE_TYPES, MZ_CLUSTER_REPLICA_SIZES, MZ_CLUSTER_REPLICAS,
     MZ_CLUSTER_SCHEDULES, MZ_CLUSTERS, MZ_COLUMNS, MZ_COMMENTS, MZ_CONNECTIONS, MZ_CONTINUAL_TASKS,
     MZ_DEFAULT_PRIVILEGES, MZ_EGRESS_IPS, MZ_FUNCTIONS, MZ_HISTORY_RETENTION_STRATEGIES,
-    MZ_ICEBERG_SINKS, MZ_INDEX_COLUMNS, MZ_INDEXES, MZ_KAFKA_CONNECTIONS, MZ_KAFKA_SINKS,
-    MZ_KAFKA_SOURCE_TABLES, MZ_KAFKA_SOURCES, MZ_LICENSE_KEYS, MZ_LIST_TYPES, MZ_MAP_TYPES,
-    MZ_MATERIALIZED_VIEW_REFRESH_STRATEGIES, MZ_MATERIALIZED_VIEWS, MZ_MYSQL_SOURCE_TABLES,
-    MZ_OBJECT_DEPENDENCIES, MZ_OBJECT_GLOBAL_IDS, MZ_OPERATORS, MZ_POSTGRES_SOURCE_TABLES,
-    MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES, MZ_REPLACEMENTS, MZ_ROLE_AUTH, MZ_ROLE_PARAMETERS,
-    MZ_ROLES, MZ_SECRETS, MZ_SESSIONS, MZ_SINKS, MZ_SOURCE_REFERENCES, MZ_SOURCES,
+    MZ_HTTP_SINKS, MZ_ICEBERG_SINKS, MZ_INDEX_COLUMNS, MZ_INDEXES, MZ_KAFKA_CONNECTIONS,
+    MZ_KAFKA_SINKS, MZ_KAFKA_SOURCE_TABLES, MZ_KAFKA_SOURCES, MZ_LICENSE_KEYS, MZ_LIST_TYPES,
+    MZ_MAP_TYPES, MZ_MATERIALIZED_VIEW_REFRESH_STRATEGIES, MZ_MATERIALIZED_VIEWS, MZ_MYSQL_SINKS,
+    MZ_MYSQL_SOURCE_TABLES, MZ_OBJECT_DEPENDENCIES, MZ_OBJECT_GLOBAL_IDS, MZ_OPERATORS,
+    MZ_POSTGRES_SINKS, MZ_POSTGRES_SOURCE_TABLES, MZ_POSTGRES_SOURCES, MZ_PSEUDO_TYPES,
+    MZ_REPLACEMENTS, MZ_ROLE_AUTH, MZ_ROLE_PARAMETERS, MZ_ROLES, MZ_S3_SINKS, MZ_SECRETS,
+    MZ_SESSIONS, MZ_SINKS, MZ_SOURCE_REFERENCES, MZ_SOURCES, MZ_SQL_SERVER_SINKS,
     MZ_SQL_SERVER_SOURCE_TABLES, MZ_SSH_TUNNEL_CONNECTIONS, MZ_STORAGE_USAGE_BY_SHARD,
     MZ_SUBSCRIPTIONS, MZ_SYSTEM_PRIVILEGES, MZ_TABLES, MZ_TYPE_PG_METADATA, MZ_TYPES, MZ_VIEWS,
     MZ_WEBHOOKS_SOURCES,
@@ -34,7 +35,7 @@ use mz_catalog::durable::SourceReferences;
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogEntry, CatalogItem, ClusterVariant, Connection, ContinualTask, DataSourceDesc, Func,
-    Index, MaterializedView, Sink, Table, TableDataSource, Type, View,
+    FuncInner, Index, MaterializedView, Sink, Table, TableDataSource, Type, View,
 };
 use mz_controller::clusters::{
     ManagedReplicaAvailabilityZones, ManagedReplicaLocation, ReplicaLocation,
@@ -56,7 +57,9 @@ use mz_repr::{
     CatalogItemId, Datum, Diff, GlobalId, ReprColumnType, Row, RowPacker, SqlScalarType, Timestamp,
 };
 use mz_sql::ast::{ContinualTaskStmt, CreateIndexStatement, Statement, UnresolvedItemName};
-use mz_sql::catalog::{CatalogCluster, CatalogType, DefaultPrivilegeObject, TypeCategory};
+use mz_sql::catalog::{
+    CatalogCluster, CatalogFuncDetails, CatalogType, DefaultPrivilegeObject, TypeCategory,
+};
 use mz_sql::func::FuncImplCatalogDetails;
 use mz_sql::names::{CommentObjectId, SchemaSpecifier};
 use mz_sql::plan::{ClusterSchedule, ConnectionDetails, SshKey};
@@ -68,7 +71,10 @@ use mz_storage_types::connections::KafkaConnection;
 use mz_storage_types::connections::aws::{AwsAuth, AwsConnection};
 use mz_storage_types::connections::inline::ReferencedConnection;
 use mz_storage_types::connections::string_or_secret::StringOrSecret;
-use mz_storage_types::sinks::{IcebergSinkConnection, KafkaSinkConnection, StorageSinkConnection};
+use mz_storage_types::sinks::{
+    HttpSinkConnection, IcebergSinkConnection, KafkaSinkConnection, MySqlSinkConnection,
+    PostgresSinkConnection, S3SinkConnection, SqlServerSinkConnection, StorageSinkConnection,
+};
 use mz_storage_types::sources::{
     GenericSourceConnection, KafkaSourceConnection, PostgresSourceConnection, SourceConnection,
 };
@@ -646,11 +652,14 @@ impl CatalogState {
                 self.pack_type_update(id, oid, schema_id, name, owner_id, privileges, ty, diff)
             }
             CatalogItem::Func(func) => {
-                self.pack_func_update(id, schema_id, name, owner_id, func, diff)
+                self.pack_func_update(id, oid, schema_id, name, owner_id, func, diff)
             }
             CatalogItem::Secret(_) => {
                 self.pack_secret_update(id, oid, schema_id, name, owner_id, privileges, diff)
             }
+            // Sequences do not (yet) have a dedicated introspection relation.
+            CatalogItem::Sequence(_) => Vec::new(),
+            CatalogItem::Policy(_) => Vec::new(),
             CatalogItem::Connection(connection) => self.pack_connection_update(
                 id, oid, schema_id, name, owner_id, privileges, connection, diff,
             ),
@@ -671,7 +680,7 @@ impl CatalogState {
         if let Some(desc) = entry.relation_desc_latest() {
             let defaults = match entry.item() {
                 CatalogItem::Table(Table {
-                    data_source: TableDataSource::TableWrites { defaults },
+                    data_source: TableDataSource::TableWrites { defaults, .. },
                     ..
                 }) => Some(defaults),
                 _ => None,
@@ -1485,6 +1494,81 @@ impl CatalogState {
                     diff,
                 ));
             }
+            StorageSinkConnection::Postgres(PostgresSinkConnection {
+                schema,
+                table,
+                progress_table,
+                ..
+            }) => {
+                updates.push(BuiltinTableUpdate::row(
+                    &*MZ_POSTGRES_SINKS,
+                    Row::pack_slice(&[
+                        Datum::String(&id.to_string()),
+                        Datum::String(schema.as_str()),
+                        Datum::String(table.as_str()),
+                        Datum::String(progress_table.as_str()),
+                    ]),
+                    diff,
+                ));
+            }
+            StorageSinkConnection::MySql(MySqlSinkConnection {
+                database,
+                table,
+                progress_table,
+                ..
+            }) => {
+                updates.push(BuiltinTableUpdate::row(
+                    &*MZ_MYSQL_SINKS,
+                    Row::pack_slice(&[
+                        Datum::String(&id.to_string()),
+                        Datum::String(database.as_str()),
+                        Datum::String(table.as_str()),
+                        Datum::String(progress_table.as_str()),
+                    ]),
+                    diff,
+                ));
+            }
+            StorageSinkConnection::SqlServer(SqlServerSinkConnection {
+                schema,
+                table,
+                progress_table,
+                ..
+            }) => {
+                updates.push(BuiltinTableUpdate::row(
+                    &*MZ_SQL_SERVER_SINKS,
+                    Row::pack_slice(&[
+                        Datum::String(&id.to_string()),
+                        Datum::String(schema.as_str()),
+                        Datum::String(table.as_str()),
+                        Datum::String(progress_table.as_str()),
+                    ]),
+                    diff,
+                ));
+            }
+            StorageSinkConnection::S3(S3SinkConnection {
+                uri,
+                format,
+                max_file_size,
+                ..
+            }) => {
+                updates.push(BuiltinTableUpdate::row(
+                    &*MZ_S3_SINKS,
+                    Row::pack_slice(&[
+                        Datum::String(&id.to_string()),
+                        Datum::String(uri.as_str()),
+                        Datum::String(format.name()),
+                        Datum::UInt64(*max_file_size),
+                    ]),
+                    diff,
+                ));
+            }
+            StorageSinkConnection::Http(HttpSinkConnection { url, .. }) => {
+                updates.push(BuiltinTableUpdate::row(
+                    &*MZ_HTTP_SINKS,
+                    Row::pack_slice(&[Datum::String(&id.to_string()), Datum::String(url.as_str())]),
+                    diff,
+                ));
+            }
         };
 
         let create_stmt = mz_sql::parse::parse(&sink.create_sql)
@@ -1727,14 +1811,24 @@ impl CatalogState {
     fn pack_func_update(
         &self,
         id: CatalogItemId,
+        oid: u32,
         schema_id: &SchemaSpecifier,
         name: &str,
         owner_id: &RoleId,
         func: &Func,
         diff: Diff,
     ) -> Vec<BuiltinTableUpdate<&'static BuiltinTable>> {
+        let inner = match &func.inner {
+            FuncInner::Builtin(inner) => inner,
+            FuncInner::Sql(details) => {
+                return vec![
+                    self.pack_sql_func_update(id, oid, schema_id, name, owner_id, details, diff),
+                ];
+            }
+        };
+
         let mut updates = vec![];
-        for func_impl_details in func.inner.func_impls() {
+        for func_impl_details in inner.func_impls() {
             let arg_type_ids = func_impl_details
                 .arg_typs
                 .iter()
@@ -1781,7 +1875,7 @@ impl CatalogState {
                 diff,
             ));
 
-            if let mz_sql::func::Func::Aggregate(_) = func.inner {
+            if let mz_sql::func::Func::Aggregate(_) = inner {
                 updates.push(BuiltinTableUpdate::row(
                     &*MZ_AGGREGATES,
                     Row::pack_slice(&[
@@ -1797,6 +1891,76 @@ impl CatalogState {
         updates
     }
 
+    /// Packs the `mz_functions` row for a user-defined SQL function, which,
+    /// unlike built-in functions, has exactly one implementation that shares
+    /// the function's OID.
+    fn pack_sql_func_update(
+        &self,
+        id: CatalogItemId,
+        oid: u32,
+        schema_id: &SchemaSpecifier,
+        name: &str,
+        owner_id: &RoleId,
+        details: &CatalogFuncDetails,
+        diff: Diff,
+    ) -> BuiltinTableUpdate<&'static BuiltinTable> {
+        let arg_type_ids = details
+            .params
+            .iter()
+            .map(|(_, typ)| self.scalar_type_id(typ))
+            .collect::<Vec<_>>();
+
+        let mut row = Row::default();
+        row.packer()
+            .try_push_array(
+                &[ArrayDimension {
+                    lower_bound: 1,
+                    length: arg_type_ids.len(),
+                }],
+                arg_type_ids.iter().map(|id| Datum::String(id)),
+            )
+            .expect("arg_type_ids is 1 dimensional, and its length is used for the array length");
+        let arg_type_ids = row.unpack_first();
+
+        let return_type_id = match details.returns.as_slice() {
+            [(_, typ)] => self.scalar_type_id(typ),
+            _ => self.get_system_type("record").id().to_string(),
+        };
+
+        BuiltinTableUpdate::row(
+            &*MZ_FUNCTIONS,
+            Row::pack_slice(&[
+                Datum::String(&id.to_string()),
+                Datum::UInt32(oid),
+                Datum::String(&schema_id.to_string()),
+                Datum::String(name),
+                arg_type_ids,
+                Datum::Null,
+                Datum::String(&return_type_id),
+                details.returns_set.into(),
+                Datum::String(&owner_id.to_string()),
+            ]),
+            diff,
+        )
+    }
+
+    /// Returns the ID of the catalog type that represents `typ`.
+    fn scalar_type_id(&self, typ: &SqlScalarType) -> String {
+        let custom_id = match typ {
+            SqlScalarType::List { custom_id, .. }
+            | SqlScalarType::Map { custom_id, .. }
+            | SqlScalarType::Record { custom_id, .. } => *custom_id,
+            _ => None,
+        };
+        match custom_id {
+            Some(id) => id.to_string(),
+            None => {
+                let name = mz_pgrepr::Type::from(typ).catalog_name();
+                self.get_system_type(name).id().to_string()
+            }
+        }
+    }
+
     pub fn pack_op_update(
         &self,
         operator: &str,
@@ -936,6 +936,8 @@ fn add_new_remove_old_builtin_items_migration(
             | CatalogItemType::Type
             | CatalogItemType::Func
             | CatalogItemType::Secret
+            | CatalogItemType::Sequence
+            | CatalogItemType::Policy
             | CatalogItemType::Connection
             | CatalogItemType::ContinualTask => continue,
         };
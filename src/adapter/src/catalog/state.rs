@@ -31,9 +31,9 @@ use mz_catalog::expr_cache::LocalExpressions;
 use mz_catalog::memory::error::{Error, ErrorKind};
 use mz_catalog::memory::objects::{
     CatalogCollectionEntry, CatalogEntry, CatalogItem, Cluster, ClusterReplica, CommentsMap,
-    Connection, DataSourceDesc, Database, DefaultPrivileges, Index, MaterializedView,
-    NetworkPolicy, Role, RoleAuth, Schema, Secret, Sink, Source, SourceReferences, Table,
-    TableDataSource, Type, View,
+    Connection, DataSourceDesc, Database, DefaultPrivileges, Func, FuncInner, Index,
+    MaterializedView, NetworkPolicy, Policy, Role, RoleAuth, Schema, Secret, Sequence, Sink,
+    Source, SourceReferences, Table, TableDataSource, Type, View,
 };
 use mz_controller::clusters::{
     ManagedReplicaAvailabilityZones, ManagedReplicaLocation, ReplicaAllocation, ReplicaLocation,
@@ -76,9 +76,9 @@ use mz_sql::names::{
     ResolvedDatabaseSpecifier, ResolvedIds, SchemaId, SchemaSpecifier, SystemObjectId,
 };
 use mz_sql::plan::{
-    CreateConnectionPlan, CreateIndexPlan, CreateMaterializedViewPlan, CreateSecretPlan,
-    CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan, CreateViewPlan, Params,
-    Plan, PlanContext,
+    CreateConnectionPlan, CreateFunctionPlan, CreateIndexPlan, CreateMaterializedViewPlan,
+    CreatePolicyPlan, CreateSecretPlan, CreateSequencePlan, CreateSinkPlan, CreateSourcePlan,
+    CreateTablePlan, CreateTypePlan, CreateViewPlan, Params, Plan, PlanContext,
 };
 use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
@@ -443,7 +443,9 @@ impl CatalogState {
             | CatalogItem::Source(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
-            | CatalogItem::Secret(_) => (),
+            | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_) => (),
         }
     }
 
@@ -1128,9 +1130,13 @@ impl CatalogState {
                         .or(table.compaction_window),
                     is_retained_metrics_object,
                     data_source: match table.data_source {
-                        mz_sql::plan::TableDataSource::TableWrites { defaults } => {
-                            TableDataSource::TableWrites { defaults }
-                        }
+                        mz_sql::plan::TableDataSource::TableWrites {
+                            defaults,
+                            identity_columns,
+                        } => TableDataSource::TableWrites {
+                            defaults,
+                            identity_columns,
+                        },
                         mz_sql::plan::TableDataSource::DataSource {
                             desc: data_source_desc,
                             timeline,
@@ -1174,6 +1180,7 @@ impl CatalogState {
                             }
                         },
                     },
+                    row_level_security: table.row_level_security,
                 })
             }
             Plan::CreateSource(CreateSourcePlan {
@@ -1315,6 +1322,7 @@ impl CatalogState {
                     conn_id: None,
                     resolved_ids,
                     dependencies: DependencyIds(dependencies),
+                    row_level_security: view.row_level_security,
                 })
             }
             Plan::CreateMaterializedView(CreateMaterializedViewPlan {
@@ -1405,6 +1413,8 @@ impl CatalogState {
                     custom_logical_compaction_window: materialized_view.compaction_window,
                     refresh_schedule: materialized_view.refresh_schedule,
                     initial_as_of,
+                    notify_channel: materialized_view.notify_channel,
+                    row_level_security: materialized_view.row_level_security,
                 })
             }
             Plan::CreateContinualTask(plan) => {
@@ -1462,10 +1472,29 @@ impl CatalogState {
                     resolved_ids,
                 })
             }
+            Plan::CreateFunction(CreateFunctionPlan { function, .. }) => CatalogItem::Func(Func {
+                create_sql: Some(function.create_sql),
+                inner: FuncInner::Sql(function.details),
+                global_id,
+                resolved_ids,
+            }),
             Plan::CreateSecret(CreateSecretPlan { secret, .. }) => CatalogItem::Secret(Secret {
                 create_sql: secret.create_sql,
                 global_id,
             }),
+            Plan::CreateSequence(CreateSequencePlan { sequence, .. }) => {
+                CatalogItem::Sequence(Sequence {
+                    create_sql: sequence.create_sql,
+                    global_id,
+                    options: sequence.options,
+                })
+            }
+            Plan::CreatePolicy(CreatePolicyPlan { policy, .. }) => CatalogItem::Policy(Policy {
+                create_sql: policy.create_sql,
+                global_id,
+                details: policy.details,
+                resolved_ids,
+            }),
             Plan::CreateConnection(CreateConnectionPlan {
                 connection:
                     mz_sql::plan::Connection {
@@ -1834,6 +1863,8 @@ impl CatalogState {
             | CatalogItemType::MaterializedView
             | CatalogItemType::Index
             | CatalogItemType::Secret
+            | CatalogItemType::Sequence
+            | CatalogItemType::Policy
             | CatalogItemType::Connection
             | CatalogItemType::ContinualTask => schema.items[builtin.name()],
         }
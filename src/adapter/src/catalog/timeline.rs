@@ -99,6 +99,8 @@ impl Catalog {
                         | CatalogItem::Type(_)
                         | CatalogItem::Func(_)
                         | CatalogItem::Secret(_)
+                        | CatalogItem::Sequence(_)
+                        | CatalogItem::Policy(_)
                         | CatalogItem::Connection(_)
                         | CatalogItem::Log(_) => {}
                     }
@@ -239,6 +241,8 @@ impl Catalog {
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Sequence(_)
+                    | CatalogItem::Policy(_)
                     | CatalogItem::Connection(_) => {}
                 }
             }
@@ -103,6 +103,10 @@ pub enum Op {
         value: Option<Value>,
         interval: Duration,
     },
+    AlterRowLevelSecurity {
+        id: CatalogItemId,
+        enabled: bool,
+    },
     AlterRole {
         id: RoleId,
         name: String,
@@ -123,6 +127,17 @@ pub enum Op {
         typ: SqlColumnType,
         sql: RawDataType,
     },
+    AlterDropColumn {
+        id: CatalogItemId,
+        new_global_id: GlobalId,
+        name: ColumnName,
+    },
+    AlterRenameColumn {
+        id: CatalogItemId,
+        new_global_id: GlobalId,
+        name: ColumnName,
+        new_name: ColumnName,
+    },
     AlterMaterializedViewApplyReplacement {
         id: CatalogItemId,
         replacement_id: CatalogItemId,
@@ -635,6 +650,8 @@ impl Catalog {
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Sequence(_)
+                    | CatalogItem::Policy(_)
                     | CatalogItem::Connection(_)
                     | CatalogItem::ContinualTask(_) => {}
                 }
@@ -921,6 +938,32 @@ impl Catalog {
 
                 Self::log_update(state, &id);
             }
+            Op::AlterRowLevelSecurity { id, enabled } => {
+                let entry = state.get_entry(&id);
+                if id.is_system() {
+                    let name = entry.name();
+                    let full_name =
+                        state.resolve_full_name(name, session.map(|session| session.conn_id()));
+                    return Err(AdapterError::Catalog(Error::new(ErrorKind::ReadOnlyItem(
+                        full_name.to_string(),
+                    ))));
+                }
+
+                let mut new_entry = entry.clone();
+                new_entry
+                    .item
+                    .update_row_level_security(enabled)
+                    .map_err(|_| {
+                        AdapterError::Catalog(Error::new(ErrorKind::Internal(
+                            "planner should have rejected invalid alter row level security item type"
+                                .to_string(),
+                        )))
+                    })?;
+
+                tx.update_item(id, new_entry.into())?;
+
+                Self::log_update(state, &id);
+            }
             Op::AlterRole {
                 id,
                 name,
@@ -1021,6 +1064,73 @@ impl Catalog {
                 tx.update_item(id, new_entry.into())?;
                 storage_collections_to_register.insert(new_global_id, shard_id);
             }
+            Op::AlterDropColumn {
+                id,
+                new_global_id,
+                name,
+            } => {
+                let mut new_entry = state.get_entry(&id).clone();
+                // Column comments are keyed by the 1-based position of the column in
+                // the latest version of the table, so they must shift to account for
+                // the dropped column.
+                let position = new_entry
+                    .relation_desc_latest()
+                    .and_then(|desc| desc.get_by_name(&name).map(|(idx, _)| idx + 1));
+                let version = new_entry.item.drop_column(name)?;
+                let shard_id = state
+                    .storage_metadata()
+                    .get_collection_shard(new_entry.latest_global_id())?;
+
+                let CatalogItem::Table(table) = &mut new_entry.item else {
+                    return Err(AdapterError::Unsupported("dropping columns from non-Table"));
+                };
+                table.collections.insert(version, new_global_id);
+
+                let comment_id = new_entry.comment_object_id();
+                if let (Some(position), Some(comments)) =
+                    (position, state.comments.get_object_comments(comment_id))
+                {
+                    for (sub, comment) in comments {
+                        match sub {
+                            Some(sub) if *sub == position => {
+                                tx.update_comment(comment_id, Some(*sub), None)?;
+                            }
+                            Some(sub) if *sub > position => {
+                                tx.update_comment(comment_id, Some(*sub), None)?;
+                                tx.update_comment(
+                                    comment_id,
+                                    Some(sub - 1),
+                                    Some(comment.clone()),
+                                )?;
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+
+                tx.update_item(id, new_entry.into())?;
+                storage_collections_to_register.insert(new_global_id, shard_id);
+            }
+            Op::AlterRenameColumn {
+                id,
+                new_global_id,
+                name,
+                new_name,
+            } => {
+                let mut new_entry = state.get_entry(&id).clone();
+                let version = new_entry.item.rename_column(name, new_name)?;
+                let shard_id = state
+                    .storage_metadata()
+                    .get_collection_shard(new_entry.latest_global_id())?;
+
+                let CatalogItem::Table(table) = &mut new_entry.item else {
+                    return Err(AdapterError::Unsupported("renaming columns of non-Table"));
+                };
+                table.collections.insert(version, new_global_id);
+
+                tx.update_item(id, new_entry.into())?;
+                storage_collections_to_register.insert(new_global_id, shard_id);
+            }
             Op::AlterMaterializedViewApplyReplacement { id, replacement_id } => {
                 let mut new_entry = state.get_entry(&id).clone();
                 let replacement = state.get_entry(&replacement_id);
@@ -1402,6 +1512,8 @@ impl Catalog {
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Sequence(_)
+                    | CatalogItem::Policy(_)
                     | CatalogItem::Connection(_) => (),
                 }
 
@@ -1586,6 +1698,8 @@ impl Catalog {
                         | CatalogItem::Type(_)
                         | CatalogItem::Func(_)
                         | CatalogItem::Secret(_)
+                        | CatalogItem::Sequence(_)
+                        | CatalogItem::Policy(_)
                         | CatalogItem::Connection(_)
                         | CatalogItem::ContinualTask(_) => {
                             EventDetails::IdFullNameV1(IdFullNameV1 {
@@ -1725,6 +1839,18 @@ impl Catalog {
                         .map(|id| id)
                         .partition(|id| !state.get_entry(*id).item().is_temporary());
                 tx.remove_items(&durable_items_to_drop)?;
+                // Drop the state of any sequences and identity columns.
+                for id in &delta.items {
+                    let entry = state.get_entry(id);
+                    if entry.sequence_details().is_some() || !entry.identity_columns().is_empty() {
+                        tx.remove_sequence_states(*id);
+                    }
+                }
+                // Remove dropped tables and materialized views from any
+                // publications that include them.
+                for id in &durable_items_to_drop {
+                    tx.remove_from_publications(*id)?;
+                }
                 temporary_item_updates.extend(temporary_items_to_drop.into_iter().map(|id| {
                     let entry = state.get_entry(&id);
                     (entry.clone().into(), StateDiff::Retraction)
@@ -3227,7 +3353,10 @@ mod tests {
                     resolved_ids: ResolvedIds::empty(),
                     custom_logical_compaction_window: None,
                     is_retained_metrics_object: false,
-                    data_source: TableDataSource::TableWrites { defaults: vec![] },
+                    data_source: TableDataSource::TableWrites {
+                        defaults: vec![],
+                        identity_columns: vec![],
+                    },
                 }),
                 owner_id: MZ_SYSTEM_ROLE_ID,
             };
@@ -8,7 +8,7 @@
 // by the Apache License, Version 2.0.
 
 use std::borrow::Cow;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::{Debug, Display, Formatter};
 use std::future::Future;
 use std::pin::{self, Pin};
@@ -222,6 +222,29 @@ impl Client {
         rx.await.expect("sender dropped")
     }
 
+    /// Synchronizes the memberships of a role with an external source of
+    /// truth, like the groups of a directory.
+    ///
+    /// The role is granted each of `granted_roles` and revoked from each of
+    /// the other `managed_roles`. Memberships of roles that are not in
+    /// `managed_roles` are left untouched, and roles that do not exist are
+    /// ignored.
+    pub async fn sync_role_memberships(
+        &self,
+        role_name: &str,
+        granted_roles: BTreeSet<String>,
+        managed_roles: BTreeSet<String>,
+    ) -> Result<(), AdapterError> {
+        let (tx, rx) = oneshot::channel();
+        self.send(Command::SyncRoleMemberships {
+            role_name: role_name.to_string(),
+            granted_roles,
+            managed_roles,
+            tx,
+        });
+        rx.await.expect("sender dropped")
+    }
+
     /// Upgrades this client to a session client.
     ///
     /// A session is a connection that has successfully negotiated parameters,
@@ -966,6 +989,22 @@ impl SessionClient {
         .await
     }
 
+    /// Durably records that the client has flushed all replicated changes
+    /// through `confirmed_flush`, so that the replication slot it is streaming
+    /// from resumes after that point.
+    pub async fn advance_replication_slot(
+        &mut self,
+        confirmed_flush: mz_repr::Timestamp,
+    ) -> Result<(), AdapterError> {
+        let conn_id = self.session().conn_id().clone();
+        self.send_without_session(|tx| Command::AdvanceReplicationSlot {
+            conn_id,
+            confirmed_flush,
+            tx,
+        })
+        .await
+    }
+
     /// Terminates the client session.
     pub async fn terminate(&mut self) {
         let conn_id = self.session().conn_id().clone();
@@ -1064,12 +1103,14 @@ impl SessionClient {
                 | Command::AuthenticateGetSASLChallenge { .. }
                 | Command::AuthenticateVerifySASLProof { .. }
                 | Command::CheckRoleCanLogin { .. }
+                | Command::SyncRoleMemberships { .. }
                 | Command::CatalogSnapshot { .. }
                 | Command::Commit { .. }
                 | Command::CancelRequest { .. }
                 | Command::PrivilegedCancelRequest { .. }
                 | Command::GetSystemVars { .. }
                 | Command::SetSystemVars { .. }
+                | Command::AdvanceReplicationSlot { .. }
                 | Command::Terminate { .. }
                 | Command::RetireExecute { .. }
                 | Command::CheckConsistency { .. }
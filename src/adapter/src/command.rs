@@ -51,7 +51,9 @@ use crate::coord::peek::{PeekDataflowPlan, PeekResponseUnary};
 use crate::coord::timestamp_selection::TimestampDetermination;
 use crate::coord::{ExecuteContextExtra, ExecuteContextGuard};
 use crate::error::AdapterError;
-use crate::session::{EndTransactionAction, RowBatchStream, Session};
+use crate::session::{
+    EndTransactionAction, PublishedRelation, ReplicationEvent, RowBatchStream, Session,
+};
 use crate::statement_logging::WatchSetCreation;
 use crate::statement_logging::{
     FrontendStatementLoggingEvent, StatementEndedExecutionReason, StatementExecutionStrategy,
@@ -124,6 +126,15 @@ pub enum Command {
         role_name: String,
     },
 
+    /// Makes the role a member of exactly those of `managed_roles` that are in
+    /// `granted_roles`.
+    SyncRoleMemberships {
+        tx: oneshot::Sender<Result<(), AdapterError>>,
+        role_name: String,
+        granted_roles: BTreeSet<String>,
+        managed_roles: BTreeSet<String>,
+    },
+
     Execute {
         portal_name: String,
         session: Session,
@@ -173,6 +184,14 @@ pub enum Command {
         tx: oneshot::Sender<Result<(), AdapterError>>,
     },
 
+    /// Durably advances the confirmed flush position of the replication slot
+    /// that the connection is streaming from.
+    AdvanceReplicationSlot {
+        conn_id: ConnectionId,
+        confirmed_flush: mz_repr::Timestamp,
+        tx: oneshot::Sender<Result<(), AdapterError>>,
+    },
+
     Terminate {
         conn_id: ConnectionId,
         tx: Option<oneshot::Sender<Result<(), AdapterError>>>,
@@ -349,12 +368,14 @@ impl Command {
             | Command::AuthenticateGetSASLChallenge { .. }
             | Command::AuthenticateVerifySASLProof { .. }
             | Command::CheckRoleCanLogin { .. }
+            | Command::SyncRoleMemberships { .. }
             | Command::CatalogSnapshot { .. }
             | Command::PrivilegedCancelRequest { .. }
             | Command::GetWebhook { .. }
             | Command::Terminate { .. }
             | Command::GetSystemVars { .. }
             | Command::SetSystemVars { .. }
+            | Command::AdvanceReplicationSlot { .. }
             | Command::RetireExecute { .. }
             | Command::CheckConsistency { .. }
             | Command::Dump { .. }
@@ -386,12 +407,14 @@ impl Command {
             | Command::AuthenticateGetSASLChallenge { .. }
             | Command::AuthenticateVerifySASLProof { .. }
             | Command::CheckRoleCanLogin { .. }
+            | Command::SyncRoleMemberships { .. }
             | Command::CatalogSnapshot { .. }
             | Command::PrivilegedCancelRequest { .. }
             | Command::GetWebhook { .. }
             | Command::Terminate { .. }
             | Command::GetSystemVars { .. }
             | Command::SetSystemVars { .. }
+            | Command::AdvanceReplicationSlot { .. }
             | Command::RetireExecute { .. }
             | Command::CheckConsistency { .. }
             | Command::Dump { .. }
@@ -571,8 +594,16 @@ pub enum ExecuteResponse {
     CreatedContinualTask,
     /// The requested type was created.
     CreatedType,
+    /// The requested function was created.
+    CreatedFunction,
+    /// The requested sequence was created.
+    CreatedSequence,
+    /// The requested policy was created.
+    CreatedPolicy,
     /// The requested network policy was created.
     CreatedNetworkPolicy,
+    /// The requested publication was created.
+    CreatedPublication,
     /// The requested prepared statement was removed.
     Deallocate { all: bool },
     /// The requested cursor was declared.
@@ -587,6 +618,10 @@ pub enum ExecuteResponse {
     DroppedObject(ObjectType),
     /// The requested objects were dropped.
     DroppedOwned,
+    /// The requested publication was dropped.
+    DroppedPublication,
+    /// The requested replication slot was dropped.
+    DroppedReplicationSlot,
     /// The provided query was empty.
     EmptyQuery,
     /// Fetch results from a cursor.
@@ -605,6 +640,8 @@ pub enum ExecuteResponse {
     GrantedRole,
     /// The specified number of rows were inserted into the requested table.
     Inserted(usize),
+    /// The session started listening on the requested channel.
+    Listened,
     /// The specified prepared statement was created.
     Prepare,
     /// A user-requested warning was raised.
@@ -634,6 +671,13 @@ pub enum ExecuteResponse {
         /// Whether the operation was a `RESET` rather than a set.
         reset: bool,
     },
+    /// Changes to the published relations will be streamed to the contained
+    /// receiver, starting after `start`.
+    StartedReplication {
+        relations: Vec<PublishedRelation>,
+        start: mz_repr::Timestamp,
+        rx: mpsc::UnboundedReceiver<ReplicationEvent>,
+    },
     /// A new transaction was started.
     StartedTransaction,
     /// Updates to the requested source or view will be streamed to the
@@ -653,6 +697,10 @@ pub enum ExecuteResponse {
         /// Session parameters that changed because the transaction ended.
         params: BTreeMap<&'static str, String>,
     },
+    /// The requested tables were truncated.
+    Truncated,
+    /// The session stopped listening on the requested channels.
+    Unlistened,
     /// The specified number of rows were updated in the requested table.
     Updated(usize),
     /// A connection was validated.
@@ -741,6 +789,10 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::CreatedNetworkPolicy => Ok(ExecuteResponse::CreatedNetworkPolicy),
             ExecuteResponseKind::CreatedContinualTask => Ok(ExecuteResponse::CreatedContinualTask),
             ExecuteResponseKind::CreatedType => Ok(ExecuteResponse::CreatedType),
+            ExecuteResponseKind::CreatedFunction => Ok(ExecuteResponse::CreatedFunction),
+            ExecuteResponseKind::CreatedSequence => Ok(ExecuteResponse::CreatedSequence),
+            ExecuteResponseKind::CreatedPolicy => Ok(ExecuteResponse::CreatedPolicy),
+            ExecuteResponseKind::CreatedPublication => Ok(ExecuteResponse::CreatedPublication),
             ExecuteResponseKind::Deallocate => Err(()),
             ExecuteResponseKind::DeclaredCursor => Ok(ExecuteResponse::DeclaredCursor),
             ExecuteResponseKind::Deleted => Err(()),
@@ -748,21 +800,29 @@ impl TryInto<ExecuteResponse> for ExecuteResponseKind {
             ExecuteResponseKind::DiscardedAll => Ok(ExecuteResponse::DiscardedAll),
             ExecuteResponseKind::DroppedObject => Err(()),
             ExecuteResponseKind::DroppedOwned => Ok(ExecuteResponse::DroppedOwned),
+            ExecuteResponseKind::DroppedPublication => Ok(ExecuteResponse::DroppedPublication),
+            ExecuteResponseKind::DroppedReplicationSlot => {
+                Ok(ExecuteResponse::DroppedReplicationSlot)
+            }
             ExecuteResponseKind::EmptyQuery => Ok(ExecuteResponse::EmptyQuery),
             ExecuteResponseKind::Fetch => Err(()),
             ExecuteResponseKind::GrantedPrivilege => Ok(ExecuteResponse::GrantedPrivilege),
             ExecuteResponseKind::GrantedRole => Ok(ExecuteResponse::GrantedRole),
             ExecuteResponseKind::Inserted => Err(()),
+            ExecuteResponseKind::Listened => Ok(ExecuteResponse::Listened),
             ExecuteResponseKind::Prepare => Ok(ExecuteResponse::Prepare),
             ExecuteResponseKind::Raised => Ok(ExecuteResponse::Raised),
             ExecuteResponseKind::ReassignOwned => Ok(ExecuteResponse::ReassignOwned),
             ExecuteResponseKind::RevokedPrivilege => Ok(ExecuteResponse::RevokedPrivilege),
             ExecuteResponseKind::RevokedRole => Ok(ExecuteResponse::RevokedRole),
             ExecuteResponseKind::SetVariable => Err(()),
+            ExecuteResponseKind::StartedReplication => Err(()),
             ExecuteResponseKind::StartedTransaction => Ok(ExecuteResponse::StartedTransaction),
             ExecuteResponseKind::Subscribing => Err(()),
             ExecuteResponseKind::TransactionCommitted => Err(()),
             ExecuteResponseKind::TransactionRolledBack => Err(()),
+            ExecuteResponseKind::Truncated => Err(()),
+            ExecuteResponseKind::Unlistened => Ok(ExecuteResponse::Unlistened),
             ExecuteResponseKind::Updated => Err(()),
             ExecuteResponseKind::ValidatedConnection => Ok(ExecuteResponse::ValidatedConnection),
             ExecuteResponseKind::SendingRowsStreaming => Err(()),
@@ -803,7 +863,11 @@ impl ExecuteResponse {
             CreatedMaterializedView { .. } => Some("CREATE MATERIALIZED VIEW".into()),
             CreatedContinualTask { .. } => Some("CREATE CONTINUAL TASK".into()),
             CreatedType => Some("CREATE TYPE".into()),
+            CreatedFunction => Some("CREATE FUNCTION".into()),
+            CreatedSequence => Some("CREATE SEQUENCE".into()),
+            CreatedPolicy => Some("CREATE POLICY".into()),
             CreatedNetworkPolicy => Some("CREATE NETWORKPOLICY".into()),
+            CreatedPublication => Some("CREATE PUBLICATION".into()),
             Deallocate { all } => Some(format!("DEALLOCATE{}", if *all { " ALL" } else { "" })),
             DeclaredCursor => Some("DECLARE CURSOR".into()),
             Deleted(n) => Some(format!("DELETE {}", n)),
@@ -811,6 +875,8 @@ impl ExecuteResponse {
             DiscardedAll => Some("DISCARD ALL".into()),
             DroppedObject(o) => Some(format!("DROP {o}")),
             DroppedOwned => Some("DROP OWNED".into()),
+            DroppedPublication => Some("DROP PUBLICATION".into()),
+            DroppedReplicationSlot => Some("DROP_REPLICATION_SLOT".into()),
             EmptyQuery => None,
             Fetch { .. } => None,
             GrantedPrivilege => Some("GRANT".into()),
@@ -825,6 +891,7 @@ impl ExecuteResponse {
                 // have OIDs.
                 Some(format!("INSERT 0 {}", n))
             }
+            Listened => Some("LISTEN".into()),
             Prepare => Some("PREPARE".into()),
             Raised => Some("RAISE".into()),
             ReassignOwned => Some("REASSIGN OWNED".into()),
@@ -833,10 +900,13 @@ impl ExecuteResponse {
             SendingRowsStreaming { .. } | SendingRowsImmediate { .. } => None,
             SetVariable { reset: true, .. } => Some("RESET".into()),
             SetVariable { reset: false, .. } => Some("SET".into()),
+            StartedReplication { .. } => None,
             StartedTransaction { .. } => Some("BEGIN".into()),
             Subscribing { .. } => None,
             TransactionCommitted { .. } => Some("COMMIT".into()),
             TransactionRolledBack { .. } => Some("ROLLBACK".into()),
+            Truncated => Some("TRUNCATE TABLE".into()),
+            Unlistened => Some("UNLISTEN".into()),
             Updated(n) => Some(format!("UPDATE {}", n)),
             ValidatedConnection => Some("VALIDATE CONNECTION".into()),
             CreatedIntrospectionSubscribe => Some("CREATE INTROSPECTION SUBSCRIBE".into()),
@@ -860,6 +930,7 @@ impl ExecuteResponse {
             | AlterItemRename
             | AlterRetainHistory
             | AlterSourceTimestampInterval
+            | AlterRowLevelSecurity
             | AlterNoop
             | AlterSchemaRename
             | AlterSchemaSwap
@@ -868,6 +939,8 @@ impl ExecuteResponse {
             | AlterSource
             | AlterSink
             | AlterTableAddColumn
+            | AlterTableDropColumn
+            | AlterTableRenameColumn
             | AlterMaterializedViewApplyReplacement
             | AlterNetworkPolicy => &[AlteredObject],
             AlterDefaultPrivileges => &[AlteredDefaultPrivileges],
@@ -896,6 +969,10 @@ impl ExecuteResponse {
             CreateContinualTask => &[CreatedContinualTask],
             CreateIndex => &[CreatedIndex],
             CreateType => &[CreatedType],
+            CreateFunction => &[CreatedFunction],
+            CreateSequence => &[CreatedSequence],
+            CreatePolicy => &[CreatedPolicy],
+            CreatePublication => &[CreatedPublication],
             PlanKind::Deallocate => &[ExecuteResponseKind::Deallocate],
             CreateNetworkPolicy => &[CreatedNetworkPolicy],
             Declare => &[DeclaredCursor],
@@ -903,6 +980,7 @@ impl ExecuteResponse {
             DiscardAll => &[DiscardedAll],
             DropObjects => &[DroppedObject],
             DropOwned => &[DroppedOwned],
+            DropPublication => &[DroppedPublication],
             PlanKind::EmptyQuery => &[ExecuteResponseKind::EmptyQuery],
             ExplainPlan | ExplainPushdown | ExplainTimestamp | Select | ShowAllVariables
             | ShowCreate | ShowColumns | ShowVariable | InspectShard | ExplainSinkSchema => &[
@@ -921,6 +999,10 @@ impl ExecuteResponse {
             GrantPrivileges => &[GrantedPrivilege],
             GrantRole => &[GrantedRole],
             Insert => &[Inserted, SendingRowsImmediate],
+            Listen => &[Listened],
+            IdentifySystem | CreateReplicationSlot => &[SendingRowsImmediate],
+            DropReplicationSlot => &[DroppedReplicationSlot],
+            StartReplication => &[StartedReplication],
             PlanKind::Prepare => &[ExecuteResponseKind::Prepare],
             PlanKind::Raise => &[ExecuteResponseKind::Raised],
             PlanKind::ReassignOwned => &[ExecuteResponseKind::ReassignOwned],
@@ -931,6 +1013,8 @@ impl ExecuteResponse {
             }
             PlanKind::Subscribe => &[Subscribing, ExecuteResponseKind::CopyTo],
             StartTransaction => &[StartedTransaction],
+            Truncate => &[Truncated],
+            Unlisten => &[Unlistened],
             SideEffectingFunc => &[SendingRowsStreaming, SendingRowsImmediate],
             ValidateConnection => &[ExecuteResponseKind::ValidatedConnection],
         }
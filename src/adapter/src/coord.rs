@@ -147,7 +147,7 @@ use mz_sql::names::{QualifiedItemName, ResolvedIds, SchemaSpecifier};
 use mz_sql::optimizer_metrics::OptimizerMetrics;
 use mz_sql::plan::{
     self, AlterSinkPlan, ConnectionDetails, CreateConnectionPlan, HirRelationExpr,
-    NetworkPolicyRule, OnTimeoutAction, Params, QueryWhen,
+    NetworkPolicyRule, OnTimeoutAction, Params, QueryWhen, SequenceId,
 };
 use mz_sql::session::user::User;
 use mz_sql::session::vars::{MAX_CREDIT_CONSUMPTION_RATE, SystemVars, Var};
@@ -188,7 +188,9 @@ use crate::coord::caught_up::CaughtUpCheckContext;
 use crate::coord::cluster_scheduling::SchedulingDecision;
 use crate::coord::id_bundle::CollectionIdBundle;
 use crate::coord::introspection::IntrospectionSubscribe;
+use crate::coord::listen::ListenSubscribe;
 use crate::coord::peek::PendingPeek;
+use crate::coord::replication::ReplicationStream;
 use crate::coord::statement_logging::StatementLogging;
 use crate::coord::timeline::{TimelineContext, TimelineState};
 use crate::coord::timestamp_selection::{TimestampContext, TimestampDetermination};
@@ -226,8 +228,10 @@ mod command_handler;
 mod ddl;
 mod indexes;
 mod introspection;
+mod listen;
 mod message_handler;
 mod privatelink_status;
+mod replication;
 mod sql;
 mod validity;
 
@@ -341,6 +345,13 @@ pub enum Message {
         table_id: CatalogItemId,
         batches: Vec<Result<ProtoBatch, String>>,
     },
+    /// Allocates values for the identity columns of rows written by a
+    /// read-then-write operation.
+    AllocateSequenceValues {
+        id: SequenceId,
+        amount: u64,
+        tx: oneshot::Sender<Result<Vec<i64>, AdapterError>>,
+    },
     StorageUsageSchedule,
     StorageUsageFetch,
     StorageUsageUpdate(ShardsUsageReferenced),
@@ -427,6 +438,7 @@ impl Message {
                 Command::GetWebhook { .. } => "command-get_webhook",
                 Command::GetSystemVars { .. } => "command-get_system_vars",
                 Command::SetSystemVars { .. } => "command-set_system_vars",
+                Command::AdvanceReplicationSlot { .. } => "command-advance_replication_slot",
                 Command::Terminate { .. } => "command-terminate",
                 Command::RetireExecute { .. } => "command-retire_execute",
                 Command::CheckConsistency { .. } => "command-check_consistency",
@@ -435,6 +447,7 @@ impl Message {
                 Command::AuthenticateGetSASLChallenge { .. } => "command-auth_get_sasl_challenge",
                 Command::AuthenticateVerifySASLProof { .. } => "command-auth_verify_sasl_proof",
                 Command::CheckRoleCanLogin { .. } => "command-check_role_can_login",
+                Command::SyncRoleMemberships { .. } => "command-sync_role_memberships",
                 Command::GetComputeInstanceClient { .. } => "get-compute-instance-client",
                 Command::GetOracle { .. } => "get-oracle",
                 Command::DetermineRealTimeRecentTimestamp { .. } => {
@@ -476,6 +489,7 @@ impl Message {
             Message::CancelPendingPeeks { .. } => "cancel_pending_peeks",
             Message::LinearizeReads => "linearize_reads",
             Message::StagedBatches { .. } => "staged_batches",
+            Message::AllocateSequenceValues { .. } => "allocate_sequence_values",
             Message::StorageUsageSchedule => "storage_usage_schedule",
             Message::StorageUsageFetch => "storage_usage_fetch",
             Message::StorageUsageUpdate(_) => "storage_usage_update",
@@ -1877,6 +1891,13 @@ pub struct Coordinator {
     staged_cancellation: BTreeMap<ConnectionId, (watch::Sender<bool>, watch::Receiver<bool>)>,
     /// Active introspection subscribes.
     introspection_subscribes: BTreeMap<GlobalId, IntrospectionSubscribe>,
+    /// Active subscribes backing `LISTEN`.
+    listen_subscribes: BTreeMap<GlobalId, ListenSubscribe>,
+    /// Active logical replication streams, by the connection they stream to.
+    replication_streams: BTreeMap<ConnectionId, ReplicationStream>,
+    /// Read holds of replication slots on the relations they stream, by slot
+    /// name.
+    replication_slot_holds: BTreeMap<String, ReadHolds<Timestamp>>,
 
     /// Locks that grant access to a specific object, populated lazily as objects are written to.
     write_locks: BTreeMap<CatalogItemId, Arc<tokio::sync::Mutex<()>>>,
@@ -2103,6 +2124,7 @@ impl Coordinator {
         info!("startup: coordinator init: bootstrap: storage collections init beginning");
         self.bootstrap_storage_collections(&migrated_storage_collections_0dt)
             .await;
+        self.bootstrap_replication_slots().await?;
         info!(
             "startup: coordinator init: bootstrap: storage collections init complete in {:?}",
             init_storage_collections_start.elapsed()
@@ -2353,7 +2375,9 @@ impl Coordinator {
                 CatalogItem::Log(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
-                | CatalogItem::Secret(_) => {}
+                | CatalogItem::Secret(_)
+                | CatalogItem::Sequence(_)
+                | CatalogItem::Policy(_) => {}
             }
         }
 
@@ -2931,7 +2955,7 @@ impl Coordinator {
                 }
                 CatalogItem::Table(table) => {
                     match &table.data_source {
-                        TableDataSource::TableWrites { defaults: _ } => {
+                        TableDataSource::TableWrites { .. } => {
                             let versions: BTreeMap<_, _> = table
                                 .collection_descs()
                                 .map(|(gid, version, desc)| (version, (gid, desc)))
@@ -3027,6 +3051,8 @@ impl Coordinator {
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
                 | CatalogItem::Secret(_)
+                | CatalogItem::Sequence(_)
+                | CatalogItem::Policy(_)
                 | CatalogItem::Connection(_) => (),
             }
         }
@@ -3460,6 +3486,8 @@ impl Coordinator {
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
                 | CatalogItem::Secret(_)
+                | CatalogItem::Sequence(_)
+                | CatalogItem::Policy(_)
                 | CatalogItem::Connection(_) => (),
             }
         }
@@ -3493,6 +3521,8 @@ impl Coordinator {
                 | CatalogItem::Type(_)
                 | CatalogItem::Func(_)
                 | CatalogItem::Secret(_)
+                | CatalogItem::Sequence(_)
+                | CatalogItem::Policy(_)
                 | CatalogItem::Connection(_) => continue,
             };
             if let Some(plan) = self.catalog.try_get_physical_plan(&gid) {
@@ -4687,6 +4717,9 @@ pub fn serve(
                     active_copies: BTreeMap::new(),
                     staged_cancellation: BTreeMap::new(),
                     introspection_subscribes: BTreeMap::new(),
+                    listen_subscribes: BTreeMap::new(),
+                    replication_streams: BTreeMap::new(),
+                    replication_slot_holds: BTreeMap::new(),
                     write_locks: BTreeMap::new(),
                     deferred_write_ops: BTreeMap::new(),
                     pending_writes: Vec::new(),
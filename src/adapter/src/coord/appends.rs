@@ -969,6 +969,9 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::CreateMaterializedView(_)
         | Plan::CreateIndex(_)
         | Plan::CreateType(_)
+        | Plan::CreateFunction(_)
+        | Plan::CreateSequence(_)
+        | Plan::CreatePolicy(_)
         | Plan::Comment(_)
         | Plan::DiscardTemp
         | Plan::DiscardAll
@@ -991,6 +994,7 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::ExplainPushdown(_)
         | Plan::ExplainSinkSchema(_)
         | Plan::Insert(_)
+        | Plan::Truncate(_)
         | Plan::AlterNetworkPolicy(_)
         | Plan::AlterNoop(_)
         | Plan::AlterClusterRename(_)
@@ -1003,6 +1007,7 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::AlterItemRename(_)
         | Plan::AlterRetainHistory(_)
         | Plan::AlterSourceTimestampInterval(_)
+        | Plan::AlterRowLevelSecurity(_)
         | Plan::AlterSchemaRename(_)
         | Plan::AlterSchemaSwap(_)
         | Plan::AlterSecret(_)
@@ -1013,6 +1018,8 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::AlterRole(_)
         | Plan::AlterOwner(_)
         | Plan::AlterTableAddColumn(_)
+        | Plan::AlterTableDropColumn(_)
+        | Plan::AlterTableRenameColumn(_)
         | Plan::AlterMaterializedViewApplyReplacement(_)
         | Plan::Declare(_)
         | Plan::Fetch(_)
@@ -1021,6 +1028,14 @@ pub(crate) fn waiting_on_startup_appends(
         | Plan::Execute(_)
         | Plan::Deallocate(_)
         | Plan::Raise(_)
+        | Plan::Listen(_)
+        | Plan::Unlisten(_)
+        | Plan::CreatePublication(_)
+        | Plan::DropPublication(_)
+        | Plan::IdentifySystem
+        | Plan::CreateReplicationSlot(_)
+        | Plan::DropReplicationSlot(_)
+        | Plan::StartReplication(_)
         | Plan::GrantRole(_)
         | Plan::RevokeRole(_)
         | Plan::GrantPrivileges(_)
@@ -669,6 +669,12 @@ impl Coordinator {
             }
         }
 
+        // Clean up any listen subscribes that rely on dropped relations or clusters.
+        let listen_subscribes_to_drop =
+            self.listen_subscribes_depending_on(&readable_collections_to_drop, &clusters_to_drop);
+        let replication_streams_to_fail =
+            self.replication_streams_depending_on(&readable_collections_to_drop, &clusters_to_drop);
+
         // Clean up any pending peeks that rely on dropped relations or clusters.
         for (uuid, pending_peek) in &self.pending_peeks {
             if let Some(id) = pending_peek
@@ -792,6 +798,15 @@ impl Coordinator {
                     .await;
             }
 
+            for id in listen_subscribes_to_drop {
+                self.drop_listen_subscribe(id);
+            }
+
+            for (conn_id, error) in replication_streams_to_fail {
+                self.fail_replication_stream(&conn_id, error);
+            }
+            self.release_replication_slot_holds(&readable_collections_to_drop);
+
             if !peeks_to_drop.is_empty() {
                 for (dropped_name, uuid) in peeks_to_drop {
                     if let Some(pending_peek) = self.remove_pending_peek(&uuid) {
@@ -1018,7 +1033,7 @@ impl Coordinator {
         // by environmentd (e.g. with INSERT INTO statements) or by the storage layer
         // (e.g. a source-fed table).
         match &table.data_source {
-            TableDataSource::TableWrites { defaults: _ } => {
+            TableDataSource::TableWrites { .. } => {
                 let versions: BTreeMap<_, _> = table
                     .collection_descs()
                     .map(|(gid, version, desc)| (version, (gid, desc)))
@@ -1430,6 +1445,8 @@ impl Coordinator {
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Sequence(_)
+                    | CatalogItem::Policy(_)
                     | CatalogItem::ContinualTask(_) => {
                         // Other item types don't have connection dependencies
                         // that need updating.
@@ -1611,6 +1628,8 @@ impl CatalogImplication {
                 CatalogItem::Log(_) => {}
                 CatalogItem::Type(_) => {}
                 CatalogItem::Func(_) => {}
+                CatalogItem::Sequence(_) => {}
+                CatalogItem::Policy(_) => {}
             },
             ParsedStateUpdateKind::TemporaryItem {
                 durable_item: _,
@@ -1652,6 +1671,8 @@ impl CatalogImplication {
                 CatalogItem::Log(_) => {}
                 CatalogItem::Type(_) => {}
                 CatalogItem::Func(_) => {}
+                CatalogItem::Sequence(_) => {}
+                CatalogItem::Policy(_) => {}
             },
             ParsedStateUpdateKind::Cluster {
                 durable_cluster: _,
@@ -1750,7 +1771,10 @@ mod tests {
             resolved_ids: ResolvedIds::empty(),
             custom_logical_compaction_window: None,
             is_retained_metrics_object: false,
-            data_source: TableDataSource::TableWrites { defaults: vec![] },
+            data_source: TableDataSource::TableWrites {
+                defaults: vec![],
+                identity_columns: vec![],
+            },
         }
     }
 
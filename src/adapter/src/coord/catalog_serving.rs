@@ -94,6 +94,9 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::CreateMaterializedView(_)
         | Plan::CreateIndex(_)
         | Plan::CreateType(_)
+        | Plan::CreateFunction(_)
+        | Plan::CreateSequence(_)
+        | Plan::CreatePolicy(_)
         | Plan::Comment(_)
         | Plan::DiscardTemp
         | Plan::DiscardAll
@@ -127,6 +130,7 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::ExplainPushdown(_)
         | Plan::ExplainSinkSchema(_)
         | Plan::Insert(_)
+        | Plan::Truncate(_)
         | Plan::AlterNetworkPolicy(_)
         | Plan::AlterNoop(_)
         | Plan::AlterClusterRename(_)
@@ -139,6 +143,7 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::AlterItemRename(_)
         | Plan::AlterRetainHistory(_)
         | Plan::AlterSourceTimestampInterval(_)
+        | Plan::AlterRowLevelSecurity(_)
         | Plan::AlterSchemaRename(_)
         | Plan::AlterSchemaSwap(_)
         | Plan::AlterSecret(_)
@@ -149,6 +154,8 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::AlterRole(_)
         | Plan::AlterOwner(_)
         | Plan::AlterTableAddColumn(_)
+        | Plan::AlterTableDropColumn(_)
+        | Plan::AlterTableRenameColumn(_)
         | Plan::AlterMaterializedViewApplyReplacement(_)
         | Plan::Declare(_)
         | Plan::Fetch(_)
@@ -158,6 +165,14 @@ pub fn auto_run_on_catalog_server<'a, 's, 'p>(
         | Plan::Execute(_)
         | Plan::Deallocate(_)
         | Plan::Raise(_)
+        | Plan::Listen(_)
+        | Plan::Unlisten(_)
+        | Plan::CreatePublication(_)
+        | Plan::DropPublication(_)
+        | Plan::IdentifySystem
+        | Plan::CreateReplicationSlot(_)
+        | Plan::DropReplicationSlot(_)
+        | Plan::StartReplication(_)
         | Plan::GrantRole(_)
         | Plan::RevokeRole(_)
         | Plan::GrantPrivileges(_)
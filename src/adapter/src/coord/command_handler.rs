@@ -50,7 +50,7 @@ use mz_sql::pure::{
 };
 use mz_sql::rbac;
 use mz_sql::rbac::CREATE_ITEM_USAGE;
-use mz_sql::session::user::User;
+use mz_sql::session::user::{MZ_SYSTEM_ROLE_ID, User};
 use mz_sql::session::vars::{
     EndTransactionAction, NETWORK_POLICY, OwnedVarInput, STATEMENT_LOGGING_SAMPLE_RATE, Value, Var,
 };
@@ -182,6 +182,18 @@ impl Coordinator {
                     self.handle_role_can_login(tx, role_name);
                 }
 
+                Command::SyncRoleMemberships {
+                    tx,
+                    role_name,
+                    granted_roles,
+                    managed_roles,
+                } => {
+                    let result = self
+                        .sync_role_memberships(&role_name, &granted_roles, &managed_roles)
+                        .await;
+                    let _ = tx.send(result);
+                }
+
                 Command::Execute {
                     portal_name,
                     session,
@@ -283,6 +295,17 @@ impl Coordinator {
                     let _ = tx.send(result);
                 }
 
+                Command::AdvanceReplicationSlot {
+                    conn_id,
+                    confirmed_flush,
+                    tx,
+                } => {
+                    let result = self
+                        .advance_replication_slot(&conn_id, confirmed_flush)
+                        .await;
+                    let _ = tx.send(result);
+                }
+
                 Command::Terminate { conn_id, tx } => {
                     self.handle_terminate(conn_id).await;
                     // Note: We purposefully do not use a ClientTransmitter here because we're already
@@ -554,6 +577,50 @@ impl Coordinator {
         let _ = tx.send(result);
     }
 
+    /// Grants the role named `role_name` each of `granted_roles` and revokes
+    /// it from each of the other `managed_roles`. Roles that do not exist are
+    /// skipped.
+    async fn sync_role_memberships(
+        &mut self,
+        role_name: &str,
+        granted_roles: &BTreeSet<String>,
+        managed_roles: &BTreeSet<String>,
+    ) -> Result<(), AdapterError> {
+        let catalog = self.catalog();
+        let Some(member) = catalog.try_get_role_by_name(role_name) else {
+            return Err(AdapterError::AuthenticationError(
+                AuthenticationError::RoleNotFound,
+            ));
+        };
+        let member_id = member.id;
+        let mut ops = Vec::new();
+        for name in managed_roles {
+            let Some(role) = catalog.try_get_role_by_name(name) else {
+                warn!(role = %name, "skipping membership sync of unknown role");
+                continue;
+            };
+            let is_member = member.membership.map.contains_key(&role.id);
+            let should_be_member = granted_roles.contains(name);
+            if should_be_member && !is_member {
+                ops.push(catalog::Op::GrantRole {
+                    role_id: role.id,
+                    member_id,
+                    grantor_id: MZ_SYSTEM_ROLE_ID,
+                });
+            } else if !should_be_member && is_member {
+                ops.push(catalog::Op::RevokeRole {
+                    role_id: role.id,
+                    member_id,
+                    grantor_id: MZ_SYSTEM_ROLE_ID,
+                });
+            }
+        }
+        if ops.is_empty() {
+            return Ok(());
+        }
+        self.catalog_transact(None, ops).await
+    }
+
     fn handle_authenticate_verify_sasl_proof(
         &self,
         tx: oneshot::Sender<Result<SASLVerifyProofResponse, AdapterError>>,
@@ -1239,6 +1306,7 @@ impl Coordinator {
                     | Statement::AlterOwner(_)
                     | Statement::AlterRetainHistory(_)
                     | Statement::AlterRole(_)
+                    | Statement::AlterRowLevelSecurity(_)
                     | Statement::AlterSecret(_)
                     | Statement::AlterSink(_)
                     | Statement::AlterSource(_)
@@ -1246,6 +1314,8 @@ impl Coordinator {
                     | Statement::AlterSystemResetAll(_)
                     | Statement::AlterSystemSet(_)
                     | Statement::AlterTableAddColumn(_)
+                    | Statement::AlterTableDropColumn(_)
+                    | Statement::AlterTableRenameColumn(_)
                     | Statement::AlterNetworkPolicy(_)
                     | Statement::CreateCluster(_)
                     | Statement::CreateClusterReplica(_)
@@ -1261,18 +1331,30 @@ impl Coordinator {
                     | Statement::CreateSubsource(_)
                     | Statement::CreateTable(_)
                     | Statement::CreateType(_)
+                    | Statement::CreateFunction(_)
+                    | Statement::CreateSequence(_)
                     | Statement::CreateView(_)
                     | Statement::CreateWebhookSource(_)
                     | Statement::CreateNetworkPolicy(_)
+                    | Statement::CreatePublication(_)
+                    | Statement::CreatePolicy(_)
+                    | Statement::CreateReplicationSlot(_)
                     | Statement::Delete(_)
                     | Statement::DropObjects(_)
                     | Statement::DropOwned(_)
+                    | Statement::DropPublication(_)
+                    | Statement::DropReplicationSlot(_)
                     | Statement::GrantPrivileges(_)
                     | Statement::GrantRole(_)
+                    | Statement::IdentifySystem(_)
                     | Statement::Insert(_)
+                    | Statement::Listen(_)
                     | Statement::ReassignOwned(_)
                     | Statement::RevokePrivileges(_)
                     | Statement::RevokeRole(_)
+                    | Statement::StartReplication(_)
+                    | Statement::Truncate(_)
+                    | Statement::Unlisten(_)
                     | Statement::Update(_)
                     | Statement::ValidateConnection(_)
                     | Statement::Comment(_) => {
@@ -1867,6 +1949,8 @@ impl Coordinator {
         self.cancel_pending_peeks(conn.conn_id());
         self.cancel_pending_watchsets(&conn_id);
         self.cancel_pending_copy(&conn_id);
+        self.drop_listen_subscribes(&conn_id, None);
+        self.end_replication_stream(&conn_id).await;
         self.end_session_for_statement_logging(conn.uuid());
 
         // Queue the builtin table update, but do not wait for it to complete. We explicitly do
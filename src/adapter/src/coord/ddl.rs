@@ -1153,7 +1153,9 @@ impl Coordinator {
                         | CatalogItem::View(_)
                         | CatalogItem::Index(_)
                         | CatalogItem::Type(_)
-                        | CatalogItem::Func(_) => {}
+                        | CatalogItem::Func(_)
+                        | CatalogItem::Sequence(_)
+                        | CatalogItem::Policy(_) => {}
                     }
                 }
                 Op::DropObjects(drop_object_infos) => {
@@ -1235,7 +1237,9 @@ impl Coordinator {
                                     | CatalogItem::View(_)
                                     | CatalogItem::Index(_)
                                     | CatalogItem::Type(_)
-                                    | CatalogItem::Func(_) => {}
+                                    | CatalogItem::Func(_)
+                                    | CatalogItem::Sequence(_)
+                                    | CatalogItem::Policy(_) => {}
                                 }
                             }
                         }
@@ -1261,6 +1265,8 @@ impl Coordinator {
                     | CatalogItem::Sink(_)
                     | CatalogItem::MaterializedView(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Sequence(_)
+                    | CatalogItem::Policy(_)
                     | CatalogItem::Log(_)
                     | CatalogItem::View(_)
                     | CatalogItem::Index(_)
@@ -1271,8 +1277,11 @@ impl Coordinator {
                 Op::AlterRole { .. }
                 | Op::AlterRetainHistory { .. }
                 | Op::AlterSourceTimestampInterval { .. }
+                | Op::AlterRowLevelSecurity { .. }
                 | Op::AlterNetworkPolicy { .. }
                 | Op::AlterAddColumn { .. }
+                | Op::AlterDropColumn { .. }
+                | Op::AlterRenameColumn { .. }
                 | Op::AlterMaterializedViewApplyReplacement { .. }
                 | Op::UpdatePrivilege { .. }
                 | Op::UpdateDefaultPrivilege { .. }
@@ -76,6 +76,8 @@ impl DataflowBuilder<'_> {
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Sequence(_)
+                    | CatalogItem::Policy(_)
                     | CatalogItem::Connection(_) => {
                         // Non-indexable thing; no work to do.
                     }
@@ -0,0 +1,362 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Support for `LISTEN` and notification channels.
+//!
+//! A materialized view created with the `NOTIFY CHANNEL` option is bound to that channel. When a
+//! session executes `LISTEN <channel>`, it is registered as a listener of one subscribe without a
+//! snapshot per relation bound to the channel, on the session's active cluster. Listen subscribes
+//! are shared: all sessions listening for changes to the same relation on the same cluster are
+//! served by a single dataflow. Every batch of updates received from such a subscribe is turned
+//! into one notification per timestamp, which is fanned out to the listeners through their
+//! notification transmitters.
+//!
+//! Notification transmitters are bounded, so a listener that doesn't drain its notifications,
+//! for example because it stays inside a transaction block, can't make the coordinator buffer
+//! an unbounded amount of them. Like PostgreSQL when its notification queue is full,
+//! notifications that don't fit are dropped. Once there is room again, the listener receives a
+//! single notification with a `"lagged": true` payload in place of the dropped ones, after which
+//! it must assume that the relation may have changed in any way.
+//!
+//! # Lifecycle of Listen Subscribes
+//!
+//! * `sequence_listen` registers a connection as a listener of the subscribes for a channel,
+//!   installing those that don't exist yet.
+//! * The coordinator calls `handle_listen_subscribe_batch` for each response it receives from a
+//!   listen subscribe.
+//! * Listeners are removed by `UNLISTEN`, `DISCARD ALL`, and when their connection terminates.
+//!   A listen subscribe is dropped when its last listener is removed, its relation is dropped,
+//!   or its cluster is dropped.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use derivative::Derivative;
+use differential_dataflow::lattice::Lattice;
+use mz_adapter_types::connection::ConnectionId;
+use mz_compute_client::protocol::response::SubscribeBatch;
+use mz_controller_types::ClusterId;
+use mz_repr::optimize::OverrideFrom;
+use mz_repr::{GlobalId, Timestamp};
+use mz_sql::plan::{ListenPlan, SubscribeFrom, UnlistenPlan};
+use mz_sql::session::metadata::SessionMetadata;
+use tokio::sync::mpsc::Sender;
+use tokio::sync::mpsc::error::TrySendError;
+use tracing::{info, warn};
+
+use crate::coord::timestamp_selection::TimestampProvider;
+use crate::coord::{Coordinator, TargetCluster};
+use crate::optimize::Optimize;
+use crate::session::{Notification, Session};
+use crate::{AdapterError, ExecuteResponse, optimize};
+
+/// State tracked about an active listen subscribe, which is shared by all connections listening
+/// for changes to its relation on its cluster.
+#[derive(Debug)]
+pub(super) struct ListenSubscribe {
+    /// The channel the relation is bound to.
+    channel: String,
+    /// The ID of the cluster running the subscribe.
+    cluster_id: ClusterId,
+    /// The ID of the relation bound to the channel.
+    relation_id: GlobalId,
+    /// The fully qualified name of the relation, as reported in notification payloads.
+    relation_name: String,
+    /// The connections listening for changes to the relation.
+    pub(super) listeners: BTreeMap<ConnectionId, Listener>,
+}
+
+/// A connection listening for changes through a listen subscribe.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub(super) struct Listener {
+    /// The transmitter through which notifications are delivered to the connection.
+    #[derivative(Debug = "ignore")]
+    notifications_tx: Sender<Notification>,
+    /// Whether notifications were dropped because the connection's notification queue was
+    /// full, and the connection hasn't been told about it yet.
+    lagged: bool,
+}
+
+impl Coordinator {
+    /// Starts listening on a channel by registering the connection as a listener of a subscribe
+    /// for each relation bound to it, installing the subscribes that don't exist yet.
+    ///
+    /// Listening on a channel the connection is already listening on is a no-op, like in
+    /// PostgreSQL.
+    pub(super) async fn sequence_listen(
+        &mut self,
+        session: &Session,
+        plan: ListenPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let ListenPlan { channel, relations } = plan;
+
+        let Some(notifications_tx) = session.retain_notification_transmitter() else {
+            return Err(AdapterError::Unsupported(
+                "asynchronous notifications outside of the PostgreSQL wire protocol",
+            ));
+        };
+        if relations.is_empty() {
+            return Ok(ExecuteResponse::Listened);
+        }
+
+        let cluster = self
+            .catalog()
+            .resolve_target_cluster(TargetCluster::Active, session)?;
+        if cluster.replicas().next().is_none() {
+            return Err(AdapterError::NoClusterReplicasAvailable {
+                name: cluster.name.clone(),
+                is_managed: cluster.is_managed(),
+            });
+        }
+        let cluster_id = cluster.id;
+        let conn_id = session.conn_id().clone();
+
+        for item_id in relations {
+            let entry = self.catalog().get_entry(&item_id);
+            let relation_id = entry.latest_global_id();
+            let listener = Listener {
+                notifications_tx: notifications_tx.clone(),
+                lagged: false,
+            };
+            let existing = self
+                .listen_subscribes
+                .values_mut()
+                .find(|s| s.relation_id == relation_id && s.cluster_id == cluster_id);
+            if let Some(subscribe) = existing {
+                subscribe
+                    .listeners
+                    .entry(conn_id.clone())
+                    .or_insert(listener);
+                continue;
+            }
+            // The subscribe is shared between connections, so the name must not depend on the
+            // connection's temporary schema.
+            let relation_name = self
+                .catalog()
+                .resolve_full_name(entry.name(), None)
+                .to_string();
+
+            let (_, sink_id) = self.allocate_transient_id();
+            let (_, view_id) = self.allocate_transient_id();
+            let compute_instance = self
+                .instance_snapshot(cluster_id)
+                .expect("compute instance does not exist");
+            let vars = self.catalog().system_config();
+            let overrides = self.catalog().get_cluster(cluster_id).config.features();
+            let optimizer_config = optimize::OptimizerConfig::from(vars).override_from(&overrides);
+
+            // Subscribing to a single materialized view is cheap to optimize, so unlike regular
+            // subscribes we don't move the optimization off the coordinator thread.
+            let mut optimizer = optimize::subscribe::Optimizer::new(
+                self.owned_catalog(),
+                compute_instance,
+                view_id,
+                sink_id,
+                false,
+                None,
+                format!("listen-{sink_id}"),
+                optimizer_config,
+                self.optimizer_metrics(),
+            );
+            let global_mir_plan =
+                optimizer.catch_unwind_optimize(SubscribeFrom::Id(relation_id))?;
+
+            // Without a snapshot, we only need to report changes that happen after the current
+            // write frontier.
+            let id_bundle = global_mir_plan.id_bundle(cluster_id);
+            let read_holds = self.acquire_read_holds(&id_bundle);
+            let mut as_of = self.greatest_available_read(&id_bundle);
+            as_of.join_assign(&read_holds.least_valid_read());
+            if as_of.is_empty() {
+                // The relation is closed for writes and will never change again.
+                continue;
+            }
+
+            let global_mir_plan = global_mir_plan.resolve(as_of);
+            let global_lir_plan = optimizer.catch_unwind_optimize(global_mir_plan)?;
+
+            info!(%sink_id, %conn_id, %channel, %relation_id, "installing listen subscribe");
+            self.listen_subscribes.insert(
+                sink_id,
+                ListenSubscribe {
+                    channel: channel.clone(),
+                    cluster_id,
+                    relation_id,
+                    relation_name,
+                    listeners: BTreeMap::from([(conn_id.clone(), listener)]),
+                },
+            );
+            let (df_desc, _df_meta) = global_lir_plan.unapply();
+            self.ship_dataflow(df_desc, cluster_id, None).await;
+
+            drop(read_holds);
+        }
+
+        Ok(ExecuteResponse::Listened)
+    }
+
+    /// Stops listening on a channel, or on all channels if none is specified.
+    pub(super) fn sequence_unlisten(
+        &mut self,
+        session: &Session,
+        plan: UnlistenPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        self.drop_listen_subscribes(session.conn_id(), plan.channel.as_deref());
+        Ok(ExecuteResponse::Unlistened)
+    }
+
+    /// Removes the given connection from the listeners of all listen subscribes, optionally
+    /// restricted to those of relations bound to `channel`.
+    pub(super) fn drop_listen_subscribes(&mut self, conn_id: &ConnectionId, channel: Option<&str>) {
+        let to_remove: Vec<_> = self
+            .listen_subscribes
+            .iter()
+            .filter(|(_, s)| s.listeners.contains_key(conn_id))
+            .filter(|(_, s)| channel.is_none_or(|channel| s.channel == channel))
+            .map(|(id, _)| *id)
+            .collect();
+
+        for id in to_remove {
+            self.remove_listener(id, conn_id);
+        }
+    }
+
+    /// Removes a connection from the listeners of a listen subscribe, dropping the subscribe if
+    /// it was the last one.
+    pub(super) fn remove_listener(&mut self, id: GlobalId, conn_id: &ConnectionId) {
+        let Some(subscribe) = self.listen_subscribes.get_mut(&id) else {
+            return;
+        };
+        subscribe.listeners.remove(conn_id);
+        if subscribe.listeners.is_empty() {
+            self.drop_listen_subscribe(id);
+        }
+    }
+
+    /// Returns the IDs of the listen subscribes that read from one of `relations` or run on one
+    /// of `clusters`.
+    pub(super) fn listen_subscribes_depending_on(
+        &self,
+        relations: &BTreeSet<GlobalId>,
+        clusters: &[ClusterId],
+    ) -> Vec<GlobalId> {
+        self.listen_subscribes
+            .iter()
+            .filter(|(_, s)| relations.contains(&s.relation_id) || clusters.contains(&s.cluster_id))
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    pub(super) fn drop_listen_subscribe(&mut self, id: GlobalId) {
+        let Some(subscribe) = self.listen_subscribes.remove(&id) else {
+            return;
+        };
+
+        info!(
+            %id,
+            channel = %subscribe.channel,
+            relation_id = %subscribe.relation_id,
+            "dropping listen subscribe",
+        );
+
+        // The compute collection is already gone if its cluster was dropped.
+        let _ = self
+            .controller
+            .compute
+            .drop_collections(subscribe.cluster_id, vec![id]);
+    }
+
+    /// Processes a batch returned by a listen subscribe.
+    ///
+    /// Each timestamp at which the relation changed results in one notification, with a JSON
+    /// payload describing the relation, the timestamp, and the number of inserted and deleted
+    /// rows, which is sent to every listener.
+    pub(super) fn handle_listen_subscribe_batch(&mut self, id: GlobalId, batch: SubscribeBatch) {
+        let Some(subscribe) = self.listen_subscribes.get_mut(&id) else {
+            return;
+        };
+
+        let updates = match batch.updates {
+            Ok(updates) => updates,
+            Err(error) => {
+                warn!(
+                    %id,
+                    channel = %subscribe.channel,
+                    "listen subscribe produced an error: {error}",
+                );
+                self.drop_listen_subscribe(id);
+                return;
+            }
+        };
+
+        let mut changes: BTreeMap<Timestamp, (i64, i64)> = BTreeMap::new();
+        for (time, _row, diff) in updates {
+            let (inserts, deletes) = changes.entry(time).or_default();
+            if diff.is_positive() {
+                *inserts += diff.into_inner();
+            } else {
+                *deletes -= diff.into_inner();
+            }
+        }
+
+        let notifications: Vec<_> = changes
+            .into_iter()
+            .map(|(time, (inserts, deletes))| {
+                let payload = serde_json::json!({
+                    "relation": subscribe.relation_name,
+                    "timestamp": u64::from(time),
+                    "inserts": inserts,
+                    "deletes": deletes,
+                });
+                Notification {
+                    channel: subscribe.channel.clone(),
+                    payload: payload.to_string(),
+                }
+            })
+            .collect();
+        let lagged_notification = Notification {
+            channel: subscribe.channel.clone(),
+            payload: serde_json::json!({
+                "relation": subscribe.relation_name,
+                "lagged": true,
+            })
+            .to_string(),
+        };
+
+        subscribe.listeners.retain(|conn_id, listener| {
+            // A lagged listener is told about the notifications it missed before it receives
+            // new ones, so it never observes a gap without knowing about it.
+            let pending = listener.lagged.then_some(&lagged_notification);
+            for notification in pending.into_iter().chain(&notifications) {
+                match listener.notifications_tx.try_send(notification.clone()) {
+                    Ok(()) => listener.lagged = false,
+                    Err(TrySendError::Full(_)) => {
+                        if !listener.lagged {
+                            warn!(
+                                %id,
+                                %conn_id,
+                                "notification queue full, dropping listen notifications",
+                            );
+                        }
+                        listener.lagged = true;
+                        break;
+                    }
+                    Err(TrySendError::Closed(_)) => return false,
+                }
+            }
+            true
+        });
+
+        // The subscribe is done if all its connections went away or the relation can no longer
+        // change.
+        if subscribe.listeners.is_empty() || batch.upper.is_empty() {
+            self.drop_listen_subscribe(id);
+        }
+    }
+}
@@ -118,6 +118,14 @@ impl Coordinator {
             } => {
                 self.commit_staged_batches(conn_id, table_id, batches);
             }
+            Message::AllocateSequenceValues { id, amount, tx } => {
+                let result = self
+                    .allocate_sequence_values(id, amount)
+                    .boxed_local()
+                    .await;
+                // It is not an error for the requester to have gone away.
+                let _ = tx.send(result);
+            }
             Message::StorageUsageSchedule => {
                 self.schedule_storage_usage_collection().boxed_local().await;
             }
@@ -372,6 +380,10 @@ impl Coordinator {
                 } else if self.introspection_subscribes.contains_key(&sink_id) {
                     self.handle_introspection_subscribe_batch(sink_id, response)
                         .await;
+                } else if self.listen_subscribes.contains_key(&sink_id) {
+                    self.handle_listen_subscribe_batch(sink_id, response);
+                } else if let Some(conn_id) = self.replication_stream_of(sink_id) {
+                    self.handle_replication_subscribe_batch(conn_id, sink_id, response);
                 } else {
                     // Cancellation may cause us to receive responses for subscribes no longer
                     // tracked, so we quietly ignore them.
@@ -0,0 +1,657 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Support for logical replication.
+//!
+//! Publications name a set of tables and materialized views. Replication slots
+//! track how far a downstream consumer has durably received the changes to the
+//! relations of the publications it streams. Both are stored in the durable
+//! catalog.
+//!
+//! Log sequence numbers (LSNs) are Materialize timestamps: the changes that
+//! happened at timestamp `t` are sent as one transaction that commits at LSN
+//! `t`, and a slot whose confirmed flush position is `t` resumes with the
+//! changes after `t`.
+//!
+//! # Lifecycle of Replication Streams
+//!
+//! * `sequence_start_replication` installs one subscribe without a snapshot per
+//!   published relation, on the session's active cluster, as of the slot's
+//!   position.
+//! * The coordinator calls `handle_replication_subscribe_batch` for each
+//!   response it receives from such a subscribe. Once all subscribes of a
+//!   stream have advanced past a timestamp, the changes at that timestamp are
+//!   handed to the connection.
+//! * The connection calls `advance_replication_slot` whenever the consumer
+//!   reports that it flushed more changes. The reported position is made
+//!   durable at most every [`REPLICATION_SLOT_PERSIST_INTERVAL`], and when the
+//!   stream ends.
+//! * Replication streams are dropped when their connection terminates, and fail
+//!   when one of their relations or their cluster is dropped.
+//!
+//! # Read Holds
+//!
+//! Each replication slot holds back compaction of the relations last streamed
+//! from it at its durable confirmed flush position, so that its consumer can
+//! resume streaming after disconnecting or after a restart. The holds are
+//! acquired when the coordinator boots and when streaming starts, are
+//! downgraded as the slot advances, and are released when the slot or the
+//! relations are dropped.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use derivative::Derivative;
+use mz_adapter_types::connection::ConnectionId;
+use mz_catalog::durable::ReplicationSlot;
+use mz_compute_client::protocol::response::SubscribeBatch;
+use mz_controller_types::ClusterId;
+use mz_repr::optimize::OverrideFrom;
+use mz_repr::{Datum, Diff, GlobalId, Row, Timestamp};
+use mz_sql::catalog::SessionCatalog;
+use mz_sql::plan::{
+    CreatePublicationPlan, CreateReplicationSlotPlan, DropPublicationPlan, DropReplicationSlotPlan,
+    PlanError, StartReplicationPlan, SubscribeFrom, bypasses_row_level_security,
+};
+use mz_sql::session::metadata::SessionMetadata;
+use timely::progress::Antichain;
+use tokio::sync::mpsc::{self, UnboundedSender};
+use tracing::{info, warn};
+
+use crate::coord::id_bundle::CollectionIdBundle;
+use crate::coord::read_policy::ReadHolds;
+use crate::coord::{Coordinator, TargetCluster};
+use crate::optimize::Optimize;
+use crate::session::{PublishedRelation, ReplicationEvent, Session};
+use crate::{AdapterError, AdapterNotice, ExecuteResponse, optimize};
+
+/// The name of the only output plugin that replication slots support.
+const PGOUTPUT: &str = "pgoutput";
+
+/// How often the confirmed flush position reported by the consumer of a
+/// replication stream is made durable.
+///
+/// Consumers report their position with every standby status update, which
+/// can happen many times a second, so we don't write each of them to the
+/// catalog. Losing a position that was not yet made durable, e.g. in a crash,
+/// only means that some already confirmed changes are sent again, which
+/// consumers skip based on their LSN.
+const REPLICATION_SLOT_PERSIST_INTERVAL: Duration = Duration::from_secs(1);
+
+/// State tracked about an active replication stream.
+#[derive(Derivative)]
+#[derivative(Debug)]
+pub(super) struct ReplicationStream {
+    /// The name of the replication slot being streamed from.
+    slot: String,
+    /// The ID of the cluster running the subscribes.
+    cluster_id: ClusterId,
+    /// The IDs and fully qualified names of the published relations.
+    relations: Vec<(GlobalId, String)>,
+    /// The subscribes of the stream, mapped to the index of the relation they
+    /// read from and the upper of the batches they returned so far.
+    subscribes: BTreeMap<GlobalId, (usize, Antichain<Timestamp>)>,
+    /// Updates received from the subscribes that are not yet complete.
+    pending: Vec<(Timestamp, usize, Row, Diff)>,
+    /// All changes at times before the frontier have been handed to the
+    /// connection.
+    frontier: Timestamp,
+    /// The latest confirmed flush position reported by the consumer.
+    confirmed_flush: Timestamp,
+    /// The confirmed flush position last made durable.
+    persisted_flush: Timestamp,
+    /// When the confirmed flush position was last made durable.
+    persisted_at: Instant,
+    /// The transmitter through which events are delivered to the connection.
+    #[derivative(Debug = "ignore")]
+    events_tx: UnboundedSender<ReplicationEvent>,
+}
+
+/// Formats `ts` as a PostgreSQL log sequence number.
+fn format_lsn(ts: Timestamp) -> String {
+    let lsn = u64::from(ts);
+    format!("{:X}/{:X}", lsn >> 32, lsn & 0xFFFF_FFFF)
+}
+
+/// Returns an error if `session` cannot run replication commands.
+fn require_replication_connection(session: &Session) -> Result<(), AdapterError> {
+    if !session.is_replication_connection() {
+        return Err(AdapterError::Unsupported(
+            "replication commands outside of replication connections",
+        ));
+    }
+    Ok(())
+}
+
+impl Coordinator {
+    pub(super) async fn sequence_create_publication(
+        &mut self,
+        plan: CreatePublicationPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let CreatePublicationPlan { name, relations } = plan;
+        let commit_ts = self.get_catalog_write_ts().await;
+        self.catalog()
+            .create_publication(&name, &relations, commit_ts)
+            .await?;
+        Ok(ExecuteResponse::CreatedPublication)
+    }
+
+    pub(super) async fn sequence_drop_publication(
+        &mut self,
+        session: &Session,
+        plan: DropPublicationPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let DropPublicationPlan { name, if_exists } = plan;
+        if if_exists && self.catalog().publication(&name).await.is_err() {
+            session.add_notice(AdapterNotice::PublicationDoesNotExist { name });
+            return Ok(ExecuteResponse::DroppedPublication);
+        }
+        let commit_ts = self.get_catalog_write_ts().await;
+        self.catalog().drop_publication(&name, commit_ts).await?;
+        Ok(ExecuteResponse::DroppedPublication)
+    }
+
+    pub(super) async fn sequence_identify_system(
+        &mut self,
+        session: &Session,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        require_replication_connection(session)?;
+        let environment_id = self.catalog().config().environment_id.to_string();
+        let xlogpos = format_lsn(self.get_local_read_ts().await);
+        let row = Row::pack_slice(&[
+            Datum::String(&environment_id),
+            // Materialize has a single timeline.
+            Datum::Int32(1),
+            Datum::String(&xlogpos),
+            Datum::String(session.vars().database()),
+        ]);
+        Ok(Self::send_immediate_rows(row))
+    }
+
+    pub(super) async fn sequence_create_replication_slot(
+        &mut self,
+        session: &Session,
+        plan: CreateReplicationSlotPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        require_replication_connection(session)?;
+        let CreateReplicationSlotPlan { name } = plan;
+        // Changes are streamed from the point at which the slot is created.
+        let confirmed_flush = self.get_local_read_ts().await;
+        let commit_ts = self.get_catalog_write_ts().await;
+        self.catalog()
+            .create_replication_slot(
+                &name,
+                ReplicationSlot {
+                    confirmed_flush,
+                    relations: Vec::new(),
+                },
+                commit_ts,
+            )
+            .await?;
+        let consistent_point = format_lsn(confirmed_flush);
+        let row = Row::pack_slice(&[
+            Datum::String(&name),
+            Datum::String(&consistent_point),
+            Datum::Null,
+            Datum::String(PGOUTPUT),
+        ]);
+        Ok(Self::send_immediate_rows(row))
+    }
+
+    pub(super) async fn sequence_drop_replication_slot(
+        &mut self,
+        session: &Session,
+        plan: DropReplicationSlotPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        require_replication_connection(session)?;
+        let DropReplicationSlotPlan { name } = plan;
+        if self.replication_streams.values().any(|s| s.slot == name) {
+            return Err(AdapterError::ReplicationSlotActive(name));
+        }
+        let commit_ts = self.get_catalog_write_ts().await;
+        self.catalog()
+            .drop_replication_slot(&name, commit_ts)
+            .await?;
+        self.replication_slot_holds.remove(&name);
+        Ok(ExecuteResponse::DroppedReplicationSlot)
+    }
+
+    /// Starts streaming the changes to the relations of the requested
+    /// publications by installing a subscribe for each relation.
+    pub(super) async fn sequence_start_replication(
+        &mut self,
+        session: &Session,
+        plan: StartReplicationPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        require_replication_connection(session)?;
+        let StartReplicationPlan {
+            slot,
+            start,
+            publications,
+        } = plan;
+
+        // A connection streams at most once at a time, so a stream it left
+        // behind has been ended by the client.
+        self.end_replication_stream(session.conn_id()).await;
+        let slot_state = self.catalog().replication_slot(&slot).await?;
+        if self.replication_streams.values().any(|s| s.slot == slot) {
+            return Err(AdapterError::ReplicationSlotActive(slot));
+        }
+        // Changes the consumer already confirmed are never sent again.
+        let start = std::cmp::max(start, slot_state.confirmed_flush);
+
+        let mut item_ids = Vec::new();
+        for publication in &publications {
+            for id in self.catalog().publication(publication).await? {
+                if !item_ids.contains(&id) {
+                    item_ids.push(id);
+                }
+            }
+        }
+        // Row-level security may have been enabled on a relation after it was
+        // published, so check again before streaming its changes.
+        let conn_catalog = self.catalog().for_session(session);
+        let role_id = session.current_role_id();
+        for item_id in &item_ids {
+            let entry = self.catalog().get_entry(item_id);
+            if entry.item().row_level_security()
+                && !bypasses_row_level_security(&conn_catalog, role_id, entry.owner_id())
+            {
+                return Err(AdapterError::PlanError(
+                    PlanError::RowLevelSecurityBypassRequired {
+                        name: conn_catalog.resolve_full_name(entry.name()).to_string(),
+                        role: conn_catalog.get_role(role_id).name().to_string(),
+                        operation: "replicate",
+                    },
+                ));
+            }
+        }
+
+        let cluster = self
+            .catalog()
+            .resolve_target_cluster(TargetCluster::Active, session)?;
+        if cluster.replicas().next().is_none() {
+            return Err(AdapterError::NoClusterReplicasAvailable {
+                name: cluster.name.clone(),
+                is_managed: cluster.is_managed(),
+            });
+        }
+        let cluster_id = cluster.id;
+        let conn_id = session.conn_id().clone();
+
+        // Plan all subscribes before installing any of them, so that a failure
+        // leaves nothing behind.
+        let as_of = Antichain::from_elem(start);
+        let mut published = Vec::with_capacity(item_ids.len());
+        let mut relations = Vec::with_capacity(item_ids.len());
+        let mut dataflows = Vec::with_capacity(item_ids.len());
+        let mut read_holds = Vec::with_capacity(item_ids.len());
+        for (index, item_id) in item_ids.iter().copied().enumerate() {
+            let entry = self.catalog().get_entry(&item_id);
+            let relation_id = entry.latest_global_id();
+            let full_name = self
+                .catalog()
+                .resolve_full_name(entry.name(), Some(&conn_id));
+            let desc = entry
+                .relation_desc_latest()
+                .expect("publications only include relations")
+                .into_owned();
+            published.push(PublishedRelation {
+                oid: entry.oid(),
+                schema: full_name.schema.clone(),
+                name: full_name.item.clone(),
+                desc,
+            });
+            relations.push((relation_id, full_name.to_string()));
+
+            let (_, sink_id) = self.allocate_transient_id();
+            let (_, view_id) = self.allocate_transient_id();
+            let compute_instance = self
+                .instance_snapshot(cluster_id)
+                .expect("compute instance does not exist");
+            let vars = self.catalog().system_config();
+            let overrides = self.catalog().get_cluster(cluster_id).config.features();
+            let optimizer_config = optimize::OptimizerConfig::from(vars).override_from(&overrides);
+
+            let mut optimizer = optimize::subscribe::Optimizer::new(
+                self.owned_catalog(),
+                compute_instance,
+                view_id,
+                sink_id,
+                false,
+                None,
+                format!("replication-{sink_id}"),
+                optimizer_config,
+                self.optimizer_metrics(),
+            );
+            let global_mir_plan =
+                optimizer.catch_unwind_optimize(SubscribeFrom::Id(relation_id))?;
+
+            let id_bundle = global_mir_plan.id_bundle(cluster_id);
+            let holds = self.acquire_read_holds(&id_bundle);
+            let since = holds.least_valid_read();
+            if !since.less_equal(&start) {
+                return Err(AdapterError::ReplicationSlotCompacted {
+                    slot,
+                    confirmed_flush: start,
+                    since: since.into_option().unwrap_or(Timestamp::MAX),
+                });
+            }
+
+            let global_mir_plan = global_mir_plan.resolve(as_of.clone());
+            let global_lir_plan = optimizer.catch_unwind_optimize(global_mir_plan)?;
+            dataflows.push((sink_id, index, global_lir_plan));
+            read_holds.push(holds);
+        }
+
+        // Hold back compaction of the streamed relations for as long as the
+        // slot exists, not just while this stream runs.
+        let commit_ts = self.get_catalog_write_ts().await;
+        self.catalog()
+            .set_replication_slot_relations(&slot, &item_ids, commit_ts)
+            .await?;
+        let relation_ids: Vec<_> = relations.iter().map(|(id, _)| *id).collect();
+        self.hold_replication_slot(slot.clone(), relation_ids, slot_state.confirmed_flush);
+
+        let (events_tx, events_rx) = mpsc::unbounded_channel();
+        info!(%conn_id, %slot, %start, "starting replication stream");
+        self.replication_streams.insert(
+            conn_id,
+            ReplicationStream {
+                slot,
+                cluster_id,
+                relations,
+                subscribes: dataflows
+                    .iter()
+                    .map(|(sink_id, index, _)| (*sink_id, (*index, as_of.clone())))
+                    .collect(),
+                pending: Vec::new(),
+                frontier: start.step_forward(),
+                confirmed_flush: slot_state.confirmed_flush,
+                persisted_flush: slot_state.confirmed_flush,
+                persisted_at: Instant::now(),
+                events_tx,
+            },
+        );
+        for (_, _, global_lir_plan) in dataflows {
+            let (df_desc, _df_meta) = global_lir_plan.unapply();
+            self.ship_dataflow(df_desc, cluster_id, None).await;
+        }
+        drop(read_holds);
+
+        Ok(ExecuteResponse::StartedReplication {
+            relations: published,
+            start,
+            rx: events_rx,
+        })
+    }
+
+    /// Advances the confirmed flush position of the slot that the connection
+    /// is streaming from. Positions beyond the changes handed to the
+    /// connection are clamped, so that changes are never skipped.
+    ///
+    /// The position is made durable at most every
+    /// [`REPLICATION_SLOT_PERSIST_INTERVAL`].
+    pub(super) async fn advance_replication_slot(
+        &mut self,
+        conn_id: &ConnectionId,
+        confirmed_flush: Timestamp,
+    ) -> Result<(), AdapterError> {
+        let Some(stream) = self.replication_streams.get_mut(conn_id) else {
+            return Err(AdapterError::Internal(
+                "connection has no active replication stream".into(),
+            ));
+        };
+        let sent = stream.frontier.step_back().unwrap_or(Timestamp::MIN);
+        let confirmed_flush = std::cmp::min(confirmed_flush, sent);
+        stream.confirmed_flush = std::cmp::max(stream.confirmed_flush, confirmed_flush);
+        if stream.persisted_at.elapsed() < REPLICATION_SLOT_PERSIST_INTERVAL {
+            return Ok(());
+        }
+        self.persist_replication_slot(conn_id).await
+    }
+
+    /// Durably records the latest confirmed flush position of the slot that
+    /// the connection is streaming from, and downgrades the slot's read holds
+    /// to it.
+    async fn persist_replication_slot(
+        &mut self,
+        conn_id: &ConnectionId,
+    ) -> Result<(), AdapterError> {
+        let Some(stream) = self.replication_streams.get_mut(conn_id) else {
+            return Ok(());
+        };
+        stream.persisted_at = Instant::now();
+        if stream.confirmed_flush <= stream.persisted_flush {
+            return Ok(());
+        }
+        let slot = stream.slot.clone();
+        let confirmed_flush = stream.confirmed_flush;
+        let commit_ts = self.get_catalog_write_ts().await;
+        self.catalog()
+            .advance_replication_slot(&slot, confirmed_flush, commit_ts)
+            .await?;
+        if let Some(stream) = self.replication_streams.get_mut(conn_id) {
+            stream.persisted_flush = std::cmp::max(stream.persisted_flush, confirmed_flush);
+        }
+        if let Some(holds) = self.replication_slot_holds.get_mut(&slot) {
+            holds.downgrade(confirmed_flush);
+        }
+        Ok(())
+    }
+
+    /// Makes the latest confirmed flush position of the connection's
+    /// replication stream durable, if any, and drops the stream.
+    pub(super) async fn end_replication_stream(&mut self, conn_id: &ConnectionId) {
+        if let Err(err) = self.persist_replication_slot(conn_id).await {
+            warn!(%conn_id, "failed to persist replication slot position: {err}");
+        }
+        self.drop_replication_stream(conn_id);
+    }
+
+    /// Acquires read holds on the relations of every replication slot at the
+    /// slot's confirmed flush position, so that consumers can resume streaming
+    /// after a restart.
+    ///
+    /// Must be called after the storage collections have been bootstrapped.
+    pub(super) async fn bootstrap_replication_slots(&mut self) -> Result<(), AdapterError> {
+        for (name, slot) in self.catalog().replication_slots().await? {
+            // Relations that have since been dropped need no holds.
+            let relation_ids: Vec<_> = slot
+                .relations
+                .iter()
+                .filter_map(|id| self.catalog().try_get_entry(id))
+                .map(|entry| entry.latest_global_id())
+                .collect();
+            self.hold_replication_slot(name, relation_ids, slot.confirmed_flush);
+        }
+        Ok(())
+    }
+
+    /// Replaces the read holds of the replication slot `slot` with holds on
+    /// `relations` at `confirmed_flush`, or as close to it as is still
+    /// possible.
+    fn hold_replication_slot(
+        &mut self,
+        slot: String,
+        relations: impl IntoIterator<Item = GlobalId>,
+        confirmed_flush: Timestamp,
+    ) {
+        let id_bundle = CollectionIdBundle {
+            storage_ids: relations.into_iter().collect(),
+            compute_ids: BTreeMap::new(),
+        };
+        let mut holds = self.acquire_read_holds(&id_bundle);
+        holds.downgrade(confirmed_flush);
+        self.replication_slot_holds.insert(slot, holds);
+    }
+
+    /// Releases the read holds that replication slots have on the given
+    /// collections, which are being dropped.
+    pub(super) fn release_replication_slot_holds(&mut self, ids: &BTreeSet<GlobalId>) {
+        for holds in self.replication_slot_holds.values_mut() {
+            for id in ids {
+                holds.remove_storage_collection(*id);
+            }
+        }
+    }
+
+    /// Returns the connection whose replication stream owns the subscribe
+    /// `sink_id`, if any.
+    pub(super) fn replication_stream_of(&self, sink_id: GlobalId) -> Option<ConnectionId> {
+        self.replication_streams
+            .iter()
+            .find(|(_, s)| s.subscribes.contains_key(&sink_id))
+            .map(|(conn_id, _)| conn_id.clone())
+    }
+
+    /// Returns the connections whose replication streams read from one of
+    /// `relations` or run on one of `clusters`, along with the error to report
+    /// to each of them.
+    pub(super) fn replication_streams_depending_on(
+        &self,
+        relations: &BTreeSet<GlobalId>,
+        clusters: &[ClusterId],
+    ) -> Vec<(ConnectionId, AdapterError)> {
+        let mut streams = Vec::new();
+        for (conn_id, stream) in &self.replication_streams {
+            let error = if clusters.contains(&stream.cluster_id) {
+                AdapterError::ConcurrentClusterDrop
+            } else if let Some((_, name)) = stream
+                .relations
+                .iter()
+                .find(|(id, _)| relations.contains(id))
+            {
+                AdapterError::ConcurrentDependencyDrop {
+                    dependency_kind: "relation",
+                    dependency_id: name.clone(),
+                }
+            } else {
+                continue;
+            };
+            streams.push((conn_id.clone(), error));
+        }
+        streams
+    }
+
+    /// Reports `error` to the connection and drops its replication stream.
+    pub(super) fn fail_replication_stream(&mut self, conn_id: &ConnectionId, error: AdapterError) {
+        if let Some(stream) = self.replication_streams.get(conn_id) {
+            let _ = stream.events_tx.send(ReplicationEvent::Error(error));
+        }
+        self.drop_replication_stream(conn_id);
+    }
+
+    pub(super) fn drop_replication_stream(&mut self, conn_id: &ConnectionId) {
+        let Some(stream) = self.replication_streams.remove(conn_id) else {
+            return;
+        };
+
+        info!(%conn_id, slot = %stream.slot, "dropping replication stream");
+
+        // The compute collections are already gone if the cluster was dropped.
+        let _ = self
+            .controller
+            .compute
+            .drop_collections(stream.cluster_id, stream.subscribes.into_keys().collect());
+    }
+
+    /// Processes a batch returned by a subscribe of a replication stream.
+    ///
+    /// Updates are buffered until every subscribe of the stream has advanced
+    /// past their timestamp, at which point each timestamp's updates are sent
+    /// as one transaction, in timestamp order.
+    pub(super) fn handle_replication_subscribe_batch(
+        &mut self,
+        conn_id: ConnectionId,
+        sink_id: GlobalId,
+        batch: SubscribeBatch,
+    ) {
+        let Some(stream) = self.replication_streams.get_mut(&conn_id) else {
+            return;
+        };
+
+        let updates = match batch.updates {
+            Ok(updates) => updates,
+            Err(error) => {
+                let error = AdapterError::Unstructured(anyhow!(error));
+                self.fail_replication_stream(&conn_id, error);
+                return;
+            }
+        };
+        let (index, upper) = stream
+            .subscribes
+            .get_mut(&sink_id)
+            .expect("subscribe belongs to stream");
+        let index = *index;
+        *upper = batch.upper;
+        stream.pending.extend(
+            updates
+                .into_iter()
+                .map(|(time, row, diff)| (time, index, row, diff)),
+        );
+
+        // The changes before the least upper of all subscribes are complete.
+        let mut frontier = Antichain::new();
+        for (_, upper) in stream.subscribes.values() {
+            frontier.extend(upper.iter().copied());
+        }
+        let Some(frontier) = frontier.into_option() else {
+            // All published relations are closed for writes; hand off what is
+            // left, which can no longer change.
+            let pending = std::mem::take(&mut stream.pending);
+            if Self::send_replication_transactions(stream, pending) {
+                self.drop_replication_stream(&conn_id);
+            }
+            return;
+        };
+        if frontier <= stream.frontier {
+            return;
+        }
+
+        let (complete, pending): (Vec<_>, Vec<_>) = std::mem::take(&mut stream.pending)
+            .into_iter()
+            .partition(|(time, ..)| *time < frontier);
+        stream.pending = pending;
+        stream.frontier = frontier;
+        let mut disconnected = Self::send_replication_transactions(stream, complete);
+        if !disconnected {
+            disconnected = stream
+                .events_tx
+                .send(ReplicationEvent::Progress(frontier))
+                .is_err();
+        }
+        if disconnected {
+            self.drop_replication_stream(&conn_id);
+        }
+    }
+
+    /// Sends `updates` to the stream's connection as one transaction per
+    /// timestamp. Returns whether the connection went away.
+    fn send_replication_transactions(
+        stream: &ReplicationStream,
+        mut updates: Vec<(Timestamp, usize, Row, Diff)>,
+    ) -> bool {
+        updates.sort_by_key(|(time, ..)| *time);
+        let mut transactions: Vec<(Timestamp, Vec<_>)> = Vec::new();
+        for (time, index, row, diff) in updates {
+            match transactions.last_mut() {
+                Some((t, updates)) if *t == time => updates.push((index, row, diff)),
+                _ => transactions.push((time, vec![(index, row, diff)])),
+            }
+        }
+        transactions.into_iter().any(|(time, updates)| {
+            stream
+                .events_tx
+                .send(ReplicationEvent::Transaction { time, updates })
+                .is_err()
+        })
+    }
+}
@@ -296,6 +296,22 @@ impl Coordinator {
                         .await;
                     ctx.retire(result);
                 }
+                Plan::CreateFunction(plan) => {
+                    let result = self
+                        .sequence_create_function(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::CreateSequence(plan) => {
+                    let result = self.sequence_create_sequence(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
+                Plan::CreatePolicy(plan) => {
+                    let result = self
+                        .sequence_create_policy(ctx.session(), plan, resolved_ids)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::CreateNetworkPolicy(plan) => {
                     let res = self
                         .sequence_create_network_policy(ctx.session(), plan)
@@ -463,6 +479,9 @@ impl Coordinator {
                 Plan::ReadThenWrite(plan) => {
                     self.sequence_read_then_write(ctx, plan).await;
                 }
+                Plan::Truncate(plan) => {
+                    self.sequence_truncate(ctx, plan).await;
+                }
                 Plan::AlterNoop(plan) => {
                     ctx.retire(Ok(ExecuteResponse::AlteredObject(plan.object_type)));
                 }
@@ -500,6 +519,12 @@ impl Coordinator {
                         .await;
                     ctx.retire(result);
                 }
+                Plan::AlterRowLevelSecurity(plan) => {
+                    let result = self
+                        .sequence_alter_row_level_security(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::AlterItemRename(plan) => {
                     let result = self.sequence_alter_item_rename(&mut ctx, plan).await;
                     ctx.retire(result);
@@ -544,6 +569,16 @@ impl Coordinator {
                     let result = self.sequence_alter_table(&mut ctx, plan).await;
                     ctx.retire(result);
                 }
+                Plan::AlterTableDropColumn(plan) => {
+                    let result = self.sequence_alter_table_drop_column(&mut ctx, plan).await;
+                    ctx.retire(result);
+                }
+                Plan::AlterTableRenameColumn(plan) => {
+                    let result = self
+                        .sequence_alter_table_rename_column(&mut ctx, plan)
+                        .await;
+                    ctx.retire(result);
+                }
                 Plan::AlterMaterializedViewApplyReplacement(plan) => {
                     self.sequence_alter_materialized_view_apply_replacement_prepare(ctx, plan)
                         .await;
@@ -562,6 +597,7 @@ impl Coordinator {
                     let ret = if let TransactionStatus::Started(_) = ctx.session().transaction() {
                         self.clear_transaction(ctx.session_mut()).await;
                         self.drop_temp_items(ctx.session().conn_id()).await;
+                        self.drop_listen_subscribes(ctx.session().conn_id(), None);
                         ctx.session_mut().reset();
                         Ok(ExecuteResponse::DiscardedAll)
                     } else {
@@ -654,6 +690,42 @@ impl Coordinator {
                         .add_notice(AdapterNotice::UserRequested { severity });
                     ctx.retire(Ok(ExecuteResponse::Raised));
                 }
+                Plan::Listen(plan) => {
+                    let result = self.sequence_listen(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
+                Plan::Unlisten(plan) => {
+                    let result = self.sequence_unlisten(ctx.session(), plan);
+                    ctx.retire(result);
+                }
+                Plan::CreatePublication(plan) => {
+                    let result = self.sequence_create_publication(plan).await;
+                    ctx.retire(result);
+                }
+                Plan::DropPublication(plan) => {
+                    let result = self.sequence_drop_publication(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
+                Plan::IdentifySystem => {
+                    let result = self.sequence_identify_system(ctx.session()).await;
+                    ctx.retire(result);
+                }
+                Plan::CreateReplicationSlot(plan) => {
+                    let result = self
+                        .sequence_create_replication_slot(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::DropReplicationSlot(plan) => {
+                    let result = self
+                        .sequence_drop_replication_slot(ctx.session(), plan)
+                        .await;
+                    ctx.retire(result);
+                }
+                Plan::StartReplication(plan) => {
+                    let result = self.sequence_start_replication(ctx.session(), plan).await;
+                    ctx.retire(result);
+                }
                 Plan::GrantPrivileges(plan) => {
                     let result = self
                         .sequence_grant_privileges(ctx.session_mut(), plan)
@@ -23,7 +23,8 @@ use mz_adapter_types::connection::ConnectionId;
 use mz_adapter_types::dyncfgs::{ENABLE_MULTI_REPLICA_SOURCES, ENABLE_PASSWORD_AUTH};
 use mz_catalog::memory::error::ErrorKind;
 use mz_catalog::memory::objects::{
-    CatalogItem, Connection, DataSourceDesc, Sink, Source, Table, TableDataSource, Type,
+    CatalogItem, Connection, DataSourceDesc, Func, FuncInner, Policy, Sequence, Sink, Source,
+    Table, TableDataSource, Type,
 };
 use mz_expr::{
     CollectionPlan, MapFilterProject, OptimizedMirRelationExpr, ResultSpec, RowSetFinishing,
@@ -40,7 +41,7 @@ use mz_repr::explain::json::json_string;
 use mz_repr::role_id::RoleId;
 use mz_repr::{
     CatalogItemId, Datum, Diff, GlobalId, RelationVersion, RelationVersionSelector, Row, RowArena,
-    RowIterator, Timestamp,
+    RowIterator, SqlScalarType, Timestamp,
 };
 use mz_sql::ast::{
     AlterSourceAddSubsourceOption, CreateSinkOption, CreateSinkOptionName, CreateSourceOptionName,
@@ -59,16 +60,16 @@ use mz_sql::names::{
 };
 use mz_sql::plan::{
     AlterMaterializedViewApplyReplacementPlan, ConnectionDetails, NetworkPolicyRule,
-    StatementContext,
+    StatementContext, bypasses_row_level_security,
 };
 use mz_sql::pure::{PurifiedSourceExport, generate_subsource_statements};
 use mz_storage_types::sinks::StorageSinkDesc;
-use mz_storage_types::sources::GenericSourceConnection;
+use mz_storage_types::sources::{GenericSourceConnection, SourceData};
 // Import `plan` module, but only import select elements to avoid merge conflicts on use statements.
 use mz_sql::plan::{
     AlterConnectionAction, AlterConnectionPlan, CreateSourcePlanBundle, ExplainSinkSchemaPlan,
     Explainee, ExplaineeStatement, MutationKind, Params, Plan, PlannedAlterRoleOption,
-    PlannedRoleVariable, QueryWhen, SideEffectingFunc, UpdatePrivilege, VariableValue,
+    PlannedRoleVariable, QueryWhen, SequenceId, SideEffectingFunc, UpdatePrivilege, VariableValue,
 };
 use mz_sql::session::metadata::SessionMetadata;
 use mz_sql::session::user::UserKind;
@@ -84,14 +85,15 @@ use mz_sql_parser::ast::{
     WithOptionValue,
 };
 use mz_ssh_util::keys::SshKeyPairSet;
+use mz_storage_client::client::TableData;
 use mz_storage_client::controller::ExportDescription;
 use mz_storage_types::AlterCompatible;
 use mz_storage_types::connections::inline::IntoInlineConnection;
 use mz_storage_types::controller::StorageError;
 use mz_transform::dataflow::DataflowMetainfo;
-use smallvec::SmallVec;
+use smallvec::{SmallVec, smallvec};
 use timely::progress::Antichain;
-use tokio::sync::{oneshot, watch};
+use tokio::sync::{mpsc, oneshot, watch};
 use tracing::{Instrument, Span, info, warn};
 
 use crate::catalog::{self, Catalog, ConnCatalog, DropObjectInfo, UpdatePrivilegeVariant};
@@ -157,6 +159,66 @@ struct CreateSourceInner {
     if_not_exists_ids: BTreeMap<CatalogItemId, QualifiedItemName>,
 }
 
+/// Replaces the `NULL` values of the identity `columns` of the rows inserted
+/// by `diffs` with values allocated from the columns' sequences. Rows inserted
+/// more than once are split so that each copy receives its own values.
+async fn fill_identity_columns(
+    table: CatalogItemId,
+    columns: &[(usize, SqlScalarType)],
+    diffs: Vec<(Row, Diff)>,
+    internal_cmd_tx: &mpsc::UnboundedSender<Message>,
+) -> Result<Vec<(Row, Diff)>, AdapterError> {
+    let mut values = Vec::with_capacity(columns.len());
+    for (column, _typ) in columns {
+        let amount: i64 = diffs
+            .iter()
+            .filter(|(row, _diff)| row.iter().nth(*column).expect("valid column").is_null())
+            .map(|(_row, diff)| diff.into_inner())
+            .sum();
+        let amount = u64::try_from(amount).expect("inserts only add rows");
+        if amount == 0 {
+            values.push(Vec::new().into_iter());
+            continue;
+        }
+        let (tx, rx) = oneshot::channel();
+        internal_cmd_tx
+            .send(Message::AllocateSequenceValues {
+                id: SequenceId::Identity {
+                    table,
+                    column: *column,
+                },
+                amount,
+                tx,
+            })
+            .map_err(|_| AdapterError::Internal("coordinator went away".into()))?;
+        let column_values = rx
+            .await
+            .map_err(|_| AdapterError::Internal("coordinator went away".into()))??;
+        values.push(column_values.into_iter());
+    }
+
+    let mut filled = Vec::with_capacity(diffs.len());
+    for (row, diff) in diffs {
+        for _ in 0..diff.into_inner() {
+            let mut datums: Vec<_> = row.iter().collect();
+            for ((column, typ), values) in columns.iter().zip_eq(values.iter_mut()) {
+                if !datums[*column].is_null() {
+                    continue;
+                }
+                let value = values.next().expect("allocated enough values");
+                // The sequence's bounds were derived from the column's type.
+                datums[*column] = match typ {
+                    SqlScalarType::Int16 => Datum::Int16(value.try_into().expect("in bounds")),
+                    SqlScalarType::Int32 => Datum::Int32(value.try_into().expect("in bounds")),
+                    _ => Datum::Int64(value),
+                };
+            }
+            filled.push((Row::pack_slice(&datums), Diff::ONE));
+        }
+    }
+    Ok(filled)
+}
+
 impl Coordinator {
     /// Sequences the next staged of a [Staged] plan. This is designed for use with plans that
     /// execute both on and off of the coordinator thread. Stages can either produce another stage
@@ -943,9 +1005,13 @@ impl Coordinator {
         let collections = [(RelationVersion::root(), global_id)].into_iter().collect();
 
         let data_source = match table.data_source {
-            plan::TableDataSource::TableWrites { defaults } => {
-                TableDataSource::TableWrites { defaults }
-            }
+            plan::TableDataSource::TableWrites {
+                defaults,
+                identity_columns,
+            } => TableDataSource::TableWrites {
+                defaults,
+                identity_columns,
+            },
             plan::TableDataSource::DataSource {
                 desc: data_source_plan,
                 timeline,
@@ -1182,6 +1248,99 @@ impl Coordinator {
         }
     }
 
+    #[instrument]
+    pub(super) async fn sequence_create_function(
+        &mut self,
+        session: &Session,
+        plan: plan::CreateFunctionPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let (item_id, global_id) = self.allocate_user_id().await?;
+        let func = Func {
+            create_sql: Some(plan.function.create_sql),
+            inner: FuncInner::Sql(plan.function.details),
+            global_id,
+            resolved_ids,
+        };
+        let ops = vec![
+            catalog::Op::DropObjects(
+                plan.drop_ids
+                    .into_iter()
+                    .map(catalog::DropObjectInfo::Item)
+                    .collect(),
+            ),
+            catalog::Op::CreateItem {
+                id: item_id,
+                name: plan.name,
+                item: CatalogItem::Func(func),
+                owner_id: *session.current_role_id(),
+            },
+        ];
+        match self.catalog_transact(Some(session), ops).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedFunction),
+            Err(err) => Err(err),
+        }
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_create_sequence(
+        &mut self,
+        session: &Session,
+        plan: plan::CreateSequencePlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let (item_id, global_id) = self.allocate_user_id().await?;
+        let sequence = Sequence {
+            create_sql: plan.sequence.create_sql,
+            global_id,
+            options: plan.sequence.options,
+        };
+        let op = catalog::Op::CreateItem {
+            id: item_id,
+            name: plan.name.clone(),
+            item: CatalogItem::Sequence(sequence),
+            owner_id: *session.current_role_id(),
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedSequence),
+            Err(AdapterError::Catalog(mz_catalog::memory::error::Error {
+                kind: ErrorKind::Sql(CatalogError::ItemAlreadyExists(_, _)),
+            })) if plan.if_not_exists => {
+                session.add_notice(AdapterNotice::ObjectAlreadyExists {
+                    name: plan.name.item,
+                    ty: "sequence",
+                });
+                Ok(ExecuteResponse::CreatedSequence)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_create_policy(
+        &mut self,
+        session: &Session,
+        plan: plan::CreatePolicyPlan,
+        resolved_ids: ResolvedIds,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let (item_id, global_id) = self.allocate_user_id().await?;
+        let policy = Policy {
+            create_sql: plan.policy.create_sql,
+            global_id,
+            details: plan.policy.details,
+            resolved_ids,
+        };
+        let op = catalog::Op::CreateItem {
+            id: item_id,
+            name: plan.name,
+            item: CatalogItem::Policy(policy),
+            owner_id: *session.current_role_id(),
+        };
+        match self.catalog_transact(Some(session), vec![op]).await {
+            Ok(()) => Ok(ExecuteResponse::CreatedPolicy),
+            Err(err) => Err(err),
+        }
+    }
+
     #[instrument]
     pub(super) async fn sequence_comment_on(
         &mut self,
@@ -2207,9 +2366,66 @@ impl Coordinator {
                 };
                 ctx.retire(Ok(Self::send_immediate_rows(Row::pack_slice(&[res]))));
             }
+            plan @ (SideEffectingFunc::NextVal { .. }
+            | SideEffectingFunc::CurrVal { .. }
+            | SideEffectingFunc::SetVal { .. }) => {
+                let result = self.execute_sequence_func(plan).await;
+                ctx.retire(result);
+            }
         }
     }
 
+    /// Durably allocates the next `amount` values of the sequence identified
+    /// by `id`.
+    pub(crate) async fn allocate_sequence_values(
+        &mut self,
+        id: SequenceId,
+        amount: u64,
+    ) -> Result<Vec<i64>, AdapterError> {
+        let commit_ts = self.get_catalog_write_ts().await;
+        let values = self
+            .catalog()
+            .allocate_sequence_values(id, amount, commit_ts)
+            .await?;
+        Ok(values)
+    }
+
+    /// Executes `nextval`, `currval`, or `setval`. Privileges must already
+    /// have been checked.
+    async fn execute_sequence_func(
+        &mut self,
+        plan: SideEffectingFunc,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let value = match plan {
+            SideEffectingFunc::NextVal { id } => self
+                .allocate_sequence_values(SequenceId::Sequence(id), 1)
+                .await?
+                .into_element(),
+            SideEffectingFunc::CurrVal { id } => {
+                self.catalog()
+                    .current_sequence_value(SequenceId::Sequence(id))
+                    .await?
+            }
+            SideEffectingFunc::SetVal {
+                id,
+                value,
+                is_called,
+            } => {
+                let commit_ts = self.get_catalog_write_ts().await;
+                self.catalog()
+                    .set_sequence_value(SequenceId::Sequence(id), value, is_called, commit_ts)
+                    .await?;
+                value
+            }
+            SideEffectingFunc::PgCancelBackend { .. } => {
+                unreachable!("not a sequence function")
+            }
+        };
+        Ok(Self::send_immediate_rows(Row::pack_slice(&[Datum::Int64(
+            value,
+        )])))
+    }
+
     /// Execute a side-effecting function from the frontend peek path.
     /// This is separate from `sequence_side_effecting_func` because
     /// - It doesn't have an ExecuteContext.
@@ -2269,6 +2485,10 @@ impl Coordinator {
                     Ok(Self::send_immediate_rows(Row::pack_slice(&[Datum::False])))
                 }
             }
+            // Privileges were checked by the frontend.
+            plan @ (SideEffectingFunc::NextVal { .. }
+            | SideEffectingFunc::CurrVal { .. }
+            | SideEffectingFunc::SetVal { .. }) => self.execute_sequence_func(plan).await,
         }
     }
 
@@ -2523,7 +2743,12 @@ impl Coordinator {
         };
 
         match optimized_mir.into_inner() {
-            selection if selection.as_const().is_some() && plan.returning.is_empty() => {
+            // Identity columns are filled in by the read-then-write path.
+            selection
+                if selection.as_const().is_some()
+                    && plan.returning.is_empty()
+                    && plan.identity_columns.is_empty() =>
+            {
                 let catalog = self.owned_catalog();
                 mz_ore::task::spawn(|| "coord::sequence_inner", async move {
                     let result =
@@ -2565,6 +2790,7 @@ impl Coordinator {
                     assignments: BTreeMap::new(),
                     kind: MutationKind::Insert,
                     returning: plan.returning,
+                    identity_columns: plan.identity_columns,
                 };
 
                 self.sequence_read_then_write(ctx, read_then_write_plan)
@@ -2638,6 +2864,7 @@ impl Coordinator {
             mut assignments,
             finishing,
             mut returning,
+            identity_columns,
         } = plan;
 
         // Read then writes can be queued, so re-verify the id exists.
@@ -2704,7 +2931,7 @@ impl Coordinator {
                             let valid_id = id.is_user() || matches!(typ, Func);
                             valid_id
                         }
-                        Source | Secret | Connection => false,
+                        Source | Secret | Connection | Sequence | Policy => false,
                         // Cannot select from sinks or indexes.
                         Sink | Index => unreachable!(),
                         Table => {
@@ -2846,6 +3073,12 @@ impl Coordinator {
                 return_if_err!(style.prep_scalar_expr(expr), ctx);
             }
 
+            let identity_positions: BTreeSet<usize> =
+                identity_columns.iter().map(|c| c.column).collect();
+            let identity_columns: Vec<_> = identity_columns
+                .iter()
+                .map(|c| (c.column, desc.get_type(c.column).scalar_type.clone()))
+                .collect();
             let make_diffs = move |mut rows: Box<dyn RowIterator>|
                   -> Result<(Vec<(Row, Diff)>, u64), AdapterError> {
                     let arena = RowArena::new();
@@ -2891,6 +3124,10 @@ impl Coordinator {
                         byte_size = byte_size.saturating_add(u64::cast_from(row.byte_len()));
                         if diff.is_positive() {
                             for (idx, datum) in row.iter().enumerate() {
+                                // Missing identity values are generated below.
+                                if datum.is_null() && identity_positions.contains(&idx) {
+                                    continue;
+                                }
                                 desc.constraints_met(idx, &datum)?;
                             }
                         }
@@ -2954,6 +3191,13 @@ impl Coordinator {
                 ))),
             };
 
+            let diffs = match diffs {
+                Ok(diffs) if !identity_columns.is_empty() => {
+                    fill_identity_columns(id, &identity_columns, diffs, &internal_cmd_tx).await
+                }
+                diffs => diffs,
+            };
+
             let mut returning_rows = Vec::new();
             let mut diff_err: Option<AdapterError> = None;
             if let (false, Ok(diffs)) = (returning.is_empty(), &diffs) {
@@ -3066,6 +3310,140 @@ impl Coordinator {
         });
     }
 
+    /// Truncates the tables in `plan` by retracting their entire contents.
+    ///
+    /// Rather than planning a `DELETE` (which reads the table through a peek
+    /// and filters it), this reads a snapshot of each table directly from
+    /// persist and commits its negation as a single write.
+    #[instrument]
+    pub(super) async fn sequence_truncate(
+        &mut self,
+        mut ctx: ExecuteContext,
+        plan: plan::TruncatePlan,
+    ) {
+        let ids: BTreeSet<_> = plan.ids.iter().copied().collect();
+
+        // If the transaction doesn't already have write locks, acquire them.
+        if ctx.session().transaction().write_locks().is_none() {
+            let mut write_locks = WriteLocks::builder(ids.iter().copied());
+            for id in &ids {
+                if let Some(lock) = self.try_grant_object_write_lock(*id) {
+                    write_locks.insert_lock(*id, lock);
+                }
+            }
+
+            let write_locks = match write_locks.all_or_nothing(ctx.session().conn_id()) {
+                Ok(locks) => locks,
+                Err(missing) => {
+                    // Defer our write if we couldn't acquire all of the locks.
+                    let role_metadata = ctx.session().role_metadata().clone();
+                    let acquire_future = self.grant_object_write_lock(missing).map(Option::Some);
+                    let plan = DeferredPlan {
+                        ctx,
+                        plan: Plan::Truncate(plan),
+                        validity: PlanValidity::new(
+                            self.catalog.transient_revision(),
+                            ids.clone(),
+                            None,
+                            None,
+                            role_metadata,
+                        ),
+                        requires_locks: ids,
+                    };
+                    return self.defer_op(acquire_future, DeferredOp::Plan(plan));
+                }
+            };
+
+            ctx.session_mut()
+                .try_grant_write_locks(write_locks)
+                .expect("session has already been granted write locks");
+        }
+
+        // Rows written earlier in this transaction are not part of the snapshot
+        // yet, so they have to be retracted as well.
+        let mut pending_retractions: BTreeMap<CatalogItemId, Vec<(Row, Diff)>> = BTreeMap::new();
+        if let Some(TransactionOps::Writes(writes)) =
+            ctx.session().transaction().inner().map(|txn| &txn.ops)
+        {
+            for WriteOp { id, rows } in writes.iter().filter(|op| ids.contains(&op.id)) {
+                match rows {
+                    TableData::Rows(rows) => pending_retractions
+                        .entry(*id)
+                        .or_default()
+                        .extend(rows.iter().map(|(row, diff)| (row.clone(), -*diff))),
+                    TableData::Batches(_) => {
+                        ctx.retire(Err(AdapterError::Unsupported(
+                            "TRUNCATEs of tables copied into or truncated in the same transaction",
+                        )));
+                        return;
+                    }
+                }
+            }
+        }
+
+        // Truncates can be queued, so re-verify the tables exist. Holding the
+        // write locks ensures that nothing else writes to the tables between the
+        // snapshot and our retractions.
+        let read_ts = self.get_local_read_ts().await;
+        let mut retractions = Vec::with_capacity(plan.ids.len());
+        for id in plan.ids {
+            let Some(entry) = self.catalog().try_get_entry(&id) else {
+                ctx.retire(Err(AdapterError::Catalog(
+                    mz_catalog::memory::error::Error {
+                        kind: ErrorKind::Sql(CatalogError::UnknownItem(id.to_string())),
+                    },
+                )));
+                return;
+            };
+            let global_id = entry.latest_global_id();
+            let snapshot_fut = self
+                .controller
+                .storage_collections
+                .snapshot_cursor(global_id, read_ts);
+            let batch_fut = self
+                .controller
+                .storage_collections
+                .create_update_builder(global_id);
+            retractions.push((id, snapshot_fut, batch_fut));
+        }
+
+        // Stream the retractions of each table's contents into a Persist batch,
+        // so that neither the coordinator nor the transaction has to hold the
+        // contents in memory.
+        task::spawn(|| "sequence_truncate", async move {
+            let mut writes = Vec::with_capacity(retractions.len());
+            for (id, snapshot_fut, batch_fut) in retractions {
+                let mut batch = match batch_fut.await {
+                    Ok(batch) => batch,
+                    Err(e) => return ctx.retire(Err(e.into())),
+                };
+                let mut snapshot = match snapshot_fut.await {
+                    Ok(snapshot) => snapshot,
+                    Err(e) => return ctx.retire(Err(e.into())),
+                };
+                while let Some(updates) = snapshot.next().await {
+                    for (data, _ts, diff) in updates {
+                        batch.add(&data, &(), &-diff).await;
+                    }
+                }
+                for (row, diff) in pending_retractions.remove(&id).into_iter().flatten() {
+                    batch
+                        .add(&SourceData(Ok(row)), &(), &diff.into_inner())
+                        .await;
+                }
+                writes.push(WriteOp {
+                    id,
+                    rows: TableData::Batches(smallvec![batch.finish().await]),
+                });
+            }
+            let result = ctx
+                .session_mut()
+                .add_transaction_ops(TransactionOps::Writes(writes))
+                .map(|()| ExecuteResponse::Truncated);
+            ctx.retire(result);
+        });
+    }
+
     #[instrument]
     pub(super) async fn sequence_alter_item_rename(
         &mut self,
@@ -3112,6 +3490,8 @@ impl Coordinator {
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Sequence(_)
+                    | CatalogItem::Policy(_)
                     | CatalogItem::Connection(_) => unreachable!(),
                 };
                 match cluster {
@@ -3166,6 +3546,72 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(ObjectType::Source))
     }
 
+    #[instrument]
+    pub(super) async fn sequence_alter_row_level_security(
+        &mut self,
+        session: &Session,
+        plan: plan::AlterRowLevelSecurityPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let ops = vec![catalog::Op::AlterRowLevelSecurity {
+            id: plan.id,
+            enabled: plan.enabled,
+        }];
+        self.catalog_transact(Some(session), ops).await?;
+
+        // Listen subscribes and replication streams read the relation without
+        // applying its policies, so stop those of roles that no longer see all
+        // of its rows.
+        if plan.enabled {
+            let entry = self.catalog().get_entry(&plan.id);
+            let owner_id = *entry.owner_id();
+            let relations: BTreeSet<_> = entry.global_ids().collect();
+            let name = self
+                .catalog()
+                .resolve_full_name(entry.name(), None)
+                .to_string();
+            let restricted = |conn_id: &ConnectionId| -> Option<RoleId> {
+                let conn = self.active_conns.get(conn_id)?;
+                let role_id = *conn.authenticated_role_id();
+                let catalog = self.catalog().for_sessionless_user(role_id);
+                (!bypasses_row_level_security(&catalog, &role_id, &owner_id)).then_some(role_id)
+            };
+            let listeners: Vec<_> = self
+                .listen_subscribes_depending_on(&relations, &[])
+                .into_iter()
+                .flat_map(|id| {
+                    let listeners = self.listen_subscribes[&id].listeners.keys();
+                    listeners.map(move |conn_id| (id, conn_id.clone()))
+                })
+                .filter(|(_, conn_id)| restricted(conn_id).is_some())
+                .collect();
+            let replication_streams: Vec<_> = self
+                .replication_streams_depending_on(&relations, &[])
+                .into_iter()
+                .filter_map(|(conn_id, _)| {
+                    let role_id = restricted(&conn_id)?;
+                    let role = self
+                        .catalog()
+                        .try_get_role(&role_id)
+                        .map_or_else(|| role_id.to_string(), |role| role.name.clone());
+                    let error = plan::PlanError::RowLevelSecurityBypassRequired {
+                        name: name.clone(),
+                        role,
+                        operation: "replicate",
+                    };
+                    Some((conn_id, AdapterError::PlanError(error)))
+                })
+                .collect();
+            for (id, conn_id) in listeners {
+                self.remove_listener(id, &conn_id);
+            }
+            for (conn_id, error) in replication_streams {
+                self.fail_replication_stream(&conn_id, error);
+            }
+        }
+
+        Ok(ExecuteResponse::AlteredObject(plan.object_type))
+    }
+
     #[instrument]
     pub(super) async fn sequence_alter_schema_rename(
         &mut self,
@@ -4719,6 +5165,58 @@ impl Coordinator {
         Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
     }
 
+    #[instrument]
+    pub(super) async fn sequence_alter_table_drop_column(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan: plan::AlterTableDropColumnPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::AlterTableDropColumnPlan {
+            relation_id,
+            column_name,
+        } = plan;
+
+        // TODO(alter_table): Support allocating GlobalIds without a CatalogItemId.
+        let (_, new_global_id) = self.allocate_user_id().await?;
+        let ops = vec![catalog::Op::AlterDropColumn {
+            id: relation_id,
+            new_global_id,
+            name: column_name,
+        }];
+
+        self.catalog_transact_with_context(None, Some(ctx), ops)
+            .await?;
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
+    }
+
+    #[instrument]
+    pub(super) async fn sequence_alter_table_rename_column(
+        &mut self,
+        ctx: &mut ExecuteContext,
+        plan: plan::AlterTableRenameColumnPlan,
+    ) -> Result<ExecuteResponse, AdapterError> {
+        let plan::AlterTableRenameColumnPlan {
+            relation_id,
+            column_name,
+            to_column_name,
+        } = plan;
+
+        // TODO(alter_table): Support allocating GlobalIds without a CatalogItemId.
+        let (_, new_global_id) = self.allocate_user_id().await?;
+        let ops = vec![catalog::Op::AlterRenameColumn {
+            id: relation_id,
+            new_global_id,
+            name: column_name,
+            new_name: to_column_name,
+        }];
+
+        self.catalog_transact_with_context(None, Some(ctx), ops)
+            .await?;
+
+        Ok(ExecuteResponse::AlteredObject(ObjectType::Table))
+    }
+
     /// Prepares to apply a replacement materialized view.
     #[instrument]
     pub(super) async fn sequence_alter_materialized_view_apply_replacement_prepare(
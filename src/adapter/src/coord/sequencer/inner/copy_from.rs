@@ -104,6 +104,8 @@ impl Coordinator {
                 mz_storage_types::oneshot_sources::ContentFormat::Csv(csv.to_owned())
             }
             CopyFormatParams::Parquet => mz_storage_types::oneshot_sources::ContentFormat::Parquet,
+            CopyFormatParams::Json => mz_storage_types::oneshot_sources::ContentFormat::Json,
+            CopyFormatParams::Avro => mz_storage_types::oneshot_sources::ContentFormat::Avro,
             CopyFormatParams::Text(_) | CopyFormatParams::Binary => {
                 mz_ore::soft_panic_or_log!("unsupported formats should be rejected in planning");
                 ctx.retire(Err(AdapterError::Unsupported("COPY FROM URL/S3 format")));
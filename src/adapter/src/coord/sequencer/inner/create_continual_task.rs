@@ -73,6 +73,7 @@ impl Coordinator {
                 is_retained_metrics_object: false,
                 data_source: TableDataSource::TableWrites {
                     defaults: Vec::new(),
+                    identity_columns: Vec::new(),
                 },
             }),
             referenced_by: Vec::new(),
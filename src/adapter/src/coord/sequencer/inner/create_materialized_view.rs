@@ -570,6 +570,7 @@ impl Coordinator {
                             non_null_assertions,
                             compaction_window,
                             refresh_schedule,
+                            notify_channel,
                             ..
                         },
                     drop_ids,
@@ -679,6 +680,7 @@ impl Coordinator {
                     custom_logical_compaction_window: compaction_window,
                     refresh_schedule: refresh_schedule.clone(),
                     initial_as_of: Some(initial_as_of.clone()),
+                    notify_channel,
                 }),
                 owner_id: *ctx.session().current_role_id(),
             },
@@ -265,6 +265,15 @@ pub enum AdapterError {
     ImpossibleTimestampConstraints {
         constraints: String,
     },
+    /// The replication slot is in use by another connection.
+    ReplicationSlotActive(String),
+    /// The changes a replication slot would resume from have been compacted
+    /// away.
+    ReplicationSlotCompacted {
+        slot: String,
+        confirmed_flush: Timestamp,
+        since: Timestamp,
+    },
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -479,6 +488,11 @@ impl AdapterError {
             AdapterError::ImpossibleTimestampConstraints { constraints } => {
                 Some(format!("Constraints:\n{}", constraints))
             }
+            AdapterError::ReplicationSlotCompacted { .. } => Some(
+                "Set RETAIN HISTORY on the published relations to cover the time the replication \
+                 client may be disconnected, then drop and recreate the replication slot."
+                    .into(),
+            ),
             _ => None,
         }
     }
@@ -581,6 +595,23 @@ impl AdapterError {
                     VarError::RequiresUnsafeMode { .. } => SqlState::CANT_CHANGE_RUNTIME_PARAM,
                     VarError::RequiresFeatureFlag { .. } => SqlState::CANT_CHANGE_RUNTIME_PARAM,
                 },
+                mz_catalog::memory::error::ErrorKind::SequenceExhausted { .. } => {
+                    SqlState::SEQUENCE_GENERATOR_LIMIT_EXCEEDED
+                }
+                mz_catalog::memory::error::ErrorKind::SequenceValueOutOfBounds { .. } => {
+                    SqlState::NUMERIC_VALUE_OUT_OF_RANGE
+                }
+                mz_catalog::memory::error::ErrorKind::SequenceValueUndefined { .. } => {
+                    SqlState::OBJECT_NOT_IN_PREREQUISITE_STATE
+                }
+                mz_catalog::memory::error::ErrorKind::PublicationAlreadyExists(_)
+                | mz_catalog::memory::error::ErrorKind::ReplicationSlotAlreadyExists(_) => {
+                    SqlState::DUPLICATE_OBJECT
+                }
+                mz_catalog::memory::error::ErrorKind::UnknownPublication(_)
+                | mz_catalog::memory::error::ErrorKind::UnknownReplicationSlot(_) => {
+                    SqlState::UNDEFINED_OBJECT
+                }
                 _ => SqlState::INTERNAL_ERROR,
             },
             AdapterError::ChangedPlan(_) => SqlState::FEATURE_NOT_SUPPORTED,
@@ -725,6 +756,10 @@ impl AdapterError {
             }
             // similar to AbsurdSubscribeBounds
             AdapterError::ImpossibleTimestampConstraints { .. } => SqlState::DATA_EXCEPTION,
+            AdapterError::ReplicationSlotActive(_) => SqlState::OBJECT_IN_USE,
+            AdapterError::ReplicationSlotCompacted { .. } => {
+                SqlState::OBJECT_NOT_IN_PREREQUISITE_STATE
+            }
         }
     }
 
@@ -918,6 +953,19 @@ impl fmt::Display for AdapterError {
                     "materialized view {name} is sealed and thus cannot be replaced"
                 )
             }
+            AdapterError::ReplicationSlotActive(slot) => {
+                write!(f, "replication slot {} is active", slot.quoted())
+            }
+            AdapterError::ReplicationSlotCompacted {
+                slot,
+                confirmed_flush,
+                since,
+            } => write!(
+                f,
+                "cannot resume replication slot {} from {confirmed_flush}: \
+                 changes before {since} are no longer available",
+                slot.quoted()
+            ),
             AdapterError::ConstraintViolation(not_null_violation) => {
                 write!(f, "{}", not_null_violation)
             }
@@ -25,7 +25,7 @@ use mz_repr::{Datum, GlobalId, IntoRowIterator, Timestamp};
 use mz_sql::ast::Raw;
 use mz_sql::catalog::CatalogCluster;
 use mz_sql::plan::Params;
-use mz_sql::plan::{self, Explainee, ExplaineeStatement, Plan, QueryWhen};
+use mz_sql::plan::{self, Explainee, ExplaineeStatement, Plan, QueryWhen, SideEffectingFunc};
 use mz_sql::rbac;
 use mz_sql::session::metadata::SessionMetadata;
 use mz_sql::session::vars::IsolationLevel;
@@ -398,7 +398,18 @@ impl PeekClient {
             Plan::SideEffectingFunc(sef_plan) => {
                 // Side-effecting functions need Coordinator state (e.g., active_conns),
                 // so delegate to the Coordinator via a Command.
-                // The RBAC check is performed in the Coordinator where active_conns is available.
+                // The RBAC check for `pg_cancel_backend` is performed in the Coordinator where
+                // active_conns is available; all other functions are checked here.
+                if !matches!(sef_plan, SideEffectingFunc::PgCancelBackend { .. }) {
+                    rbac::check_plan(
+                        &conn_catalog,
+                        None::<fn(u32) -> Option<RoleId>>,
+                        session,
+                        &plan,
+                        None,
+                        &resolved_ids,
+                    )?;
+                }
                 let response = self
                     .call_coordinator(|tx| Command::ExecuteSideEffectingFunc {
                         plan: sef_plan.clone(),
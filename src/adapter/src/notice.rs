@@ -47,6 +47,9 @@ pub enum AdapterNotice {
     DatabaseDoesNotExist {
         name: String,
     },
+    PublicationDoesNotExist {
+        name: String,
+    },
     ClusterDoesNotExist {
         name: String,
     },
@@ -158,6 +161,7 @@ impl AdapterNotice {
             AdapterNotice::TableAlreadyExists { .. } => Severity::Notice,
             AdapterNotice::ObjectAlreadyExists { .. } => Severity::Notice,
             AdapterNotice::DatabaseDoesNotExist { .. } => Severity::Notice,
+            AdapterNotice::PublicationDoesNotExist { .. } => Severity::Notice,
             AdapterNotice::ClusterDoesNotExist { .. } => Severity::Notice,
             AdapterNotice::DefaultClusterDoesNotExist { .. } => Severity::Notice,
             AdapterNotice::NoResolvableSearchPathSchema { .. } => Severity::Notice,
@@ -190,6 +194,7 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(notice) => match notice {
                 PlanNotice::ObjectDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::ColumnAlreadyExists { .. } => Severity::Notice,
+                PlanNotice::ColumnDoesNotExist { .. } => Severity::Notice,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => Severity::Warning,
                 PlanNotice::ReplicaDiskOptionDeprecated { .. } => Severity::Notice,
             },
@@ -266,6 +271,7 @@ impl AdapterNotice {
             AdapterNotice::TableAlreadyExists { .. } => SqlState::DUPLICATE_TABLE,
             AdapterNotice::ObjectAlreadyExists { .. } => SqlState::DUPLICATE_OBJECT,
             AdapterNotice::DatabaseDoesNotExist { .. } => SqlState::from_code("MZ006"),
+            AdapterNotice::PublicationDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
             AdapterNotice::ClusterDoesNotExist { .. } => SqlState::from_code("MZ007"),
             AdapterNotice::NoResolvableSearchPathSchema { .. } => SqlState::from_code("MZ008"),
             AdapterNotice::ExistingTransactionInProgress => SqlState::ACTIVE_SQL_TRANSACTION,
@@ -296,6 +302,7 @@ impl AdapterNotice {
             AdapterNotice::PlanNotice(plan) => match plan {
                 PlanNotice::ObjectDoesNotExist { .. } => SqlState::UNDEFINED_OBJECT,
                 PlanNotice::ColumnAlreadyExists { .. } => SqlState::DUPLICATE_COLUMN,
+                PlanNotice::ColumnDoesNotExist { .. } => SqlState::UNDEFINED_COLUMN,
                 PlanNotice::UpsertSinkKeyNotEnforced { .. } => SqlState::WARNING,
                 PlanNotice::ReplicaDiskOptionDeprecated { .. } => {
                     SqlState::WARNING_DEPRECATED_FEATURE
@@ -334,6 +341,9 @@ impl fmt::Display for AdapterNotice {
             AdapterNotice::DatabaseDoesNotExist { name } => {
                 write!(f, "database {} does not exist", name.quoted())
             }
+            AdapterNotice::PublicationDoesNotExist { name } => {
+                write!(f, "publication {} does not exist, skipping", name.quoted())
+            }
             AdapterNotice::CascadeDroppedObject { objects } => {
                 write!(f, "drop cascades to {} other objects", objects.len())
             }
@@ -377,6 +377,8 @@ impl<'a> DataflowBuilder<'a> {
                     | CatalogItem::Type(_)
                     | CatalogItem::Func(_)
                     | CatalogItem::Secret(_)
+                    | CatalogItem::Sequence(_)
+                    | CatalogItem::Policy(_)
                     | CatalogItem::Connection(_) => {
                         // Non-importable thing; can't get here.
                         unreachable!()
@@ -549,6 +551,8 @@ impl<'a> DataflowBuilder<'a> {
                     self.monotonic_object_inner(*on, memo, features)
                 }
                 CatalogItem::Secret(_)
+                | CatalogItem::Sequence(_)
+                | CatalogItem::Policy(_)
                 | CatalogItem::Type(_)
                 | CatalogItem::Connection(_)
                 | CatalogItem::Log(_)
@@ -32,7 +32,10 @@ use mz_ore::now::{EpochMillis, NowFn};
 use mz_pgwire_common::Format;
 use mz_repr::role_id::RoleId;
 use mz_repr::user::{ExternalUserMetadata, InternalUserMetadata};
-use mz_repr::{CatalogItemId, Datum, Row, RowIterator, SqlScalarType, TimestampManipulation};
+use mz_repr::{
+    CatalogItemId, Datum, Diff, RelationDesc, Row, RowIterator, SqlScalarType, Timestamp,
+    TimestampManipulation,
+};
 use mz_sql::ast::{AstInfo, Raw, Statement, TransactionAccessMode};
 use mz_sql::plan::{Params, PlanContext, QueryWhen, StatementDesc};
 use mz_sql::session::metadata::SessionMetadata;
@@ -103,6 +106,12 @@ where
     vars: SessionVars,
     notices_tx: mpsc::UnboundedSender<AdapterNotice>,
     notices_rx: mpsc::UnboundedReceiver<AdapterNotice>,
+    /// The channel on which asynchronous notifications for `LISTEN` are
+    /// delivered, if the session's protocol supports them.
+    notifications_tx: Option<mpsc::Sender<Notification>>,
+    /// Whether the session is a logical replication connection, which may run
+    /// replication commands like `START_REPLICATION`.
+    replication: bool,
     next_transaction_id: TransactionId,
     secret_key: u32,
     external_metadata_rx: Option<watch::Receiver<ExternalUserMetadata>>,
@@ -351,6 +360,8 @@ impl<T: TimestampManipulation> Session<T> {
             vars,
             notices_tx,
             notices_rx,
+            notifications_tx: None,
+            replication: false,
             next_transaction_id: 0,
             secret_key: rand::random(),
             external_metadata_rx,
@@ -515,6 +526,28 @@ impl<T: TimestampManipulation> Session<T> {
         self.notices_tx.clone()
     }
 
+    /// Sets the channel on which to deliver asynchronous notifications to the
+    /// session's client.
+    pub fn set_notification_transmitter(&mut self, tx: mpsc::Sender<Notification>) {
+        self.notifications_tx = Some(tx);
+    }
+
+    /// Returns a channel on which to send asynchronous notifications to the
+    /// session, or `None` if the session cannot receive them.
+    pub fn retain_notification_transmitter(&self) -> Option<mpsc::Sender<Notification>> {
+        self.notifications_tx.clone()
+    }
+
+    /// Marks the session as a logical replication connection.
+    pub fn set_replication_connection(&mut self) {
+        self.replication = true;
+    }
+
+    /// Reports whether the session is a logical replication connection.
+    pub fn is_replication_connection(&self) -> bool {
+        self.replication
+    }
+
     /// Adds a notice to the session.
     pub fn add_notice(&self, notice: AdapterNotice) {
         self.add_notices([notice])
@@ -1080,6 +1113,44 @@ impl InProgressRows {
 /// A channel of batched rows.
 pub type RowBatchStream = UnboundedReceiver<PeekResponseUnary>;
 
+/// An asynchronous notification for a channel the session is listening on.
+#[derive(Debug, Clone)]
+pub struct Notification {
+    /// The name of the channel that raised the notification.
+    pub channel: String,
+    /// The notification's payload.
+    pub payload: String,
+}
+
+/// A table or materialized view whose changes are streamed over a logical
+/// replication connection.
+#[derive(Debug, Clone)]
+pub struct PublishedRelation {
+    /// The OID of the relation.
+    pub oid: u32,
+    /// The name of the schema containing the relation.
+    pub schema: String,
+    /// The name of the relation.
+    pub name: String,
+    /// The columns of the relation.
+    pub desc: RelationDesc,
+}
+
+/// An event of a logical replication stream.
+#[derive(Debug)]
+pub enum ReplicationEvent {
+    /// The published relations changed at `time`. Each update refers to a
+    /// relation by its index in the stream's list of [`PublishedRelation`]s.
+    Transaction {
+        time: Timestamp,
+        updates: Vec<(usize, Row, Diff)>,
+    },
+    /// All changes at times before the contained timestamp have been sent.
+    Progress(Timestamp),
+    /// The stream failed and will not produce further events.
+    Error(AdapterError),
+}
+
 /// Part of statement lifecycle. These are timestamps that come from the Adapter frontend
 /// (`mz-pgwire`) part of the lifecycle.
 #[derive(Debug, Clone)]
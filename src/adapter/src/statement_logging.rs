@@ -235,7 +235,11 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::CreatedMaterializedView
             | ExecuteResponse::CreatedContinualTask
             | ExecuteResponse::CreatedType
+            | ExecuteResponse::CreatedFunction
+            | ExecuteResponse::CreatedSequence
+            | ExecuteResponse::CreatedPolicy
             | ExecuteResponse::CreatedNetworkPolicy
+            | ExecuteResponse::CreatedPublication
             | ExecuteResponse::Deallocate { .. }
             | ExecuteResponse::DeclaredCursor
             | ExecuteResponse::Deleted(_)
@@ -243,19 +247,25 @@ impl From<&ExecuteResponse> for StatementEndedExecutionReason {
             | ExecuteResponse::DiscardedAll
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
+            | ExecuteResponse::DroppedPublication
+            | ExecuteResponse::DroppedReplicationSlot
             | ExecuteResponse::EmptyQuery
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(_)
             | ExecuteResponse::Prepare
+            | ExecuteResponse::Listened
             | ExecuteResponse::Raised
             | ExecuteResponse::ReassignOwned
             | ExecuteResponse::RevokedPrivilege
             | ExecuteResponse::RevokedRole
             | ExecuteResponse::SetVariable { .. }
+            | ExecuteResponse::StartedReplication { .. }
             | ExecuteResponse::StartedTransaction
             | ExecuteResponse::TransactionCommitted { .. }
             | ExecuteResponse::TransactionRolledBack { .. }
+            | ExecuteResponse::Truncated
+            | ExecuteResponse::Unlistened
             | ExecuteResponse::Updated(_)
             | ExecuteResponse::ValidatedConnection { .. } => {
                 StatementEndedExecutionReason::Success {
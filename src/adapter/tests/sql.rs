@@ -107,6 +107,7 @@ async fn datadriven() {
                                             is_retained_metrics_object: false,
                                             data_source: TableDataSource::TableWrites {
                                                 defaults: vec![],
+                                                identity_columns: vec![],
                                             },
                                         }),
                                         owner_id: MZ_SYSTEM_ROLE_ID,
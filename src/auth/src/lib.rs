@@ -23,6 +23,10 @@ pub enum AuthenticatorKind {
     Sasl,
     /// Authenticated via OIDC (JWT tokens).
     Oidc,
+    /// Authenticated via TLS client certificates.
+    Cert,
+    /// Authenticated via an LDAP directory.
+    Ldap,
     /// No authentication performed.
     #[default]
     None,
@@ -0,0 +1,330 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! TLS client certificate authentication.
+//!
+//! The certificate chain, including revocation, is verified by OpenSSL during
+//! the TLS handshake. This module maps the common name (CN) or the subject
+//! alternative names (SANs) of a verified certificate to a role, using the
+//! rules in the `cert_authentication_rules` system variable.
+
+use mz_adapter::{AdapterError, AuthenticationError, Client as AdapterClient};
+use mz_adapter_types::dyncfgs::CERT_AUTHENTICATION_RULES;
+use mz_auth::Authenticated;
+use mz_pgwire_common::{ErrorResponse, Severity};
+use openssl::nid::Nid;
+use openssl::x509::X509Ref;
+use regex::Regex;
+use serde::Deserialize;
+use tokio_postgres::error::SqlState;
+use tracing::warn;
+
+/// Errors that can occur during certificate authentication.
+#[derive(Debug)]
+pub enum CertError {
+    /// The client did not present a certificate.
+    MissingCertificate,
+    /// The rules in `cert_authentication_rules` are malformed.
+    InvalidRules(String),
+    /// No rule maps the certificate to the requested role.
+    NoMatchingRule {
+        /// The requested role, if any.
+        user: Option<String>,
+    },
+    /// The role exists but does not have the LOGIN attribute.
+    NonLogin,
+    /// The role does not exist.
+    UnknownRole,
+    LoginCheckError,
+}
+
+impl std::fmt::Display for CertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CertError::MissingCertificate => write!(f, "client certificate required"),
+            CertError::InvalidRules(_) => {
+                write!(
+                    f,
+                    "failed to parse cert_authentication_rules system variable"
+                )
+            }
+            CertError::NoMatchingRule { user: Some(user) } => {
+                write!(f, "certificate authentication failed for user \"{user}\"")
+            }
+            CertError::NoMatchingRule { user: None } => {
+                write!(f, "certificate does not map to any role")
+            }
+            CertError::NonLogin => write!(f, "role is not allowed to login"),
+            CertError::UnknownRole => write!(f, "role does not exist"),
+            CertError::LoginCheckError => write!(f, "unexpected error checking if role can login"),
+        }
+    }
+}
+
+impl std::error::Error for CertError {}
+
+impl CertError {
+    pub fn code(&self) -> SqlState {
+        SqlState::INVALID_AUTHORIZATION_SPECIFICATION
+    }
+
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            CertError::InvalidRules(e) => Some(e.clone()),
+            CertError::NoMatchingRule { .. } => Some(
+                "No rule in cert_authentication_rules maps the client certificate to the role."
+                    .into(),
+            ),
+            CertError::NonLogin => Some("The role does not have the LOGIN attribute.".into()),
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            CertError::MissingCertificate => Some(
+                "Connect over TLS and present a certificate signed by a trusted client CA.".into(),
+            ),
+            _ => None,
+        }
+    }
+
+    pub fn into_response(self) -> ErrorResponse {
+        ErrorResponse {
+            severity: Severity::Fatal,
+            code: self.code(),
+            message: self.to_string(),
+            detail: self.detail(),
+            hint: self.hint(),
+            position: None,
+        }
+    }
+}
+
+/// The part of a certificate that a [`CertRule`] matches.
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum CertField {
+    /// The common names of the subject.
+    Cn,
+    /// The DNS names among the subject alternative names.
+    DnsSan,
+    /// The email addresses among the subject alternative names.
+    EmailSan,
+    /// The URIs among the subject alternative names.
+    UriSan,
+}
+
+/// A rule that maps certificates to roles.
+#[derive(Debug, Deserialize)]
+struct CertRule {
+    /// The part of the certificate to match.
+    field: CertField,
+    /// A regular expression that must match the entire field.
+    pattern: String,
+    /// The name of the role, in which `$1`, `$name`, etc. are replaced by the
+    /// groups captured by `pattern`.
+    role: String,
+}
+
+/// Returns the values of `field` in `cert`.
+fn field_values(cert: &X509Ref, field: CertField) -> Vec<String> {
+    match field {
+        CertField::Cn => cert
+            .subject_name()
+            .entries_by_nid(Nid::COMMONNAME)
+            .filter_map(|entry| entry.data().as_utf8().ok())
+            .map(|cn| cn.to_string())
+            .collect(),
+        CertField::DnsSan | CertField::EmailSan | CertField::UriSan => {
+            let Some(names) = cert.subject_alt_names() else {
+                return vec![];
+            };
+            names
+                .iter()
+                .filter_map(|name| match field {
+                    CertField::DnsSan => name.dnsname(),
+                    CertField::EmailSan => name.email(),
+                    CertField::UriSan => name.uri(),
+                    CertField::Cn => unreachable!(),
+                })
+                .map(|value| value.to_string())
+                .collect()
+        }
+    }
+}
+
+/// Returns the roles that `rules` map `cert` to, in order of preference.
+fn map_roles(cert: &X509Ref, rules: &[CertRule]) -> Result<Vec<String>, CertError> {
+    let mut roles = vec![];
+    for rule in rules {
+        let pattern = Regex::new(&format!("^(?:{})$", rule.pattern))
+            .map_err(|e| CertError::InvalidRules(e.to_string()))?;
+        for value in field_values(cert, rule.field) {
+            if let Some(captures) = pattern.captures(&value) {
+                let mut role = String::new();
+                captures.expand(&rule.role, &mut role);
+                if !role.is_empty() {
+                    roles.push(role);
+                }
+            }
+        }
+    }
+    Ok(roles)
+}
+
+/// Authenticates users by the TLS client certificate of their connection.
+#[derive(Clone, Debug)]
+pub struct CertAuthenticator {
+    adapter_client: AdapterClient,
+}
+
+impl CertAuthenticator {
+    /// Create a new [`CertAuthenticator`] with an [`AdapterClient`].
+    ///
+    /// The mapping rules are fetched from system variables on each
+    /// authentication attempt.
+    pub fn new(adapter_client: AdapterClient) -> Self {
+        Self { adapter_client }
+    }
+
+    /// Authenticates the holder of `cert`, which must already be verified.
+    ///
+    /// If `expected_user` is present, the certificate must map to that role.
+    /// Otherwise, the certificate is mapped to the role of the first matching
+    /// rule. Returns the name of the authenticated role.
+    pub async fn authenticate(
+        &self,
+        cert: Option<&X509Ref>,
+        expected_user: Option<&str>,
+    ) -> Result<(String, Authenticated), CertError> {
+        let cert = cert.ok_or(CertError::MissingCertificate)?;
+        let system_vars = self.adapter_client.get_system_vars().await;
+        let rules: Vec<CertRule> =
+            serde_json::from_value(CERT_AUTHENTICATION_RULES.get(system_vars.dyncfgs()))
+                .map_err(|e| CertError::InvalidRules(e.to_string()))?;
+        let roles = map_roles(cert, &rules)?;
+        let user = match expected_user {
+            Some(expected) => roles.into_iter().find(|role| role == expected),
+            None => roles.into_iter().next(),
+        };
+        let Some(user) = user else {
+            return Err(CertError::NoMatchingRule {
+                user: expected_user.map(|u| u.to_string()),
+            });
+        };
+        self.check_role_login(&user).await?;
+        Ok((user, Authenticated))
+    }
+
+    /// Checks whether the role exists and has the LOGIN attribute. Unlike with
+    /// OIDC, roles are never auto-provisioned for certificates.
+    async fn check_role_login(&self, role_name: &str) -> Result<(), CertError> {
+        match self.adapter_client.role_can_login(role_name).await {
+            Ok(()) => Ok(()),
+            Err(AdapterError::AuthenticationError(AuthenticationError::RoleNotFound)) => {
+                Err(CertError::UnknownRole)
+            }
+            Err(AdapterError::AuthenticationError(AuthenticationError::NonLogin)) => {
+                Err(CertError::NonLogin)
+            }
+            Err(e) => {
+                warn!(?e, "unexpected error checking certificate role login");
+                Err(CertError::LoginCheckError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use openssl::asn1::Asn1Time;
+    use openssl::hash::MessageDigest;
+    use openssl::pkey::PKey;
+    use openssl::rsa::Rsa;
+    use openssl::x509::extension::SubjectAlternativeName;
+    use openssl::x509::{X509, X509NameBuilder};
+
+    use super::*;
+
+    fn cert(cn: &str, dns_sans: &[&str]) -> X509 {
+        let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_nid(Nid::COMMONNAME, cn).unwrap();
+        let name = name.build();
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        if !dns_sans.is_empty() {
+            let mut san = SubjectAlternativeName::new();
+            for dns in dns_sans {
+                san.dns(dns);
+            }
+            let san = san.build(&builder.x509v3_context(None, None)).unwrap();
+            builder.append_extension(san).unwrap();
+        }
+        builder.sign(&key, MessageDigest::sha256()).unwrap();
+        builder.build()
+    }
+
+    fn rules(json: &str) -> Vec<CertRule> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn test_map_roles_cn() {
+        let cert = cert("alice", &[]);
+        let roles = map_roles(
+            &cert,
+            &rules(r#"[{"field":"cn","pattern":".+","role":"$0"}]"#),
+        );
+        assert_eq!(roles.unwrap(), vec!["alice".to_string()]);
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn test_map_roles_san() {
+        let cert = cert("ignored", &["billing.svc.example.com", "other.example.org"]);
+        let rules = rules(
+            r#"[{"field":"dns_san","pattern":"(?<svc>[a-z]+)\\.svc\\.example\\.com","role":"svc_$svc"}]"#,
+        );
+        assert_eq!(
+            map_roles(&cert, &rules).unwrap(),
+            vec!["svc_billing".to_string()]
+        );
+    }
+
+    #[mz_ore::test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    fn test_map_roles_anchored() {
+        // Patterns must match the entire field.
+        let cert = cert("alice.evil", &[]);
+        let rules = rules(r#"[{"field":"cn","pattern":"alice","role":"alice"}]"#);
+        assert!(map_roles(&cert, &rules).unwrap().is_empty());
+    }
+
+    #[mz_ore::test]
+    fn test_invalid_pattern() {
+        let cert = X509::builder().unwrap().build();
+        let rules = rules(r#"[{"field":"cn","pattern":"(","role":"$0"}]"#);
+        assert!(matches!(
+            map_roles(&cert, &rules),
+            Err(CertError::InvalidRules(_))
+        ));
+    }
+}
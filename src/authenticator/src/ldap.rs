@@ -0,0 +1,446 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! LDAP authentication.
+//!
+//! Passwords are verified by binding to an LDAP directory as the user. The DN
+//! of the user is either derived from a template (simple bind) or found by
+//! searching the directory (search+bind). Optionally, the groups of the user in
+//! the directory are synchronized into role memberships at each login.
+//!
+//! The directory is configured by the `ldap_*` system variables.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+
+use ldap3::{LdapConnAsync, LdapConnSettings, Scope, SearchEntry};
+use mz_adapter::{AdapterError, AuthenticationError, Client as AdapterClient};
+use mz_adapter_types::dyncfgs::{
+    LDAP_BIND_DN_TEMPLATE, LDAP_GROUP_ATTRIBUTE, LDAP_GROUP_ROLE_MAPPING, LDAP_SEARCH_BASE_DN,
+    LDAP_SEARCH_BIND_DN, LDAP_SEARCH_BIND_PASSWORD_FILE, LDAP_SEARCH_FILTER, LDAP_URL,
+};
+use mz_auth::Authenticated;
+use mz_auth::password::Password;
+use mz_pgwire_common::{ErrorResponse, Severity};
+use tokio_postgres::error::SqlState;
+use tracing::{debug, warn};
+
+/// The timeout for connecting to the LDAP server and for each operation.
+const LDAP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The placeholder for the user name in DN and filter templates.
+const USERNAME_PLACEHOLDER: &str = "{username}";
+
+/// Errors that can occur during LDAP authentication.
+#[derive(Debug)]
+pub enum LdapError {
+    MissingUrl,
+    /// Neither a bind DN template nor a search base DN is configured.
+    MissingUserLookup,
+    /// The `ldap_group_role_mapping` system variable is malformed.
+    InvalidGroupRoleMapping(String),
+    /// The password of the search bind DN could not be read.
+    SearchBindPassword(String),
+    /// Communication with the LDAP server failed.
+    ServerError(String),
+    /// The user does not exist in the directory or the password is wrong.
+    InvalidCredentials,
+    /// The role exists but does not have the LOGIN attribute.
+    NonLogin,
+    /// The role does not exist.
+    UnknownRole,
+    LoginCheckError,
+    /// Synchronizing the role memberships of the user failed.
+    RoleSyncError,
+}
+
+impl std::fmt::Display for LdapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LdapError::MissingUrl => write!(f, "LDAP URL is not configured"),
+            LdapError::MissingUserLookup => write!(
+                f,
+                "neither an LDAP bind DN template nor an LDAP search base DN is configured"
+            ),
+            LdapError::InvalidGroupRoleMapping(_) => {
+                write!(f, "failed to parse ldap_group_role_mapping system variable")
+            }
+            LdapError::SearchBindPassword(_) => {
+                write!(f, "failed to read LDAP search bind password")
+            }
+            LdapError::ServerError(_) => write!(f, "failed to communicate with LDAP server"),
+            LdapError::InvalidCredentials => write!(f, "invalid password"),
+            LdapError::NonLogin => write!(f, "role is not allowed to login"),
+            LdapError::UnknownRole => write!(f, "role does not exist"),
+            LdapError::LoginCheckError => write!(f, "unexpected error checking if role can login"),
+            LdapError::RoleSyncError => {
+                write!(f, "failed to synchronize role memberships with LDAP groups")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LdapError {}
+
+impl LdapError {
+    pub fn code(&self) -> SqlState {
+        match self {
+            LdapError::InvalidCredentials => SqlState::INVALID_PASSWORD,
+            _ => SqlState::INVALID_AUTHORIZATION_SPECIFICATION,
+        }
+    }
+
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            LdapError::InvalidGroupRoleMapping(e) | LdapError::SearchBindPassword(e) => {
+                Some(e.clone())
+            }
+            LdapError::NonLogin => Some("The role does not have the LOGIN attribute.".into()),
+            _ => None,
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            LdapError::MissingUrl => Some("Set the ldap_url system variable.".into()),
+            LdapError::MissingUserLookup => Some(
+                "Set either the ldap_bind_dn_template or the ldap_search_base_dn system variable."
+                    .into(),
+            ),
+            LdapError::UnknownRole => {
+                Some("Roles must be created before logging in with LDAP.".into())
+            }
+            _ => None,
+        }
+    }
+
+    pub fn into_response(self) -> ErrorResponse {
+        ErrorResponse {
+            severity: Severity::Fatal,
+            code: self.code(),
+            message: self.to_string(),
+            detail: self.detail(),
+            hint: self.hint(),
+            position: None,
+        }
+    }
+}
+
+/// How to find the DN of a user.
+#[derive(Debug)]
+enum UserLookup {
+    /// Substitute the user name into a DN template.
+    Template(String),
+    /// Search the directory for the user.
+    Search {
+        base_dn: String,
+        filter: String,
+        bind_dn: Option<String>,
+        bind_password_file: Option<String>,
+    },
+}
+
+/// The LDAP configuration, as of one authentication attempt.
+#[derive(Debug)]
+struct LdapConfig {
+    url: String,
+    user_lookup: UserLookup,
+    group_attribute: String,
+    /// Maps lowercased group DNs to role names.
+    group_role_mapping: BTreeMap<String, String>,
+}
+
+/// Substitutes `user` into a DN template, escaping it as a DN value.
+fn user_dn(template: &str, user: &str) -> String {
+    template.replace(USERNAME_PLACEHOLDER, &ldap3::dn_escape(user))
+}
+
+/// Substitutes `user` into a search filter template, escaping it as a filter
+/// value.
+fn search_filter(template: &str, user: &str) -> String {
+    template.replace(USERNAME_PLACEHOLDER, &ldap3::ldap_escape(user))
+}
+
+/// Parses the `ldap_group_role_mapping` system variable.
+fn parse_group_role_mapping(
+    value: serde_json::Value,
+) -> Result<BTreeMap<String, String>, LdapError> {
+    let mapping: BTreeMap<String, String> = serde_json::from_value(value)
+        .map_err(|e| LdapError::InvalidGroupRoleMapping(e.to_string()))?;
+    // Attribute values of DNs are compared case-insensitively by most
+    // directories.
+    Ok(mapping
+        .into_iter()
+        .map(|(group, role)| (group.to_lowercase(), role))
+        .collect())
+}
+
+/// Returns the roles that `groups` map to.
+fn mapped_roles(groups: &[String], mapping: &BTreeMap<String, String>) -> BTreeSet<String> {
+    groups
+        .iter()
+        .filter_map(|group| mapping.get(&group.to_lowercase()))
+        .cloned()
+        .collect()
+}
+
+/// Returns the values of `attribute` in `entry`, matching its name
+/// case-insensitively.
+fn attribute_values(entry: SearchEntry, attribute: &str) -> Vec<String> {
+    entry
+        .attrs
+        .into_iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case(attribute))
+        .flat_map(|(_, values)| values)
+        .collect()
+}
+
+/// Authenticates users with an LDAP directory.
+#[derive(Clone, Debug)]
+pub struct LdapAuthenticator {
+    adapter_client: AdapterClient,
+}
+
+impl LdapAuthenticator {
+    /// Create a new [`LdapAuthenticator`] with an [`AdapterClient`].
+    ///
+    /// The directory configuration is fetched from system variables on each
+    /// authentication attempt.
+    pub fn new(adapter_client: AdapterClient) -> Self {
+        Self { adapter_client }
+    }
+
+    /// Authenticates `user` with `password`. If `ldap_group_role_mapping` is
+    /// not empty, the role memberships of the user are synchronized with their
+    /// groups in the directory.
+    pub async fn authenticate(
+        &self,
+        user: &str,
+        password: &Password,
+    ) -> Result<Authenticated, LdapError> {
+        // An empty password would make for an unauthenticated bind, which
+        // some directories treat as an anonymous bind that always succeeds.
+        if password.0.is_empty() {
+            return Err(LdapError::InvalidCredentials);
+        }
+        let config = self.config().await?;
+        let groups = self.bind(&config, user, &password.0).await?;
+        self.check_role_login(user).await?;
+        if !config.group_role_mapping.is_empty() {
+            let granted_roles = mapped_roles(&groups, &config.group_role_mapping);
+            let managed_roles = config.group_role_mapping.into_values().collect();
+            self.adapter_client
+                .sync_role_memberships(user, granted_roles, managed_roles)
+                .await
+                .map_err(|e| {
+                    warn!(?e, "failed to synchronize LDAP role memberships");
+                    LdapError::RoleSyncError
+                })?;
+        }
+        Ok(Authenticated)
+    }
+
+    /// Reads the LDAP configuration from system variables.
+    async fn config(&self) -> Result<LdapConfig, LdapError> {
+        let system_vars = self.adapter_client.get_system_vars().await;
+        let dyncfgs = system_vars.dyncfgs();
+        let Some(url) = LDAP_URL.get(dyncfgs) else {
+            return Err(LdapError::MissingUrl);
+        };
+        // Empty values are treated as unset, so that `ALTER SYSTEM SET ... = ''`
+        // can switch between the lookup methods.
+        let user_lookup = match (
+            LDAP_BIND_DN_TEMPLATE
+                .get(dyncfgs)
+                .filter(|template| !template.is_empty()),
+            LDAP_SEARCH_BASE_DN
+                .get(dyncfgs)
+                .filter(|base_dn| !base_dn.is_empty()),
+        ) {
+            (Some(template), _) => UserLookup::Template(template),
+            (None, Some(base_dn)) => UserLookup::Search {
+                base_dn,
+                filter: LDAP_SEARCH_FILTER.get(dyncfgs),
+                bind_dn: LDAP_SEARCH_BIND_DN.get(dyncfgs),
+                bind_password_file: LDAP_SEARCH_BIND_PASSWORD_FILE.get(dyncfgs),
+            },
+            (None, None) => return Err(LdapError::MissingUserLookup),
+        };
+        Ok(LdapConfig {
+            url,
+            user_lookup,
+            group_attribute: LDAP_GROUP_ATTRIBUTE.get(dyncfgs),
+            group_role_mapping: parse_group_role_mapping(LDAP_GROUP_ROLE_MAPPING.get(dyncfgs))?,
+        })
+    }
+
+    /// Binds to the directory as `user`, returning the groups of the user if
+    /// they are needed for role synchronization.
+    async fn bind(
+        &self,
+        config: &LdapConfig,
+        user: &str,
+        password: &str,
+    ) -> Result<Vec<String>, LdapError> {
+        let server_error = |e: ldap3::LdapError| LdapError::ServerError(e.to_string());
+        let settings = LdapConnSettings::new().set_conn_timeout(LDAP_TIMEOUT);
+        let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &config.url)
+            .await
+            .map_err(server_error)?;
+        mz_ore::task::spawn(|| "ldap-connection", async move {
+            if let Err(e) = conn.drive().await {
+                debug!(?e, "LDAP connection failed");
+            }
+        });
+        let sync_groups = !config.group_role_mapping.is_empty();
+        let attributes = vec![config.group_attribute.as_str()];
+
+        let (dn, groups) = match &config.user_lookup {
+            UserLookup::Template(template) => (user_dn(template, user), None),
+            UserLookup::Search {
+                base_dn,
+                filter,
+                bind_dn,
+                bind_password_file,
+            } => {
+                if let Some(bind_dn) = bind_dn {
+                    let bind_password = match bind_password_file {
+                        Some(path) => std::fs::read_to_string(path)
+                            .map_err(|e| LdapError::SearchBindPassword(e.to_string()))?,
+                        None => String::new(),
+                    };
+                    ldap.with_timeout(LDAP_TIMEOUT)
+                        .simple_bind(bind_dn, bind_password.trim_end())
+                        .await
+                        .and_then(|result| result.success())
+                        .map_err(server_error)?;
+                }
+                let (entries, _) = ldap
+                    .with_timeout(LDAP_TIMEOUT)
+                    .search(
+                        base_dn,
+                        Scope::Subtree,
+                        &search_filter(filter, user),
+                        attributes.clone(),
+                    )
+                    .await
+                    .and_then(|result| result.success())
+                    .map_err(server_error)?;
+                let mut entries = entries.into_iter().map(SearchEntry::construct);
+                let (Some(entry), None) = (entries.next(), entries.next()) else {
+                    // Users that do not exist, or are ambiguous, are reported
+                    // like wrong passwords so as not to reveal them.
+                    debug!(user, "LDAP search did not find exactly one user");
+                    return Err(LdapError::InvalidCredentials);
+                };
+                let dn = entry.dn.clone();
+                (dn, Some(attribute_values(entry, &config.group_attribute)))
+            }
+        };
+
+        let result = ldap
+            .with_timeout(LDAP_TIMEOUT)
+            .simple_bind(&dn, password)
+            .await
+            .map_err(server_error)?;
+        if result.rc != 0 {
+            debug!(user, rc = result.rc, "LDAP bind failed");
+            return Err(LdapError::InvalidCredentials);
+        }
+
+        let groups = match groups {
+            Some(groups) => groups,
+            None if sync_groups => {
+                let (entries, _) = ldap
+                    .with_timeout(LDAP_TIMEOUT)
+                    .search(&dn, Scope::Base, "(objectClass=*)", attributes)
+                    .await
+                    .and_then(|result| result.success())
+                    .map_err(server_error)?;
+                entries
+                    .into_iter()
+                    .map(SearchEntry::construct)
+                    .flat_map(|entry| attribute_values(entry, &config.group_attribute))
+                    .collect()
+            }
+            None => vec![],
+        };
+        let _ = ldap.unbind().await;
+        Ok(groups)
+    }
+
+    /// Checks whether the role exists and has the LOGIN attribute. Unlike with
+    /// OIDC, roles are never auto-provisioned for LDAP users.
+    async fn check_role_login(&self, role_name: &str) -> Result<(), LdapError> {
+        match self.adapter_client.role_can_login(role_name).await {
+            Ok(()) => Ok(()),
+            Err(AdapterError::AuthenticationError(AuthenticationError::RoleNotFound)) => {
+                Err(LdapError::UnknownRole)
+            }
+            Err(AdapterError::AuthenticationError(AuthenticationError::NonLogin)) => {
+                Err(LdapError::NonLogin)
+            }
+            Err(e) => {
+                warn!(?e, "unexpected error checking LDAP role login");
+                Err(LdapError::LoginCheckError)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_user_dn() {
+        assert_eq!(
+            user_dn("uid={username},ou=people,dc=example,dc=com", "alice"),
+            "uid=alice,ou=people,dc=example,dc=com"
+        );
+        // Special characters cannot inject additional RDNs.
+        let dn = user_dn("uid={username},dc=example", "a,ou=admins");
+        assert_eq!(dn.matches(',').count(), 1, "{dn}");
+    }
+
+    #[mz_ore::test]
+    fn test_search_filter() {
+        assert_eq!(search_filter("(uid={username})", "alice"), "(uid=alice)");
+        // Special characters cannot widen the filter.
+        assert_eq!(
+            search_filter("(uid={username})", "*)(uid=*"),
+            "(uid=\\2a\\29\\28uid=\\2a)"
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_mapped_roles() {
+        let mapping = parse_group_role_mapping(serde_json::json!({
+            "cn=Analysts,ou=groups,dc=example,dc=com": "analyst",
+            "cn=admins,ou=groups,dc=example,dc=com": "admin",
+        }))
+        .unwrap();
+        let groups = vec![
+            "CN=analysts,OU=groups,DC=example,DC=com".to_string(),
+            "cn=unmapped,ou=groups,dc=example,dc=com".to_string(),
+        ];
+        assert_eq!(
+            mapped_roles(&groups, &mapping),
+            BTreeSet::from(["analyst".to_string()])
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_invalid_group_role_mapping() {
+        assert!(matches!(
+            parse_group_role_mapping(serde_json::json!(["analyst"])),
+            Err(LdapError::InvalidGroupRoleMapping(_))
+        ));
+    }
+}
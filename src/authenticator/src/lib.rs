@@ -7,11 +7,15 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
+pub mod cert;
+pub mod ldap;
 pub mod oidc;
 
 use mz_adapter::Client as AdapterClient;
 use mz_frontegg_auth::Authenticator as FronteggAuthenticator;
 
+pub use cert::{CertAuthenticator, CertError};
+pub use ldap::{LdapAuthenticator, LdapError};
 pub use oidc::{GenericOidcAuthenticator, OidcClaims, OidcError, ValidatedClaims};
 
 use mz_auth::AuthenticatorKind;
@@ -22,6 +26,8 @@ pub enum Authenticator {
     Password(AdapterClient),
     Sasl(AdapterClient),
     Oidc(GenericOidcAuthenticator),
+    Cert(CertAuthenticator),
+    Ldap(LdapAuthenticator),
     None,
 }
 
@@ -32,6 +38,8 @@ impl Authenticator {
             Authenticator::Password(_) => AuthenticatorKind::Password,
             Authenticator::Sasl(_) => AuthenticatorKind::Sasl,
             Authenticator::Oidc(_) => AuthenticatorKind::Oidc,
+            Authenticator::Cert(_) => AuthenticatorKind::Cert,
+            Authenticator::Ldap(_) => AuthenticatorKind::Ldap,
             Authenticator::None => AuthenticatorKind::None,
         }
     }
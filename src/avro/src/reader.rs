@@ -140,11 +140,23 @@ pub struct Block {
 }
 
 impl<R: AvroRead> BlockIter<R> {
+    /// Creates a `BlockIter` whose blocks are to be decoded with the writer schema.
+    pub fn new(inner: R) -> Result<Self, AvroError> {
+        Ok(Self {
+            inner: Reader::new(inner)?,
+        })
+    }
+
     pub fn with_schema(reader_schema: &Schema, inner: R) -> Result<Self, AvroError> {
         Ok(Self {
             inner: Reader::with_schema(reader_schema, inner)?,
         })
     }
+
+    /// Get a reference to the writer `Schema`.
+    pub fn writer_schema(&self) -> &Schema {
+        self.inner.writer_schema()
+    }
 }
 
 impl<R: AvroRead> Iterator for BlockIter<R> {
@@ -143,14 +143,7 @@ impl<W: Write> Writer<W> {
     /// internal buffering for performance reasons. If you want to be sure the value has been
     /// written, then call [`flush`](struct.Writer.html#method.flush).
     pub fn append<T: ToAvro>(&mut self, value: T) -> Result<usize, Error> {
-        let n = if !self.has_header {
-            let header = self.header()?;
-            let n = self.append_bytes(header.as_ref())?;
-            self.has_header = true;
-            n
-        } else {
-            0
-        };
+        let n = self.write_header()?;
         let avro = value.avro();
         write_value_ref(&self.schema, &avro, &mut self.buffer)?;
 
@@ -171,14 +164,7 @@ impl<W: Write> Writer<W> {
     /// internal buffering for performance reasons. If you want to be sure the value has been
     /// written, then call [`flush`](struct.Writer.html#method.flush).
     pub fn append_value_ref(&mut self, value: &Value) -> Result<usize, Error> {
-        let n = if !self.has_header {
-            let header = self.header()?;
-            let n = self.append_bytes(header.as_ref())?;
-            self.has_header = true;
-            n
-        } else {
-            0
-        };
+        let n = self.write_header()?;
 
         write_value_ref(&self.schema, value, &mut self.buffer)?;
 
@@ -242,6 +228,22 @@ impl<W: Write> Writer<W> {
         Ok(num_bytes)
     }
 
+    /// Write the header of the file, unless it has already been written.
+    ///
+    /// The header is written implicitly when the first value is appended, so
+    /// this only needs to be called to produce a valid file without any values.
+    ///
+    /// Return the number of bytes written.
+    pub fn write_header(&mut self) -> Result<usize, Error> {
+        if self.has_header {
+            return Ok(0);
+        }
+        let header = self.header()?;
+        let n = self.append_bytes(header.as_ref())?;
+        self.has_header = true;
+        Ok(n)
+    }
+
     /// Flush the content appended to a `Writer`. Call this function to make sure all the content
     /// has been written before releasing the `Writer`.
     ///
@@ -282,6 +284,14 @@ impl<W: Write> Writer<W> {
         self.writer
     }
 
+    /// Get a mutable reference to what the `Writer` is writing to.
+    ///
+    /// **NOTE** Only content that has been flushed is written to it. Writing to
+    /// it directly will corrupt the file.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.writer
+    }
+
     /// Generate and append synchronization marker to the payload.
     fn append_marker(&mut self) -> Result<usize, Error> {
         // using .writer.write directly to avoid mutable borrow of self
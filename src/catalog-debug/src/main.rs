@@ -710,6 +710,8 @@ async fn upgrade_check(
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Connection(_) => None,
         });
 
@@ -224,6 +224,8 @@ impl RustType<crate::objects::CatalogItemType> for CatalogItemType {
             CatalogItemType::Secret => crate::objects::CatalogItemType::Secret,
             CatalogItemType::Connection => crate::objects::CatalogItemType::Connection,
             CatalogItemType::ContinualTask => crate::objects::CatalogItemType::ContinualTask,
+            CatalogItemType::Sequence => unreachable!("sequences are never system objects"),
+            CatalogItemType::Policy => unreachable!("policies are never system objects"),
         }
     }
 
@@ -2096,6 +2096,143 @@ pub static MZ_ICEBERG_SINKS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTa
     access: vec![PUBLIC_SELECT],
 });
 
+pub static MZ_POSTGRES_SINKS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_postgres_sinks",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: oid::TABLE_MZ_POSTGRES_SINKS_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", SqlScalarType::String.nullable(false))
+        .with_column("schema", SqlScalarType::String.nullable(false))
+        .with_column("table", SqlScalarType::String.nullable(false))
+        .with_column("progress_table", SqlScalarType::String.nullable(false))
+        .with_key(vec![0])
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        ("id", "The ID of the sink."),
+        (
+            "schema",
+            "The schema of the upstream table into which the sink is writing.",
+        ),
+        (
+            "table",
+            "The upstream table into which the sink is writing.",
+        ),
+        (
+            "progress_table",
+            "The upstream table in which the sink records its progress.",
+        ),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_MYSQL_SINKS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_mysql_sinks",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: oid::TABLE_MZ_MYSQL_SINKS_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", SqlScalarType::String.nullable(false))
+        .with_column("database", SqlScalarType::String.nullable(false))
+        .with_column("table", SqlScalarType::String.nullable(false))
+        .with_column("progress_table", SqlScalarType::String.nullable(false))
+        .with_key(vec![0])
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        ("id", "The ID of the sink."),
+        (
+            "database",
+            "The database of the upstream table into which the sink is writing.",
+        ),
+        (
+            "table",
+            "The upstream table into which the sink is writing.",
+        ),
+        (
+            "progress_table",
+            "The upstream table in which the sink records its progress.",
+        ),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_SQL_SERVER_SINKS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_sql_server_sinks",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: oid::TABLE_MZ_SQL_SERVER_SINKS_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", SqlScalarType::String.nullable(false))
+        .with_column("schema", SqlScalarType::String.nullable(false))
+        .with_column("table", SqlScalarType::String.nullable(false))
+        .with_column("progress_table", SqlScalarType::String.nullable(false))
+        .with_key(vec![0])
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        ("id", "The ID of the sink."),
+        (
+            "schema",
+            "The schema of the upstream table into which the sink is writing.",
+        ),
+        (
+            "table",
+            "The upstream table into which the sink is writing.",
+        ),
+        (
+            "progress_table",
+            "The upstream table in which the sink records its progress.",
+        ),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_S3_SINKS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_s3_sinks",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: oid::TABLE_MZ_S3_SINKS_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", SqlScalarType::String.nullable(false))
+        .with_column("url", SqlScalarType::String.nullable(false))
+        .with_column("format", SqlScalarType::String.nullable(false))
+        .with_column("max_file_size", SqlScalarType::UInt64.nullable(false))
+        .with_key(vec![0])
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        ("id", "The ID of the sink."),
+        (
+            "url",
+            "The S3 URL under which the sink writes its files and manifest.",
+        ),
+        ("format", "The format of the files written by the sink."),
+        (
+            "max_file_size",
+            "The maximum size in bytes of each file written by the sink.",
+        ),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
+pub static MZ_HTTP_SINKS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
+    name: "mz_http_sinks",
+    schema: MZ_CATALOG_SCHEMA,
+    oid: oid::TABLE_MZ_HTTP_SINKS_OID,
+    desc: RelationDesc::builder()
+        .with_column("id", SqlScalarType::String.nullable(false))
+        .with_column("url", SqlScalarType::String.nullable(false))
+        .with_key(vec![0])
+        .finish(),
+    column_comments: BTreeMap::from_iter([
+        ("id", "The ID of the sink."),
+        (
+            "url",
+            "The URL to which the sink posts its batches of changes.",
+        ),
+    ]),
+    is_retained_metrics_object: false,
+    access: vec![PUBLIC_SELECT],
+});
+
 pub static MZ_KAFKA_SINKS: LazyLock<BuiltinTable> = LazyLock::new(|| BuiltinTable {
     name: "mz_kafka_sinks",
     schema: MZ_CATALOG_SCHEMA,
@@ -2799,7 +2936,7 @@ pub static MZ_SINKS: LazyLock<BuiltinTable> = LazyLock::new(|| {
             ("size", "The size of the sink."),
             (
                 "envelope_type",
-                "The envelope of the sink: `upsert`, or `debezium`.",
+                "The envelope of the sink: `upsert`, `debezium`, or `changelog`.",
             ),
             (
                 "format",
@@ -14144,6 +14281,11 @@ pub static BUILTINS_STATIC: LazyLock<Vec<Builtin<NameReference>>> = LazyLock::ne
         Builtin::Table(&MZ_KAFKA_SOURCES),
         Builtin::Table(&MZ_OBJECT_DEPENDENCIES),
         Builtin::Table(&MZ_ICEBERG_SINKS),
+        Builtin::Table(&MZ_POSTGRES_SINKS),
+        Builtin::Table(&MZ_MYSQL_SINKS),
+        Builtin::Table(&MZ_SQL_SERVER_SINKS),
+        Builtin::Table(&MZ_S3_SINKS),
+        Builtin::Table(&MZ_HTTP_SINKS),
         Builtin::MaterializedView(&MZ_DATABASES),
         Builtin::MaterializedView(&MZ_SCHEMAS),
         Builtin::Table(&MZ_COLUMNS),
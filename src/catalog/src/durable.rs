@@ -9,6 +9,7 @@
 
 //! This crate is responsible for durably storing and modifying the catalog contents.
 
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::num::NonZeroI64;
 use std::sync::Arc;
@@ -24,6 +25,7 @@ use mz_persist_client::PersistClient;
 use mz_persist_types::ShardId;
 use mz_repr::{CatalogItemId, Diff, GlobalId, RelationDesc, SqlScalarType};
 use mz_sql::catalog::CatalogError as SqlCatalogError;
+use mz_sql::plan::{SequenceId, SequenceOptions, SequenceState};
 use uuid::Uuid;
 
 use crate::config::ClusterReplicaSizeMap;
@@ -37,7 +39,7 @@ use crate::durable::objects::state_update::{StateUpdateKindJson, TryIntoStateUpd
 pub use crate::durable::objects::{
     Cluster, ClusterConfig, ClusterReplica, ClusterVariant, ClusterVariantManaged, Comment,
     Database, DefaultPrivilege, IntrospectionSourceIndex, Item, NetworkPolicy, ReplicaConfig,
-    ReplicaLocation, Role, RoleAuth, Schema, SourceReference, SourceReferences,
+    ReplicaLocation, ReplicationSlot, Role, RoleAuth, Schema, SourceReference, SourceReferences,
     StorageCollectionMetadata, SystemConfiguration, SystemObjectDescription, SystemObjectMapping,
     UnfinalizedShard,
 };
@@ -75,6 +77,15 @@ pub(crate) const CATALOG_CONTENT_VERSION_KEY: &str = "catalog_content_version";
 pub const BUILTIN_MIGRATION_SHARD_KEY: &str = "builtin_migration_shard";
 pub const EXPRESSION_CACHE_SHARD_KEY: &str = "expression_cache_shard";
 pub const MOCK_AUTHENTICATION_NONCE_KEY: &str = "mock_authentication_nonce";
+/// Prefix of the settings that hold the state of each sequence, keyed by
+/// [`mz_sql::plan::SequenceId`].
+pub(crate) const SEQUENCE_STATE_KEY_PREFIX: &str = "sequence_state.";
+/// Prefix of the settings that hold the relations of each publication, keyed
+/// by publication name.
+pub(crate) const PUBLICATION_KEY_PREFIX: &str = "publication.";
+/// Prefix of the settings that hold the state of each replication slot, keyed
+/// by slot name.
+pub(crate) const REPLICATION_SLOT_KEY_PREFIX: &str = "replication_slot.";
 
 #[derive(Clone, Debug)]
 pub struct BootstrapArgs {
@@ -382,6 +393,212 @@ pub trait DurableCatalogState: ReadOnlyDurableCatalogState {
         Ok((CatalogItemId::User(id), GlobalId::User(id)))
     }
 
+    /// Allocates and returns the next `amount` values of the sequence
+    /// identified by `id`, or `None` if the sequence would be exhausted, in
+    /// which case nothing is allocated.
+    ///
+    /// See [`Self::commit_transaction`] for details on `commit_ts`.
+    async fn allocate_sequence_values(
+        &mut self,
+        id: SequenceId,
+        options: &SequenceOptions,
+        amount: u64,
+        commit_ts: Timestamp,
+    ) -> Result<Option<Vec<i64>>, CatalogError> {
+        if amount == 0 {
+            return Ok(Some(Vec::new()));
+        }
+        let mut txn = self.transaction().await?;
+        let mut state = txn
+            .get_sequence_state(id)
+            .unwrap_or_else(|| options.initial_state());
+        let mut values = Vec::new();
+        for _ in 0..amount {
+            match options.next(state) {
+                Some(next) => {
+                    state = next;
+                    values.push(next.last_value);
+                }
+                None => return Ok(None),
+            }
+        }
+        txn.set_sequence_state(id, state)?;
+        txn.commit_internal(commit_ts).await?;
+        Ok(Some(values))
+    }
+
+    /// Returns the state of the sequence identified by `id`, or `None` if the
+    /// sequence has never been advanced or set.
+    async fn get_sequence_state(
+        &mut self,
+        id: SequenceId,
+    ) -> Result<Option<SequenceState>, CatalogError> {
+        let txn = self.transaction().await?;
+        Ok(txn.get_sequence_state(id))
+    }
+
+    /// Overwrites the state of the sequence identified by `id`.
+    ///
+    /// See [`Self::commit_transaction`] for details on `commit_ts`.
+    async fn set_sequence_state(
+        &mut self,
+        id: SequenceId,
+        state: SequenceState,
+        commit_ts: Timestamp,
+    ) -> Result<(), CatalogError> {
+        let mut txn = self.transaction().await?;
+        txn.set_sequence_state(id, state)?;
+        txn.commit_internal(commit_ts).await?;
+        Ok(())
+    }
+
+    /// Returns the relations of the publication named `name`, or `None` if no
+    /// such publication exists.
+    async fn get_publication(
+        &mut self,
+        name: &str,
+    ) -> Result<Option<Vec<CatalogItemId>>, CatalogError> {
+        let txn = self.transaction().await?;
+        Ok(txn.get_publication(name))
+    }
+
+    /// Creates the publication named `name` over `relations`. Returns `false`
+    /// without changing anything if the publication already exists.
+    ///
+    /// See [`Self::commit_transaction`] for details on `commit_ts`.
+    async fn create_publication(
+        &mut self,
+        name: &str,
+        relations: &[CatalogItemId],
+        commit_ts: Timestamp,
+    ) -> Result<bool, CatalogError> {
+        let mut txn = self.transaction().await?;
+        if txn.get_publication(name).is_some() {
+            return Ok(false);
+        }
+        txn.set_publication(name, Some(relations))?;
+        txn.commit_internal(commit_ts).await?;
+        Ok(true)
+    }
+
+    /// Drops the publication named `name`. Returns `false` if the publication
+    /// does not exist.
+    ///
+    /// See [`Self::commit_transaction`] for details on `commit_ts`.
+    async fn drop_publication(
+        &mut self,
+        name: &str,
+        commit_ts: Timestamp,
+    ) -> Result<bool, CatalogError> {
+        let mut txn = self.transaction().await?;
+        if txn.get_publication(name).is_none() {
+            return Ok(false);
+        }
+        txn.set_publication(name, None)?;
+        txn.commit_internal(commit_ts).await?;
+        Ok(true)
+    }
+
+    /// Returns the state of the replication slot named `name`, or `None` if no
+    /// such slot exists.
+    async fn get_replication_slot(
+        &mut self,
+        name: &str,
+    ) -> Result<Option<ReplicationSlot>, CatalogError> {
+        let txn = self.transaction().await?;
+        Ok(txn.get_replication_slot(name))
+    }
+
+    /// Returns the state of every replication slot, by slot name.
+    async fn get_replication_slots(
+        &mut self,
+    ) -> Result<BTreeMap<String, ReplicationSlot>, CatalogError> {
+        let txn = self.transaction().await?;
+        Ok(txn.get_replication_slots())
+    }
+
+    /// Records `relations` as the relations streamed from the replication slot
+    /// named `name`. Returns `false` if the slot does not exist.
+    ///
+    /// See [`Self::commit_transaction`] for details on `commit_ts`.
+    async fn set_replication_slot_relations(
+        &mut self,
+        name: &str,
+        relations: &[CatalogItemId],
+        commit_ts: Timestamp,
+    ) -> Result<bool, CatalogError> {
+        let mut txn = self.transaction().await?;
+        let Some(mut slot) = txn.get_replication_slot(name) else {
+            return Ok(false);
+        };
+        if slot.relations != relations {
+            slot.relations = relations.to_vec();
+            txn.set_replication_slot(name, Some(slot))?;
+            txn.commit_internal(commit_ts).await?;
+        }
+        Ok(true)
+    }
+
+    /// Creates the replication slot named `name` with state `slot`. Returns
+    /// `false` without changing anything if the slot already exists.
+    ///
+    /// See [`Self::commit_transaction`] for details on `commit_ts`.
+    async fn create_replication_slot(
+        &mut self,
+        name: &str,
+        slot: ReplicationSlot,
+        commit_ts: Timestamp,
+    ) -> Result<bool, CatalogError> {
+        let mut txn = self.transaction().await?;
+        if txn.get_replication_slot(name).is_some() {
+            return Ok(false);
+        }
+        txn.set_replication_slot(name, Some(slot))?;
+        txn.commit_internal(commit_ts).await?;
+        Ok(true)
+    }
+
+    /// Advances the confirmed flush position of the replication slot named
+    /// `name` to `confirmed_flush`. The position never regresses. Returns
+    /// `false` if the slot does not exist.
+    ///
+    /// See [`Self::commit_transaction`] for details on `commit_ts`.
+    async fn advance_replication_slot(
+        &mut self,
+        name: &str,
+        confirmed_flush: Timestamp,
+        commit_ts: Timestamp,
+    ) -> Result<bool, CatalogError> {
+        let mut txn = self.transaction().await?;
+        let Some(mut slot) = txn.get_replication_slot(name) else {
+            return Ok(false);
+        };
+        if confirmed_flush > slot.confirmed_flush {
+            slot.confirmed_flush = confirmed_flush;
+            txn.set_replication_slot(name, Some(slot))?;
+            txn.commit_internal(commit_ts).await?;
+        }
+        Ok(true)
+    }
+
+    /// Drops the replication slot named `name`. Returns `false` if the slot
+    /// does not exist.
+    ///
+    /// See [`Self::commit_transaction`] for details on `commit_ts`.
+    async fn drop_replication_slot(
+        &mut self,
+        name: &str,
+        commit_ts: Timestamp,
+    ) -> Result<bool, CatalogError> {
+        let mut txn = self.transaction().await?;
+        if txn.get_replication_slot(name).is_none() {
+            return Ok(false);
+        }
+        txn.set_replication_slot(name, None)?;
+        txn.commit_internal(commit_ts).await?;
+        Ok(true)
+    }
+
     /// Allocates and returns a user [`ClusterId`].
     ///
     /// See [`Self::commit_transaction`] for details on `commit_ts`.
@@ -392,6 +392,8 @@ pub(crate) async fn initialize(
             ObjectType::Cluster => mz_audit_log::ObjectType::Cluster,
             ObjectType::ClusterReplica => mz_audit_log::ObjectType::ClusterReplica,
             ObjectType::Secret => mz_audit_log::ObjectType::Secret,
+            ObjectType::Sequence => mz_audit_log::ObjectType::Table,
+            ObjectType::Policy => mz_audit_log::ObjectType::Table,
             ObjectType::Connection => mz_audit_log::ObjectType::Connection,
             ObjectType::Database => mz_audit_log::ObjectType::Database,
             ObjectType::Schema => mz_audit_log::ObjectType::Schema,
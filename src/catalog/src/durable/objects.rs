@@ -964,6 +964,18 @@ pub struct Setting {
     pub value: String,
 }
 
+/// The durable state of a logical replication slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplicationSlot {
+    /// The timestamp through which the slot's consumer has confirmed that it
+    /// durably received all changes. Streaming from the slot resumes just
+    /// after it.
+    pub confirmed_flush: mz_repr::Timestamp,
+    /// The relations that were last streamed from the slot, whose compaction
+    /// the slot holds back to `confirmed_flush`.
+    pub relations: Vec<CatalogItemId>,
+}
+
 impl DurableType for Setting {
     type Key = SettingKey;
     type Value = SettingValue;
@@ -1357,6 +1369,8 @@ pub fn item_type(create_sql: &str) -> CatalogItemType {
         Some("FUNCTION") => CatalogItemType::Func,
         Some("SECRET") => CatalogItemType::Secret,
         Some("CONNECTION") => CatalogItemType::Connection,
+        Some("SEQUENCE") => CatalogItemType::Sequence,
+        Some("POLICY") => CatalogItemType::Policy,
         _ => panic!("unexpected create sql: {}", create_sql),
     }
 }
@@ -35,7 +35,7 @@ use mz_sql::catalog::{
     RoleAttributesRaw, RoleMembership, RoleVars,
 };
 use mz_sql::names::{CommentObjectId, DatabaseId, ResolvedDatabaseSpecifier, SchemaId};
-use mz_sql::plan::NetworkPolicyRule;
+use mz_sql::plan::{NetworkPolicyRule, SequenceId, SequenceState};
 use mz_sql_parser::ast::QualifiedReplica;
 use mz_storage_client::controller::StorageTxn;
 use mz_storage_types::controller::StorageError;
@@ -64,7 +64,8 @@ use crate::durable::{
     AUDIT_LOG_ID_ALLOC_KEY, BUILTIN_MIGRATION_SHARD_KEY, CATALOG_CONTENT_VERSION_KEY, CatalogError,
     DATABASE_ID_ALLOC_KEY, DefaultPrivilege, DurableCatalogError, DurableCatalogState,
     EXPRESSION_CACHE_SHARD_KEY, MOCK_AUTHENTICATION_NONCE_KEY, NetworkPolicy, OID_ALLOC_KEY,
-    SCHEMA_ID_ALLOC_KEY, STORAGE_USAGE_ID_ALLOC_KEY, SYSTEM_CLUSTER_ID_ALLOC_KEY,
+    PUBLICATION_KEY_PREFIX, REPLICATION_SLOT_KEY_PREFIX, ReplicationSlot, SCHEMA_ID_ALLOC_KEY,
+    SEQUENCE_STATE_KEY_PREFIX, STORAGE_USAGE_ID_ALLOC_KEY, SYSTEM_CLUSTER_ID_ALLOC_KEY,
     SYSTEM_ITEM_ALLOC_KEY, SYSTEM_REPLICA_ID_ALLOC_KEY, Snapshot, SystemConfiguration,
     USER_ITEM_ALLOC_KEY, USER_NETWORK_POLICY_ID_ALLOC_KEY, USER_REPLICA_ID_ALLOC_KEY,
     USER_ROLE_ID_ALLOC_KEY,
@@ -2001,6 +2002,118 @@ impl<'a> Transaction<'a> {
         )
     }
 
+    /// Returns the durable state of the sequence identified by `id`, or `None`
+    /// if the sequence has never been advanced or set.
+    pub fn get_sequence_state(&self, id: SequenceId) -> Option<SequenceState> {
+        let value = self.get_setting(format!("{SEQUENCE_STATE_KEY_PREFIX}{id}"))?;
+        let (last_value, is_called) = value.split_once(',').expect("valid sequence state");
+        Some(SequenceState {
+            last_value: last_value.parse().expect("valid sequence value"),
+            is_called: is_called.parse().expect("valid sequence is_called"),
+        })
+    }
+
+    /// Durably records `state` as the state of the sequence identified by `id`.
+    pub fn set_sequence_state(
+        &mut self,
+        id: SequenceId,
+        state: SequenceState,
+    ) -> Result<(), CatalogError> {
+        self.set_setting(
+            format!("{SEQUENCE_STATE_KEY_PREFIX}{id}"),
+            Some(format!("{},{}", state.last_value, state.is_called)),
+        )
+    }
+
+    /// Removes the state of every sequence owned by the catalog item `id`,
+    /// i.e. the item itself if it is a sequence, or its identity columns if it
+    /// is a table.
+    pub fn remove_sequence_states(&mut self, id: CatalogItemId) {
+        let key = format!("{SEQUENCE_STATE_KEY_PREFIX}{id}");
+        let column_prefix = format!("{key}.");
+        self.settings.delete(
+            |k, _| k.name == key || k.name.starts_with(&column_prefix),
+            self.op_id,
+        );
+    }
+
+    /// Returns the relations of the publication named `name`, or `None` if no
+    /// such publication exists.
+    pub fn get_publication(&self, name: &str) -> Option<Vec<CatalogItemId>> {
+        let value = self.get_setting(format!("{PUBLICATION_KEY_PREFIX}{name}"))?;
+        Some(parse_publication_relations(value))
+    }
+
+    /// Durably records `relations` as the relations of the publication named
+    /// `name`, or removes the publication if `relations` is `None`.
+    pub fn set_publication(
+        &mut self,
+        name: &str,
+        relations: Option<&[CatalogItemId]>,
+    ) -> Result<(), CatalogError> {
+        self.set_setting(
+            format!("{PUBLICATION_KEY_PREFIX}{name}"),
+            relations.map(|relations| relations.iter().join(",")),
+        )
+    }
+
+    /// Removes the catalog item `id` from every publication that includes it.
+    pub fn remove_from_publications(&mut self, id: CatalogItemId) -> Result<(), CatalogError> {
+        self.settings.update(
+            |k, v| {
+                if !k.name.starts_with(PUBLICATION_KEY_PREFIX) {
+                    return None;
+                }
+                let relations = parse_publication_relations(&v.value);
+                if !relations.contains(&id) {
+                    return None;
+                }
+                let value = relations.into_iter().filter(|r| *r != id).join(",");
+                Some(SettingValue { value })
+            },
+            self.op_id,
+        )?;
+        Ok(())
+    }
+
+    /// Returns the durable state of the replication slot named `name`, or
+    /// `None` if no such slot exists.
+    pub fn get_replication_slot(&self, name: &str) -> Option<ReplicationSlot> {
+        let value = self.get_setting(format!("{REPLICATION_SLOT_KEY_PREFIX}{name}"))?;
+        Some(parse_replication_slot(value))
+    }
+
+    /// Returns the durable state of every replication slot, by slot name.
+    pub fn get_replication_slots(&self) -> BTreeMap<String, ReplicationSlot> {
+        self.settings
+            .items()
+            .into_iter()
+            .filter_map(|(k, v)| {
+                let name = k.name.strip_prefix(REPLICATION_SLOT_KEY_PREFIX)?;
+                Some((name.to_string(), parse_replication_slot(&v.value)))
+            })
+            .collect()
+    }
+
+    /// Durably records `slot` as the state of the replication slot named
+    /// `name`, or removes the slot if `slot` is `None`.
+    pub fn set_replication_slot(
+        &mut self,
+        name: &str,
+        slot: Option<ReplicationSlot>,
+    ) -> Result<(), CatalogError> {
+        self.set_setting(
+            format!("{REPLICATION_SLOT_KEY_PREFIX}{name}"),
+            slot.map(|slot| {
+                format!(
+                    "{};{}",
+                    slot.confirmed_flush,
+                    slot.relations.iter().join(",")
+                )
+            }),
+        )
+    }
+
     /// Updates the catalog `with_0dt_deployment_max_wait` "config" value to
     /// match the `with_0dt_deployment_max_wait` "system var" value.
     ///
@@ -2622,6 +2735,29 @@ use crate::durable::async_trait;
 
 use super::objects::{RoleAuthKey, RoleAuthValue};
 
+/// Parses the state of a replication slot, as stored by
+/// [`Transaction::set_replication_slot`]. Slots created before their relations
+/// were recorded only store the confirmed flush position.
+fn parse_replication_slot(value: &str) -> ReplicationSlot {
+    let (confirmed_flush, relations) = value.split_once(';').unwrap_or((value, ""));
+    ReplicationSlot {
+        confirmed_flush: confirmed_flush
+            .parse()
+            .expect("valid replication slot state"),
+        relations: parse_publication_relations(relations),
+    }
+}
+
+/// Parses the relations of a publication, as stored by
+/// [`Transaction::set_publication`].
+fn parse_publication_relations(value: &str) -> Vec<CatalogItemId> {
+    value
+        .split(',')
+        .filter(|id| !id.is_empty())
+        .map(|id| id.parse().expect("valid publication relation"))
+        .collect()
+}
+
 #[async_trait]
 impl StorageTxn<mz_repr::Timestamp> for Transaction<'_> {
     fn get_collection_metadata(&self) -> BTreeMap<GlobalId, ShardId> {
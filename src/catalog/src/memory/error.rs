@@ -112,6 +112,33 @@ pub enum ErrorKind {
     InvalidClusterReplicaSize { size: String, expected: Vec<String> },
     #[error("failed to get catalog setting: {0}")]
     SettingError(String),
+    #[error("nextval: reached {bound} value of sequence {} ({limit})", .name.quoted())]
+    SequenceExhausted {
+        name: String,
+        /// Either "maximum" or "minimum".
+        bound: &'static str,
+        limit: i64,
+    },
+    #[error(
+        "setval: value {value} is out of bounds for sequence {} ({min}..{max})",
+        .name.quoted()
+    )]
+    SequenceValueOutOfBounds {
+        name: String,
+        value: i64,
+        min: i64,
+        max: i64,
+    },
+    #[error("currval of sequence {} is not yet defined", .name.quoted())]
+    SequenceValueUndefined { name: String },
+    #[error("publication {} already exists", .0.quoted())]
+    PublicationAlreadyExists(String),
+    #[error("publication {} does not exist", .0.quoted())]
+    UnknownPublication(String),
+    #[error("replication slot {} already exists", .0.quoted())]
+    ReplicationSlotAlreadyExists(String),
+    #[error("replication slot {} does not exist", .0.quoted())]
+    UnknownReplicationSlot(String),
     #[error("internal error: {0}")]
     Internal(String),
 }
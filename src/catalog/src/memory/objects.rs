@@ -40,10 +40,11 @@ use mz_sql::ast::{
     UnresolvedItemName, Value, WithOptionValue,
 };
 use mz_sql::catalog::{
-    CatalogClusterReplica, CatalogError as SqlCatalogError, CatalogItem as SqlCatalogItem,
-    CatalogItemType as SqlCatalogItemType, CatalogItemType, CatalogSchema, CatalogType,
-    CatalogTypeDetails, DefaultPrivilegeAclItem, DefaultPrivilegeObject, IdReference,
-    RoleAttributes, RoleMembership, RoleVars, SystemObjectType,
+    CatalogClusterReplica, CatalogError as SqlCatalogError, CatalogFuncDetails,
+    CatalogItem as SqlCatalogItem, CatalogItemType as SqlCatalogItemType, CatalogItemType,
+    CatalogPolicyDetails, CatalogSchema, CatalogType, CatalogTypeDetails, DefaultPrivilegeAclItem,
+    DefaultPrivilegeObject, IdReference, RoleAttributes, RoleMembership, RoleVars,
+    SystemObjectType,
 };
 use mz_sql::names::{
     Aug, CommentObjectId, DatabaseId, DependencyIds, FullItemName, QualifiedItemName,
@@ -52,8 +53,8 @@ use mz_sql::names::{
 use mz_sql::plan::{
     ClusterSchedule, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig, ConnectionDetails,
     CreateClusterManagedPlan, CreateClusterPlan, CreateClusterVariant, CreateSourcePlan,
-    HirRelationExpr, NetworkPolicyRule, PlanError, WebhookBodyFormat, WebhookHeaders,
-    WebhookValidation,
+    HirRelationExpr, IdentityColumn, NetworkPolicyRule, PlanError, SequenceOptions,
+    WebhookBodyFormat, WebhookHeaders, WebhookValidation,
 };
 use mz_sql::rbac;
 use mz_sql::session::vars::OwnedVarInput;
@@ -750,14 +751,38 @@ impl mz_sql::catalog::CatalogItem for CatalogCollectionEntry {
         self.entry.writable_table_details()
     }
 
+    fn identity_columns(&self) -> &[IdentityColumn] {
+        self.entry.identity_columns()
+    }
+
     fn replacement_target(&self) -> Option<CatalogItemId> {
         self.entry.replacement_target()
     }
 
+    fn notify_channel(&self) -> Option<&str> {
+        self.entry.notify_channel()
+    }
+
     fn type_details(&self) -> Option<&CatalogTypeDetails<IdReference>> {
         self.entry.type_details()
     }
 
+    fn func_details(&self) -> Option<&CatalogFuncDetails> {
+        self.entry.func_details()
+    }
+
+    fn sequence_details(&self) -> Option<&SequenceOptions> {
+        self.entry.sequence_details()
+    }
+
+    fn policy_details(&self) -> Option<&CatalogPolicyDetails> {
+        self.entry.policy_details()
+    }
+
+    fn row_level_security(&self) -> bool {
+        self.entry.row_level_security()
+    }
+
     fn references(&self) -> &ResolvedIds {
         self.entry.references()
     }
@@ -840,6 +865,8 @@ pub enum CatalogItem {
     Secret(Secret),
     Connection(Connection),
     ContinualTask(ContinualTask),
+    Sequence(Sequence),
+    Policy(Policy),
 }
 
 impl From<CatalogEntry> for durable::Item {
@@ -882,6 +909,9 @@ pub struct Table {
     pub is_retained_metrics_object: bool,
     /// Where data for this table comes from, e.g. `INSERT` statements or an upstream source.
     pub data_source: TableDataSource,
+    /// Whether row-level security policies restrict the rows of this table, e.g. set via
+    /// `ALTER TABLE ... ENABLE ROW LEVEL SECURITY`.
+    pub row_level_security: bool,
 }
 
 impl Table {
@@ -938,6 +968,8 @@ pub enum TableDataSource {
     TableWrites {
         #[serde(skip)]
         defaults: Vec<Expr<Aug>>,
+        /// The columns declared `GENERATED ... AS IDENTITY`.
+        identity_columns: Vec<IdentityColumn>,
     },
 
     /// The table receives its data from the identified `DataSourceDesc`.
@@ -1322,6 +1354,8 @@ impl Sink {
         match &self.envelope {
             SinkEnvelope::Debezium => Some("debezium"),
             SinkEnvelope::Upsert => Some("upsert"),
+            SinkEnvelope::Append => Some("append"),
+            SinkEnvelope::Changelog => Some("changelog"),
         }
     }
 
@@ -1332,7 +1366,12 @@ impl Sink {
     pub fn combined_format(&self) -> Option<Cow<'_, str>> {
         match &self.connection {
             StorageSinkConnection::Kafka(connection) => Some(connection.format.get_format_name()),
-            StorageSinkConnection::Iceberg(_) => None,
+            StorageSinkConnection::S3(connection) => Some(Cow::Borrowed(connection.format.name())),
+            StorageSinkConnection::Http(_) => Some(Cow::Borrowed("json")),
+            StorageSinkConnection::Iceberg(_)
+            | StorageSinkConnection::Postgres(_)
+            | StorageSinkConnection::MySql(_)
+            | StorageSinkConnection::SqlServer(_) => None,
         }
     }
 
@@ -1348,7 +1387,15 @@ impl Sink {
                 let value_format = connection.format.value_format.get_format_name();
                 Some((key_format, value_format))
             }
-            StorageSinkConnection::Iceberg(_) => None,
+            StorageSinkConnection::S3(connection) => Some((None, connection.format.name())),
+            StorageSinkConnection::Http(connection) => {
+                let key_format = connection.key_desc_and_indices.as_ref().map(|_| "json");
+                Some((key_format, "json"))
+            }
+            StorageSinkConnection::Iceberg(_)
+            | StorageSinkConnection::Postgres(_)
+            | StorageSinkConnection::MySql(_)
+            | StorageSinkConnection::SqlServer(_) => None,
         }
     }
 
@@ -1380,6 +1427,9 @@ pub struct View {
     pub resolved_ids: ResolvedIds,
     /// All of the catalog objects that are referenced by this view.
     pub dependencies: DependencyIds,
+    /// Whether row-level security policies restrict the rows of this view, e.g. set via
+    /// `ALTER VIEW ... ENABLE ROW LEVEL SECURITY`.
+    pub row_level_security: bool,
 }
 
 impl View {
@@ -1425,6 +1475,12 @@ pub struct MaterializedView {
     /// Note: This doesn't change upon restarts.
     /// (The dataflow's initial `as_of` can be different.)
     pub initial_as_of: Option<Antichain<mz_repr::Timestamp>>,
+    /// Channel on which changes are announced to `LISTEN`ing sessions, e.g. set via the
+    /// `NOTIFY CHANNEL` option.
+    pub notify_channel: Option<String>,
+    /// Whether row-level security policies restrict the rows of this materialized view, e.g.
+    /// set via `ALTER MATERIALIZED VIEW ... ENABLE ROW LEVEL SECURITY`.
+    pub row_level_security: bool,
 }
 
 impl MaterializedView {
@@ -1526,6 +1582,8 @@ impl MaterializedView {
             custom_logical_compaction_window: replacement.custom_logical_compaction_window,
             refresh_schedule: replacement.refresh_schedule,
             initial_as_of: replacement.initial_as_of,
+            notify_channel: replacement.notify_channel,
+            row_level_security: replacement.row_level_security,
         };
     }
 }
@@ -1576,11 +1634,24 @@ pub struct Type {
 
 #[derive(Debug, Clone, Serialize)]
 pub struct Func {
-    /// Static definition of the function.
+    /// Parse-able SQL that defines this function, if it is user-defined.
+    pub create_sql: Option<String>,
+    /// Definition of the function.
     #[serde(skip)]
-    pub inner: &'static mz_sql::func::Func,
+    pub inner: FuncInner,
     /// [`GlobalId`] used to reference this function from outside the catalog.
     pub global_id: GlobalId,
+    /// Other catalog objects referenced by this function.
+    pub resolved_ids: ResolvedIds,
+}
+
+/// The definition of a [`Func`].
+#[derive(Debug, Clone)]
+pub enum FuncInner {
+    /// A built-in function, which has a static definition.
+    Builtin(&'static mz_sql::func::Func),
+    /// A user-defined function, whose body is written in SQL.
+    Sql(CatalogFuncDetails),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1591,6 +1662,29 @@ pub struct Secret {
     pub global_id: GlobalId,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct Sequence {
+    /// Parse-able SQL that defines this sequence.
+    pub create_sql: String,
+    /// [`GlobalId`] used to reference this sequence from outside the catalog.
+    pub global_id: GlobalId,
+    /// The options of this sequence, derived from the `create_sql`.
+    pub options: SequenceOptions,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Policy {
+    /// Parse-able SQL that defines this policy.
+    pub create_sql: String,
+    /// [`GlobalId`] used to reference this policy from outside the catalog.
+    pub global_id: GlobalId,
+    /// The relation, roles and predicate of this policy, derived from the `create_sql`.
+    #[serde(skip)]
+    pub details: CatalogPolicyDetails,
+    /// Other catalog objects referenced by this policy, including the relation it applies to.
+    pub resolved_ids: ResolvedIds,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct Connection {
     /// Parse-able SQL that defines this connection.
@@ -1723,6 +1817,8 @@ impl CatalogItem {
             CatalogItem::Type(_) => CatalogItemType::Type,
             CatalogItem::Func(_) => CatalogItemType::Func,
             CatalogItem::Secret(_) => CatalogItemType::Secret,
+            CatalogItem::Sequence(_) => CatalogItemType::Sequence,
+            CatalogItem::Policy(_) => CatalogItemType::Policy,
             CatalogItem::Connection(_) => CatalogItemType::Connection,
             CatalogItem::ContinualTask(_) => CatalogItemType::ContinualTask,
         }
@@ -1743,6 +1839,8 @@ impl CatalogItem {
             CatalogItem::Func(func) => func.global_id,
             CatalogItem::Type(ty) => ty.global_id,
             CatalogItem::Secret(secret) => secret.global_id,
+            CatalogItem::Sequence(sequence) => sequence.global_id,
+            CatalogItem::Policy(policy) => policy.global_id,
             CatalogItem::Connection(conn) => conn.global_id,
             CatalogItem::Table(table) => {
                 return itertools::Either::Left(table.collections.values().copied());
@@ -1766,6 +1864,8 @@ impl CatalogItem {
             CatalogItem::Func(func) => func.global_id,
             CatalogItem::Type(ty) => ty.global_id,
             CatalogItem::Secret(secret) => secret.global_id,
+            CatalogItem::Sequence(sequence) => sequence.global_id,
+            CatalogItem::Policy(policy) => policy.global_id,
             CatalogItem::Connection(conn) => conn.global_id,
             CatalogItem::Table(table) => table.global_id_writes(),
         }
@@ -1785,6 +1885,8 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Connection(_) => false,
         }
     }
@@ -1811,6 +1913,8 @@ impl CatalogItem {
             | CatalogItem::Index(_)
             | CatalogItem::Sink(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Connection(_)
             | CatalogItem::Type(_) => None,
         }
@@ -1821,7 +1925,10 @@ impl CatalogItem {
         entry: &CatalogEntry,
     ) -> Result<&'static mz_sql::func::Func, SqlCatalogError> {
         match &self {
-            CatalogItem::Func(func) => Ok(func.inner),
+            CatalogItem::Func(Func {
+                inner: FuncInner::Builtin(func),
+                ..
+            }) => Ok(func),
             _ => Err(SqlCatalogError::UnexpectedType {
                 name: entry.name().item.to_string(),
                 actual_type: entry.item_type(),
@@ -1830,6 +1937,17 @@ impl CatalogItem {
         }
     }
 
+    /// Returns the definition of this item if it is a user-defined function.
+    pub fn func_details(&self) -> Option<&CatalogFuncDetails> {
+        match &self {
+            CatalogItem::Func(Func {
+                inner: FuncInner::Sql(details),
+                ..
+            }) => Some(details),
+            _ => None,
+        }
+    }
+
     pub fn source_desc(
         &self,
         entry: &CatalogEntry,
@@ -1868,7 +1986,7 @@ impl CatalogItem {
     pub fn references(&self) -> &ResolvedIds {
         static EMPTY: LazyLock<ResolvedIds> = LazyLock::new(ResolvedIds::empty);
         match self {
-            CatalogItem::Func(_) => &*EMPTY,
+            CatalogItem::Func(func) => &func.resolved_ids,
             CatalogItem::Index(idx) => &idx.resolved_ids,
             CatalogItem::Sink(sink) => &sink.resolved_ids,
             CatalogItem::Source(source) => &source.resolved_ids,
@@ -1878,6 +1996,8 @@ impl CatalogItem {
             CatalogItem::View(view) => &view.resolved_ids,
             CatalogItem::MaterializedView(mview) => &mview.resolved_ids,
             CatalogItem::Secret(_) => &*EMPTY,
+            CatalogItem::Sequence(_) => &*EMPTY,
+            CatalogItem::Policy(policy) => &policy.resolved_ids,
             CatalogItem::Connection(connection) => &connection.resolved_ids,
             CatalogItem::ContinualTask(ct) => &ct.resolved_ids,
         }
@@ -1891,8 +2011,6 @@ impl CatalogItem {
     pub fn uses(&self) -> BTreeSet<CatalogItemId> {
         let mut uses: BTreeSet<_> = self.references().items().copied().collect();
         match self {
-            // TODO(jkosh44) This isn't really correct for functions. They may use other objects in
-            // their implementation. However, currently there's no way to get that information.
             CatalogItem::Func(_) => {}
             CatalogItem::Index(_) => {}
             CatalogItem::Sink(_) => {}
@@ -1906,6 +2024,8 @@ impl CatalogItem {
             }
             CatalogItem::ContinualTask(ct) => uses.extend(ct.dependencies.0.iter().copied()),
             CatalogItem::Secret(_) => {}
+            CatalogItem::Sequence(_) => {}
+            CatalogItem::Policy(_) => {}
             CatalogItem::Connection(_) => {}
         }
         uses
@@ -1923,6 +2043,8 @@ impl CatalogItem {
             | CatalogItem::Sink(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Connection(_)
@@ -1942,6 +2064,8 @@ impl CatalogItem {
             | CatalogItem::Sink(_)
             | CatalogItem::MaterializedView(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Connection(_)
@@ -2014,6 +2138,16 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Secret(i))
             }
+            CatalogItem::Sequence(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Sequence(i))
+            }
+            CatalogItem::Policy(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Policy(i))
+            }
             CatalogItem::Connection(i) => {
                 let mut i = i.clone();
                 i.create_sql = do_rewrite(i.create_sql)?;
@@ -2092,7 +2226,22 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql)?;
                 Ok(CatalogItem::Secret(i))
             }
-            CatalogItem::Func(_) | CatalogItem::Type(_) => {
+            CatalogItem::Sequence(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Sequence(i))
+            }
+            CatalogItem::Policy(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql)?;
+                Ok(CatalogItem::Policy(i))
+            }
+            CatalogItem::Func(i) => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite).transpose()?;
+                Ok(CatalogItem::Func(i))
+            }
+            CatalogItem::Type(_) => {
                 unreachable!("{}s cannot be renamed", self.typ())
             }
             CatalogItem::Connection(i) => {
@@ -2159,7 +2308,22 @@ impl CatalogItem {
                 i.create_sql = do_rewrite(i.create_sql);
                 CatalogItem::Secret(i)
             }
-            CatalogItem::Func(_) | CatalogItem::Type(_) => {
+            CatalogItem::Sequence(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql);
+                CatalogItem::Sequence(i)
+            }
+            CatalogItem::Policy(i) => {
+                let mut i = i.clone();
+                i.create_sql = do_rewrite(i.create_sql);
+                CatalogItem::Policy(i)
+            }
+            CatalogItem::Func(i) => {
+                let mut i = i.clone();
+                i.create_sql = i.create_sql.map(do_rewrite);
+                CatalogItem::Func(i)
+            }
+            CatalogItem::Type(_) => {
                 unreachable!("references of {}s cannot be replaced", self.typ())
             }
             CatalogItem::Connection(i) => {
@@ -2292,6 +2456,71 @@ impl CatalogItem {
         }
     }
 
+    /// Enables or disables row-level security on a table, view or materialized view. Returns an
+    /// error if this item does not support row-level security.
+    pub fn update_row_level_security(&mut self, enabled: bool) -> Result<(), ()> {
+        let update = |ast: &mut Statement<Raw>| {
+            // Each statement type has unique option types. This macro handles them commonly.
+            macro_rules! update_row_level_security {
+                ( $options:expr, $opt:ident, $name:ident ) => {{
+                    $options.retain(|o| o.name != mz_sql_parser::ast::$name::RowLevelSecurity);
+                    if enabled {
+                        $options.push(mz_sql_parser::ast::$opt {
+                            name: mz_sql_parser::ast::$name::RowLevelSecurity,
+                            value: None,
+                        });
+                    }
+                }};
+            }
+            match ast {
+                Statement::CreateTable(stmt) => {
+                    update_row_level_security!(stmt.with_options, TableOption, TableOptionName)
+                }
+                Statement::CreateView(stmt) => update_row_level_security!(
+                    stmt.definition.with_options,
+                    ViewOption,
+                    ViewOptionName
+                ),
+                Statement::CreateMaterializedView(stmt) => update_row_level_security!(
+                    stmt.with_options,
+                    MaterializedViewOption,
+                    MaterializedViewOptionName
+                ),
+                _ => return Err(()),
+            }
+            Ok(())
+        };
+
+        self.update_sql(update)?;
+        match self {
+            CatalogItem::Table(table) => table.row_level_security = enabled,
+            CatalogItem::View(view) => view.row_level_security = enabled,
+            CatalogItem::MaterializedView(mv) => mv.row_level_security = enabled,
+            _ => return Err(()),
+        }
+        Ok(())
+    }
+
+    /// Reports whether row-level security policies restrict the rows of this item.
+    pub fn row_level_security(&self) -> bool {
+        match self {
+            CatalogItem::Table(table) => table.row_level_security,
+            CatalogItem::View(view) => view.row_level_security,
+            CatalogItem::MaterializedView(mv) => mv.row_level_security,
+            CatalogItem::Log(_)
+            | CatalogItem::Source(_)
+            | CatalogItem::Sink(_)
+            | CatalogItem::Index(_)
+            | CatalogItem::Type(_)
+            | CatalogItem::Func(_)
+            | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
+            | CatalogItem::Connection(_)
+            | CatalogItem::ContinualTask(_) => false,
+        }
+    }
+
     pub fn add_column(
         &mut self,
         name: ColumnName,
@@ -2332,6 +2561,63 @@ impl CatalogItem {
         Ok(next_version)
     }
 
+    pub fn drop_column(&mut self, name: ColumnName) -> Result<RelationVersion, PlanError> {
+        let CatalogItem::Table(table) = self else {
+            return Err(PlanError::Unsupported {
+                feature: "dropping columns from a non-Table".to_string(),
+                discussion_no: None,
+            });
+        };
+        let next_version = table.desc.drop_column(name.clone());
+
+        let update = |ast: &mut Statement<Raw>| {
+            let column = live_column_def(ast, &name)?;
+            column.options.push(ColumnOptionDef {
+                name: None,
+                option: ColumnOption::Versioned {
+                    action: ColumnVersioned::Dropped,
+                    version: next_version.into(),
+                },
+            });
+            Ok(())
+        };
+
+        self.update_sql(update)
+            .map_err(|()| PlanError::Unstructured("expected CREATE TABLE statement".to_string()))?;
+        Ok(next_version)
+    }
+
+    pub fn rename_column(
+        &mut self,
+        name: ColumnName,
+        new_name: ColumnName,
+    ) -> Result<RelationVersion, PlanError> {
+        let CatalogItem::Table(table) = self else {
+            return Err(PlanError::Unsupported {
+                feature: "renaming columns of a non-Table".to_string(),
+                discussion_no: None,
+            });
+        };
+        let next_version = table.desc.rename_column(name.clone(), new_name.clone());
+
+        let update = |ast: &mut Statement<Raw>| {
+            let column = live_column_def(ast, &name)?;
+            let from = std::mem::replace(&mut column.name, new_name.into());
+            column.options.push(ColumnOptionDef {
+                name: None,
+                option: ColumnOption::Versioned {
+                    action: ColumnVersioned::Renamed { from },
+                    version: next_version.into(),
+                },
+            });
+            Ok(())
+        };
+
+        self.update_sql(update)
+            .map_err(|()| PlanError::Unstructured("expected CREATE TABLE statement".to_string()))?;
+        Ok(next_version)
+    }
+
     /// Updates the create_sql field of this item. Returns an error if this is a builtin item,
     /// otherwise returns f's result.
     pub fn update_sql<F, T>(&mut self, f: F) -> Result<T, ()>
@@ -2347,6 +2633,8 @@ impl CatalogItem {
             | CatalogItem::MaterializedView(MaterializedView { create_sql, .. })
             | CatalogItem::Index(Index { create_sql, .. })
             | CatalogItem::Secret(Secret { create_sql, .. })
+            | CatalogItem::Sequence(Sequence { create_sql, .. })
+            | CatalogItem::Policy(Policy { create_sql, .. })
             | CatalogItem::Connection(Connection { create_sql, .. })
             | CatalogItem::ContinualTask(ContinualTask { create_sql, .. }) => Some(create_sql),
             CatalogItem::Func(_) | CatalogItem::Log(_) => None,
@@ -2383,6 +2671,8 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -2412,6 +2702,8 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Connection(_) => None,
         }
     }
@@ -2430,6 +2722,8 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -2452,6 +2746,8 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => return None,
         };
@@ -2478,6 +2774,8 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Connection(_) => return None,
         };
         Some(custom_logical_compaction_window.unwrap_or(CompactionWindow::Default))
@@ -2498,6 +2796,8 @@ impl CatalogItem {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => false,
         }
@@ -2550,12 +2850,26 @@ impl CatalogItem {
             CatalogItem::Secret(secret) => {
                 (secret.create_sql.clone(), secret.global_id, BTreeMap::new())
             }
+            CatalogItem::Sequence(sequence) => (
+                sequence.create_sql.clone(),
+                sequence.global_id,
+                BTreeMap::new(),
+            ),
+            CatalogItem::Policy(policy) => {
+                (policy.create_sql.clone(), policy.global_id, BTreeMap::new())
+            }
             CatalogItem::Connection(connection) => (
                 connection.create_sql.clone(),
                 connection.global_id,
                 BTreeMap::new(),
             ),
-            CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
+            CatalogItem::Func(func) => (
+                func.create_sql
+                    .clone()
+                    .expect("builtin functions cannot be serialized"),
+                func.global_id,
+                BTreeMap::new(),
+            ),
             CatalogItem::ContinualTask(ct) => {
                 (ct.create_sql.clone(), ct.global_id, BTreeMap::new())
             }
@@ -2600,10 +2914,19 @@ impl CatalogItem {
                 (create_sql, typ.global_id, BTreeMap::new())
             }
             CatalogItem::Secret(secret) => (secret.create_sql, secret.global_id, BTreeMap::new()),
+            CatalogItem::Sequence(sequence) => {
+                (sequence.create_sql, sequence.global_id, BTreeMap::new())
+            }
+            CatalogItem::Policy(policy) => (policy.create_sql, policy.global_id, BTreeMap::new()),
             CatalogItem::Connection(connection) => {
                 (connection.create_sql, connection.global_id, BTreeMap::new())
             }
-            CatalogItem::Func(_) => unreachable!("cannot serialize functions yet"),
+            CatalogItem::Func(func) => (
+                func.create_sql
+                    .expect("builtin functions cannot be serialized"),
+                func.global_id,
+                BTreeMap::new(),
+            ),
             CatalogItem::ContinualTask(ct) => (ct.create_sql, ct.global_id, BTreeMap::new()),
         }
     }
@@ -2622,6 +2945,8 @@ impl CatalogItem {
             CatalogItem::Type(ty) => return Some(ty.global_id),
             CatalogItem::Func(func) => return Some(func.global_id),
             CatalogItem::Secret(secret) => return Some(secret.global_id),
+            CatalogItem::Sequence(sequence) => return Some(sequence.global_id),
+            CatalogItem::Policy(policy) => return Some(policy.global_id),
             CatalogItem::Connection(conn) => return Some(conn.global_id),
             CatalogItem::ContinualTask(ct) => return Some(ct.global_id),
         };
@@ -2632,6 +2957,32 @@ impl CatalogItem {
     }
 }
 
+/// Returns the definition of the column `name` in the `CREATE TABLE` statement
+/// `ast`, skipping columns that have been dropped.
+fn live_column_def<'a>(
+    ast: &'a mut Statement<Raw>,
+    name: &ColumnName,
+) -> Result<&'a mut ColumnDef<Raw>, ()> {
+    let Statement::CreateTable(stmt) = ast else {
+        return Err(());
+    };
+    stmt.columns
+        .iter_mut()
+        .find(|column| {
+            let dropped = column.options.iter().any(|o| {
+                matches!(
+                    o.option,
+                    ColumnOption::Versioned {
+                        action: ColumnVersioned::Dropped,
+                        ..
+                    }
+                )
+            });
+            column.name.as_str() == name.as_str() && !dropped
+        })
+        .ok_or(())
+}
+
 impl CatalogEntry {
     /// Reports the latest [`RelationDesc`] of the rows produced by this [`CatalogEntry`], if it
     /// produces rows.
@@ -2828,6 +3179,8 @@ impl CatalogEntry {
             | CatalogItem::Type(_)
             | CatalogItem::Func(_)
             | CatalogItem::Secret(_)
+            | CatalogItem::Sequence(_)
+            | CatalogItem::Policy(_)
             | CatalogItem::Connection(_)
             | CatalogItem::ContinualTask(_) => None,
         }
@@ -2958,6 +3311,9 @@ impl CatalogEntry {
             Type => CommentObjectId::Type(self.id),
             Secret => CommentObjectId::Secret(self.id),
             ContinualTask => CommentObjectId::ContinualTask(self.id),
+            // Sequences and policies cannot be commented on, so they never
+            // have comments to look up. Their IDs never collide with tables'.
+            Sequence | Policy => CommentObjectId::Table(self.id),
         }
     }
 }
@@ -3375,7 +3731,7 @@ impl mz_sql::catalog::CatalogSchema for Schema {
     }
 
     fn has_items(&self) -> bool {
-        !self.items.is_empty()
+        !self.items.is_empty() || !self.functions.is_empty()
     }
 
     fn item_ids(&self) -> Box<dyn Iterator<Item = CatalogItemId> + '_> {
@@ -3567,8 +3923,12 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
                 create_sql.as_deref().unwrap_or("<builtin>")
             }
             CatalogItem::Secret(Secret { create_sql, .. }) => create_sql,
+            CatalogItem::Sequence(Sequence { create_sql, .. }) => create_sql,
+            CatalogItem::Policy(Policy { create_sql, .. }) => create_sql,
             CatalogItem::Connection(Connection { create_sql, .. }) => create_sql,
-            CatalogItem::Func(_) => "<builtin>",
+            CatalogItem::Func(Func { create_sql, .. }) => {
+                create_sql.as_deref().unwrap_or("<builtin>")
+            }
             CatalogItem::Log(_) => "<builtin>",
             CatalogItem::ContinualTask(ContinualTask { create_sql, .. }) => create_sql,
         }
@@ -3588,7 +3948,7 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
 
     fn writable_table_details(&self) -> Option<&[Expr<Aug>]> {
         if let CatalogItem::Table(Table {
-            data_source: TableDataSource::TableWrites { defaults },
+            data_source: TableDataSource::TableWrites { defaults, .. },
             ..
         }) = self.item()
         {
@@ -3598,6 +3958,21 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         }
     }
 
+    fn identity_columns(&self) -> &[IdentityColumn] {
+        if let CatalogItem::Table(Table {
+            data_source:
+                TableDataSource::TableWrites {
+                    identity_columns, ..
+                },
+            ..
+        }) = self.item()
+        {
+            identity_columns.as_slice()
+        } else {
+            &[]
+        }
+    }
+
     fn replacement_target(&self) -> Option<CatalogItemId> {
         if let CatalogItem::MaterializedView(mv) = self.item() {
             mv.replacement_target
@@ -3606,6 +3981,14 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         }
     }
 
+    fn notify_channel(&self) -> Option<&str> {
+        if let CatalogItem::MaterializedView(mv) = self.item() {
+            mv.notify_channel.as_deref()
+        } else {
+            None
+        }
+    }
+
     fn type_details(&self) -> Option<&CatalogTypeDetails<IdReference>> {
         if let CatalogItem::Type(Type { details, .. }) = self.item() {
             Some(details)
@@ -3614,6 +3997,30 @@ impl mz_sql::catalog::CatalogItem for CatalogEntry {
         }
     }
 
+    fn func_details(&self) -> Option<&CatalogFuncDetails> {
+        self.item().func_details()
+    }
+
+    fn sequence_details(&self) -> Option<&SequenceOptions> {
+        if let CatalogItem::Sequence(Sequence { options, .. }) = self.item() {
+            Some(options)
+        } else {
+            None
+        }
+    }
+
+    fn policy_details(&self) -> Option<&CatalogPolicyDetails> {
+        if let CatalogItem::Policy(Policy { details, .. }) = self.item() {
+            Some(details)
+        } else {
+            None
+        }
+    }
+
+    fn row_level_security(&self) -> bool {
+        self.item().row_level_security()
+    }
+
     fn references(&self) -> &ResolvedIds {
         self.references()
     }
@@ -107,6 +107,10 @@ struct Args {
         default_value = "http://localhost:6879"
     )]
     persist_pubsub_url: String,
+    /// A local directory in which persist caches blobs, so that they needn't
+    /// be refetched after a restart.
+    #[clap(long, env = "PERSIST_BLOB_CACHE_DIRECTORY", value_name = "PATH")]
+    persist_blob_cache_directory: Option<PathBuf>,
 
     // === Cloud options. ===
     /// An external ID to be supplied to all AWS AssumeRole operations.
@@ -334,6 +338,7 @@ async fn run(args: Args) -> Result<(), anyhow::Error> {
         PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), mz_dyncfgs::all_dyncfgs());
     persist_cfg.is_cc_active = args.is_cc;
     persist_cfg.announce_memory_limit = args.announce_memory_limit;
+    persist_cfg.blob_cache_disk_dir = args.persist_blob_cache_directory;
     // Start with compaction disabled, will get enabled once a cluster receives AllowWrites.
     persist_cfg.disable_compaction();
 
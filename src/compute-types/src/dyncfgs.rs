@@ -174,11 +174,19 @@ pub const COPY_TO_S3_PARQUET_ROW_GROUP_FILE_RATIO: Config<usize> = Config::new(
         Must be <= 100.",
 );
 
+/// See `src/storage-operators/src/s3_oneshot_sink/orc.rs` for more details.
+pub const COPY_TO_S3_ORC_STRIPE_FILE_RATIO: Config<usize> = Config::new(
+    "copy_to_s3_orc_stripe_file_ratio",
+    20,
+    "The ratio (defined as a percentage) of ORC stripe size to max-file-size. \
+        Must be <= 100.",
+);
+
 /// See `src/storage-operators/src/s3_oneshot_sink/parquet.rs` for more details.
 pub const COPY_TO_S3_ARROW_BUILDER_BUFFER_RATIO: Config<usize> = Config::new(
     "copy_to_s3_arrow_builder_buffer_ratio",
     150,
-    "The ratio (defined as a percentage) of arrow-builder size to row-group size. \
+    "The ratio (defined as a percentage) of arrow-builder size to row-group or stripe size. \
         Must be >= 100.",
 );
 
@@ -367,6 +375,7 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&DATAFLOW_MAX_INFLIGHT_BYTES_CC)
         .add(&HYDRATION_CONCURRENCY)
         .add(&COPY_TO_S3_PARQUET_ROW_GROUP_FILE_RATIO)
+        .add(&COPY_TO_S3_ORC_STRIPE_FILE_RATIO)
         .add(&COPY_TO_S3_ARROW_BUILDER_BUFFER_RATIO)
         .add(&COPY_TO_S3_MULTIPART_PART_SIZE_BYTES)
         .add(&ENABLE_COMPUTE_REPLICA_EXPIRATION)
@@ -658,12 +658,21 @@ pub fn reduction_type(func: &AggregateFunc) -> ReductionType {
         | AggregateFunc::ArrayConcat { .. }
         | AggregateFunc::ListConcat { .. }
         | AggregateFunc::StringAgg { .. }
+        | AggregateFunc::PercentileCont { .. }
+        | AggregateFunc::PercentileDisc { .. }
+        | AggregateFunc::Mode { .. }
+        | AggregateFunc::HllMerge
+        | AggregateFunc::ApproxCountDistinct
         | AggregateFunc::RowNumber { .. }
         | AggregateFunc::Rank { .. }
         | AggregateFunc::DenseRank { .. }
+        | AggregateFunc::PercentRank { .. }
+        | AggregateFunc::CumeDist { .. }
+        | AggregateFunc::Ntile { .. }
         | AggregateFunc::LagLead { .. }
         | AggregateFunc::FirstValue { .. }
         | AggregateFunc::LastValue { .. }
+        | AggregateFunc::NthValue { .. }
         | AggregateFunc::WindowAggregate { .. }
         | AggregateFunc::FusedValueWindowFunc { .. }
         | AggregateFunc::FusedWindowAggregate { .. } => ReductionType::Basic,
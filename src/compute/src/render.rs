@@ -128,7 +128,9 @@ use mz_compute_types::plan::LirId;
 use mz_compute_types::plan::render_plan::{
     self, BindStage, LetBind, LetFreePlan, RecBind, RenderPlan,
 };
+use mz_expr::func::TableSample;
 use mz_expr::{EvalError, Id, LocalId, permutation_for_arrangement};
+use mz_persist_client::fetch::PartSample;
 use mz_persist_client::operators::shard_source::{ErrorHandler, SnapshotMode};
 use mz_repr::explain::DummyHumanizer;
 use mz_repr::{Datum, DatumVec, Diff, GlobalId, ReprRelationType, Row, SharedRow};
@@ -241,7 +243,21 @@ pub fn build_compute_dataflow<A: Allocate>(
             for (source_id, import) in dataflow.source_imports.iter() {
                 region.region_named(&format!("Source({:?})", source_id), |inner| {
                     let mut read_schema = None;
+                    let mut part_sample = None;
                     let mut mfp = import.desc.arguments.operators.clone().map(|mut ops| {
+                        // `TABLESAMPLE SYSTEM` is implemented by reading only a sample of
+                        // the shard's parts, in place of the sampling predicate. This is
+                        // only correct for monotonic sources, as otherwise the sample
+                        // could contain updates without the retractions that cancel them;
+                        // other sources leave the predicate to sample individual rows.
+                        if import.monotonic {
+                            part_sample = ops.take_system_sample().map(|sample| PartSample {
+                                rate: sample.rate,
+                                scale: TableSample::SCALE,
+                                seed: sample.seed,
+                            });
+                        }
+
                         // If enabled, we read from Persist with a `RelationDesc` that
                         // omits uneeded columns.
                         if apply_demands {
@@ -293,6 +309,7 @@ pub fn build_compute_dataflow<A: Allocate>(
                         snapshot_mode,
                         until.clone(),
                         mfp.as_mut(),
+                        part_sample,
                         compute_state.dataflow_max_inflight_bytes(),
                         start_signal.clone(),
                         ErrorHandler::Halt("compute_import"),
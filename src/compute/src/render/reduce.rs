@@ -556,29 +556,49 @@ where
                 .clone()
                 .mz_reduce_abelian::<_, RowRowBuilder<_, _>, RowRowSpine<_, _>>(name, {
                     move |key, source, target| {
-                        // We respect the multiplicity here (unlike in hierarchical aggregation)
-                        // because we don't know that the aggregation method is not sensitive
-                        // to the number of records.
-                        let iter = source.iter().flat_map(|(v, w)| {
-                            // Note that in the non-positive case, this is wrong, but harmless because
-                            // our other reduction will produce an error.
-                            let count = usize::try_from(w.into_inner()).unwrap_or(0);
-                            std::iter::repeat(v.to_datum_iter().next().unwrap()).take(count)
-                        });
-
                         let temp_storage = RowArena::new();
                         let datum_iter = key.to_datum_iter();
                         let mut datums_local = datums1.borrow();
                         datums_local.extend(datum_iter);
                         let key_len = datums_local.len();
-                        datums_local.push(
-                        // Note that this is not necessarily a window aggregation, in which case
-                        // `eval_with_fast_window_agg` delegates to the normal `eval`.
-                        func.eval_with_fast_window_agg::<_, window_agg_helpers::OneByOneAggrImpls>(
-                            iter,
-                            &temp_storage,
-                        ),
-                    );
+
+                        // Note that in the non-positive case, the counts below are wrong, but
+                        // harmless because our other reduction will produce an error.
+                        let datum = if func.prefers_weighted_eval() {
+                            // Some aggregates, like ordered-set aggregates, consume the distinct
+                            // values of the group along with their multiplicities, which the
+                            // arrangement has already consolidated. This keeps each update linear
+                            // in the number of distinct values, rather than re-sorting the
+                            // expanded group.
+                            //
+                            // It's still a basic reduction, which revisits every distinct value
+                            // of a group whenever the group changes: an incremental rendering of
+                            // ordered-set aggregates is out of scope for now. Unlike for min and
+                            // max, the percentile or mode of a group can't be computed from those
+                            // of parts of the group, so there is no hierarchical rendering for
+                            // them, and an order-statistic tree would need an order-preserving
+                            // bucketing of arbitrary datums that arrangements don't provide.
+                            let iter = source.iter().map(|(v, w)| {
+                                let count = usize::try_from(w.into_inner()).unwrap_or(0);
+                                (v.to_datum_iter().next().unwrap(), count)
+                            });
+                            func.eval_weighted(iter, &temp_storage)
+                        } else {
+                            // We respect the multiplicity here (unlike in hierarchical
+                            // aggregation) because we don't know that the aggregation method is
+                            // not sensitive to the number of records.
+                            let iter = source.iter().flat_map(|(v, w)| {
+                                let count = usize::try_from(w.into_inner()).unwrap_or(0);
+                                std::iter::repeat(v.to_datum_iter().next().unwrap()).take(count)
+                            });
+                            // Note that this is not necessarily a window aggregation, in which
+                            // case `eval_with_fast_window_agg` delegates to the normal `eval`.
+                            func.eval_with_fast_window_agg::<
+                                _,
+                                window_agg_helpers::OneByOneAggrImpls,
+                            >(iter, &temp_storage)
+                        };
+                        datums_local.push(datum);
 
                         if let Some(row) = evaluate_mfp_after(
                             &mfp_after1,
@@ -661,25 +681,30 @@ where
 
                             // We know that `mfp_after` can error if it exists, so try to evaluate it here.
                             let Some(mfp) = &mfp_after2 else { return };
-                            let iter = source.iter().flat_map(|&(mut v, ref w)| {
-                                let count = usize::try_from(w.into_inner()).unwrap_or(0);
-                                // This would ideally use `to_datum_iter` but we cannot as it needs to
-                                // borrow `v` and only presents datums with that lifetime, not any longer.
-                                std::iter::repeat(v.next().unwrap()).take(count)
-                            });
-
                             let temp_storage = RowArena::new();
                             let datum_iter = key.to_datum_iter();
                             let mut datums_local = datums2.borrow();
                             datums_local.extend(datum_iter);
-                            datums_local.push(
+
+                            // This would ideally use `to_datum_iter` but we cannot as it needs to
+                            // borrow `v` and only presents datums with that lifetime, not any longer.
+                            let datum = if func2.prefers_weighted_eval() {
+                                let iter = source.iter().map(|&(mut v, ref w)| {
+                                    let count = usize::try_from(w.into_inner()).unwrap_or(0);
+                                    (v.next().unwrap(), count)
+                                });
+                                func2.eval_weighted(iter, &temp_storage)
+                            } else {
+                                let iter = source.iter().flat_map(|&(mut v, ref w)| {
+                                    let count = usize::try_from(w.into_inner()).unwrap_or(0);
+                                    std::iter::repeat(v.next().unwrap()).take(count)
+                                });
                                 func2.eval_with_fast_window_agg::<
                                     _,
                                     window_agg_helpers::OneByOneAggrImpls,
-                                >(
-                                    iter, &temp_storage
-                                ),
-                            );
+                                >(iter, &temp_storage)
+                            };
+                            datums_local.push(datum);
                             if let Err(e) = mfp.evaluate_inner(&mut datums_local, &temp_storage) {
                                 target.push((e.into(), Diff::ONE));
                             }
@@ -2366,12 +2391,21 @@ mod monoids {
             | AggregateFunc::ArrayConcat { .. }
             | AggregateFunc::ListConcat { .. }
             | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::HllMerge
+            | AggregateFunc::ApproxCountDistinct
             | AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => None,
@@ -15,7 +15,7 @@ use differential_dataflow::{Hashable, VecCollection};
 use mz_compute_client::protocol::response::CopyToResponse;
 use mz_compute_types::dyncfgs::{
     COPY_TO_S3_ARROW_BUILDER_BUFFER_RATIO, COPY_TO_S3_MULTIPART_PART_SIZE_BYTES,
-    COPY_TO_S3_PARQUET_ROW_GROUP_FILE_RATIO,
+    COPY_TO_S3_ORC_STRIPE_FILE_RATIO, COPY_TO_S3_PARQUET_ROW_GROUP_FILE_RATIO,
 };
 use mz_compute_types::sinks::{ComputeSinkDesc, CopyToS3OneshotSinkConnection};
 use mz_repr::{Diff, GlobalId, Row, Timestamp};
@@ -110,6 +110,7 @@ where
         let params = mz_storage_operators::s3_oneshot_sink::CopyToParameters {
             parquet_row_group_ratio: COPY_TO_S3_PARQUET_ROW_GROUP_FILE_RATIO
                 .get(&compute_state.worker_config),
+            orc_stripe_ratio: COPY_TO_S3_ORC_STRIPE_FILE_RATIO.get(&compute_state.worker_config),
             arrow_builder_buffer_ratio: COPY_TO_S3_ARROW_BUILDER_BUFFER_RATIO
                 .get(&compute_state.worker_config),
             s3_multipart_part_size_bytes: COPY_TO_S3_MULTIPART_PART_SIZE_BYTES
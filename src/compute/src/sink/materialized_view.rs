@@ -409,6 +409,7 @@ where
         SnapshotMode::Include,
         until,
         map_filter_project,
+        None,
         compute_state.dataflow_max_inflight_bytes(),
         start_signal,
         ErrorHandler::Halt("compute persist sink"),
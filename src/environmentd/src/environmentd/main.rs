@@ -378,6 +378,10 @@ pub struct Args {
     /// of threads returned by [`num_cpus::get`].
     #[clap(long, env = "PERSIST_ISOLATED_RUNTIME_THREADS")]
     persist_isolated_runtime_threads: Option<isize>,
+    /// A local directory in which persist caches blobs, so that they needn't
+    /// be refetched after a restart.
+    #[clap(long, env = "PERSIST_BLOB_CACHE_DIRECTORY", value_name = "PATH")]
+    persist_blob_cache_directory: Option<PathBuf>,
     /// The interval in seconds at which to collect storage usage information.
     #[clap(
         long,
@@ -938,6 +942,7 @@ fn run(mut args: Args) -> Result<(), anyhow::Error> {
         PersistConfig::new(&BUILD_INFO, now.clone(), mz_dyncfgs::all_dyncfgs());
     // Start with compaction disabled, later enable it if we're not in read-only mode.
     persist_config.disable_compaction();
+    persist_config.blob_cache_disk_dir = args.persist_blob_cache_directory.clone();
 
     let persist_pubsub_server = PersistGrpcPubSubServer::new(&persist_config, &metrics_registry);
     let persist_pubsub_client = persist_pubsub_server.new_same_process_connection();
@@ -22,6 +22,9 @@
 //!    protected request and resolves the caller's identity via one of:
 //!    - **Credentials in headers.** The caller supplies a username/password or
 //!      token in the request headers. Supported by all [`listeners::AuthenticatorKind`]s.
+//!    - **Client certificate.** The caller presents a TLS client certificate
+//!      that is mapped to a role, optionally selected by the username in the
+//!      request headers. Only available for [`listeners::AuthenticatorKind::Cert`].
 //!    - **Session reuse.** If the caller has an active authenticated session
 //!      (established via `POST /api/login`) and has not supplied credentials
 //!      in the request headers, the session is reused. Only available for
@@ -77,7 +80,7 @@ use mz_adapter::session::{Session as AdapterSession, SessionConfig as AdapterSes
 use mz_adapter::{AdapterError, AdapterNotice, Client, SessionClient, WebhookAppenderCache};
 use mz_auth::Authenticated;
 use mz_auth::password::Password;
-use mz_authenticator::Authenticator;
+use mz_authenticator::{Authenticator, CertAuthenticator, CertError, LdapAuthenticator};
 use mz_controller::ReplicaHttpLocator;
 use mz_frontegg_auth::Error as FronteggError;
 use mz_http_util::DynamicFilterTarget;
@@ -95,6 +98,7 @@ use mz_sql::session::user::{
 };
 use mz_sql::session::vars::{Value, Var, VarInput, WELCOME_MESSAGE};
 use openssl::ssl::Ssl;
+use openssl::x509::{X509, X509Ref, X509VerifyResult};
 use prometheus::{
     COMPUTE_METRIC_QUERIES, FRONTIER_METRIC_QUERIES, STORAGE_METRIC_QUERIES, USAGE_METRIC_QUERIES,
 };
@@ -578,7 +582,16 @@ impl Server for HttpServer {
                         let _ = ssl_stream.get_mut().inner_mut().shutdown().await;
                         return Err(e.into());
                     }
-                    (MaybeHttpsStream::Https(ssl_stream), ConnProtocol::Https)
+                    let ssl = ssl_stream.ssl();
+                    let peer_certificate = if ssl.verify_result() == X509VerifyResult::OK {
+                        ssl.peer_certificate()
+                    } else {
+                        None
+                    };
+                    (
+                        MaybeHttpsStream::Https(ssl_stream),
+                        ConnProtocol::Https { peer_certificate },
+                    )
                 }
                 _ => (MaybeHttpsStream::Http(conn), ConnProtocol::Http),
             };
@@ -667,9 +680,24 @@ async fn x_materialize_user_header_auth(mut req: Request, next: Next) -> impl In
 type Delayed<T> = Shared<oneshot::Receiver<T>>;
 
 #[derive(Clone)]
-enum ConnProtocol {
+pub(crate) enum ConnProtocol {
     Http,
-    Https,
+    Https {
+        /// The verified certificate that the client presented during the TLS
+        /// handshake, if any.
+        peer_certificate: Option<X509>,
+    },
+}
+
+impl ConnProtocol {
+    /// Returns the verified certificate that the client presented during the
+    /// TLS handshake, if any.
+    pub(crate) fn peer_certificate(&self) -> Option<&X509Ref> {
+        match self {
+            ConnProtocol::Https { peer_certificate } => peer_certificate.as_deref(),
+            ConnProtocol::Http => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -949,7 +977,7 @@ async fn http_auth(
     // First, extract the username from the certificate, validating that the
     // connection matches the TLS configuration along the way.
     // Fall back to existing authentication methods.
-    let conn_protocol = req.extensions().get::<ConnProtocol>().unwrap();
+    let conn_protocol = req.extensions().get::<ConnProtocol>().unwrap().clone();
     match (tls_enabled, &conn_protocol) {
         (false, ConnProtocol::Http) => {}
         (false, ConnProtocol::Https { .. }) => unreachable!(),
@@ -987,6 +1015,7 @@ async fn http_auth(
     let user = auth(
         &authenticator,
         creds,
+        conn_protocol.peer_certificate(),
         allowed_roles,
         include_www_authenticate_header,
     )
@@ -1012,6 +1041,7 @@ async fn init_ws(
     }: WsState,
     existing_user: Option<ExistingUser>,
     peer_addr: IpAddr,
+    peer_certificate: Option<&X509Ref>,
     ws: &mut WebSocket,
 ) -> Result<AuthedClient, anyhow::Error> {
     // TODO: Add a timeout here to prevent resource leaks by clients that
@@ -1071,9 +1101,28 @@ async fn init_ws(
                 &adapter_client_rx,
             )
             .await;
-            let user = auth(&authenticator, Some(creds), allowed_roles, false).await?;
+            let user = auth(
+                &authenticator,
+                Some(creds),
+                peer_certificate,
+                allowed_roles,
+                false,
+            )
+            .await?;
             user
         }
+        // A client certificate is enough to authenticate on its own.
+        (None, None) if authenticator_kind == listeners::AuthenticatorKind::Cert => {
+            let authenticator = get_authenticator(
+                authenticator_kind,
+                None,
+                frontegg,
+                &oidc_rx,
+                &adapter_client_rx,
+            )
+            .await;
+            auth(&authenticator, None, peer_certificate, allowed_roles, false).await?
+        }
         (None, None) => anyhow::bail!("expected auth information"),
     };
 
@@ -1125,6 +1174,14 @@ async fn get_authenticator(
             }
             _ => Authenticator::Oidc(oidc_rx.clone().await.expect("sender not dropped")),
         },
+        listeners::AuthenticatorKind::Cert => {
+            let client = adapter_client_rx.clone().await.expect("sender not dropped");
+            Authenticator::Cert(CertAuthenticator::new(client))
+        }
+        listeners::AuthenticatorKind::Ldap => {
+            let client = adapter_client_rx.clone().await.expect("sender not dropped");
+            Authenticator::Ldap(LdapAuthenticator::new(client))
+        }
         listeners::AuthenticatorKind::None => Authenticator::None,
     }
 }
@@ -1176,6 +1233,7 @@ pub(crate) async fn ensure_session_unexpired(
 async fn auth(
     authenticator: &Authenticator,
     creds: Option<Credentials>,
+    peer_certificate: Option<&X509Ref>,
     allowed_roles: AllowedRoles,
     include_www_authenticate_header: bool,
 ) -> Result<AuthedUser, AuthError> {
@@ -1240,6 +1298,38 @@ async fn auth(
                 });
             }
         },
+        Authenticator::Ldap(ldap) => match creds {
+            Some(Credentials::Password { username, password }) => {
+                let authenticated = ldap
+                    .authenticate(&username, &password)
+                    .await
+                    .map_err(|_| AuthError::InvalidCredentials)?;
+                (username, None, authenticated)
+            }
+            _ => {
+                return Err(AuthError::MissingHttpAuthentication {
+                    include_www_authenticate_header,
+                });
+            }
+        },
+        Authenticator::Cert(cert) => {
+            // A username in the HTTP auth header selects among the roles that
+            // the certificate maps to. Any password is ignored.
+            let expected_user = match &creds {
+                Some(Credentials::Password { username, .. }) => Some(username.as_str()),
+                _ => None,
+            };
+            let (name, authenticated) = cert
+                .authenticate(peer_certificate, expected_user)
+                .await
+                .map_err(|e| match e {
+                    CertError::MissingCertificate => AuthError::MissingHttpAuthentication {
+                        include_www_authenticate_header,
+                    },
+                    _ => AuthError::InvalidCredentials,
+                })?;
+            (name, None, authenticated)
+        }
         Authenticator::None => {
             // If no authentication, use whatever is in the HTTP auth
             // header (without checking the password), or fall back to the
@@ -57,8 +57,8 @@ use tungstenite::protocol::frame::coding::CloseCode;
 
 use crate::http::prometheus::PrometheusSqlQuery;
 use crate::http::{
-    AuthError, AuthedClient, AuthedUser, MAX_REQUEST_SIZE, WsState, ensure_session_unexpired,
-    init_ws, maybe_get_authenticated_session,
+    AuthError, AuthedClient, AuthedUser, ConnProtocol, MAX_REQUEST_SIZE, WsState,
+    ensure_session_unexpired, init_ws, maybe_get_authenticated_session,
 };
 
 #[derive(Debug, thiserror::Error)]
@@ -290,6 +290,7 @@ pub enum ExistingUser {
 pub(crate) async fn handle_sql_ws(
     State(state): State<WsState>,
     existing_user: Option<Extension<AuthedUser>>,
+    Extension(conn_protocol): Extension<ConnProtocol>,
     ws: WebSocketUpgrade,
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     tower_session: Option<Extension<TowerSession>>,
@@ -312,7 +313,7 @@ pub(crate) async fn handle_sql_ws(
     let addr = Box::new(addr.ip());
     Ok(ws
         .max_message_size(MAX_REQUEST_SIZE)
-        .on_upgrade(|ws| async move { run_ws(state, user, *addr, ws).await }))
+        .on_upgrade(|ws| async move { run_ws(state, user, *addr, conn_protocol, ws).await }))
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
@@ -335,8 +336,15 @@ pub enum WebSocketAuth {
     },
 }
 
-async fn run_ws(state: WsState, user: Option<ExistingUser>, peer_addr: IpAddr, mut ws: WebSocket) {
-    let mut client = match init_ws(state, user, peer_addr, &mut ws).await {
+async fn run_ws(
+    state: WsState,
+    user: Option<ExistingUser>,
+    peer_addr: IpAddr,
+    conn_protocol: ConnProtocol,
+    mut ws: WebSocket,
+) {
+    let peer_certificate = conn_protocol.peer_certificate();
+    let mut client = match init_ws(state, user, peer_addr, peer_certificate, &mut ws).await {
         Ok(client) => client,
         Err(e) => {
             // We omit most detail from the error message we send to the client, to
@@ -1265,7 +1273,8 @@ pub(in crate::http) async fn execute_request<S: ResultSender>(
                     | ExecuteResponseKind::Subscribing
                     | ExecuteResponseKind::CopyFrom
                     | ExecuteResponseKind::DeclaredCursor
-                    | ExecuteResponseKind::ClosedCursor => true,
+                    | ExecuteResponseKind::ClosedCursor
+                    | ExecuteResponseKind::StartedReplication => true,
                     // Various statements generate `PeekPlan` (`SELECT`, `COPY`,
                     // `EXPLAIN`, `SHOW`) which has both `SendRows` and `CopyTo` as its
                     // possible response types. but `COPY` needs be picked out because
@@ -1492,24 +1501,33 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::CreatedMaterializedView { .. }
         | ExecuteResponse::CreatedContinualTask { .. }
         | ExecuteResponse::CreatedType
+        | ExecuteResponse::CreatedFunction
+        | ExecuteResponse::CreatedSequence
+        | ExecuteResponse::CreatedPolicy
         | ExecuteResponse::CreatedNetworkPolicy
+        | ExecuteResponse::CreatedPublication
         | ExecuteResponse::Comment
         | ExecuteResponse::Deleted(_)
         | ExecuteResponse::DiscardedTemp
         | ExecuteResponse::DiscardedAll
         | ExecuteResponse::DroppedObject(_)
         | ExecuteResponse::DroppedOwned
+        | ExecuteResponse::DroppedPublication
+        | ExecuteResponse::DroppedReplicationSlot
         | ExecuteResponse::EmptyQuery
         | ExecuteResponse::GrantedPrivilege
         | ExecuteResponse::GrantedRole
         | ExecuteResponse::Inserted(_)
         | ExecuteResponse::Copied(_)
+        | ExecuteResponse::Listened
         | ExecuteResponse::Raised
         | ExecuteResponse::ReassignOwned
         | ExecuteResponse::RevokedPrivilege
         | ExecuteResponse::AlteredDefaultPrivileges
         | ExecuteResponse::RevokedRole
         | ExecuteResponse::StartedTransaction { .. }
+        | ExecuteResponse::Truncated
+        | ExecuteResponse::Unlistened
         | ExecuteResponse::Updated(_)
         | ExecuteResponse::AlteredObject(_)
         | ExecuteResponse::AlteredRole
@@ -1629,7 +1647,8 @@ async fn execute_stmt<S: ResultSender>(
         | ExecuteResponse::CopyTo { .. }
         | ExecuteResponse::CopyFrom { .. }
         | ExecuteResponse::DeclaredCursor
-        | ExecuteResponse::ClosedCursor) => SqlResult::err(
+        | ExecuteResponse::ClosedCursor
+        | ExecuteResponse::StartedReplication { .. }) => SqlResult::err(
             client,
             Error::Unstructured(anyhow!(
                 "internal error: encountered prohibited ExecuteResponse {:?}.\n\n
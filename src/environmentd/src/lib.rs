@@ -353,6 +353,16 @@ impl Listeners {
             Some(tls_config) => Some(tls_config.reloading_context(config.tls_reload_certs)?),
             None => None,
         };
+        // Only listeners that authenticate users by certificate ask clients
+        // for one, so each listener gets its own view of the context.
+        let mut sql_tls_contexts = BTreeMap::new();
+        for (name, listener) in &self.sql {
+            let context = tls_reloading_context
+                .as_ref()
+                .map(|tls| tls.for_authenticator(listener.config.authenticator_kind()))
+                .transpose()?;
+            sql_tls_contexts.insert(name.clone(), context);
+        }
 
         let active_connection_counter = ConnectionCounter::default();
         let (deployment_state, deployment_state_handle) = DeploymentState::new();
@@ -383,7 +393,10 @@ impl Listeners {
             let authenticator_kind = listener.config.authenticator_kind();
             let source: &'static str = Box::leak(name.clone().into_boxed_str());
             let tls = if listener.config.enable_tls() {
-                tls_reloading_context.clone()
+                tls_reloading_context
+                    .as_ref()
+                    .map(|tls| tls.for_authenticator(authenticator_kind))
+                    .transpose()?
             } else {
                 None
             };
@@ -796,13 +809,16 @@ impl Listeners {
         // Launch SQL server.
         let mut sql_listener_handles = BTreeMap::new();
         for (name, listener) in self.sql {
+            let tls_context = sql_tls_contexts
+                .remove(&name)
+                .expect("context computed for every listener");
             sql_listener_handles.insert(
                 name.clone(),
                 listener
                     .serve_sql(
                         name,
                         active_connection_counter.clone(),
-                        tls_reloading_context.clone(),
+                        tls_context,
                         config.frontegg.clone(),
                         adapter_client.clone(),
                         oidc.clone(),
@@ -16,7 +16,7 @@ use std::pin::Pin;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::LazyLock;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use std::{env, fs, iter};
 
 use anyhow::anyhow;
@@ -60,14 +60,16 @@ use mz_sql::catalog::EnvironmentId;
 use mz_storage_types::connections::ConnectionContext;
 use mz_tracing::CloneableEnvFilter;
 use openssl::asn1::Asn1Time;
+use openssl::bn::{BigNum, MsbOption};
 use openssl::error::ErrorStack;
 use openssl::hash::MessageDigest;
 use openssl::nid::Nid;
 use openssl::pkey::{PKey, Private};
 use openssl::rsa::Rsa;
+use openssl::sign::Signer;
 use openssl::ssl::{SslConnector, SslConnectorBuilder, SslMethod, SslOptions};
 use openssl::x509::extension::{BasicConstraints, SubjectAlternativeName};
-use openssl::x509::{X509, X509Name, X509NameBuilder};
+use openssl::x509::{X509, X509Crl, X509Name, X509NameBuilder};
 use postgres::error::DbError;
 use postgres::tls::{MakeTlsConnect, TlsConnect};
 use postgres::types::{FromSql, Type};
@@ -77,7 +79,7 @@ use tempfile::TempDir;
 use tokio::net::TcpListener;
 use tokio::runtime::Runtime;
 use tokio_postgres::config::{Host, SslMode};
-use tokio_postgres::{AsyncMessage, Client};
+use tokio_postgres::{AsyncMessage, Client, Notification};
 use tokio_stream::wrappers::TcpListenerStream;
 use tower_http::cors::AllowOrigin;
 use tracing::Level;
@@ -294,6 +296,8 @@ impl TestHarness {
         self.tls = Some(TlsCertConfig {
             cert: cert_path.into(),
             key: key_path.into(),
+            client_ca: None,
+            client_crl: None,
         });
         for (_, listener) in &mut self.listeners_config.sql {
             listener.enable_tls = true;
@@ -458,6 +462,144 @@ impl TestHarness {
         self
     }
 
+    /// Authenticates users on the external listeners against the LDAP server at
+    /// `url`. The bind DN template or search parameters must be set separately
+    /// via [`TestHarness::with_system_parameter_default`].
+    pub fn with_ldap_auth(mut self, url: String) -> Self {
+        let enable_tls = self.tls.is_some();
+        self.listeners_config = ListenersConfig {
+            sql: btreemap! {
+                "external".to_owned() => SqlListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::Ldap,
+                    allowed_roles: AllowedRoles::Normal,
+                    enable_tls,
+                },
+                "internal".to_owned() => SqlListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::None,
+                    allowed_roles: AllowedRoles::NormalAndInternal,
+                    enable_tls: false,
+                },
+            },
+            http: btreemap! {
+                "external".to_owned() => HttpListenerConfig {
+                    base: BaseListenerConfig {
+                        addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                        authenticator_kind: AuthenticatorKind::Ldap,
+                        allowed_roles: AllowedRoles::Normal,
+                        enable_tls,
+                    },
+                    routes: HttpRoutesEnabled{
+                        base: true,
+                        webhook: true,
+                        internal: false,
+                        metrics: false,
+                        profiling: false,
+                        mcp_agents: false,
+                        mcp_observatory: false,
+                        console_config: true,
+                    },
+                },
+                "internal".to_owned() => HttpListenerConfig {
+                    base: BaseListenerConfig {
+                        addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                        authenticator_kind: AuthenticatorKind::None,
+                        allowed_roles: AllowedRoles::NormalAndInternal,
+                        enable_tls: false,
+                    },
+                    routes: HttpRoutesEnabled{
+                        base: true,
+                        webhook: true,
+                        internal: true,
+                        metrics: true,
+                        profiling: true,
+                        mcp_agents: false,
+                        mcp_observatory: false,
+                        console_config: true,
+                    },
+                },
+            },
+        };
+
+        self.system_parameter_defaults
+            .insert("ldap_url".to_string(), url);
+
+        self
+    }
+
+    /// Authenticates users on the external listeners by the TLS client
+    /// certificates signed by the certificate authorities at `client_ca`,
+    /// unless they're revoked by the lists at `client_crl`. Must be called
+    /// after [`TestHarness::with_tls`].
+    pub fn with_cert_auth(
+        mut self,
+        client_ca: impl Into<PathBuf>,
+        client_crl: Option<PathBuf>,
+    ) -> Self {
+        let tls = self
+            .tls
+            .as_mut()
+            .expect("certificate authentication requires TLS");
+        tls.client_ca = Some(client_ca.into());
+        tls.client_crl = client_crl;
+        self.listeners_config = ListenersConfig {
+            sql: btreemap! {
+                "external".to_owned() => SqlListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::Cert,
+                    allowed_roles: AllowedRoles::Normal,
+                    enable_tls: true,
+                },
+                "internal".to_owned() => SqlListenerConfig {
+                    addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                    authenticator_kind: AuthenticatorKind::None,
+                    allowed_roles: AllowedRoles::NormalAndInternal,
+                    enable_tls: false,
+                },
+            },
+            http: btreemap! {
+                "external".to_owned() => HttpListenerConfig {
+                    base: BaseListenerConfig {
+                        addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                        authenticator_kind: AuthenticatorKind::Cert,
+                        allowed_roles: AllowedRoles::Normal,
+                        enable_tls: true,
+                    },
+                    routes: HttpRoutesEnabled{
+                        base: true,
+                        webhook: true,
+                        internal: false,
+                        metrics: false,
+                        profiling: false,
+                        mcp_agents: false,
+                        mcp_observatory: false,
+                        console_config: true,
+                    },
+                },
+                "internal".to_owned() => HttpListenerConfig {
+                    base: BaseListenerConfig {
+                        addr: SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0),
+                        authenticator_kind: AuthenticatorKind::None,
+                        allowed_roles: AllowedRoles::NormalAndInternal,
+                        enable_tls: false,
+                    },
+                    routes: HttpRoutesEnabled{
+                        base: true,
+                        webhook: true,
+                        internal: true,
+                        metrics: true,
+                        profiling: true,
+                        mcp_agents: false,
+                        mcp_observatory: false,
+                        console_config: true,
+                    },
+                },
+            },
+        };
+        self
+    }
+
     pub fn with_password_auth(mut self, mz_system_password: Password) -> Self {
         self.external_login_password_mz_system = Some(mz_system_password);
         let enable_tls = self.tls.is_some();
@@ -1009,6 +1151,8 @@ pub struct ConnectBuilder<'s, T, H> {
 
     /// Callback that gets invoked for every notice we receive.
     notice_callback: Option<Box<dyn FnMut(tokio_postgres::error::DbError) + Send + 'static>>,
+    /// Callback that gets invoked for every asynchronous notification we receive.
+    notification_callback: Option<Box<dyn FnMut(Notification) + Send + 'static>>,
 
     /// Type variable for whether or not we include the handle for the spawned [`tokio::task`].
     _with_handle: H,
@@ -1029,6 +1173,7 @@ impl<'s> ConnectBuilder<'s, (), NoHandle> {
             port: server.sql_local_addr().port(),
             tls: (),
             notice_callback: None,
+            notification_callback: None,
             _with_handle: NoHandle,
         }
     }
@@ -1045,6 +1190,7 @@ impl<'s, T, H> ConnectBuilder<'s, T, H> {
             port: self.port,
             tls: postgres::NoTls,
             notice_callback: self.notice_callback,
+            notification_callback: self.notification_callback,
             _with_handle: self._with_handle,
         }
     }
@@ -1063,6 +1209,7 @@ impl<'s, T, H> ConnectBuilder<'s, T, H> {
             port: self.port,
             tls,
             notice_callback: self.notice_callback,
+            notification_callback: self.notification_callback,
             _with_handle: self._with_handle,
         }
     }
@@ -1127,6 +1274,18 @@ impl<'s, T, H> ConnectBuilder<'s, T, H> {
         }
     }
 
+    /// Sets a callback for any asynchronous notifications that are received from the
+    /// [`TestServer`].
+    pub fn notification_callback(
+        self,
+        callback: impl FnMut(Notification) + Send + 'static,
+    ) -> Self {
+        ConnectBuilder {
+            notification_callback: Some(Box::new(callback)),
+            ..self
+        }
+    }
+
     /// Configures this [`ConnectBuilder`] to return the [`mz_ore::task::JoinHandle`] that is
     /// polling the underlying postgres connection, associated with the returned client.
     pub fn with_handle(self) -> ConnectBuilder<'s, T, WithHandle> {
@@ -1136,6 +1295,7 @@ impl<'s, T, H> ConnectBuilder<'s, T, H> {
             port: self.port,
             tls: self.tls,
             notice_callback: self.notice_callback,
+            notification_callback: self.notification_callback,
             _with_handle: WithHandle,
         }
     }
@@ -1203,6 +1363,7 @@ where
 
             let (client, mut conn) = self.pg_config.connect(self.tls).await?;
             let mut notice_callback = self.notice_callback.take();
+            let mut notification_callback = self.notification_callback.take();
 
             let handle = task::spawn(|| "connect", async move {
                 while let Some(msg) = std::future::poll_fn(|cx| conn.poll_message(cx)).await {
@@ -1212,6 +1373,11 @@ where
                                 callback(notice);
                             }
                         }
+                        Ok(AsyncMessage::Notification(notification)) => {
+                            if let Some(callback) = notification_callback.as_mut() {
+                                callback(notification);
+                            }
+                        }
                         Ok(msg) => {
                             tracing::debug!(?msg, "Dropping message from database");
                         }
@@ -1808,6 +1974,7 @@ impl Ca {
             let mut builder = X509::builder()?;
             builder.set_version(2)?;
             builder.set_pubkey(&pkey)?;
+            builder.set_serial_number(&*random_serial_number()?)?;
             builder.set_issuer_name(self.cert.subject_name())?;
             builder.set_subject_name(&subject_name)?;
             builder.set_not_before(&*Asn1Time::days_from_now(0)?)?;
@@ -1828,4 +1995,91 @@ impl Ca {
         fs::write(&key_path, pkey.private_key_to_pem_pkcs8()?)?;
         Ok((cert_path, key_path))
     }
+
+    /// Generates a certificate revocation list, signed by the CA, that revokes
+    /// the certificates at `revoked`.
+    ///
+    /// Returns the path to the list.
+    pub fn request_crl(&self, revoked: &[&Path]) -> Result<PathBuf, Box<dyn Error>> {
+        // OpenSSL can parse but not build CRLs, so we encode one by hand.
+        fn der(tag: u8, contents: &[u8]) -> Vec<u8> {
+            let mut out = vec![tag];
+            match u8::try_from(contents.len()) {
+                Ok(len) if len < 0x80 => out.push(len),
+                _ => {
+                    let len = contents.len().to_be_bytes();
+                    let len = &len[len.iter().take_while(|b| **b == 0).count()..];
+                    out.push(0x80 | u8::try_from(len.len()).expect("short length"));
+                    out.extend(len);
+                }
+            }
+            out.extend(contents);
+            out
+        }
+        fn integer(mut bytes: Vec<u8>) -> Vec<u8> {
+            if bytes.first().map_or(true, |b| b & 0x80 != 0) {
+                bytes.insert(0, 0);
+            }
+            der(0x02, &bytes)
+        }
+        fn utc_time(time: SystemTime) -> Result<Vec<u8>, Box<dyn Error>> {
+            let secs = time.duration_since(UNIX_EPOCH)?.as_secs();
+            let time = chrono::DateTime::from_timestamp(i64::try_from(secs)?, 0)
+                .ok_or("time out of range")?;
+            Ok(der(
+                0x17,
+                time.format("%y%m%d%H%M%SZ").to_string().as_bytes(),
+            ))
+        }
+
+        // sha256WithRSAEncryption, with NULL parameters.
+        let algorithm = der(
+            0x30,
+            &[
+                der(
+                    0x06,
+                    &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b],
+                ),
+                der(0x05, &[]),
+            ]
+            .concat(),
+        );
+        let now = SystemTime::now();
+        let mut revoked_certs = vec![];
+        for path in revoked {
+            let cert = X509::from_pem(&fs::read(path)?)?;
+            let serial = cert.serial_number().to_bn()?.to_vec();
+            revoked_certs.extend(der(0x30, &[integer(serial), utc_time(now)?].concat()));
+        }
+        let mut tbs = [
+            integer(vec![1]),
+            algorithm.clone(),
+            self.cert.subject_name().to_der()?,
+            utc_time(now)?,
+            utc_time(now + Duration::from_secs(60 * 60 * 24))?,
+        ]
+        .concat();
+        if !revoked_certs.is_empty() {
+            tbs.extend(der(0x30, &revoked_certs));
+        }
+        let tbs = der(0x30, &tbs);
+
+        let mut signer = Signer::new(MessageDigest::sha256(), &self.pkey)?;
+        signer.update(&tbs)?;
+        let signature = [vec![0], signer.sign_to_vec()?].concat();
+        let crl = der(0x30, &[tbs, algorithm, der(0x03, &signature)].concat());
+
+        let crl = X509Crl::from_der(&crl)?;
+        let crl_path = self.dir.path().join("ca.crl");
+        fs::write(&crl_path, crl.to_pem()?)?;
+        Ok(crl_path)
+    }
+}
+
+/// Returns a random serial number for a certificate, so that revocation lists
+/// can tell the certificates of a CA apart.
+fn random_serial_number() -> Result<openssl::asn1::Asn1Integer, ErrorStack> {
+    let mut serial = BigNum::new()?;
+    serial.rand(64, MsbOption::MAYBE_ZERO, false)?;
+    serial.to_asn1_integer()
 }
@@ -18,6 +18,7 @@ use std::future::IntoFuture;
 use std::io::{Read, Write};
 use std::net::{IpAddr, Ipv4Addr, TcpStream};
 use std::num::NonZeroUsize;
+use std::path::Path;
 use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
@@ -47,6 +48,7 @@ use mz_frontegg_auth::{
 use mz_frontegg_mock::{
     FronteggMockServer, models::ApiToken, models::TenantApiTokenConfig, models::UserConfig,
 };
+use mz_ldap_mock::{LdapMockEntry, LdapMockServer};
 use mz_oidc_mock::{AudClaim, GenerateJwtOptions, OidcMockServer};
 use mz_ore::error::ErrorExt;
 use mz_ore::metrics::MetricsRegistry;
@@ -56,7 +58,9 @@ use mz_ore::{assert_contains, assert_err, assert_none, assert_ok};
 use mz_sql::names::PUBLIC_ROLE_NAME;
 use mz_sql::session::user::{HTTP_DEFAULT_USER, SYSTEM_USER};
 use openssl::error::ErrorStack;
-use openssl::ssl::{SslConnector, SslConnectorBuilder, SslMethod, SslOptions, SslVerifyMode};
+use openssl::ssl::{
+    SslConnector, SslConnectorBuilder, SslFiletype, SslMethod, SslOptions, SslVerifyMode,
+};
 use postgres::config::SslMode;
 use postgres::error::SqlState;
 use serde::Deserialize;
@@ -5368,3 +5372,366 @@ async fn test_auth_oidc_non_login_role() {
     )
     .await;
 }
+
+/// Tests TLS client certificate authentication, including revocation.
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_auth_cert() {
+    let server_ca = Ca::new_root("test ca").unwrap();
+    let (server_cert, server_key) = server_ca
+        .request_cert("server", vec![IpAddr::V4(Ipv4Addr::LOCALHOST)])
+        .unwrap();
+    let client_ca = Ca::new_root("test client ca").unwrap();
+    let (alice_cert, alice_key) = client_ca.request_client_cert("alice").unwrap();
+    let (mallory_cert, mallory_key) = client_ca.request_client_cert("mallory").unwrap();
+    let client_crl = client_ca.request_crl(&[&mallory_cert]).unwrap();
+
+    let server = test_util::TestHarness::default()
+        .with_tls(server_cert, server_key)
+        .with_cert_auth(client_ca.ca_cert_path(), Some(client_crl))
+        .start()
+        .await;
+
+    let admin_client = server.connect().internal().await.unwrap();
+    for role in ["alice", "mallory"] {
+        admin_client
+            .batch_execute(&format!("CREATE ROLE {role} LOGIN"))
+            .await
+            .unwrap();
+    }
+
+    let with_cert = |cert: &Path, key: &Path| {
+        let (cert, key) = (cert.to_owned(), key.to_owned());
+        move |b: &mut SslConnectorBuilder| {
+            b.set_verify(SslVerifyMode::NONE);
+            b.set_certificate_file(&cert, SslFiletype::PEM)?;
+            b.set_private_key_file(&key, SslFiletype::PEM)
+        }
+    };
+    let no_headers = HeaderMap::new();
+
+    run_tests(
+        "Cert",
+        &server,
+        &[
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: None,
+                ssl_mode: SslMode::Require,
+                options: None,
+                configure: Box::new(with_cert(&alice_cert, &alice_key)),
+                assert: Assert::Success,
+            },
+            // The certificate maps to alice, not mallory.
+            TestCase::Pgwire {
+                user_to_auth_as: "mallory",
+                user_reported_by_system: "mallory",
+                password: None,
+                ssl_mode: SslMode::Require,
+                options: None,
+                configure: Box::new(with_cert(&alice_cert, &alice_key)),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(
+                        err.message(),
+                        r#"certificate authentication failed for user "mallory""#
+                    );
+                    assert_eq!(*err.code(), SqlState::INVALID_AUTHORIZATION_SPECIFICATION);
+                })),
+            },
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: None,
+                ssl_mode: SslMode::Require,
+                options: None,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(err.message(), "client certificate required");
+                    assert_eq!(*err.code(), SqlState::INVALID_AUTHORIZATION_SPECIFICATION);
+                })),
+            },
+            // Revoked certificates fail the TLS handshake.
+            TestCase::Pgwire {
+                user_to_auth_as: "mallory",
+                user_reported_by_system: "mallory",
+                password: None,
+                ssl_mode: SslMode::Require,
+                options: None,
+                configure: Box::new(with_cert(&mallory_cert, &mallory_key)),
+                assert: Assert::Err(Box::new(|err| {
+                    assert_none!(err.as_db_error());
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: Box::new(with_cert(&alice_cert, &alice_key)),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: Box::new(|b| Ok(b.set_verify(SslVerifyMode::NONE))),
+                assert: Assert::Err(Box::new(|code, _| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "mallory",
+                user_reported_by_system: "mallory",
+                scheme: Scheme::HTTPS,
+                headers: &no_headers,
+                configure: Box::new(with_cert(&mallory_cert, &mallory_key)),
+                assert: Assert::Err(Box::new(|code, _| {
+                    assert_eq!(code, None);
+                })),
+            },
+            TestCase::Ws {
+                user_reported_by_system: "alice",
+                auth: &WebSocketAuth::OptionsOnly {
+                    options: BTreeMap::default(),
+                },
+                headers: &no_headers,
+                configure: Box::new(with_cert(&alice_cert, &alice_key)),
+                assert: Assert::Success,
+            },
+        ],
+    )
+    .await;
+}
+
+/// Tests LDAP authentication with a bind DN template and with search+bind.
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_auth_ldap() {
+    let ldap_server = LdapMockServer::start(
+        None,
+        vec![
+            LdapMockEntry::user(
+                "uid=alice,ou=people,dc=example,dc=com",
+                "alice",
+                "secret",
+                &[],
+            ),
+            LdapMockEntry::user("uid=bob,ou=staff,dc=example,dc=com", "bob", "hunter2", &[]),
+        ],
+    )
+    .await
+    .unwrap();
+
+    let server = test_util::TestHarness::default()
+        .with_ldap_auth(ldap_server.url.clone())
+        .with_system_parameter_default(
+            "ldap_bind_dn_template".to_string(),
+            "uid={username},ou=people,dc=example,dc=com".to_string(),
+        )
+        .start()
+        .await;
+
+    let admin_client = server.connect().internal().await.unwrap();
+    for role in ["alice", "bob", "carol"] {
+        admin_client
+            .batch_execute(&format!("CREATE ROLE {role} LOGIN"))
+            .await
+            .unwrap();
+    }
+
+    let http_header = |user, password| make_header(Authorization::basic(user, password));
+    let alice_header = http_header("alice", "secret");
+    let alice_bad_header = http_header("alice", "wrong");
+
+    run_tests(
+        "LDAP bind DN template",
+        &server,
+        &[
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: Some(Cow::Borrowed("secret")),
+                ssl_mode: SslMode::Disable,
+                options: None,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Success,
+            },
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: Some(Cow::Borrowed("wrong")),
+                ssl_mode: SslMode::Disable,
+                options: None,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(err.message(), "invalid password");
+                    assert_eq!(*err.code(), SqlState::INVALID_PASSWORD);
+                })),
+            },
+            // An empty password must not be mistaken for an anonymous bind.
+            TestCase::Pgwire {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                password: None,
+                ssl_mode: SslMode::Disable,
+                options: None,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(err.message(), "invalid password");
+                })),
+            },
+            // bob is not under the template's OU.
+            TestCase::Pgwire {
+                user_to_auth_as: "bob",
+                user_reported_by_system: "bob",
+                password: Some(Cow::Borrowed("hunter2")),
+                ssl_mode: SslMode::Disable,
+                options: None,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(err.message(), "invalid password");
+                })),
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTP,
+                headers: &alice_header,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Success,
+            },
+            TestCase::Http {
+                user_to_auth_as: "alice",
+                user_reported_by_system: "alice",
+                scheme: Scheme::HTTP,
+                headers: &alice_bad_header,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Err(Box::new(|code, _| {
+                    assert_eq!(code, Some(StatusCode::UNAUTHORIZED));
+                })),
+            },
+        ],
+    )
+    .await;
+
+    // Switch to search+bind, which finds users anywhere under the base DN.
+    for sql in [
+        "ALTER SYSTEM SET ldap_bind_dn_template = ''",
+        "ALTER SYSTEM SET ldap_search_base_dn = 'dc=example,dc=com'",
+    ] {
+        admin_client.batch_execute(sql).await.unwrap();
+    }
+
+    run_tests(
+        "LDAP search+bind",
+        &server,
+        &[
+            TestCase::Pgwire {
+                user_to_auth_as: "bob",
+                user_reported_by_system: "bob",
+                password: Some(Cow::Borrowed("hunter2")),
+                ssl_mode: SslMode::Disable,
+                options: None,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::Success,
+            },
+            // carol is a role but not a directory user.
+            TestCase::Pgwire {
+                user_to_auth_as: "carol",
+                user_reported_by_system: "carol",
+                password: Some(Cow::Borrowed("hunter2")),
+                ssl_mode: SslMode::Disable,
+                options: None,
+                configure: Box::new(|_| Ok(())),
+                assert: Assert::DbErr(Box::new(|err| {
+                    assert_eq!(err.message(), "invalid password");
+                })),
+            },
+        ],
+    )
+    .await;
+}
+
+/// Tests that LDAP group memberships are synced into role memberships at login.
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+#[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `OPENSSL_init_ssl` on OS `linux`
+async fn test_auth_ldap_group_sync() {
+    let alice_dn = "uid=alice,ou=people,dc=example,dc=com";
+    let analysts = "cn=analysts,ou=groups,dc=example,dc=com";
+    let admins = "cn=admins,ou=groups,dc=example,dc=com";
+    let ldap_server = LdapMockServer::start(
+        None,
+        vec![LdapMockEntry::user(
+            alice_dn,
+            "alice",
+            "secret",
+            &[analysts],
+        )],
+    )
+    .await
+    .unwrap();
+
+    let server = test_util::TestHarness::default()
+        .with_ldap_auth(ldap_server.url.clone())
+        .with_system_parameter_default(
+            "ldap_bind_dn_template".to_string(),
+            "uid={username},ou=people,dc=example,dc=com".to_string(),
+        )
+        .with_system_parameter_default(
+            "ldap_group_role_mapping".to_string(),
+            serde_json::to_string(&BTreeMap::from([(analysts, "analyst"), (admins, "admin")]))
+                .unwrap(),
+        )
+        .start()
+        .await;
+
+    let admin_client = server.connect().internal().await.unwrap();
+    for sql in [
+        "CREATE ROLE alice LOGIN",
+        "CREATE ROLE analyst",
+        "CREATE ROLE admin",
+        "CREATE ROLE manual",
+        "GRANT manual TO alice",
+    ] {
+        admin_client.batch_execute(sql).await.unwrap();
+    }
+
+    let login = || async {
+        server
+            .connect()
+            .user("alice")
+            .password("secret")
+            .await
+            .unwrap()
+    };
+    let memberships = || async {
+        admin_client
+            .query(
+                "SELECT r.name FROM mz_role_members m
+                 JOIN mz_roles r ON m.role_id = r.id
+                 JOIN mz_roles u ON m.member = u.id
+                 WHERE u.name = 'alice'
+                 ORDER BY r.name",
+                &[],
+            )
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| row.get::<_, String>(0))
+            .collect::<Vec<_>>()
+    };
+
+    // Roles not named in the mapping are left alone.
+    login().await;
+    assert_eq!(memberships().await, vec!["analyst", "manual"]);
+
+    ldap_server.set_attribute(alice_dn, "memberOf", vec![admins.to_string()]);
+    login().await;
+    assert_eq!(memberships().await, vec!["admin", "manual"]);
+
+    ldap_server.set_attribute(alice_dn, "memberOf", vec![]);
+    login().await;
+    assert_eq!(memberships().await, vec!["manual"]);
+}
@@ -14,10 +14,12 @@
 use std::io::{Read, Write};
 use std::net::TcpStream;
 use std::path::Path;
+use std::pin::Pin;
 use std::time::Duration;
 
-use bytes::BytesMut;
+use bytes::{Buf, BufMut, Bytes, BytesMut};
 use fallible_iterator::FallibleIterator;
+use futures::{SinkExt, StreamExt};
 use mz_adapter::session::DEFAULT_DATABASE_NAME;
 use mz_environmentd::test_util::{self, PostgresErrorExt};
 use mz_ore::collections::CollectionExt;
@@ -31,6 +33,8 @@ use postgres::error::SqlState;
 use postgres::types::Type;
 use postgres_array::{Array, Dimension};
 use tokio::sync::mpsc;
+use tokio_postgres::CopyBothDuplex;
+use tokio_postgres::config::ReplicationMode;
 
 #[mz_ore::test]
 fn test_bind_params() {
@@ -876,3 +880,301 @@ fn test_many_columns() {
         }
     }
 }
+
+/// Starts streaming from `slot` over a new logical replication connection.
+async fn start_replication(
+    server: &test_util::TestServer,
+    slot: &str,
+    publication: &str,
+) -> Result<(tokio_postgres::Client, Pin<Box<CopyBothDuplex<Bytes>>>), tokio_postgres::Error> {
+    let builder = server.connect().internal();
+    let mut config = builder.as_pg_config().clone();
+    config.replication_mode(ReplicationMode::Logical);
+    let client = builder.with_config(config).await?;
+    let query = format!(
+        "START_REPLICATION SLOT {slot} LOGICAL 0/0 \
+         (proto_version '1', publication_names '{publication}')"
+    );
+    let stream = client.copy_both_simple::<Bytes>(&query).await?;
+    Ok((client, Box::pin(stream)))
+}
+
+/// Receives the `pgoutput` messages of the next transaction of a replication
+/// stream, skipping keepalives.
+async fn recv_replication_transaction(stream: &mut Pin<Box<CopyBothDuplex<Bytes>>>) -> Vec<Bytes> {
+    let mut messages = Vec::new();
+    loop {
+        let mut data = stream
+            .next()
+            .await
+            .expect("replication stream ended")
+            .expect("replication stream failed");
+        match data.get_u8() {
+            b'k' => continue,
+            b'w' => {
+                // Skip the start and end positions and the send time.
+                data.advance(24);
+                let tag = data[0];
+                messages.push(data);
+                if tag == b'C' {
+                    return messages;
+                }
+            }
+            tag => panic!("unexpected replication message {tag}"),
+        }
+    }
+}
+
+/// Decodes an `Insert` message of the relation with OID `oid`.
+fn decode_replication_insert(mut message: Bytes, oid: u32) -> Vec<Option<String>> {
+    assert_eq!(message.get_u8(), b'I');
+    assert_eq!(message.get_u32(), oid);
+    assert_eq!(message.get_u8(), b'N');
+    let columns = message.get_u16();
+    (0..columns)
+        .map(|_| match message.get_u8() {
+            b'n' => None,
+            b't' => {
+                let len = usize::try_from(message.get_u32()).unwrap();
+                Some(String::from_utf8(message.split_to(len).to_vec()).unwrap())
+            }
+            kind => panic!("unexpected column kind {kind}"),
+        })
+        .collect()
+}
+
+/// Decodes a `Relation` message, returning the relation's OID and name.
+fn decode_replication_relation(mut message: Bytes) -> (u32, String) {
+    assert_eq!(message.get_u8(), b'R');
+    let oid = message.get_u32();
+    let schema_end = message.iter().position(|b| *b == 0).unwrap();
+    let schema = String::from_utf8(message.split_to(schema_end).to_vec()).unwrap();
+    message.advance(1);
+    let name_end = message.iter().position(|b| *b == 0).unwrap();
+    let name = String::from_utf8(message.split_to(name_end).to_vec()).unwrap();
+    (oid, format!("{schema}.{name}"))
+}
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+async fn test_logical_replication() {
+    let server = test_util::TestHarness::default().start().await;
+    server
+        .enable_feature_flags(&["enable_logical_replication"])
+        .await;
+    let client = server.connect().internal().await.unwrap();
+    client
+        .batch_execute(
+            "CREATE TABLE t (a int, b text);
+             CREATE PUBLICATION p FOR TABLE materialize.public.t;",
+        )
+        .await
+        .unwrap();
+
+    let builder = server.connect().internal();
+    let mut config = builder.as_pg_config().clone();
+    config.replication_mode(ReplicationMode::Logical);
+    let repl_client = builder.with_config(config).await.unwrap();
+    repl_client
+        .simple_query("CREATE_REPLICATION_SLOT s LOGICAL pgoutput")
+        .await
+        .unwrap();
+    drop(repl_client);
+
+    // Changes are streamed as pgoutput transactions inside CopyBoth.
+    let (repl_client, mut stream) = Retry::default()
+        .retry_async(|_| start_replication(&server, "s", "p"))
+        .await
+        .unwrap();
+    client
+        .batch_execute("INSERT INTO t VALUES (1, 'one'), (2, NULL)")
+        .await
+        .unwrap();
+    let mut messages = recv_replication_transaction(&mut stream).await;
+    assert_eq!(messages.len(), 5, "begin, relation, 2 inserts, commit");
+    assert_eq!(messages[0][0], b'B');
+    let (oid, name) = decode_replication_relation(messages[1].clone());
+    assert_eq!(name, "public.t");
+    let mut rows = vec![
+        decode_replication_insert(messages[2].clone(), oid),
+        decode_replication_insert(messages[3].clone(), oid),
+    ];
+    rows.sort();
+    assert_eq!(
+        rows,
+        vec![
+            vec![Some("1".into()), Some("one".into())],
+            vec![Some("2".into()), None],
+        ]
+    );
+    let mut commit = messages.pop().unwrap();
+    assert_eq!(commit.get_u8(), b'C');
+    commit.advance(1);
+    let commit_lsn = commit.get_u64();
+
+    // Confirm the transaction and disconnect.
+    let mut status_update = BytesMut::new();
+    status_update.put_u8(b'r');
+    status_update.put_u64(commit_lsn);
+    status_update.put_u64(commit_lsn);
+    status_update.put_u64(commit_lsn);
+    status_update.put_i64(0);
+    status_update.put_u8(0);
+    stream.send(status_update.freeze()).await.unwrap();
+    drop(stream);
+    drop(repl_client);
+
+    client
+        .batch_execute("INSERT INTO t VALUES (3, 'three')")
+        .await
+        .unwrap();
+
+    // Resuming from the slot only streams the changes that were not confirmed.
+    // The previous stream is only released once its connection is terminated.
+    let (_repl_client, mut stream) = Retry::default()
+        .retry_async(|_| start_replication(&server, "s", "p"))
+        .await
+        .unwrap();
+    let messages = recv_replication_transaction(&mut stream).await;
+    assert_eq!(messages.len(), 4, "begin, relation, insert, commit");
+    let mut begin = messages[0].clone();
+    assert_eq!(begin.get_u8(), b'B');
+    assert!(begin.get_u64() > commit_lsn);
+    assert_eq!(decode_replication_relation(messages[1].clone()).0, oid);
+    assert_eq!(
+        decode_replication_insert(messages[2].clone(), oid),
+        vec![Some("3".into()), Some("three".into())]
+    );
+}
+
+#[mz_ore::test(tokio::test(flavor = "multi_thread", worker_threads = 1))]
+async fn test_listen() {
+    // Counts the dataflows that back listen subscribes.
+    const LISTEN_DATAFLOW_QUERY: &str = "\
+        SELECT count(*) \
+        FROM mz_introspection.mz_dataflows \
+        WHERE name LIKE '%listen-%'";
+
+    let server = test_util::TestHarness::default()
+        .with_system_parameter_default("enable_listen_notify".to_string(), "true".to_string())
+        .start()
+        .await;
+    let client = server.connect().await.unwrap();
+    client
+        .batch_execute(
+            "CREATE TABLE orders (id int, amount int);
+             CREATE MATERIALIZED VIEW order_totals WITH (NOTIFY CHANNEL = order_changes)
+                AS SELECT sum(amount) AS total FROM orders;",
+        )
+        .await
+        .unwrap();
+
+    let await_listen_dataflows = |expected: i64| {
+        let client = &client;
+        async move {
+            Retry::default()
+                .max_duration(Duration::from_secs(60))
+                .retry_async(|_| async {
+                    let count: i64 = client
+                        .query_one(LISTEN_DATAFLOW_QUERY, &[])
+                        .await
+                        .map_err(|e| e.to_string())?
+                        .get(0);
+                    if count == expected {
+                        Ok(())
+                    } else {
+                        Err(format!("{count} listen dataflows, expected {expected}"))
+                    }
+                })
+                .await
+                .unwrap();
+        }
+    };
+
+    let (notification_tx, mut notification_rx) = mpsc::unbounded_channel();
+    let listener = server
+        .connect()
+        .notification_callback(move |notification| {
+            let _ = notification_tx.send(notification);
+        })
+        .await
+        .unwrap();
+    listener
+        .batch_execute("LISTEN order_changes")
+        .await
+        .unwrap();
+    await_listen_dataflows(1).await;
+
+    // Updating the single row of the materialized view is one deletion and one
+    // insertion.
+    client
+        .batch_execute("INSERT INTO orders VALUES (1, 10)")
+        .await
+        .unwrap();
+    let notification = tokio::time::timeout(Duration::from_secs(60), notification_rx.recv())
+        .await
+        .expect("timed out waiting for notification")
+        .unwrap();
+    assert_eq!(notification.channel(), "order_changes");
+    let payload: serde_json::Value = serde_json::from_str(notification.payload()).unwrap();
+    assert_eq!(payload["relation"], "materialize.public.order_totals");
+    assert_eq!(payload["inserts"], 1);
+    assert_eq!(payload["deletes"], 1);
+    assert!(
+        payload["timestamp"].is_u64(),
+        "unexpected payload: {payload}"
+    );
+
+    // Dropping the materialized view drops the listen subscribe.
+    client
+        .batch_execute("DROP MATERIALIZED VIEW order_totals")
+        .await
+        .unwrap();
+    await_listen_dataflows(0).await;
+
+    // So does closing the listening connection.
+    client
+        .batch_execute(
+            "CREATE MATERIALIZED VIEW order_totals WITH (NOTIFY CHANNEL = order_changes)
+                AS SELECT sum(amount) AS total FROM orders",
+        )
+        .await
+        .unwrap();
+    listener
+        .batch_execute("LISTEN order_changes")
+        .await
+        .unwrap();
+    await_listen_dataflows(1).await;
+
+    // A second listener shares the dataflow of the first, and both receive the
+    // notifications.
+    let (other_notification_tx, mut other_notification_rx) = mpsc::unbounded_channel();
+    let other_listener = server
+        .connect()
+        .notification_callback(move |notification| {
+            let _ = other_notification_tx.send(notification);
+        })
+        .await
+        .unwrap();
+    other_listener
+        .batch_execute("LISTEN order_changes")
+        .await
+        .unwrap();
+    await_listen_dataflows(1).await;
+    client
+        .batch_execute("INSERT INTO orders VALUES (2, 20)")
+        .await
+        .unwrap();
+    for rx in [&mut notification_rx, &mut other_notification_rx] {
+        let notification = tokio::time::timeout(Duration::from_secs(60), rx.recv())
+            .await
+            .expect("timed out waiting for notification")
+            .unwrap();
+        let payload: serde_json::Value = serde_json::from_str(notification.payload()).unwrap();
+        assert_eq!(payload["relation"], "materialize.public.order_totals");
+    }
+
+    // The dataflow is dropped once all listeners go away.
+    drop(listener);
+    drop(other_listener);
+    await_listen_dataflows(0).await;
+}
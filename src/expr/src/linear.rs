@@ -12,8 +12,9 @@ use std::fmt::Display;
 use mz_repr::{Datum, Row};
 use serde::{Deserialize, Serialize};
 
+use crate::func::TableSample;
 use crate::visit::Visit;
-use crate::{MirRelationExpr, MirScalarExpr};
+use crate::{MirRelationExpr, MirScalarExpr, VariadicFunc};
 
 /// A compound operator that can be applied row-by-row.
 ///
@@ -452,6 +453,26 @@ impl MapFilterProject {
             .project(0..old_projection_len)
     }
 
+    /// Removes a `TABLESAMPLE SYSTEM` predicate from `self`, if present, and returns it.
+    ///
+    /// The caller takes on the responsibility of sampling the input, e.g. by skipping
+    /// entire parts of a persist shard, in place of the removed predicate.
+    pub fn take_system_sample(&mut self) -> Option<TableSample> {
+        let position = self
+            .predicates
+            .iter()
+            .position(|(_, predicate)| predicate.is_system_sample())?;
+        let (_, predicate) = self.predicates.remove(position);
+        let MirScalarExpr::CallVariadic {
+            func: VariadicFunc::TableSample(sample),
+            ..
+        } = predicate
+        else {
+            unreachable!("checked above")
+        };
+        Some(sample)
+    }
+
     /// Extracts common expressions from multiple `Self` into a result `Self`.
     ///
     /// The argument `mfps` are mutated so that each are functionaly equivalent to their
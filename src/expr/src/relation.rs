@@ -2531,8 +2531,10 @@ impl AggregateExpr {
     /// returned by `on_unique`, which is cheaper. (See `ReduceElision`.)
     pub fn on_unique(&self, input_type: &[ReprColumnType]) -> MirScalarExpr {
         match &self.func {
-            // Count is one if non-null, and zero if null.
-            AggregateFunc::Count => self
+            // Count is one if non-null, and zero if null. The same holds for the
+            // approximate count of distinct values, which is exact for a single
+            // value.
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct => self
                 .expr
                 .clone()
                 .call_unary(UnaryFunc::IsNull(crate::func::IsNull))
@@ -2629,20 +2631,45 @@ impl AggregateExpr {
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)))
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
 
+            // PercentileCont and PercentileDisc take a (value, percentile) record and output the value
+            AggregateFunc::PercentileCont { .. } | AggregateFunc::PercentileDisc { .. } => self
+                .expr
+                .clone()
+                .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
+
             // ListConcat and ArrayConcat take a single level of records and output a list containing exactly 1 element
             AggregateFunc::ListConcat { .. } | AggregateFunc::ArrayConcat { .. } => self
                 .expr
                 .clone()
                 .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0))),
 
-            // RowNumber, Rank, DenseRank take a list of records and output a list containing exactly 1 element
-            AggregateFunc::RowNumber { .. } => {
-                self.on_unique_ranking_window_funcs(input_type, "?row_number?")
-            }
-            AggregateFunc::Rank { .. } => self.on_unique_ranking_window_funcs(input_type, "?rank?"),
-            AggregateFunc::DenseRank { .. } => {
-                self.on_unique_ranking_window_funcs(input_type, "?dense_rank?")
-            }
+            // RowNumber, Rank, DenseRank, PercentRank, CumeDist take a list of records and output a
+            // list containing exactly 1 element
+            AggregateFunc::RowNumber { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?row_number?",
+                MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
+            ),
+            AggregateFunc::Rank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?rank?",
+                MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
+            ),
+            AggregateFunc::DenseRank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?dense_rank?",
+                MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
+            ),
+            AggregateFunc::PercentRank { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?percent_rank?",
+                MirScalarExpr::literal_ok(Datum::from(0.0f64), ReprScalarType::Float64),
+            ),
+            AggregateFunc::CumeDist { .. } => self.on_unique_ranking_window_funcs(
+                input_type,
+                "?cume_dist?",
+                MirScalarExpr::literal_ok(Datum::from(1.0f64), ReprScalarType::Float64),
+            ),
 
             // The input type for LagLead is ((OriginalRow, (InputValue, Offset, Default)), OrderByExprs...)
             AggregateFunc::LagLead { lag_lead, .. } => {
@@ -2771,6 +2798,51 @@ impl AggregateExpr {
                 )
             }
 
+            // The input type for Ntile is ((OriginalRow, NumBuckets), OrderByExprs...)
+            // The input type for NthValue is ((OriginalRow, (InputValue, N)), OrderByExprs...)
+            AggregateFunc::Ntile { .. } | AggregateFunc::NthValue { .. } => {
+                let tuple = self
+                    .expr
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Get the overall return type
+                let return_type_with_orig_row = self
+                    .typ(input_type)
+                    .scalar_type
+                    .unwrap_list_element_type()
+                    .clone();
+                let return_type = return_type_with_orig_row.unwrap_record_element_type()[0].clone();
+
+                // Extract the original row
+                let original_row = tuple
+                    .clone()
+                    .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+
+                // Extract the encoded args
+                let encoded_args =
+                    tuple.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+
+                let (result_expr, column_name) = match &self.func {
+                    AggregateFunc::NthValue { window_frame, .. } => {
+                        Self::on_unique_nth_value(window_frame, encoded_args, return_type)
+                    }
+                    _ => Self::on_unique_ntile(encoded_args),
+                };
+
+                MirScalarExpr::call_variadic(
+                    ListCreate {
+                        elem_type: SqlScalarType::from_repr(&return_type_with_orig_row),
+                    },
+                    vec![MirScalarExpr::call_variadic(
+                        RecordCreate {
+                            field_names: vec![column_name, ColumnName::from("?record?")],
+                        },
+                        vec![result_expr, original_row],
+                    )],
+                )
+            }
+
             // The input type for window aggs is ((OriginalRow, InputValue), OrderByExprs...)
             // See an example MIR in `window_func_applied_to`.
             AggregateFunc::WindowAggregate {
@@ -2960,6 +3032,21 @@ impl AggregateExpr {
                                 return_type_for_func,
                             )
                         }
+                        AggregateFunc::Ntile { order_by } => {
+                            assert_eq!(order_by, outer_order_by);
+                            Self::on_unique_ntile(args_for_func)
+                        }
+                        AggregateFunc::NthValue {
+                            window_frame,
+                            order_by,
+                        } => {
+                            assert_eq!(order_by, outer_order_by);
+                            Self::on_unique_nth_value(
+                                window_frame,
+                                args_for_func,
+                                return_type_for_func,
+                            )
+                        }
                         _ => panic!("unknown function in FusedValueWindowFunc"),
                     };
                     func_result_exprs.push(result);
@@ -3030,15 +3117,18 @@ impl AggregateExpr {
             | AggregateFunc::SumNumeric
             | AggregateFunc::Any
             | AggregateFunc::All
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::HllMerge
             | AggregateFunc::Dummy => self.expr.clone(),
         }
     }
 
-    /// `on_unique` for ROW_NUMBER, RANK, DENSE_RANK
+    /// `on_unique` for ROW_NUMBER, RANK, DENSE_RANK, PERCENT_RANK, CUME_DIST
     fn on_unique_ranking_window_funcs(
         &self,
         input_type: &[ReprColumnType],
         col_name: &str,
+        result_expr: MirScalarExpr,
     ) -> MirScalarExpr {
         let sql_input_type: Vec<SqlColumnType> =
             input_type.iter().map(SqlColumnType::from_repr).collect();
@@ -3069,10 +3159,7 @@ impl AggregateExpr {
                 RecordCreate {
                     field_names: vec![ColumnName::from(col_name), ColumnName::from("?record?")],
                 },
-                vec![
-                    MirScalarExpr::literal_ok(Datum::Int64(1), ReprScalarType::Int64),
-                    record,
-                ],
+                vec![result_expr, record],
             )],
         )
     }
@@ -3128,6 +3215,42 @@ impl AggregateExpr {
         (result_expr, ColumnName::from("?first_value?"))
     }
 
+    /// `on_unique` for `ntile`
+    fn on_unique_ntile(num_buckets: MirScalarExpr) -> (MirScalarExpr, ColumnName) {
+        // The single row always goes into the first bucket.
+        let result_expr = num_buckets
+            .call_unary(UnaryFunc::IsNull(crate::func::IsNull))
+            .if_then_else(
+                MirScalarExpr::literal_null(ReprScalarType::Int32),
+                MirScalarExpr::literal_ok(Datum::Int32(1), ReprScalarType::Int32),
+            );
+        (result_expr, ColumnName::from("?ntile?"))
+    }
+
+    /// `on_unique` for `nth_value`
+    fn on_unique_nth_value(
+        window_frame: &WindowFrame,
+        encoded_args: MirScalarExpr,
+        return_type: ReprScalarType,
+    ) -> (MirScalarExpr, ColumnName) {
+        // If the window frame includes the current (single) row, then it's the first row of the
+        // frame, so return its value if the first value was asked for, and null otherwise.
+        let result_expr = if window_frame.includes_current_row() {
+            let expr = encoded_args
+                .clone()
+                .call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(0)));
+            let n = encoded_args.call_unary(UnaryFunc::RecordGet(scalar_func::RecordGet(1)));
+            n.call_binary(
+                MirScalarExpr::literal_ok(Datum::Int32(1), ReprScalarType::Int32),
+                crate::func::Eq,
+            )
+            .if_then_else(expr, MirScalarExpr::literal_null(return_type))
+        } else {
+            MirScalarExpr::literal_null(return_type)
+        };
+        (result_expr, ColumnName::from("?nth_value?"))
+    }
+
     /// `on_unique` for window aggregations
     fn on_unique_window_agg(
         window_frame: &WindowFrame,
@@ -10,6 +10,7 @@
 #![allow(missing_docs)]
 
 use std::cmp::{max, min};
+use std::collections::BTreeMap;
 use std::iter::Sum;
 use std::ops::Deref;
 use std::str::FromStr;
@@ -19,12 +20,13 @@ use chrono::{DateTime, NaiveDateTime, NaiveTime, Utc};
 use dec::OrderedDecimal;
 use itertools::{Either, Itertools};
 use mz_lowertest::MzReflect;
-use mz_ore::cast::CastFrom;
+use mz_ore::cast::{CastFrom, CastLossy};
 
 use mz_ore::str::separated;
-use mz_ore::{soft_assert_eq_no_log, soft_assert_or_log};
+use mz_ore::{soft_assert_eq_no_log, soft_assert_or_log, soft_panic_or_log};
 use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::date::Date;
+use mz_repr::adt::hll::HyperLogLog;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::numeric::{self, Numeric, NumericMaxScale};
 use mz_repr::adt::regex::{Regex as ReprRegex, RegexCompilationError};
@@ -223,6 +225,160 @@ where
     Datum::String(temp_storage.push_string(s))
 }
 
+/// Returns the value at zero-indexed position `rank` in the sorted order of
+/// `values`, where each value is repeated according to its multiplicity.
+///
+/// This is a quickselect that partitions the distinct values around a pivot
+/// and discards the half that cannot contain `rank`. It runs in expected time
+/// linear in `values.len()` and avoids sorting `values` entirely.
+///
+/// # Panics
+///
+/// Panics if `rank` is not less than the total multiplicity of `values`.
+fn weighted_select<'a>(
+    mut values: &mut [(Datum<'a>, usize)],
+    mut rank: usize,
+    desc: bool,
+) -> Datum<'a> {
+    let cmp = |(a, _): &(Datum<'a>, usize), (b, _): &(Datum<'a>, usize)| {
+        if desc { b.cmp(a) } else { a.cmp(b) }
+    };
+    loop {
+        let mid = values.len() / 2;
+        let (lower, (pivot, pivot_count), upper) = { values }.select_nth_unstable_by(mid, cmp);
+        let lower_count: usize = lower.iter().map(|(_, count)| count).sum();
+        if rank < lower_count {
+            values = lower;
+        } else if rank < lower_count + *pivot_count {
+            return *pivot;
+        } else {
+            rank -= lower_count + *pivot_count;
+            values = upper;
+        }
+    }
+}
+
+/// Unpacks the input of `percentile_cont` and `percentile_disc`, whose datums
+/// are `(value, percentile)` records.
+///
+/// Returns the requested percentile, the non-null values with their
+/// multiplicities, and the total multiplicity of those values. Returns `None`
+/// if there are no non-null values or the percentile is null, in which case the
+/// result of the aggregation is null.
+fn percentile_input<'a, I>(datums: I) -> Option<(f64, Vec<(Datum<'a>, usize)>, usize)>
+where
+    I: IntoIterator<Item = (Datum<'a>, usize)>,
+{
+    let mut percentile = None;
+    let mut values = Vec::new();
+    let mut total = 0;
+    for (datum, count) in datums {
+        if datum.is_null() || count == 0 {
+            continue;
+        }
+        let mut fields = datum.unwrap_list().iter();
+        let value = fields.next().unwrap();
+        // The percentile is the same for every datum in the group.
+        let percentile = percentile.get_or_insert_with(|| fields.next().unwrap());
+        if !value.is_null() && !percentile.is_null() {
+            values.push((value, count));
+            total += count;
+        }
+    }
+    match percentile {
+        Some(Datum::Float64(percentile)) if total > 0 => {
+            Some((percentile.into_inner(), values, total))
+        }
+        _ => None,
+    }
+}
+
+fn percentile_cont<'a, I>(datums: I, desc: bool) -> Datum<'a>
+where
+    I: IntoIterator<Item = (Datum<'a>, usize)>,
+{
+    let Some((percentile, mut values, total)) = percentile_input(datums) else {
+        return Datum::Null;
+    };
+    // Like PostgreSQL, interpolate between the values at the positions
+    // surrounding `percentile * (total - 1)`. Planning ensures that the
+    // percentile is between 0 and 1, but clamp anyway, so that a bad one can't
+    // ask for a rank past the last value.
+    let last = f64::cast_lossy(total - 1);
+    let position = (percentile * last).clamp(0.0, last);
+    let lower_rank = usize::cast_lossy(position.floor()).min(total - 1);
+    let upper_rank = usize::cast_lossy(position.ceil()).min(total - 1);
+    let lower = weighted_select(&mut values, lower_rank, desc).unwrap_float64();
+    if upper_rank == lower_rank {
+        return Datum::from(lower);
+    }
+    let upper = weighted_select(&mut values, upper_rank, desc).unwrap_float64();
+    Datum::from(lower + (upper - lower) * (position - f64::cast_lossy(lower_rank)))
+}
+
+fn percentile_disc<'a, I>(datums: I, desc: bool) -> Datum<'a>
+where
+    I: IntoIterator<Item = (Datum<'a>, usize)>,
+{
+    let Some((percentile, mut values, total)) = percentile_input(datums) else {
+        return Datum::Null;
+    };
+    // Like PostgreSQL, return the first value whose position in the ordering
+    // is at or past `percentile * total`.
+    let rank = usize::cast_lossy((percentile * f64::cast_lossy(total)).ceil()).clamp(1, total) - 1;
+    weighted_select(&mut values, rank, desc)
+}
+
+fn mode<'a, I>(datums: I, desc: bool) -> Datum<'a>
+where
+    I: IntoIterator<Item = (Datum<'a>, usize)>,
+{
+    let mut result: Option<(Datum<'a>, usize)> = None;
+    for (datum, count) in datums {
+        if datum.is_null() || count == 0 {
+            continue;
+        }
+        let replace = match result {
+            None => true,
+            Some((best, best_count)) => {
+                let sorts_first = if desc { datum > best } else { datum < best };
+                count > best_count || (count == best_count && sorts_first)
+            }
+        };
+        if replace {
+            result = Some((datum, count));
+        }
+    }
+    result.map_or(Datum::Null, |(datum, _)| datum)
+}
+
+/// Merges the serialized [`HyperLogLog`] sketches among `datums`, ignoring
+/// their multiplicities. Returns `None` if there are no sketches to merge.
+fn hll_merge<'a, I>(datums: I) -> Option<HyperLogLog>
+where
+    I: IntoIterator<Item = (Datum<'a>, usize)>,
+{
+    let mut merged: Option<HyperLogLog> = None;
+    for (datum, count) in datums {
+        if datum.is_null() || count == 0 {
+            continue;
+        }
+        // The sketches were validated before they were aggregated, so neither
+        // decoding nor merging should fail.
+        let result = HyperLogLog::decode(datum.unwrap_bytes()).and_then(|sketch| {
+            match &mut merged {
+                Some(merged) => merged.merge(&sketch)?,
+                None => merged = Some(sketch),
+            }
+            Ok(())
+        });
+        if let Err(e) = result {
+            soft_panic_or_log!("invalid HyperLogLog sketch in aggregation: {e}");
+        }
+    }
+    merged
+}
+
 fn jsonb_agg<'a, I>(datums: I, temp_storage: &'a RowArena, order_by: &[ColumnOrder]) -> Datum<'a>
 where
     I: IntoIterator<Item = Datum<'a>>,
@@ -577,6 +733,134 @@ where
     })
 }
 
+/// The expected input is in the format of `[((OriginalRow, [EncodedArgs]), OrderByExprs...)]`
+/// The output is in the format of `[result_value, original_row]`.
+/// See an example at `lag_lead`, where the input-output formats are similar.
+fn percent_rank<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let datums = percent_rank_no_list(datums, &temp_storage, order_by);
+
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(datums);
+    })
+}
+
+/// Like `percent_rank`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn percent_rank_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let datums = flatten_ranked_datums(datums, order_by);
+
+    // The relative rank is `(rank - 1) / (partition size - 1)`, or 0 for a single-row partition.
+    let denominator = f64::cast_lossy(datums.len().saturating_sub(1)).max(1.0);
+    let mut rank = 0;
+    let results: Vec<_> = datums
+        .iter()
+        .enumerate()
+        .map(|(idx, (d, order_row))| {
+            if idx == 0 || datums[idx - 1].1 != *order_row {
+                rank = idx;
+            }
+            (*d, f64::cast_lossy(rank) / denominator)
+        })
+        .collect();
+
+    callers_temp_storage.reserve(results.len());
+    results.into_iter().map(|(d, percent_rank)| {
+        callers_temp_storage.make_datum(|packer| {
+            packer.push_list_with(|packer| {
+                packer.push(Datum::Float64(percent_rank.into()));
+                packer.push(d);
+            });
+        })
+    })
+}
+
+/// The expected input is in the format of `[((OriginalRow, [EncodedArgs]), OrderByExprs...)]`
+/// The output is in the format of `[result_value, original_row]`.
+/// See an example at `lag_lead`, where the input-output formats are similar.
+fn cume_dist<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let datums = cume_dist_no_list(datums, &temp_storage, order_by);
+
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(datums);
+    })
+}
+
+/// Like `cume_dist`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn cume_dist_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let datums = flatten_ranked_datums(datums, order_by);
+
+    // The cumulative distribution is the number of rows preceding or peer with the current row,
+    // divided by the partition size. So we need to know where each peer group ends.
+    let length = datums.len();
+    let mut results = Vec::with_capacity(length);
+    let mut peer_group_start = 0;
+    for idx in 0..length {
+        if idx + 1 == length || datums[idx + 1].1 != datums[idx].1 {
+            let cume_dist = f64::cast_lossy(idx + 1) / f64::cast_lossy(length);
+            for (d, _) in &datums[peer_group_start..=idx] {
+                results.push((*d, cume_dist));
+            }
+            peer_group_start = idx + 1;
+        }
+    }
+
+    callers_temp_storage.reserve(results.len());
+    results.into_iter().map(|(d, cume_dist)| {
+        callers_temp_storage.make_datum(|packer| {
+            packer.push_list_with(|packer| {
+                packer.push(Datum::Float64(cume_dist.into()));
+                packer.push(d);
+            });
+        })
+    })
+}
+
+/// Sorts the input of a ranking window function, and unwraps the original rows from their lists,
+/// keeping the row used for ordering around to identify peers.
+fn flatten_ranked_datums<'a, I>(datums: I, order_by: &[ColumnOrder]) -> Vec<(Datum<'a>, Row)>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    order_aggregate_datums_with_rank(datums, order_by)
+        .flat_map(|(d0, order_row)| {
+            d0.unwrap_list()
+                .iter()
+                .map(move |d1| (d1, order_row.clone()))
+        })
+        .collect()
+}
+
 /// The expected input is in the format of `[((OriginalRow, EncodedArgs), OrderByExprs...)]`
 /// For example,
 ///
@@ -1116,11 +1400,238 @@ fn last_value_inner<'a>(
     results
 }
 
+/// The expected input is in the format of [((OriginalRow, NumBuckets), OrderByExprs...)]
+fn ntile<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let iter = ntile_no_list(datums, &temp_storage, order_by);
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(iter);
+    })
+}
+
+/// Like `ntile`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn ntile_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the (OriginalRow, NumBuckets) record
+    let datums = order_aggregate_datums(datums, order_by);
+
+    // Decode the input (OriginalRow, NumBuckets) into separate datums
+    let (orig_rows, args): (Vec<_>, Vec<_>) = datums
+        .into_iter()
+        .map(|d| {
+            let mut iter = d.unwrap_list().iter();
+            let original_row = iter.next().unwrap();
+            let arg = iter.next().unwrap();
+
+            (original_row, arg)
+        })
+        .unzip();
+
+    let results = ntile_inner(args);
+
+    callers_temp_storage.reserve(results.len());
+    results
+        .into_iter()
+        .zip_eq(orig_rows)
+        .map(|(result_value, original_row)| {
+            callers_temp_storage.make_datum(|packer| {
+                packer.push_list_with(|packer| {
+                    packer.push(result_value);
+                    packer.push(original_row);
+                });
+            })
+        })
+}
+
+/// `args` has the number of buckets evaluated for each input row. Like in Postgres, only the
+/// value for the first row of the partition is considered. (The planning ensures that it's
+/// positive.)
+fn ntile_inner<'a>(args: Vec<Datum<'a>>) -> Vec<Datum<'a>> {
+    let length = args.len();
+    let num_buckets = match args.first() {
+        None | Some(Datum::Null) => return vec![Datum::Null; length],
+        Some(num_buckets) => usize::cast_from(
+            u32::try_from(num_buckets.unwrap_int32()).expect("checked during planning"),
+        ),
+    };
+
+    // The first `remainder` buckets get one more row than the others.
+    let rows_per_bucket = length / num_buckets;
+    let remainder = length % num_buckets;
+    let larger_buckets_rows = remainder * (rows_per_bucket + 1);
+    (0..length)
+        .map(|idx| {
+            let bucket = if idx < larger_buckets_rows {
+                idx / (rows_per_bucket + 1)
+            } else {
+                remainder + (idx - larger_buckets_rows) / rows_per_bucket
+            };
+            Datum::Int32(i32::try_from(bucket + 1).expect("at most `num_buckets`"))
+        })
+        .collect()
+}
+
+/// The expected input is in the format of [((OriginalRow, (InputValue, N)), OrderByExprs...)]
+fn nth_value<'a, I>(
+    datums: I,
+    callers_temp_storage: &'a RowArena,
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> Datum<'a>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    let temp_storage = RowArena::new();
+    let iter = nth_value_no_list(datums, &temp_storage, order_by, window_frame);
+    callers_temp_storage.make_datum(|packer| {
+        packer.push_list(iter);
+    })
+}
+
+/// Like `nth_value`, but doesn't perform the final wrapping in a list, returning an Iterator
+/// instead.
+fn nth_value_no_list<'a: 'b, 'b, I>(
+    datums: I,
+    callers_temp_storage: &'b RowArena,
+    order_by: &[ColumnOrder],
+    window_frame: &WindowFrame,
+) -> impl Iterator<Item = Datum<'b>>
+where
+    I: IntoIterator<Item = Datum<'a>>,
+{
+    // Sort the datums according to the ORDER BY expressions and return the ((OriginalRow, EncodedArgs), OrderByRow) record
+    // The OrderByRow is kept around because it is required to compute the peer groups in RANGE mode
+    let datums = order_aggregate_datums_with_rank(datums, order_by);
+
+    // Decode the input (OriginalRow, EncodedArgs) into separate datums, while keeping the OrderByRow
+    let size_hint = datums.size_hint().0;
+    let mut args = Vec::with_capacity(size_hint);
+    let mut original_rows = Vec::with_capacity(size_hint);
+    let mut order_by_rows = Vec::with_capacity(size_hint);
+    for (d, order_by_row) in datums.into_iter() {
+        let mut iter = d.unwrap_list().iter();
+        let original_row = iter.next().unwrap();
+        let encoded_args = iter.next().unwrap();
+        order_by_rows.push(order_by_row);
+        original_rows.push(original_row);
+        args.push(unwrap_nth_value_encoded_args(encoded_args));
+    }
+
+    let results = nth_value_inner(args, &order_by_rows, window_frame);
+
+    callers_temp_storage.reserve(results.len());
+    results
+        .into_iter()
+        .zip_eq(original_rows)
+        .map(|(result_value, original_row)| {
+            callers_temp_storage.make_datum(|packer| {
+                packer.push_list_with(|packer| {
+                    packer.push(result_value);
+                    packer.push(original_row);
+                });
+            })
+        })
+}
+
+/// nth_value's arguments are in a record. This function unwraps this record.
+fn unwrap_nth_value_encoded_args(encoded_args: Datum) -> (Datum, Datum) {
+    let mut encoded_args_iter = encoded_args.unwrap_list().iter();
+    (
+        encoded_args_iter.next().unwrap(),
+        encoded_args_iter.next().unwrap(),
+    )
+}
+
+/// Each element of `args` has the value and the (1-based) position of the value to return within
+/// the window frame, evaluated for a single input row.
+fn nth_value_inner<'a>(
+    args: Vec<(Datum<'a>, Datum<'a>)>,
+    order_by_rows: &[Row],
+    window_frame: &WindowFrame,
+) -> Vec<Datum<'a>> {
+    let length = args.len();
+    let mut results: Vec<Datum> = Vec::with_capacity(length);
+    for (idx, (_, n)) in args.iter().enumerate() {
+        let nth_value = if n.is_null() {
+            Datum::Null
+        } else {
+            let n =
+                usize::cast_from(u32::try_from(n.unwrap_int32()).expect("checked during planning"));
+            window_frame_bounds(idx, length, order_by_rows, window_frame)
+                .and_then(|(start_idx, end_idx)| {
+                    let target_idx = start_idx + (n - 1);
+                    (target_idx <= end_idx).then(|| args[target_idx].0)
+                })
+                .unwrap_or(Datum::Null)
+        };
+        results.push(nth_value);
+    }
+    results
+}
+
+/// Returns the first and last index (both inclusive) of the window frame of the row at `idx` in a
+/// window partition of `length` rows, or `None` if the frame is empty.
+///
+/// `order_by_rows` is needed only to find the peers of the current row in RANGE mode.
+fn window_frame_bounds(
+    idx: usize,
+    length: usize,
+    order_by_rows: &[Row],
+    window_frame: &WindowFrame,
+) -> Option<(usize, usize)> {
+    let start_idx = match &window_frame.start_bound {
+        UnboundedPreceding => 0,
+        OffsetPreceding(offset) => idx.saturating_sub(usize::cast_from(*offset)),
+        CurrentRow => idx,
+        OffsetFollowing(offset) => idx.saturating_add(usize::cast_from(*offset)),
+        // Forbidden during planning
+        UnboundedFollowing => unreachable!(),
+    };
+    let end_idx = match &window_frame.end_bound {
+        UnboundedFollowing => length - 1,
+        OffsetFollowing(offset) => min(idx.saturating_add(usize::cast_from(*offset)), length - 1),
+        CurrentRow => match &window_frame.units {
+            Rows => idx,
+            // When in RANGE mode, the frame ends at the last row of the peer group, i.e., the
+            // group of rows with the same ORDER BY value.
+            // Note: Range is only supported for the default window frame (RANGE BETWEEN UNBOUNDED
+            // PRECEDING AND CURRENT ROW).
+            Range => {
+                idx + order_by_rows[idx + 1..]
+                    .iter()
+                    .take_while(|row| **row == order_by_rows[idx])
+                    .count()
+            }
+            // GROUPS is not supported, and forbidden during planning
+            Groups => unreachable!(),
+        },
+        // If the frame ends before the first row, it's empty.
+        OffsetPreceding(offset) => idx.checked_sub(usize::cast_from(*offset))?,
+        // Forbidden during planning
+        UnboundedPreceding => unreachable!(),
+    };
+    (start_idx <= end_idx).then_some((start_idx, end_idx))
+}
+
 /// Executes `FusedValueWindowFunc` on a reduction group.
 /// The expected input is in the format of `[((OriginalRow, (Args1, Args2, ...)), OrderByExprs...)]`
 /// where `Args1`, `Args2`, are the arguments of each of the fused functions. For functions that
-/// have only a single argument (first_value/last_value), these are simple values. For functions
-/// that have multiple arguments (lag/lead), these are also records.
+/// have only a single argument (first_value/last_value/ntile), these are simple values. For
+/// functions that have multiple arguments (lag/lead/nth_value), these are also records.
 fn fused_value_window_func<'a, I>(
     input_datums: I,
     callers_temp_storage: &'a RowArena,
@@ -1148,9 +1659,12 @@ fn fused_value_window_func_no_list<'a: 'b, 'b, I>(
 where
     I: IntoIterator<Item = Datum<'a>>,
 {
-    let has_last_value = funcs
-        .iter()
-        .any(|f| matches!(f, AggregateFunc::LastValue { .. }));
+    let needs_order_by_rows = funcs.iter().any(|f| {
+        matches!(
+            f,
+            AggregateFunc::LastValue { .. } | AggregateFunc::NthValue { .. }
+        )
+    });
 
     let input_datums_with_ranks = order_aggregate_datums_with_rank(input_datums, order_by);
 
@@ -1167,7 +1681,7 @@ where
             let encoded_args = argss_iter.next().unwrap();
             encoded_argsss[i].push(encoded_args);
         }
-        if has_last_value {
+        if needs_order_by_rows {
             order_by_rows.push(order_by_row);
         }
     }
@@ -1205,6 +1719,23 @@ where
                 // wrapped into a record.)
                 last_value_inner(encoded_argss, &order_by_rows, window_frame)
             }
+            AggregateFunc::Ntile {
+                order_by: inner_order_by,
+            } => {
+                assert_eq!(order_by, inner_order_by);
+                ntile_inner(encoded_argss)
+            }
+            AggregateFunc::NthValue {
+                order_by: inner_order_by,
+                window_frame,
+            } => {
+                assert_eq!(order_by, inner_order_by);
+                let unwrapped_argss = encoded_argss
+                    .into_iter()
+                    .map(|encoded_args| unwrap_nth_value_encoded_args(encoded_args))
+                    .collect();
+                nth_value_inner(unwrapped_argss, &order_by_rows, window_frame)
+            }
             _ => panic!("unknown window function in FusedValueWindowFunc"),
         };
         for (results, result) in results_per_row.iter_mut().zip_eq(results) {
@@ -1903,6 +2434,33 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// Computes the continuous percentile of `Datum::List`s whose first element
+    /// is a `Datum::Float64` to aggregate and whose second element is the
+    /// requested percentile, interpolating between adjacent values if needed.
+    /// Values are ordered descending if `desc` is set.
+    PercentileCont {
+        desc: bool,
+    },
+    /// Like `PercentileCont`, but returns the first value at or past the
+    /// requested percentile rather than interpolating. The values may be of
+    /// any type.
+    PercentileDisc {
+        desc: bool,
+    },
+    /// Returns the most frequent input value, breaking ties in favor of the
+    /// value that sorts first (or last, if `desc` is set).
+    Mode {
+        desc: bool,
+    },
+    /// Merges serialized `HyperLogLog` sketches into a single sketch.
+    ///
+    /// Applied to the singleton sketches of individual values, this maintains
+    /// a counting sketch: each distinct `(register, rank)` pair is one distinct
+    /// input datum, so retractions are handled by the input's multiplicities.
+    HllMerge,
+    /// Like `HllMerge`, but returns the sketch's estimate of the number of
+    /// distinct values rather than the sketch itself.
+    ApproxCountDistinct,
     RowNumber {
         order_by: Vec<ColumnOrder>,
     },
@@ -1912,6 +2470,15 @@ pub enum AggregateFunc {
     DenseRank {
         order_by: Vec<ColumnOrder>,
     },
+    PercentRank {
+        order_by: Vec<ColumnOrder>,
+    },
+    CumeDist {
+        order_by: Vec<ColumnOrder>,
+    },
+    Ntile {
+        order_by: Vec<ColumnOrder>,
+    },
     LagLead {
         order_by: Vec<ColumnOrder>,
         lag_lead: LagLeadType,
@@ -1925,6 +2492,10 @@ pub enum AggregateFunc {
         order_by: Vec<ColumnOrder>,
         window_frame: WindowFrame,
     },
+    NthValue {
+        order_by: Vec<ColumnOrder>,
+        window_frame: WindowFrame,
+    },
     /// Several value window functions fused into one function, to amortize overheads.
     FusedValueWindowFunc {
         funcs: Vec<AggregateFunc>,
@@ -2020,9 +2591,29 @@ impl AggregateFunc {
             AggregateFunc::ArrayConcat { order_by } => array_concat(datums, temp_storage, order_by),
             AggregateFunc::ListConcat { order_by } => list_concat(datums, temp_storage, order_by),
             AggregateFunc::StringAgg { order_by } => string_agg(datums, temp_storage, order_by),
+            AggregateFunc::PercentileCont { desc } => {
+                percentile_cont(datums.into_iter().map(|d| (d, 1)), *desc)
+            }
+            AggregateFunc::PercentileDisc { desc } => {
+                percentile_disc(datums.into_iter().map(|d| (d, 1)), *desc)
+            }
+            AggregateFunc::Mode { desc } => {
+                // Unlike in `eval_weighted`, equal datums may appear separately.
+                let mut counts = BTreeMap::new();
+                for datum in datums {
+                    *counts.entry(datum).or_insert(0) += 1;
+                }
+                mode(counts, *desc)
+            }
+            AggregateFunc::HllMerge | AggregateFunc::ApproxCountDistinct => {
+                self.eval_weighted(datums.into_iter().map(|d| (d, 1)), temp_storage)
+            }
             AggregateFunc::RowNumber { order_by } => row_number(datums, temp_storage, order_by),
             AggregateFunc::Rank { order_by } => rank(datums, temp_storage, order_by),
             AggregateFunc::DenseRank { order_by } => dense_rank(datums, temp_storage, order_by),
+            AggregateFunc::PercentRank { order_by } => percent_rank(datums, temp_storage, order_by),
+            AggregateFunc::CumeDist { order_by } => cume_dist(datums, temp_storage, order_by),
+            AggregateFunc::Ntile { order_by } => ntile(datums, temp_storage, order_by),
             AggregateFunc::LagLead {
                 order_by,
                 lag_lead: lag_lead_type,
@@ -2036,6 +2627,10 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value(datums, temp_storage, order_by, window_frame),
+            AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            } => nth_value(datums, temp_storage, order_by, window_frame),
             AggregateFunc::WindowAggregate {
                 wrapped_aggregate,
                 order_by,
@@ -2065,6 +2660,50 @@ impl AggregateFunc {
         }
     }
 
+    /// Like `eval`, but it's given each distinct input datum together with its
+    /// multiplicity, rather than the datum repeated that many times.
+    ///
+    /// Ordered-set aggregates are computed directly from the weighted input in
+    /// time linear in the number of distinct datums, without sorting them, and
+    /// the HyperLogLog aggregates ignore multiplicities entirely. All other
+    /// aggregates fall back to `eval` on the expanded input.
+    pub fn eval_weighted<'a, I>(&self, datums: I, temp_storage: &'a RowArena) -> Datum<'a>
+    where
+        I: IntoIterator<Item = (Datum<'a>, usize)>,
+    {
+        match self {
+            AggregateFunc::PercentileCont { desc } => percentile_cont(datums, *desc),
+            AggregateFunc::PercentileDisc { desc } => percentile_disc(datums, *desc),
+            AggregateFunc::Mode { desc } => mode(datums, *desc),
+            AggregateFunc::HllMerge => hll_merge(datums).map_or(Datum::Null, |sketch| {
+                Datum::Bytes(temp_storage.push_bytes(sketch.encode()))
+            }),
+            AggregateFunc::ApproxCountDistinct => {
+                Datum::Int64(hll_merge(datums).map_or(0, |sketch| sketch.estimate()))
+            }
+            _ => self.eval(
+                datums
+                    .into_iter()
+                    .flat_map(|(datum, count)| iter::repeat(datum).take(count)),
+                temp_storage,
+            ),
+        }
+    }
+
+    /// Returns whether this aggregate should be evaluated with
+    /// [`AggregateFunc::eval_weighted`] rather than on its input expanded by
+    /// multiplicity.
+    pub fn prefers_weighted_eval(&self) -> bool {
+        matches!(
+            self,
+            AggregateFunc::PercentileCont { .. }
+                | AggregateFunc::PercentileDisc { .. }
+                | AggregateFunc::Mode { .. }
+                | AggregateFunc::HllMerge
+                | AggregateFunc::ApproxCountDistinct
+        )
+    }
+
     /// Like `eval`, but it's given a [OneByOneAggr]. If `self` is a `WindowAggregate`, then
     /// the given [OneByOneAggr] will be used to evaluate the wrapped aggregate inside the
     /// `WindowAggregate`. If `self` is not a `WindowAggregate`, then it simply calls `eval`.
@@ -2125,6 +2764,15 @@ impl AggregateFunc {
             AggregateFunc::DenseRank { order_by } => {
                 dense_rank_no_list(datums, temp_storage, order_by).collect_vec()
             }
+            AggregateFunc::PercentRank { order_by } => {
+                percent_rank_no_list(datums, temp_storage, order_by).collect_vec()
+            }
+            AggregateFunc::CumeDist { order_by } => {
+                cume_dist_no_list(datums, temp_storage, order_by).collect_vec()
+            }
+            AggregateFunc::Ntile { order_by } => {
+                ntile_no_list(datums, temp_storage, order_by).collect_vec()
+            }
             AggregateFunc::LagLead {
                 order_by,
                 lag_lead: lag_lead_type,
@@ -2139,6 +2787,10 @@ impl AggregateFunc {
                 order_by,
                 window_frame,
             } => last_value_no_list(datums, temp_storage, order_by, window_frame).collect_vec(),
+            AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            } => nth_value_no_list(datums, temp_storage, order_by, window_frame).collect_vec(),
             AggregateFunc::FusedValueWindowFunc { funcs, order_by } => {
                 fused_value_window_func_no_list(datums, temp_storage, funcs, order_by).collect_vec()
             }
@@ -2175,7 +2827,7 @@ impl AggregateFunc {
     /// input relation.
     pub fn default(&self) -> Datum<'static> {
         match self {
-            AggregateFunc::Count => Datum::Int64(0),
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct => Datum::Int64(0),
             AggregateFunc::Any => Datum::False,
             AggregateFunc::All => Datum::True,
             AggregateFunc::Dummy => Datum::Dummy,
@@ -2195,9 +2847,13 @@ impl AggregateFunc {
             AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => Datum::empty_list(),
@@ -2248,7 +2904,12 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => Datum::Null,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::HllMerge
+            | AggregateFunc::ApproxCountDistinct => Datum::Null,
         }
     }
 
@@ -2257,9 +2918,13 @@ impl AggregateFunc {
             AggregateFunc::RowNumber { .. }
             | AggregateFunc::Rank { .. }
             | AggregateFunc::DenseRank { .. }
+            | AggregateFunc::PercentRank { .. }
+            | AggregateFunc::CumeDist { .. }
+            | AggregateFunc::Ntile { .. }
             | AggregateFunc::LagLead { .. }
             | AggregateFunc::FirstValue { .. }
             | AggregateFunc::LastValue { .. }
+            | AggregateFunc::NthValue { .. }
             | AggregateFunc::WindowAggregate { .. }
             | AggregateFunc::FusedValueWindowFunc { .. }
             | AggregateFunc::FusedWindowAggregate { .. } => true,
@@ -2315,7 +2980,12 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => false,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::HllMerge
+            | AggregateFunc::ApproxCountDistinct => false,
         }
     }
 
@@ -2353,14 +3023,56 @@ impl AggregateFunc {
                 }
             }
             AggregateFunc::StringAgg { .. } => SqlScalarType::String,
-            AggregateFunc::RowNumber { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?row_number?")
-            }
-            AggregateFunc::Rank { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?rank?")
+            AggregateFunc::PercentileCont { .. } => SqlScalarType::Float64,
+            AggregateFunc::PercentileDisc { .. } => {
+                // The input is a (value, percentile) record.
+                input_type.scalar_type.unwrap_record_element_type()[0].clone()
             }
-            AggregateFunc::DenseRank { .. } => {
-                AggregateFunc::output_type_ranking_window_funcs(&input_type, "?dense_rank?")
+            AggregateFunc::HllMerge => SqlScalarType::Bytes,
+            AggregateFunc::ApproxCountDistinct => SqlScalarType::Int64,
+            AggregateFunc::RowNumber { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?row_number?",
+                SqlScalarType::Int64,
+            ),
+            AggregateFunc::Rank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?rank?",
+                SqlScalarType::Int64,
+            ),
+            AggregateFunc::DenseRank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?dense_rank?",
+                SqlScalarType::Int64,
+            ),
+            AggregateFunc::PercentRank { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?percent_rank?",
+                SqlScalarType::Float64,
+            ),
+            AggregateFunc::CumeDist { .. } => AggregateFunc::output_type_ranking_window_funcs(
+                &input_type,
+                "?cume_dist?",
+                SqlScalarType::Float64,
+            ),
+            AggregateFunc::Ntile { .. } => {
+                // The input type for Ntile is ((OriginalRow, NumBuckets), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+                let value_type = SqlScalarType::Int32.nullable(true); // null when NumBuckets is null
+
+                SqlScalarType::List {
+                    element_type: Box::new(SqlScalarType::Record {
+                        fields: [
+                            (ColumnName::from("?ntile?"), value_type),
+                            (ColumnName::from("?orig_row?"), original_row_type),
+                        ].into(),
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
             }
             AggregateFunc::LagLead { lag_lead: lag_lead_type, .. } => {
                 // The input type for Lag is ((OriginalRow, EncodedArgs), OrderByExprs...)
@@ -2426,6 +3138,26 @@ impl AggregateFunc {
                     custom_id: None,
                 }
             }
+            AggregateFunc::NthValue { .. } => {
+                // The input type for NthValue is ((OriginalRow, (InputValue, N)), OrderByExprs...)
+                let fields = input_type.scalar_type.unwrap_record_element_type();
+                let original_row_type = fields[0].unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(false);
+                let encoded_args = fields[0].unwrap_record_element_type()[1];
+                let value_type = Self::nth_value_output_type_inner_from_encoded_args(encoded_args);
+
+                SqlScalarType::List {
+                    element_type: Box::new(SqlScalarType::Record {
+                        fields: [
+                            (ColumnName::from("?nth_value?"), value_type),
+                            (ColumnName::from("?orig_row?"), original_row_type),
+                        ].into(),
+                        custom_id: None,
+                    }),
+                    custom_id: None,
+                }
+            }
             AggregateFunc::WindowAggregate {
                 wrapped_aggregate, ..
             } => {
@@ -2486,8 +3218,8 @@ impl AggregateFunc {
             AggregateFunc::FusedValueWindowFunc { funcs, order_by: _ } => {
                 // The input type is ((OriginalRow, EncodedArgs), OrderByExprs...)
                 // where EncodedArgs is a record, where each element is the argument to one of the
-                // function calls that got fused. This is a record for lag/lead, and a simple type
-                // for first_value/last_value.
+                // function calls that got fused. This is a record for lag/lead/nth_value, and a
+                // simple type for first_value/last_value/ntile.
                 let fields = input_type.scalar_type.unwrap_record_element_type();
                 let original_row_type = fields[0].unwrap_record_element_type()[0]
                     .clone()
@@ -2529,6 +3261,20 @@ impl AggregateFunc {
                                                 arg_type.clone().nullable(true),
                                             )
                                         }
+                                        AggregateFunc::Ntile { .. } => {
+                                            (
+                                                ColumnName::from("?ntile?"),
+                                                SqlScalarType::Int32.nullable(true),
+                                            )
+                                        }
+                                        AggregateFunc::NthValue { .. } => {
+                                            (
+                                                ColumnName::from("?nth_value?"),
+                                                Self::nth_value_output_type_inner_from_encoded_args(
+                                                    arg_type,
+                                                ),
+                                            )
+                                        }
                                         _ => panic!("FusedValueWindowFunc has an unknown function"),
                                     }
                                 }).collect(),
@@ -2581,12 +3327,13 @@ impl AggregateFunc {
             | AggregateFunc::MinTime
             | AggregateFunc::SumFloat32
             | AggregateFunc::SumFloat64
-            | AggregateFunc::SumNumeric => input_type.scalar_type.clone(),
+            | AggregateFunc::SumNumeric
+            | AggregateFunc::Mode { .. } => input_type.scalar_type.clone(),
         };
         // Count never produces null, and other aggregations only produce
         // null in the presence of null inputs.
         let nullable = match self {
-            AggregateFunc::Count => false,
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct => false,
             // Use the nullability of the underlying column being aggregated, not the Records wrapping it
             AggregateFunc::StringAgg { .. } => match input_type.scalar_type {
                 // The outer Record wraps the input in the first position, and any ORDER BY expressions afterwards
@@ -2597,6 +3344,12 @@ impl AggregateFunc {
                 },
                 _ => unreachable!(),
             },
+            // Use the nullability of the value being aggregated, not the
+            // record wrapping it together with the percentile.
+            AggregateFunc::PercentileCont { .. } | AggregateFunc::PercentileDisc { .. } => {
+                input_type.nullable
+                    || input_type.scalar_type.unwrap_record_element_column_type()[0].nullable
+            }
             _ => input_type.nullable,
         };
         scalar_type.nullable(nullable)
@@ -2609,19 +3362,17 @@ impl AggregateFunc {
         ReprColumnType::from(&self.output_sql_type(SqlColumnType::from_repr(&input_type)))
     }
 
-    /// Compute output type for ROW_NUMBER, RANK, DENSE_RANK
+    /// Compute output type for ROW_NUMBER, RANK, DENSE_RANK, PERCENT_RANK, CUME_DIST
     fn output_type_ranking_window_funcs(
         input_type: &SqlColumnType,
         col_name: &str,
+        result_type: SqlScalarType,
     ) -> SqlScalarType {
         match input_type.scalar_type {
             SqlScalarType::Record { ref fields, .. } => SqlScalarType::List {
                 element_type: Box::new(SqlScalarType::Record {
                     fields: [
-                        (
-                            ColumnName::from(col_name),
-                            SqlScalarType::Int64.nullable(false),
-                        ),
+                        (ColumnName::from(col_name), result_type.nullable(false)),
                         (ColumnName::from("?orig_row?"), {
                             let inner = match &fields[0].1.scalar_type {
                                 SqlScalarType::List { element_type, .. } => element_type.clone(),
@@ -2653,6 +3404,18 @@ impl AggregateFunc {
             .nullable(true)
     }
 
+    /// Given the `EncodedArgs` part of `((OriginalRow, EncodedArgs), OrderByExprs...)`,
+    /// this computes the type of the first field of the output type for `nth_value`.
+    fn nth_value_output_type_inner_from_encoded_args(
+        encoded_args_type: &SqlScalarType,
+    ) -> SqlColumnType {
+        // The output type is the same as the type of the value, but always nullable. (It's null
+        // when the window frame has fewer rows than requested.)
+        encoded_args_type.unwrap_record_element_type()[0]
+            .clone()
+            .nullable(true)
+    }
+
     fn lag_lead_result_column_name(lag_lead_type: &LagLeadType) -> ColumnName {
         ColumnName::from(match lag_lead_type {
             LagLeadType::Lag => "?lag?",
@@ -2707,7 +3470,7 @@ impl AggregateFunc {
             | AggregateFunc::SumNumeric
             | AggregateFunc::StringAgg { .. } => true,
             // Count is never null
-            AggregateFunc::Count => false,
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct => false,
             _ => false,
         }
     }
@@ -3030,9 +3793,17 @@ impl AggregateFunc {
             Self::ArrayConcat { .. } => "array_agg",
             Self::ListConcat { .. } => "list_agg",
             Self::StringAgg { .. } => "string_agg",
+            Self::PercentileCont { .. } => "percentile_cont",
+            Self::PercentileDisc { .. } => "percentile_disc",
+            Self::Mode { .. } => "mode",
+            Self::HllMerge => "hll_merge",
+            Self::ApproxCountDistinct => "approx_count_distinct",
             Self::RowNumber { .. } => "row_number",
             Self::Rank { .. } => "rank",
             Self::DenseRank { .. } => "dense_rank",
+            Self::PercentRank { .. } => "percent_rank",
+            Self::CumeDist { .. } => "cume_dist",
+            Self::Ntile { .. } => "ntile",
             Self::LagLead {
                 lag_lead: LagLeadType::Lag,
                 ..
@@ -3043,6 +3814,7 @@ impl AggregateFunc {
             } => "lead",
             Self::FirstValue { .. } => "first_value",
             Self::LastValue { .. } => "last_value",
+            Self::NthValue { .. } => "nth_value",
             Self::WindowAggregate { .. } => "window_agg",
             Self::FusedValueWindowFunc { .. } => "fused_value_window_func",
             Self::FusedWindowAggregate { .. } => "fused_window_agg",
@@ -3067,7 +3839,10 @@ where
             | StringAgg { order_by }
             | RowNumber { order_by }
             | Rank { order_by }
-            | DenseRank { order_by } => {
+            | DenseRank { order_by }
+            | PercentRank { order_by }
+            | CumeDist { order_by }
+            | Ntile { order_by } => {
                 let order_by = order_by.iter().map(|col| self.child(col));
                 write!(f, "{}[order_by=[{}]]", name, separated(", ", order_by))
             }
@@ -3101,6 +3876,10 @@ where
             LastValue {
                 order_by,
                 window_frame,
+            }
+            | NthValue {
+                order_by,
+                window_frame,
             } => {
                 let order_by = order_by.iter().map(|col| self.child(col));
                 f.write_str(name)?;
@@ -3136,6 +3915,9 @@ where
                 write!(f, "order_by=[{}]", separated(", ", order_by))?;
                 f.write_str("]")
             }
+            PercentileCont { desc: true } | PercentileDisc { desc: true } | Mode { desc: true } => {
+                write!(f, "{}[desc]", name)
+            }
             _ => f.write_str(name),
         }
     }
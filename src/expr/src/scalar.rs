@@ -43,8 +43,8 @@ use crate::scalar::func::variadic::{
     And, Coalesce, ListCreate, ListIndex, Or, RegexpMatch, RegexpReplace, RegexpSplitToArray,
 };
 use crate::scalar::func::{
-    BinaryFunc, UnaryFunc, UnmaterializableFunc, VariadicFunc, parse_timezone,
-    regexp_replace_parse_flags,
+    BinaryFunc, TableSample, TableSampleMethod, UnaryFunc, UnmaterializableFunc, VariadicFunc,
+    parse_timezone, regexp_replace_parse_flags,
 };
 use crate::scalar::proto_eval_error::proto_incompatible_array_dimensions::ProtoDims;
 use crate::visit::{Visit, VisitChildren};
@@ -605,6 +605,20 @@ impl MirScalarExpr {
         )
     }
 
+    /// Reports whether `self` is the sampling predicate of a `TABLESAMPLE SYSTEM` clause.
+    pub fn is_system_sample(&self) -> bool {
+        matches!(
+            self,
+            Self::CallVariadic {
+                func: VariadicFunc::TableSample(TableSample {
+                    method: TableSampleMethod::System,
+                    ..
+                }),
+                ..
+            }
+        )
+    }
+
     /// If `self` expresses a temporal filter, normalize it to start with `mz_now()` and return
     /// references.
     ///
@@ -33,6 +33,7 @@ mod range;
 mod record;
 mod regproc;
 mod string;
+mod table_sample;
 mod time;
 mod timestamp;
 mod uint16;
@@ -67,6 +68,7 @@ pub use crate::scalar::func::impls::range::*;
 pub use crate::scalar::func::impls::record::*;
 pub use crate::scalar::func::impls::regproc::*;
 pub use crate::scalar::func::impls::string::*;
+pub use crate::scalar::func::impls::table_sample::*;
 pub use crate::scalar::func::impls::time::*;
 pub use crate::scalar::func::impls::timestamp::*;
 pub use crate::scalar::func::impls::uint16::*;
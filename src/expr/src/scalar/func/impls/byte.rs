@@ -9,6 +9,7 @@
 
 use mz_expr_derive::sqlfunc;
 use mz_ore::cast::CastFrom;
+use mz_repr::adt::hll::{self, HllError, HyperLogLog};
 use mz_repr::strconv;
 
 use crate::EvalError;
@@ -54,6 +55,35 @@ fn seahash_string<'a>(a: &'a str) -> u64 {
     seahash_bytes(a.as_bytes())
 }
 
+/// Decodes a HyperLogLog sketch produced by `hll_sketch` or `hll_merge`.
+fn decode_hll_sketch(a: &[u8]) -> Result<HyperLogLog, EvalError> {
+    let sketch = HyperLogLog::decode(a).and_then(|sketch| {
+        // Sketches with different precisions cannot be merged, so we only
+        // accept the precision that we produce.
+        if sketch.precision() == hll::DEFAULT_PRECISION {
+            Ok(sketch)
+        } else {
+            Err(HllError::InvalidPrecision(sketch.precision()))
+        }
+    });
+    sketch.map_err(|e| {
+        EvalError::InvalidParameterValue(format!("invalid HyperLogLog sketch: {e}").into())
+    })
+}
+
+#[sqlfunc]
+fn hll_estimate<'a>(a: &'a [u8]) -> Result<i64, EvalError> {
+    Ok(decode_hll_sketch(a)?.estimate())
+}
+
+/// Returns `a` if it is a valid HyperLogLog sketch, so that the sketches input
+/// to `hll_merge` are validated before they are aggregated.
+#[sqlfunc]
+fn hll_validate_sketch<'a>(a: &'a [u8]) -> Result<&'a [u8], EvalError> {
+    decode_hll_sketch(a)?;
+    Ok(a)
+}
+
 #[sqlfunc(sqlname = "bit_count")]
 fn bit_count_bytes<'a>(a: &'a [u8]) -> Result<i64, EvalError> {
     let count: u64 = a.iter().map(|b| u64::cast_from(b.count_ones())).sum();
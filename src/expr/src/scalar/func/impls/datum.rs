@@ -8,7 +8,8 @@
 // by the Apache License, Version 2.0.
 
 use mz_expr_derive::sqlfunc;
-use mz_repr::{Datum, DatumList};
+use mz_repr::adt::hll::{self, HyperLogLog};
+use mz_repr::{Datum, DatumList, Row};
 
 use crate::EvalError;
 
@@ -40,6 +41,19 @@ fn pg_column_size<'a>(a: Datum<'a>) -> Result<Option<i32>, EvalError> {
     }
 }
 
+/// Returns the serialized HyperLogLog sketch that has observed only `a`, for
+/// aggregation by `hll_sketch` and `approx_count_distinct`.
+#[sqlfunc]
+fn hll_singleton<'a>(a: Datum<'a>) -> Option<Vec<u8>> {
+    if a.is_null() {
+        return None;
+    }
+    // Hash the datum's row encoding, so that values of any type can be
+    // sketched.
+    let hash = seahash::hash(Row::pack_slice(&[a]).data());
+    Some(HyperLogLog::singleton(hll::DEFAULT_PRECISION, hash).encode())
+}
+
 #[sqlfunc]
 // TODO[btv] - if we plan to keep changing row format,
 // should we make this unmaterializable?
@@ -0,0 +1,143 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! The sampling predicate behind `TABLESAMPLE`.
+//!
+//! `FROM t TABLESAMPLE <method> (p)` is planned as a filter on `t` with a
+//! predicate `CallVariadic { func: TableSample { .. }, exprs }`, where `exprs`
+//! are all of the columns of `t`. Evaluated row by row, the predicate keeps a
+//! row if a seeded hash of the row falls below the sampling rate.
+//!
+//! For [`TableSampleMethod::System`], readers of monotonic persist shards
+//! instead remove the predicate (see
+//! [`crate::MapFilterProject::take_system_sample`]) and skip entire parts of the
+//! shard, which avoids fetching the unsampled data at all. Where that is not
+//! possible, e.g. when the data is read from an index or the shard contains
+//! retractions, the predicate falls back to sampling individual rows.
+
+use std::fmt;
+
+use mz_lowertest::MzReflect;
+use mz_ore::cast::CastLossy;
+use mz_repr::{Datum, Row, RowArena, SqlColumnType, SqlScalarType};
+use serde::{Deserialize, Serialize};
+
+use crate::scalar::func::variadic::LazyVariadicFunc;
+use crate::{EvalError, MirScalarExpr};
+
+/// The sampling method of a [`TableSample`].
+#[derive(
+    Ord,
+    PartialOrd,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Hash,
+    MzReflect
+)]
+pub enum TableSampleMethod {
+    /// Samples individual rows.
+    Bernoulli,
+    /// Samples entire parts of the underlying storage.
+    System,
+}
+
+/// Keeps a deterministic, seeded sample of the rows it is evaluated on.
+#[derive(
+    Ord,
+    PartialOrd,
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    Hash,
+    MzReflect
+)]
+pub struct TableSample {
+    pub method: TableSampleMethod,
+    /// The fraction of rows to keep, in units of `1 / TableSample::SCALE`.
+    pub rate: u64,
+    /// The seed of the hash that decides which rows to keep.
+    pub seed: u64,
+}
+
+impl TableSample {
+    /// The denominator of [`TableSample::rate`].
+    pub const SCALE: u64 = 1_000_000_000;
+
+    /// Returns a sample that keeps `percentage` percent of rows.
+    ///
+    /// The percentage must be between 0 and 100, inclusive.
+    pub fn new(method: TableSampleMethod, percentage: f64, seed: u64) -> Self {
+        assert!((0.0..=100.0).contains(&percentage));
+        let rate = u64::cast_lossy((percentage / 100.0 * f64::cast_lossy(Self::SCALE)).round());
+        TableSample { method, rate, seed }
+    }
+
+    /// Reports whether the given hash falls within the sample.
+    pub fn includes_hash(&self, hash: u64) -> bool {
+        hash % Self::SCALE < self.rate
+    }
+}
+
+impl LazyVariadicFunc for TableSample {
+    fn eval<'a>(
+        &'a self,
+        datums: &[Datum<'a>],
+        temp_storage: &'a RowArena,
+        exprs: &'a [MirScalarExpr],
+    ) -> Result<Datum<'a>, EvalError> {
+        let mut row = Row::default();
+        let mut packer = row.packer();
+        for e in exprs {
+            packer.push(e.eval(datums, temp_storage)?);
+        }
+        let hash = seahash::hash_seeded(row.data(), self.seed, 0, 0, 0);
+        Ok(self.includes_hash(hash).into())
+    }
+
+    fn output_type(&self, _input_types: &[SqlColumnType]) -> SqlColumnType {
+        SqlScalarType::Bool.nullable(false)
+    }
+
+    fn propagates_nulls(&self) -> bool {
+        false
+    }
+
+    fn introduces_nulls(&self) -> bool {
+        false
+    }
+
+    fn could_error(&self) -> bool {
+        false
+    }
+}
+
+impl fmt::Display for TableSample {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let method = match self.method {
+            TableSampleMethod::Bernoulli => "bernoulli",
+            TableSampleMethod::System => "system",
+        };
+        write!(
+            f,
+            "tablesample_{}[rate={}/{}, seed={}]",
+            method,
+            self.rate,
+            Self::SCALE,
+            self.seed
+        )
+    }
+}
@@ -504,6 +504,9 @@ derive_unary!(
     Panic,
     AdjustNumericScale,
     PgColumnSize,
+    HllSingleton,
+    HllEstimate,
+    HllValidateSketch,
     MzRowSize,
     MzTypeName,
     StepMzTimestamp,
@@ -13,7 +13,7 @@ mod decode;
 mod encode;
 mod schema;
 
-pub use crate::avro::decode::{Decoder, DiffPair};
+pub use crate::avro::decode::{Decoder, DiffPair, decode_datum};
 pub use crate::avro::encode::{
     AvroEncoder, AvroSchemaGenerator, DocTarget, encode_datums_as_avro,
     encode_debezium_transaction_unchecked, get_debezium_transaction_schema,
@@ -13,7 +13,7 @@ use anyhow::{Context, Error};
 use mz_avro::error::{DecodeError, Error as AvroError};
 use mz_avro::{
     AvroArrayAccess, AvroDecode, AvroDeserializer, AvroMapAccess, AvroRead, AvroRecordAccess,
-    GeneralDeserializer, ValueDecoder, ValueOrReader, give_value,
+    GeneralDeserializer, Schema, ValueDecoder, ValueOrReader, give_value,
 };
 use mz_ore::error::ErrorExt;
 use mz_repr::adt::date::Date;
@@ -140,6 +140,29 @@ impl Decoder {
     }
 }
 
+/// Decodes a single Avro datum written with `schema` from `bytes` into `row`, flattening a
+/// top-level record into columns in the same way as [`Decoder`].
+///
+/// This is useful for decoding the datums of Avro Object Container Files, which share a single
+/// schema that is stored in the file's header. Advances `bytes` past the decoded datum.
+pub fn decode_datum(
+    schema: &Schema,
+    bytes: &mut &[u8],
+    row: &mut Row,
+    buf: &mut Vec<u8>,
+) -> Result<(), AvroError> {
+    let mut packer = row.packer();
+    let dec = AvroFlatDecoder {
+        packer: &mut packer,
+        buf,
+        is_top: true,
+    };
+    let dsr = GeneralDeserializer {
+        schema: schema.top_node(),
+    };
+    dsr.deserialize(bytes, dec)
+}
+
 #[derive(Debug)]
 pub struct AvroFlatDecoder<'a, 'row> {
     pub packer: &'a mut RowPacker<'row>,
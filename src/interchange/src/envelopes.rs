@@ -18,8 +18,10 @@ use differential_dataflow::{AsCollection, VecCollection};
 use itertools::{EitherOrBoth, Itertools};
 use maplit::btreemap;
 use mz_ore::cast::CastFrom;
+use mz_repr::adt::numeric::{Numeric, NumericMaxScale};
 use mz_repr::{
-    CatalogItemId, ColumnName, Datum, Diff, Row, RowPacker, SqlColumnType, SqlScalarType,
+    CatalogItemId, ColumnName, Datum, Diff, RelationDesc, Row, RowPacker, SqlColumnType,
+    SqlScalarType, Timestamp,
 };
 use timely::dataflow::Scope;
 use timely::dataflow::channels::pact::Pipeline;
@@ -121,6 +123,71 @@ where
         .as_collection()
 }
 
+/// Given a stream of batches, produce a stream of the individual updates they
+/// contain, grouped by key, at each timestamp.
+///
+/// Unlike [`combine_at_timestamp`], updates are not paired up into befores and
+/// afters. Instead each update becomes a `DiffPair` with only an `after`, whose
+/// row is the updated row followed by the update's timestamp and diff, as
+/// described by [`changelog_desc`]. Diffs keep their multiplicity, so a row
+/// inserted three times at once produces a single update with an `mz_diff` of
+/// 3.
+pub fn changelog_at_timestamp<G: Scope<Timestamp = Timestamp>, Tr>(
+    arranged: Arranged<G, Tr>,
+) -> VecCollection<G, (Tr::KeyOwn, Vec<DiffPair<Row>>), Diff>
+where
+    Tr: Clone + TraceReader<Diff = Diff, Time = Timestamp, KeyOwn: Clone + 'static, ValOwn = Row>,
+{
+    arranged
+        .stream
+        .unary(Pipeline, "changelog_at_timestamp", move |_, _| {
+            let mut row_buf = Row::default();
+            move |input, output| {
+                input.for_each_time(|time, batches| {
+                    let mut session = output.session(&time);
+                    for batch in batches.flat_map(IntoIterator::into_iter) {
+                        let mut updates = vec![];
+
+                        let mut cursor = batch.cursor();
+                        while cursor.key_valid(batch) {
+                            let k = cursor.key(batch);
+
+                            while cursor.val_valid(batch) {
+                                let v = cursor.val(batch);
+                                cursor.map_times(batch, |t, diff| {
+                                    let t = Tr::owned_time(t);
+                                    changelog_format(
+                                        &mut row_buf.packer(),
+                                        &Tr::owned_val(v),
+                                        t,
+                                        Tr::owned_diff(diff),
+                                    );
+                                    updates.push((t, row_buf.clone()));
+                                });
+                                cursor.step_val(batch);
+                            }
+
+                            // For each timestamp, emit the group of updates.
+                            updates.sort_by_key(|(t, _row)| *t);
+                            for (t, group) in &updates.drain(..).chunk_by(|(t, _row)| *t) {
+                                let group = group
+                                    .map(|(_t, row)| DiffPair {
+                                        before: None,
+                                        after: Some(row),
+                                    })
+                                    .collect();
+                                session.give(((Tr::owned_key(k), group), t, Diff::ONE));
+                            }
+
+                            cursor.step_key(batch);
+                        }
+                    }
+                });
+            }
+        })
+        .as_collection()
+}
+
 // NOTE(benesch): statically allocating transient IDs for the
 // transaction and row types is a bit of a hack to allow us to attach
 // custom names to these types in the generated Avro schema. In the
@@ -163,3 +230,34 @@ pub fn dbz_format(rp: &mut RowPacker, dp: DiffPair<Row>) {
         rp.push(Datum::Null);
     }
 }
+
+/// The name of the column that holds the timestamp of each update in the
+/// changelog envelope.
+pub const CHANGELOG_TIMESTAMP_COLUMN: &str = "mz_timestamp";
+/// The name of the column that holds the diff of each update in the changelog
+/// envelope.
+pub const CHANGELOG_DIFF_COLUMN: &str = "mz_diff";
+
+/// Describes the rows produced by the changelog envelope for a relation
+/// described by `desc`: the relation's columns followed by the `mz_timestamp`
+/// and `mz_diff` columns, with the same types that `SUBSCRIBE` uses.
+///
+/// The relation's keys are not carried over, as a key may appear in any number
+/// of updates.
+pub fn changelog_desc(desc: &RelationDesc) -> RelationDesc {
+    let timestamp_type = SqlScalarType::Numeric {
+        max_scale: Some(NumericMaxScale::ZERO),
+    };
+    desc.clone().without_keys().concat(
+        RelationDesc::builder()
+            .with_column(CHANGELOG_TIMESTAMP_COLUMN, timestamp_type.nullable(false))
+            .with_column(CHANGELOG_DIFF_COLUMN, SqlScalarType::Int64.nullable(false))
+            .finish(),
+    )
+}
+
+pub fn changelog_format(rp: &mut RowPacker, row: &Row, time: Timestamp, diff: Diff) {
+    rp.extend_by_row(row);
+    rp.push(Datum::from(Numeric::from(time)));
+    rp.push(Datum::Int64(diff.into_inner()));
+}
@@ -15,7 +15,7 @@ use mz_repr::adt::array::ArrayDimension;
 use mz_repr::adt::char;
 use mz_repr::adt::jsonb::JsonbRef;
 use mz_repr::adt::numeric::{NUMERIC_AGG_MAX_PRECISION, NUMERIC_DATUM_MAX_PRECISION};
-use mz_repr::{CatalogItemId, ColumnName, Datum, RelationDesc, SqlColumnType, SqlScalarType};
+use mz_repr::{CatalogItemId, ColumnName, Datum, RelationDesc, Row, SqlColumnType, SqlScalarType};
 use serde_json::{Map, json};
 
 use crate::avro::DocTarget;
@@ -38,12 +38,17 @@ impl JsonEncoder {
         };
         JsonEncoder { columns }
     }
+
+    /// Encodes `row` as a JSON value, for callers that embed it in a larger
+    /// JSON document rather than sending it on its own.
+    pub fn encode_value(&self, row: &Row) -> serde_json::Value {
+        encode_datums_as_json(row.iter(), self.columns.as_ref())
+    }
 }
 
 impl Encode for JsonEncoder {
-    fn encode_unchecked(&self, row: mz_repr::Row) -> Vec<u8> {
-        let value = encode_datums_as_json(row.iter(), self.columns.as_ref());
-        value.to_string().into_bytes()
+    fn encode_unchecked(&self, row: Row) -> Vec<u8> {
+        self.encode_value(&row).to_string().into_bytes()
     }
 }
 
@@ -0,0 +1,500 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! LDAP mock server for testing.
+//!
+//! This module provides an in-memory directory that speaks just enough of
+//! LDAPv3 to test LDAP authentication: simple binds, searches with equality,
+//! presence, `and`, `or` and `not` filters, and unbinds. See [RFC 4511] for the
+//! protocol.
+//!
+//! [RFC 4511]: https://www.rfc-editor.org/rfc/rfc4511
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, bail};
+use mz_ore::task::JoinHandle;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::debug;
+
+/// BER tags of the LDAP messages and values that the mock server handles.
+mod tag {
+    pub const INTEGER: u8 = 0x02;
+    pub const OCTET_STRING: u8 = 0x04;
+    pub const ENUMERATED: u8 = 0x0a;
+    pub const SEQUENCE: u8 = 0x30;
+    pub const SET: u8 = 0x31;
+
+    pub const BIND_REQUEST: u8 = 0x60;
+    pub const BIND_RESPONSE: u8 = 0x61;
+    pub const UNBIND_REQUEST: u8 = 0x42;
+    pub const SEARCH_REQUEST: u8 = 0x63;
+    pub const SEARCH_RESULT_ENTRY: u8 = 0x64;
+    pub const SEARCH_RESULT_DONE: u8 = 0x65;
+    pub const EXTENDED_REQUEST: u8 = 0x77;
+    pub const EXTENDED_RESPONSE: u8 = 0x78;
+
+    pub const SIMPLE_AUTHENTICATION: u8 = 0x80;
+
+    pub const FILTER_AND: u8 = 0xa0;
+    pub const FILTER_OR: u8 = 0xa1;
+    pub const FILTER_NOT: u8 = 0xa2;
+    pub const FILTER_EQUALITY: u8 = 0xa3;
+    pub const FILTER_PRESENT: u8 = 0x87;
+}
+
+/// LDAP result codes.
+mod result_code {
+    pub const SUCCESS: u8 = 0;
+    pub const PROTOCOL_ERROR: u8 = 2;
+    pub const AUTH_METHOD_NOT_SUPPORTED: u8 = 7;
+    pub const NO_SUCH_OBJECT: u8 = 32;
+    pub const INVALID_CREDENTIALS: u8 = 49;
+    pub const UNWILLING_TO_PERFORM: u8 = 53;
+}
+
+/// An entry of the mock directory.
+#[derive(Debug, Clone)]
+pub struct LdapMockEntry {
+    /// The distinguished name of the entry.
+    pub dn: String,
+    /// The password that binds as the entry, if any.
+    pub password: Option<String>,
+    /// The attributes of the entry, like `uid` or `memberOf`.
+    pub attributes: BTreeMap<String, Vec<String>>,
+}
+
+impl LdapMockEntry {
+    /// Creates an entry for a user who can bind with `password`.
+    pub fn user(dn: &str, uid: &str, password: &str, groups: &[&str]) -> LdapMockEntry {
+        let mut attributes = BTreeMap::new();
+        attributes.insert("uid".to_string(), vec![uid.to_string()]);
+        attributes.insert(
+            "memberOf".to_string(),
+            groups.iter().map(|g| g.to_string()).collect(),
+        );
+        LdapMockEntry {
+            dn: dn.to_string(),
+            password: Some(password.to_string()),
+            attributes,
+        }
+    }
+}
+
+/// LDAP mock server for testing.
+pub struct LdapMockServer {
+    /// The URL of the server, like `ldap://127.0.0.1:1234`.
+    pub url: String,
+    /// The entries of the directory.
+    pub entries: Arc<Mutex<Vec<LdapMockEntry>>>,
+    /// Handle to the server task.
+    pub handle: JoinHandle<()>,
+}
+
+impl LdapMockServer {
+    /// Starts an [`LdapMockServer`] that serves `entries`.
+    ///
+    /// Must be started from within a [`tokio::runtime::Runtime`].
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - Optional address to bind to. If None, binds to localhost on a random port.
+    /// * `entries` - The entries of the directory.
+    pub async fn start(
+        addr: Option<&SocketAddr>,
+        entries: Vec<LdapMockEntry>,
+    ) -> Result<LdapMockServer, anyhow::Error> {
+        let addr = match addr {
+            Some(addr) => Cow::Borrowed(addr),
+            None => Cow::Owned(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 0)),
+        };
+        let listener = TcpListener::bind(*addr).await?;
+        let url = format!("ldap://{}", listener.local_addr()?);
+        let entries = Arc::new(Mutex::new(entries));
+
+        println!("ldap-mock listening...");
+        println!(" LDAP address: {}", url);
+        let server_entries = Arc::clone(&entries);
+        let handle = mz_ore::task::spawn(|| "ldap-mock-server", async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let entries = Arc::clone(&server_entries);
+                mz_ore::task::spawn(|| "ldap-mock-connection", async move {
+                    if let Err(e) = serve_connection(stream, entries).await {
+                        debug!("ldap-mock connection failed: {e}");
+                    }
+                });
+            }
+        });
+
+        Ok(LdapMockServer {
+            url,
+            entries,
+            handle,
+        })
+    }
+
+    /// Replaces the values of `attribute` of the entry named `dn`.
+    pub fn set_attribute(&self, dn: &str, attribute: &str, values: Vec<String>) {
+        let mut entries = self.entries.lock().expect("lock poisoned");
+        let entry = entries
+            .iter_mut()
+            .find(|entry| entry.dn.eq_ignore_ascii_case(dn))
+            .expect("entry exists");
+        entry.attributes.insert(attribute.to_string(), values);
+    }
+}
+
+/// Serves the LDAP requests of a single client.
+async fn serve_connection(
+    mut stream: TcpStream,
+    entries: Arc<Mutex<Vec<LdapMockEntry>>>,
+) -> Result<(), anyhow::Error> {
+    let mut buf = Vec::new();
+    loop {
+        // Accumulate bytes until a full message is available.
+        let len = loop {
+            if let Some((_, len)) = read_element(&buf)? {
+                break len;
+            }
+            let mut chunk = [0; 4096];
+            let n = stream.read(&mut chunk).await?;
+            if n == 0 {
+                return Ok(());
+            }
+            buf.extend_from_slice(&chunk[..n]);
+        };
+        let (message, _) = read_element(&buf)?.expect("message is complete");
+        if message.tag != tag::SEQUENCE {
+            bail!("expected LDAPMessage");
+        }
+        let fields = read_elements(message.contents)?;
+        let [message_id, op, ..] = fields.as_slice() else {
+            bail!("invalid LDAPMessage");
+        };
+        let message_id = message_id.contents.to_vec();
+        let mut responses = Vec::new();
+        match op.tag {
+            tag::BIND_REQUEST => {
+                let code = {
+                    let entries = entries.lock().expect("lock poisoned");
+                    bind(&entries, op.contents)?
+                };
+                responses.push(ldap_result(tag::BIND_RESPONSE, code));
+            }
+            tag::SEARCH_REQUEST => {
+                let entries = entries.lock().expect("lock poisoned");
+                let (found, code) = search(&entries, op.contents)?;
+                responses.extend(found);
+                responses.push(ldap_result(tag::SEARCH_RESULT_DONE, code));
+            }
+            tag::UNBIND_REQUEST => return Ok(()),
+            tag::EXTENDED_REQUEST => {
+                responses.push(ldap_result(
+                    tag::EXTENDED_RESPONSE,
+                    result_code::PROTOCOL_ERROR,
+                ));
+            }
+            other => bail!("unsupported LDAP operation {other:#x}"),
+        }
+        let mut out = Vec::new();
+        for response in responses {
+            let mut contents = Vec::new();
+            write_element(&mut contents, tag::INTEGER, &message_id);
+            contents.extend(response);
+            write_element(&mut out, tag::SEQUENCE, &contents);
+        }
+        stream.write_all(&out).await?;
+        buf.drain(..len);
+    }
+}
+
+/// Handles a `BindRequest`, returning the result code.
+fn bind(entries: &[LdapMockEntry], request: &[u8]) -> Result<u8, anyhow::Error> {
+    let fields = read_elements(request)?;
+    let [_version, name, authentication] = fields.as_slice() else {
+        bail!("invalid BindRequest");
+    };
+    if authentication.tag != tag::SIMPLE_AUTHENTICATION {
+        return Ok(result_code::AUTH_METHOD_NOT_SUPPORTED);
+    }
+    let name = read_string(name)?;
+    let password = std::str::from_utf8(authentication.contents)?;
+    let code = match (name.is_empty(), password.is_empty()) {
+        // An anonymous bind.
+        (true, true) => result_code::SUCCESS,
+        // An unauthenticated bind, which real servers are usually configured
+        // to reject.
+        (false, true) => result_code::UNWILLING_TO_PERFORM,
+        _ => {
+            let valid = entries.iter().any(|entry| {
+                entry.dn.eq_ignore_ascii_case(&name) && entry.password.as_deref() == Some(password)
+            });
+            if valid {
+                result_code::SUCCESS
+            } else {
+                result_code::INVALID_CREDENTIALS
+            }
+        }
+    };
+    Ok(code)
+}
+
+/// Handles a `SearchRequest`, returning the encoded `SearchResultEntry`
+/// messages and the result code.
+fn search(entries: &[LdapMockEntry], request: &[u8]) -> Result<(Vec<Vec<u8>>, u8), anyhow::Error> {
+    let fields = read_elements(request)?;
+    let [
+        base,
+        scope,
+        _deref_aliases,
+        _size_limit,
+        _time_limit,
+        _types_only,
+        filter,
+        attributes,
+    ] = fields.as_slice()
+    else {
+        bail!("invalid SearchRequest");
+    };
+    let base = read_string(base)?;
+    let scope = scope.contents.first().copied().unwrap_or(0);
+    let filter = Filter::decode(filter)?;
+    let attributes = read_elements(attributes.contents)?
+        .iter()
+        .map(read_string)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if !base.is_empty()
+        && !entries
+            .iter()
+            .any(|entry| is_descendant(&entry.dn, &base, 2))
+    {
+        return Ok((vec![], result_code::NO_SUCH_OBJECT));
+    }
+
+    let mut found = Vec::new();
+    for entry in entries {
+        if !is_descendant(&entry.dn, &base, scope) || !filter.matches(entry) {
+            continue;
+        }
+        let mut partial_attributes = Vec::new();
+        for (name, values) in &entry.attributes {
+            let requested = attributes.is_empty()
+                || attributes
+                    .iter()
+                    .any(|a| a == "*" || a.eq_ignore_ascii_case(name));
+            if !requested {
+                continue;
+            }
+            let mut set = Vec::new();
+            for value in values {
+                write_element(&mut set, tag::OCTET_STRING, value.as_bytes());
+            }
+            let mut attribute = Vec::new();
+            write_element(&mut attribute, tag::OCTET_STRING, name.as_bytes());
+            write_element(&mut attribute, tag::SET, &set);
+            write_element(&mut partial_attributes, tag::SEQUENCE, &attribute);
+        }
+        let mut contents = Vec::new();
+        write_element(&mut contents, tag::OCTET_STRING, entry.dn.as_bytes());
+        write_element(&mut contents, tag::SEQUENCE, &partial_attributes);
+        let mut message = Vec::new();
+        write_element(&mut message, tag::SEARCH_RESULT_ENTRY, &contents);
+        found.push(message);
+    }
+    Ok((found, result_code::SUCCESS))
+}
+
+/// Reports whether `dn` is within `scope` of `base`: the base object itself
+/// (0), its immediate children (1) or its entire subtree (2).
+fn is_descendant(dn: &str, base: &str, scope: u8) -> bool {
+    let dn = dn.to_ascii_lowercase();
+    let base = base.to_ascii_lowercase();
+    if base.is_empty() {
+        return scope != 0 || dn.is_empty();
+    }
+    if dn == base {
+        return scope != 1;
+    }
+    let Some(prefix) = dn.strip_suffix(&format!(",{base}")) else {
+        return false;
+    };
+    match scope {
+        0 => false,
+        1 => !prefix.contains(','),
+        _ => true,
+    }
+}
+
+/// A search filter.
+#[derive(Debug)]
+enum Filter {
+    And(Vec<Filter>),
+    Or(Vec<Filter>),
+    Not(Box<Filter>),
+    Equality {
+        attribute: String,
+        value: String,
+    },
+    Present(String),
+    /// A filter that the mock server does not support, which matches nothing.
+    Unsupported,
+}
+
+impl Filter {
+    fn decode(element: &Element<'_>) -> Result<Filter, anyhow::Error> {
+        let filter = match element.tag {
+            tag::FILTER_AND | tag::FILTER_OR => {
+                let filters = read_elements(element.contents)?
+                    .iter()
+                    .map(Filter::decode)
+                    .collect::<Result<_, _>>()?;
+                if element.tag == tag::FILTER_AND {
+                    Filter::And(filters)
+                } else {
+                    Filter::Or(filters)
+                }
+            }
+            tag::FILTER_NOT => {
+                let filters = read_elements(element.contents)?;
+                let [filter] = filters.as_slice() else {
+                    bail!("invalid not filter");
+                };
+                Filter::Not(Box::new(Filter::decode(filter)?))
+            }
+            tag::FILTER_EQUALITY => {
+                let fields = read_elements(element.contents)?;
+                let [attribute, value] = fields.as_slice() else {
+                    bail!("invalid equality filter");
+                };
+                Filter::Equality {
+                    attribute: read_string(attribute)?,
+                    value: read_string(value)?,
+                }
+            }
+            tag::FILTER_PRESENT => {
+                Filter::Present(std::str::from_utf8(element.contents)?.to_string())
+            }
+            _ => Filter::Unsupported,
+        };
+        Ok(filter)
+    }
+
+    fn matches(&self, entry: &LdapMockEntry) -> bool {
+        let values = |attribute: &str| {
+            entry
+                .attributes
+                .iter()
+                .filter(move |(name, _)| name.eq_ignore_ascii_case(attribute))
+                .flat_map(|(_, values)| values)
+        };
+        match self {
+            Filter::And(filters) => filters.iter().all(|f| f.matches(entry)),
+            Filter::Or(filters) => filters.iter().any(|f| f.matches(entry)),
+            Filter::Not(filter) => !filter.matches(entry),
+            Filter::Equality { attribute, value } => {
+                values(attribute).any(|v| v.eq_ignore_ascii_case(value))
+            }
+            // Every entry has an object class.
+            Filter::Present(attribute) if attribute.eq_ignore_ascii_case("objectClass") => true,
+            Filter::Present(attribute) => values(attribute).next().is_some(),
+            Filter::Unsupported => false,
+        }
+    }
+}
+
+/// Encodes an `LDAPResult` with no matched DN or diagnostic message.
+fn ldap_result(tag: u8, code: u8) -> Vec<u8> {
+    let mut contents = Vec::new();
+    write_element(&mut contents, tag::ENUMERATED, &[code]);
+    write_element(&mut contents, tag::OCTET_STRING, &[]);
+    write_element(&mut contents, tag::OCTET_STRING, &[]);
+    let mut result = Vec::new();
+    write_element(&mut result, tag, &contents);
+    result
+}
+
+/// A BER element.
+#[derive(Debug)]
+struct Element<'a> {
+    tag: u8,
+    contents: &'a [u8],
+}
+
+/// Reads the element at the start of `buf`, returning it and its encoded
+/// length, or `None` if `buf` does not contain the entire element.
+///
+/// Only single-byte tags are supported, which suffices for LDAP.
+fn read_element(buf: &[u8]) -> Result<Option<(Element<'_>, usize)>, anyhow::Error> {
+    let [tag, first_len, rest @ ..] = buf else {
+        return Ok(None);
+    };
+    let (len, header_len) = if first_len & 0x80 == 0 {
+        (usize::from(*first_len), 2)
+    } else {
+        let n = usize::from(first_len & 0x7f);
+        if n == 0 || n > 4 {
+            bail!("unsupported BER length");
+        }
+        let Some(bytes) = rest.get(..n) else {
+            return Ok(None);
+        };
+        let len = bytes
+            .iter()
+            .fold(0, |len, byte| (len << 8) | usize::from(*byte));
+        (len, 2 + n)
+    };
+    let Some(contents) = buf.get(header_len..header_len + len) else {
+        return Ok(None);
+    };
+    Ok(Some((
+        Element {
+            tag: *tag,
+            contents,
+        },
+        header_len + len,
+    )))
+}
+
+/// Reads the elements that make up the contents of a constructed element.
+fn read_elements(mut contents: &[u8]) -> Result<Vec<Element<'_>>, anyhow::Error> {
+    let mut elements = Vec::new();
+    while !contents.is_empty() {
+        let (element, len) = read_element(contents)?.ok_or_else(|| anyhow!("truncated BER"))?;
+        elements.push(element);
+        contents = &contents[len..];
+    }
+    Ok(elements)
+}
+
+fn read_string(element: &Element<'_>) -> Result<String, anyhow::Error> {
+    if element.tag != tag::OCTET_STRING {
+        bail!("expected OCTET STRING");
+    }
+    Ok(std::str::from_utf8(element.contents)?.to_string())
+}
+
+/// Appends an element with `tag` and `contents` to `buf`.
+fn write_element(buf: &mut Vec<u8>, tag: u8, contents: &[u8]) {
+    buf.push(tag);
+    let len = contents.len();
+    if len < 0x80 {
+        buf.push(u8::try_from(len).expect("checked"));
+    } else {
+        let bytes = len.to_be_bytes();
+        let skip = bytes.iter().take_while(|b| **b == 0).count();
+        buf.push(0x80 | u8::try_from(bytes.len() - skip).expect("fits"));
+        buf.extend_from_slice(&bytes[skip..]);
+    }
+    buf.extend_from_slice(contents);
+}
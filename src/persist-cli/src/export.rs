@@ -0,0 +1,384 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Export the contents of a persist shard to files.
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::bail;
+use arrow::array::{ArrayRef, Int64Array, RecordBatch};
+use arrow::datatypes::{DataType, Field, Schema};
+use mz_arrow_util::builder::ArrowBuilder;
+use mz_persist_client::ShardId;
+use mz_persist_client::cli::args::StateArgs;
+use mz_persist_client::cli::inspect::snapshot_cursor;
+use mz_repr::{RelationDesc, Row};
+use mz_storage_types::sources::SourceData;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::metadata::KeyValue;
+use parquet::file::properties::{EnabledStatistics, WriterProperties, WriterVersion};
+use tracing::info;
+
+/// The name of the column holding the multiplicity of each exported row.
+const DIFF_COLUMN_NAME: &str = "mz_diff";
+
+/// Export a shard of `SourceData` as of some time.
+#[derive(Debug, clap::Parser)]
+pub struct Args {
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// The time as of which to read the shard.
+    #[clap(long)]
+    as_of: u64,
+
+    /// The format of the exported files.
+    #[clap(long, value_enum, default_value_t = Format::Parquet)]
+    format: Format,
+
+    /// The directory to write the exported files to.
+    #[clap(long)]
+    output_dir: PathBuf,
+
+    /// The maximum number of rows to write to each file.
+    #[clap(long, default_value_t = 1_000_000)]
+    rows_per_file: usize,
+}
+
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+enum Format {
+    Parquet,
+}
+
+/// The number of rows to buffer before encoding them as a [RecordBatch].
+const BATCH_ROWS: usize = 8192;
+
+/// The maximum number of rows in each Parquet row group, which bounds the
+/// amount of data the writer buffers before flushing to the file.
+const ROW_GROUP_ROWS: usize = 128 * 1024;
+
+pub async fn run(args: Args) -> Result<(), anyhow::Error> {
+    if args.rows_per_file == 0 {
+        bail!("--rows-per-file must be positive");
+    }
+    let shard_id = args.state.shard_id();
+    let (schemas, mut cursor) = snapshot_cursor::<SourceData, ()>(&args.state, args.as_of).await?;
+    let desc = RelationDesc::clone(&schemas.key);
+    if desc
+        .iter_names()
+        .any(|name| name.as_str() == DIFF_COLUMN_NAME)
+    {
+        bail!(
+            "shard {} already has a column named {}",
+            shard_id,
+            DIFF_COLUMN_NAME
+        );
+    }
+
+    std::fs::create_dir_all(&args.output_dir)?;
+    let mut writer = match args.format {
+        Format::Parquet => ParquetFiles::new(desc, &args, shard_id),
+    };
+    // The cursor advances every update to the as_of and consolidates them a
+    // chunk at a time, so we never hold more than a few parts in memory.
+    while let Some(updates) = cursor.try_next().await? {
+        for ((SourceData(row), ()), _time, diff) in updates {
+            match row {
+                Ok(row) => writer.push(row, diff)?,
+                Err(err) => bail!(
+                    "shard {} contains an error as of {}: {}",
+                    shard_id,
+                    args.as_of,
+                    err
+                ),
+            }
+        }
+    }
+    let (rows, files) = writer.finish()?;
+    info!(
+        "exported {} rows of {} as of {} to {} files",
+        rows, shard_id, args.as_of, files
+    );
+    Ok(())
+}
+
+/// Writes updates to a sequence of Parquet files with at most `rows_per_file`
+/// rows each.
+struct ParquetFiles {
+    desc: RelationDesc,
+    output_dir: PathBuf,
+    rows_per_file: usize,
+    metadata: Vec<KeyValue>,
+    /// Updates that have not yet been encoded as a [RecordBatch].
+    pending: Vec<(Row, i64)>,
+    /// The file currently being written and the number of rows written to it.
+    current: Option<(PathBuf, ArrowWriter<File>, usize)>,
+    files: usize,
+    rows: usize,
+}
+
+impl ParquetFiles {
+    fn new(desc: RelationDesc, args: &Args, shard_id: ShardId) -> Self {
+        ParquetFiles {
+            desc,
+            output_dir: args.output_dir.clone(),
+            rows_per_file: args.rows_per_file,
+            metadata: vec![
+                KeyValue::new("mz_shard_id".to_owned(), shard_id.to_string()),
+                KeyValue::new("mz_as_of".to_owned(), args.as_of.to_string()),
+            ],
+            pending: Vec::new(),
+            current: None,
+            files: 0,
+            rows: 0,
+        }
+    }
+
+    fn push(&mut self, row: Row, diff: i64) -> Result<(), anyhow::Error> {
+        self.pending.push((row, diff));
+        let written = self.current.as_ref().map_or(0, |(_, _, rows)| *rows);
+        if self.pending.len() >= BATCH_ROWS || written + self.pending.len() >= self.rows_per_file {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Writes the pending updates to the current file, closing it if it is full.
+    fn flush(&mut self) -> Result<(), anyhow::Error> {
+        if self.pending.is_empty() {
+            return Ok(());
+        }
+        let batch = record_batch(&self.desc, &self.pending)?;
+        let (path, mut writer, mut rows) = match self.current.take() {
+            Some(current) => current,
+            None => {
+                let path = self
+                    .output_dir
+                    .join(format!("part-{:05}.parquet", self.files));
+                let writer = parquet_writer(&path, batch.schema(), self.metadata.clone())?;
+                self.files += 1;
+                (path, writer, 0)
+            }
+        };
+        writer.write(&batch)?;
+        rows += self.pending.len();
+        self.rows += self.pending.len();
+        self.pending.clear();
+        if rows >= self.rows_per_file {
+            writer.close()?;
+            info!("wrote {} rows to {}", rows, path.display());
+        } else {
+            self.current = Some((path, writer, rows));
+        }
+        Ok(())
+    }
+
+    /// Flushes and closes the last file, returning the total number of rows
+    /// and files written.
+    fn finish(mut self) -> Result<(usize, usize), anyhow::Error> {
+        self.flush()?;
+        if let Some((path, writer, rows)) = self.current.take() {
+            writer.close()?;
+            info!("wrote {} rows to {}", rows, path.display());
+        }
+        Ok((self.rows, self.files))
+    }
+}
+
+/// Encodes the given updates as a [RecordBatch] with the columns of `desc`
+/// followed by their diffs.
+fn record_batch(desc: &RelationDesc, rows: &[(Row, i64)]) -> Result<RecordBatch, anyhow::Error> {
+    let mut builder = ArrowBuilder::new(desc, rows.len(), 0)?;
+    for (row, _) in rows {
+        builder.add_row(row)?;
+    }
+    let batch = builder.to_record_batch()?;
+
+    let mut fields = batch.schema().fields().to_vec();
+    fields.push(Arc::new(Field::new(
+        DIFF_COLUMN_NAME,
+        DataType::Int64,
+        false,
+    )));
+    let mut columns = batch.columns().to_vec();
+    let diffs: ArrayRef = Arc::new(Int64Array::from_iter_values(
+        rows.iter().map(|(_, diff)| *diff),
+    ));
+    columns.push(diffs);
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)?;
+    Ok(batch)
+}
+
+fn parquet_writer(
+    path: &Path,
+    schema: Arc<Schema>,
+    metadata: Vec<KeyValue>,
+) -> Result<ArrowWriter<File>, anyhow::Error> {
+    let props = WriterProperties::builder()
+        .set_writer_version(WriterVersion::PARQUET_1_0)
+        .set_compression(Compression::SNAPPY)
+        .set_statistics_enabled(EnabledStatistics::None)
+        .set_max_row_group_size(ROW_GROUP_ROWS)
+        .set_key_value_metadata(Some(metadata))
+        .build();
+    let file = File::create(path)?;
+    let writer = ArrowWriter::try_new(file, schema, Some(props))?;
+    Ok(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow::array::AsArray;
+    use arrow::datatypes::Int64Type;
+    use clap::Parser;
+    use mz_persist_client::cache::PersistClientCache;
+    use mz_persist_client::{Diagnostics, PersistLocation, ShardId};
+    use mz_persist_types::codec_impls::UnitSchema;
+    use mz_repr::{Datum, RelationDesc, Row, SqlScalarType};
+    use mz_storage_types::errors::{DataflowError, DecodeError, DecodeErrorKind};
+    use mz_storage_types::sources::SourceData;
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use timely::progress::Antichain;
+
+    use super::*;
+
+    #[mz_ore::test(tokio::test(flavor = "multi_thread"))]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `open`
+    async fn export_parquet() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let blob_uri = format!("file://{}/blob", dir.path().display());
+        let consensus_uri = format!("file://{}/consensus", dir.path().display());
+        let location = PersistLocation {
+            blob_uri: blob_uri.parse().expect("valid uri"),
+            consensus_uri: consensus_uri.parse().expect("valid uri"),
+        };
+        let client = PersistClientCache::new_no_metrics()
+            .open(location)
+            .await
+            .expect("client");
+
+        let desc = RelationDesc::builder()
+            .with_column("a", SqlScalarType::Int64.nullable(false))
+            .with_column("b", SqlScalarType::String.nullable(true))
+            .finish();
+        let shard_id = ShardId::new();
+        let mut write = client
+            .open_writer::<SourceData, (), u64, i64>(
+                shard_id,
+                Arc::new(desc),
+                Arc::new(UnitSchema),
+                Diagnostics::for_tests(),
+            )
+            .await
+            .expect("codecs");
+        let row = |a: i64, b: Option<&str>| {
+            SourceData(Ok(Row::pack_slice(&[
+                Datum::Int64(a),
+                b.map_or(Datum::Null, Datum::String),
+            ])))
+        };
+        let updates = vec![
+            ((row(1, Some("one")), ()), 0, 1),
+            ((row(2, None), ()), 0, 2),
+            ((row(3, Some("three")), ()), 1, 1),
+            ((row(1, Some("one")), ()), 1, -1),
+            ((row(4, Some("four")), ()), 2, 1),
+        ];
+        write
+            .compare_and_append(&updates, Antichain::from_elem(0), Antichain::from_elem(3))
+            .await
+            .expect("usage")
+            .expect("upper");
+
+        let export = |as_of: u64, output_dir: &Path| {
+            Args::parse_from([
+                "export",
+                "--shard-id",
+                shard_id.to_string().as_str(),
+                "--consensus-uri",
+                consensus_uri.as_str(),
+                "--blob-uri",
+                blob_uri.as_str(),
+                "--as-of",
+                as_of.to_string().as_str(),
+                "--output-dir",
+                output_dir.display().to_string().as_str(),
+                "--rows-per-file",
+                "1",
+            ])
+        };
+
+        // Only the updates at or before the as_of are exported, consolidated.
+        let output_dir = dir.path().join("export");
+        run(export(1, &output_dir)).await.expect("export");
+        let mut paths = std::fs::read_dir(&output_dir)
+            .expect("output dir")
+            .map(|entry| entry.expect("entry").path())
+            .collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths.len(), 2);
+        let mut rows = Vec::new();
+        for path in paths {
+            let builder = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+                .expect("parquet file");
+            let metadata = builder
+                .metadata()
+                .file_metadata()
+                .key_value_metadata()
+                .expect("metadata");
+            assert!(
+                metadata
+                    .iter()
+                    .any(|kv| kv.key == "mz_as_of" && kv.value.as_deref() == Some("1"))
+            );
+            for batch in builder.build().expect("reader") {
+                let batch = batch.expect("batch");
+                let a = batch
+                    .column_by_name("a")
+                    .unwrap()
+                    .as_primitive::<Int64Type>();
+                let b = batch.column_by_name("b").unwrap().as_string::<i64>();
+                let diff = batch
+                    .column_by_name(DIFF_COLUMN_NAME)
+                    .unwrap()
+                    .as_primitive::<Int64Type>();
+                for idx in 0..batch.num_rows() {
+                    let b = b.is_valid(idx).then(|| b.value(idx).to_owned());
+                    rows.push((a.value(idx), b, diff.value(idx)));
+                }
+            }
+        }
+        rows.sort();
+        assert_eq!(rows, vec![(2, None, 2), (3, Some("three".to_owned()), 1)]);
+
+        // An error in the shard fails the export rather than being dropped.
+        let err = SourceData(Err(DataflowError::DecodeError(Box::new(DecodeError {
+            kind: DecodeErrorKind::Text("boom".into()),
+            raw: vec![],
+        }))));
+        write
+            .compare_and_append(
+                &[((err, ()), 3u64, 1i64)],
+                Antichain::from_elem(3),
+                Antichain::from_elem(4),
+            )
+            .await
+            .expect("usage")
+            .expect("upper");
+        let err = run(export(3, &dir.path().join("export-err")))
+            .await
+            .expect_err("export should fail");
+        assert!(err.to_string().contains("boom"), "{}", err);
+    }
+}
@@ -21,6 +21,7 @@ use mz_ore::cli::{self, CliConfig};
 use mz_ore::error::ErrorExt;
 use mz_ore::metrics::MetricsRegistry;
 
+pub mod export;
 pub mod maelstrom;
 pub mod open_loop;
 pub mod service;
@@ -43,6 +44,7 @@ enum Command {
     Inspect(mz_persist_client::cli::inspect::InspectArgs),
     Admin(mz_persist_client::cli::admin::AdminArgs),
     Bench(mz_persist_client::cli::bench::BenchArgs),
+    Export(crate::export::Args),
     Service(crate::service::Args),
 }
 
@@ -80,6 +82,7 @@ fn main() {
         }
         Command::Admin(command) => runtime.block_on(mz_persist_client::cli::admin::run(command)),
         Command::Bench(command) => runtime.block_on(mz_persist_client::cli::bench::run(command)),
+        Command::Export(args) => runtime.block_on(crate::export::run(args)),
         Command::Service(args) => runtime.block_on(crate::service::run(args)),
     };
 
@@ -1462,6 +1462,47 @@ impl<T: Timestamp> PartDeletes<T> {
         }
     }
 
+    /// Returns the keys of the individual parts to be deleted.
+    pub fn keys(&self) -> impl Iterator<Item = &PartialBatchKey> {
+        self.blob_keys.iter()
+    }
+
+    /// Adds the keys of individual parts to be deleted.
+    pub fn extend_keys(&mut self, keys: impl IntoIterator<Item = PartialBatchKey>) {
+        self.blob_keys.extend(keys);
+    }
+
+    /// Retains only the keys of individual parts for which `f` returns true.
+    pub fn retain_keys(&mut self, f: impl FnMut(&PartialBatchKey) -> bool) {
+        self.blob_keys.retain(f);
+    }
+
+    /// Removes and returns the keys of parts that are owned by some other shard
+    /// (see [PartialBatchKey::qualify]).
+    pub fn take_qualified_keys(&mut self) -> BTreeSet<PartialBatchKey> {
+        let (qualified, unqualified) = mem::take(&mut self.blob_keys)
+            .into_iter()
+            .partition(|key| key.owner().is_some());
+        self.blob_keys = unqualified;
+        qualified
+    }
+
+    /// Replaces every hollow run with the keys of its parts (and of the run
+    /// itself), so that all blobs to be deleted are individually known.
+    pub async fn expand_runs(&mut self, blob: &dyn Blob, shard_id: ShardId, metrics: &Metrics)
+    where
+        T: Codec64,
+    {
+        while let Some((run_key, run_ref)) = self.hollow_runs.pop_first() {
+            if let Some(run) = run_ref.get(shard_id, blob, metrics).await {
+                for part in &run.parts {
+                    self.add(part);
+                }
+                self.blob_keys.insert(run_key);
+            };
+        }
+    }
+
     pub async fn delete(
         mut self,
         blob: &dyn Blob,
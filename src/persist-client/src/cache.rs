@@ -25,6 +25,7 @@ use mz_ore::metrics::MetricsRegistry;
 use mz_ore::task::{AbortOnDropHandle, JoinHandle};
 use mz_ore::url::SensitiveUrl;
 use mz_persist::cfg::{BlobConfig, ConsensusConfig};
+use mz_persist::encrypted::EncryptedBlob;
 use mz_persist::location::{
     BLOB_GET_LIVENESS_KEY, Blob, CONSENSUS_HEAD_LIVENESS_KEY, Consensus, ExternalError, Tasked,
     VersionedData,
@@ -32,11 +33,11 @@ use mz_persist::location::{
 use mz_persist_types::{Codec, Codec64};
 use timely::progress::Timestamp;
 use tokio::sync::{Mutex, OnceCell};
-use tracing::debug;
+use tracing::{debug, warn};
 
 use crate::async_runtime::IsolatedRuntime;
 use crate::error::{CodecConcreteType, CodecMismatch};
-use crate::internal::cache::BlobMemCache;
+use crate::internal::cache::{BlobDiskCache, BlobMemCache};
 use crate::internal::machine::retry_external;
 use crate::internal::metrics::{LockMetrics, Metrics, MetricsBlob, MetricsConsensus, ShardMetrics};
 use crate::internal::state::TypedState;
@@ -246,6 +247,12 @@ impl PersistClientCache {
                     Arc::clone(&self.cfg.configs),
                 )
                 .await?;
+                // Encryption is applied outside the disk cache, so that it only
+                // ever writes ciphertext to local disk.
+                let (blob, encryption) = match blob {
+                    BlobConfig::Encrypted(blob, encryption) => (*blob, Some(encryption)),
+                    blob => (blob, None),
+                };
                 let blob = retry_external(&self.metrics.retries.external.blob_open, || {
                     blob.clone().open()
                 })
@@ -258,8 +265,34 @@ impl PersistClientCache {
                     Self::PROMETHEUS_SCRAPE_INTERVAL,
                 )
                 .await;
-                // This is intentionally "outside" (wrapping) MetricsBlob so
-                // that we don't include cached responses in blob metrics.
+                // The caches are intentionally "outside" (wrapping) MetricsBlob
+                // so that we don't include cached responses in blob metrics.
+                let blob: Arc<dyn Blob> = match &self.cfg.blob_cache_disk_dir {
+                    Some(dir) => {
+                        // Multiple blobs may be opened in one process, so give
+                        // each its own directory.
+                        let dir = dir.join(format!(
+                            "{:016x}",
+                            seahash::hash(x.key().as_str().as_bytes())
+                        ));
+                        let metrics = Arc::clone(&self.metrics);
+                        match BlobDiskCache::open(&self.cfg, metrics, &dir, Arc::clone(&blob)).await
+                        {
+                            Ok(cache) => cache,
+                            Err(err) => {
+                                // The cache is only an optimization, so don't
+                                // fail to start without it.
+                                warn!("failed to open blob disk cache: {}", err);
+                                blob
+                            }
+                        }
+                    }
+                    None => blob,
+                };
+                let blob: Arc<dyn Blob> = match encryption {
+                    Some(encryption) => Arc::new(EncryptedBlob::new(blob, encryption)),
+                    None => blob,
+                };
                 let blob = BlobMemCache::new(&self.cfg, Arc::clone(&self.metrics), blob);
                 Arc::clone(&x.insert((RttLatencyTask(task.abort_on_drop()), blob)).1)
             }
@@ -811,6 +844,7 @@ mod tests {
 
     use super::*;
     use crate::rpc::NoopPubSubSender;
+    use bytes::Bytes;
     use futures::stream::{FuturesUnordered, StreamExt};
     use mz_build_info::DUMMY_BUILD_INFO;
     use mz_ore::task::spawn;
@@ -1207,4 +1241,78 @@ mod tests {
             futures.next().await.unwrap();
         }
     }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `open`
+    async fn blob_disk_cache_encrypted() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let key_file = dir.path().join("keys");
+        std::fs::write(&key_file, format!("k1 {}=\n", "A".repeat(43))).expect("write");
+        let cache_dir = dir.path().join("cache");
+        let new_cache = || {
+            let mut cfg = PersistConfig::new_for_tests();
+            cfg.blob_cache_disk_dir = Some(cache_dir.clone());
+            PersistClientCache::new(cfg, &MetricsRegistry::new(), |_, _| {
+                PubSubClientConnection::noop()
+            })
+        };
+        let blob_uri = |name: &str| {
+            SensitiveUrl::from_str(&format!(
+                "file://{}/{}?encryption_key_file={}",
+                dir.path().display(),
+                name,
+                key_file.display()
+            ))
+            .expect("invalid URL")
+        };
+
+        let plaintext = b"plaintext that must not be cached on local disk";
+        let blob = new_cache()
+            .open_blob(blob_uri("blob"))
+            .await
+            .expect("open blob");
+        blob.set("s1/a", Bytes::from_static(plaintext))
+            .await
+            .expect("set");
+
+        // Read through a fresh client cache, so that the value is fetched
+        // from the underlying blob and written to the disk cache.
+        let blob = new_cache()
+            .open_blob(blob_uri("blob"))
+            .await
+            .expect("open blob");
+        let value = blob.get("s1/a").await.expect("get");
+        assert_eq!(value.map(|x| x.into_contiguous()), Some(plaintext.to_vec()));
+
+        fn files(dir: &std::path::Path, out: &mut Vec<std::path::PathBuf>) {
+            for entry in std::fs::read_dir(dir).expect("read dir") {
+                let path = entry.expect("entry").path();
+                if path.is_dir() {
+                    files(&path, out);
+                } else {
+                    out.push(path);
+                }
+            }
+        }
+        let mut cached = Vec::new();
+        files(&cache_dir, &mut cached);
+        assert!(!cached.is_empty());
+        for path in cached {
+            let contents = std::fs::read(&path).expect("read");
+            assert!(
+                !contents
+                    .windows(plaintext.len())
+                    .any(|window| window == plaintext),
+                "{} contains plaintext",
+                path.display()
+            );
+        }
+
+        // Different blobs in one process are cached in different directories.
+        let _ = new_cache()
+            .open_blob(blob_uri("other"))
+            .await
+            .expect("open blob");
+        assert_eq!(std::fs::read_dir(&cache_dir).expect("read dir").count(), 2);
+    }
 }
@@ -11,6 +11,7 @@
 
 //! The tunable knobs for persist.
 
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -137,6 +138,9 @@ pub struct PersistConfig {
     /// Number of worker threads to create for the [`crate::IsolatedRuntime`], defaults to the
     /// number of threads.
     pub isolated_runtime_worker_threads: usize,
+    /// A local directory to cache blobs in across restarts, if any. Each blob
+    /// URI is cached in its own subdirectory.
+    pub blob_cache_disk_dir: Option<PathBuf>,
 }
 
 // Impl Deref to ConfigSet for convenience of accessing the dynamic configs.
@@ -178,6 +182,7 @@ impl PersistConfig {
             writer_lease_duration: 60 * Duration::from_secs(60),
             critical_downgrade_interval: Duration::from_secs(30),
             isolated_runtime_worker_threads: num_cpus::get(),
+            blob_cache_disk_dir: None,
             // TODO: This doesn't work with the process orchestrator. Instead,
             // separate --log-prefix into --service-name and --enable-log-prefix
             // options, where the first is always provided and the second is
@@ -332,6 +337,8 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&crate::internal::cache::BLOB_CACHE_MEM_LIMIT_BYTES)
         .add(&crate::internal::cache::BLOB_CACHE_SCALE_WITH_THREADS)
         .add(&crate::internal::cache::BLOB_CACHE_SCALE_FACTOR_BYTES)
+        .add(&crate::internal::cache::BLOB_CACHE_DISK_ENABLED)
+        .add(&crate::internal::cache::BLOB_CACHE_DISK_LIMIT_BYTES)
         .add(&crate::internal::compact::COMPACTION_MINIMUM_TIMEOUT)
         .add(&crate::internal::compact::COMPACTION_CHECK_PROCESS_FLAG)
         .add(&crate::internal::machine::CLAIM_UNCLAIMED_COMPACTIONS)
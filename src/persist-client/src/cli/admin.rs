@@ -74,6 +74,9 @@ pub(crate) enum Command {
     ForceGc(ForceGcArgs),
     /// Manually finalize an unfinalized shard.
     Finalize(FinalizeArgs),
+    /// Create a new shard with the contents of a shard as of some time,
+    /// without copying any data.
+    Fork(ForkArgs),
     /// Attempt to ensure that all the files referenced by consensus are available
     /// in Blob.
     RestoreBlob(RestoreBlobArgs),
@@ -112,6 +115,17 @@ pub(crate) struct FinalizeArgs {
     force_downgrade_upper: bool,
 }
 
+/// Forks a shard.
+#[derive(Debug, clap::Parser)]
+pub(crate) struct ForkArgs {
+    #[clap(flatten)]
+    state: StateArgs,
+
+    /// The time as of which to fork the shard.
+    #[clap(long)]
+    as_of: u64,
+}
+
 /// Attempt to restore all the blobs that are referenced by the current state of consensus.
 #[derive(Debug, clap::Parser)]
 pub(crate) struct RestoreBlobArgs {
@@ -292,6 +306,80 @@ pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
             }
             info_log_non_zero_metrics(&metrics_registry.gather());
         }
+        Command::Fork(args) => {
+            let ForkArgs {
+                state:
+                    StateArgs {
+                        shard_id,
+                        consensus_uri,
+                        blob_uri,
+                    },
+                as_of,
+            } = args;
+            let shard_id = ShardId::from_str(&shard_id).expect("invalid shard id");
+            let commit = command.commit;
+            let expected_version = command
+                .expected_version
+                .as_ref()
+                .map(|v| Version::parse(v))
+                .transpose()?;
+
+            let configs = all_dyncfgs(ConfigSet::default());
+            // TODO: Fetch the latest values of these configs from Launch Darkly.
+            let cfg = PersistConfig::new(&BUILD_INFO, SYSTEM_TIME.clone(), configs);
+            let metrics_registry = MetricsRegistry::new();
+            let metrics = Arc::new(Metrics::new(&cfg, &metrics_registry));
+            let consensus =
+                make_consensus(&cfg, &consensus_uri, commit, Arc::clone(&metrics)).await?;
+            let blob = make_blob(&cfg, &blob_uri, commit, Arc::clone(&metrics)).await?;
+
+            // Open a machine to check the version and set our fake codecs,
+            // which the fork inherits.
+            let _machine = make_machine(
+                &cfg,
+                Arc::clone(&consensus),
+                Arc::clone(&blob),
+                Arc::clone(&metrics),
+                shard_id,
+                commit,
+                expected_version,
+            )
+            .await?;
+
+            let isolated_runtime = Arc::new(IsolatedRuntime::new(&metrics_registry, None));
+            let pubsub_sender: Arc<dyn PubSubSender> = Arc::new(NoopPubSubSender);
+            let shared_states = Arc::new(StateCache::new(
+                &cfg,
+                Arc::clone(&metrics),
+                Arc::clone(&pubsub_sender),
+            ));
+            let persist_client = PersistClient::new(
+                cfg,
+                blob,
+                consensus,
+                metrics,
+                isolated_runtime,
+                shared_states,
+                pubsub_sender,
+            )?;
+            let diagnostics = Diagnostics {
+                shard_name: shard_id.to_string(),
+                handle_purpose: "persist-cli fork shard".to_string(),
+            };
+            let fork_id = persist_client
+                .fork_shard::<crate::cli::inspect::K, crate::cli::inspect::V, u64, i64>(
+                    shard_id,
+                    &Antichain::from_elem(as_of),
+                    diagnostics,
+                )
+                .await?;
+            if commit {
+                info!("forked {shard_id} as of {as_of} into {fork_id}");
+            } else {
+                info!("would have forked {shard_id} as of {as_of} (dry run)");
+            }
+            info_log_non_zero_metrics(&metrics_registry.gather());
+        }
         Command::RestoreBlob(args) => {
             let RestoreBlobArgs {
                 state:
@@ -322,7 +410,11 @@ pub async fn run(command: AdminArgs) -> Result<(), anyhow::Error> {
                 .flat_map_unordered(concurrency, |shard| {
                     stream::once(Box::pin(async {
                         let shard_id = shard?;
-                        let shard_id = ShardId::from_str(&shard_id).expect("invalid shard id");
+                        // Consensus also holds the part refs of forked shards,
+                        // which aren't shards themselves.
+                        let Ok(shard_id) = ShardId::from_str(&shard_id) else {
+                            return Ok(vec![]);
+                        };
                         let start = Instant::now();
                         info!("Restoring blob state for shard {shard_id}.",);
                         let shard_not_restored = crate::internal::restore::restore_blob(
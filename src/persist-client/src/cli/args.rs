@@ -106,7 +106,8 @@ pub(crate) const READ_ALL_BUILD_INFO: BuildInfo = BuildInfo {
 pub(crate) const NO_COMMIT: bool = false;
 
 impl StateArgs {
-    pub(crate) fn shard_id(&self) -> ShardId {
+    /// Returns the shard to inspect.
+    pub fn shard_id(&self) -> ShardId {
         ShardId::from_str(&self.shard_id).expect("invalid shard id")
     }
 
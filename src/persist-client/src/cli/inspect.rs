@@ -11,6 +11,7 @@
 
 use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Debug;
 use std::pin::pin;
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
@@ -30,17 +31,20 @@ use mz_persist_types::{Codec, Codec64};
 use mz_proto::RustType;
 use prost::Message;
 use serde_json::json;
+use timely::progress::Antichain;
 
 use crate::async_runtime::IsolatedRuntime;
 use crate::cache::StateCache;
+use crate::cfg::COMPACTION_MEMORY_BOUND_BYTES;
 use crate::cli::args::{NO_COMMIT, READ_ALL_BUILD_INFO, StateArgs, make_blob, make_consensus};
 use crate::error::CodecConcreteType;
 use crate::fetch::{EncodedPart, FetchConfig};
-use crate::internal::encoding::{Rollup, UntypedState};
+use crate::internal::encoding::{Rollup, Schemas, UntypedState};
 use crate::internal::paths::{
     BlobKey, BlobKeyPrefix, PartialBatchKey, PartialBlobKey, PartialRollupKey, WriterKey,
 };
 use crate::internal::state::{BatchPart, ProtoRollup, ProtoStateDiff, State};
+use crate::read::{Cursor, ReadHandle};
 use crate::rpc::NoopPubSubSender;
 use crate::usage::{HumanBytes, StorageUsageClient};
 use crate::{Metrics, PersistClient, PersistConfig, ShardId};
@@ -435,6 +439,69 @@ async fn consolidated_size(args: &StateArgs) -> Result<(), anyhow::Error> {
     Ok(())
 }
 
+/// Returns the latest schemas registered for a shard, along with a cursor over
+/// its contents as of `as_of`, consolidated and decoded with those schemas.
+///
+/// The cursor fetches parts as it goes, so memory use is bounded regardless of
+/// the size of the shard. Like everything else in `inspect`, this doesn't
+/// write to the shard, so nothing holds back garbage collection of the parts
+/// it reads: if the shard is compacted concurrently, the cursor may return an
+/// error.
+pub async fn snapshot_cursor<K, V>(
+    args: &StateArgs,
+    as_of: u64,
+) -> Result<(Schemas<K, V>, Cursor<K, V, u64, i64, ()>), anyhow::Error>
+where
+    K: Debug + Codec + Ord,
+    V: Debug + Codec + Ord,
+{
+    let shard_id = args.shard_id();
+    let state_versions = args.open().await?;
+    let cfg = &state_versions.cfg;
+    let versions = state_versions
+        .fetch_recent_live_diffs::<u64>(&shard_id)
+        .await;
+    let state = state_versions
+        .fetch_current_state::<u64>(&shard_id, versions.0.clone())
+        .await;
+    let state = state.check_codecs::<K, V, i64>(&shard_id)?;
+
+    let Some((schema_id, schemas)) = state.collections.schemas.last_key_value() else {
+        return Err(anyhow!("shard {} has no registered schema", shard_id));
+    };
+    let schemas = Schemas {
+        id: Some(*schema_id),
+        key: Arc::new(K::decode_schema(&schemas.key)),
+        val: Arc::new(V::decode_schema(&schemas.val)),
+    };
+    let as_of = Antichain::from_elem(as_of);
+    let batches = state.snapshot(&as_of).map_err(|err| {
+        anyhow!(
+            "cannot read shard {} as of {:?}: {:?}",
+            shard_id,
+            as_of.elements(),
+            err
+        )
+    })?;
+
+    let cursor = ReadHandle::<K, V, u64, i64>::read_batches_consolidated(
+        cfg,
+        Arc::clone(&state_versions.metrics),
+        state_versions.metrics.shards.shard(&shard_id, "unknown"),
+        state_versions.metrics.read.snapshot.clone(),
+        Arc::clone(&state_versions.blob),
+        shard_id,
+        as_of,
+        schemas.clone(),
+        &batches,
+        (),
+        |_| true,
+        COMPACTION_MEMORY_BOUND_BYTES.get(cfg),
+    )
+    .map_err(|since| anyhow!("cannot read shard {} before {:?}", shard_id, since.0))?;
+    Ok((schemas, cursor))
+}
+
 /// Arguments for commands that run only against the blob store.
 #[derive(Debug, Clone, clap::Parser)]
 pub struct BlobArgs {
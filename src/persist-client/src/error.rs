@@ -84,6 +84,15 @@ pub enum InvalidUsage<T> {
     CodecMismatch(Box<CodecMismatch>),
     /// An invalid usage of [crate::batch::Batch::rewrite_ts].
     InvalidRewrite(String),
+    /// A shard was forked at an as_of that it can't be read at.
+    InvalidForkAsOf {
+        /// The requested as_of.
+        as_of: Antichain<T>,
+        /// The since of the shard being forked.
+        since: Antichain<T>,
+        /// The upper of the shard being forked.
+        upper: Antichain<T>,
+    },
 }
 
 impl<T: Debug> std::fmt::Display for InvalidUsage<T> {
@@ -134,6 +143,15 @@ impl<T: Debug> std::fmt::Display for InvalidUsage<T> {
             }
             InvalidUsage::CodecMismatch(err) => std::fmt::Display::fmt(err, f),
             InvalidUsage::InvalidRewrite(err) => write!(f, "invalid rewrite: {err}"),
+            InvalidUsage::InvalidForkAsOf {
+                as_of,
+                since,
+                upper,
+            } => write!(
+                f,
+                "invalid fork as_of {:?} for shard with since {:?} and upper {:?}",
+                as_of, since, upper
+            ),
         }
     }
 }
@@ -482,6 +482,24 @@ impl Lease {
     }
 }
 
+/// A deterministic, seeded sample of the parts of a shard.
+///
+/// This is used to implement `TABLESAMPLE SYSTEM`: parts that are not included
+/// in the sample are skipped before they are fetched. Note that a part's
+/// updates can be cancelled by retractions in another part, so a sample of the
+/// parts of a shard is only a sample of its contents if the shard is
+/// append-only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartSample {
+    /// A part is included in the sample if its hash, modulo `scale`, is less
+    /// than `rate`.
+    pub rate: u64,
+    /// See `rate`.
+    pub scale: u64,
+    /// The seed mixed into the hash of each part.
+    pub seed: u64,
+}
+
 /// A token representing one fetch-able batch part.
 ///
 /// It is tradeable via `crate::fetch::fetch_batch` for the resulting data
@@ -565,6 +583,23 @@ where
         self.part.stats().map(|x| x.decode())
     }
 
+    /// Returns whether this part is included in the given sample.
+    ///
+    /// The decision is a deterministic function of the part's contents (or
+    /// blob key) and the sample's seed, so the same part is included in the
+    /// same sample by every reader. The hash is seahash rather than std's
+    /// `DefaultHasher`, whose output may change between Rust releases, so
+    /// that `REPEATABLE` samples stay the same across upgrades.
+    pub fn is_sampled(&self, sample: &PartSample) -> bool {
+        let hash = match &self.part {
+            BatchPart::Hollow(x) => seahash::hash_seeded(x.key.0.as_bytes(), sample.seed, 0, 0, 0),
+            BatchPart::Inline { updates, .. } => {
+                seahash::hash_seeded(&updates.into_proto(), sample.seed, 0, 0, 0)
+            }
+        };
+        hash % sample.scale < sample.rate
+    }
+
     /// Apply any relevant projection pushdown optimizations, assuming that the data in the part
     /// is equivalent to the provided key and value.
     pub fn maybe_optimize(&mut self, cfg: &ConfigSet, key: ArrayRef, val: ArrayRef) {
@@ -233,6 +233,19 @@ where
             })
     }
 
+    /// A point-in-time read from the current state of whether the shard has
+    /// ever been forked.
+    ///
+    /// Due to sharing state with other handles, successive reads to this fn or any other may
+    /// see a different version of state, even if this Applier has not explicitly fetched and
+    /// updated to the latest state. Once this fn returns true, it will always return true.
+    pub fn has_forks(&self) -> bool {
+        self.state
+            .read_lock(&self.metrics.locks.applier_read_cacheable, |state| {
+                state.collections.has_forks
+            })
+    }
+
     /// See [crate::PersistClient::get_schema].
     pub fn get_schema(&self, schema_id: SchemaId) -> Option<(K::Schema, V::Schema)> {
         self.state
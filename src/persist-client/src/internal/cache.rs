@@ -7,16 +7,22 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-//! In-process caches of [Blob].
+//! In-process and on-disk caches of [Blob].
 
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
 
+use anyhow::anyhow;
 use async_trait::async_trait;
-use bytes::Bytes;
+use bytes::{Buf, BufMut, Bytes};
 use mz_dyncfg::{Config, ConfigSet};
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::CastFrom;
 use mz_persist::location::{Blob, BlobMetadata, ExternalError};
+use tracing::warn;
+use uuid::Uuid;
 
 use crate::cfg::PersistConfig;
 use crate::internal::metrics::Metrics;
@@ -53,6 +59,19 @@ pub(crate) const BLOB_CACHE_SCALE_FACTOR_BYTES: Config<usize> = Config::new(
     "Scale factor for the in-mem blob cache, in bytes, if scaling with threads (Materialize).",
 );
 
+pub(crate) const BLOB_CACHE_DISK_ENABLED: Config<bool> = Config::new(
+    "persist_blob_cache_disk_enabled",
+    true,
+    "Whether to use the on-disk blob cache, if the process was started with a directory for it (Materialize).",
+);
+
+pub(crate) const BLOB_CACHE_DISK_LIMIT_BYTES: Config<usize> = Config::new(
+    "persist_blob_cache_disk_limit_bytes",
+    // 10GiB
+    10 * 1024 * 1024 * 1024,
+    "Capacity of the on-disk blob cache in bytes (Materialize).",
+);
+
 impl BlobMemCache {
     pub fn new(cfg: &PersistConfig, metrics: Arc<Metrics>, blob: Arc<dyn Blob>) -> Arc<dyn Blob> {
         let eviction_metrics = Arc::clone(&metrics);
@@ -170,6 +189,348 @@ impl Blob for BlobMemCache {
     }
 }
 
+/// On-disk cache for [Blob].
+///
+/// Blobs that are fetched from the wrapped [Blob] are written to a local
+/// directory and served from there on subsequent reads, including after the
+/// process restarts, which saves refetching the same parts from S3 to
+/// rehydrate. The total size of the cached blobs is bounded, evicting the
+/// least recently used ones. Every cached blob is stored with a checksum, and
+/// blobs that fail validation are discarded and refetched.
+///
+/// Only reads populate the cache: writes go straight to the wrapped [Blob].
+///
+/// The directory must not be shared with other processes.
+#[derive(Debug)]
+pub struct BlobDiskCache {
+    /// [`ConfigSet`] of dynamic configs.
+    cfg: Arc<ConfigSet>,
+    metrics: Arc<Metrics>,
+    /// The directory that holds cached blobs, at the path of their key.
+    entries_dir: PathBuf,
+    /// The directory that holds blobs while they are written, so that the
+    /// cache never observes partial writes.
+    tmp_dir: PathBuf,
+    /// The cached keys, weighted by the size of their file.
+    cache: Mutex<lru::Lru<String, ()>>,
+    /// Keys evicted from `cache` whose files have yet to be removed.
+    evicted: Arc<Mutex<Vec<String>>>,
+    blob: Arc<dyn Blob>,
+}
+
+impl BlobDiskCache {
+    /// The magic bytes, including a format version, that start every cached
+    /// blob.
+    const MAGIC: &'static [u8; 4] = b"MZB1";
+    /// The length of the header in front of every cached blob: the magic
+    /// bytes, a crc32 checksum and the length of the blob.
+    const HEADER_LEN: usize = 4 + 4 + 8;
+
+    /// Opens the cache in `dir`, picking up any blobs cached by previous
+    /// incarnations of this process.
+    pub async fn open(
+        cfg: &PersistConfig,
+        metrics: Arc<Metrics>,
+        dir: &Path,
+        blob: Arc<dyn Blob>,
+    ) -> Result<Arc<dyn Blob>, ExternalError> {
+        let entries_dir = dir.join("entries");
+        let tmp_dir = dir.join("tmp");
+
+        let mut existing = {
+            let entries_dir = entries_dir.clone();
+            let tmp_dir = tmp_dir.clone();
+            mz_ore::task::spawn_blocking(
+                || "persist::blob_disk_cache::open",
+                move || -> Result<_, std::io::Error> {
+                    // Anything left in the tmp dir was written by a process
+                    // that crashed before it finished.
+                    match std::fs::remove_dir_all(&tmp_dir) {
+                        Err(err) if err.kind() != ErrorKind::NotFound => return Err(err),
+                        _ => {}
+                    }
+                    std::fs::create_dir_all(&tmp_dir)?;
+                    std::fs::create_dir_all(&entries_dir)?;
+                    let mut existing = Vec::new();
+                    Self::scan(&entries_dir, &entries_dir, &mut existing)?;
+                    Ok(existing)
+                },
+            )
+            .await
+            .map_err(|err| anyhow!("opening blob disk cache in {}: {}", dir.display(), err))?
+        };
+
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let eviction_metrics = Arc::clone(&metrics);
+        let evicted_keys = Arc::clone(&evicted);
+        let mut cache = lru::Lru::new(
+            BLOB_CACHE_DISK_LIMIT_BYTES.get(&cfg.configs),
+            move |key, (), _| {
+                eviction_metrics.blob_cache_disk.evictions.inc();
+                evicted_keys.lock().expect("lock poisoned").push(key);
+            },
+        );
+        // Approximate the recency of blobs cached by previous incarnations by
+        // the time they were written.
+        existing.sort_by_key(|(_, _, modified)| *modified);
+        for (key, len, _) in existing {
+            cache.insert(key, (), len);
+        }
+
+        let blob = BlobDiskCache {
+            cfg: Arc::clone(&cfg.configs),
+            metrics,
+            entries_dir,
+            tmp_dir,
+            cache: Mutex::new(cache),
+            evicted,
+            blob,
+        };
+        {
+            let mut cache = blob.cache.lock().expect("lock poisoned");
+            blob.resize_and_update_size_metrics(&mut cache);
+        }
+        blob.remove_evicted().await;
+        Ok(Arc::new(blob))
+    }
+
+    /// Collects the key, size and modification time of every file below
+    /// `dir`.
+    fn scan(
+        root: &Path,
+        dir: &Path,
+        existing: &mut Vec<(String, usize, SystemTime)>,
+    ) -> Result<(), std::io::Error> {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                Self::scan(root, &entry.path(), existing)?;
+                continue;
+            }
+            let key = entry
+                .path()
+                .strip_prefix(root)
+                .ok()
+                .and_then(|path| path.to_str())
+                .map(|path| path.replace(std::path::MAIN_SEPARATOR, "/"));
+            match key {
+                Some(key) if Self::is_cacheable(&key) => {
+                    existing.push((key, usize::cast_from(metadata.len()), metadata.modified()?))
+                }
+                // Not something we wrote, leave it alone.
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns whether blobs with the given key can be cached, which is the
+    /// case if the key maps to a path below the cache directory.
+    fn is_cacheable(key: &str) -> bool {
+        key.split('/').all(|component| {
+            !component.is_empty()
+                && component != "."
+                && component != ".."
+                && component
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        self.entries_dir.join(key)
+    }
+
+    fn resize_and_update_size_metrics(&self, cache: &mut lru::Lru<String, ()>) {
+        cache.update_capacity(BLOB_CACHE_DISK_LIMIT_BYTES.get(&self.cfg));
+        self.metrics
+            .blob_cache_disk
+            .size_blobs
+            .set(u64::cast_from(cache.entry_count()));
+        self.metrics
+            .blob_cache_disk
+            .size_bytes
+            .set(u64::cast_from(cache.entry_weight()));
+    }
+
+    /// Removes the files of blobs that were evicted from the cache.
+    async fn remove_evicted(&self) {
+        let evicted = std::mem::take(&mut *self.evicted.lock().expect("lock poisoned"));
+        for key in evicted {
+            self.remove_file(&key).await;
+        }
+    }
+
+    async fn remove_file(&self, key: &str) {
+        match tokio::fs::remove_file(self.path(key)).await {
+            Err(err) if err.kind() != ErrorKind::NotFound => {
+                warn!("failed to remove {} from blob disk cache: {}", key, err);
+            }
+            _ => {}
+        }
+    }
+
+    /// Reads the cached blob with the given key, if it is present and valid.
+    async fn read(&self, key: &str) -> Option<Bytes> {
+        if self.cache.lock().expect("lock poisoned").get(key).is_none() {
+            return None;
+        }
+        match tokio::fs::read(self.path(key)).await.map(Self::decode) {
+            Ok(Some(value)) => return Some(value),
+            Ok(None) => {
+                self.metrics.blob_cache_disk.invalid_blobs.inc();
+                warn!("discarding invalid blob {} from blob disk cache", key);
+            }
+            // The blob was concurrently evicted.
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => {
+                warn!("failed to read {} from blob disk cache: {}", key, err);
+            }
+        }
+        {
+            let mut cache = self.cache.lock().expect("lock poisoned");
+            cache.remove(key);
+            self.resize_and_update_size_metrics(&mut cache);
+        }
+        self.remove_file(key).await;
+        None
+    }
+
+    /// Adds a blob to the cache.
+    ///
+    /// Failures are logged, but otherwise ignored: the cache is best-effort.
+    async fn write(&self, key: &str, value: &SegmentedBytes) {
+        let buf = Self::encode(value);
+        let len = buf.len();
+        {
+            let cache = self.cache.lock().expect("lock poisoned");
+            // If the weight of this single blob is greater than the capacity
+            // of the cache, it will push out everything in the cache and then
+            // immediately get evicted itself. So, skip adding it in that case.
+            if len > cache.capacity() {
+                return;
+            }
+        }
+
+        // Write to a temporary file, then move it into place, so that readers
+        // never see partially written blobs.
+        let tmp_path = self.tmp_dir.join(Uuid::new_v4().to_string());
+        let path = self.path(key);
+        let res = async {
+            tokio::fs::write(&tmp_path, buf).await?;
+            if let Some(parent) = path.parent() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+            tokio::fs::rename(&tmp_path, &path).await
+        }
+        .await;
+        if let Err(err) = res {
+            warn!("failed to write {} to blob disk cache: {}", key, err);
+            let _ = tokio::fs::remove_file(&tmp_path).await;
+            return;
+        }
+
+        {
+            let mut cache = self.cache.lock().expect("lock poisoned");
+            cache.insert(key.to_owned(), (), len);
+            self.resize_and_update_size_metrics(&mut cache);
+        }
+        self.remove_evicted().await;
+    }
+
+    fn encode(value: &SegmentedBytes) -> Vec<u8> {
+        let segments: Vec<Bytes> = value.clone().into_segments().collect();
+        let mut hasher = crc32fast::Hasher::new();
+        for segment in segments.iter() {
+            hasher.update(segment);
+        }
+        let mut buf = Vec::with_capacity(Self::HEADER_LEN + value.len());
+        buf.put_slice(Self::MAGIC);
+        buf.put_u32_le(hasher.finalize());
+        buf.put_u64_le(u64::cast_from(value.len()));
+        for segment in segments.iter() {
+            buf.put_slice(segment);
+        }
+        buf
+    }
+
+    /// Returns the blob in the output of [Self::encode], or None if it is
+    /// corrupt.
+    fn decode(buf: Vec<u8>) -> Option<Bytes> {
+        if buf.len() < Self::HEADER_LEN || &buf[..Self::MAGIC.len()] != Self::MAGIC {
+            return None;
+        }
+        let mut header = &buf[Self::MAGIC.len()..Self::HEADER_LEN];
+        let checksum = header.get_u32_le();
+        let len = header.get_u64_le();
+        let mut buf = Bytes::from(buf);
+        buf.advance(Self::HEADER_LEN);
+        let valid = u64::cast_from(buf.len()) == len && crc32fast::hash(&buf) == checksum;
+        valid.then_some(buf)
+    }
+}
+
+#[async_trait]
+impl Blob for BlobDiskCache {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let enabled = BLOB_CACHE_DISK_ENABLED.get(&self.cfg) && Self::is_cacheable(key);
+        if !enabled {
+            return self.blob.get(key).await;
+        }
+
+        // Blobs are write-once modify-never, so a cached blob always matches
+        // the one in the wrapped blob (see BlobMemCache).
+        if let Some(value) = self.read(key).await {
+            self.metrics.blob_cache_disk.hits_blobs.inc();
+            self.metrics
+                .blob_cache_disk
+                .hits_bytes
+                .inc_by(u64::cast_from(value.len()));
+            return Ok(Some(SegmentedBytes::from(value)));
+        }
+
+        self.metrics.blob_cache_disk.misses_blobs.inc();
+        let res = self.blob.get(key).await?;
+        if let Some(value) = res.as_ref() {
+            self.write(key, value).await;
+        }
+        Ok(res)
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        self.blob.set(key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        let res = self.blob.delete(key).await;
+        if Self::is_cacheable(key) {
+            let removed = {
+                let mut cache = self.cache.lock().expect("lock poisoned");
+                let removed = cache.remove(key).is_some();
+                self.resize_and_update_size_metrics(&mut cache);
+                removed
+            };
+            if removed {
+                self.remove_file(key).await;
+            }
+        }
+        res
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
 mod lru {
     use std::borrow::Borrow;
     use std::collections::BTreeMap;
@@ -411,11 +772,14 @@ mod lru {
 #[cfg(test)]
 mod tests {
     use mz_ore::assert_none;
+    use mz_ore::metrics::MetricsRegistry;
+    use mz_persist::mem::{MemBlob, MemBlobConfig};
     use proptest::arbitrary::any;
     use proptest::proptest;
     use proptest_derive::Arbitrary;
 
     use super::lru::*;
+    use super::*;
 
     #[derive(Debug, Arbitrary)]
     enum LruOp {
@@ -555,4 +919,92 @@ mod tests {
         assert_eq!(cache.entry_weight(), 2);
         assert_eq!(cache.keys(), &["j", "i"]);
     }
+
+    async fn get(blob: &dyn Blob, key: &str) -> Option<Vec<u8>> {
+        blob.get(key)
+            .await
+            .expect("mem blob does not fail")
+            .map(|x| x.into_contiguous())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `open`
+    async fn blob_disk_cache() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let cfg = PersistConfig::new_for_tests();
+        let metrics = Arc::new(Metrics::new(&cfg, &MetricsRegistry::new()));
+        let inner: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        inner.set("s1/a", Bytes::from("aaaa")).await.expect("set");
+        inner.set("s1/b", Bytes::from("bbbb")).await.expect("set");
+
+        let open = || async {
+            BlobDiskCache::open(&cfg, Arc::clone(&metrics), dir.path(), Arc::clone(&inner))
+                .await
+                .expect("open")
+        };
+        let hits = || metrics.blob_cache_disk.hits_blobs.get();
+        let misses = || metrics.blob_cache_disk.misses_blobs.get();
+
+        // The first read is a miss, the second a hit.
+        let cache = open().await;
+        assert_eq!(get(&*cache, "s1/a").await, Some(b"aaaa".to_vec()));
+        assert_eq!((hits(), misses()), (0, 1));
+        assert_eq!(get(&*cache, "s1/a").await, Some(b"aaaa".to_vec()));
+        assert_eq!((hits(), misses()), (1, 1));
+        assert_eq!(get(&*cache, "s1/missing").await, None);
+        assert_eq!((hits(), misses()), (1, 2));
+
+        // The cache survives restarts.
+        drop(cache);
+        let cache = open().await;
+        assert_eq!(metrics.blob_cache_disk.size_blobs.get(), 1);
+        assert_eq!(get(&*cache, "s1/a").await, Some(b"aaaa".to_vec()));
+        assert_eq!((hits(), misses()), (2, 2));
+
+        // Corrupt blobs are discarded and refetched.
+        let path = dir.path().join("entries").join("s1").join("a");
+        let mut corrupt = std::fs::read(&path).expect("read");
+        *corrupt.last_mut().expect("non-empty") ^= 1;
+        std::fs::write(&path, corrupt).expect("write");
+        assert_eq!(get(&*cache, "s1/a").await, Some(b"aaaa".to_vec()));
+        assert_eq!((hits(), misses()), (2, 3));
+        assert_eq!(metrics.blob_cache_disk.invalid_blobs.get(), 1);
+        assert_eq!(get(&*cache, "s1/a").await, Some(b"aaaa".to_vec()));
+        assert_eq!((hits(), misses()), (3, 3));
+
+        // Deletes remove blobs from the cache.
+        cache.delete("s1/a").await.expect("delete");
+        assert!(!path.exists());
+        assert_eq!(get(&*cache, "s1/a").await, None);
+
+        // The least recently used blobs are evicted to stay within capacity.
+        let capacity = 2 * (BlobDiskCache::HEADER_LEN + 4);
+        cfg.set_config(&BLOB_CACHE_DISK_LIMIT_BYTES, capacity);
+        inner.set("s1/c", Bytes::from("cccc")).await.expect("set");
+        assert_eq!(get(&*cache, "s1/b").await, Some(b"bbbb".to_vec()));
+        assert_eq!(get(&*cache, "s1/c").await, Some(b"cccc".to_vec()));
+        assert_eq!(get(&*cache, "s1/b").await, Some(b"bbbb".to_vec()));
+        inner.set("s1/d", Bytes::from("dddd")).await.expect("set");
+        assert_eq!(get(&*cache, "s1/d").await, Some(b"dddd".to_vec()));
+        assert_eq!(metrics.blob_cache_disk.evictions.get(), 1);
+        assert!(!dir.path().join("entries").join("s1").join("c").exists());
+        assert_eq!(metrics.blob_cache_disk.size_blobs.get(), 2);
+
+        // Blobs are not cached while the cache is disabled.
+        cfg.set_config(&BLOB_CACHE_DISK_ENABLED, false);
+        let misses_before = misses();
+        assert_eq!(get(&*cache, "s1/b").await, Some(b"bbbb".to_vec()));
+        assert_eq!(misses(), misses_before);
+    }
+
+    #[mz_ore::test]
+    fn blob_disk_cache_cacheable_keys() {
+        assert!(BlobDiskCache::is_cacheable("s1234/v1/r5678"));
+        assert!(BlobDiskCache::is_cacheable("s-a_b.c/d"));
+        assert!(!BlobDiskCache::is_cacheable(""));
+        assert!(!BlobDiskCache::is_cacheable("/abs"));
+        assert!(!BlobDiskCache::is_cacheable("s1//a"));
+        assert!(!BlobDiskCache::is_cacheable("s1/../a"));
+        assert!(!BlobDiskCache::is_cacheable("s1/a b"));
+    }
 }
@@ -38,6 +38,7 @@ use uuid::Uuid;
 
 use crate::critical::{CriticalReaderId, Opaque};
 use crate::error::{CodecMismatch, CodecMismatchT};
+use crate::internal::gc::{PartRef, PartRefs};
 use crate::internal::metrics::Metrics;
 use crate::internal::paths::{PartialBatchKey, PartialRollupKey};
 use crate::internal::state::{
@@ -47,10 +48,11 @@ use crate::internal::state::{
     ProtoEncodedSchemas, ProtoHandleDebugState, ProtoHollowBatch, ProtoHollowBatchPart,
     ProtoHollowRollup, ProtoHollowRun, ProtoHollowRunRef, ProtoIdHollowBatch, ProtoIdMerge,
     ProtoIdSpineBatch, ProtoInlineBatchPart, ProtoInlinedDiffs, ProtoLeasedReaderState, ProtoMerge,
-    ProtoRollup, ProtoRunMeta, ProtoRunOrder, ProtoSpineBatch, ProtoSpineId, ProtoStateDiff,
-    ProtoStateField, ProtoStateFieldDiffType, ProtoStateFieldDiffs, ProtoTrace, ProtoU64Antichain,
-    ProtoU64Description, ProtoVersionedData, ProtoWriterState, RunId, RunMeta, RunOrder, RunPart,
-    State, StateCollections, TypedState, WriterState, proto_hollow_batch_part,
+    ProtoPartRef, ProtoPartRefs, ProtoRollup, ProtoRunMeta, ProtoRunOrder, ProtoSpineBatch,
+    ProtoSpineId, ProtoStateDiff, ProtoStateField, ProtoStateFieldDiffType, ProtoStateFieldDiffs,
+    ProtoTrace, ProtoU64Antichain, ProtoU64Description, ProtoVersionedData, ProtoWriterState,
+    RunId, RunMeta, RunOrder, RunPart, State, StateCollections, TypedState, WriterState,
+    proto_hollow_batch_part,
 };
 use crate::internal::state_diff::{
     ProtoStateFieldDiff, ProtoStateFieldDiffsWriter, StateDiff, StateFieldDiff, StateFieldValDiff,
@@ -445,6 +447,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
             critical_readers,
             writers,
             schemas,
+            has_forks,
             since,
             legacy_batches,
             hollow_batches,
@@ -470,6 +473,7 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
         );
         field_diffs_into_proto(ProtoStateField::Writers, writers, &mut writer);
         field_diffs_into_proto(ProtoStateField::Schemas, schemas, &mut writer);
+        field_diffs_into_proto(ProtoStateField::HasForks, has_forks, &mut writer);
         field_diffs_into_proto(ProtoStateField::Since, since, &mut writer);
         field_diffs_into_proto(ProtoStateField::LegacyBatches, legacy_batches, &mut writer);
         field_diffs_into_proto(ProtoStateField::HollowBatches, hollow_batches, &mut writer);
@@ -600,6 +604,12 @@ impl<T: Timestamp + Codec64> RustType<ProtoStateDiff> for StateDiff<T> {
                             |v| v.into_rust(),
                         )?
                     }
+                    ProtoStateField::HasForks => field_diff_into_rust::<(), bool, _, _, _, _>(
+                        diff,
+                        &mut state_diff.has_forks,
+                        |()| Ok(()),
+                        |v| v.into_rust(),
+                    )?,
                     ProtoStateField::Since => {
                         field_diff_into_rust::<(), ProtoU64Antichain, _, _, _, _>(
                             diff,
@@ -972,6 +982,7 @@ impl<T: Timestamp + Lattice + Codec64> RustType<ProtoRollup> for Rollup<T> {
             ts_codec: T::codec_name(),
             diff_codec: self.state.diff_codec.into_proto(),
             last_gc_req: self.state.state.collections.last_gc_req.into_proto(),
+            has_forks: self.state.state.collections.has_forks,
             active_rollup: self.state.state.collections.active_rollup.into_proto(),
             active_gc: self.state.state.collections.active_gc.into_proto(),
             rollups: self
@@ -1079,6 +1090,7 @@ impl<T: Timestamp + Lattice + Codec64> RustType<ProtoRollup> for Rollup<T> {
             critical_readers,
             writers,
             schemas,
+            has_forks: x.has_forks,
             trace: x.trace.into_rust_if_some("trace")?,
         };
         let state = State {
@@ -1854,6 +1866,47 @@ impl RustType<ProtoHollowRollup> for HollowRollup {
     }
 }
 
+impl RustType<ProtoPartRefs> for PartRefs {
+    fn into_proto(&self) -> ProtoPartRefs {
+        ProtoPartRefs {
+            refs: self
+                .refs
+                .iter()
+                .map(|(key, part)| (key.into_proto(), part.into_proto()))
+                .collect(),
+        }
+    }
+
+    fn from_proto(proto: ProtoPartRefs) -> Result<Self, TryFromProtoError> {
+        let mut refs = BTreeMap::new();
+        for (key, part) in proto.refs {
+            refs.insert(key.into_rust()?, part.into_rust()?);
+        }
+        Ok(PartRefs { refs })
+    }
+}
+
+impl RustType<ProtoPartRef> for PartRef {
+    fn into_proto(&self) -> ProtoPartRef {
+        ProtoPartRef {
+            holders: self.holders.iter().map(|x| x.into_proto()).collect(),
+            released: self.released,
+        }
+    }
+
+    fn from_proto(proto: ProtoPartRef) -> Result<Self, TryFromProtoError> {
+        let holders = proto
+            .holders
+            .into_iter()
+            .map(|x| x.into_rust())
+            .collect::<Result<_, _>>()?;
+        Ok(PartRef {
+            holders,
+            released: proto.released,
+        })
+    }
+}
+
 impl RustType<ProtoActiveRollup> for ActiveRollup {
     fn into_proto(&self) -> ProtoActiveRollup {
         ProtoActiveRollup {
@@ -0,0 +1,200 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! See documentation on [[fork_shard]].
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use differential_dataflow::difference::Monoid;
+use differential_dataflow::lattice::Lattice;
+use mz_persist::location::Blob;
+use mz_persist_types::{Codec, Codec64};
+use timely::progress::{Antichain, Timestamp};
+use tracing::info;
+
+use crate::error::InvalidUsage;
+use crate::internal::gc::{GarbageCollector, PartRefs};
+use crate::internal::machine::Machine;
+use crate::internal::paths::PartialBatchKey;
+use crate::internal::state::{BatchPart, HollowBatch, RunPart};
+use crate::metrics::Metrics;
+use crate::read::{LeasedReaderId, READER_LEASE_DURATION};
+use crate::{Diagnostics, PersistClient, ShardId};
+
+/// Creates a new shard with the contents of `source_id` as of `as_of`, without
+/// copying any data.
+///
+/// The state of the new shard references the batch parts of the source shard
+/// (and, transitively, of any shard that one was forked from) directly. Both
+/// shards are otherwise independent: writes, compaction, and GC of either one
+/// don't affect the other, and [PartRefs] keeps a shared part around until
+/// neither references it anymore.
+///
+/// The upper of the fork is the upper of the last batch needed to read the
+/// source at `as_of`, so it may contain some updates beyond `as_of`. Its since
+/// is `as_of`.
+pub(crate) async fn fork_shard<K, V, T, D>(
+    client: &PersistClient,
+    source_id: ShardId,
+    as_of: &Antichain<T>,
+    diagnostics: Diagnostics,
+) -> Result<ShardId, InvalidUsage<T>>
+where
+    K: Debug + Codec,
+    V: Debug + Codec,
+    T: Timestamp + Lattice + Codec64 + Sync,
+    D: Monoid + Codec64 + Send + Sync,
+{
+    let source = client
+        .make_machine::<K, V, T, D>(source_id, diagnostics.clone())
+        .await?;
+    let source_gc = GarbageCollector::new(source.clone(), Arc::clone(&client.isolated_runtime));
+
+    // Hold back GC of the source until the fork holds its own references to
+    // the parts it shares.
+    let reader_id = LeasedReaderId::new();
+    let (reader_state, maintenance) = source
+        .register_leased_reader(
+            &reader_id,
+            &format!("fork of {}", source_id),
+            READER_LEASE_DURATION.get(&client.cfg),
+            (client.cfg.now)(),
+            false,
+        )
+        .await;
+    maintenance.start_performing(&source, &source_gc);
+
+    // GC of the source only consults its PartRefs once this is set. The
+    // reader above holds back its seqno_since until after this is applied, so
+    // any GC that could delete a part shared with the fork sees it.
+    let maintenance = source.mark_forked().await;
+    maintenance.start_performing(&source, &source_gc);
+
+    let res = fork_at(client, &source, as_of, reader_state.since, diagnostics).await;
+
+    let (_seqno, maintenance) = source.expire_leased_reader(&reader_id).await;
+    maintenance.start_performing(&source, &source_gc);
+    res
+}
+
+async fn fork_at<K, V, T, D>(
+    client: &PersistClient,
+    source: &Machine<K, V, T, D>,
+    as_of: &Antichain<T>,
+    since: Antichain<T>,
+    diagnostics: Diagnostics,
+) -> Result<ShardId, InvalidUsage<T>>
+where
+    K: Debug + Codec,
+    V: Debug + Codec,
+    T: Timestamp + Lattice + Codec64 + Sync,
+    D: Monoid + Codec64 + Send + Sync,
+{
+    let source_id = source.shard_id();
+    let snapshot = match source.applier.snapshot(as_of) {
+        Ok(snapshot) if !snapshot.is_empty() => snapshot,
+        // An empty snapshot means the source has never been written to, so
+        // there's no upper to give the fork.
+        _ => {
+            return Err(InvalidUsage::InvalidForkAsOf {
+                as_of: as_of.clone(),
+                since,
+                upper: source.applier.clone_upper(),
+            });
+        }
+    };
+    let schemas = source.applier.schemas(|_seqno, schemas| schemas.clone());
+
+    let mut batches = Vec::with_capacity(snapshot.len());
+    for batch in snapshot.iter() {
+        batches.push(qualify_batch(source_id, batch, client.blob.as_ref(), &client.metrics).await);
+    }
+
+    let mut keys_by_owner = BTreeMap::<ShardId, Vec<PartialBatchKey>>::new();
+    for part in batches.iter().flat_map(|b| b.parts.iter()) {
+        if let RunPart::Single(BatchPart::Hollow(x)) = part {
+            let owner = x.key.owner().expect("qualified key has an owner");
+            keys_by_owner.entry(owner).or_default().push(x.key.clone());
+        }
+    }
+
+    let fork_id = ShardId::new();
+    let retry_metrics = &client.metrics.retries.external.part_refs;
+    for (owner, keys) in keys_by_owner.iter() {
+        PartRefs::acquire(&*client.consensus, retry_metrics, owner, &fork_id, keys).await;
+    }
+
+    let fork = client
+        .make_machine::<K, V, T, D>(fork_id, diagnostics)
+        .await?;
+    let fork_gc = GarbageCollector::new(fork.clone(), Arc::clone(&client.isolated_runtime));
+    let (forked, maintenance) = fork.fork_from(&batches, as_of, &schemas).await;
+    maintenance.start_performing(&fork, &fork_gc);
+    // We just minted the id of the fork and checked that there's at least one
+    // batch, so nothing else could have used it.
+    assert!(
+        forked,
+        "fork {} of {} was already in use",
+        fork_id, source_id
+    );
+
+    info!(
+        "forked {} as of {:?} into {} with {} batches",
+        source_id,
+        as_of.elements(),
+        fork_id,
+        batches.len(),
+    );
+    Ok(fork_id)
+}
+
+/// Returns a copy of `batch` in which every hollow run is replaced by the
+/// parts it contains and every part key is qualified with the shard that owns
+/// it, so that the batch can be referenced from the state of a fork.
+async fn qualify_batch<T: Timestamp + Codec64>(
+    shard_id: ShardId,
+    batch: &HollowBatch<T>,
+    blob: &dyn Blob,
+    metrics: &Metrics,
+) -> HollowBatch<T> {
+    let mut parts = Vec::with_capacity(batch.parts.len());
+    let mut run_splits = Vec::new();
+    let mut run_meta = Vec::new();
+    for (meta, run) in batch.runs() {
+        if !parts.is_empty() {
+            run_splits.push(parts.len());
+        }
+        run_meta.push(meta.clone());
+
+        let mut pending = run.iter().cloned().collect::<VecDeque<_>>();
+        while let Some(part) = pending.pop_front() {
+            match part {
+                RunPart::Many(run_ref) => {
+                    let run = run_ref
+                        .get(shard_id, blob, metrics)
+                        .await
+                        .expect("runs referenced by a held seqno exist");
+                    for part in run.parts.into_iter().rev() {
+                        pending.push_front(part);
+                    }
+                }
+                RunPart::Single(BatchPart::Hollow(mut x)) => {
+                    x.key = x.key.qualify(&shard_id);
+                    parts.push(RunPart::Single(BatchPart::Hollow(x)));
+                }
+                RunPart::Single(inline @ BatchPart::Inline { .. }) => {
+                    parts.push(RunPart::Single(inline));
+                }
+            }
+        }
+    }
+    HollowBatch::new(batch.desc.clone(), parts, batch.len, run_meta, run_splits)
+}
@@ -8,18 +8,21 @@
 // by the Apache License, Version 2.0.
 
 use std::borrow::Borrow;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::mem;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Instant;
 
+use bytes::Bytes;
 use differential_dataflow::difference::Monoid;
 use differential_dataflow::lattice::Lattice;
 use futures_util::StreamExt;
 use futures_util::stream::FuturesUnordered;
 use prometheus::Counter;
+use prost::Message;
 use timely::progress::Timestamp;
 use tokio::sync::mpsc::UnboundedSender;
 use tokio::sync::{Semaphore, mpsc, oneshot};
@@ -32,15 +35,16 @@ use crate::cfg::GC_BLOB_DELETE_CONCURRENCY_LIMIT;
 use mz_ore::cast::CastFrom;
 use mz_ore::collections::HashSet;
 use mz_ore::soft_assert_or_log;
-use mz_persist::location::{Blob, SeqNo};
+use mz_persist::location::{Blob, CaSResult, Consensus, SeqNo, VersionedData};
 use mz_persist_types::{Codec, Codec64};
+use mz_proto::{ProtoType, RustType};
 
 use crate::ShardId;
 use crate::internal::machine::{Machine, retry_external};
 use crate::internal::maintenance::RoutineMaintenance;
 use crate::internal::metrics::{GcStepTimings, RetryMetrics};
-use crate::internal::paths::{BlobKey, PartialBlobKey, PartialRollupKey};
-use crate::internal::state::{GC_USE_ACTIVE_GC, HollowBlobRef};
+use crate::internal::paths::{BlobKey, PartialBatchKey, PartialBlobKey, PartialRollupKey};
+use crate::internal::state::{GC_USE_ACTIVE_GC, HollowBlobRef, ProtoPartRefs};
 use crate::internal::state_versions::{InspectDiff, StateVersionsIter, UntypedStateVersionsIter};
 
 #[derive(Debug, Clone, PartialEq)]
@@ -593,7 +597,8 @@ where
         let concurrency_limit = GC_BLOB_DELETE_CONCURRENCY_LIMIT.get(&machine.applier.cfg);
         let delete_semaphore = Semaphore::new(concurrency_limit);
 
-        let batch_parts = std::mem::take(batch_parts);
+        let mut batch_parts = std::mem::take(batch_parts);
+        Self::release_part_refs(&mut batch_parts, machine).await;
         batch_parts
             .delete(
                 machine.applier.state_versions.blob.borrow(),
@@ -625,6 +630,62 @@ where
         timer(&machine.applier.metrics.gc.steps.truncate_diff_seconds);
     }
 
+    /// Removes any part that some other shard still references from
+    /// `batch_parts`, and adds any part of some other shard that we were the
+    /// last to reference. See [PartRefs] for the protocol.
+    async fn release_part_refs(batch_parts: &mut PartDeletes<T>, machine: &Machine<K, V, T, D>) {
+        let shard_id = machine.shard_id();
+        let consensus = &*machine.applier.state_versions.consensus;
+        let blob = &*machine.applier.state_versions.blob;
+        let metrics = &machine.applier.metrics;
+        let retry_metrics = &metrics.retries.external.part_refs;
+
+        // Our own parts that some fork still references are left for the last
+        // fork to delete. In the common case, a shard that has never been
+        // forked, there are none and we don't need to look. Forking marks the
+        // source before taking any references and holds back its seqno_since
+        // until it has, so a GC that could delete a shared part always sees
+        // the mark.
+        let refs = if machine.applier.has_forks() {
+            let (_seqno, refs) = PartRefs::fetch(consensus, retry_metrics, &shard_id).await;
+            refs
+        } else {
+            PartRefs::default()
+        };
+        if !refs.refs.is_empty() {
+            // Forks reference the individual parts of a run, not the run.
+            batch_parts.expand_runs(blob, shard_id, metrics).await;
+            let keys = batch_parts
+                .keys()
+                .map(|key| key.qualify(&shard_id))
+                .collect::<Vec<_>>();
+            let held = PartRefs::update(consensus, retry_metrics, &shard_id, |refs| {
+                let mut held = BTreeSet::new();
+                for key in keys.iter() {
+                    if let Some(part) = refs.refs.get_mut(key) {
+                        part.released = true;
+                        held.insert(key.clone());
+                    }
+                }
+                held
+            })
+            .await;
+            batch_parts.retain_keys(|key| !held.contains(&key.qualify(&shard_id)));
+        }
+
+        // The parts we inherited from the shard we were forked from.
+        let mut keys_by_owner = BTreeMap::<ShardId, Vec<PartialBatchKey>>::new();
+        for key in batch_parts.take_qualified_keys() {
+            let owner = key.owner().expect("qualified key has an owner");
+            keys_by_owner.entry(owner).or_default().push(key);
+        }
+        for (owner, keys) in keys_by_owner {
+            let deletable =
+                PartRefs::release(consensus, retry_metrics, &owner, &shard_id, &keys).await;
+            batch_parts.extend_keys(deletable);
+        }
+    }
+
     // There's also a bulk delete API in s3 if the performance of this
     // becomes an issue. Maybe make Blob::delete take a list of keys?
     //
@@ -657,6 +718,163 @@ where
     }
 }
 
+/// The batch parts owned by one shard that are also referenced by the state of
+/// one or more forks of it.
+///
+/// A fork's state refers directly to the blobs of the shard it was forked from
+/// (see [PartialBatchKey::qualify]), so neither shard can delete one of those
+/// parts just because its own state stopped referencing it. Instead, each
+/// owning shard has a reference count for its shared parts, stored in
+/// Consensus next to (not inside) its state:
+///
+/// - Forking adds the new shard as a holder of every part it references. This
+///   happens before the fork's state is written, while a seqno hold on the
+///   source shard keeps its GC from deleting any of them.
+/// - When GC of the owning shard finds that a part is no longer referenced by
+///   its state, it deletes it only if there are no holders. Otherwise it marks
+///   the part as released and leaves the deletion to the last holder.
+/// - When GC of a fork finds that a part of some other shard is no longer
+///   referenced by its state, it removes itself from the holders. If it was the
+///   last one and the owner has already released the part, it deletes it.
+///
+/// Each of these is a read-modify-write of a single Consensus key, so they're
+/// linearized with each other. The failure modes are all leaks, never early
+/// deletes: e.g. a process that dies between adding holders and writing the
+/// fork's state, or an Indeterminate error that hides our own successful
+/// release, leaves behind a blob that nothing will delete.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct PartRefs {
+    /// Keyed by the qualified key of the part.
+    pub(crate) refs: BTreeMap<PartialBatchKey, PartRef>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct PartRef {
+    /// The shards whose state references the part.
+    pub(crate) holders: BTreeSet<ShardId>,
+    /// Whether the owning shard itself no longer references the part.
+    pub(crate) released: bool,
+}
+
+impl PartRefs {
+    /// The Consensus key under which the [PartRefs] of `owner` are stored.
+    pub(crate) fn consensus_key(owner: &ShardId) -> String {
+        format!("{}/part_refs", owner)
+    }
+
+    /// The inverse of [Self::consensus_key]: returns the owner if `key` is the
+    /// Consensus key of some shard's [PartRefs].
+    pub(crate) fn owner_of_consensus_key(key: &str) -> Option<ShardId> {
+        let owner = key.strip_suffix("/part_refs")?;
+        ShardId::from_str(owner).ok()
+    }
+
+    /// Returns the latest [PartRefs] of `owner`, along with the SeqNo of the
+    /// Consensus version it was read from.
+    pub(crate) async fn fetch(
+        consensus: &dyn Consensus,
+        metrics: &RetryMetrics,
+        owner: &ShardId,
+    ) -> (Option<SeqNo>, PartRefs) {
+        let key = Self::consensus_key(owner);
+        let current = retry_external(metrics, || consensus.head(&key)).await;
+        match current {
+            Some(current) => {
+                let proto = ProtoPartRefs::decode(current.data).expect("invalid encoded part refs");
+                let refs = proto.into_rust().expect("invalid encoded part refs");
+                (Some(current.seqno), refs)
+            }
+            None => (None, PartRefs::default()),
+        }
+    }
+
+    /// Applies `work_fn` to the latest [PartRefs] of `owner`, retrying on
+    /// concurrent modification, and returns its result once any change it
+    /// made is durable.
+    pub(crate) async fn update<R>(
+        consensus: &dyn Consensus,
+        metrics: &RetryMetrics,
+        owner: &ShardId,
+        mut work_fn: impl FnMut(&mut PartRefs) -> R,
+    ) -> R {
+        let key = Self::consensus_key(owner);
+        loop {
+            let (expected, mut refs) = Self::fetch(consensus, metrics, owner).await;
+            let prev = refs.clone();
+            let ret = work_fn(&mut refs);
+            if refs == prev {
+                return ret;
+            }
+
+            let new = VersionedData {
+                seqno: expected.map_or_else(SeqNo::minimum, |x| x.next()),
+                data: Bytes::from(refs.into_proto().encode_to_vec()),
+            };
+            let cas_res = retry_external(metrics, || {
+                consensus.compare_and_set(&key, expected, new.clone())
+            })
+            .await;
+            match cas_res {
+                CaSResult::Committed => {
+                    // Only the latest version is ever read.
+                    let _ = retry_external(metrics, || consensus.truncate(&key, new.seqno)).await;
+                    return ret;
+                }
+                CaSResult::ExpectationMismatch => continue,
+            }
+        }
+    }
+
+    /// Adds `holder` to the holders of the given (qualified) part keys of
+    /// `owner`.
+    pub(crate) async fn acquire(
+        consensus: &dyn Consensus,
+        metrics: &RetryMetrics,
+        owner: &ShardId,
+        holder: &ShardId,
+        keys: &[PartialBatchKey],
+    ) {
+        Self::update(consensus, metrics, owner, |refs| {
+            for key in keys {
+                refs.refs
+                    .entry(key.clone())
+                    .or_default()
+                    .holders
+                    .insert(*holder);
+            }
+        })
+        .await
+    }
+
+    /// Removes `holder` from the holders of the given (qualified) part keys of
+    /// `owner`, returning the ones that nothing references anymore.
+    pub(crate) async fn release(
+        consensus: &dyn Consensus,
+        metrics: &RetryMetrics,
+        owner: &ShardId,
+        holder: &ShardId,
+        keys: &[PartialBatchKey],
+    ) -> Vec<PartialBatchKey> {
+        Self::update(consensus, metrics, owner, |refs| {
+            let mut deletable = Vec::new();
+            for key in keys {
+                let Some(part) = refs.refs.get_mut(key) else {
+                    continue;
+                };
+                part.holders.remove(holder);
+                if part.holders.is_empty() {
+                    if part.released {
+                        deletable.push(key.clone());
+                    }
+                    refs.refs.remove(key);
+                }
+            }
+            deletable
+        })
+        .await
+    }
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct GcResults {
     pub(crate) batch_parts_deleted_from_blob: usize,
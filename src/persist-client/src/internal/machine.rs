@@ -9,6 +9,7 @@
 
 //! Implementation of the persist state machine.
 
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::ops::ControlFlow::{self, Break, Continue};
 use std::sync::Arc;
@@ -45,8 +46,9 @@ use crate::internal::maintenance::{RoutineMaintenance, WriterMaintenance};
 use crate::internal::metrics::{CmdMetrics, Metrics, MetricsRetryStream, RetryMetrics};
 use crate::internal::paths::PartialRollupKey;
 use crate::internal::state::{
-    CompareAndAppendBreak, CriticalReaderState, HandleDebugState, HollowBatch, HollowRollup,
-    IdempotencyToken, LeasedReaderState, NoOpStateTransition, Since, SnapshotErr, StateCollections,
+    CompareAndAppendBreak, CriticalReaderState, EncodedSchemas, HandleDebugState, HollowBatch,
+    HollowRollup, IdempotencyToken, LeasedReaderState, NoOpStateTransition, Since, SnapshotErr,
+    StateCollections,
 };
 use crate::internal::state_versions::StateVersions;
 use crate::internal::trace::{ApplyMergeResult, FueledMergeRes};
@@ -288,6 +290,31 @@ where
         (state, maintenance)
     }
 
+    pub async fn mark_forked(&self) -> RoutineMaintenance {
+        let metrics = Arc::clone(&self.applier.metrics);
+        let (_seqno, (), maintenance) = self
+            .apply_unbatched_idempotent_cmd(&metrics.cmds.fork, |_seqno, _cfg, state| {
+                state.mark_forked()
+            })
+            .await;
+        maintenance
+    }
+
+    pub async fn fork_from(
+        &self,
+        batches: &[HollowBatch<T>],
+        since: &Antichain<T>,
+        schemas: &BTreeMap<SchemaId, EncodedSchemas>,
+    ) -> (bool, RoutineMaintenance) {
+        let metrics = Arc::clone(&self.applier.metrics);
+        let (_seqno, forked, maintenance) = self
+            .apply_unbatched_idempotent_cmd(&metrics.cmds.fork, |_seqno, _cfg, state| {
+                state.fork_from(batches, since, schemas)
+            })
+            .await;
+        (forked, maintenance)
+    }
+
     pub async fn spine_exert(&self, fuel: usize) -> (Vec<CompactReq<T>>, RoutineMaintenance) {
         // Performance special case for no-ops, to avoid the State clones.
         if fuel == 0 || self.applier.all_batches().len() < 2 {
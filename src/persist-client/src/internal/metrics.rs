@@ -94,6 +94,8 @@ pub struct Metrics {
     pub consolidation: ConsolidationMetrics,
     /// Metrics for blob caching.
     pub blob_cache_mem: BlobMemCache,
+    /// Metrics for on-disk blob caching.
+    pub blob_cache_disk: BlobDiskCache,
     /// Metrics for tokio tasks.
     pub tasks: TasksMetrics,
     /// Metrics for columnar data encoding and decoding.
@@ -166,6 +168,7 @@ impl Metrics {
             pushdown: PushdownMetrics::new(registry),
             consolidation: ConsolidationMetrics::new(registry),
             blob_cache_mem: BlobMemCache::new(registry),
+            blob_cache_disk: BlobDiskCache::new(registry),
             tasks: TasksMetrics::new(registry),
             columnar,
             schema: SchemaMetrics::new(registry),
@@ -426,6 +429,7 @@ impl MetricsVecs {
             merge_res: self.cmd_metrics("merge_res"),
             become_tombstone: self.cmd_metrics("become_tombstone"),
             compare_and_evolve_schema: self.cmd_metrics("compare_and_evolve_schema"),
+            fork: self.cmd_metrics("fork"),
             spine_exert: self.cmd_metrics("spine_exert"),
             fetch_upper_count: registry.register(metric!(
                 name: "mz_persist_cmd_fetch_upper_count",
@@ -460,6 +464,7 @@ impl MetricsVecs {
                 fetch_state_scan: self.retry_metrics("fetch_state::scan"),
                 gc_truncate: self.retry_metrics("gc::truncate"),
                 maybe_init_cas: self.retry_metrics("maybe_init::cas"),
+                part_refs: self.retry_metrics("part_refs"),
                 rollup_delete: self.retry_metrics("rollup::delete"),
                 rollup_get: self.retry_metrics("rollup::get"),
                 rollup_set: self.retry_metrics("rollup::set"),
@@ -634,6 +639,7 @@ pub struct CmdsMetrics {
     pub(crate) merge_res: CmdMetrics,
     pub(crate) become_tombstone: CmdMetrics,
     pub(crate) compare_and_evolve_schema: CmdMetrics,
+    pub(crate) fork: CmdMetrics,
     pub(crate) spine_exert: CmdMetrics,
     pub(crate) fetch_upper_count: IntCounter,
 }
@@ -669,6 +675,7 @@ pub struct RetryExternal {
     pub(crate) fetch_state_scan: RetryMetrics,
     pub(crate) gc_truncate: RetryMetrics,
     pub(crate) maybe_init_cas: RetryMetrics,
+    pub(crate) part_refs: RetryMetrics,
     pub(crate) rollup_delete: RetryMetrics,
     pub(crate) rollup_get: RetryMetrics,
     pub(crate) rollup_set: RetryMetrics,
@@ -2508,6 +2515,59 @@ impl BlobMemCache {
     }
 }
 
+#[derive(Debug)]
+pub struct BlobDiskCache {
+    pub(crate) size_blobs: UIntGauge,
+    pub(crate) size_bytes: UIntGauge,
+    pub(crate) hits_blobs: IntCounter,
+    pub(crate) hits_bytes: IntCounter,
+    pub(crate) misses_blobs: IntCounter,
+    pub(crate) evictions: IntCounter,
+    pub(crate) invalid_blobs: IntCounter,
+}
+
+impl BlobDiskCache {
+    fn new(registry: &MetricsRegistry) -> Self {
+        BlobDiskCache {
+            size_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_size_blobs",
+                help: "count of blobs in the cache",
+                const_labels: {"cache" => "disk"},
+            )),
+            size_bytes: registry.register(metric!(
+                name: "mz_persist_blob_cache_size_bytes",
+                help: "total size of blobs in the cache",
+                const_labels: {"cache" => "disk"},
+            )),
+            hits_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_hits_blobs",
+                help: "count of blobs served via cache instead of s3",
+                const_labels: {"cache" => "disk"},
+            )),
+            hits_bytes: registry.register(metric!(
+                name: "mz_persist_blob_cache_hits_bytes",
+                help: "total size of blobs served via cache instead of s3",
+                const_labels: {"cache" => "disk"},
+            )),
+            misses_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_misses_blobs",
+                help: "count of blobs that were not served via cache",
+                const_labels: {"cache" => "disk"},
+            )),
+            evictions: registry.register(metric!(
+                name: "mz_persist_blob_cache_evictions",
+                help: "count of capacity-based cache evictions",
+                const_labels: {"cache" => "disk"},
+            )),
+            invalid_blobs: registry.register(metric!(
+                name: "mz_persist_blob_cache_invalid_blobs",
+                help: "count of cached blobs that were discarded because they failed validation",
+                const_labels: {"cache" => "disk"},
+            )),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct SemaphoreMetrics {
     cfg: PersistConfig,
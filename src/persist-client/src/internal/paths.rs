@@ -116,6 +116,11 @@ impl Display for WriterKey {
 /// Used to reduce the bytes needed to refer to a blob key in memory and in
 /// persistent state, all access to blobs are always within the context of an
 /// individual shard.
+///
+/// The one exception is a fork of a shard, whose state refers to the batch
+/// parts of the shard it was forked from. Those keys are qualified with the
+/// [ShardId] of the shard that owns the blob (see [Self::qualify]) and complete
+/// to the same [BlobKey] no matter which shard they're completed with.
 #[derive(
     Arbitrary,
     Clone,
@@ -146,11 +151,40 @@ impl PartialBatchKey {
     }
 
     pub fn split(&self) -> Option<(WriterKey, PartId)> {
-        split_batch_key(&self.0).ok()
+        let key = match self.owner() {
+            Some(_) => self.0.split_once('/')?.1,
+            None => self.0.as_str(),
+        };
+        split_batch_key(key).ok()
     }
 
     pub fn complete(&self, shard_id: &ShardId) -> BlobKey {
-        BlobKey(format!("{}/{}", shard_id, self))
+        match self.owner() {
+            Some(_) => BlobKey(self.0.clone()),
+            None => BlobKey(format!("{}/{}", shard_id, self)),
+        }
+    }
+
+    /// Returns the shard that owns the referenced blob, if this key was
+    /// qualified by [Self::qualify]. Returns None for keys that belong to the
+    /// shard whose state they appear in.
+    pub fn owner(&self) -> Option<ShardId> {
+        // WriterKeys start with `w` or `n`, so this can't be confused with an
+        // unqualified key.
+        if !self.0.starts_with('s') {
+            return None;
+        }
+        let (shard, _) = self.0.split_once('/')?;
+        ShardId::from_str(shard).ok()
+    }
+
+    /// Returns a version of this key that, when it's referenced from the state
+    /// of some other shard, still completes to the blob owned by `shard_id`.
+    pub fn qualify(&self, shard_id: &ShardId) -> Self {
+        match self.owner() {
+            Some(_) => self.clone(),
+            None => PartialBatchKey(format!("{}/{}", shard_id, self)),
+        }
     }
 }
 
@@ -357,6 +391,27 @@ mod tests {
         );
     }
 
+    #[mz_ore::test]
+    fn partial_blob_key_qualified() {
+        let (owner, fork) = (ShardId::new(), ShardId::new());
+        let (writer_id, part_id) = (WriterId::new(), PartId::new());
+        let partial_key = PartialBatchKey::new(&WriterKey::Id(writer_id.clone()), &part_id);
+        assert_eq!(partial_key.owner(), None);
+
+        let qualified = partial_key.qualify(&owner);
+        assert_eq!(qualified.owner(), Some(owner));
+        assert_eq!(qualified.qualify(&fork), qualified);
+        assert_eq!(qualified.complete(&fork), partial_key.complete(&owner));
+        assert_eq!(qualified.split(), partial_key.split());
+        assert_eq!(
+            BlobKey::parse_ids(&qualified.complete(&fork)),
+            Ok((
+                owner,
+                PartialBlobKey::Batch(WriterKey::Id(writer_id), part_id)
+            ))
+        );
+    }
+
     #[mz_ore::test]
     fn blob_key_parse() -> Result<(), String> {
         let (shard_id, writer_id, part_id) = (ShardId::new(), WriterId::new(), PartId::new());
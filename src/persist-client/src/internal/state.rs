@@ -1334,6 +1334,11 @@ pub struct StateCollections<T> {
     pub(crate) writers: BTreeMap<WriterId, WriterState<T>>,
     pub(crate) schemas: BTreeMap<SchemaId, EncodedSchemas>,
 
+    /// Whether this shard has ever been forked, and so whether GC has to
+    /// consult its [crate::internal::gc::PartRefs] before deleting a part.
+    // - Invariant: Never goes from true back to false.
+    pub(crate) has_forks: bool,
+
     // - Invariant: `trace.since == meet(all reader.since)`
     // - Invariant: `trace.since` doesn't regress across state versions.
     // - Invariant: `trace.upper` doesn't regress across state versions.
@@ -1650,15 +1655,13 @@ where
 
         let key_fn = backward_compatible(&current_key_dt, &key_dt);
         let val_fn = backward_compatible(&current_val_dt, &val_dt);
-        let (Some(key_fn), Some(val_fn)) = (key_fn, val_fn) else {
-            return Break(NoOpStateTransition(CaESchema::Incompatible));
-        };
-        // Persist initially disallows dropping columns. This would require a
-        // bunch more work (e.g. not safe to use the latest schema in
-        // compaction) and isn't initially necessary in mz.
-        if key_fn.contains_drop() || val_fn.contains_drop() {
+        if key_fn.is_none() || val_fn.is_none() {
             return Break(NoOpStateTransition(CaESchema::Incompatible));
         }
+        // Migrations are allowed to drop columns. Data written at an older
+        // schema may then no longer be sorted once migrated, so compaction and
+        // consolidating reads treat runs written at any schema other than the
+        // one they sort by as unordered.
 
         // We'll have to do something more sophisticated here to
         // generate the next id if/when we start supporting the removal
@@ -1676,6 +1679,53 @@ where
         Continue(CaESchema::Ok(id))
     }
 
+    /// Initializes a shard that has never been written to with the batches,
+    /// since, and schemas of the shard it's being forked from.
+    ///
+    /// Returns false if the shard was already in use (and so can't become a
+    /// fork), true otherwise.
+    /// Records that this shard has been forked.
+    pub fn mark_forked(&mut self) -> ControlFlow<NoOpStateTransition<()>, ()> {
+        if self.has_forks || self.is_tombstone() {
+            return Break(NoOpStateTransition(()));
+        }
+        self.has_forks = true;
+        Continue(())
+    }
+
+    pub fn fork_from(
+        &mut self,
+        batches: &[HollowBatch<T>],
+        since: &Antichain<T>,
+        schemas: &BTreeMap<SchemaId, EncodedSchemas>,
+    ) -> ControlFlow<NoOpStateTransition<bool>, bool> {
+        if self.is_tombstone() {
+            return Break(NoOpStateTransition(false));
+        }
+
+        let fork_upper = match batches.last() {
+            Some(batch) => batch.desc.upper(),
+            None => return Break(NoOpStateTransition(false)),
+        };
+        // The forked shard is never handed out before this is applied, so if
+        // the upper already matches, this is a retry of a cmd that committed.
+        if self.trace.upper() == fork_upper {
+            return Break(NoOpStateTransition(true));
+        }
+        if self.trace.upper() != &Antichain::from_elem(T::minimum()) || !self.schemas.is_empty() {
+            return Break(NoOpStateTransition(false));
+        }
+
+        // The batches were compacted to at most the since of the source
+        // shard, which is at most `since`, so the trace invariants hold.
+        self.trace.downgrade_since(since);
+        for batch in batches {
+            self.trace.push_batch_no_merge_reqs(batch.clone());
+        }
+        self.schemas.clone_from(schemas);
+        Continue(true)
+    }
+
     pub fn compare_and_append(
         &mut self,
         batch: &HollowBatch<T>,
@@ -2328,6 +2378,7 @@ where
                 critical_readers: BTreeMap::new(),
                 writers: BTreeMap::new(),
                 schemas: BTreeMap::new(),
+                has_forks: false,
                 trace: Trace::default(),
             },
         };
@@ -2739,16 +2790,18 @@ impl<T: Serialize + Timestamp + Lattice> Serialize for State<T> {
                     critical_readers,
                     writers,
                     schemas,
+                    has_forks,
                     trace,
                 },
         } = self;
-        let mut s = s.serialize_struct("State", 13)?;
+        let mut s = s.serialize_struct("State", 14)?;
         let () = s.serialize_field("applier_version", &applier_version.to_string())?;
         let () = s.serialize_field("shard_id", shard_id)?;
         let () = s.serialize_field("seqno", seqno)?;
         let () = s.serialize_field("walltime_ms", walltime_ms)?;
         let () = s.serialize_field("hostname", hostname)?;
         let () = s.serialize_field("last_gc_req", last_gc_req)?;
+        let () = s.serialize_field("has_forks", has_forks)?;
         let () = s.serialize_field("rollups", rollups)?;
         let () = s.serialize_field("active_rollup", active_rollup)?;
         let () = s.serialize_field("active_gc", active_gc)?;
@@ -3127,6 +3180,7 @@ pub(crate) mod tests {
                     critical_readers,
                     writers,
                     schemas,
+                    has_forks: false,
                     trace,
                 },
             },
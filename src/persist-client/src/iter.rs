@@ -33,6 +33,7 @@ use mz_persist::metrics::ColumnarMetrics;
 use mz_persist_types::arrow::{ArrayBound, ArrayIdx, ArrayOrd};
 use mz_persist_types::columnar::data_type;
 use mz_persist_types::part::Part;
+use mz_persist_types::schema::SchemaId;
 use mz_persist_types::{Codec, Codec64};
 use semver::Version;
 use timely::progress::Timestamp;
@@ -64,6 +65,18 @@ pub(crate) trait RowSort<T, D> {
     fn updates_from_blob(&self, updates: BlobTraceUpdates) -> StructuredUpdates;
 
     fn updates_to_blob(&self, updates: StructuredUpdates) -> Part;
+
+    /// The id of the schema that fetched data is migrated to before sorting, if known.
+    fn schema_id(&self) -> Option<SchemaId>;
+}
+
+/// Returns true if a run written with `run_schema` has to be migrated before it can be compared
+/// with data at `sort_schema`.
+///
+/// Migrations may drop columns, after which neither the sort order of the run nor its recorded
+/// key lowers are meaningful, so such runs are handled as if they were unordered.
+fn schema_mismatch(run_schema: Option<SchemaId>, sort_schema: Option<SchemaId>) -> bool {
+    matches!((run_schema, sort_schema), (Some(run), Some(sort)) if run != sort)
 }
 
 fn interleave_updates<T: Codec64, D: Codec64>(
@@ -202,6 +215,10 @@ impl<K: Codec, V: Codec, T: Codec64, D: Codec64> RowSort<T, D> for StructuredSor
     fn updates_to_blob(&self, updates: StructuredUpdates) -> Part {
         updates.data
     }
+
+    fn schema_id(&self) -> Option<SchemaId> {
+        self.schemas.id
+    }
 }
 
 type FetchResult<T> = Result<EncodedPart<T>, HollowRun<T>>;
@@ -446,15 +463,29 @@ where
         run_meta: &RunMeta,
         parts: impl IntoIterator<Item = RunPart<T>>,
     ) {
+        let mismatch = schema_mismatch(run_meta.schema, self.sort.schema_id());
+        let run_meta = if mismatch {
+            RunMeta {
+                order: Some(RunOrder::Unordered),
+                ..run_meta.clone()
+            }
+        } else {
+            run_meta.clone()
+        };
         let run = parts
             .into_iter()
             .map(|part| {
                 let bytes = part.encoded_size_bytes();
+                let structured_lower = if mismatch {
+                    None
+                } else {
+                    part.structured_key_lower()
+                };
                 let c_part = ConsolidationPart::Queued {
                     data: FetchData {
                         run_meta: run_meta.clone(),
                         part_desc: desc.clone(),
-                        structured_lower: part.structured_key_lower(),
+                        structured_lower,
                         part,
                     },
                     task: None,
@@ -572,6 +603,7 @@ where
                 .unwrap_or(self.runs.len())
         };
 
+        let sort_schema = self.sort.schema_id();
         let mut ready_futures: FuturesUnordered<_> = self.runs[0..first_larger]
             .iter_mut()
             .map(|run| async {
@@ -620,7 +652,12 @@ where
                             // Since we're pushing these onto the _front_ of the queue, we need to
                             // iterate in reverse order.
                             for part in run_part.parts.into_iter().rev() {
-                                let structured_lower = part.structured_key_lower();
+                                let structured_lower =
+                                    if schema_mismatch(data.run_meta.schema, sort_schema) {
+                                        None
+                                    } else {
+                                        part.structured_key_lower()
+                                    };
                                 let size = part.max_part_bytes();
                                 run.push_front((
                                     ConsolidationPart::Queued {
@@ -664,10 +701,38 @@ where
             total_ready > 0,
             "at least one part should be fetched and ready to go"
         );
+        drop(ready_futures);
+
+        // Expanding a hollow run may have queued up parts that can't be consolidated in the order
+        // they were written. Split those into their own runs, and make sure they get fetched too.
+        if self.split_unordered_runs() {
+            return Box::pin(self.unblock_progress()).await;
+        }
 
         Ok(())
     }
 
+    /// Splits up any multi-part run that contains possibly-unordered parts. Returns true if any
+    /// run was split.
+    fn split_unordered_runs(&mut self) -> bool {
+        let (unordered, ordered): (Vec<_>, Vec<_>) =
+            mem::take(&mut self.runs).into_iter().partition(|run| {
+                run.len() > 1
+                    && run.iter().any(|(part, _)| match part {
+                        ConsolidationPart::Queued { data, .. } => {
+                            data.run_meta.order != Some(RunOrder::Structured)
+                        }
+                        ConsolidationPart::Encoded { .. } => false,
+                    })
+            });
+        self.runs = ordered;
+        let split = !unordered.is_empty();
+        for run in unordered {
+            self.push_run(run);
+        }
+        split
+    }
+
     /// Wait until data is available, then return an iterator over the next
     /// consolidated chunk of output. If this method returns `None`, that all the data has been
     /// exhausted and the full consolidated dataset has been returned.
@@ -104,6 +104,7 @@ mod internal {
     pub mod cache;
     pub mod compact;
     pub mod encoding;
+    pub mod fork;
     pub mod gc;
     pub mod machine;
     pub mod maintenance;
@@ -820,6 +821,31 @@ impl PersistClient {
         Ok(())
     }
 
+    /// Creates a new shard with the contents of `source_id` as of `as_of` and
+    /// returns its id.
+    ///
+    /// No data is copied: the new shard references the existing batch parts
+    /// of the source, and the two diverge once either is written to. The
+    /// since of the new shard is `as_of` and its upper is at least past
+    /// `as_of`; it may contain some updates of the source beyond `as_of`.
+    ///
+    /// Returns an error if the source can't currently be read at `as_of`.
+    #[instrument(level = "debug", fields(shard = %source_id))]
+    pub async fn fork_shard<K, V, T, D>(
+        &self,
+        source_id: ShardId,
+        as_of: &Antichain<T>,
+        diagnostics: Diagnostics,
+    ) -> Result<ShardId, InvalidUsage<T>>
+    where
+        K: Debug + Codec,
+        V: Debug + Codec,
+        T: Timestamp + Lattice + Codec64 + Sync,
+        D: Monoid + Codec64 + Send + Sync,
+    {
+        crate::internal::fork::fork_shard::<K, V, T, D>(self, source_id, as_of, diagnostics).await
+    }
+
     /// Upgrade the state to the latest version. This should only be called once we will no longer
     /// need to interoperate with older versions, like after a successful upgrade.
     pub async fn upgrade_version<K, V, T, D>(
@@ -2125,6 +2151,84 @@ mod tests {
         assert!(is_finalized, "shard must still be finalized");
     }
 
+    /// Verify that a fork has the contents of its source as of the fork, that
+    /// the two diverge afterward, and that the fork stays readable once the
+    /// source has been finalized and its parts garbage collected.
+    #[mz_persist_proc::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: returning ready events from epoll_wait is not yet implemented
+    async fn fork_shard(dyncfgs: ConfigUpdates) {
+        let data = [
+            (("1".to_owned(), "one".to_owned()), 1, 1),
+            (("2".to_owned(), "two".to_owned()), 2, 1),
+            (("3".to_owned(), "three".to_owned()), 3, 1),
+            (("4".to_owned(), "four".to_owned()), 3, 1),
+        ];
+        let client = new_test_client(&dyncfgs).await;
+
+        let source_id = ShardId::new();
+        let (mut write, mut read) = client
+            .expect_open::<String, String, u64, i64>(source_id)
+            .await;
+        write.expect_append(&data[..2], vec![0], vec![3]).await;
+
+        // The source can't be read at times beyond its upper.
+        let res = client
+            .fork_shard::<String, String, u64, i64>(
+                source_id,
+                &Antichain::from_elem(3),
+                Diagnostics::for_tests(),
+            )
+            .await;
+        assert!(matches!(res, Err(InvalidUsage::InvalidForkAsOf { .. })));
+
+        let fork_id = client
+            .fork_shard::<String, String, u64, i64>(
+                source_id,
+                &Antichain::from_elem(2),
+                Diagnostics::for_tests(),
+            )
+            .await
+            .expect("as_of should be valid");
+        assert_ne!(fork_id, source_id);
+        let (mut fork_write, mut fork_read) = client
+            .expect_open::<String, String, u64, i64>(fork_id)
+            .await;
+        assert_eq!(fork_write.upper(), &Antichain::from_elem(3));
+        assert_eq!(fork_read.since(), &Antichain::from_elem(2));
+        assert_eq!(
+            fork_read.expect_snapshot_and_fetch(2).await,
+            all_ok(&data[..2], 2)
+        );
+
+        // Writes to either shard aren't visible in the other one.
+        write.expect_append(&data[2..3], vec![3], vec![4]).await;
+        fork_write.expect_append(&data[3..], vec![3], vec![4]).await;
+        assert_eq!(
+            read.expect_snapshot_and_fetch(3).await,
+            all_ok(&data[..3], 3)
+        );
+        let mut expected = data[..2].to_vec();
+        expected.push(data[3].clone());
+        assert_eq!(
+            fork_read.expect_snapshot_and_fetch(3).await,
+            all_ok(&expected, 3)
+        );
+
+        // Finalizing the source deletes the parts only it references, but not
+        // the ones it shares with the fork.
+        let () = read.downgrade_since(&Antichain::new()).await;
+        let () = write.advance_upper(&Antichain::new()).await;
+        drop(read);
+        client
+            .finalize_shard::<String, String, u64, i64>(source_id, Diagnostics::for_tests())
+            .await
+            .expect("finalization should succeed");
+        assert_eq!(
+            fork_read.expect_snapshot_and_fetch(3).await,
+            all_ok(&expected, 3)
+        );
+    }
+
     proptest! {
         #![proptest_config(ProptestConfig::with_cases(4096))]
 
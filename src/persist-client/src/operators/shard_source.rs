@@ -47,7 +47,7 @@ use tracing::{debug, trace};
 
 use crate::batch::BLOB_TARGET_SIZE;
 use crate::cfg::{RetryParameters, USE_CRITICAL_SINCE_SOURCE};
-use crate::fetch::{ExchangeableBatchPart, FetchedBlob, Lease};
+use crate::fetch::{ExchangeableBatchPart, FetchedBlob, Lease, PartSample};
 use crate::internal::state::BatchPart;
 use crate::stats::{STATS_AUDIT_PERCENT, STATS_FILTER_ENABLED};
 use crate::{Diagnostics, PersistClient, ShardId};
@@ -151,6 +151,8 @@ pub fn shard_source<'g, K, V, T, D, DT, G, C>(
     key_schema: Arc<K::Schema>,
     val_schema: Arc<V::Schema>,
     filter_fn: impl FnMut(&PartStats, AntichainRef<G::Timestamp>) -> FilterResult + 'static,
+    // If Some, only the parts included in this sample are fetched.
+    part_sample: Option<PartSample>,
     // If Some, an override for the default listen sleep retry parameters.
     listen_sleep: Option<impl Fn() -> RetryParameters + 'static>,
     start_signal: impl Future<Output = ()> + 'static,
@@ -219,6 +221,7 @@ where
         Arc::clone(&key_schema),
         Arc::clone(&val_schema),
         filter_fn,
+        part_sample,
         listen_sleep,
         start_signal,
         error_handler.clone(),
@@ -305,6 +308,7 @@ pub(crate) fn shard_source_descs<K, V, D, G>(
     key_schema: Arc<K::Schema>,
     val_schema: Arc<V::Schema>,
     mut filter_fn: impl FnMut(&PartStats, AntichainRef<G::Timestamp>) -> FilterResult + 'static,
+    part_sample: Option<PartSample>,
     // If Some, an override for the default listen sleep retry parameters.
     listen_sleep: Option<impl Fn() -> RetryParameters + 'static>,
     start_signal: impl Future<Output = ()> + 'static,
@@ -504,6 +508,14 @@ where
             let session_cap = cap_set.delayed(current_ts);
 
             for mut part_desc in parts {
+                // Skip the fetch entirely for parts that are not in the sample.
+                if let Some(sample) = &part_sample {
+                    if !part_desc.is_sampled(sample) {
+                        debug!("skipping part not included in sample {:?}", sample);
+                        continue;
+                    }
+                }
+
                 // TODO: Push more of this logic into LeasedBatchPart like we've
                 // done for project?
                 if STATS_FILTER_ENABLED.get(&cfg) {
@@ -764,6 +776,7 @@ mod tests {
                             <std::string::String as mz_persist_types::Codec>::Schema::default(),
                         ),
                         FilterResult::keep_all,
+                        None,
                         false.then_some(|| unreachable!()),
                         async {},
                         ErrorHandler::Halt("test"),
@@ -834,6 +847,7 @@ mod tests {
                             <std::string::String as mz_persist_types::Codec>::Schema::default(),
                         ),
                         FilterResult::keep_all,
+                        None,
                         false.then_some(|| unreachable!()),
                         async {},
                         ErrorHandler::Halt("test"),
@@ -1085,6 +1085,15 @@ where
 {
     /// Grab the next batch of consolidated data.
     pub async fn next(&mut self) -> Option<impl Iterator<Item = ((K, V), T, D)> + '_> {
+        self.try_next().await.expect("fetching a leased part")
+    }
+
+    /// Like [Self::next], but returns an error instead of panicking if a part
+    /// can't be fetched, which can happen when the cursor isn't backed by a
+    /// lease.
+    pub async fn try_next(
+        &mut self,
+    ) -> Result<Option<impl Iterator<Item = ((K, V), T, D)> + '_>, anyhow::Error> {
         let Self {
             consolidator,
             max_len,
@@ -1093,10 +1102,9 @@ where
             read_schemas: _,
         } = self;
 
-        let part = consolidator
-            .next_chunk(*max_len, *max_bytes)
-            .await
-            .expect("fetching a leased part")?;
+        let Some(part) = consolidator.next_chunk(*max_len, *max_bytes).await? else {
+            return Ok(None);
+        };
         let key_decoder = self
             .read_schemas
             .key
@@ -1117,7 +1125,7 @@ where
             ((k, v), t, d)
         });
 
-        Some(iter)
+        Ok(Some(iter))
     }
 }
 
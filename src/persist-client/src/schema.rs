@@ -618,13 +618,12 @@ mod tests {
         write0.try_register_schema().await;
         assert_eq!(write0.write_schemas.id.unwrap(), SchemaId(0));
 
-        // Not backward compatible (yet... we don't support dropping a column at
-        // the moment).
+        // Not backward compatible: added columns must be nullable.
         let res = client
             .compare_and_evolve_schema::<Strings, (), u64, i64>(
                 shard_id,
                 SchemaId(0),
-                &StringsSchema(vec![]),
+                &StringsSchema(vec![false, false]),
                 &UnitSchema,
                 d.clone(),
             )
@@ -677,6 +676,19 @@ mod tests {
             .await
             .unwrap();
         assert_eq!(write1.write_schemas.id.unwrap(), SchemaId(1));
+
+        // Dropping a column is also a successful evolution.
+        let res = client
+            .compare_and_evolve_schema::<Strings, (), u64, i64>(
+                shard_id,
+                SchemaId(1),
+                &StringsSchema(vec![false]),
+                &UnitSchema,
+                d.clone(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(res, CaESchema::Ok(SchemaId(2)));
     }
 
     fn strings(xs: &[((Strings, ()), u64, i64)]) -> Vec<Vec<&str>> {
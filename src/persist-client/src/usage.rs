@@ -9,19 +9,20 @@
 
 //! Introspection of storage utilization by persist
 
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 use std::time::Instant;
 
-use futures::stream::{FuturesUnordered, StreamExt};
+use futures::stream::{FuturesUnordered, StreamExt, TryStreamExt};
 use mz_ore::cast::CastFrom;
 use mz_persist::location::Blob;
 use tokio::sync::Semaphore;
 use tracing::{error, info};
 
 use crate::cfg::{PersistConfig, USAGE_STATE_FETCH_CONCURRENCY_LIMIT};
+use crate::internal::gc::PartRefs;
 use crate::internal::paths::{BlobKey, BlobKeyPrefix, PartialBlobKey, WriterKey};
-use crate::internal::state::HollowBlobRef;
+use crate::internal::state::{BatchPart, HollowBlobRef, RunPart};
 use crate::internal::state_versions::StateVersions;
 use crate::{Metrics, PersistClient, ShardId, retry_external};
 
@@ -79,6 +80,7 @@ pub struct ShardUsageAudit {
     ///   expired
     /// - A rollup leaked by a crash, but GC has not yet advanced past the
     ///   SeqNo
+    /// - A batch part that's still referenced by a fork of the shard
     pub not_leaked_not_referenced_bytes: u64,
     /// 2b: Data that is eligible for reclamation by a (future) leaked blob
     /// cleanup task (database-issues#5018).
@@ -141,6 +143,9 @@ struct BlobUsage {
 #[derive(Clone, Debug, Default)]
 struct ShardBlobUsage {
     by_writer: BTreeMap<WriterKey, u64>,
+    /// The subset of `by_writer` that the shard itself no longer references,
+    /// but some fork of it still does.
+    fork_held_by_writer: BTreeMap<WriterKey, u64>,
     rollup_bytes: u64,
 }
 
@@ -282,7 +287,10 @@ impl StorageUsageClient {
     /// [Self::shard_usage_referenced] to avoid the (costly!) scan of [Blob] if the additional
     /// categorizations are not needed.
     pub async fn shard_usage_audit(&self, shard_id: ShardId) -> ShardUsageAudit {
-        let mut blob_usage = self.blob_raw_usage(BlobKeyPrefix::Shard(&shard_id)).await;
+        let fork_held = self.fork_held_blobs(vec![shard_id]).await;
+        let mut blob_usage = self
+            .blob_raw_usage(BlobKeyPrefix::Shard(&shard_id), &fork_held)
+            .await;
         let blob_usage = blob_usage.by_shard.remove(&shard_id).unwrap_or_default();
         self.shard_usage_given_blob_usage(shard_id, &blob_usage)
             .await
@@ -292,7 +300,20 @@ impl StorageUsageClient {
     ///
     /// See [Self::shard_usage_audit] for more details on when to use a full audit.
     pub async fn shards_usage_audit(&self) -> ShardsUsageAudit {
-        let blob_usage = self.blob_raw_usage(BlobKeyPrefix::All).await;
+        // Consensus holds the PartRefs of each shard that has been forked next
+        // to its state, which is how we find them all.
+        let consensus = &*self.state_versions.consensus;
+        let consensus_keys: Vec<String> =
+            retry_external(&self.metrics.retries.external.part_refs, || {
+                consensus.list_keys().try_collect()
+            })
+            .await;
+        let forked = consensus_keys
+            .iter()
+            .filter_map(|key| PartRefs::owner_of_consensus_key(key))
+            .collect();
+        let fork_held = self.fork_held_blobs(forked).await;
+        let blob_usage = self.blob_raw_usage(BlobKeyPrefix::All, &fork_held).await;
         self.metrics
             .audit
             .blob_batch_part_bytes
@@ -335,7 +356,34 @@ impl StorageUsageClient {
         }
     }
 
-    async fn blob_raw_usage(&self, prefix: BlobKeyPrefix<'_>) -> BlobUsage {
+    /// Returns the keys of the blobs of the given shards that the shards
+    /// themselves no longer reference, but some fork of them still does.
+    async fn fork_held_blobs(&self, shard_ids: Vec<ShardId>) -> BTreeSet<String> {
+        let consensus = &*self.state_versions.consensus;
+        let mut held = BTreeSet::new();
+        for shard_id in shard_ids {
+            let (_seqno, refs) = PartRefs::fetch(
+                consensus,
+                &self.metrics.retries.external.part_refs,
+                &shard_id,
+            )
+            .await;
+            for (key, part) in refs.refs {
+                // Parts that haven't been released are still referenced by the
+                // state of the shard itself.
+                if part.released {
+                    held.insert(key.complete(&shard_id).to_string());
+                }
+            }
+        }
+        held
+    }
+
+    async fn blob_raw_usage(
+        &self,
+        prefix: BlobKeyPrefix<'_>,
+        fork_held: &BTreeSet<String>,
+    ) -> BlobUsage {
         retry_external(
             &self.metrics.retries.external.storage_usage_shard_size,
             || async {
@@ -364,6 +412,12 @@ impl StorageUsageClient {
                                     PartialBlobKey::Batch(writer_id, _) => {
                                         usage.batch_part_bytes += metadata.size_in_bytes;
                                         usage.batch_part_count += 1;
+                                        if fork_held.contains(metadata.key) {
+                                            *shard_usage
+                                                .fork_held_by_writer
+                                                .entry(writer_id.clone())
+                                                .or_default() += metadata.size_in_bytes;
+                                        }
                                         *shard_usage.by_writer.entry(writer_id).or_default() +=
                                             metadata.size_in_bytes;
                                     }
@@ -449,7 +503,7 @@ impl StorageUsageClient {
         while let Some(_) = states_iter.next(|x| {
             x.referenced_blobs().for_each(|x| match x {
                 HollowBlobRef::Batch(x) => {
-                    for part in x.parts.iter() {
+                    for part in x.parts.iter().filter(|x| is_own_part(x)) {
                         if let Some(writer_id) = part.writer_key() {
                             let writer_referenced_batches_bytes =
                                 referenced_batches_bytes.entry(writer_id).or_default();
@@ -470,7 +524,7 @@ impl StorageUsageClient {
         let mut current_state_rollups_bytes = 0;
         states_iter.state().blobs().for_each(|x| match x {
             HollowBlobRef::Batch(x) => {
-                for part in x.parts.iter() {
+                for part in x.parts.iter().filter(|x| is_own_part(x)) {
                     current_state_batches_bytes += u64::cast_from(part.hollow_bytes());
                 }
             }
@@ -535,6 +589,16 @@ impl StorageUsageClient {
     }
 }
 
+/// Whether `part` is a blob of the shard whose state references it, rather
+/// than one inherited from the shard it was forked from. The latter are
+/// accounted to the shard that owns them.
+fn is_own_part<T>(part: &RunPart<T>) -> bool {
+    match part {
+        RunPart::Single(BatchPart::Hollow(x)) => x.key.owner().is_none(),
+        RunPart::Single(BatchPart::Inline { .. }) | RunPart::Many(_) => true,
+    }
+}
+
 #[derive(Debug)]
 struct ShardUsageCumulativeMaybeRacy<'a> {
     current_state_batches_bytes: u64,
@@ -561,12 +625,18 @@ impl From<ShardUsageCumulativeMaybeRacy<'_>> for ShardUsageAudit {
                 // reclamation by a (future) leaked blob detector.
                 let writer_referenced =
                     x.referenced_batches_bytes.get(writer_key).map_or(0, |x| *x);
+                // Neither can anything that a fork still references.
+                let writer_fork_held = x
+                    .blob_usage
+                    .fork_held_by_writer
+                    .get(writer_key)
+                    .map_or(0, |x| *x);
                 // It's possible, due to races, that a writer has more
                 // referenced batches in state than we saw for that writer in
                 // blob. Cap it at the number of bytes we saw in blob, otherwise
                 // we could hit the "blob inputs should be cumulative" panic
                 // below.
-                not_leaked_bytes += std::cmp::min(*bytes, writer_referenced);
+                not_leaked_bytes += std::cmp::min(*bytes, writer_referenced + writer_fork_held);
             }
         }
         // For now, assume rollups aren't leaked. We could compute which rollups
@@ -987,6 +1057,7 @@ mod tests {
         referenced_batches_bytes: Vec<(WriterKey, u64)>,
         min_writer_key: WriterKey,
         blob_usage_by_writer: Vec<(WriterKey, u64)>,
+        blob_usage_fork_held: Vec<(WriterKey, u64)>,
         blob_usage_rollups: u64,
     }
 
@@ -1004,6 +1075,11 @@ mod tests {
                     .iter()
                     .map(|(id, b)| (id.clone(), *b))
                     .collect(),
+                fork_held_by_writer: self
+                    .blob_usage_fork_held
+                    .iter()
+                    .map(|(id, b)| (id.clone(), *b))
+                    .collect(),
                 rollup_bytes: self.blob_usage_rollups,
             };
             let input = ShardUsageCumulativeMaybeRacy {
@@ -1053,6 +1129,7 @@ mod tests {
             // - Some data leaked by a still active writer: (v3, 7) - (a, 4)
             // - Some data leaked by a now-expired writer: (v2, 8) - (b, 5)
             blob_usage_by_writer: vec![(version(3), 7), (version(2), 8)],
+            blob_usage_fork_held: vec![],
             // - Some data in rollups
             blob_usage_rollups: 6,
         }
@@ -1069,6 +1146,7 @@ mod tests {
             referenced_batches_bytes: vec![(version(3), 1)],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![(version(3), 1)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("1 0/1 0/1 0/1 0/1");
@@ -1081,6 +1159,7 @@ mod tests {
             referenced_batches_bytes: vec![(version(3), 1)],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![(version(3), 1)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("1 0/1 0/1 0/1 1/0");
@@ -1093,6 +1172,7 @@ mod tests {
             referenced_batches_bytes: vec![(version(3), 1)],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![(version(3), 1)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("1 0/1 0/1 1/0 0/0");
@@ -1105,6 +1185,7 @@ mod tests {
             referenced_batches_bytes: vec![],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![(version(3), 1)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("1 0/1 1/0 0/0 0/0");
@@ -1117,6 +1198,7 @@ mod tests {
             referenced_batches_bytes: vec![],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![(version(2), 1)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("1 1/0 0/0 0/0 0/0");
@@ -1129,6 +1211,7 @@ mod tests {
             referenced_batches_bytes: vec![],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("0 0/0 0/0 0/0 0/0");
@@ -1148,6 +1231,7 @@ mod tests {
             referenced_batches_bytes: vec![(version(3), 4)],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![(version(3), 8), (version(2), 2)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("10 2/8 2/6 2/4 2/2");
@@ -1160,6 +1244,7 @@ mod tests {
             referenced_batches_bytes: vec![(version(3), 4)],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![(version(3), 8), (version(2), 1)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("9 1/8 2/6 2/4 2/2");
@@ -1172,6 +1257,7 @@ mod tests {
             referenced_batches_bytes: vec![(version(3), 4)],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![(version(3), 7)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("7 0/7 1/6 2/4 2/2");
@@ -1184,6 +1270,7 @@ mod tests {
             referenced_batches_bytes: vec![(version(3), 4)],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![(version(3), 5)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("5 0/5 0/5 1/4 2/2");
@@ -1196,6 +1283,7 @@ mod tests {
             referenced_batches_bytes: vec![(version(3), 4)],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![(version(3), 3)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("3 0/3 0/3 0/3 1/2");
@@ -1208,6 +1296,7 @@ mod tests {
             referenced_batches_bytes: vec![(version(3), 4)],
             min_writer_key: version(3),
             blob_usage_by_writer: vec![(version(3), 1)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("1 0/1 0/1 0/1 0/1");
@@ -1225,11 +1314,29 @@ mod tests {
             referenced_batches_bytes: vec![(version(3), 5)],
             min_writer_key: version(10),
             blob_usage_by_writer: vec![(version(3), 3)],
+            blob_usage_fork_held: vec![],
             blob_usage_rollups: 0,
         }
         .run("3 0/3 0/3 3/0 0/0");
     }
 
+    /// Parts of an expired writer that the shard no longer references, but a
+    /// fork of it does, aren't leaked.
+    #[mz_ore::test]
+    fn usage_fork_held() {
+        TestCase {
+            current_state_batches_bytes: 0,
+            current_state_bytes: 0,
+            referenced_other_bytes: 0,
+            referenced_batches_bytes: vec![],
+            min_writer_key: version(3),
+            blob_usage_by_writer: vec![(version(2), 8)],
+            blob_usage_fork_held: vec![(version(2), 5)],
+            blob_usage_rollups: 0,
+        }
+        .run("8 3/5 5/0 0/0 0/0");
+    }
+
     /// Regression test for (part of) database-issues#5170, where an interrupted
     /// `bin/environmentd --reset` resulted in panic in persist usage code.
     ///
@@ -10,6 +10,7 @@
 //! Configuration for [crate::location] implementations.
 
 use std::collections::BTreeMap;
+use std::path::Path;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -22,7 +23,8 @@ use mz_postgres_client::PostgresClientKnobs;
 use mz_postgres_client::metrics::PostgresClientMetrics;
 
 use crate::azure::{AzureBlob, AzureBlobConfig};
-use crate::file::{FileBlob, FileBlobConfig};
+use crate::encrypted::{EncryptedBlob, EncryptedBlobConfig, LocalKeyProvider};
+use crate::file::{FileBlob, FileBlobConfig, FileConsensus, FileConsensusConfig};
 #[cfg(feature = "foundationdb")]
 use crate::foundationdb::{FdbConsensus, FdbConsensusConfig};
 use crate::location::{Blob, Consensus, Determinate, ExternalError};
@@ -53,6 +55,9 @@ pub enum BlobConfig {
     Mem(bool),
     /// Config for [AzureBlob].
     Azure(AzureBlobConfig),
+    /// Config for [EncryptedBlob], which encrypts the values of the wrapped
+    /// [Blob].
+    Encrypted(Box<BlobConfig>, EncryptedBlobConfig),
     #[cfg(feature = "turmoil")]
     /// Config for [crate::turmoil::TurmoilBlob].
     Turmoil(crate::turmoil::BlobConfig),
@@ -82,6 +87,10 @@ impl BlobConfig {
             BlobConfig::Mem(tombstone) => {
                 Ok(Arc::new(MemBlob::open(MemBlobConfig::new(tombstone))))
             }
+            BlobConfig::Encrypted(config, encryption) => {
+                let blob = Box::pin(config.open()).await?;
+                Ok(Arc::new(EncryptedBlob::new(blob, encryption)))
+            }
             #[cfg(feature = "turmoil")]
             BlobConfig::Turmoil(config) => Ok(Arc::new(crate::turmoil::TurmoilBlob::open(config))),
         }
@@ -95,6 +104,21 @@ impl BlobConfig {
         cfg: Arc<ConfigSet>,
     ) -> Result<Self, ExternalError> {
         let mut query_params = url.query_pairs().collect::<BTreeMap<_, _>>();
+        // Any blob can be encrypted, so this is handled independently of the
+        // scheme.
+        let encryption_key_file = query_params
+            .remove("encryption_key_file")
+            .map(|x| x.into_owned());
+        let allow_plaintext_reads = match query_params
+            .remove("encryption_allow_plaintext_reads")
+            .as_deref()
+        {
+            None | Some("false") => false,
+            Some("true") => true,
+            Some(other) => Err(Determinate::new(anyhow!(
+                "invalid encryption_allow_plaintext_reads param value: {other}"
+            )))?,
+        };
 
         let config = match url.scheme() {
             "file" => {
@@ -217,7 +241,21 @@ impl BlobConfig {
             )));
         }
 
-        Ok(config)
+        match encryption_key_file {
+            Some(path) => {
+                let keys = LocalKeyProvider::open(Path::new(&path)).await?;
+                let encryption = EncryptedBlobConfig {
+                    keys: Arc::new(keys),
+                    allow_plaintext_reads,
+                };
+                Ok(BlobConfig::Encrypted(Box::new(config), encryption))
+            }
+            None if allow_plaintext_reads => Err(ExternalError::from(anyhow!(
+                "encryption_allow_plaintext_reads requires encryption_key_file: {}",
+                url.as_str()
+            ))),
+            None => Ok(config),
+        }
     }
 }
 
@@ -229,6 +267,8 @@ pub enum ConsensusConfig {
     FoundationDB(FdbConsensusConfig),
     /// Config for [PostgresConsensus].
     Postgres(PostgresConsensusConfig),
+    /// Config for [FileConsensus].
+    File(FileConsensusConfig),
     /// Config for [MemConsensus], only available in testing.
     Mem,
     #[cfg(feature = "turmoil")]
@@ -247,6 +287,7 @@ impl ConsensusConfig {
             ConsensusConfig::Postgres(config) => {
                 Ok(Arc::new(PostgresConsensus::open(config).await?))
             }
+            ConsensusConfig::File(config) => Ok(Arc::new(FileConsensus::open(config).await?)),
             ConsensusConfig::Mem => Ok(Arc::new(MemConsensus::default())),
             #[cfg(feature = "turmoil")]
             ConsensusConfig::Turmoil(config) => {
@@ -270,6 +311,7 @@ impl ConsensusConfig {
             "postgres" | "postgresql" => Ok(ConsensusConfig::Postgres(
                 PostgresConsensusConfig::new(url, knobs, metrics, dyncfg)?,
             )),
+            "file" => Ok(ConsensusConfig::File(FileConsensusConfig::from(url.path()))),
             "mem" => {
                 if !cfg!(debug_assertions) {
                     warn!("persist unexpectedly using in-mem consensus in a release binary");
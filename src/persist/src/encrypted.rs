@@ -0,0 +1,609 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Client-side envelope encryption of [Blob] values.
+//!
+//! Every value written through an [EncryptedBlob] is encrypted with
+//! AES-256-GCM under a randomly generated _data key_. The data key is in turn
+//! encrypted ("wrapped") by a [KeyProvider], which holds the long-lived _key
+//! encryption keys_, and the wrapped data key is stored in a header in front
+//! of the ciphertext. Reading a value only requires the provider to unwrap
+//! its data key, so a provider can rotate its key encryption key at any time:
+//! new values are wrapped with the new key, while existing values keep naming
+//! the key they were wrapped with and remain readable for as long as the
+//! provider retains it. Nothing has to be rewritten.
+//!
+//! The blob key is authenticated as additional data, so a value can't be
+//! moved or copied to a different key without failing to decrypt.
+//!
+//! Encryption is enabled with the `encryption_key_file` parameter of the blob
+//! URL. By default, values that weren't written by an [EncryptedBlob] are
+//! rejected. To enable encryption on an existing environment, also set
+//! `encryption_allow_plaintext_reads=true`, which reads values without the
+//! encryption header as they are, while still encrypting every new value.
+//! Persist gradually rewrites its data as parts are compacted and as state is
+//! rolled up, but the parts of shards that are never compacted again stay in
+//! plaintext. Only unset the parameter once no plaintext values remain, e.g.
+//! after listing the blob and checking that every value starts with the
+//! encryption header; there is no tool to rewrite the remaining values.
+
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use base64::Engine;
+use bytes::{BufMut, Bytes};
+use mz_ore::bytes::SegmentedBytes;
+use openssl::symm::{Cipher, decrypt_aead, encrypt_aead};
+
+use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+
+/// The magic bytes, including a format version, that start every value
+/// written by [EncryptedBlob].
+const MAGIC: &[u8; 4] = b"MZE1";
+/// The length of AES-256 keys, in bytes.
+const KEY_LEN: usize = 32;
+/// The length of AES-GCM nonces, in bytes.
+const NONCE_LEN: usize = 12;
+/// The length of AES-GCM authentication tags, in bytes.
+const TAG_LEN: usize = 16;
+
+/// The number of values that are encrypted with the same data key before a
+/// new one is generated.
+///
+/// Nonces are random, so this keeps the probability of a nonce collision
+/// negligible.
+const DATA_KEY_MAX_USES: u64 = 1 << 20;
+/// The maximum time a data key is used to encrypt new values.
+///
+/// This bounds how long values keep being wrapped with a key encryption key
+/// after the [KeyProvider] rotated it.
+const DATA_KEY_MAX_AGE: Duration = Duration::from_secs(60 * 60);
+/// The number of unwrapped data keys to retain for decrypting values.
+const DATA_KEY_CACHE_SIZE: usize = 1024;
+
+/// A data key encrypted by a [KeyProvider].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct WrappedKey {
+    /// The id of the key encryption key that wrapped the data key.
+    pub key_id: String,
+    /// The encrypted data key.
+    pub ciphertext: Vec<u8>,
+}
+
+/// A source of key encryption keys for [EncryptedBlob], e.g. a local key file
+/// or a key management service.
+#[async_trait]
+pub trait KeyProvider: Debug + Send + Sync {
+    /// Encrypts `data_key` with the currently active key encryption key.
+    async fn wrap(&self, data_key: &[u8]) -> Result<WrappedKey, ExternalError>;
+
+    /// Decrypts a data key previously returned by [KeyProvider::wrap].
+    ///
+    /// Implementations must continue to unwrap keys that were wrapped with key
+    /// encryption keys that have since been rotated out.
+    async fn unwrap(&self, wrapped: &WrappedKey) -> Result<Vec<u8>, ExternalError>;
+}
+
+/// A [KeyProvider] backed by a set of AES-256 keys held in memory, usually
+/// loaded from a local key file.
+pub struct LocalKeyProvider {
+    keys: BTreeMap<String, [u8; KEY_LEN]>,
+    active_key_id: String,
+}
+
+impl Debug for LocalKeyProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Intentionally don't print the keys.
+        f.debug_struct("LocalKeyProvider")
+            .field("key_ids", &self.keys.keys().collect::<Vec<_>>())
+            .field("active_key_id", &self.active_key_id)
+            .finish()
+    }
+}
+
+impl LocalKeyProvider {
+    /// Returns a new [LocalKeyProvider] for the given `(key_id, key)` pairs.
+    ///
+    /// The last key is the active one, which wraps new data keys. The others
+    /// are only used to unwrap existing data keys.
+    pub fn new(keys: Vec<(String, Vec<u8>)>) -> Result<Self, ExternalError> {
+        let Some((active_key_id, _)) = keys.last() else {
+            return Err(Determinate::new(anyhow!("no encryption keys provided")).into());
+        };
+        let active_key_id = active_key_id.clone();
+        let mut by_id = BTreeMap::new();
+        for (key_id, key) in keys {
+            let key: [u8; KEY_LEN] = key.try_into().map_err(|key: Vec<u8>| {
+                Determinate::new(anyhow!(
+                    "encryption key {key_id} must be {KEY_LEN} bytes, got {}",
+                    key.len()
+                ))
+            })?;
+            if by_id.insert(key_id.clone(), key).is_some() {
+                return Err(Determinate::new(anyhow!("duplicate encryption key {key_id}")).into());
+            }
+        }
+        Ok(LocalKeyProvider {
+            keys: by_id,
+            active_key_id,
+        })
+    }
+
+    /// Loads a [LocalKeyProvider] from a key file.
+    ///
+    /// Each non-empty line of the file that does not start with `#` contains a
+    /// key id and a base64-encoded 256-bit key, separated by whitespace. The
+    /// last key in the file is the active one. To rotate keys, append a new
+    /// key to the file and restart; keep the old keys around for as long as
+    /// values wrapped with them may still be read.
+    pub async fn open(path: &Path) -> Result<Self, ExternalError> {
+        let contents = tokio::fs::read_to_string(path).await.map_err(|err| {
+            Determinate::new(anyhow!(
+                "reading encryption key file {}: {err}",
+                path.display()
+            ))
+        })?;
+        let mut keys = Vec::new();
+        for (idx, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let invalid = || {
+                Determinate::new(anyhow!(
+                    "invalid encryption key file {} at line {}",
+                    path.display(),
+                    idx + 1
+                ))
+            };
+            let (key_id, key) = line.split_once(char::is_whitespace).ok_or_else(invalid)?;
+            let key = base64::engine::general_purpose::STANDARD
+                .decode(key.trim())
+                .map_err(|_| invalid())?;
+            keys.push((key_id.to_owned(), key));
+        }
+        Self::new(keys)
+    }
+}
+
+#[async_trait]
+impl KeyProvider for LocalKeyProvider {
+    async fn wrap(&self, data_key: &[u8]) -> Result<WrappedKey, ExternalError> {
+        let key = &self.keys[&self.active_key_id];
+        let ciphertext = seal(key, self.active_key_id.as_bytes(), data_key)?;
+        Ok(WrappedKey {
+            key_id: self.active_key_id.clone(),
+            ciphertext,
+        })
+    }
+
+    async fn unwrap(&self, wrapped: &WrappedKey) -> Result<Vec<u8>, ExternalError> {
+        let key = self.keys.get(&wrapped.key_id).ok_or_else(|| {
+            Determinate::new(anyhow!("unknown encryption key {}", wrapped.key_id))
+        })?;
+        unseal(key, wrapped.key_id.as_bytes(), &wrapped.ciphertext)
+    }
+}
+
+/// The data key currently used to encrypt new values.
+#[derive(Debug)]
+struct DataKey {
+    key: [u8; KEY_LEN],
+    wrapped: WrappedKey,
+    created_at: Instant,
+    uses: u64,
+}
+
+/// Config for an [EncryptedBlob].
+#[derive(Debug, Clone)]
+pub struct EncryptedBlobConfig {
+    /// The provider that wraps and unwraps data keys.
+    pub keys: Arc<dyn KeyProvider>,
+    /// Whether values that weren't written by an [EncryptedBlob] are read as
+    /// they are, rather than rejected. See the [module docs](self).
+    pub allow_plaintext_reads: bool,
+}
+
+/// A [Blob] that transparently encrypts values before handing them to another
+/// [Blob].
+///
+/// See the [module docs](self) for details. Note that the sizes reported by
+/// [Blob::list_keys_and_metadata] are those of the encrypted values, which are
+/// slightly larger than the values that were set. The size reported by
+/// [Blob::delete], which is only used for metrics, assumes that the value was
+/// encrypted like a value set now would be.
+#[derive(Debug)]
+pub struct EncryptedBlob {
+    blob: Arc<dyn Blob>,
+    keys: Arc<dyn KeyProvider>,
+    allow_plaintext_reads: bool,
+    data_key: Mutex<Option<DataKey>>,
+    unwrapped: Mutex<BTreeMap<WrappedKey, [u8; KEY_LEN]>>,
+}
+
+impl EncryptedBlob {
+    /// Returns a new [EncryptedBlob] that stores values in `blob`.
+    pub fn new(blob: Arc<dyn Blob>, config: EncryptedBlobConfig) -> Self {
+        EncryptedBlob {
+            blob,
+            keys: config.keys,
+            allow_plaintext_reads: config.allow_plaintext_reads,
+            data_key: Mutex::new(None),
+            unwrapped: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Returns the number of bytes that encryption with the current data key
+    /// adds to a value, if there is a current data key.
+    fn overhead(&self) -> Option<usize> {
+        let data_key = self.data_key.lock().expect("lock poisoned");
+        data_key
+            .as_ref()
+            .map(|data_key| header_len(&data_key.wrapped) + NONCE_LEN + TAG_LEN)
+    }
+
+    /// Returns the data key to encrypt a new value with, generating a new one
+    /// if the current key has been used too much or for too long.
+    async fn data_key(&self) -> Result<([u8; KEY_LEN], WrappedKey), ExternalError> {
+        {
+            let mut data_key = self.data_key.lock().expect("lock poisoned");
+            if let Some(data_key) = data_key.as_mut() {
+                if data_key.uses < DATA_KEY_MAX_USES
+                    && data_key.created_at.elapsed() < DATA_KEY_MAX_AGE
+                {
+                    data_key.uses += 1;
+                    return Ok((data_key.key, data_key.wrapped.clone()));
+                }
+            }
+        }
+
+        // Wrap the new key without holding the lock. If we race with another
+        // writer, the last one wins, which is harmless.
+        let mut key = [0; KEY_LEN];
+        openssl::rand::rand_bytes(&mut key).map_err(|err| anyhow!(err))?;
+        let wrapped = self.keys.wrap(&key).await?;
+        *self.data_key.lock().expect("lock poisoned") = Some(DataKey {
+            key,
+            wrapped: wrapped.clone(),
+            created_at: Instant::now(),
+            uses: 1,
+        });
+        Ok((key, wrapped))
+    }
+
+    /// Returns the unwrapped data key for `wrapped`.
+    async fn unwrap(&self, wrapped: WrappedKey) -> Result<[u8; KEY_LEN], ExternalError> {
+        if let Some(key) = self.unwrapped.lock().expect("lock poisoned").get(&wrapped) {
+            return Ok(*key);
+        }
+        let key: [u8; KEY_LEN] = self
+            .keys
+            .unwrap(&wrapped)
+            .await?
+            .try_into()
+            .map_err(|_| Determinate::new(anyhow!("unwrapped data key has invalid length")))?;
+        let mut unwrapped = self.unwrapped.lock().expect("lock poisoned");
+        if unwrapped.len() >= DATA_KEY_CACHE_SIZE {
+            unwrapped.clear();
+        }
+        unwrapped.insert(wrapped, key);
+        Ok(key)
+    }
+}
+
+#[async_trait]
+impl Blob for EncryptedBlob {
+    async fn get(&self, key: &str) -> Result<Option<SegmentedBytes>, ExternalError> {
+        let Some(value) = self.blob.get(key).await? else {
+            return Ok(None);
+        };
+        let value = value.into_contiguous();
+        if self.allow_plaintext_reads && !value.starts_with(MAGIC) {
+            return Ok(Some(SegmentedBytes::from(value)));
+        }
+        let (wrapped, sealed) = decode_header(&value).map_err(|err| {
+            Determinate::new(anyhow!("invalid encrypted blob value {key}: {err}"))
+        })?;
+        let data_key = self.unwrap(wrapped).await?;
+        let plaintext = unseal(&data_key, key.as_bytes(), sealed)?;
+        Ok(Some(SegmentedBytes::from(plaintext)))
+    }
+
+    async fn list_keys_and_metadata(
+        &self,
+        key_prefix: &str,
+        f: &mut (dyn FnMut(BlobMetadata) + Send + Sync),
+    ) -> Result<(), ExternalError> {
+        self.blob.list_keys_and_metadata(key_prefix, f).await
+    }
+
+    async fn set(&self, key: &str, value: Bytes) -> Result<(), ExternalError> {
+        let (data_key, wrapped) = self.data_key().await?;
+        let sealed = seal(&data_key, key.as_bytes(), &value)?;
+        let value = encode_header(&wrapped, &sealed)?;
+        self.blob.set(key, value).await
+    }
+
+    async fn delete(&self, key: &str) -> Result<Option<usize>, ExternalError> {
+        let size = self.blob.delete(key).await?;
+        let overhead = self.overhead().unwrap_or(0);
+        Ok(size.map(|size| size.saturating_sub(overhead)))
+    }
+
+    async fn restore(&self, key: &str) -> Result<(), ExternalError> {
+        self.blob.restore(key).await
+    }
+}
+
+/// Encrypts `plaintext` with `key`, returning the nonce, ciphertext and tag.
+fn seal(key: &[u8; KEY_LEN], aad: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, ExternalError> {
+    let mut nonce = [0; NONCE_LEN];
+    openssl::rand::rand_bytes(&mut nonce).map_err(|err| anyhow!(err))?;
+    let mut tag = [0; TAG_LEN];
+    let ciphertext = encrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(&nonce),
+        aad,
+        plaintext,
+        &mut tag,
+    )
+    .map_err(|err| anyhow!(err))?;
+    let mut sealed = Vec::with_capacity(NONCE_LEN + ciphertext.len() + TAG_LEN);
+    sealed.extend_from_slice(&nonce);
+    sealed.extend_from_slice(&ciphertext);
+    sealed.extend_from_slice(&tag);
+    Ok(sealed)
+}
+
+/// Decrypts the output of [seal].
+fn unseal(key: &[u8; KEY_LEN], aad: &[u8], sealed: &[u8]) -> Result<Vec<u8>, ExternalError> {
+    if sealed.len() < NONCE_LEN + TAG_LEN {
+        return Err(Determinate::new(anyhow!("encrypted value is truncated")).into());
+    }
+    let (nonce, rest) = sealed.split_at(NONCE_LEN);
+    let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+    decrypt_aead(
+        Cipher::aes_256_gcm(),
+        key,
+        Some(nonce),
+        aad,
+        ciphertext,
+        tag,
+    )
+    .map_err(|_| {
+        // The error stack of a failed authentication is not informative.
+        Determinate::new(anyhow!("failed to decrypt value: authentication failed")).into()
+    })
+}
+
+/// Prepends the header of an [EncryptedBlob] value to `sealed`.
+fn encode_header(wrapped: &WrappedKey, sealed: &[u8]) -> Result<Bytes, ExternalError> {
+    let key_id_len = u16::try_from(wrapped.key_id.len())
+        .map_err(|_| Determinate::new(anyhow!("encryption key id too long")))?;
+    let wrapped_len = u16::try_from(wrapped.ciphertext.len())
+        .map_err(|_| Determinate::new(anyhow!("wrapped data key too long")))?;
+    let mut buf = Vec::with_capacity(header_len(wrapped) + sealed.len());
+    buf.put_slice(MAGIC);
+    buf.put_u16(key_id_len);
+    buf.put_slice(wrapped.key_id.as_bytes());
+    buf.put_u16(wrapped_len);
+    buf.put_slice(&wrapped.ciphertext);
+    buf.put_slice(sealed);
+    Ok(Bytes::from(buf))
+}
+
+/// Returns the length of the header of an [EncryptedBlob] value whose data key
+/// was wrapped as `wrapped`.
+fn header_len(wrapped: &WrappedKey) -> usize {
+    MAGIC.len() + 2 + wrapped.key_id.len() + 2 + wrapped.ciphertext.len()
+}
+
+/// Splits an [EncryptedBlob] value into its wrapped data key and the output
+/// of [seal].
+fn decode_header(value: &[u8]) -> Result<(WrappedKey, &[u8]), anyhow::Error> {
+    fn take<'a>(buf: &mut &'a [u8], len: usize) -> Result<&'a [u8], anyhow::Error> {
+        if buf.len() < len {
+            return Err(anyhow!("value is truncated"));
+        }
+        let (head, tail) = buf.split_at(len);
+        *buf = tail;
+        Ok(head)
+    }
+    fn take_u16(buf: &mut &[u8]) -> Result<usize, anyhow::Error> {
+        let bytes = take(buf, 2)?;
+        Ok(usize::from(u16::from_be_bytes([bytes[0], bytes[1]])))
+    }
+
+    let mut buf = value;
+    if take(&mut buf, MAGIC.len())? != MAGIC {
+        return Err(anyhow!("value was not written by an encrypted blob"));
+    }
+    let key_id_len = take_u16(&mut buf)?;
+    let key_id = std::str::from_utf8(take(&mut buf, key_id_len)?)?.to_owned();
+    let wrapped_len = take_u16(&mut buf)?;
+    let ciphertext = take(&mut buf, wrapped_len)?.to_vec();
+    Ok((WrappedKey { key_id, ciphertext }, buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::location::tests::blob_impl_test;
+    use crate::mem::{MemBlob, MemBlobConfig, MemMultiRegistry};
+
+    use super::*;
+
+    fn test_keys(ids: &[&str]) -> Vec<(String, Vec<u8>)> {
+        ids.iter()
+            .enumerate()
+            .map(|(idx, id)| {
+                let byte = u8::try_from(idx).expect("few keys");
+                (id.to_string(), vec![byte; KEY_LEN])
+            })
+            .collect()
+    }
+
+    fn test_config(ids: &[&str]) -> Result<EncryptedBlobConfig, ExternalError> {
+        Ok(EncryptedBlobConfig {
+            keys: Arc::new(LocalKeyProvider::new(test_keys(ids))?),
+            allow_plaintext_reads: false,
+        })
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn encrypted_blob() -> Result<(), ExternalError> {
+        let registry = Arc::new(tokio::sync::Mutex::new(MemMultiRegistry::new(false)));
+        let config = test_config(&["k0"])?;
+        blob_impl_test(move |path| {
+            let path = path.to_owned();
+            let registry = Arc::clone(&registry);
+            let config = config.clone();
+            async move {
+                let inner: Arc<dyn Blob> = Arc::new(registry.lock().await.blob(&path));
+                Ok(EncryptedBlob::new(inner, config))
+            }
+        })
+        .await
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn encrypted_blob_at_rest() -> Result<(), ExternalError> {
+        let inner: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        let blob = EncryptedBlob::new(Arc::clone(&inner), test_config(&["k0"])?);
+
+        let secret = b"super secret part data";
+        blob.set("a", Bytes::from_static(secret)).await?;
+        let stored = inner.get("a").await?.expect("value").into_contiguous();
+        assert!(!stored.windows(secret.len()).any(|w| w == secret));
+        assert_eq!(
+            blob.get("a").await?.map(|x| x.into_contiguous()),
+            Some(secret.to_vec())
+        );
+
+        // Values can't be moved to another key.
+        inner.set("b", Bytes::from(stored.clone())).await?;
+        assert!(blob.get("b").await.is_err());
+
+        // Tampered values fail to decrypt.
+        let mut tampered = stored;
+        *tampered.last_mut().expect("non-empty") ^= 1;
+        inner.set("a", Bytes::from(tampered)).await?;
+        assert!(blob.get("a").await.is_err());
+
+        // Values that weren't written through the encrypted blob are rejected.
+        inner.set("c", Bytes::from_static(secret)).await?;
+        assert!(blob.get("c").await.is_err());
+
+        // Missing values and deletes pass through.
+        assert_eq!(blob.get("d").await?, None);
+        assert!(blob.delete("c").await?.is_some());
+        assert_eq!(blob.delete("c").await?, None);
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn encrypted_blob_plaintext_reads() -> Result<(), ExternalError> {
+        let inner: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+        inner.set("old", Bytes::from_static(b"plaintext")).await?;
+
+        let blob = EncryptedBlob::new(
+            Arc::clone(&inner),
+            EncryptedBlobConfig {
+                allow_plaintext_reads: true,
+                ..test_config(&["k0"])?
+            },
+        );
+        // Values written before encryption was enabled are read as they are,
+        // while new values are still encrypted.
+        assert_eq!(
+            blob.get("old").await?.map(|x| x.into_contiguous()),
+            Some(b"plaintext".to_vec())
+        );
+        blob.set("new", Bytes::from_static(b"plaintext")).await?;
+        let stored = inner.get("new").await?.expect("value").into_contiguous();
+        assert!(stored.starts_with(MAGIC));
+        assert_eq!(
+            blob.get("new").await?.map(|x| x.into_contiguous()),
+            Some(b"plaintext".to_vec())
+        );
+
+        // Encrypted values are still authenticated.
+        let mut tampered = stored;
+        *tampered.last_mut().expect("non-empty") ^= 1;
+        inner.set("new", Bytes::from(tampered)).await?;
+        assert!(blob.get("new").await.is_err());
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn encrypted_blob_key_rotation() -> Result<(), ExternalError> {
+        let inner: Arc<dyn Blob> = Arc::new(MemBlob::open(MemBlobConfig::default()));
+
+        let old = EncryptedBlob::new(Arc::clone(&inner), test_config(&["k0"])?);
+        old.set("a", Bytes::from_static(b"old")).await?;
+
+        // After rotating, new values are wrapped with the new key and old
+        // values remain readable.
+        let rotated = EncryptedBlob::new(Arc::clone(&inner), test_config(&["k0", "k1"])?);
+        rotated.set("b", Bytes::from_static(b"new")).await?;
+        assert_eq!(
+            rotated.get("a").await?.map(|x| x.into_contiguous()),
+            Some(b"old".to_vec())
+        );
+        let stored = inner.get("b").await?.expect("value").into_contiguous();
+        let (wrapped, _) = decode_header(&stored).map_err(ExternalError::from)?;
+        assert_eq!(wrapped.key_id, "k1");
+
+        // Once the old key is retired, values wrapped with it are unreadable.
+        let retired = EncryptedBlob::new(
+            Arc::clone(&inner),
+            EncryptedBlobConfig {
+                keys: Arc::new(LocalKeyProvider::new(vec![(
+                    "k1".into(),
+                    test_keys(&["k0", "k1"]).remove(1).1,
+                )])?),
+                allow_plaintext_reads: false,
+            },
+        );
+        assert!(retired.get("a").await.is_err());
+        assert_eq!(
+            retired.get("b").await?.map(|x| x.into_contiguous()),
+            Some(b"new".to_vec())
+        );
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function
+    async fn local_key_provider_file() -> Result<(), ExternalError> {
+        let dir = tempfile::tempdir().map_err(anyhow::Error::from)?;
+        let path = dir.path().join("keys");
+        let key = base64::engine::general_purpose::STANDARD.encode([7; KEY_LEN]);
+        std::fs::write(&path, format!("# comment\n\nk0 {key}\nk1\t{key}\n"))
+            .map_err(anyhow::Error::from)?;
+        let provider = LocalKeyProvider::open(&path).await?;
+        assert_eq!(provider.active_key_id, "k1");
+        assert_eq!(provider.keys.len(), 2);
+
+        std::fs::write(&path, "k0 AAAA\n").map_err(anyhow::Error::from)?;
+        assert!(LocalKeyProvider::open(&path).await.is_err());
+        std::fs::write(&path, "").map_err(anyhow::Error::from)?;
+        assert!(LocalKeyProvider::open(&path).await.is_err());
+        Ok(())
+    }
+}
@@ -7,22 +7,32 @@
 // the Business Source License, use of this software will be governed
 // by the Apache License, Version 2.0.
 
-//! File backed implementations for testing and benchmarking.
+//! File backed implementations for testing, benchmarking, and single-node
+//! deployments.
 
-use std::io::ErrorKind;
+use std::collections::BTreeMap;
+use std::io::{ErrorKind, Read, Seek, SeekFrom, Write};
+use std::os::unix::fs::MetadataExt;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 use anyhow::anyhow;
+use async_stream::try_stream;
 use async_trait::async_trait;
 use bytes::Bytes;
 use fail::fail_point;
+use md5::{Digest, Md5};
 use mz_ore::bytes::SegmentedBytes;
 use mz_ore::cast::CastFrom;
 use tokio::fs::{self, File};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tracing::warn;
 
 use crate::error::Error;
-use crate::location::{Blob, BlobMetadata, Determinate, ExternalError};
+use crate::location::{
+    Blob, BlobMetadata, CaSResult, Consensus, Determinate, ExternalError, ResultStream, SeqNo,
+    VersionedData,
+};
 
 /// Configuration for opening a [FileBlob].
 #[derive(Debug, Clone)]
@@ -269,9 +279,540 @@ impl Blob for FileBlob {
     }
 }
 
+/// Configuration for opening a [FileConsensus].
+#[derive(Debug, Clone)]
+pub struct FileConsensusConfig {
+    base_dir: PathBuf,
+}
+
+impl<P: AsRef<Path>> From<P> for FileConsensusConfig {
+    fn from(base_dir: P) -> Self {
+        FileConsensusConfig {
+            base_dir: base_dir.as_ref().to_path_buf(),
+        }
+    }
+}
+
+/// Implementation of [Consensus] backed by an append-only log file.
+///
+/// Every committed `compare_and_set` and every `truncate` is appended to the
+/// log and fsync'd before it returns. Each process that has the location open
+/// keeps a copy of the log's contents in memory, which it brings up to date
+/// with any appends by other processes before every operation. Access to the
+/// log is coordinated with an advisory lock on a separate lock file: shared
+/// for reads, exclusive for writes.
+///
+/// Once most of the log is made up of truncated versions, it's atomically
+/// replaced by one that has only the live versions. Other processes notice
+/// the new file the next time they catch up and reload it from scratch.
+///
+/// All processes must be on the same machine, and the location must be on a
+/// local filesystem with working `flock` and `rename`. This makes it suitable
+/// for single-node deployments, which can then run without Postgres or
+/// CockroachDB.
+#[derive(Debug)]
+pub struct FileConsensus {
+    inner: Arc<FileConsensusInner>,
+}
+
+#[derive(Debug)]
+struct FileConsensusInner {
+    /// Locked shared while reading the log and exclusive while writing it.
+    lock_file: std::fs::File,
+    /// Serializes access to the log within this process.
+    log: Mutex<ConsensusLog>,
+}
+
+#[derive(Debug)]
+struct ConsensusLog {
+    base_dir: PathBuf,
+    /// Our handle to the log, which may since have been replaced.
+    file: std::fs::File,
+    /// The (device, inode) of `file`.
+    file_id: (u64, u64),
+    /// The offset in `file` up to which it's been applied to `data`.
+    offset: u64,
+    /// The size the log would be if it contained only the versions in `data`.
+    live_len: u64,
+    data: BTreeMap<String, Vec<VersionedData>>,
+}
+
+impl FileConsensus {
+    const LOCK_FILE: &'static str = "consensus.lock";
+    const LOG_FILE: &'static str = "consensus.log";
+    const LOG_TMP_FILE: &'static str = "consensus.log.tmp";
+
+    /// The log is never rewritten while it's smaller than this.
+    const COMPACTION_MIN_BYTES: u64 = 1024 * 1024;
+
+    /// Opens the given location for non-exclusive read-write access.
+    pub async fn open(config: FileConsensusConfig) -> Result<Self, ExternalError> {
+        let FileConsensusConfig { base_dir } = config;
+        fs::create_dir_all(&base_dir).await.map_err(Error::from)?;
+        let lock_file = std::fs::OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(base_dir.join(Self::LOCK_FILE))?;
+
+        let inner = mz_ore::task::spawn_blocking(
+            || "file_consensus_open",
+            move || -> Result<_, ExternalError> {
+                let guard = Flock::acquire(&lock_file, true)?;
+                let log_path = base_dir.join(Self::LOG_FILE);
+                let file = ConsensusLog::open_file(&log_path, true)?;
+                // fsync the directory so it's guaranteed to see the log
+                std::fs::File::open(&base_dir)?.sync_all()?;
+                let mut log = ConsensusLog {
+                    file_id: file_id(&file.metadata()?),
+                    file,
+                    base_dir,
+                    offset: 0,
+                    live_len: 0,
+                    data: BTreeMap::new(),
+                };
+                log.catch_up(true)?;
+                drop(guard);
+                Ok(FileConsensusInner {
+                    lock_file,
+                    log: Mutex::new(log),
+                })
+            },
+        )
+        .await?;
+        Ok(FileConsensus {
+            inner: Arc::new(inner),
+        })
+    }
+
+    /// Runs `f` on a log that's up to date with all writes by any process,
+    /// while holding the lock for reading or, if `exclusive`, for writing.
+    async fn run<R, F>(&self, exclusive: bool, f: F) -> Result<R, ExternalError>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut ConsensusLog) -> Result<R, ExternalError> + Send + 'static,
+    {
+        let inner = Arc::clone(&self.inner);
+        mz_ore::task::spawn_blocking(
+            || "file_consensus",
+            move || {
+                let mut log = inner.log.lock().map_err(Error::from)?;
+                let _guard = Flock::acquire(&inner.lock_file, exclusive)?;
+                log.catch_up(exclusive)?;
+                f(&mut log)
+            },
+        )
+        .await
+    }
+}
+
+impl ConsensusLog {
+    fn open_file(path: &Path, create: bool) -> Result<std::fs::File, ExternalError> {
+        let file = std::fs::OpenOptions::new()
+            .create(create)
+            .read(true)
+            .append(true)
+            .open(path)?;
+        Ok(file)
+    }
+
+    /// Applies everything that's been appended to the log since we last
+    /// looked, reloading it from scratch if it's been replaced.
+    ///
+    /// A crash in the middle of an append can leave behind a partial record at
+    /// the end of the log. That write was never acknowledged, so it's ignored
+    /// and, if we're about to write ourselves, removed. Any other record that
+    /// doesn't decode means the log is corrupt, which is returned as an error
+    /// without modifying the log.
+    fn catch_up(&mut self, exclusive: bool) -> Result<(), ExternalError> {
+        let log_path = self.base_dir.join(FileConsensus::LOG_FILE);
+        let metadata = std::fs::metadata(&log_path)?;
+        if file_id(&metadata) != self.file_id {
+            self.file = Self::open_file(&log_path, false)?;
+            self.file_id = file_id(&self.file.metadata()?);
+            self.offset = 0;
+            self.live_len = 0;
+            self.data.clear();
+        }
+
+        let mut buf = Vec::new();
+        self.file.seek(SeekFrom::Start(self.offset))?;
+        self.file.read_to_end(&mut buf)?;
+        let mut offset = 0;
+        while offset < buf.len() {
+            let Some((record, len)) = LogRecord::decode(&buf[offset..]) else {
+                if !LogRecord::is_torn(&buf[offset..]) {
+                    return Err(ExternalError::from(anyhow!(
+                        "corrupt FileConsensus log {} at offset {}",
+                        log_path.display(),
+                        self.offset + u64::cast_from(offset),
+                    )));
+                }
+                if exclusive {
+                    warn!(
+                        "discarding partially written record at end of FileConsensus log {}",
+                        log_path.display()
+                    );
+                    self.file.set_len(self.offset + u64::cast_from(offset))?;
+                    self.file.sync_all()?;
+                }
+                break;
+            };
+            self.apply(record);
+            offset += len;
+        }
+        self.offset += u64::cast_from(offset);
+        Ok(())
+    }
+
+    /// Durably appends `record` to the log and applies it. The log must be up
+    /// to date and exclusively locked.
+    ///
+    /// If this returns an error, the record may or may not have made it into
+    /// the log. Either way, the next [Self::catch_up] sorts it out.
+    fn append(&mut self, record: LogRecord) -> Result<usize, ExternalError> {
+        let mut buf = Vec::new();
+        record.encode(&mut buf);
+        self.file.write_all(&buf)?;
+        // fsync the file, so the record survives a crash once we return
+        self.file.sync_data()?;
+        self.offset += u64::cast_from(buf.len());
+        Ok(self.apply(record))
+    }
+
+    /// Applies `record` to `data`, returning the number of versions it
+    /// deleted.
+    fn apply(&mut self, record: LogRecord) -> usize {
+        match record {
+            LogRecord::Set { key, data } => {
+                self.live_len += LogRecord::set_len(&key, &data);
+                self.data.entry(key).or_default().push(data);
+                0
+            }
+            LogRecord::Truncate { key, seqno } => {
+                let Some(values) = self.data.get_mut(&key) else {
+                    return 0;
+                };
+                let deleted = values.partition_point(|x| x.seqno < seqno);
+                for value in values.drain(..deleted) {
+                    self.live_len -= LogRecord::set_len(&key, &value);
+                }
+                deleted
+            }
+        }
+    }
+
+    /// Replaces the log with one that contains only the live versions, if
+    /// enough of it is garbage to make that worthwhile. The log must be up to
+    /// date and exclusively locked.
+    fn maybe_compact(&mut self) -> Result<(), ExternalError> {
+        if self.offset < u64::max(FileConsensus::COMPACTION_MIN_BYTES, 2 * self.live_len) {
+            return Ok(());
+        }
+
+        let mut buf = Vec::with_capacity(usize::cast_from(self.live_len));
+        for (key, values) in self.data.iter() {
+            for value in values {
+                LogRecord::encode_set(&mut buf, key, value);
+            }
+        }
+
+        // Same as FileBlob::set: write a temp file and atomically rename it
+        // into place.
+        let log_path = self.base_dir.join(FileConsensus::LOG_FILE);
+        let tmp_path = self.base_dir.join(FileConsensus::LOG_TMP_FILE);
+        let mut tmp = std::fs::File::create(&tmp_path)?;
+        tmp.write_all(&buf)?;
+        tmp.sync_all()?;
+        let parent_dir = std::fs::File::open(&self.base_dir)?;
+        parent_dir.sync_all()?;
+        std::fs::rename(&tmp_path, &log_path)?;
+        parent_dir.sync_all()?;
+
+        // Any failure before this point leaves us with the old log, and any
+        // after it is noticed by the next catch_up.
+        self.file = Self::open_file(&log_path, false)?;
+        self.file_id = file_id(&self.file.metadata()?);
+        self.offset = u64::cast_from(buf.len());
+        self.live_len = self.offset;
+        Ok(())
+    }
+}
+
+/// An advisory lock on a file, held until dropped.
+struct Flock<'a>(&'a std::fs::File);
+
+impl<'a> Flock<'a> {
+    fn acquire(file: &'a std::fs::File, exclusive: bool) -> Result<Self, ExternalError> {
+        if exclusive {
+            file.lock()?;
+        } else {
+            file.lock_shared()?;
+        }
+        Ok(Flock(file))
+    }
+}
+
+impl Drop for Flock<'_> {
+    fn drop(&mut self) {
+        if let Err(err) = self.0.unlock() {
+            warn!("failed to unlock FileConsensus lock file: {}", err);
+        }
+    }
+}
+
+fn file_id(metadata: &std::fs::Metadata) -> (u64, u64) {
+    (metadata.dev(), metadata.ino())
+}
+
+#[async_trait]
+impl Consensus for FileConsensus {
+    fn list_keys(&self) -> ResultStream<'_, String> {
+        Box::pin(try_stream! {
+            let keys = self
+                .run(false, |log| Ok(log.data.keys().cloned().collect::<Vec<_>>()))
+                .await?;
+            for key in keys {
+                yield key;
+            }
+        })
+    }
+
+    async fn head(&self, key: &str) -> Result<Option<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        self.run(false, move |log| {
+            Ok(log.data.get(&key).and_then(|values| values.last().cloned()))
+        })
+        .await
+    }
+
+    async fn compare_and_set(
+        &self,
+        key: &str,
+        expected: Option<SeqNo>,
+        new: VersionedData,
+    ) -> Result<CaSResult, ExternalError> {
+        if let Some(expected) = expected {
+            if new.seqno <= expected {
+                return Err(ExternalError::from(anyhow!(
+                    "new seqno must be strictly greater than expected. Got new: {:?} expected: {:?}",
+                    new.seqno,
+                    expected
+                )));
+            }
+        }
+
+        if new.seqno.0 > i64::MAX.try_into().expect("i64::MAX known to fit in u64") {
+            return Err(ExternalError::from(anyhow!(
+                "sequence numbers must fit within [0, i64::MAX], received: {:?}",
+                new.seqno
+            )));
+        }
+
+        let key = key.to_owned();
+        self.run(true, move |log| {
+            let current = log.data.get(&key).and_then(|values| values.last());
+            if current.map(|x| x.seqno) != expected {
+                return Ok(CaSResult::ExpectationMismatch);
+            }
+            log.append(LogRecord::Set { key, data: new })?;
+            Ok(CaSResult::Committed)
+        })
+        .await
+    }
+
+    async fn scan(
+        &self,
+        key: &str,
+        from: SeqNo,
+        limit: usize,
+    ) -> Result<Vec<VersionedData>, ExternalError> {
+        let key = key.to_owned();
+        self.run(false, move |log| {
+            let Some(values) = log.data.get(&key) else {
+                return Ok(Vec::new());
+            };
+            let from_idx = values.partition_point(|x| x.seqno < from);
+            let from_values = &values[from_idx..];
+            Ok(from_values[..usize::min(limit, from_values.len())].to_vec())
+        })
+        .await
+    }
+
+    async fn truncate(&self, key: &str, seqno: SeqNo) -> Result<Option<usize>, ExternalError> {
+        let key = key.to_owned();
+        self.run(true, move |log| {
+            let values = log.data.get(&key);
+            if values
+                .and_then(|x| x.last())
+                .is_none_or(|data| data.seqno < seqno)
+            {
+                return Err(ExternalError::from(anyhow!(
+                    "upper bound too high for truncate: {:?}",
+                    seqno
+                )));
+            }
+            if values.is_none_or(|x| x.first().is_none_or(|x| x.seqno >= seqno)) {
+                return Ok(Some(0));
+            }
+
+            let deleted = log.append(LogRecord::Truncate { key, seqno })?;
+            // The truncate is durable at this point, so a failure to compact
+            // only means we'll try again next time.
+            if let Err(err) = log.maybe_compact() {
+                warn!("failed to compact FileConsensus log: {}", err);
+            }
+            Ok(Some(deleted))
+        })
+        .await
+    }
+}
+
+/// An entry in the log of a [FileConsensus].
+///
+/// Each record is framed as a little-endian u32 length, the payload, and the
+/// MD5 checksum of the payload. The payload is a tag byte, a little-endian u32
+/// key length, the key, a little-endian u64 seqno, and for [LogRecord::Set],
+/// the data.
+#[derive(Debug)]
+enum LogRecord {
+    /// A version written by `compare_and_set`.
+    Set { key: String, data: VersionedData },
+    /// A `truncate` of all versions of a key before `seqno`.
+    Truncate { key: String, seqno: SeqNo },
+}
+
+impl LogRecord {
+    const SET: u8 = 0;
+    const TRUNCATE: u8 = 1;
+
+    const LEN_BYTES: usize = 4;
+    const CHECKSUM_BYTES: usize = 16;
+    /// The size of the payload of a record with an empty key and no data: a
+    /// tag, a key length, and a seqno.
+    const MIN_PAYLOAD_BYTES: usize = 1 + 4 + 8;
+
+    fn encode(&self, buf: &mut Vec<u8>) {
+        match self {
+            LogRecord::Set { key, data } => Self::encode_set(buf, key, data),
+            LogRecord::Truncate { key, seqno } => {
+                Self::encode_raw(buf, Self::TRUNCATE, key, *seqno, &[])
+            }
+        }
+    }
+
+    fn encode_set(buf: &mut Vec<u8>, key: &str, data: &VersionedData) {
+        Self::encode_raw(buf, Self::SET, key, data.seqno, &data.data);
+    }
+
+    fn encode_raw(buf: &mut Vec<u8>, tag: u8, key: &str, seqno: SeqNo, data: &[u8]) {
+        let payload_len = Self::MIN_PAYLOAD_BYTES + key.len() + data.len();
+        let payload_len = u32::try_from(payload_len).expect("record larger than 4 GiB");
+        let key_len = u32::try_from(key.len()).expect("key larger than 4 GiB");
+        buf.extend_from_slice(&payload_len.to_le_bytes());
+        let start = buf.len();
+        buf.push(tag);
+        buf.extend_from_slice(&key_len.to_le_bytes());
+        buf.extend_from_slice(key.as_bytes());
+        buf.extend_from_slice(&seqno.0.to_le_bytes());
+        buf.extend_from_slice(data);
+        let checksum = Md5::digest(&buf[start..]);
+        buf.extend_from_slice(&checksum);
+    }
+
+    /// The encoded size of a [LogRecord::Set] of `data` to `key`.
+    fn set_len(key: &str, data: &VersionedData) -> u64 {
+        u64::cast_from(Self::framed_len(
+            Self::MIN_PAYLOAD_BYTES + key.len() + data.data.len(),
+        ))
+    }
+
+    fn framed_len(payload_len: usize) -> usize {
+        Self::LEN_BYTES + payload_len + Self::CHECKSUM_BYTES
+    }
+
+    /// Returns whether `buf`, which doesn't start with an intact record, is
+    /// the start of a record that was cut off by a crash in the middle of an
+    /// append, rather than a corrupt part of the log.
+    ///
+    /// Appends are the only writes to the end of the log, so a torn record
+    /// must run to the end of `buf`, must agree with itself for as much of it
+    /// as made it to disk, and can't be followed by any intact record.
+    fn is_torn(buf: &[u8]) -> bool {
+        let Some(len) = buf.get(..Self::LEN_BYTES) else {
+            return true;
+        };
+        let payload_len = Self::decode_len(len);
+        // A record that fits in `buf` but fails its checksum was fully
+        // written, and then damaged.
+        if payload_len < Self::MIN_PAYLOAD_BYTES || Self::framed_len(payload_len) <= buf.len() {
+            return false;
+        }
+        let payload = &buf[Self::LEN_BYTES..];
+        match payload.first() {
+            None => {}
+            Some(&Self::SET) | Some(&Self::TRUNCATE) => {}
+            Some(_) => return false,
+        }
+        if let Some(key_len) = payload.get(1..5) {
+            let key_len = Self::decode_len(key_len);
+            let min_payload_len = Self::MIN_PAYLOAD_BYTES + key_len;
+            let plausible = match payload[0] {
+                Self::TRUNCATE => payload_len == min_payload_len,
+                _ => payload_len >= min_payload_len,
+            };
+            if !plausible {
+                return false;
+            }
+        }
+        (1..buf.len()).all(|offset| Self::decode(&buf[offset..]).is_none())
+    }
+
+    fn decode_len(buf: &[u8]) -> usize {
+        let prefix = buf[..Self::LEN_BYTES].try_into().expect("slice of len 4");
+        usize::cast_from(u32::from_le_bytes(prefix))
+    }
+
+    /// Decodes the record at the start of `buf`, returning it along with its
+    /// encoded size, or None if `buf` doesn't start with a complete and intact
+    /// record.
+    fn decode(buf: &[u8]) -> Option<(LogRecord, usize)> {
+        let payload_len = Self::decode_len(buf.get(..Self::LEN_BYTES)?);
+        let payload = buf.get(Self::LEN_BYTES..Self::LEN_BYTES + payload_len)?;
+        let checksum = buf.get(Self::LEN_BYTES + payload_len..Self::framed_len(payload_len))?;
+        if Md5::digest(payload).as_slice() != checksum {
+            return None;
+        }
+
+        let (&tag, payload) = payload.split_first()?;
+        let key_len = Self::decode_len(payload.get(..4)?);
+        let key = std::str::from_utf8(payload.get(4..4 + key_len)?).ok()?;
+        let payload = &payload[4 + key_len..];
+        let seqno = SeqNo(u64::from_le_bytes(payload.get(..8)?.try_into().ok()?));
+        let data = &payload[8..];
+        let record = match tag {
+            Self::SET => LogRecord::Set {
+                key: key.to_owned(),
+                data: VersionedData {
+                    seqno,
+                    data: Bytes::copy_from_slice(data),
+                },
+            },
+            Self::TRUNCATE if data.is_empty() => LogRecord::Truncate {
+                key: key.to_owned(),
+                seqno,
+            },
+            _ => return None,
+        };
+        Some((record, Self::framed_len(payload_len)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::location::tests::blob_impl_test;
+    use futures_util::TryStreamExt;
+
+    use crate::location::tests::{blob_impl_test, consensus_impl_test};
 
     use super::*;
 
@@ -296,4 +837,185 @@ mod tests {
 
         Ok(())
     }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock`
+    async fn file_consensus() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        consensus_impl_test(|| FileConsensus::open(temp_dir.path().into())).await
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock`
+    async fn file_consensus_durability() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let versions = (0..200u64)
+            .map(|seqno| VersionedData {
+                seqno: SeqNo(seqno),
+                data: Bytes::from(vec![b'a'; 10 * 1024]),
+            })
+            .collect::<Vec<_>>();
+
+        // Write, and truncate, enough that the log gets compacted at least
+        // once along the way, while another instance (standing in for another
+        // process) has the same location open.
+        let consensus = FileConsensus::open(temp_dir.path().into()).await?;
+        let other = FileConsensus::open(temp_dir.path().into()).await?;
+        assert_eq!(other.head("k").await, Ok(None));
+        let mut expected = None;
+        for version in versions.iter() {
+            let res = consensus
+                .compare_and_set("k", expected, version.clone())
+                .await?;
+            assert_eq!(res, CaSResult::Committed);
+            expected = Some(version.seqno);
+            let truncate_to = SeqNo(version.seqno.0.saturating_sub(1));
+            consensus.truncate("k", truncate_to).await?;
+        }
+        let log_len = consensus.inner.log.lock().expect("lock poisoned").offset;
+        assert!(log_len < FileConsensus::COMPACTION_MIN_BYTES, "{log_len}");
+
+        // The other instance sees the writes and can make its own.
+        assert_eq!(
+            other.scan("k", SeqNo::minimum(), usize::MAX).await,
+            Ok(versions[198..].to_vec())
+        );
+        assert_eq!(
+            other
+                .compare_and_set("k", Some(SeqNo(198)), versions[0].clone())
+                .await,
+            Err(ExternalError::from(anyhow!(
+                "new seqno must be strictly greater than expected. Got new: SeqNo(0) expected: SeqNo(198)"
+            )))
+        );
+        let other_version = VersionedData {
+            seqno: SeqNo(0),
+            data: Bytes::from("other"),
+        };
+        assert_eq!(
+            other
+                .compare_and_set("other", None, other_version.clone())
+                .await,
+            Ok(CaSResult::Committed)
+        );
+        assert_eq!(
+            consensus.head("other").await,
+            Ok(Some(other_version.clone()))
+        );
+        drop(consensus);
+        drop(other);
+
+        // Everything that was acknowledged survives a reopen, including after
+        // a crash in the middle of an append.
+        let log_path = temp_dir.path().join(FileConsensus::LOG_FILE);
+        let mut torn = Vec::new();
+        LogRecord::encode_set(
+            &mut torn,
+            "k",
+            &VersionedData {
+                seqno: SeqNo(1000),
+                data: Bytes::from("torn"),
+            },
+        );
+        let mut log = std::fs::OpenOptions::new().append(true).open(&log_path)?;
+        log.write_all(&torn[..torn.len() - 1])?;
+        log.sync_all()?;
+        drop(log);
+
+        let consensus = FileConsensus::open(temp_dir.path().into()).await?;
+        assert_eq!(
+            consensus.scan("k", SeqNo::minimum(), usize::MAX).await,
+            Ok(versions[198..].to_vec())
+        );
+        assert_eq!(consensus.head("other").await, Ok(Some(other_version)));
+        let keys: Vec<_> = consensus.list_keys().try_collect().await?;
+        assert_eq!(keys, vec!["k".to_owned(), "other".to_owned()]);
+
+        // The torn record was dropped, so later appends aren't lost.
+        let next = VersionedData {
+            seqno: SeqNo(200),
+            data: Bytes::from("next"),
+        };
+        assert_eq!(
+            consensus
+                .compare_and_set("k", Some(SeqNo(199)), next.clone())
+                .await,
+            Ok(CaSResult::Committed)
+        );
+        drop(consensus);
+        let consensus = FileConsensus::open(temp_dir.path().into()).await?;
+        assert_eq!(consensus.head("k").await, Ok(Some(next)));
+
+        Ok(())
+    }
+
+    #[mz_ore::test(tokio::test)]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `flock`
+    async fn file_consensus_corruption() -> Result<(), ExternalError> {
+        let temp_dir = tempfile::tempdir().map_err(Error::from)?;
+        let versions = (0..3u64)
+            .map(|seqno| VersionedData {
+                seqno: SeqNo(seqno),
+                data: Bytes::from(format!("v{seqno}")),
+            })
+            .collect::<Vec<_>>();
+        let consensus = FileConsensus::open(temp_dir.path().into()).await?;
+        let mut expected = None;
+        for version in versions.iter() {
+            let res = consensus
+                .compare_and_set("k", expected, version.clone())
+                .await?;
+            assert_eq!(res, CaSResult::Committed);
+            expected = Some(version.seqno);
+        }
+        drop(consensus);
+
+        let log_path = temp_dir.path().join(FileConsensus::LOG_FILE);
+        let log = std::fs::read(&log_path)?;
+        let open_corrupt = |corrupt: Vec<u8>| {
+            let temp_dir = temp_dir.path().to_owned();
+            let log_path = log_path.clone();
+            async move {
+                std::fs::write(&log_path, &corrupt)?;
+                let res = FileConsensus::open(temp_dir.into()).await;
+                assert!(
+                    res.as_ref()
+                        .is_err_and(|err| err.to_string().contains("corrupt FileConsensus log")),
+                    "{res:?}"
+                );
+                // The log is left as is, for a human to look at.
+                assert_eq!(std::fs::read(&log_path)?, corrupt);
+                Ok::<_, ExternalError>(())
+            }
+        };
+
+        // A length prefix in the middle of the log that points past its end
+        // isn't mistaken for a partial write at the end.
+        let second = usize::cast_from(LogRecord::set_len("k", &versions[0]));
+        let mut corrupt = log.clone();
+        let past_end = u32::try_from(log.len()).expect("small log");
+        corrupt[second..second + LogRecord::LEN_BYTES].copy_from_slice(&past_end.to_le_bytes());
+        open_corrupt(corrupt).await?;
+
+        // Neither is one that's implausibly short.
+        let mut corrupt = log.clone();
+        corrupt[second..second + LogRecord::LEN_BYTES].copy_from_slice(&1u32.to_le_bytes());
+        open_corrupt(corrupt).await?;
+
+        // Nor a complete record at the end that fails its checksum.
+        let mut corrupt = log.clone();
+        let last = corrupt.len() - LogRecord::CHECKSUM_BYTES - 1;
+        corrupt[last] ^= 0xff;
+        open_corrupt(corrupt).await?;
+
+        // Once repaired, everything is still there.
+        std::fs::write(&log_path, &log)?;
+        let consensus = FileConsensus::open(temp_dir.path().into()).await?;
+        assert_eq!(
+            consensus.scan("k", SeqNo::minimum(), usize::MAX).await,
+            Ok(versions)
+        );
+
+        Ok(())
+    }
 }
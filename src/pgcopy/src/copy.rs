@@ -434,6 +434,8 @@ pub enum CopyFormatParams<'a> {
     Csv(CopyCsvFormatParams<'a>),
     Binary,
     Parquet,
+    Json,
+    Avro,
 }
 
 impl CopyFormatParams<'static> {
@@ -443,6 +445,8 @@ impl CopyFormatParams<'static> {
             &CopyFormatParams::Csv(_) => "csv",
             &CopyFormatParams::Binary => "bin",
             &CopyFormatParams::Parquet => "parquet",
+            &CopyFormatParams::Json => "json",
+            &CopyFormatParams::Avro => "avro",
         }
     }
 
@@ -452,6 +456,8 @@ impl CopyFormatParams<'static> {
             CopyFormatParams::Csv(params) => params.header,
             CopyFormatParams::Binary => false,
             CopyFormatParams::Parquet => false,
+            CopyFormatParams::Json => false,
+            CopyFormatParams::Avro => false,
         }
     }
 }
@@ -473,6 +479,8 @@ pub fn decode_copy_format<'a>(
             // TODO(cf2): Support Parquet over STDIN.
             Err(io::Error::new(io::ErrorKind::Unsupported, "parquet format"))
         }
+        CopyFormatParams::Json => Err(io::Error::new(io::ErrorKind::Unsupported, "json format")),
+        CopyFormatParams::Avro => Err(io::Error::new(io::ErrorKind::Unsupported, "avro format")),
     }
 }
 
@@ -491,6 +499,8 @@ pub fn encode_copy_format<'a>(
             // TODO(cf2): Support Parquet over STDIN.
             Err(io::Error::new(io::ErrorKind::Unsupported, "parquet format"))
         }
+        CopyFormatParams::Json => Err(io::Error::new(io::ErrorKind::Unsupported, "json format")),
+        CopyFormatParams::Avro => Err(io::Error::new(io::ErrorKind::Unsupported, "avro format")),
     }
 }
 
@@ -520,6 +530,8 @@ pub fn encode_copy_format_header<'a>(
             // TODO(cf2): Support Parquet over STDIN.
             Err(io::Error::new(io::ErrorKind::Unsupported, "parquet format"))
         }
+        CopyFormatParams::Json => Err(io::Error::new(io::ErrorKind::Unsupported, "json format")),
+        CopyFormatParams::Avro => Err(io::Error::new(io::ErrorKind::Unsupported, "avro format")),
     }
 }
 
@@ -788,3 +788,13 @@ pub const SOURCE_MZ_CATALOG_RAW_OID: u32 = 17067;
 pub const LOG_MZ_CLUSTER_PROMETHEUS_METRICS_OID: u32 = 17068;
 pub const FUNC_PARSE_CATALOG_ID_OID: u32 = 17069;
 pub const FUNC_PARSE_CATALOG_PRIVILEGES_OID: u32 = 17070;
+pub const TABLE_MZ_POSTGRES_SINKS_OID: u32 = 17071;
+pub const TABLE_MZ_MYSQL_SINKS_OID: u32 = 17072;
+pub const TABLE_MZ_SQL_SERVER_SINKS_OID: u32 = 17073;
+pub const TABLE_MZ_S3_SINKS_OID: u32 = 17074;
+pub const TABLE_MZ_HTTP_SINKS_OID: u32 = 17075;
+pub const FUNC_APPROX_COUNT_DISTINCT_OID: u32 = 17076;
+pub const FUNC_HLL_SKETCH_OID: u32 = 17077;
+pub const FUNC_HLL_MERGE_OID: u32 = 17078;
+pub const FUNC_HLL_ESTIMATE_OID: u32 = 17079;
+pub const FUNC_GROUPING_OID: u32 = 17080;
@@ -282,6 +282,7 @@ impl Codec {
             BackendMessage::ParameterStatus(_, _) => b'S',
             BackendMessage::PortalSuspended => b's',
             BackendMessage::BackendKeyData { .. } => b'K',
+            BackendMessage::NotificationResponse { .. } => b'A',
             BackendMessage::ParameterDescription(_) => b't',
             BackendMessage::ParseComplete => b'1',
             BackendMessage::BindComplete => b'2',
@@ -295,6 +296,7 @@ impl Codec {
             }
             BackendMessage::CopyInResponse { .. } => b'G',
             BackendMessage::CopyOutResponse { .. } => b'H',
+            BackendMessage::CopyBothResponse { .. } => b'W',
             BackendMessage::CopyData(_) => b'd',
             BackendMessage::CopyDone => b'c',
         };
@@ -313,6 +315,10 @@ impl Codec {
             | BackendMessage::CopyOutResponse {
                 overall_format,
                 column_formats,
+            }
+            | BackendMessage::CopyBothResponse {
+                overall_format,
+                column_formats,
             } => {
                 dst.put_format_i8(overall_format);
                 if column_formats.len() > usize::try_from(i16::MAX).expect("i16::MAX is positive") {
@@ -442,6 +448,15 @@ impl Codec {
                 dst.put_u32(conn_id);
                 dst.put_u32(secret_key);
             }
+            BackendMessage::NotificationResponse {
+                pid,
+                channel,
+                payload,
+            } => {
+                dst.put_u32(pid);
+                dst.put_string(&channel);
+                dst.put_string(&payload);
+            }
             BackendMessage::ParameterDescription(params) => {
                 if params.len() > usize::try_from(i16::MAX).expect("i16::MAX is positive") {
                     return Err(io::Error::new(
@@ -43,6 +43,11 @@ pub enum BackendMessage {
     BindComplete,
     CloseComplete,
     ErrorResponse(ErrorResponse),
+    NotificationResponse {
+        pid: u32,
+        channel: String,
+        payload: String,
+    },
     CopyInResponse {
         overall_format: mz_pgwire_common::Format,
         column_formats: Vec<mz_pgwire_common::Format>,
@@ -51,6 +56,10 @@ pub enum BackendMessage {
         overall_format: mz_pgwire_common::Format,
         column_formats: Vec<mz_pgwire_common::Format>,
     },
+    CopyBothResponse {
+        overall_format: mz_pgwire_common::Format,
+        column_formats: Vec<mz_pgwire_common::Format>,
+    },
     CopyData(Vec<u8>),
     CopyDone,
 }
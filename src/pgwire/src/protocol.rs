@@ -22,8 +22,8 @@ use futures::future::{BoxFuture, FutureExt, pending};
 use itertools::Itertools;
 use mz_adapter::client::RecordFirstRowStream;
 use mz_adapter::session::{
-    EndTransactionAction, InProgressRows, LifecycleTimestamps, PortalRefMut, PortalState, Session,
-    SessionConfig, TransactionStatus,
+    EndTransactionAction, InProgressRows, LifecycleTimestamps, Notification, PortalRefMut,
+    PortalState, PublishedRelation, ReplicationEvent, Session, SessionConfig, TransactionStatus,
 };
 use mz_adapter::statement_logging::{StatementEndedExecutionReason, StatementExecutionStrategy};
 use mz_adapter::{
@@ -32,7 +32,9 @@ use mz_adapter::{
 };
 use mz_auth::Authenticated;
 use mz_auth::password::Password;
-use mz_authenticator::{Authenticator, GenericOidcAuthenticator};
+use mz_authenticator::{
+    Authenticator, CertAuthenticator, GenericOidcAuthenticator, LdapAuthenticator,
+};
 use mz_frontegg_auth::Authenticator as FronteggAuthenticator;
 use mz_ore::cast::CastFrom;
 use mz_ore::netio::AsyncReady;
@@ -41,12 +43,12 @@ use mz_ore::str::StrExt;
 use mz_ore::{assert_none, assert_ok, instrument, soft_assert_eq_or_log};
 use mz_pgcopy::{CopyCsvFormatParams, CopyFormatParams, CopyTextFormatParams};
 use mz_pgwire_common::{
-    ConnectionCounter, Cursor, ErrorResponse, Format, FrontendMessage, Severity, VERSION_3,
+    Conn, ConnectionCounter, Cursor, ErrorResponse, Format, FrontendMessage, Severity, VERSION_3,
     VERSIONS,
 };
 use mz_repr::{
     CatalogItemId, ColumnIndex, Datum, RelationDesc, RowArena, RowIterator, RowRef,
-    SqlRelationType, SqlScalarType,
+    SqlRelationType, SqlScalarType, Timestamp,
 };
 use mz_server_core::TlsMode;
 use mz_server_core::listeners;
@@ -60,9 +62,11 @@ use mz_sql::plan::{CopyFormat, ExecuteTimeout, StatementDesc};
 use mz_sql::session::metadata::SessionMetadata;
 use mz_sql::session::user::INTERNAL_USER_NAMES;
 use mz_sql::session::vars::VarInput;
+use openssl::x509::{X509, X509VerifyResult};
 use postgres::error::SqlState;
 use tokio::io::{self, AsyncRead, AsyncWrite};
 use tokio::select;
+use tokio::sync::mpsc;
 use tokio::time::{self};
 use tokio_metrics::TaskMetrics;
 use tokio_stream::wrappers::UnboundedReceiverStream;
@@ -76,6 +80,7 @@ use crate::message::{
     self, BackendMessage, SASLServerFinalMessage, SASLServerFinalMessageKinds,
     SASLServerFirstMessage,
 };
+use crate::replication::{self, StandbyMessage, TransactionEncoder};
 
 /// Reports whether the given stream begins with a pgwire handshake.
 ///
@@ -172,6 +177,33 @@ where
     }
 
     let user = params.remove("user").unwrap_or_else(String::new);
+
+    // Clients request a walsender connection with the `replication` startup
+    // parameter. Only logical replication, which is bound to a database, is
+    // supported.
+    let replication = match params.remove("replication").as_deref() {
+        None => false,
+        Some("database") => true,
+        Some(value) => match mz_repr::strconv::parse_bool(value) {
+            Ok(false) => false,
+            Ok(true) => {
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::FEATURE_NOT_SUPPORTED,
+                        "physical replication is not supported",
+                    ))
+                    .await;
+            }
+            Err(_) => {
+                return conn
+                    .send(ErrorResponse::fatal(
+                        SqlState::INVALID_PARAMETER_VALUE,
+                        format!("invalid value for parameter \"replication\": \"{value}\""),
+                    ))
+                    .await;
+            }
+        },
+    };
     let options = parse_options(params.get("options").unwrap_or(&String::new()));
 
     // If oidc_auth_enabled exists as an option, return its value and filter it from
@@ -285,6 +317,64 @@ where
                 }
             }
         }
+        Authenticator::Cert(cert) => {
+            let peer_cert = peer_certificate(conn.inner());
+            match cert.authenticate(peer_cert.as_deref(), Some(&user)).await {
+                Ok((user, authenticated)) => {
+                    let session = adapter_client.new_session(
+                        SessionConfig {
+                            conn_id: conn.conn_id().clone(),
+                            uuid: conn_uuid,
+                            user,
+                            client_ip: conn.peer_addr().clone(),
+                            external_metadata_rx: None,
+                            helm_chart_version,
+                            authenticator_kind,
+                        },
+                        authenticated,
+                    );
+                    // Certificates are only checked during the handshake, so
+                    // the auth session lasts indefinitely.
+                    (session, pending().right_future())
+                }
+                Err(err) => {
+                    warn!(?err, "pgwire connection failed authentication");
+                    return conn.send(err.into_response()).await;
+                }
+            }
+        }
+        Authenticator::Ldap(ldap) => {
+            let password = match request_cleartext_password(conn).await {
+                Ok(password) => Password(password),
+                Err(PasswordRequestError::IoError(e)) => return Err(e),
+                Err(PasswordRequestError::InvalidPasswordError(e)) => {
+                    return conn.send(e).await;
+                }
+            };
+            match ldap.authenticate(&user, &password).await {
+                Ok(authenticated) => {
+                    let session = adapter_client.new_session(
+                        SessionConfig {
+                            conn_id: conn.conn_id().clone(),
+                            uuid: conn_uuid,
+                            user,
+                            client_ip: conn.peer_addr().clone(),
+                            external_metadata_rx: None,
+                            helm_chart_version,
+                            authenticator_kind,
+                        },
+                        authenticated,
+                    );
+                    // The directory is only consulted at login, so the auth
+                    // session lasts indefinitely.
+                    (session, pending().right_future())
+                }
+                Err(err) => {
+                    warn!(?err, "pgwire connection failed authentication");
+                    return conn.send(err.into_response()).await;
+                }
+            }
+        }
         Authenticator::Password(adapter_client) => {
             let session = match authenticate_with_password(
                 conn,
@@ -553,6 +643,14 @@ where
         }
     };
 
+    // Asynchronous notifications for `LISTEN` are delivered on this channel.
+    let (notifications_tx, notifications_rx) = mpsc::channel(MAX_QUEUED_NOTIFICATIONS);
+    session.set_notification_transmitter(notifications_tx);
+
+    if replication {
+        session.set_replication_connection();
+    }
+
     // Register session with adapter.
     let mut adapter_client = match adapter_client.startup(session).await {
         Ok(adapter_client) => adapter_client,
@@ -585,6 +683,7 @@ where
         adapter_client,
         txn_needs_commit: false,
         tokio_metrics_intervals,
+        notifications: notifications_rx,
     };
 
     select! {
@@ -820,6 +919,7 @@ where
     adapter_client: mz_adapter::SessionClient,
     txn_needs_commit: bool,
     tokio_metrics_intervals: I,
+    notifications: mpsc::Receiver<Notification>,
 }
 
 enum SendRowsEndedReason {
@@ -836,6 +936,10 @@ enum SendRowsEndedReason {
 const ABORTED_TXN_MSG: &str =
     "current transaction is aborted, commands ignored until end of transaction block";
 
+/// The maximum number of asynchronous notifications queued for a connection, e.g. while it is
+/// inside a transaction block. Further notifications are dropped until the queue drains.
+const MAX_QUEUED_NOTIFICATIONS: usize = 1024;
+
 impl<'a, A, I> StateMachine<'a, A, I>
 where
     A: AsyncRead + AsyncWrite + AsyncReady + Send + Sync + Unpin + 'a,
@@ -869,6 +973,11 @@ where
             .next()
             .expect("infinite iterator");
 
+        let deliver_notifications = matches!(
+            self.adapter_client.session().transaction(),
+            TransactionStatus::Default
+        );
+
         // Handle timeouts first so we don't execute any statements when there's a pending timeout.
         let message = select! {
             biased;
@@ -892,6 +1001,19 @@ where
                 let _ = self.conn.recv().await?;
                 return error_state;
             },
+            // Like PostgreSQL, only deliver notifications outside of transaction blocks.
+            // `recv()` is cancel-safe as per it's docs.
+            Some(notification) = self.notifications.recv(), if deliver_notifications => {
+                self.send(BackendMessage::NotificationResponse {
+                    // Notifications are raised by the system rather than by another backend.
+                    pid: 0,
+                    channel: notification.channel,
+                    payload: notification.payload,
+                })
+                .await?;
+                self.conn.flush().await?;
+                return Ok(State::Ready);
+            },
             // `recv()` is cancel-safe as per it's docs.
             message = self.conn.recv() => message?,
         };
@@ -2240,6 +2362,11 @@ where
                 self.copy_from(target_id, target_name, columns, params, row_desc, ctx_extra)
                     .await
             }
+            ExecuteResponse::StartedReplication {
+                relations,
+                start,
+                rx,
+            } => self.stream_replication(relations, start, rx).await,
             ExecuteResponse::TransactionCommitted { params }
             | ExecuteResponse::TransactionRolledBack { params } => {
                 let notify_set: mz_ore::collections::HashSet<String> = self
@@ -2280,9 +2407,13 @@ where
             | ExecuteResponse::CreatedSource { .. }
             | ExecuteResponse::CreatedTable { .. }
             | ExecuteResponse::CreatedType
+            | ExecuteResponse::CreatedFunction
+            | ExecuteResponse::CreatedSequence
+            | ExecuteResponse::CreatedPolicy
             | ExecuteResponse::CreatedView { .. }
             | ExecuteResponse::CreatedViews { .. }
             | ExecuteResponse::CreatedNetworkPolicy
+            | ExecuteResponse::CreatedPublication
             | ExecuteResponse::Comment
             | ExecuteResponse::Deallocate { .. }
             | ExecuteResponse::Deleted(..)
@@ -2290,16 +2421,21 @@ where
             | ExecuteResponse::DiscardedTemp
             | ExecuteResponse::DroppedObject(_)
             | ExecuteResponse::DroppedOwned
+            | ExecuteResponse::DroppedPublication
+            | ExecuteResponse::DroppedReplicationSlot
             | ExecuteResponse::GrantedPrivilege
             | ExecuteResponse::GrantedRole
             | ExecuteResponse::Inserted(..)
             | ExecuteResponse::Copied(..)
             | ExecuteResponse::Prepare
+            | ExecuteResponse::Listened
             | ExecuteResponse::Raised
             | ExecuteResponse::ReassignOwned
             | ExecuteResponse::RevokedPrivilege
             | ExecuteResponse::RevokedRole
             | ExecuteResponse::StartedTransaction { .. }
+            | ExecuteResponse::Truncated
+            | ExecuteResponse::Unlistened
             | ExecuteResponse::Updated(..)
             | ExecuteResponse::ValidatedConnection => {
                 command_complete!()
@@ -2593,8 +2729,8 @@ where
                 CopyFormatParams::Csv(CopyCsvFormatParams::default()),
                 Format::Text,
             ),
-            CopyFormat::Parquet => {
-                let text = "Parquet format is not supported".to_string();
+            CopyFormat::Parquet | CopyFormat::Avro | CopyFormat::Orc | CopyFormat::Json => {
+                let text = format!("{format:?} format is not supported");
                 return self
                     .send_error_and_get_state(ErrorResponse::error(
                         SqlState::INTERNAL_ERROR,
@@ -2695,6 +2831,123 @@ where
         ))
     }
 
+    /// Handles the copy-both mode of the postgres protocol that streams the
+    /// changes of a logical replication stream to the client, until the
+    /// client ends the stream.
+    #[instrument(level = "debug")]
+    async fn stream_replication(
+        &mut self,
+        relations: Vec<PublishedRelation>,
+        start: Timestamp,
+        mut rx: mpsc::UnboundedReceiver<ReplicationEvent>,
+    ) -> Result<State, io::Error> {
+        self.send(BackendMessage::CopyBothResponse {
+            overall_format: Format::Text,
+            column_formats: vec![],
+        })
+        .await?;
+        self.conn.flush().await?;
+
+        let mut encoder = TransactionEncoder::new(relations);
+        // All changes up to `sent` have been sent, and the client confirmed
+        // all changes up to `flushed`.
+        let mut sent = start;
+        let mut flushed = start;
+        loop {
+            select! {
+                event = rx.recv() => match event {
+                    Some(ReplicationEvent::Transaction { time, updates }) => {
+                        for data in encoder.encode(time, &updates, SYSTEM_TIME()) {
+                            self.send(BackendMessage::CopyData(data)).await?;
+                        }
+                        sent = time;
+                    }
+                    Some(ReplicationEvent::Progress(frontier)) => {
+                        sent = frontier.step_back().unwrap_or(sent);
+                        let keepalive = replication::encode_keepalive(sent, SYSTEM_TIME(), false);
+                        self.send(BackendMessage::CopyData(keepalive)).await?;
+                    }
+                    Some(ReplicationEvent::Error(e)) => {
+                        return self
+                            .send_error_and_get_state(e.into_response(Severity::Error))
+                            .await;
+                    }
+                    // The published relations can no longer change.
+                    None => {
+                        self.send(BackendMessage::CopyDone).await?;
+                        self.send(BackendMessage::CommandComplete {
+                            tag: "START_REPLICATION".into(),
+                        })
+                        .await?;
+                        return Ok(State::Ready);
+                    }
+                },
+                message = self.conn.recv() => match message? {
+                    Some(FrontendMessage::CopyData(data)) => {
+                        match replication::decode_standby_message(&data) {
+                            Ok(StandbyMessage::StatusUpdate { flush, reply_requested }) => {
+                                let flush = Timestamp::from(flush);
+                                if flush > flushed {
+                                    if let Err(e) = self
+                                        .adapter_client
+                                        .advance_replication_slot(flush)
+                                        .await
+                                    {
+                                        return self
+                                            .send_error_and_get_state(e.into_response(Severity::Error))
+                                            .await;
+                                    }
+                                    flushed = flush;
+                                }
+                                if reply_requested {
+                                    let keepalive =
+                                        replication::encode_keepalive(sent, SYSTEM_TIME(), false);
+                                    self.send(BackendMessage::CopyData(keepalive)).await?;
+                                }
+                            }
+                            Ok(StandbyMessage::HotStandbyFeedback) => {}
+                            Err(e) => {
+                                return self
+                                    .send_error_and_get_state(ErrorResponse::error(
+                                        SqlState::PROTOCOL_VIOLATION,
+                                        e.to_string(),
+                                    ))
+                                    .await;
+                            }
+                        }
+                    }
+                    Some(FrontendMessage::CopyDone) => {
+                        self.send(BackendMessage::CopyDone).await?;
+                        self.send(BackendMessage::CommandComplete {
+                            tag: "START_REPLICATION".into(),
+                        })
+                        .await?;
+                        return Ok(State::Ready);
+                    }
+                    Some(FrontendMessage::CopyFail(err)) => {
+                        return self
+                            .send_error_and_get_state(ErrorResponse::error(
+                                SqlState::QUERY_CANCELED,
+                                format!("replication failed: {}", err),
+                            ))
+                            .await;
+                    }
+                    Some(FrontendMessage::Flush) | Some(FrontendMessage::Sync) => {}
+                    Some(_) => {
+                        return self
+                            .send_error_and_get_state(ErrorResponse::error(
+                                SqlState::PROTOCOL_VIOLATION,
+                                "unexpected message type during replication",
+                            ))
+                            .await;
+                    }
+                    None => return Ok(State::Done),
+                },
+            }
+            self.conn.flush().await?;
+        }
+    }
+
     /// Handles the copy-in mode of the postgres protocol from transferring
     /// data to the server.
     #[instrument(level = "debug")]
@@ -3195,10 +3448,31 @@ fn get_authenticator(
                 Authenticator::Password(adapter_client)
             }
         }
+        listeners::AuthenticatorKind::Cert => {
+            Authenticator::Cert(CertAuthenticator::new(adapter_client))
+        }
+        listeners::AuthenticatorKind::Ldap => {
+            Authenticator::Ldap(LdapAuthenticator::new(adapter_client))
+        }
         listeners::AuthenticatorKind::None => Authenticator::None,
     }
 }
 
+/// Returns the verified certificate that the client presented during the TLS
+/// handshake, if any.
+fn peer_certificate<A>(conn: &Conn<A>) -> Option<X509> {
+    match conn {
+        Conn::Unencrypted(_) => None,
+        Conn::Ssl(stream) => {
+            let ssl = stream.ssl();
+            if ssl.verify_result() != X509VerifyResult::OK {
+                return None;
+            }
+            ssl.peer_certificate()
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone)]
 enum ExecuteCount {
     All,
@@ -0,0 +1,386 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Encoding/decoding of the messages exchanged during logical replication.
+//!
+//! Once a replication connection starts streaming, the server sends
+//! `CopyData` messages that wrap either a keepalive or an `XLogData` frame,
+//! and the client answers with `CopyData` messages that wrap standby status
+//! updates. The payload of each `XLogData` frame is a message of the
+//! `pgoutput` logical decoding output plugin. See "[Streaming Replication
+//! Protocol][1]" and "[Logical Replication Message Formats][2]" in the
+//! PostgreSQL reference for the specification.
+//!
+//! [1]: https://www.postgresql.org/docs/current/protocol-replication.html
+//! [2]: https://www.postgresql.org/docs/current/protocol-logicalrep-message-formats.html
+
+use bytes::{Buf, BufMut, BytesMut};
+use mz_adapter::session::PublishedRelation;
+use mz_ore::now::EpochMillis;
+use mz_pgwire_common::Pgbuf;
+use mz_repr::{Diff, Row, Timestamp};
+use tokio::io;
+
+/// The number of milliseconds between the Unix epoch and the PostgreSQL epoch,
+/// 2000-01-01.
+const POSTGRES_EPOCH_MILLIS: i64 = 946_684_800_000;
+
+/// A message sent by the client while streaming.
+#[derive(Debug)]
+pub enum StandbyMessage {
+    /// The client reports its replication progress.
+    StatusUpdate {
+        /// Changes up to this log sequence number are durably stored by the
+        /// client.
+        flush: u64,
+        /// Whether the client asks for an immediate keepalive.
+        reply_requested: bool,
+    },
+    /// Feedback about a physical standby, which is meaningless for logical
+    /// replication.
+    HotStandbyFeedback,
+}
+
+/// Decodes the payload of a `CopyData` message sent by the client while
+/// streaming.
+pub fn decode_standby_message(mut data: &[u8]) -> Result<StandbyMessage, io::Error> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+    if !data.has_remaining() {
+        return Err(invalid("empty standby message"));
+    }
+    match data.get_u8() {
+        b'r' => {
+            // Written, flushed and applied positions, followed by the
+            // client's clock and the reply flag.
+            if data.remaining() < 8 * 4 + 1 {
+                return Err(invalid("invalid standby status update message"));
+            }
+            let _written = data.get_u64();
+            let flush = data.get_u64();
+            let _applied = data.get_u64();
+            let _clock = data.get_i64();
+            let reply_requested = data.get_u8() == 1;
+            Ok(StandbyMessage::StatusUpdate {
+                flush,
+                reply_requested,
+            })
+        }
+        b'h' => Ok(StandbyMessage::HotStandbyFeedback),
+        _ => Err(invalid("unexpected standby message type")),
+    }
+}
+
+/// Encodes a primary keepalive message, reporting that all changes up to
+/// `wal_end` have been sent.
+pub fn encode_keepalive(wal_end: Timestamp, now: EpochMillis, reply_requested: bool) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(18);
+    buf.put_u8(b'k');
+    buf.put_u64(u64::from(wal_end));
+    buf.put_i64(postgres_time(now));
+    buf.put_u8(u8::from(reply_requested));
+    buf
+}
+
+/// Encodes the changes of a replication stream as `pgoutput` messages.
+#[derive(Debug)]
+pub struct TransactionEncoder {
+    /// The relations of the stream.
+    relations: Vec<PublishedRelation>,
+    /// Whether the client has been sent the `Relation` message of each of
+    /// `relations`.
+    announced: Vec<bool>,
+    /// The ID of the next transaction.
+    next_xid: u32,
+}
+
+impl TransactionEncoder {
+    pub fn new(relations: Vec<PublishedRelation>) -> TransactionEncoder {
+        let announced = vec![false; relations.len()];
+        TransactionEncoder {
+            relations,
+            announced,
+            next_xid: 1,
+        }
+    }
+
+    /// Encodes the changes that happened at `time` as one transaction that
+    /// commits at log sequence number `time`. Returns the payloads of the
+    /// `CopyData` messages to send.
+    pub fn encode(
+        &mut self,
+        time: Timestamp,
+        updates: &[(usize, Row, Diff)],
+        now: EpochMillis,
+    ) -> Vec<Vec<u8>> {
+        let lsn = u64::from(time);
+        let commit_time = postgres_time(now);
+        let xid = self.next_xid;
+        self.next_xid = self.next_xid.checked_add(1).unwrap_or(1);
+
+        let mut messages = Vec::new();
+        let mut begin = BytesMut::new();
+        begin.put_u8(b'B');
+        begin.put_u64(lsn);
+        begin.put_i64(commit_time);
+        begin.put_u32(xid);
+        messages.push(begin);
+
+        for (index, row, diff) in updates {
+            if !self.announced[*index] {
+                messages.push(self.encode_relation(*index));
+                self.announced[*index] = true;
+            }
+            let relation = &self.relations[*index];
+            let mut change = BytesMut::new();
+            if diff.is_positive() {
+                change.put_u8(b'I');
+                change.put_u32(relation.oid);
+                change.put_u8(b'N');
+            } else {
+                // Relations are published with a full replica identity, so
+                // deletes carry the entire old row.
+                change.put_u8(b'D');
+                change.put_u32(relation.oid);
+                change.put_u8(b'O');
+            }
+            encode_tuple(relation, row, &mut change);
+            for _ in 0..diff.unsigned_abs() {
+                messages.push(change.clone());
+            }
+        }
+
+        let mut commit = BytesMut::new();
+        commit.put_u8(b'C');
+        commit.put_u8(0);
+        commit.put_u64(lsn);
+        commit.put_u64(lsn);
+        commit.put_i64(commit_time);
+        messages.push(commit);
+
+        messages
+            .into_iter()
+            .map(|message| encode_xlog_data(time, time, now, &message))
+            .collect()
+    }
+
+    /// Encodes the `Relation` message describing the relation at `index`.
+    fn encode_relation(&self, index: usize) -> BytesMut {
+        let relation = &self.relations[index];
+        let mut buf = BytesMut::new();
+        buf.put_u8(b'R');
+        buf.put_u32(relation.oid);
+        buf.put_string(&relation.schema);
+        buf.put_string(&relation.name);
+        // Materialize relations have no primary keys to identify rows by, so
+        // their replica identity is always full.
+        buf.put_u8(b'f');
+        let columns = u16::try_from(relation.desc.arity()).expect("relations have < 2^16 columns");
+        buf.put_u16(columns);
+        for (name, typ) in relation.desc.iter() {
+            let ty = mz_pgrepr::Type::from(&typ.scalar_type);
+            // With a full replica identity, every column is part of the key.
+            buf.put_u8(1);
+            buf.put_string(name.as_str());
+            buf.put_u32(ty.oid());
+            buf.put_i32(ty.typmod());
+        }
+        buf
+    }
+}
+
+/// Encodes `row` as the `TupleData` of a change to `relation`, using the text
+/// format.
+fn encode_tuple(relation: &PublishedRelation, row: &Row, buf: &mut BytesMut) {
+    let typ = relation.desc.typ();
+    let columns = u16::try_from(typ.column_types.len()).expect("relations have < 2^16 columns");
+    buf.put_u16(columns);
+    for (datum, column_type) in row.iter().zip(&typ.column_types) {
+        match mz_pgrepr::Value::from_datum(datum, &column_type.scalar_type) {
+            None => buf.put_u8(b'n'),
+            Some(value) => {
+                let mut text = BytesMut::new();
+                value.encode_text(&mut text);
+                buf.put_u8(b't');
+                buf.put_u32(u32::try_from(text.len()).expect("values are < 4GiB"));
+                buf.put_slice(&text);
+            }
+        }
+    }
+}
+
+/// Wraps a `pgoutput` message in an `XLogData` frame.
+fn encode_xlog_data(
+    wal_start: Timestamp,
+    wal_end: Timestamp,
+    now: EpochMillis,
+    message: &[u8],
+) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(25 + message.len());
+    buf.put_u8(b'w');
+    buf.put_u64(u64::from(wal_start));
+    buf.put_u64(u64::from(wal_end));
+    buf.put_i64(postgres_time(now));
+    buf.put_slice(message);
+    buf
+}
+
+/// Converts `now` to the number of microseconds since the PostgreSQL epoch.
+fn postgres_time(now: EpochMillis) -> i64 {
+    let millis = i64::try_from(now).unwrap_or(i64::MAX);
+    millis
+        .saturating_sub(POSTGRES_EPOCH_MILLIS)
+        .saturating_mul(1000)
+}
+
+#[cfg(test)]
+mod tests {
+    use mz_repr::{Datum, RelationDesc, SqlScalarType};
+
+    use super::*;
+
+    /// The `now` that encodes as one second after the PostgreSQL epoch.
+    const NOW: EpochMillis = 946_684_801_000;
+
+    /// Strips the `XLogData` frame from `message`, checking its header.
+    fn xlog_payload(message: &[u8], lsn: u64) -> &[u8] {
+        let mut buf = message;
+        assert_eq!(buf.get_u8(), b'w');
+        assert_eq!(buf.get_u64(), lsn);
+        assert_eq!(buf.get_u64(), lsn);
+        assert_eq!(buf.get_i64(), 1_000_000);
+        buf
+    }
+
+    fn relation() -> PublishedRelation {
+        PublishedRelation {
+            oid: 20_001,
+            schema: "public".into(),
+            name: "t".into(),
+            desc: RelationDesc::builder()
+                .with_column("a", SqlScalarType::Int32.nullable(false))
+                .with_column("b", SqlScalarType::String.nullable(true))
+                .finish(),
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_encode_keepalive() {
+        let mut buf = &encode_keepalive(Timestamp::new(42), NOW, true)[..];
+        assert_eq!(buf.get_u8(), b'k');
+        assert_eq!(buf.get_u64(), 42);
+        assert_eq!(buf.get_i64(), 1_000_000);
+        assert_eq!(buf.get_u8(), 1);
+        assert!(!buf.has_remaining());
+    }
+
+    #[mz_ore::test]
+    fn test_decode_standby_message() {
+        let mut buf = vec![b'r'];
+        buf.put_u64(3);
+        buf.put_u64(2);
+        buf.put_u64(1);
+        buf.put_i64(0);
+        buf.put_u8(1);
+        match decode_standby_message(&buf).unwrap() {
+            StandbyMessage::StatusUpdate {
+                flush,
+                reply_requested,
+            } => {
+                assert_eq!(flush, 2);
+                assert!(reply_requested);
+            }
+            other => panic!("unexpected message: {other:?}"),
+        }
+
+        assert!(matches!(
+            decode_standby_message(b"h").unwrap(),
+            StandbyMessage::HotStandbyFeedback
+        ));
+        assert!(decode_standby_message(b"").is_err());
+        assert!(decode_standby_message(b"x").is_err());
+        assert!(decode_standby_message(&buf[..buf.len() - 1]).is_err());
+    }
+
+    #[mz_ore::test]
+    fn test_encode_transaction() {
+        let mut encoder = TransactionEncoder::new(vec![relation()]);
+        let updates = [
+            (
+                0,
+                Row::pack_slice(&[Datum::Int32(1), Datum::String("x")]),
+                Diff::from(2),
+            ),
+            (
+                0,
+                Row::pack_slice(&[Datum::Int32(2), Datum::Null]),
+                Diff::MINUS_ONE,
+            ),
+        ];
+        let messages = encoder.encode(Timestamp::new(5), &updates, NOW);
+        let payloads: Vec<_> = messages.iter().map(|m| xlog_payload(m, 5)).collect();
+        assert_eq!(
+            payloads.len(),
+            6,
+            "begin, relation, 2 inserts, delete, commit"
+        );
+
+        let mut begin = payloads[0];
+        assert_eq!(begin.get_u8(), b'B');
+        assert_eq!(begin.get_u64(), 5);
+        assert_eq!(begin.get_i64(), 1_000_000);
+        assert_eq!(begin.get_u32(), 1);
+        assert!(!begin.has_remaining());
+
+        let mut expected_relation = vec![b'R'];
+        expected_relation.put_u32(20_001);
+        expected_relation.put_slice(b"public\0t\0f");
+        expected_relation.put_u16(2);
+        expected_relation.put_slice(b"\x01a\0");
+        expected_relation.put_u32(23);
+        expected_relation.put_i32(-1);
+        expected_relation.put_slice(b"\x01b\0");
+        expected_relation.put_u32(25);
+        expected_relation.put_i32(-1);
+        assert_eq!(payloads[1], &expected_relation[..]);
+
+        let mut expected_insert = vec![b'I'];
+        expected_insert.put_u32(20_001);
+        expected_insert.put_u8(b'N');
+        expected_insert.put_u16(2);
+        expected_insert.put_slice(b"t\0\0\0\x011t\0\0\0\x01x");
+        assert_eq!(payloads[2], &expected_insert[..]);
+        assert_eq!(payloads[3], &expected_insert[..]);
+
+        let mut expected_delete = vec![b'D'];
+        expected_delete.put_u32(20_001);
+        expected_delete.put_u8(b'O');
+        expected_delete.put_u16(2);
+        expected_delete.put_slice(b"t\0\0\0\x012n");
+        assert_eq!(payloads[4], &expected_delete[..]);
+
+        let mut expected_commit = vec![b'C', 0];
+        expected_commit.put_u64(5);
+        expected_commit.put_u64(5);
+        expected_commit.put_i64(1_000_000);
+        assert_eq!(payloads[5], &expected_commit[..]);
+
+        // The relation is only described once, and every transaction gets a
+        // new ID.
+        let messages = encoder.encode(Timestamp::new(7), &updates[1..], NOW);
+        let payloads: Vec<_> = messages.iter().map(|m| xlog_payload(m, 7)).collect();
+        assert_eq!(payloads.len(), 3, "begin, delete, commit");
+        let mut begin = payloads[0];
+        assert_eq!(begin.get_u8(), b'B');
+        assert_eq!(begin.get_u64(), 7);
+        assert_eq!(begin.get_i64(), 1_000_000);
+        assert_eq!(begin.get_u32(), 2);
+        assert_eq!(payloads[1][0], b'D');
+        assert_eq!(payloads[2][0], b'C');
+    }
+}
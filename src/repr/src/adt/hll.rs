@@ -0,0 +1,359 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! HyperLogLog sketches for approximate distinct counting.
+//!
+//! A sketch consists of `2^precision` registers. Each hash is routed to a
+//! register by its first `precision` bits, and the register records the
+//! maximum _rank_ of the hashes routed to it, where the rank is one plus the
+//! number of leading zeros in the remaining bits. The number of distinct hashes
+//! is then estimated from the harmonic mean of the registers, as described in
+//! Flajolet et al., "HyperLogLog: the analysis of a near-optimal cardinality
+//! estimation algorithm" (2007). The relative standard error of the estimate
+//! is about `1.04 / sqrt(2^precision)`.
+//!
+//! Sketches are merged by taking the register-wise maximum. Taking a maximum
+//! cannot be undone, so a sketch on its own cannot support retractions.
+//! Incrementally maintained sketches instead use a _counting_ variant: every
+//! hash is reduced to a [`HyperLogLog::singleton`] sketch, which holds exactly
+//! one `(register, rank)` pair, and it is the multiset of singletons that gets
+//! updated. Retracting a value decrements the count of its singleton, and the
+//! merged sketch is recomputed from the singletons whose counts are positive.
+//! There are at most `2^precision * (65 - precision)` distinct singletons, no
+//! matter how many distinct values are observed.
+
+use std::error::Error;
+use std::fmt;
+
+use mz_ore::cast::{CastFrom, CastLossy};
+
+/// The precision of sketches created by SQL functions, which yields 4096
+/// registers and a relative standard error of about 1.6%.
+pub const DEFAULT_PRECISION: u8 = 12;
+
+/// The minimum supported precision.
+pub const MIN_PRECISION: u8 = 4;
+
+/// The maximum supported precision, which keeps register indexes within a
+/// `u16`.
+pub const MAX_PRECISION: u8 = 16;
+
+/// The version of the serialization format produced by
+/// [`HyperLogLog::encode`].
+const FORMAT_VERSION: u8 = 1;
+
+/// The size of the `(version, precision, representation)` header.
+const HEADER_LEN: usize = 3;
+
+/// A representation that lists the non-zero registers as `(index, rank)`
+/// pairs, each taking three bytes.
+const SPARSE: u8 = 0;
+
+/// A representation that lists every register as a one-byte rank.
+const DENSE: u8 = 1;
+
+/// A HyperLogLog sketch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// Creates an empty sketch with `2^precision` registers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `precision` is not between [`MIN_PRECISION`] and
+    /// [`MAX_PRECISION`].
+    pub fn new(precision: u8) -> HyperLogLog {
+        assert!(
+            (MIN_PRECISION..=MAX_PRECISION).contains(&precision),
+            "invalid HyperLogLog precision {precision}"
+        );
+        HyperLogLog {
+            precision,
+            registers: vec![0; 1 << precision],
+        }
+    }
+
+    /// Creates a sketch that has observed only `hash`.
+    pub fn singleton(precision: u8, hash: u64) -> HyperLogLog {
+        let mut hll = HyperLogLog::new(precision);
+        hll.insert_hash(hash);
+        hll
+    }
+
+    /// Returns the number of bits used to select a register.
+    pub fn precision(&self) -> u8 {
+        self.precision
+    }
+
+    /// Records an observation of `hash`, which must be uniformly distributed
+    /// over all 64-bit values.
+    pub fn insert_hash(&mut self, hash: u64) {
+        let precision = u32::from(self.precision);
+        let index = usize::cast_from(hash >> (64 - precision));
+        // The rank is the position of the first one bit after the index bits,
+        // or one past the last position if those bits are all zero.
+        let rank = (hash << precision).leading_zeros().min(64 - precision) + 1;
+        let rank = u8::try_from(rank).expect("rank is at most 61");
+        let register = &mut self.registers[index];
+        *register = (*register).max(rank);
+    }
+
+    /// Merges `other` into `self`, so that `self` reflects the union of the
+    /// hashes observed by both sketches.
+    pub fn merge(&mut self, other: &HyperLogLog) -> Result<(), HllError> {
+        if self.precision != other.precision {
+            return Err(HllError::PrecisionMismatch(self.precision, other.precision));
+        }
+        for (register, other) in self.registers.iter_mut().zip(&other.registers) {
+            *register = (*register).max(*other);
+        }
+        Ok(())
+    }
+
+    /// Estimates the number of distinct hashes observed by the sketch.
+    pub fn estimate(&self) -> i64 {
+        let m = f64::cast_lossy(self.registers.len());
+        let alpha = match self.precision {
+            4 => 0.673,
+            5 => 0.697,
+            6 => 0.709,
+            _ => 0.7213 / (1.0 + 1.079 / m),
+        };
+        let mut sum = 0.0;
+        let mut zeros = 0;
+        for register in &self.registers {
+            sum += 2f64.powi(-i32::from(*register));
+            if *register == 0 {
+                zeros += 1;
+            }
+        }
+        let raw = alpha * m * m / sum;
+        // The raw estimate is biased for small cardinalities, where linear
+        // counting over the empty registers is more accurate. With 64-bit
+        // hashes, no correction is needed for large cardinalities.
+        let estimate = if raw <= 2.5 * m && zeros > 0 {
+            m * (m / f64::cast_lossy(zeros)).ln()
+        } else {
+            raw
+        };
+        i64::cast_lossy(estimate.round())
+    }
+
+    /// Serializes the sketch to bytes.
+    ///
+    /// Sketches that have few non-zero registers are stored sparsely. The
+    /// encoding is canonical: equal sketches always encode to equal bytes.
+    pub fn encode(&self) -> Vec<u8> {
+        let non_zero = self.registers.iter().filter(|r| **r != 0).count();
+        let mut buf = vec![FORMAT_VERSION, self.precision];
+        if 3 * non_zero < self.registers.len() {
+            buf.reserve(1 + 3 * non_zero);
+            buf.push(SPARSE);
+            for (index, rank) in self.registers.iter().enumerate() {
+                if *rank != 0 {
+                    let index = u16::try_from(index).expect("precision is at most 16");
+                    buf.extend(index.to_be_bytes());
+                    buf.push(*rank);
+                }
+            }
+        } else {
+            buf.reserve(1 + self.registers.len());
+            buf.push(DENSE);
+            buf.extend(&self.registers);
+        }
+        buf
+    }
+
+    /// Deserializes a sketch produced by [`HyperLogLog::encode`].
+    pub fn decode(buf: &[u8]) -> Result<HyperLogLog, HllError> {
+        let (header, body) = buf
+            .split_first_chunk::<HEADER_LEN>()
+            .ok_or(HllError::Truncated)?;
+        let [version, precision, representation] = *header;
+        if version != FORMAT_VERSION {
+            return Err(HllError::UnsupportedVersion(version));
+        }
+        if !(MIN_PRECISION..=MAX_PRECISION).contains(&precision) {
+            return Err(HllError::InvalidPrecision(precision));
+        }
+        let mut hll = HyperLogLog::new(precision);
+        let max_rank = 65 - precision;
+        match representation {
+            SPARSE => {
+                let (entries, rest) = body.as_chunks::<3>();
+                if !rest.is_empty() {
+                    return Err(HllError::Truncated);
+                }
+                let mut prev = None;
+                for [hi, lo, rank] in entries {
+                    let index = usize::from(u16::from_be_bytes([*hi, *lo]));
+                    if index >= hll.registers.len() || prev.is_some_and(|prev| index <= prev) {
+                        return Err(HllError::InvalidRegister(index));
+                    }
+                    if *rank == 0 || *rank > max_rank {
+                        return Err(HllError::InvalidRank(*rank));
+                    }
+                    hll.registers[index] = *rank;
+                    prev = Some(index);
+                }
+            }
+            DENSE => {
+                if body.len() != hll.registers.len() {
+                    return Err(HllError::Truncated);
+                }
+                if let Some(rank) = body.iter().find(|rank| **rank > max_rank) {
+                    return Err(HllError::InvalidRank(*rank));
+                }
+                hll.registers.copy_from_slice(body);
+            }
+            _ => return Err(HllError::InvalidRepresentation(representation)),
+        }
+        Ok(hll)
+    }
+}
+
+/// An error that can occur when decoding or merging [`HyperLogLog`] sketches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HllError {
+    /// The serialized sketch ended prematurely.
+    Truncated,
+    /// The serialized sketch has an unknown format version.
+    UnsupportedVersion(u8),
+    /// The serialized sketch has an out-of-range precision.
+    InvalidPrecision(u8),
+    /// The serialized sketch has an unknown representation.
+    InvalidRepresentation(u8),
+    /// The serialized sketch names a register that is out of range or out of
+    /// order.
+    InvalidRegister(usize),
+    /// The serialized sketch has a register with an impossible rank.
+    InvalidRank(u8),
+    /// The sketches being merged have different precisions.
+    PrecisionMismatch(u8, u8),
+}
+
+impl fmt::Display for HllError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HllError::Truncated => f.write_str("unexpected end of sketch"),
+            HllError::UnsupportedVersion(v) => write!(f, "unsupported sketch version {v}"),
+            HllError::InvalidPrecision(p) => write!(f, "invalid precision {p}"),
+            HllError::InvalidRepresentation(r) => write!(f, "invalid representation {r}"),
+            HllError::InvalidRegister(i) => write!(f, "invalid register {i}"),
+            HllError::InvalidRank(r) => write!(f, "invalid register value {r}"),
+            HllError::PrecisionMismatch(l, r) => {
+                write!(f, "cannot merge sketches with precisions {l} and {r}")
+            }
+        }
+    }
+}
+
+impl Error for HllError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(i: u64) -> u64 {
+        // A cheap bijective mixer (from splitmix64), to make test inputs
+        // uniformly distributed.
+        let mut z = i.wrapping_add(0x9e3779b97f4a7c15);
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    #[mz_ore::test]
+    fn test_estimate() {
+        for n in [0, 1, 10, 100, 1_000, 10_000, 100_000] {
+            let mut hll = HyperLogLog::new(DEFAULT_PRECISION);
+            for i in 0..n {
+                hll.insert_hash(hash(i));
+                // Duplicates do not affect the estimate.
+                hll.insert_hash(hash(i));
+            }
+            let estimate = hll.estimate();
+            let error = (estimate - i64::try_from(n).unwrap()).abs();
+            // Allow for five standard errors.
+            assert!(
+                error <= 1 + i64::try_from(n).unwrap() * 8 / 100,
+                "estimate {estimate} for {n} distinct values"
+            );
+        }
+    }
+
+    #[mz_ore::test]
+    fn test_merge() {
+        let mut all = HyperLogLog::new(DEFAULT_PRECISION);
+        let mut merged = HyperLogLog::new(DEFAULT_PRECISION);
+        for i in 0..5_000 {
+            all.insert_hash(hash(i));
+            merged
+                .merge(&HyperLogLog::singleton(DEFAULT_PRECISION, hash(i)))
+                .unwrap();
+        }
+        assert_eq!(all, merged);
+        assert_eq!(
+            merged.merge(&HyperLogLog::new(DEFAULT_PRECISION + 1)),
+            Err(HllError::PrecisionMismatch(
+                DEFAULT_PRECISION,
+                DEFAULT_PRECISION + 1
+            ))
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_encode_roundtrip() {
+        let mut hll = HyperLogLog::new(DEFAULT_PRECISION);
+        for n in [0, 1, 100, 10_000] {
+            for i in 0..n {
+                hll.insert_hash(hash(i));
+            }
+            let buf = hll.encode();
+            assert_eq!(HyperLogLog::decode(&buf).unwrap(), hll);
+        }
+        // Singletons are small.
+        assert_eq!(
+            HyperLogLog::singleton(DEFAULT_PRECISION, 42).encode().len(),
+            6
+        );
+    }
+
+    #[mz_ore::test]
+    fn test_decode_invalid() {
+        let valid = HyperLogLog::singleton(DEFAULT_PRECISION, 42).encode();
+        assert_eq!(HyperLogLog::decode(&[]), Err(HllError::Truncated));
+        assert_eq!(
+            HyperLogLog::decode(&valid[..valid.len() - 1]),
+            Err(HllError::Truncated)
+        );
+        let mut bad_version = valid.clone();
+        bad_version[0] = 2;
+        assert_eq!(
+            HyperLogLog::decode(&bad_version),
+            Err(HllError::UnsupportedVersion(2))
+        );
+        let mut bad_rank = valid.clone();
+        bad_rank[5] = 64;
+        assert_eq!(
+            HyperLogLog::decode(&bad_rank),
+            Err(HllError::InvalidRank(64))
+        );
+        let mut bad_register = valid;
+        bad_register.extend([0, 0, 1]);
+        assert!(matches!(
+            HyperLogLog::decode(&bad_register),
+            Err(HllError::InvalidRegister(_))
+        ));
+    }
+}
@@ -1596,19 +1596,25 @@ impl RelationVersionSelector {
     }
 }
 
-/// A wrapper around [`RelationDesc`] that provides an interface for adding
-/// columns and generating new versions.
+/// A wrapper around [`RelationDesc`] that provides an interface for adding,
+/// dropping, and renaming columns and generating new versions.
 ///
 /// TODO(parkmycar): Using an immutable data structure for RelationDesc would
 /// be great.
 #[derive(Debug, Clone, Serialize)]
 pub struct VersionedRelationDesc {
     inner: RelationDesc,
+    /// Columns that were renamed, keyed by the version that renamed them,
+    /// along with the name the column had before that version.
+    renames: BTreeMap<RelationVersion, (ColumnIndex, ColumnName)>,
 }
 
 impl VersionedRelationDesc {
     pub fn new(inner: RelationDesc) -> Self {
-        VersionedRelationDesc { inner }
+        VersionedRelationDesc {
+            inner,
+            renames: BTreeMap::new(),
+        }
     }
 
     /// Adds a new column to this [`RelationDesc`], creating a new version of the [`RelationDesc`].
@@ -1695,9 +1701,49 @@ impl VersionedRelationDesc {
         new_version
     }
 
+    /// Renames the column `name` to `new_name`, creating a new version of the
+    /// [`RelationDesc`]. Older versions continue to report the previous name.
+    ///
+    /// # Panics
+    ///
+    /// * Panics if a column with `name` does not exist.
+    /// * Panics if a column with `new_name` already exists that hasn't been dropped.
+    #[must_use]
+    pub fn rename_column<N, M>(&mut self, name: N, new_name: M) -> RelationVersion
+    where
+        N: Into<ColumnName>,
+        M: Into<ColumnName>,
+    {
+        let name = name.into();
+        let new_name = new_name.into();
+        let new_version = self.latest_version().bump();
+
+        let existing = self
+            .inner
+            .metadata
+            .iter()
+            .find(|(_, meta)| meta.name == new_name && meta.dropped.is_none());
+        if let Some(existing) = existing {
+            panic!("column named '{new_name}' already exists! {existing:?}");
+        }
+
+        let (col_idx, col) = self
+            .inner
+            .metadata
+            .iter_mut()
+            .find(|(_, meta)| meta.name == name && meta.dropped.is_none())
+            .expect("column to exist");
+        let prev_name = std::mem::replace(&mut col.name, new_name);
+        let prev = self.renames.insert(new_version, (*col_idx, prev_name));
+        assert_none!(prev, "rename version overlap!");
+
+        self.validate();
+        new_version
+    }
+
     /// Returns the [`RelationDesc`] at the latest version.
     pub fn latest(&self) -> RelationDesc {
-        self.inner.clone()
+        self.at_version(RelationVersionSelector::Latest)
     }
 
     /// Returns this [`RelationDesc`] at the specified version.
@@ -1728,8 +1774,16 @@ impl VersionedRelationDesc {
         // and 2. If we drop column "b" then we'll have "a" and "c" with column
         // indexes 0 and 2, but their indices in SqlRelationType will be 0 and 1.
         for (col_idx, meta) in valid_columns {
+            // If the column was renamed after the requested version, report the
+            // name it had before the earliest such rename.
+            let name = self
+                .renames
+                .iter()
+                .find(|(v, (idx, _))| **v > up_to_version && idx == col_idx)
+                .map(|(_, (_, prev_name))| prev_name)
+                .unwrap_or(&meta.name);
             let new_meta = ColumnMetadata {
-                name: meta.name.clone(),
+                name: name.clone(),
                 typ_idx: column_types.len(),
                 added: meta.added.clone(),
                 dropped: meta.dropped.clone(),
@@ -1774,6 +1828,7 @@ impl VersionedRelationDesc {
             .values()
             // N.B. Dropped is always greater than added.
             .map(|meta| meta.dropped.unwrap_or(meta.added))
+            .chain(self.renames.keys().copied())
             .max()
             // If there aren't any columns we're implicitly the root version.
             .unwrap_or_else(RelationVersion::root)
@@ -1785,7 +1840,10 @@ impl VersionedRelationDesc {
     ///
     /// Panics if a constraint is not satisfied.
     fn validate(&self) {
-        fn validate_inner(desc: &RelationDesc) -> Result<(), anyhow::Error> {
+        fn validate_inner(
+            desc: &RelationDesc,
+            renames: &BTreeMap<RelationVersion, (ColumnIndex, ColumnName)>,
+        ) -> Result<(), anyhow::Error> {
             if desc.typ.column_types.len() != desc.metadata.len() {
                 anyhow::bail!("mismatch between number of types and metadatas");
             }
@@ -1810,34 +1868,36 @@ impl VersionedRelationDesc {
                 }
             }
 
-            let versions = desc
-                .metadata
-                .values()
-                .map(|meta| meta.dropped.unwrap_or(meta.added));
-            let mut max = 0;
-            let mut sum = 0;
-            for version in versions {
-                max = std::cmp::max(max, version.0);
-                sum += version.0;
+            for (col_idx, _) in renames.values() {
+                if !desc.metadata.contains_key(col_idx) {
+                    anyhow::bail!("renamed column does not exist");
+                }
             }
 
-            // Other than RelationVersion(0), we should never have duplicate
-            // versions and they should always increase by 1. In other words, the
-            // sum of all RelationVersions should be the sum of [0, max].
-            //
-            // N.B. n * (n + 1) / 2 = sum of [0, n]
-            //
-            // While I normally don't like tricks like this, it allows us to
-            // validate that our column versions are correct in O(n) time and
-            // without allocations.
-            if sum != (max * (max + 1) / 2) {
+            // Other than RelationVersion(0), every change to the relation
+            // (adding, dropping, or renaming a column) should have a unique
+            // version, and versions should always increase by 1.
+            let mut versions: Vec<_> = desc
+                .metadata
+                .values()
+                .flat_map(|meta| [Some(meta.added), meta.dropped])
+                .flatten()
+                .chain(renames.keys().copied())
+                .filter(|version| *version != RelationVersion::root())
+                .collect();
+            versions.sort();
+            let expected = (1..=u64::cast_from(versions.len())).map(RelationVersion);
+            if !versions.into_iter().eq(expected) {
                 anyhow::bail!("there is a duplicate or missing relation version");
             }
 
             Ok(())
         }
 
-        assert_ok!(validate_inner(&self.inner), "validate failed! {self:?}");
+        assert_ok!(
+            validate_inner(&self.inner, &self.renames),
+            "validate failed! {self:?}"
+        );
     }
 }
 
@@ -2019,9 +2079,7 @@ mod tests {
             .with_column("z", SqlScalarType::String.nullable(false))
             .finish();
 
-        let mut versioned_desc = VersionedRelationDesc {
-            inner: desc.clone(),
-        };
+        let mut versioned_desc = VersionedRelationDesc::new(desc.clone());
         versioned_desc.validate();
 
         let latest = versioned_desc.at_version(RelationVersionSelector::Latest);
@@ -2125,9 +2183,7 @@ mod tests {
             .with_key(vec![1])
             .finish();
 
-        let mut versioned_desc = VersionedRelationDesc {
-            inner: desc.clone(),
-        };
+        let mut versioned_desc = VersionedRelationDesc::new(desc.clone());
         versioned_desc.validate();
 
         let v1 = versioned_desc.drop_column("a");
@@ -2309,6 +2365,67 @@ mod tests {
         "###);
     }
 
+    #[mz_ore::test]
+    fn test_rename_column() {
+        let desc = RelationDesc::builder()
+            .with_column("a", SqlScalarType::Bool.nullable(true))
+            .with_column("b", SqlScalarType::String.nullable(false))
+            .finish();
+        let mut versioned = VersionedRelationDesc::new(desc);
+
+        let v1 = versioned.rename_column("a", "c");
+        assert_eq!(v1, RelationVersion(1));
+        let v2 = versioned.rename_column("c", "d");
+        assert_eq!(v2, RelationVersion(2));
+        let v3 = versioned.drop_column("b");
+        assert_eq!(v3, RelationVersion(3));
+
+        let names = |version| {
+            versioned
+                .at_version(RelationVersionSelector::specific(version))
+                .iter_names()
+                .map(|name| name.as_str().to_string())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(0), ["a", "b"]);
+        assert_eq!(names(1), ["c", "b"]);
+        assert_eq!(names(2), ["d", "b"]);
+        assert_eq!(names(3), ["d"]);
+
+        let latest = versioned.at_version(RelationVersionSelector::Latest);
+        assert!(latest.iter_names().eq([&ColumnName::from("d")]));
+        assert_eq!(versioned.latest_version(), RelationVersion(3));
+    }
+
+    #[mz_ore::test]
+    #[should_panic(expected = "column named 'b' already exists!")]
+    fn test_rename_column_to_existing_panics() {
+        let desc = RelationDesc::builder()
+            .with_column("a", SqlScalarType::Bool.nullable(true))
+            .with_column("b", SqlScalarType::String.nullable(false))
+            .finish();
+        let mut versioned = VersionedRelationDesc::new(desc);
+
+        let _ = versioned.rename_column("a", "b");
+    }
+
+    #[mz_ore::test]
+    fn test_drop_added_column() {
+        let desc = RelationDesc::builder()
+            .with_column("a", SqlScalarType::Bool.nullable(true))
+            .finish();
+        let mut versioned = VersionedRelationDesc::new(desc);
+
+        let v1 = versioned.add_column("b", SqlScalarType::String.nullable(true));
+        let v2 = versioned.drop_column("b");
+        assert_eq!((v1, v2), (RelationVersion(1), RelationVersion(2)));
+
+        let v1 = versioned.at_version(RelationVersionSelector::Specific(v1));
+        assert_eq!(v1.arity(), 2);
+        let v2 = versioned.at_version(RelationVersionSelector::Specific(v2));
+        assert!(v2.iter_names().eq([&ColumnName::from("a")]));
+    }
+
     #[mz_ore::test]
     #[cfg_attr(miri, ignore)]
     fn apply_demand() {
@@ -29,7 +29,10 @@ use mz_ore::error::ErrorExt;
 use mz_ore::netio::AsyncReady;
 use mz_ore::option::OptionExt;
 use mz_ore::task::JoinSetExt;
-use openssl::ssl::{SslAcceptor, SslContext, SslFiletype, SslMethod};
+use openssl::ssl::{
+    SslAcceptor, SslAcceptorBuilder, SslContext, SslFiletype, SslMethod, SslVerifyMode,
+};
+use openssl::x509::{CrlStatus, X509Crl, X509StoreContextRef};
 use proxy_header::{ParseConfig, ProxiedAddress, ProxyHeader};
 use schemars::JsonSchema;
 use scopeguard::ScopeGuard;
@@ -44,6 +47,8 @@ use tokio_stream::wrappers::{IntervalStream, TcpListenerStream};
 use tracing::{debug, error, warn};
 use uuid::Uuid;
 
+use crate::listeners::AuthenticatorKind;
+
 pub mod listeners;
 
 /// TCP keepalive settings. The idle time and interval match CockroachDB [0].
@@ -480,11 +485,48 @@ pub struct TlsCertConfig {
     pub cert: PathBuf,
     /// The path to the TLS key.
     pub key: PathBuf,
+    /// The path to the certificate authorities that sign TLS client
+    /// certificates.
+    ///
+    /// If present, clients of listeners that authenticate users by
+    /// certificate are asked to present one during the TLS handshake.
+    pub client_ca: Option<PathBuf>,
+    /// The path to the certificate revocation lists for TLS client
+    /// certificates.
+    pub client_crl: Option<PathBuf>,
 }
 
 impl TlsCertConfig {
     /// Returns the SSL context to use in TlsConfigs.
     pub fn load_context(&self) -> Result<SslContext, anyhow::Error> {
+        Ok(self.acceptor_builder()?.build().into_context())
+    }
+
+    /// Returns the SSL context to use in the TlsConfigs of listeners that
+    /// authenticate users by TLS client certificate, or `None` if there is no
+    /// client certificate authority.
+    pub fn load_client_cert_context(&self) -> Result<Option<SslContext>, anyhow::Error> {
+        let Some(client_ca) = &self.client_ca else {
+            return Ok(None);
+        };
+        let mut builder = self.acceptor_builder()?;
+        builder.set_ca_file(client_ca)?;
+        let crls = match &self.client_crl {
+            Some(client_crl) => load_crls(client_crl)?,
+            None => vec![],
+        };
+        // Clients are asked for, but not required to present, a certificate,
+        // so that the authenticator can report a missing one. Certificates that
+        // are presented must be valid and unrevoked.
+        builder.set_verify_callback(SslVerifyMode::PEER, move |preverified, ctx| {
+            preverified && !is_revoked(&crls, ctx)
+        });
+        // Sessions are only resumed by the server that verified them.
+        builder.set_session_id_context(b"materialize")?;
+        Ok(Some(builder.build().into_context()))
+    }
+
+    fn acceptor_builder(&self) -> Result<SslAcceptorBuilder, anyhow::Error> {
         // Mozilla publishes three presets: old, intermediate, and modern. They
         // recommend the intermediate preset for general purpose servers, which
         // is what we use, as it is compatible with nearly every client released
@@ -494,7 +536,7 @@ impl TlsCertConfig {
         let mut builder = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls())?;
         builder.set_certificate_chain_file(&self.cert)?;
         builder.set_private_key_file(&self.key, SslFiletype::PEM)?;
-        Ok(builder.build().into_context())
+        Ok(builder)
     }
 
     /// Like [Self::load_context] but attempts to reload the files each time `ticker` yields an item.
@@ -507,13 +549,25 @@ impl TlsCertConfig {
         mut ticker: ReloadTrigger,
     ) -> Result<ReloadingSslContext, anyhow::Error> {
         let context = Arc::new(RwLock::new(self.load_context()?));
+        let client_cert_context = self
+            .load_client_cert_context()?
+            .map(|ctx| Arc::new(RwLock::new(ctx)));
         let updater_context = Arc::clone(&context);
+        let updater_client_cert_context = client_cert_context.clone();
         let config = self.clone();
         mz_ore::task::spawn(|| "TlsCertConfig reloading_context", async move {
             while let Some(chan) = ticker.next().await {
-                let result = match config.load_context() {
-                    Ok(ctx) => {
+                let result = config
+                    .load_context()
+                    .and_then(|ctx| Ok((ctx, config.load_client_cert_context()?)));
+                let result = match result {
+                    Ok((ctx, client_cert_ctx)) => {
                         *updater_context.write().expect("poisoned") = ctx;
+                        if let (Some(updater), Some(ctx)) =
+                            (&updater_client_cert_context, client_cert_ctx)
+                        {
+                            *updater.write().expect("poisoned") = ctx;
+                        }
                         Ok(())
                     }
                     Err(err) => {
@@ -527,8 +581,41 @@ impl TlsCertConfig {
             }
             tracing::warn!("TlsCertConfig reloading_context updater closed");
         });
-        Ok(ReloadingSslContext { context })
+        Ok(ReloadingSslContext {
+            context,
+            client_cert_context,
+        })
+    }
+}
+
+/// Loads the PEM-encoded certificate revocation lists in the file at `path`.
+fn load_crls(path: &PathBuf) -> Result<Vec<X509Crl>, anyhow::Error> {
+    const BEGIN: &str = "-----BEGIN X509 CRL-----";
+    let pem = std::fs::read_to_string(path)?;
+    let mut crls = vec![];
+    for (start, _) in pem.match_indices(BEGIN) {
+        crls.push(X509Crl::from_pem(pem[start..].as_bytes())?);
     }
+    if crls.is_empty() {
+        bail!("no certificate revocation lists in {}", path.display());
+    }
+    Ok(crls)
+}
+
+/// Reports whether one of `crls` revokes the certificate being verified in
+/// `ctx`.
+fn is_revoked(crls: &[X509Crl], ctx: &X509StoreContextRef) -> bool {
+    let Some(cert) = ctx.current_cert() else {
+        return false;
+    };
+    let cert = cert.to_owned();
+    crls.iter().any(|crl| {
+        let same_issuer = crl
+            .issuer_name()
+            .try_cmp(cert.issuer_name())
+            .is_ok_and(|ordering| ordering.is_eq());
+        same_issuer && !matches!(crl.get_by_cert(&cert), CrlStatus::NotRevoked)
+    })
 }
 
 /// An SslContext whose inner value can be updated.
@@ -536,12 +623,39 @@ impl TlsCertConfig {
 pub struct ReloadingSslContext {
     /// The current SSL context.
     context: Arc<RwLock<SslContext>>,
+    /// The current SSL context for listeners that authenticate users by TLS
+    /// client certificate, if there is a client certificate authority.
+    client_cert_context: Option<Arc<RwLock<SslContext>>>,
 }
 
 impl ReloadingSslContext {
     pub fn get(&self) -> RwLockReadGuard<'_, SslContext> {
         self.context.read().expect("poisoned")
     }
+
+    /// Returns the context to use for a listener with the given authenticator.
+    ///
+    /// Only listeners that authenticate users by TLS client certificate ask
+    /// clients to present one, so that clients of other listeners aren't
+    /// prompted for (or rejected over) certificates they don't need.
+    pub fn for_authenticator(
+        &self,
+        authenticator_kind: AuthenticatorKind,
+    ) -> Result<ReloadingSslContext, anyhow::Error> {
+        if authenticator_kind != AuthenticatorKind::Cert {
+            return Ok(ReloadingSslContext {
+                context: Arc::clone(&self.context),
+                client_cert_context: None,
+            });
+        }
+        match &self.client_cert_context {
+            Some(context) => Ok(ReloadingSslContext {
+                context: Arc::clone(context),
+                client_cert_context: None,
+            }),
+            None => bail!("certificate authentication requires a TLS client certificate authority"),
+        }
+    }
 }
 
 /// Configures a server's TLS encryption and authentication with reloading.
@@ -610,6 +724,26 @@ pub struct TlsCliArgs {
         value_name = "PATH"
     )]
     tls_key: Option<PathBuf>,
+    /// Certificate authorities for TLS client certificates.
+    ///
+    /// If set, clients are asked to present a certificate signed by one of
+    /// these authorities, which listeners that use certificate authentication
+    /// require.
+    #[clap(
+        long,
+        env = "TLS_CLIENT_CA",
+        requires = "tls_cert",
+        value_name = "PATH"
+    )]
+    tls_client_ca: Option<PathBuf>,
+    /// Certificate revocation lists for TLS client certificates.
+    #[clap(
+        long,
+        env = "TLS_CLIENT_CRL",
+        requires = "tls_client_ca",
+        value_name = "PATH"
+    )]
+    tls_client_crl: Option<PathBuf>,
 }
 
 impl TlsCliArgs {
@@ -622,11 +756,19 @@ impl TlsCliArgs {
             if self.tls_key.is_some() {
                 bail!("cannot specify --tls-mode=disable and --tls-key simultaneously");
             }
+            if self.tls_client_ca.is_some() {
+                bail!("cannot specify --tls-mode=disable and --tls-client-ca simultaneously");
+            }
             Ok(None)
         } else {
             let cert = self.tls_cert.unwrap();
             let key = self.tls_key.unwrap();
-            Ok(Some(TlsCertConfig { cert, key }))
+            Ok(Some(TlsCertConfig {
+                cert,
+                key,
+                client_ca: self.tls_client_ca,
+                client_crl: self.tls_client_crl,
+            }))
         }
     }
 }
@@ -33,6 +33,11 @@ pub enum AuthenticatorKind {
     Sasl,
     /// Authenticate users using OIDC (JWT tokens).
     Oidc,
+    /// Authenticate users using TLS client certificates.
+    /// Requires TLS and a client certificate authority.
+    Cert,
+    /// Authenticate users using passwords verified by an LDAP directory.
+    Ldap,
     /// Do not authenticate users. Trust they are who they say they are without verification.
     #[default]
     None,
@@ -123,7 +128,7 @@ impl ListenerConfig for SqlListenerConfig {
     }
 
     fn validate(&self) -> Result<(), String> {
-        Ok(())
+        validate_cert_authentication(self)
     }
 }
 impl ListenerConfig for HttpListenerConfig {
@@ -147,7 +152,17 @@ impl ListenerConfig for HttpListenerConfig {
         if self.base.authenticator_kind == AuthenticatorKind::Sasl {
             Err("SASL authentication is not supported for HTTP listeners".to_string())
         } else {
-            Ok(())
+            validate_cert_authentication(self)
         }
     }
 }
+
+/// Certificates are only presented during the TLS handshake, so listeners that
+/// authenticate users by certificate must require TLS.
+fn validate_cert_authentication<C: ListenerConfig>(config: &C) -> Result<(), String> {
+    if config.authenticator_kind() == AuthenticatorKind::Cert && !config.enable_tls() {
+        Err("certificate authentication requires TLS".to_string())
+    } else {
+        Ok(())
+    }
+}
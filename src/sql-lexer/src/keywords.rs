@@ -106,6 +106,8 @@ impl Keyword {
             ON | JOIN | INNER | CROSS | FULL | LEFT | RIGHT | NATURAL | USING |
             // Needed for UPDATE.
             SET |
+            // Needed for `FROM <table_name> TABLESAMPLE ...`.
+            TABLESAMPLE |
             // `OUTER` is not strictly ambiguous, but it prevents `a OUTER JOIN
             // b` from parsing as `a AS outer JOIN b`, instead producing a nice
             // syntax error.
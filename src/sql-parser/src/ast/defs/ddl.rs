@@ -25,7 +25,8 @@ use std::fmt;
 
 use crate::ast::display::{self, AstDisplay, AstFormatter, WithOptionName};
 use crate::ast::{
-    AstInfo, ColumnName, Expr, Ident, OrderByExpr, UnresolvedItemName, Version, WithOptionValue,
+    AstInfo, ColumnName, Expr, Ident, OrderByExpr, SequenceOption, UnresolvedItemName, Version,
+    WithOptionValue,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -36,6 +37,10 @@ pub enum MaterializedViewOptionName {
     RetainHistory,
     /// The `REFRESH [=] ...` option.
     Refresh,
+    /// The `NOTIFY CHANNEL [=] <channel>` option.
+    NotifyChannel,
+    /// The `ROW LEVEL SECURITY [[=] <bool>]` option.
+    RowLevelSecurity,
 }
 
 impl AstDisplay for MaterializedViewOptionName {
@@ -45,6 +50,8 @@ impl AstDisplay for MaterializedViewOptionName {
             MaterializedViewOptionName::PartitionBy => f.write_str("PARTITION BY"),
             MaterializedViewOptionName::RetainHistory => f.write_str("RETAIN HISTORY"),
             MaterializedViewOptionName::Refresh => f.write_str("REFRESH"),
+            MaterializedViewOptionName::NotifyChannel => f.write_str("NOTIFY CHANNEL"),
+            MaterializedViewOptionName::RowLevelSecurity => f.write_str("ROW LEVEL SECURITY"),
         }
     }
 }
@@ -60,7 +67,9 @@ impl WithOptionName for MaterializedViewOptionName {
             MaterializedViewOptionName::AssertNotNull
             | MaterializedViewOptionName::PartitionBy
             | MaterializedViewOptionName::RetainHistory
-            | MaterializedViewOptionName::Refresh => false,
+            | MaterializedViewOptionName::Refresh
+            | MaterializedViewOptionName::NotifyChannel
+            | MaterializedViewOptionName::RowLevelSecurity => false,
         }
     }
 }
@@ -72,6 +81,40 @@ pub struct MaterializedViewOption<T: AstInfo> {
 }
 impl_display_for_with_option!(MaterializedViewOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ViewOptionName {
+    /// The `ROW LEVEL SECURITY [[=] <bool>]` option.
+    RowLevelSecurity,
+}
+
+impl AstDisplay for ViewOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            ViewOptionName::RowLevelSecurity => f.write_str("ROW LEVEL SECURITY"),
+        }
+    }
+}
+
+impl WithOptionName for ViewOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            ViewOptionName::RowLevelSecurity => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ViewOption<T: AstInfo> {
+    pub name: ViewOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(ViewOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ContinualTaskOptionName {
     /// The `SNAPSHOT [=] ...` option.
@@ -689,6 +732,7 @@ impl_display!(SourceEnvelope);
 pub enum SinkEnvelope {
     Debezium,
     Upsert,
+    Changelog,
 }
 
 impl AstDisplay for SinkEnvelope {
@@ -700,26 +744,37 @@ impl AstDisplay for SinkEnvelope {
             Self::Debezium => {
                 f.write_str("DEBEZIUM");
             }
+            Self::Changelog => {
+                f.write_str("CHANGELOG");
+            }
         }
     }
 }
 impl_display!(SinkEnvelope);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub enum IcebergSinkMode {
+pub enum SinkMode {
     Upsert,
+    Append,
+    Changelog,
 }
 
-impl AstDisplay for IcebergSinkMode {
+impl AstDisplay for SinkMode {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             Self::Upsert => {
                 f.write_str("UPSERT");
             }
+            Self::Append => {
+                f.write_str("APPEND");
+            }
+            Self::Changelog => {
+                f.write_str("CHANGELOG");
+            }
         }
     }
 }
-impl_display!(IcebergSinkMode);
+impl_display!(SinkMode);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum SubscribeOutput<T: AstInfo> {
@@ -1171,6 +1226,202 @@ pub struct IcebergSinkConfigOption<T: AstInfo> {
 impl_display_for_with_option!(IcebergSinkConfigOption);
 impl_display_t!(IcebergSinkConfigOption);
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PostgresSinkConfigOptionName {
+    ProgressTable,
+    Table,
+}
+
+impl AstDisplay for PostgresSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            PostgresSinkConfigOptionName::ProgressTable => "PROGRESS TABLE",
+            PostgresSinkConfigOptionName::Table => "TABLE",
+        })
+    }
+}
+impl_display!(PostgresSinkConfigOptionName);
+
+impl WithOptionName for PostgresSinkConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            PostgresSinkConfigOptionName::ProgressTable | PostgresSinkConfigOptionName::Table => {
+                false
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PostgresSinkConfigOption<T: AstInfo> {
+    pub name: PostgresSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(PostgresSinkConfigOption);
+impl_display_t!(PostgresSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MySqlSinkConfigOptionName {
+    ProgressTable,
+    Table,
+}
+
+impl AstDisplay for MySqlSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            MySqlSinkConfigOptionName::ProgressTable => "PROGRESS TABLE",
+            MySqlSinkConfigOptionName::Table => "TABLE",
+        })
+    }
+}
+impl_display!(MySqlSinkConfigOptionName);
+
+impl WithOptionName for MySqlSinkConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            MySqlSinkConfigOptionName::ProgressTable | MySqlSinkConfigOptionName::Table => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MySqlSinkConfigOption<T: AstInfo> {
+    pub name: MySqlSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(MySqlSinkConfigOption);
+impl_display_t!(MySqlSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SqlServerSinkConfigOptionName {
+    ProgressTable,
+    Table,
+}
+
+impl AstDisplay for SqlServerSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            SqlServerSinkConfigOptionName::ProgressTable => "PROGRESS TABLE",
+            SqlServerSinkConfigOptionName::Table => "TABLE",
+        })
+    }
+}
+impl_display!(SqlServerSinkConfigOptionName);
+
+impl WithOptionName for SqlServerSinkConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            SqlServerSinkConfigOptionName::ProgressTable | SqlServerSinkConfigOptionName::Table => {
+                false
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SqlServerSinkConfigOption<T: AstInfo> {
+    pub name: SqlServerSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(SqlServerSinkConfigOption);
+impl_display_t!(SqlServerSinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum S3SinkConfigOptionName {
+    Format,
+    MaxFileSize,
+    Url,
+}
+
+impl AstDisplay for S3SinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            S3SinkConfigOptionName::Format => "FORMAT",
+            S3SinkConfigOptionName::MaxFileSize => "MAX FILE SIZE",
+            S3SinkConfigOptionName::Url => "URL",
+        })
+    }
+}
+impl_display!(S3SinkConfigOptionName);
+
+impl WithOptionName for S3SinkConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            S3SinkConfigOptionName::Format
+            | S3SinkConfigOptionName::MaxFileSize
+            | S3SinkConfigOptionName::Url => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct S3SinkConfigOption<T: AstInfo> {
+    pub name: S3SinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(S3SinkConfigOption);
+impl_display_t!(S3SinkConfigOption);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HttpSinkConfigOptionName {
+    Headers,
+    Url,
+}
+
+impl AstDisplay for HttpSinkConfigOptionName {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            HttpSinkConfigOptionName::Headers => "HEADERS",
+            HttpSinkConfigOptionName::Url => "URL",
+        })
+    }
+}
+impl_display!(HttpSinkConfigOptionName);
+
+impl WithOptionName for HttpSinkConfigOptionName {
+    /// # WARNING
+    ///
+    /// Whenever implementing this trait consider very carefully whether or not
+    /// this value could contain sensitive user data. If you're uncertain, err
+    /// on the conservative side and return `true`.
+    fn redact_value(&self) -> bool {
+        match self {
+            // Headers often carry credentials, even when they are not taken
+            // from secrets.
+            HttpSinkConfigOptionName::Headers => true,
+            HttpSinkConfigOptionName::Url => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HttpSinkConfigOption<T: AstInfo> {
+    pub name: HttpSinkConfigOptionName,
+    pub value: Option<WithOptionValue<T>>,
+}
+impl_display_for_with_option!(HttpSinkConfigOption);
+impl_display_t!(HttpSinkConfigOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PgConfigOptionName {
     /// Hex encoded string of binary serialization of
@@ -1561,6 +1812,29 @@ pub enum CreateSinkConnection<T: AstInfo> {
         key: Option<SinkKey>,
         options: Vec<IcebergSinkConfigOption<T>>,
     },
+    Postgres {
+        connection: T::ItemName,
+        options: Vec<PostgresSinkConfigOption<T>>,
+        key: Option<SinkKey>,
+    },
+    MySql {
+        connection: T::ItemName,
+        options: Vec<MySqlSinkConfigOption<T>>,
+        key: Option<SinkKey>,
+    },
+    SqlServer {
+        connection: T::ItemName,
+        options: Vec<SqlServerSinkConfigOption<T>>,
+        key: Option<SinkKey>,
+    },
+    S3 {
+        connection: T::ItemName,
+        options: Vec<S3SinkConfigOption<T>>,
+    },
+    Http {
+        options: Vec<HttpSinkConfigOption<T>>,
+        key: Option<SinkKey>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
@@ -1608,6 +1882,81 @@ impl<T: AstInfo> AstDisplay for CreateSinkConnection<T> {
                     f.write_node(key);
                 }
             }
+            CreateSinkConnection::Postgres {
+                connection,
+                options,
+                key,
+            } => {
+                f.write_str("POSTGRES CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+                if let Some(key) = key.as_ref() {
+                    f.write_str(" ");
+                    f.write_node(key);
+                }
+            }
+            CreateSinkConnection::MySql {
+                connection,
+                options,
+                key,
+            } => {
+                f.write_str("MYSQL CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+                if let Some(key) = key.as_ref() {
+                    f.write_str(" ");
+                    f.write_node(key);
+                }
+            }
+            CreateSinkConnection::SqlServer {
+                connection,
+                options,
+                key,
+            } => {
+                f.write_str("SQL SERVER CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+                if let Some(key) = key.as_ref() {
+                    f.write_str(" ");
+                    f.write_node(key);
+                }
+            }
+            CreateSinkConnection::S3 {
+                connection,
+                options,
+            } => {
+                f.write_str("S3 CONNECTION ");
+                f.write_node(connection);
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+            }
+            CreateSinkConnection::Http { options, key } => {
+                f.write_str("HTTP");
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::comma_separated(options));
+                    f.write_str(")");
+                }
+                if let Some(key) = key.as_ref() {
+                    f.write_str(" ");
+                    f.write_node(key);
+                }
+            }
         }
     }
 }
@@ -1850,6 +2199,11 @@ pub enum ColumnOption<T: AstInfo> {
         action: ColumnVersioned,
         version: Version,
     },
+    /// `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY [ ( <sequence options> ) ]`
+    Identity {
+        always: bool,
+        options: Vec<SequenceOption>,
+    },
 }
 
 impl<T: AstInfo> AstDisplay for ColumnOption<T> {
@@ -1890,6 +2244,18 @@ impl<T: AstInfo> AstDisplay for ColumnOption<T> {
                 f.write_str(" ");
                 f.write_node(version);
             }
+            Identity { always, options } => {
+                if *always {
+                    f.write_str("GENERATED ALWAYS AS IDENTITY");
+                } else {
+                    f.write_str("GENERATED BY DEFAULT AS IDENTITY");
+                }
+                if !options.is_empty() {
+                    f.write_str(" (");
+                    f.write_node(&display::separated(options, " "));
+                    f.write_str(")");
+                }
+            }
         }
     }
 }
@@ -1898,6 +2264,11 @@ impl_display_t!(ColumnOption);
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ColumnVersioned {
     Added,
+    Dropped,
+    /// The column was renamed from `from` at this version.
+    Renamed {
+        from: Ident,
+    },
 }
 
 impl AstDisplay for ColumnVersioned {
@@ -1906,8 +2277,12 @@ impl AstDisplay for ColumnVersioned {
         W: fmt::Write,
     {
         match self {
-            // TODO(alter_table): Support dropped columns.
             ColumnVersioned::Added => f.write_str("ADDED"),
+            ColumnVersioned::Dropped => f.write_str("DROPPED"),
+            ColumnVersioned::Renamed { from } => {
+                f.write_str("RENAMED FROM ");
+                f.write_node(from);
+            }
         }
     }
 }
@@ -847,6 +847,16 @@ impl<T: AstInfo> AstDisplay for Function<T> {
         }
         f.write_node(&self.args);
         f.write_str(")");
+        if let FunctionArgs::Args {
+            order_by,
+            within_group: true,
+            ..
+        } = &self.args
+        {
+            f.write_str(" WITHIN GROUP (ORDER BY ");
+            f.write_node(&display::comma_separated(order_by));
+            f.write_str(")");
+        }
         if let Some(filter) = &self.filter {
             f.write_str(" FILTER (WHERE ");
             f.write_node(&filter);
@@ -868,6 +878,10 @@ pub enum FunctionArgs<T: AstInfo> {
     Args {
         args: Vec<Expr<T>>,
         order_by: Vec<OrderByExpr<T>>,
+        /// Whether `order_by` was specified in a `WITHIN GROUP` clause, as is
+        /// required for ordered-set aggregates like `percentile_cont`, rather
+        /// than inside the argument list.
+        within_group: bool,
     },
 }
 
@@ -876,6 +890,7 @@ impl<T: AstInfo> FunctionArgs<T> {
         Self::Args {
             args,
             order_by: vec![],
+            within_group: false,
         }
     }
 
@@ -916,9 +931,15 @@ impl<T: AstInfo> AstDisplay for FunctionArgs<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
             FunctionArgs::Star => f.write_str("*"),
-            FunctionArgs::Args { args, order_by } => {
+            FunctionArgs::Args {
+                args,
+                order_by,
+                within_group,
+            } => {
                 f.write_node(&display::comma_separated(args));
-                if !order_by.is_empty() {
+                // `WITHIN GROUP` is printed after the closing parenthesis by
+                // `Function`.
+                if !order_by.is_empty() && !within_group {
                     f.write_str(" ORDER BY ");
                     f.write_node(&display::comma_separated(order_by));
                 }
@@ -241,7 +241,7 @@ pub struct Select<T: AstInfo> {
     /// WHERE
     pub selection: Option<Expr<T>>,
     /// GROUP BY
-    pub group_by: Vec<Expr<T>>,
+    pub group_by: Vec<GroupingElement<T>>,
     /// HAVING
     pub having: Option<Expr<T>>,
     /// QUALIFY
@@ -327,6 +327,52 @@ impl<T: AstInfo> AstDisplay for Distinct<T> {
     }
 }
 
+/// An element of a `GROUP BY` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum GroupingElement<T: AstInfo> {
+    /// A single grouping expression.
+    Expr(Expr<T>),
+    /// A parenthesized list of grouping expressions, as in `(a, b)`, which
+    /// may only appear within a `ROLLUP`, `CUBE`, or `GROUPING SETS`. The
+    /// empty list `()` denotes the empty grouping set.
+    List(Vec<Expr<T>>),
+    /// `ROLLUP (...)`
+    Rollup(Vec<GroupingElement<T>>),
+    /// `CUBE (...)`
+    Cube(Vec<GroupingElement<T>>),
+    /// `GROUPING SETS (...)`
+    GroupingSets(Vec<GroupingElement<T>>),
+}
+
+impl<T: AstInfo> AstDisplay for GroupingElement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            GroupingElement::Expr(expr) => f.write_node(expr),
+            GroupingElement::List(exprs) => {
+                f.write_str("(");
+                f.write_node(&display::comma_separated(exprs));
+                f.write_str(")");
+            }
+            GroupingElement::Rollup(elems) => {
+                f.write_str("ROLLUP (");
+                f.write_node(&display::comma_separated(elems));
+                f.write_str(")");
+            }
+            GroupingElement::Cube(elems) => {
+                f.write_str("CUBE (");
+                f.write_node(&display::comma_separated(elems));
+                f.write_str(")");
+            }
+            GroupingElement::GroupingSets(elems) => {
+                f.write_str("GROUPING SETS (");
+                f.write_node(&display::comma_separated(elems));
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(GroupingElement);
+
 /// A block of common table expressions (CTEs).
 ///
 /// The block can either be entirely "simple" (traditional SQL `WITH` block),
@@ -558,6 +604,7 @@ pub enum TableFactor<T: AstInfo> {
     Table {
         name: T::ItemName,
         alias: Option<TableAlias>,
+        sample: Option<TableSample<T>>,
     },
     Function {
         function: Function<T>,
@@ -587,12 +634,20 @@ pub enum TableFactor<T: AstInfo> {
 impl<T: AstInfo> AstDisplay for TableFactor<T> {
     fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
         match self {
-            TableFactor::Table { name, alias } => {
+            TableFactor::Table {
+                name,
+                alias,
+                sample,
+            } => {
                 f.write_node(name);
                 if let Some(alias) = alias {
                     f.write_str(" AS ");
                     f.write_node(alias);
                 }
+                if let Some(sample) = sample {
+                    f.write_str(" ");
+                    f.write_node(sample);
+                }
             }
             TableFactor::Function {
                 function,
@@ -654,6 +709,51 @@ impl<T: AstInfo> AstDisplay for TableFactor<T> {
 }
 impl_display_t!(TableFactor);
 
+/// A `TABLESAMPLE` clause attached to a table reference, as in
+/// `FROM t TABLESAMPLE BERNOULLI (10) REPEATABLE (42)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct TableSample<T: AstInfo> {
+    pub method: TableSampleMethod,
+    /// The percentage of the relation to sample, between 0 and 100.
+    pub percentage: Expr<T>,
+    /// The seed to use, if the sample should be repeatable.
+    pub repeatable: Option<Expr<T>>,
+}
+
+impl<T: AstInfo> AstDisplay for TableSample<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("TABLESAMPLE ");
+        f.write_node(&self.method);
+        f.write_str(" (");
+        f.write_node(&self.percentage);
+        f.write_str(")");
+        if let Some(repeatable) = &self.repeatable {
+            f.write_str(" REPEATABLE (");
+            f.write_node(repeatable);
+            f.write_str(")");
+        }
+    }
+}
+impl_display_t!(TableSample);
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TableSampleMethod {
+    /// Samples individual rows.
+    Bernoulli,
+    /// Samples blocks of rows, i.e., entire parts of the underlying storage.
+    System,
+}
+
+impl AstDisplay for TableSampleMethod {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str(match self {
+            TableSampleMethod::Bernoulli => "BERNOULLI",
+            TableSampleMethod::System => "SYSTEM",
+        })
+    }
+}
+impl_display!(TableSampleMethod);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct TableAlias {
     pub name: Ident,
@@ -30,10 +30,10 @@ use crate::ast::{
     AstInfo, ColumnDef, ConnectionOption, ConnectionOptionName, ContinualTaskOption,
     CreateConnectionOption, CreateConnectionType, CreateSinkConnection, CreateSourceConnection,
     CreateSourceOption, CreateSourceOptionName, CteMutRecColumnDef, DeferredItemName, Expr, Format,
-    FormatSpecifier, IcebergSinkMode, Ident, IntervalValue, KeyConstraint, MaterializedViewOption,
-    Query, SelectItem, SinkEnvelope, SourceEnvelope, SourceIncludeMetadata, SubscribeOutput,
+    FormatSpecifier, Ident, IntervalValue, KeyConstraint, MaterializedViewOption, Query,
+    SelectItem, SinkEnvelope, SinkMode, SourceEnvelope, SourceIncludeMetadata, SubscribeOutput,
     TableAlias, TableConstraint, TableWithJoins, UnresolvedDatabaseName, UnresolvedItemName,
-    UnresolvedObjectName, UnresolvedSchemaName, Value,
+    UnresolvedObjectName, UnresolvedSchemaName, Value, ViewOption,
 };
 
 /// A top-level statement (SELECT, INSERT, CREATE, etc.)
@@ -46,6 +46,7 @@ pub enum Statement<T: AstInfo> {
     Copy(CopyStatement<T>),
     Update(UpdateStatement<T>),
     Delete(DeleteStatement<T>),
+    Truncate(TruncateStatement<T>),
     CreateConnection(CreateConnectionStatement<T>),
     CreateDatabase(CreateDatabaseStatement),
     CreateSchema(CreateSchemaStatement),
@@ -60,16 +61,21 @@ pub enum Statement<T: AstInfo> {
     CreateTableFromSource(CreateTableFromSourceStatement<T>),
     CreateIndex(CreateIndexStatement<T>),
     CreateType(CreateTypeStatement<T>),
+    CreateFunction(CreateFunctionStatement<T>),
+    CreateSequence(CreateSequenceStatement),
     CreateRole(CreateRoleStatement),
     CreateCluster(CreateClusterStatement<T>),
     CreateClusterReplica(CreateClusterReplicaStatement<T>),
     CreateSecret(CreateSecretStatement<T>),
     CreateNetworkPolicy(CreateNetworkPolicyStatement<T>),
+    CreatePublication(CreatePublicationStatement<T>),
+    CreatePolicy(CreatePolicyStatement<T>),
     AlterCluster(AlterClusterStatement<T>),
     AlterOwner(AlterOwnerStatement<T>),
     AlterObjectRename(AlterObjectRenameStatement),
     AlterObjectSwap(AlterObjectSwapStatement),
     AlterRetainHistory(AlterRetainHistoryStatement<T>),
+    AlterRowLevelSecurity(AlterRowLevelSecurityStatement),
     AlterIndex(AlterIndexStatement<T>),
     AlterSecret(AlterSecretStatement<T>),
     AlterSetCluster(AlterSetClusterStatement<T>),
@@ -82,10 +88,13 @@ pub enum Statement<T: AstInfo> {
     AlterNetworkPolicy(AlterNetworkPolicyStatement<T>),
     AlterRole(AlterRoleStatement<T>),
     AlterTableAddColumn(AlterTableAddColumnStatement<T>),
+    AlterTableDropColumn(AlterTableDropColumnStatement),
+    AlterTableRenameColumn(AlterTableRenameColumnStatement),
     AlterMaterializedViewApplyReplacement(AlterMaterializedViewApplyReplacementStatement),
     Discard(DiscardStatement),
     DropObjects(DropObjectsStatement),
     DropOwned(DropOwnedStatement<T>),
+    DropPublication(DropPublicationStatement),
     SetVariable(SetVariableStatement),
     ResetVariable(ResetVariableStatement),
     Show(ShowStatement<T>),
@@ -107,6 +116,12 @@ pub enum Statement<T: AstInfo> {
     Execute(ExecuteStatement<T>),
     Deallocate(DeallocateStatement),
     Raise(RaiseStatement),
+    Listen(ListenStatement),
+    Unlisten(UnlistenStatement),
+    IdentifySystem(IdentifySystemStatement),
+    CreateReplicationSlot(CreateReplicationSlotStatement),
+    DropReplicationSlot(DropReplicationSlotStatement),
+    StartReplication(StartReplicationStatement),
     GrantRole(GrantRoleStatement<T>),
     RevokeRole(RevokeRoleStatement<T>),
     GrantPrivileges(GrantPrivilegesStatement<T>),
@@ -125,6 +140,7 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Copy(stmt) => f.write_node(stmt),
             Statement::Update(stmt) => f.write_node(stmt),
             Statement::Delete(stmt) => f.write_node(stmt),
+            Statement::Truncate(stmt) => f.write_node(stmt),
             Statement::CreateConnection(stmt) => f.write_node(stmt),
             Statement::CreateDatabase(stmt) => f.write_node(stmt),
             Statement::CreateSchema(stmt) => f.write_node(stmt),
@@ -141,14 +157,19 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::CreateRole(stmt) => f.write_node(stmt),
             Statement::CreateSecret(stmt) => f.write_node(stmt),
             Statement::CreateType(stmt) => f.write_node(stmt),
+            Statement::CreateFunction(stmt) => f.write_node(stmt),
+            Statement::CreateSequence(stmt) => f.write_node(stmt),
             Statement::CreateCluster(stmt) => f.write_node(stmt),
             Statement::CreateClusterReplica(stmt) => f.write_node(stmt),
             Statement::CreateNetworkPolicy(stmt) => f.write_node(stmt),
+            Statement::CreatePublication(stmt) => f.write_node(stmt),
+            Statement::CreatePolicy(stmt) => f.write_node(stmt),
             Statement::AlterCluster(stmt) => f.write_node(stmt),
             Statement::AlterNetworkPolicy(stmt) => f.write_node(stmt),
             Statement::AlterOwner(stmt) => f.write_node(stmt),
             Statement::AlterObjectRename(stmt) => f.write_node(stmt),
             Statement::AlterRetainHistory(stmt) => f.write_node(stmt),
+            Statement::AlterRowLevelSecurity(stmt) => f.write_node(stmt),
             Statement::AlterObjectSwap(stmt) => f.write_node(stmt),
             Statement::AlterIndex(stmt) => f.write_node(stmt),
             Statement::AlterSetCluster(stmt) => f.write_node(stmt),
@@ -161,10 +182,13 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::AlterConnection(stmt) => f.write_node(stmt),
             Statement::AlterRole(stmt) => f.write_node(stmt),
             Statement::AlterTableAddColumn(stmt) => f.write_node(stmt),
+            Statement::AlterTableDropColumn(stmt) => f.write_node(stmt),
+            Statement::AlterTableRenameColumn(stmt) => f.write_node(stmt),
             Statement::AlterMaterializedViewApplyReplacement(stmt) => f.write_node(stmt),
             Statement::Discard(stmt) => f.write_node(stmt),
             Statement::DropObjects(stmt) => f.write_node(stmt),
             Statement::DropOwned(stmt) => f.write_node(stmt),
+            Statement::DropPublication(stmt) => f.write_node(stmt),
             Statement::SetVariable(stmt) => f.write_node(stmt),
             Statement::ResetVariable(stmt) => f.write_node(stmt),
             Statement::Show(stmt) => f.write_node(stmt),
@@ -186,6 +210,12 @@ impl<T: AstInfo> AstDisplay for Statement<T> {
             Statement::Execute(stmt) => f.write_node(stmt),
             Statement::Deallocate(stmt) => f.write_node(stmt),
             Statement::Raise(stmt) => f.write_node(stmt),
+            Statement::Listen(stmt) => f.write_node(stmt),
+            Statement::Unlisten(stmt) => f.write_node(stmt),
+            Statement::IdentifySystem(stmt) => f.write_node(stmt),
+            Statement::CreateReplicationSlot(stmt) => f.write_node(stmt),
+            Statement::DropReplicationSlot(stmt) => f.write_node(stmt),
+            Statement::StartReplication(stmt) => f.write_node(stmt),
             Statement::GrantRole(stmt) => f.write_node(stmt),
             Statement::RevokeRole(stmt) => f.write_node(stmt),
             Statement::GrantPrivileges(stmt) => f.write_node(stmt),
@@ -207,6 +237,7 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Copy => "copy",
         StatementKind::Update => "update",
         StatementKind::Delete => "delete",
+        StatementKind::Truncate => "truncate",
         StatementKind::CreateConnection => "create_connection",
         StatementKind::CreateDatabase => "create_database",
         StatementKind::CreateSchema => "create_schema",
@@ -221,14 +252,19 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::CreateTableFromSource => "create_table_from_source",
         StatementKind::CreateIndex => "create_index",
         StatementKind::CreateType => "create_type",
+        StatementKind::CreateFunction => "create_function",
+        StatementKind::CreateSequence => "create_sequence",
         StatementKind::CreateRole => "create_role",
         StatementKind::CreateCluster => "create_cluster",
         StatementKind::CreateClusterReplica => "create_cluster_replica",
         StatementKind::CreateSecret => "create_secret",
         StatementKind::CreateNetworkPolicy => "create_network_policy",
+        StatementKind::CreatePublication => "create_publication",
+        StatementKind::CreatePolicy => "create_policy",
         StatementKind::AlterCluster => "alter_cluster",
         StatementKind::AlterObjectRename => "alter_object_rename",
         StatementKind::AlterRetainHistory => "alter_retain_history",
+        StatementKind::AlterRowLevelSecurity => "alter_row_level_security",
         StatementKind::AlterObjectSwap => "alter_object_swap",
         StatementKind::AlterIndex => "alter_index",
         StatementKind::AlterNetworkPolicy => "alter_network_policy",
@@ -243,12 +279,15 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::AlterOwner => "alter_owner",
         StatementKind::AlterConnection => "alter_connection",
         StatementKind::AlterTableAddColumn => "alter_table",
+        StatementKind::AlterTableDropColumn => "alter_table",
+        StatementKind::AlterTableRenameColumn => "alter_table",
         StatementKind::AlterMaterializedViewApplyReplacement => {
             "alter_materialized_view_apply_replacement"
         }
         StatementKind::Discard => "discard",
         StatementKind::DropObjects => "drop_objects",
         StatementKind::DropOwned => "drop_owned",
+        StatementKind::DropPublication => "drop_publication",
         StatementKind::SetVariable => "set_variable",
         StatementKind::ResetVariable => "reset_variable",
         StatementKind::Show => "show",
@@ -270,6 +309,12 @@ pub fn statement_kind_label_value(kind: StatementKind) -> &'static str {
         StatementKind::Execute => "execute",
         StatementKind::Deallocate => "deallocate",
         StatementKind::Raise => "raise",
+        StatementKind::Listen => "listen",
+        StatementKind::Unlisten => "unlisten",
+        StatementKind::IdentifySystem => "identify_system",
+        StatementKind::CreateReplicationSlot => "create_replication_slot",
+        StatementKind::DropReplicationSlot => "drop_replication_slot",
+        StatementKind::StartReplication => "start_replication",
         StatementKind::GrantRole => "grant_role",
         StatementKind::RevokeRole => "revoke_role",
         StatementKind::GrantPrivileges => "grant_privileges",
@@ -548,6 +593,21 @@ impl<T: AstInfo> AstDisplay for DeleteStatement<T> {
 }
 impl_display_t!(DeleteStatement);
 
+/// `TRUNCATE`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TruncateStatement<T: AstInfo> {
+    /// The tables to truncate.
+    pub table_names: Vec<T::ItemName>,
+}
+
+impl<T: AstInfo> AstDisplay for TruncateStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("TRUNCATE TABLE ");
+        f.write_node(&display::comma_separated(&self.table_names));
+    }
+}
+impl_display_t!(TruncateStatement);
+
 /// `CREATE DATABASE`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateDatabaseStatement {
@@ -1287,7 +1347,7 @@ pub struct CreateSinkStatement<T: AstInfo> {
     pub connection: CreateSinkConnection<T>,
     pub format: Option<FormatSpecifier<T>>,
     pub envelope: Option<SinkEnvelope>,
-    pub mode: Option<IcebergSinkMode>,
+    pub mode: Option<SinkMode>,
     pub with_options: Vec<CreateSinkOption<T>>,
 }
 
@@ -1337,6 +1397,7 @@ pub struct ViewDefinition<T: AstInfo> {
     /// View name
     pub name: UnresolvedItemName,
     pub columns: Vec<Ident>,
+    pub with_options: Vec<ViewOption<T>>,
     pub query: Query<T>,
 }
 
@@ -1350,6 +1411,12 @@ impl<T: AstInfo> AstDisplay for ViewDefinition<T> {
             f.write_str(")");
         }
 
+        if !self.with_options.is_empty() {
+            f.write_str(" WITH (");
+            f.write_node(&display::comma_separated(&self.with_options));
+            f.write_str(")");
+        }
+
         f.write_str(" AS ");
         f.write_node(&self.query);
     }
@@ -1645,6 +1712,8 @@ pub enum TableOptionName {
     PartitionBy,
     // The `RETAIN HISTORY` option
     RetainHistory,
+    /// The `ROW LEVEL SECURITY [[=] <bool>]` option
+    RowLevelSecurity,
     /// A special option to test that we do redact values.
     RedactedTest,
 }
@@ -1658,6 +1727,9 @@ impl AstDisplay for TableOptionName {
             TableOptionName::RetainHistory => {
                 f.write_str("RETAIN HISTORY");
             }
+            TableOptionName::RowLevelSecurity => {
+                f.write_str("ROW LEVEL SECURITY");
+            }
             TableOptionName::RedactedTest => {
                 f.write_str("REDACTED");
             }
@@ -1675,6 +1747,7 @@ impl WithOptionName for TableOptionName {
         match self {
             TableOptionName::PartitionBy => false,
             TableOptionName::RetainHistory => false,
+            TableOptionName::RowLevelSecurity => false,
             TableOptionName::RedactedTest => true,
         }
     }
@@ -2218,6 +2291,183 @@ impl<T: AstInfo> AstDisplay for CreateTypeStatement<T> {
 }
 impl_display_t!(CreateTypeStatement);
 
+/// `CREATE FUNCTION ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateFunctionStatement<T: AstInfo> {
+    /// Name of the created function.
+    pub name: UnresolvedItemName,
+    /// Whether to replace an existing function of the same name.
+    pub if_exists: IfExistsBehavior,
+    /// The function's parameters.
+    pub params: Vec<FunctionParameter<T>>,
+    /// What the function returns.
+    pub returns: FunctionReturns<T>,
+    /// The body of the function, a query that refers to the parameters by name
+    /// or by position (`$1`, `$2`, ...).
+    pub body: Query<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreateFunctionStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE");
+        if self.if_exists == IfExistsBehavior::Replace {
+            f.write_str(" OR REPLACE");
+        }
+        f.write_str(" FUNCTION ");
+        f.write_node(&self.name);
+        f.write_str("(");
+        f.write_node(&display::comma_separated(&self.params));
+        f.write_str(") RETURNS ");
+        f.write_node(&self.returns);
+        f.write_str(" LANGUAGE SQL AS ");
+        // The body is written as a string literal, but is printed in the same
+        // mode as the rest of the statement so that names stay quoted and
+        // literals stay redacted.
+        let body = self.body.to_ast_string(f.mode());
+        f.write_node(&display::escaped_string_literal(&body));
+    }
+}
+impl_display_t!(CreateFunctionStatement);
+
+/// A parameter of a function created with `CREATE FUNCTION`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionParameter<T: AstInfo> {
+    pub name: Ident,
+    pub data_type: T::DataType,
+}
+
+impl<T: AstInfo> AstDisplay for FunctionParameter<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        f.write_str(" ");
+        f.write_node(&self.data_type);
+    }
+}
+impl_display_t!(FunctionParameter);
+
+/// The result of a function created with `CREATE FUNCTION`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FunctionReturns<T: AstInfo> {
+    /// `RETURNS <type>`
+    Scalar(T::DataType),
+    /// `RETURNS TABLE (<column> <type>, ...)`
+    Table(Vec<ColumnDef<T>>),
+}
+
+impl<T: AstInfo> AstDisplay for FunctionReturns<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            FunctionReturns::Scalar(data_type) => f.write_node(data_type),
+            FunctionReturns::Table(columns) => {
+                f.write_str("TABLE (");
+                f.write_node(&display::comma_separated(columns));
+                f.write_str(")");
+            }
+        }
+    }
+}
+impl_display_t!(FunctionReturns);
+
+/// `CREATE SEQUENCE ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateSequenceStatement {
+    /// Name of the created sequence.
+    pub name: UnresolvedItemName,
+    /// Whether to ignore the statement if a sequence of the same name exists.
+    pub if_not_exists: bool,
+    /// The sequence's options.
+    pub options: Vec<SequenceOption>,
+}
+
+impl AstDisplay for CreateSequenceStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE SEQUENCE ");
+        if self.if_not_exists {
+            f.write_str("IF NOT EXISTS ");
+        }
+        f.write_node(&self.name);
+        for option in &self.options {
+            f.write_str(" ");
+            f.write_node(option);
+        }
+    }
+}
+impl_display!(CreateSequenceStatement);
+
+/// `CREATE POLICY ..`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreatePolicyStatement<T: AstInfo> {
+    /// Name of the created policy.
+    pub name: Ident,
+    /// The relation whose rows the policy filters.
+    pub on_name: T::ItemName,
+    /// The roles the policy applies to. Empty if it applies to all roles.
+    pub roles: Vec<T::RoleName>,
+    /// The condition that rows must satisfy to be visible.
+    pub using: Expr<T>,
+}
+
+impl<T: AstInfo> AstDisplay for CreatePolicyStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE POLICY ");
+        f.write_node(&self.name);
+        f.write_str(" ON ");
+        f.write_node(&self.on_name);
+        if !self.roles.is_empty() {
+            f.write_str(" TO ");
+            f.write_node(&display::comma_separated(&self.roles));
+        }
+        f.write_str(" USING (");
+        f.write_node(&self.using);
+        f.write_str(")");
+    }
+}
+impl_display_t!(CreatePolicyStatement);
+
+/// An option of a sequence, specified either in `CREATE SEQUENCE` or in the
+/// `GENERATED ... AS IDENTITY` option of a column.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SequenceOption {
+    /// `INCREMENT [BY] <n>`
+    IncrementBy(i64),
+    /// `MINVALUE <n>` or `NO MINVALUE`
+    MinValue(Option<i64>),
+    /// `MAXVALUE <n>` or `NO MAXVALUE`
+    MaxValue(Option<i64>),
+    /// `START [WITH] <n>`
+    StartWith(i64),
+    /// `CYCLE` or `NO CYCLE`
+    Cycle(bool),
+}
+
+impl AstDisplay for SequenceOption {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        match self {
+            SequenceOption::IncrementBy(n) => {
+                f.write_str("INCREMENT BY ");
+                f.write_str(n);
+            }
+            SequenceOption::MinValue(Some(n)) => {
+                f.write_str("MINVALUE ");
+                f.write_str(n);
+            }
+            SequenceOption::MinValue(None) => f.write_str("NO MINVALUE"),
+            SequenceOption::MaxValue(Some(n)) => {
+                f.write_str("MAXVALUE ");
+                f.write_str(n);
+            }
+            SequenceOption::MaxValue(None) => f.write_str("NO MAXVALUE"),
+            SequenceOption::StartWith(n) => {
+                f.write_str("START WITH ");
+                f.write_str(n);
+            }
+            SequenceOption::Cycle(true) => f.write_str("CYCLE"),
+            SequenceOption::Cycle(false) => f.write_str("NO CYCLE"),
+        }
+    }
+}
+impl_display!(SequenceOption);
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ClusterOptionName {
     /// The `AVAILABILITY ZONES [[=] '[' <values> ']' ]` option.
@@ -2793,6 +3043,35 @@ impl<T: AstInfo> AstDisplay for AlterRetainHistoryStatement<T> {
 }
 impl_display_t!(AlterRetainHistoryStatement);
 
+/// `ALTER <OBJECT> ... { ENABLE | DISABLE } ROW LEVEL SECURITY`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterRowLevelSecurityStatement {
+    pub object_type: ObjectType,
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    /// Whether row-level security is enabled or disabled.
+    pub enabled: bool,
+}
+
+impl AstDisplay for AlterRowLevelSecurityStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("ALTER ");
+        f.write_node(&self.object_type);
+        f.write_str(" ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+        if self.enabled {
+            f.write_str(" ENABLE");
+        } else {
+            f.write_str(" DISABLE");
+        }
+        f.write_str(" ROW LEVEL SECURITY");
+    }
+}
+impl_display!(AlterRowLevelSecurityStatement);
+
 /// `ALTER <OBJECT> SWAP ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AlterObjectSwapStatement {
@@ -3232,6 +3511,66 @@ impl<T: AstInfo> AstDisplay for AlterTableAddColumnStatement<T> {
 
 impl_display_t!(AlterTableAddColumnStatement);
 
+/// `ALTER TABLE ... DROP COLUMN ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTableDropColumnStatement {
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    pub if_col_exists: bool,
+    pub column_name: Ident,
+}
+
+impl AstDisplay for AlterTableDropColumnStatement {
+    fn fmt<W>(&self, f: &mut AstFormatter<W>)
+    where
+        W: fmt::Write,
+    {
+        f.write_str("ALTER TABLE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+
+        f.write_str(" DROP COLUMN ");
+        if self.if_col_exists {
+            f.write_str("IF EXISTS ");
+        }
+
+        f.write_node(&self.column_name);
+    }
+}
+
+impl_display!(AlterTableDropColumnStatement);
+
+/// `ALTER TABLE ... RENAME COLUMN ... TO ...`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AlterTableRenameColumnStatement {
+    pub if_exists: bool,
+    pub name: UnresolvedItemName,
+    pub column_name: Ident,
+    pub to_column_name: Ident,
+}
+
+impl AstDisplay for AlterTableRenameColumnStatement {
+    fn fmt<W>(&self, f: &mut AstFormatter<W>)
+    where
+        W: fmt::Write,
+    {
+        f.write_str("ALTER TABLE ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+
+        f.write_str(" RENAME COLUMN ");
+        f.write_node(&self.column_name);
+        f.write_str(" TO ");
+        f.write_node(&self.to_column_name);
+    }
+}
+
+impl_display!(AlterTableRenameColumnStatement);
+
 /// `ALTER MATERIALIZED VIEW ... APPLY REPLACEMENT ...`
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AlterMaterializedViewApplyReplacementStatement {
@@ -4284,6 +4623,8 @@ pub enum ObjectType {
     Subsource,
     ContinualTask,
     NetworkPolicy,
+    Sequence,
+    Policy,
 }
 
 impl ObjectType {
@@ -4300,7 +4641,9 @@ impl ObjectType {
             | ObjectType::Connection
             | ObjectType::Func
             | ObjectType::Subsource
-            | ObjectType::ContinualTask => true,
+            | ObjectType::ContinualTask
+            | ObjectType::Sequence
+            | ObjectType::Policy => true,
             ObjectType::Database
             | ObjectType::Schema
             | ObjectType::Cluster
@@ -4332,6 +4675,8 @@ impl AstDisplay for ObjectType {
             ObjectType::Subsource => "SUBSOURCE",
             ObjectType::ContinualTask => "CONTINUAL TASK",
             ObjectType::NetworkPolicy => "NETWORK POLICY",
+            ObjectType::Sequence => "SEQUENCE",
+            ObjectType::Policy => "POLICY",
         })
     }
 }
@@ -5119,6 +5464,178 @@ impl AstDisplay for RaiseStatement {
 }
 impl_display!(RaiseStatement);
 
+/// `LISTEN <channel>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ListenStatement {
+    pub channel: Ident,
+}
+
+impl AstDisplay for ListenStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("LISTEN ");
+        f.write_node(&self.channel);
+    }
+}
+impl_display!(ListenStatement);
+
+/// `UNLISTEN { <channel> | * }`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UnlistenStatement {
+    /// The channel to stop listening on, or `None` for `UNLISTEN *`.
+    pub channel: Option<Ident>,
+}
+
+impl AstDisplay for UnlistenStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("UNLISTEN ");
+        match &self.channel {
+            Some(channel) => f.write_node(channel),
+            None => f.write_str("*"),
+        }
+    }
+}
+impl_display!(UnlistenStatement);
+
+/// `CREATE PUBLICATION <name> FOR TABLE <relation> [, ...]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreatePublicationStatement<T: AstInfo> {
+    pub name: Ident,
+    /// The relations published to logical replication consumers.
+    pub relations: Vec<T::ItemName>,
+}
+
+impl<T: AstInfo> AstDisplay for CreatePublicationStatement<T> {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE PUBLICATION ");
+        f.write_node(&self.name);
+        f.write_str(" FOR TABLE ");
+        f.write_node(&display::comma_separated(&self.relations));
+    }
+}
+impl_display_t!(CreatePublicationStatement);
+
+/// `DROP PUBLICATION [IF EXISTS] <name>`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropPublicationStatement {
+    pub name: Ident,
+    pub if_exists: bool,
+}
+
+impl AstDisplay for DropPublicationStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("DROP PUBLICATION ");
+        if self.if_exists {
+            f.write_str("IF EXISTS ");
+        }
+        f.write_node(&self.name);
+    }
+}
+impl_display!(DropPublicationStatement);
+
+/// An option of a replication command, like `proto_version '1'` in
+/// `START_REPLICATION`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReplicationOption {
+    pub name: Ident,
+    pub value: Option<Value>,
+}
+
+impl AstDisplay for ReplicationOption {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_node(&self.name);
+        if let Some(value) = &self.value {
+            f.write_str(" ");
+            f.write_node(value);
+        }
+    }
+}
+impl_display!(ReplicationOption);
+
+/// `IDENTIFY_SYSTEM`
+///
+/// Like the other replication commands, this is only accepted on replication
+/// connections.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IdentifySystemStatement {}
+
+impl AstDisplay for IdentifySystemStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("IDENTIFY_SYSTEM");
+    }
+}
+impl_display!(IdentifySystemStatement);
+
+/// `CREATE_REPLICATION_SLOT <name> [TEMPORARY] LOGICAL <plugin> [(<option> [, ...])]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CreateReplicationSlotStatement {
+    pub name: Ident,
+    pub temporary: bool,
+    /// The output plugin that decodes changes for the slot's consumers.
+    pub plugin: Ident,
+    pub options: Vec<ReplicationOption>,
+}
+
+impl AstDisplay for CreateReplicationSlotStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("CREATE_REPLICATION_SLOT ");
+        f.write_node(&self.name);
+        if self.temporary {
+            f.write_str(" TEMPORARY");
+        }
+        f.write_str(" LOGICAL ");
+        f.write_node(&self.plugin);
+        if !self.options.is_empty() {
+            f.write_str(" (");
+            f.write_node(&display::comma_separated(&self.options));
+            f.write_str(")");
+        }
+    }
+}
+impl_display!(CreateReplicationSlotStatement);
+
+/// `DROP_REPLICATION_SLOT <name> [WAIT]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DropReplicationSlotStatement {
+    pub name: Ident,
+    pub wait: bool,
+}
+
+impl AstDisplay for DropReplicationSlotStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("DROP_REPLICATION_SLOT ");
+        f.write_node(&self.name);
+        if self.wait {
+            f.write_str(" WAIT");
+        }
+    }
+}
+impl_display!(DropReplicationSlotStatement);
+
+/// `START_REPLICATION SLOT <name> LOGICAL <lsn> [(<option> [, ...])]`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StartReplicationStatement {
+    pub slot: Ident,
+    /// The log sequence number from which to start streaming changes.
+    pub lsn: u64,
+    pub options: Vec<ReplicationOption>,
+}
+
+impl AstDisplay for StartReplicationStatement {
+    fn fmt<W: fmt::Write>(&self, f: &mut AstFormatter<W>) {
+        f.write_str("START_REPLICATION SLOT ");
+        f.write_node(&self.slot);
+        f.write_str(" LOGICAL ");
+        // Log sequence numbers are written as two hexadecimal 32-bit halves.
+        f.write_str(format!("{:X}/{:X}", self.lsn >> 32, self.lsn & 0xFFFF_FFFF));
+        if !self.options.is_empty() {
+            f.write_str(" (");
+            f.write_node(&display::comma_separated(&self.options));
+            f.write_str(")");
+        }
+    }
+}
+impl_display!(StartReplicationStatement);
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NoticeSeverity {
     Debug,
@@ -138,6 +138,11 @@ where
         }
     }
 
+    /// Returns the current mode.
+    pub fn mode(&self) -> FormatMode {
+        self.mode
+    }
+
     pub fn set_mode(&mut self, mode: FormatMode) {
         self.mode = mode;
     }
@@ -163,6 +163,20 @@ pub fn parse_expr(sql: &str) -> Result<Expr<Raw>, ParserError> {
     }
 }
 
+/// Parses the body of a SQL function, which must contain a single query,
+/// optionally followed by a semicolon.
+fn parse_function_body(sql: &str) -> Result<Query<Raw>, ParserError> {
+    let tokens = lexer::lex(sql)?;
+    let mut parser = Parser::new(sql, tokens);
+    let query = parser.parse_query()?;
+    let _ = parser.consume_token(&Token::Semicolon);
+    if parser.next_token().is_some() {
+        parser_err!(parser, parser.peek_prev_pos(), "extra token after query")
+    } else {
+        Ok(query)
+    }
+}
+
 /// Parses a SQL string containing a single data type.
 pub fn parse_data_type(sql: &str) -> Result<RawDataType, ParserError> {
     let tokens = lexer::lex(sql)?;
@@ -179,6 +193,22 @@ pub fn parse_data_type(sql: &str) -> Result<RawDataType, ParserError> {
     }
 }
 
+/// Parses a SQL string containing a single, possibly qualified, item name.
+pub fn parse_item_name(sql: &str) -> Result<UnresolvedItemName, ParserError> {
+    let tokens = lexer::lex(sql)?;
+    let mut parser = Parser::new(sql, tokens);
+    let name = parser.parse_item_name()?;
+    if parser.next_token().is_some() {
+        parser_err!(
+            parser,
+            parser.peek_prev_pos(),
+            "extra token after item name"
+        )
+    } else {
+        Ok(name)
+    }
+}
+
 /// Parses a string containing a comma-separated list of identifiers and
 /// returns their underlying string values.
 ///
@@ -397,6 +427,9 @@ impl<'a> Parser<'a> {
                 Token::Keyword(UPDATE) => {
                     Ok(self.parse_update().map_parser_err(StatementKind::Update)?)
                 }
+                Token::Keyword(TRUNCATE) => Ok(self
+                    .parse_truncate()
+                    .map_parser_err(StatementKind::Truncate)?),
                 Token::Keyword(ALTER) => Ok(self.parse_alter()?),
                 Token::Keyword(COPY) => Ok(self.parse_copy()?),
                 Token::Keyword(SET) => Ok(self.parse_set()?),
@@ -445,6 +478,26 @@ impl<'a> Parser<'a> {
                 Token::Keyword(RAISE) => {
                     Ok(self.parse_raise().map_parser_err(StatementKind::Raise)?)
                 }
+                Token::Keyword(LISTEN) => {
+                    Ok(self.parse_listen().map_parser_err(StatementKind::Listen)?)
+                }
+                Token::Keyword(UNLISTEN) => Ok(self
+                    .parse_unlisten()
+                    .map_parser_err(StatementKind::Unlisten)?),
+                // Replication commands are spelled with underscores, so they
+                // are lexed as identifiers rather than keywords.
+                Token::Ident(id) if id.as_str() == "identify_system" => {
+                    Ok(Statement::IdentifySystem(IdentifySystemStatement {}))
+                }
+                Token::Ident(id) if id.as_str() == "create_replication_slot" => Ok(self
+                    .parse_create_replication_slot()
+                    .map_parser_err(StatementKind::CreateReplicationSlot)?),
+                Token::Ident(id) if id.as_str() == "drop_replication_slot" => Ok(self
+                    .parse_drop_replication_slot()
+                    .map_parser_err(StatementKind::DropReplicationSlot)?),
+                Token::Ident(id) if id.as_str() == "start_replication" => Ok(self
+                    .parse_start_replication()
+                    .map_parser_err(StatementKind::StartReplication)?),
                 Token::Keyword(GRANT) => Ok(self.parse_grant()?),
                 Token::Keyword(REVOKE) => Ok(self.parse_revoke()?),
                 Token::Keyword(REASSIGN) => Ok(self
@@ -757,6 +810,36 @@ impl<'a> Parser<'a> {
             ));
         }
 
+        let args = if self.parse_keywords(&[WITHIN, GROUP]) {
+            let pos = self.peek_prev_pos();
+            let args = match args {
+                FunctionArgs::Star => {
+                    return Err(self.error(pos, "cannot use * with WITHIN GROUP".to_string()));
+                }
+                FunctionArgs::Args { order_by, .. } if !order_by.is_empty() => {
+                    return Err(self.error(
+                        pos,
+                        "cannot use multiple ORDER BY clauses with WITHIN GROUP".to_string(),
+                    ));
+                }
+                FunctionArgs::Args { args, .. } => args,
+            };
+            if distinct {
+                return Err(self.error(pos, "cannot use DISTINCT with WITHIN GROUP".to_string()));
+            }
+            self.expect_token(&Token::LParen)?;
+            self.expect_keywords(&[ORDER, BY])?;
+            let order_by = self.parse_comma_separated(Parser::parse_order_by_expr)?;
+            self.expect_token(&Token::RParen)?;
+            FunctionArgs::Args {
+                args,
+                order_by,
+                within_group: true,
+            }
+        } else {
+            args
+        };
+
         let filter = if self.parse_keyword(FILTER) {
             self.expect_token(&Token::LParen)?;
             self.expect_keyword(WHERE)?;
@@ -1989,6 +2072,18 @@ impl<'a> Parser<'a> {
         } else if self.peek_keywords(&[NETWORK, POLICY]) {
             self.parse_create_network_policy()
                 .map_parser_err(StatementKind::CreateNetworkPolicy)
+        } else if self.peek_keyword(FUNCTION) || self.peek_keywords(&[OR, REPLACE, FUNCTION]) {
+            self.parse_create_function()
+                .map_parser_err(StatementKind::CreateFunction)
+        } else if self.peek_keyword(SEQUENCE) {
+            self.parse_create_sequence()
+                .map_parser_err(StatementKind::CreateSequence)
+        } else if self.peek_keyword(PUBLICATION) {
+            self.parse_create_publication()
+                .map_parser_err(StatementKind::CreatePublication)
+        } else if self.peek_keyword(POLICY) {
+            self.parse_create_policy()
+                .map_parser_err(StatementKind::CreatePolicy)
         } else {
             let index = self.index;
 
@@ -2004,13 +2099,13 @@ impl<'a> Parser<'a> {
                 let expected_msg = match (parsed_or_replace, parsed_temporary) {
                     (true, true) => "VIEW after CREATE OR REPLACE TEMPORARY",
                     (true, false) => {
-                        "[TEMPORARY] VIEW, or MATERIALIZED VIEW after CREATE OR REPLACE"
+                        "[TEMPORARY] VIEW, MATERIALIZED VIEW, or FUNCTION after CREATE OR REPLACE"
                     }
                     (false, true) => "TABLE, or VIEW after CREATE TEMPORARY",
                     (false, false) => {
                         "DATABASE, SCHEMA, ROLE, TYPE, INDEX, SINK, SOURCE, [TEMPORARY] TABLE, \
-                        SECRET, [OR REPLACE] [TEMPORARY] VIEW, or [OR REPLACE] MATERIALIZED VIEW \
-                        after CREATE"
+                        SECRET, [OR REPLACE] [TEMPORARY] VIEW, [OR REPLACE] MATERIALIZED VIEW, \
+                        [OR REPLACE] FUNCTION, or SEQUENCE after CREATE"
                     }
                 };
                 self.expected(self.peek_pos(), expected_msg, self.peek_token())
@@ -2402,16 +2497,30 @@ impl<'a> Parser<'a> {
             Ok(SinkEnvelope::Upsert)
         } else if self.parse_keyword(DEBEZIUM) {
             Ok(SinkEnvelope::Debezium)
+        } else if self.parse_keyword(CHANGELOG) {
+            Ok(SinkEnvelope::Changelog)
         } else {
-            self.expected(self.peek_pos(), "UPSERT, DEBEZIUM", self.peek_token())
+            self.expected(
+                self.peek_pos(),
+                "UPSERT, DEBEZIUM, CHANGELOG",
+                self.peek_token(),
+            )
         }
     }
 
-    fn parse_iceberg_sink_mode(&mut self) -> Result<IcebergSinkMode, ParserError> {
+    fn parse_sink_mode(&mut self) -> Result<SinkMode, ParserError> {
         if self.parse_keyword(UPSERT) {
-            Ok(IcebergSinkMode::Upsert)
+            Ok(SinkMode::Upsert)
+        } else if self.parse_keyword(APPEND) {
+            Ok(SinkMode::Append)
+        } else if self.parse_keyword(CHANGELOG) {
+            Ok(SinkMode::Changelog)
         } else {
-            self.expected(self.peek_pos(), "UPSERT", self.peek_token())
+            self.expected(
+                self.peek_pos(),
+                "UPSERT, APPEND, or CHANGELOG",
+                self.peek_token(),
+            )
         }
     }
 
@@ -2626,6 +2735,85 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_postgres_sink_config_option(
+        &mut self,
+    ) -> Result<PostgresSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[PROGRESS, TABLE])? {
+            PROGRESS => {
+                self.expect_keyword(TABLE)?;
+                PostgresSinkConfigOptionName::ProgressTable
+            }
+            TABLE => PostgresSinkConfigOptionName::Table,
+            _ => unreachable!(),
+        };
+        Ok(PostgresSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_mysql_sink_config_option(
+        &mut self,
+    ) -> Result<MySqlSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[PROGRESS, TABLE])? {
+            PROGRESS => {
+                self.expect_keyword(TABLE)?;
+                MySqlSinkConfigOptionName::ProgressTable
+            }
+            TABLE => MySqlSinkConfigOptionName::Table,
+            _ => unreachable!(),
+        };
+        Ok(MySqlSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_sql_server_sink_config_option(
+        &mut self,
+    ) -> Result<SqlServerSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[PROGRESS, TABLE])? {
+            PROGRESS => {
+                self.expect_keyword(TABLE)?;
+                SqlServerSinkConfigOptionName::ProgressTable
+            }
+            TABLE => SqlServerSinkConfigOptionName::Table,
+            _ => unreachable!(),
+        };
+        Ok(SqlServerSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_s3_sink_config_option(&mut self) -> Result<S3SinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[FORMAT, MAX, URL])? {
+            FORMAT => S3SinkConfigOptionName::Format,
+            MAX => {
+                self.expect_keywords(&[FILE, SIZE])?;
+                S3SinkConfigOptionName::MaxFileSize
+            }
+            URL => S3SinkConfigOptionName::Url,
+            _ => unreachable!(),
+        };
+        Ok(S3SinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
+    fn parse_http_sink_config_option(&mut self) -> Result<HttpSinkConfigOption<Raw>, ParserError> {
+        let name = match self.expect_one_of_keywords(&[HEADERS, URL])? {
+            HEADERS => HttpSinkConfigOptionName::Headers,
+            URL => HttpSinkConfigOptionName::Url,
+            _ => unreachable!(),
+        };
+        Ok(HttpSinkConfigOption {
+            name,
+            value: self.parse_optional_option_value()?,
+        })
+    }
+
     fn parse_kafka_sink_config_option(
         &mut self,
     ) -> Result<KafkaSinkConfigOption<Raw>, ParserError> {
@@ -3276,7 +3464,9 @@ impl<'a> Parser<'a> {
         })
     }
 
-    fn parse_create_iceberg_sink(
+    /// Parses the remainder of a `CREATE SINK` statement for sinks that are configured with
+    /// `MODE` rather than `FORMAT` and `ENVELOPE`.
+    fn parse_create_sink_with_mode(
         &mut self,
         name: Option<UnresolvedItemName>,
         in_cluster: Option<RawClusterName>,
@@ -3285,7 +3475,7 @@ impl<'a> Parser<'a> {
         connection: CreateSinkConnection<Raw>,
     ) -> Result<CreateSinkStatement<Raw>, ParserError> {
         let mode = if self.parse_keyword(MODE) {
-            Some(self.parse_iceberg_sink_mode()?)
+            Some(self.parse_sink_mode()?)
         } else {
             None
         };
@@ -3312,6 +3502,44 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses the remainder of a `CREATE SINK` statement for sinks that are configured with
+    /// `ENVELOPE` but, unlike Kafka sinks, without `FORMAT`.
+    fn parse_create_sink_with_envelope(
+        &mut self,
+        name: Option<UnresolvedItemName>,
+        in_cluster: Option<RawClusterName>,
+        from: RawItemName,
+        if_not_exists: bool,
+        connection: CreateSinkConnection<Raw>,
+    ) -> Result<CreateSinkStatement<Raw>, ParserError> {
+        let envelope = if self.parse_keyword(ENVELOPE) {
+            Some(self.parse_sink_envelope()?)
+        } else {
+            None
+        };
+
+        let with_options = if self.parse_keyword(WITH) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_create_sink_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
+        Ok(CreateSinkStatement {
+            name,
+            in_cluster,
+            from,
+            connection,
+            format: None,
+            envelope,
+            mode: None,
+            if_not_exists,
+            with_options,
+        })
+    }
+
     fn parse_create_kafka_sink(
         &mut self,
         name: Option<UnresolvedItemName>,
@@ -3391,8 +3619,14 @@ impl<'a> Parser<'a> {
             conn @ CreateSinkConnection::Kafka { .. } => {
                 self.parse_create_kafka_sink(name, in_cluster, from, if_not_exists, conn)
             }
-            conn @ CreateSinkConnection::Iceberg { .. } => {
-                self.parse_create_iceberg_sink(name, in_cluster, from, if_not_exists, conn)
+            conn @ (CreateSinkConnection::Iceberg { .. }
+            | CreateSinkConnection::MySql { .. }
+            | CreateSinkConnection::SqlServer { .. }
+            | CreateSinkConnection::S3 { .. }) => {
+                self.parse_create_sink_with_mode(name, in_cluster, from, if_not_exists, conn)
+            }
+            conn @ (CreateSinkConnection::Postgres { .. } | CreateSinkConnection::Http { .. }) => {
+                self.parse_create_sink_with_envelope(name, in_cluster, from, if_not_exists, conn)
             }
         }?;
 
@@ -3844,13 +4078,167 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_create_postgres_sink_connection(
+        &mut self,
+    ) -> Result<CreateSinkConnection<Raw>, ParserError> {
+        self.expect_keyword(CONNECTION)?;
+        let connection = self.parse_raw_name()?;
+
+        let options = if self.consume_token(&Token::LParen) {
+            let options = self.parse_comma_separated(Parser::parse_postgres_sink_config_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
+        let key = if self.parse_keyword(KEY) {
+            let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+
+            let not_enforced = self.parse_keywords(&[NOT, ENFORCED]);
+            Some(SinkKey {
+                key_columns,
+                not_enforced,
+            })
+        } else {
+            None
+        };
+
+        Ok(CreateSinkConnection::Postgres {
+            connection,
+            options,
+            key,
+        })
+    }
+
+    fn parse_create_mysql_sink_connection(
+        &mut self,
+    ) -> Result<CreateSinkConnection<Raw>, ParserError> {
+        self.expect_keyword(CONNECTION)?;
+        let connection = self.parse_raw_name()?;
+
+        let options = if self.consume_token(&Token::LParen) {
+            let options = self.parse_comma_separated(Parser::parse_mysql_sink_config_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
+        let key = if self.parse_keyword(KEY) {
+            let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+
+            let not_enforced = self.parse_keywords(&[NOT, ENFORCED]);
+            Some(SinkKey {
+                key_columns,
+                not_enforced,
+            })
+        } else {
+            None
+        };
+
+        Ok(CreateSinkConnection::MySql {
+            connection,
+            options,
+            key,
+        })
+    }
+
+    fn parse_create_sql_server_sink_connection(
+        &mut self,
+    ) -> Result<CreateSinkConnection<Raw>, ParserError> {
+        self.expect_keyword(CONNECTION)?;
+        let connection = self.parse_raw_name()?;
+
+        let options = if self.consume_token(&Token::LParen) {
+            let options =
+                self.parse_comma_separated(Parser::parse_sql_server_sink_config_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
+        let key = if self.parse_keyword(KEY) {
+            let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+
+            let not_enforced = self.parse_keywords(&[NOT, ENFORCED]);
+            Some(SinkKey {
+                key_columns,
+                not_enforced,
+            })
+        } else {
+            None
+        };
+
+        Ok(CreateSinkConnection::SqlServer {
+            connection,
+            options,
+            key,
+        })
+    }
+
+    fn parse_create_s3_sink_connection(
+        &mut self,
+    ) -> Result<CreateSinkConnection<Raw>, ParserError> {
+        self.expect_keyword(CONNECTION)?;
+        let connection = self.parse_raw_name()?;
+
+        let options = if self.consume_token(&Token::LParen) {
+            let options = self.parse_comma_separated(Parser::parse_s3_sink_config_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
+        Ok(CreateSinkConnection::S3 {
+            connection,
+            options,
+        })
+    }
+
+    fn parse_create_http_sink_connection(
+        &mut self,
+    ) -> Result<CreateSinkConnection<Raw>, ParserError> {
+        let options = if self.consume_token(&Token::LParen) {
+            let options = self.parse_comma_separated(Parser::parse_http_sink_config_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
+
+        let key = if self.parse_keyword(KEY) {
+            let key_columns = self.parse_parenthesized_column_list(Mandatory)?;
+
+            let not_enforced = self.parse_keywords(&[NOT, ENFORCED]);
+            Some(SinkKey {
+                key_columns,
+                not_enforced,
+            })
+        } else {
+            None
+        };
+
+        Ok(CreateSinkConnection::Http { options, key })
+    }
+
     fn parse_create_sink_connection(&mut self) -> Result<CreateSinkConnection<Raw>, ParserError> {
-        match self.expect_one_of_keywords(&[KAFKA, ICEBERG])? {
+        match self.expect_one_of_keywords(&[KAFKA, ICEBERG, POSTGRES, MYSQL, SQL, S3, HTTP])? {
             KAFKA => self.parse_create_kafka_sink_connection(),
             ICEBERG => {
                 self.expect_keyword(CATALOG)?;
                 self.parse_create_iceberg_sink_connection()
             }
+            POSTGRES => self.parse_create_postgres_sink_connection(),
+            MYSQL => self.parse_create_mysql_sink_connection(),
+            SQL => {
+                self.expect_keyword(SERVER)?;
+                self.parse_create_sql_server_sink_connection()
+            }
+            S3 => self.parse_create_s3_sink_connection(),
+            HTTP => self.parse_create_http_sink_connection(),
             _ => unreachable!(),
         }
     }
@@ -3880,17 +4268,32 @@ impl<'a> Parser<'a> {
         // ANSI SQL and Postgres support RECURSIVE here, but we don't.
         let name = self.parse_item_name()?;
         let columns = self.parse_parenthesized_column_list(Optional)?;
-        // Postgres supports WITH options here, but we don't.
+        let with_options = if self.parse_keyword(WITH) {
+            self.expect_token(&Token::LParen)?;
+            let options = self.parse_comma_separated(Parser::parse_view_option)?;
+            self.expect_token(&Token::RParen)?;
+            options
+        } else {
+            vec![]
+        };
         self.expect_keyword(AS)?;
         let query = self.parse_query()?;
         // Optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` is widely supported here.
         Ok(ViewDefinition {
             name,
             columns,
+            with_options,
             query,
         })
     }
 
+    fn parse_view_option(&mut self) -> Result<ViewOption<Raw>, ParserError> {
+        self.expect_keywords(&[ROW, LEVEL, SECURITY])?;
+        let name = ViewOptionName::RowLevelSecurity;
+        let value = self.parse_optional_option_value()?;
+        Ok(ViewOption { name, value })
+    }
+
     fn parse_create_materialized_view(&mut self) -> Result<Statement<Raw>, ParserError> {
         let mut if_exists = if self.parse_keyword(OR) {
             self.expect_keyword(REPLACE)?;
@@ -4118,6 +4521,7 @@ impl<'a> Parser<'a> {
                         relation: TableFactor::Table {
                             name: input.clone(),
                             alias: None,
+                            sample: None,
                         },
                         joins: Vec::new(),
                     }],
@@ -4167,7 +4571,8 @@ impl<'a> Parser<'a> {
     fn parse_materialized_view_option_name(
         &mut self,
     ) -> Result<MaterializedViewOptionName, ParserError> {
-        let option = self.expect_one_of_keywords(&[ASSERT, PARTITION, RETAIN, REFRESH])?;
+        let option =
+            self.expect_one_of_keywords(&[ASSERT, PARTITION, RETAIN, REFRESH, NOTIFY, ROW])?;
         let name = match option {
             ASSERT => {
                 self.expect_keywords(&[NOT, NULL])?;
@@ -4182,6 +4587,14 @@ impl<'a> Parser<'a> {
                 MaterializedViewOptionName::RetainHistory
             }
             REFRESH => MaterializedViewOptionName::Refresh,
+            NOTIFY => {
+                self.expect_keyword(CHANNEL)?;
+                MaterializedViewOptionName::NotifyChannel
+            }
+            ROW => {
+                self.expect_keywords(&[LEVEL, SECURITY])?;
+                MaterializedViewOptionName::RowLevelSecurity
+            }
             _ => unreachable!(),
         };
         Ok(name)
@@ -4347,7 +4760,7 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(REDACTED) {
             return Ok(TableOptionName::RedactedTest);
         }
-        let name = match self.expect_one_of_keywords(&[PARTITION, RETAIN])? {
+        let name = match self.expect_one_of_keywords(&[PARTITION, RETAIN, ROW])? {
             PARTITION => {
                 self.expect_keyword(BY)?;
                 TableOptionName::PartitionBy
@@ -4356,6 +4769,10 @@ impl<'a> Parser<'a> {
                 self.expect_keyword(HISTORY)?;
                 TableOptionName::RetainHistory
             }
+            ROW => {
+                self.expect_keywords(&[LEVEL, SECURITY])?;
+                TableOptionName::RowLevelSecurity
+            }
             _ => unreachable!(),
         };
         Ok(name)
@@ -4366,6 +4783,7 @@ impl<'a> Parser<'a> {
         let value = match name {
             TableOptionName::PartitionBy => self.parse_optional_option_value(),
             TableOptionName::RetainHistory => self.parse_option_retain_history(),
+            TableOptionName::RowLevelSecurity => self.parse_optional_option_value(),
             TableOptionName::RedactedTest => self.parse_optional_option_value(),
         }?;
         Ok(TableOption { name, value })
@@ -4522,6 +4940,114 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn parse_create_function(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = if self.parse_keywords(&[OR, REPLACE]) {
+            IfExistsBehavior::Replace
+        } else {
+            IfExistsBehavior::Error
+        };
+        self.expect_keyword(FUNCTION)?;
+        let name = self.parse_item_name()?;
+
+        self.expect_token(&Token::LParen)?;
+        let params = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let params = self.parse_comma_separated(|parser| {
+                Ok(FunctionParameter {
+                    name: parser.parse_identifier()?,
+                    data_type: parser.parse_data_type()?,
+                })
+            })?;
+            self.expect_token(&Token::RParen)?;
+            params
+        };
+
+        self.expect_keyword(RETURNS)?;
+        let returns = if self.parse_keyword(TABLE) {
+            FunctionReturns::Table(self.parse_composite_type_definition()?)
+        } else {
+            FunctionReturns::Scalar(self.parse_data_type()?)
+        };
+
+        self.expect_keywords(&[LANGUAGE, SQL])?;
+        self.expect_keyword(AS)?;
+        let body_pos = self.peek_pos();
+        let body = self.parse_literal_string()?;
+        let body = parse_function_body(&body)
+            .map_err(|e| self.error(body_pos, format!("invalid function body: {}", e.message)))?;
+
+        Ok(Statement::CreateFunction(CreateFunctionStatement {
+            name,
+            if_exists,
+            params,
+            returns,
+            body,
+        }))
+    }
+
+    fn parse_create_sequence(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(SEQUENCE)?;
+        let if_not_exists = self.parse_if_not_exists()?;
+        let name = self.parse_item_name()?;
+        let mut options = vec![];
+        while let Some(option) = self.parse_optional_sequence_option()? {
+            options.push(option);
+        }
+        Ok(Statement::CreateSequence(CreateSequenceStatement {
+            name,
+            if_not_exists,
+            options,
+        }))
+    }
+
+    fn parse_create_policy(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(POLICY)?;
+        let name = self.parse_identifier()?;
+        self.expect_keyword(ON)?;
+        let on_name = self.parse_raw_name()?;
+        let roles = if self.parse_keyword(TO) {
+            self.parse_comma_separated(Parser::expect_role_specification)?
+        } else {
+            vec![]
+        };
+        self.expect_keyword(USING)?;
+        self.expect_token(&Token::LParen)?;
+        let using = self.parse_expr()?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Statement::CreatePolicy(CreatePolicyStatement {
+            name,
+            on_name,
+            roles,
+            using,
+        }))
+    }
+
+    fn parse_optional_sequence_option(&mut self) -> Result<Option<SequenceOption>, ParserError> {
+        let option = if self.parse_keyword(INCREMENT) {
+            let _ = self.parse_keyword(BY);
+            SequenceOption::IncrementBy(self.parse_literal_int()?)
+        } else if self.parse_keyword(MINVALUE) {
+            SequenceOption::MinValue(Some(self.parse_literal_int()?))
+        } else if self.parse_keyword(MAXVALUE) {
+            SequenceOption::MaxValue(Some(self.parse_literal_int()?))
+        } else if self.parse_keyword(START) {
+            let _ = self.parse_keyword(WITH);
+            SequenceOption::StartWith(self.parse_literal_int()?)
+        } else if self.parse_keyword(CYCLE) {
+            SequenceOption::Cycle(true)
+        } else if self.parse_keywords(&[NO, MINVALUE]) {
+            SequenceOption::MinValue(None)
+        } else if self.parse_keywords(&[NO, MAXVALUE]) {
+            SequenceOption::MaxValue(None)
+        } else if self.parse_keywords(&[NO, CYCLE]) {
+            SequenceOption::Cycle(false)
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(option))
+    }
+
     fn parse_create_type_list_option(&mut self) -> Result<CreateTypeListOption<Raw>, ParserError> {
         self.expect_keywords(&[ELEMENT, TYPE])?;
         let name = CreateTypeListOptionName::ElementType;
@@ -4883,6 +5409,9 @@ impl<'a> Parser<'a> {
         if self.parse_keyword(OWNED) {
             self.parse_drop_owned()
                 .map_parser_err(StatementKind::DropOwned)
+        } else if self.parse_keyword(PUBLICATION) {
+            self.parse_drop_publication()
+                .map_parser_err(StatementKind::DropPublication)
         } else {
             self.parse_drop_objects()
                 .map_parser_err(StatementKind::DropObjects)
@@ -4957,6 +5486,9 @@ impl<'a> Parser<'a> {
             | ObjectType::Type
             | ObjectType::Secret
             | ObjectType::Connection
+            | ObjectType::Func
+            | ObjectType::Sequence
+            | ObjectType::Policy
             | ObjectType::ContinualTask => {
                 let names = self.parse_comma_separated(|parser| {
                     Ok(UnresolvedObjectName::Item(parser.parse_item_name()?))
@@ -4972,7 +5504,7 @@ impl<'a> Parser<'a> {
                     cascade,
                 }))
             }
-            ObjectType::Func | ObjectType::Subsource => parser_err!(
+            ObjectType::Subsource => parser_err!(
                 self,
                 self.peek_prev_pos(),
                 format!("Unsupported DROP on {object_type}")
@@ -5462,11 +5994,37 @@ impl<'a> Parser<'a> {
             self.expect_token(&Token::RParen)?;
             ColumnOption::Check(expr)
         } else if self.parse_keyword(VERSION) {
-            self.expect_keyword(ADDED)?;
-            let action = ColumnVersioned::Added;
+            let action = match self.expect_one_of_keywords(&[ADDED, DROPPED, RENAMED])? {
+                ADDED => ColumnVersioned::Added,
+                DROPPED => ColumnVersioned::Dropped,
+                RENAMED => {
+                    self.expect_keyword(FROM)?;
+                    let from = self.parse_identifier()?;
+                    ColumnVersioned::Renamed { from }
+                }
+                _ => unreachable!(),
+            };
             let version = self.parse_version()?;
 
             ColumnOption::Versioned { action, version }
+        } else if self.parse_keyword(GENERATED) {
+            let always = match self.expect_one_of_keywords(&[ALWAYS, BY])? {
+                ALWAYS => true,
+                BY => {
+                    self.expect_keyword(DEFAULT)?;
+                    false
+                }
+                _ => unreachable!(),
+            };
+            self.expect_keywords(&[AS, IDENTITY])?;
+            let mut options = vec![];
+            if self.consume_token(&Token::LParen) {
+                while let Some(option) = self.parse_optional_sequence_option()? {
+                    options.push(option);
+                }
+                self.expect_token(&Token::RParen)?;
+            }
+            ColumnOption::Identity { always, options }
         } else {
             return self.expected(self.peek_pos(), "column option", self.peek_token());
         };
@@ -5755,7 +6313,10 @@ impl<'a> Parser<'a> {
             ObjectType::NetworkPolicy => self
                 .parse_alter_network_policy()
                 .map_parser_err(StatementKind::AlterNetworkPolicy),
-            ObjectType::Func | ObjectType::Subsource => parser_err!(
+            ObjectType::Func
+            | ObjectType::Sequence
+            | ObjectType::Subsource
+            | ObjectType::Policy => parser_err!(
                 self,
                 self.peek_prev_pos(),
                 format!("Unsupported ALTER on {object_type}")
@@ -6498,9 +7059,10 @@ impl<'a> Parser<'a> {
         let if_exists = self.parse_if_exists().map_no_statement_parser_err()?;
         let name = self.parse_item_name().map_no_statement_parser_err()?;
         let keywords: &[_] = match object_type {
-            ObjectType::Table => &[SET, RENAME, OWNER, RESET, ADD],
-            ObjectType::MaterializedView => &[SET, RENAME, OWNER, RESET, APPLY],
-            ObjectType::View | ObjectType::ContinualTask => &[SET, RENAME, OWNER, RESET],
+            ObjectType::Table => &[SET, RENAME, OWNER, RESET, ADD, DROP, ENABLE, DISABLE],
+            ObjectType::MaterializedView => &[SET, RENAME, OWNER, RESET, APPLY, ENABLE, DISABLE],
+            ObjectType::View => &[SET, RENAME, OWNER, RESET, ENABLE, DISABLE],
+            ObjectType::ContinualTask => &[SET, RENAME, OWNER, RESET],
             ObjectType::Source
             | ObjectType::Sink
             | ObjectType::Index
@@ -6513,8 +7075,10 @@ impl<'a> Parser<'a> {
             | ObjectType::Database
             | ObjectType::Schema
             | ObjectType::Func
+            | ObjectType::Sequence
             | ObjectType::Subsource
-            | ObjectType::NetworkPolicy => {
+            | ObjectType::NetworkPolicy
+            | ObjectType::Policy => {
                 unreachable!("parse_alter_views called with unsupported object type: {object_type}")
             }
         };
@@ -6523,6 +7087,25 @@ impl<'a> Parser<'a> {
             .expect_one_of_keywords(keywords)
             .map_no_statement_parser_err()?;
         match action {
+            RENAME if object_type == ObjectType::Table && !self.peek_keyword(TO) => {
+                let _ = self.parse_keyword(COLUMN);
+                let column_name = self
+                    .parse_identifier()
+                    .map_parser_err(StatementKind::AlterTableRenameColumn)?;
+                self.expect_keyword(TO)
+                    .map_parser_err(StatementKind::AlterTableRenameColumn)?;
+                let to_column_name = self
+                    .parse_identifier()
+                    .map_parser_err(StatementKind::AlterTableRenameColumn)?;
+                Ok(Statement::AlterTableRenameColumn(
+                    AlterTableRenameColumnStatement {
+                        if_exists,
+                        name,
+                        column_name,
+                        to_column_name,
+                    },
+                ))
+            }
             RENAME => {
                 self.expect_keyword(TO).map_no_statement_parser_err()?;
                 let to_item_name = self
@@ -6582,6 +7165,18 @@ impl<'a> Parser<'a> {
                     new_owner,
                 }))
             }
+            action @ (ENABLE | DISABLE) => {
+                self.expect_keywords(&[ROW, LEVEL, SECURITY])
+                    .map_parser_err(StatementKind::AlterRowLevelSecurity)?;
+                Ok(Statement::AlterRowLevelSecurity(
+                    AlterRowLevelSecurityStatement {
+                        object_type,
+                        if_exists,
+                        name,
+                        enabled: action == ENABLE,
+                    },
+                ))
+            }
             ADD => {
                 assert_eq!(object_type, ObjectType::Table, "checked object_type above");
 
@@ -6607,6 +7202,27 @@ impl<'a> Parser<'a> {
                     },
                 ))
             }
+            DROP => {
+                assert_eq!(object_type, ObjectType::Table, "checked object_type above");
+
+                self.expect_keyword(COLUMN)
+                    .map_parser_err(StatementKind::AlterTableDropColumn)?;
+                let if_col_exists = self
+                    .parse_if_exists()
+                    .map_parser_err(StatementKind::AlterTableDropColumn)?;
+                let column_name = self
+                    .parse_identifier()
+                    .map_parser_err(StatementKind::AlterTableDropColumn)?;
+
+                Ok(Statement::AlterTableDropColumn(
+                    AlterTableDropColumnStatement {
+                        if_exists,
+                        name,
+                        if_col_exists,
+                        column_name,
+                    },
+                ))
+            }
             APPLY => {
                 assert_eq!(
                     object_type,
@@ -7353,6 +7969,8 @@ impl<'a> Parser<'a> {
             | ObjectType::Secret
             | ObjectType::Connection
             | ObjectType::Func
+            | ObjectType::Sequence
+            | ObjectType::Policy
             | ObjectType::ContinualTask => UnresolvedObjectName::Item(self.parse_item_name()?),
             ObjectType::Role => UnresolvedObjectName::Role(self.parse_identifier()?),
             ObjectType::Cluster => UnresolvedObjectName::Cluster(self.parse_identifier()?),
@@ -7511,6 +8129,13 @@ impl<'a> Parser<'a> {
         }))
     }
 
+    fn parse_truncate(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let _ = self.parse_keyword(TABLE);
+        let table_names =
+            self.parse_comma_separated(|parser| Ok(RawItemName::Name(parser.parse_item_name()?)))?;
+        Ok(Statement::Truncate(TruncateStatement { table_names }))
+    }
+
     /// Parses a SELECT (or WITH, VALUES, TABLE) statement with optional AS OF.
     fn parse_select_statement(&mut self) -> Result<SelectStatement<Raw>, ParserError> {
         Ok(SelectStatement {
@@ -7899,7 +8524,7 @@ impl<'a> Parser<'a> {
         };
 
         let group_by = if self.parse_keywords(&[GROUP, BY]) {
-            self.parse_comma_separated(Parser::parse_expr)?
+            self.parse_comma_separated(Parser::parse_grouping_element)?
         } else {
             vec![]
         };
@@ -7937,6 +8562,75 @@ impl<'a> Parser<'a> {
         })
     }
 
+    /// Parses a top-level element of a `GROUP BY` clause.
+    fn parse_grouping_element(&mut self) -> Result<GroupingElement<Raw>, ParserError> {
+        if let Some(elem) = self.parse_grouping_set_constructor()? {
+            Ok(elem)
+        } else if self.peek_token() == Some(Token::LParen)
+            && self.peek_nth_token(1) == Some(Token::RParen)
+        {
+            self.next_token();
+            self.next_token();
+            Ok(GroupingElement::List(vec![]))
+        } else {
+            Ok(GroupingElement::Expr(self.parse_expr()?))
+        }
+    }
+
+    /// Parses a `ROLLUP (...)`, `CUBE (...)`, or `GROUPING SETS (...)`
+    /// construct, if one is next in the token stream.
+    fn parse_grouping_set_constructor(
+        &mut self,
+    ) -> Result<Option<GroupingElement<Raw>>, ParserError> {
+        // `ROLLUP` and `CUBE` are not reserved, so they only introduce a
+        // grouping set construct when followed by an opening parenthesis.
+        let (constructor, allow_nested): (
+            fn(Vec<GroupingElement<Raw>>) -> GroupingElement<Raw>,
+            _,
+        ) = if self.peek_keyword(ROLLUP) && self.peek_nth_token(1) == Some(Token::LParen) {
+            self.next_token();
+            (GroupingElement::Rollup, false)
+        } else if self.peek_keyword(CUBE) && self.peek_nth_token(1) == Some(Token::LParen) {
+            self.next_token();
+            (GroupingElement::Cube, false)
+        } else if self.parse_keywords(&[GROUPING, SETS]) {
+            (GroupingElement::GroupingSets, true)
+        } else {
+            return Ok(None);
+        };
+        self.expect_token(&Token::LParen)?;
+        let elems = self.parse_comma_separated(|parser| {
+            if allow_nested {
+                if let Some(elem) =
+                    parser.checked_recur_mut(Parser::parse_grouping_set_constructor)?
+                {
+                    return Ok(elem);
+                }
+            }
+            parser.parse_grouping_set_element()
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok(Some(constructor(elems)))
+    }
+
+    /// Parses an expression or a parenthesized list of expressions within a
+    /// grouping set construct.
+    fn parse_grouping_set_element(&mut self) -> Result<GroupingElement<Raw>, ParserError> {
+        if self.peek_token() != Some(Token::LParen) {
+            return Ok(GroupingElement::Expr(self.parse_expr()?));
+        }
+        if self.peek_nth_token(1) == Some(Token::RParen) {
+            self.next_token();
+            self.next_token();
+            return Ok(GroupingElement::List(vec![]));
+        }
+        Ok(match self.parse_expr()? {
+            Expr::Row { exprs } => GroupingElement::List(exprs),
+            Expr::Nested(expr) => GroupingElement::List(vec![*expr]),
+            expr => GroupingElement::Expr(expr),
+        })
+    }
+
     fn parse_select_option(&mut self) -> Result<SelectOption<Raw>, ParserError> {
         let name = match self.expect_one_of_keywords(&[EXPECTED, AGGREGATE, DISTINCT, LIMIT])? {
             EXPECTED => {
@@ -8177,7 +8871,7 @@ impl<'a> Parser<'a> {
                         on_object,
                     }
                 }
-                ObjectType::Func => {
+                ObjectType::Func | ObjectType::Sequence | ObjectType::Policy => {
                     return parser_err!(
                         self,
                         self.peek_prev_pos(),
@@ -8513,14 +9207,47 @@ impl<'a> Parser<'a> {
                     with_ordinality,
                 })
             } else {
+                let alias = self.parse_optional_table_alias()?;
+                let sample = if self.parse_keyword(TABLESAMPLE) {
+                    Some(self.parse_table_sample()?)
+                } else {
+                    None
+                };
                 Ok(TableFactor::Table {
                     name,
-                    alias: self.parse_optional_table_alias()?,
+                    alias,
+                    sample,
                 })
             }
         }
     }
 
+    /// Parses the remainder of a `TABLESAMPLE` clause, assuming the
+    /// `TABLESAMPLE` keyword has already been consumed.
+    fn parse_table_sample(&mut self) -> Result<TableSample<Raw>, ParserError> {
+        let method = match self.expect_one_of_keywords(&[BERNOULLI, SYSTEM])? {
+            BERNOULLI => TableSampleMethod::Bernoulli,
+            SYSTEM => TableSampleMethod::System,
+            _ => unreachable!(),
+        };
+        self.expect_token(&Token::LParen)?;
+        let percentage = self.parse_expr()?;
+        self.expect_token(&Token::RParen)?;
+        let repeatable = if self.parse_keyword(REPEATABLE) {
+            self.expect_token(&Token::LParen)?;
+            let seed = self.parse_expr()?;
+            self.expect_token(&Token::RParen)?;
+            Some(seed)
+        } else {
+            None
+        };
+        Ok(TableSample {
+            method,
+            percentage,
+            repeatable,
+        })
+    }
+
     fn parse_rows_from(&mut self) -> Result<TableFactor<Raw>, ParserError> {
         self.expect_token(&Token::LParen)?;
         let functions = self.parse_comma_separated(Parser::parse_named_function)?;
@@ -8676,7 +9403,11 @@ impl<'a> Parser<'a> {
                 vec![]
             };
             self.expect_token(&Token::RParen)?;
-            Ok(FunctionArgs::Args { args, order_by })
+            Ok(FunctionArgs::Args {
+                args,
+                order_by,
+                within_group: false,
+            })
         }
     }
 
@@ -9444,6 +10175,152 @@ impl<'a> Parser<'a> {
         Ok(Statement::Raise(RaiseStatement { severity }))
     }
 
+    /// Parse a `LISTEN` statement, assuming that the `LISTEN` token has
+    /// already been consumed.
+    fn parse_listen(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = self.parse_identifier()?;
+        Ok(Statement::Listen(ListenStatement { channel }))
+    }
+
+    /// Parse an `UNLISTEN` statement, assuming that the `UNLISTEN` token has
+    /// already been consumed.
+    fn parse_unlisten(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let channel = if self.consume_token(&Token::Star) {
+            None
+        } else {
+            Some(self.parse_identifier()?)
+        };
+        Ok(Statement::Unlisten(UnlistenStatement { channel }))
+    }
+
+    /// Parse a `CREATE PUBLICATION` statement, assuming that the `CREATE`
+    /// token has already been consumed.
+    fn parse_create_publication(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(PUBLICATION)?;
+        let name = self.parse_identifier()?;
+        self.expect_keywords(&[FOR, TABLE])?;
+        let relations =
+            self.parse_comma_separated(|parser| Ok(RawItemName::Name(parser.parse_item_name()?)))?;
+        Ok(Statement::CreatePublication(CreatePublicationStatement {
+            name,
+            relations,
+        }))
+    }
+
+    /// Parse a `DROP PUBLICATION` statement, assuming that the `DROP
+    /// PUBLICATION` tokens have already been consumed.
+    fn parse_drop_publication(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let if_exists = self.parse_if_exists()?;
+        let name = self.parse_identifier()?;
+        Ok(Statement::DropPublication(DropPublicationStatement {
+            name,
+            if_exists,
+        }))
+    }
+
+    /// Parse a `CREATE_REPLICATION_SLOT` replication command, assuming that
+    /// the `CREATE_REPLICATION_SLOT` token has already been consumed.
+    fn parse_create_replication_slot(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let name = self.parse_identifier()?;
+        let temporary = self.parse_keyword(TEMPORARY);
+        self.expect_keyword(LOGICAL)?;
+        let plugin = self.parse_identifier()?;
+        let options = if self.peek_token() == Some(Token::LParen) {
+            self.parse_replication_options()?
+        } else {
+            // Options may also be given without parentheses, using the syntax
+            // that predates PostgreSQL 15, e.g. `NOEXPORT_SNAPSHOT`.
+            let mut options = vec![];
+            while let Some(name) = self.consume_identifier()? {
+                options.push(ReplicationOption { name, value: None });
+            }
+            options
+        };
+        Ok(Statement::CreateReplicationSlot(
+            CreateReplicationSlotStatement {
+                name,
+                temporary,
+                plugin,
+                options,
+            },
+        ))
+    }
+
+    /// Parse a `DROP_REPLICATION_SLOT` replication command, assuming that the
+    /// `DROP_REPLICATION_SLOT` token has already been consumed.
+    fn parse_drop_replication_slot(&mut self) -> Result<Statement<Raw>, ParserError> {
+        let name = self.parse_identifier()?;
+        let wait = self.parse_keyword(WAIT);
+        Ok(Statement::DropReplicationSlot(
+            DropReplicationSlotStatement { name, wait },
+        ))
+    }
+
+    /// Parse a `START_REPLICATION` replication command, assuming that the
+    /// `START_REPLICATION` token has already been consumed.
+    fn parse_start_replication(&mut self) -> Result<Statement<Raw>, ParserError> {
+        self.expect_keyword(SLOT)?;
+        let slot = self.parse_identifier()?;
+        self.expect_keyword(LOGICAL)?;
+        let lsn = self.parse_lsn()?;
+        let options = if self.peek_token() == Some(Token::LParen) {
+            self.parse_replication_options()?
+        } else {
+            vec![]
+        };
+        Ok(Statement::StartReplication(StartReplicationStatement {
+            slot,
+            lsn,
+            options,
+        }))
+    }
+
+    /// Parse a parenthesized list of replication command options, like
+    /// `(proto_version '1', publication_names 'p')`.
+    fn parse_replication_options(&mut self) -> Result<Vec<ReplicationOption>, ParserError> {
+        self.expect_token(&Token::LParen)?;
+        let options = self.parse_comma_separated(|parser| {
+            let name = parser.parse_identifier()?;
+            let value = match parser.peek_token() {
+                Some(Token::Comma | Token::RParen) => None,
+                _ => Some(parser.parse_value()?),
+            };
+            Ok(ReplicationOption { name, value })
+        })?;
+        self.expect_token(&Token::RParen)?;
+        Ok(options)
+    }
+
+    /// Parse a log sequence number, which is written as two hexadecimal
+    /// numbers separated by a slash, like `16/B374D848`.
+    fn parse_lsn(&mut self) -> Result<u64, ParserError> {
+        // The lexer splits a log sequence number into several adjacent tokens,
+        // e.g. `16`, `/`, `b374`, and `d848`, so reassemble it from the
+        // source text.
+        let start = self.peek_pos();
+        loop {
+            match self.peek_token() {
+                Some(Token::Number(_) | Token::Ident(_) | Token::Keyword(_)) => {}
+                Some(Token::Op(op)) if op == "/" => {}
+                _ => break,
+            }
+            self.next_token();
+        }
+        let text = self.sql[start..self.peek_pos()].trim_end();
+        let lsn = text.split_once('/').and_then(|(hi, lo)| {
+            let hi = u32::from_str_radix(hi, 16).ok()?;
+            let lo = u32::from_str_radix(lo, 16).ok()?;
+            Some((u64::from(hi) << 32) | u64::from(lo))
+        });
+        match lsn {
+            Some(lsn) => Ok(lsn),
+            None if text.is_empty() => {
+                self.expected(start, "log sequence number", self.peek_token())
+            }
+            None => parser_err!(self, start, "invalid log sequence number: {}", text),
+        }
+    }
+
     /// Parse a `GRANT` statement, assuming that the `GRANT` token
     /// has already been consumed.
     fn parse_grant(&mut self) -> Result<Statement<Raw>, ParserStatementError> {
@@ -9637,7 +10514,9 @@ impl<'a> Parser<'a> {
             | ObjectType::ClusterReplica
             | ObjectType::Role
             | ObjectType::Func
-            | ObjectType::Subsource => {
+            | ObjectType::Sequence
+            | ObjectType::Subsource
+            | ObjectType::Policy => {
                 parser_err!(
                     self,
                     self.peek_prev_pos(),
@@ -9674,8 +10553,10 @@ impl<'a> Parser<'a> {
                 DATABASE,
                 SCHEMA,
                 FUNCTION,
+                SEQUENCE,
                 CONTINUAL,
                 NETWORK,
+                POLICY,
             ])? {
                 TABLE => ObjectType::Table,
                 VIEW => ObjectType::View,
@@ -9703,6 +10584,7 @@ impl<'a> Parser<'a> {
                 DATABASE => ObjectType::Database,
                 SCHEMA => ObjectType::Schema,
                 FUNCTION => ObjectType::Func,
+                SEQUENCE => ObjectType::Sequence,
                 CONTINUAL => {
                     if let Err(e) = self.expect_keyword(TASK) {
                         self.prev_token();
@@ -9717,6 +10599,7 @@ impl<'a> Parser<'a> {
                     }
                     ObjectType::NetworkPolicy
                 }
+                POLICY => ObjectType::Policy,
                 _ => unreachable!(),
             },
         )
@@ -9741,6 +10624,7 @@ impl<'a> Parser<'a> {
                 DATABASE,
                 SCHEMA,
                 FUNCTION,
+                SEQUENCE,
             ])? {
                 TABLE => ObjectType::Table,
                 VIEW => ObjectType::View,
@@ -9769,6 +10653,7 @@ impl<'a> Parser<'a> {
                 DATABASE => ObjectType::Database,
                 SCHEMA => ObjectType::Schema,
                 FUNCTION => ObjectType::Func,
+                SEQUENCE => ObjectType::Sequence,
                 _ => unreachable!(),
             },
         )
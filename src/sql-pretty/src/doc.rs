@@ -780,6 +780,13 @@ impl Pretty {
                 ")",
             ));
         }
+        if !v.with_options.is_empty() {
+            docs.push(bracket(
+                "WITH (",
+                comma_separate(|wo| self.doc_display_pass(wo), &v.with_options),
+                ")",
+            ));
+        }
         docs.push(nest_title("AS", self.doc_query(&v.query)));
         RcDoc::intersperse(docs, Doc::line()).group()
     }
@@ -1020,11 +1027,18 @@ impl Pretty {
                 }
                 doc
             }
-            TableFactor::Table { name, alias } => {
+            TableFactor::Table {
+                name,
+                alias,
+                sample,
+            } => {
                 let mut doc = self.doc_display_pass(name);
                 if let Some(alias) = alias {
                     doc = nest(doc, RcDoc::text(format!("AS {}", alias)));
                 }
+                if let Some(sample) = sample {
+                    doc = nest(doc, self.doc_display_pass(sample));
+                }
                 doc
             }
             _ => self.doc_display(v, "table factor variant"),
@@ -1042,6 +1056,24 @@ impl Pretty {
         }
     }
 
+    fn doc_grouping_element<'a, T: AstInfo>(&'a self, v: &'a GroupingElement<T>) -> RcDoc<'a> {
+        let (title, elems) = match v {
+            GroupingElement::Expr(expr) => return self.doc_expr(expr),
+            GroupingElement::List(exprs) if exprs.is_empty() => return RcDoc::text("()"),
+            GroupingElement::List(exprs) => {
+                return bracket("(", comma_separate(|e| self.doc_expr(e), exprs), ")");
+            }
+            GroupingElement::Rollup(elems) => ("ROLLUP (", elems),
+            GroupingElement::Cube(elems) => ("CUBE (", elems),
+            GroupingElement::GroupingSets(elems) => ("GROUPING SETS (", elems),
+        };
+        bracket(
+            title,
+            comma_separate(|e| self.doc_grouping_element(e), elems),
+            ")",
+        )
+    }
+
     fn doc_select<'a, T: AstInfo>(&'a self, v: &'a Select<T>) -> RcDoc<'a> {
         let mut docs = vec![];
         let mut select = RcDoc::text("SELECT");
@@ -1066,7 +1098,7 @@ impl Pretty {
         if !v.group_by.is_empty() {
             docs.push(title_comma_separate(
                 "GROUP BY",
-                |e| self.doc_expr(e),
+                |e| self.doc_grouping_element(e),
                 &v.group_by,
             ));
         }
@@ -1248,8 +1280,8 @@ impl Pretty {
     fn doc_function<'a, T: AstInfo>(&'a self, v: &'a Function<T>) -> RcDoc<'a> {
         match &v.args {
             FunctionArgs::Star => self.doc_display_pass(v),
-            FunctionArgs::Args { args, order_by } => {
-                if args.is_empty() {
+            FunctionArgs::Args { args, order_by, .. } => {
+                if args.is_empty() && order_by.is_empty() {
                     // Nullary, don't allow newline between parens, so just delegate.
                     return self.doc_display_pass(v);
                 }
@@ -18,10 +18,11 @@ use mz_sql_parser::ast::CreateTableFromSourceStatement;
 use crate::ast::visit::{self, Visit};
 use crate::ast::visit_mut::{self, VisitMut};
 use crate::ast::{
-    AstInfo, CreateConnectionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
-    CreateTableStatement, CreateViewStatement, CreateWebhookSourceStatement, Expr, Ident, Query,
-    Raw, RawItemName, Statement, UnresolvedItemName, ViewDefinition,
+    AstInfo, CreateConnectionStatement, CreateFunctionStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreatePolicyStatement, CreateSecretStatement,
+    CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement, CreateTableStatement,
+    CreateViewStatement, CreateWebhookSourceStatement, Expr, Ident, Query, Raw, RawItemName,
+    Statement, UnresolvedItemName, ViewDefinition,
 };
 use crate::names::FullItemName;
 
@@ -47,6 +48,9 @@ pub fn create_stmt_rename_schema_refs(
         | stmt @ Statement::CreateTableFromSource(_)
         | stmt @ Statement::CreateIndex(_)
         | stmt @ Statement::CreateType(_)
+        | stmt @ Statement::CreateFunction(_)
+        | stmt @ Statement::CreateSequence(_)
+        | stmt @ Statement::CreatePolicy(_)
         | stmt @ Statement::CreateSecret(_) => {
             let mut visitor = CreateSqlRewriteSchema {
                 database,
@@ -199,6 +203,9 @@ pub fn create_stmt_rename_refs(
         Statement::CreateSink(CreateSinkStatement { from, .. }) => {
             maybe_update_item_name(from.name_mut());
         }
+        Statement::CreatePolicy(CreatePolicyStatement { on_name, .. }) => {
+            maybe_update_item_name(on_name.name_mut());
+        }
         Statement::CreateView(CreateViewStatement {
             definition: ViewDefinition { query, .. },
             ..
@@ -215,6 +222,9 @@ pub fn create_stmt_rename_refs(
             }
             rewrite_query(from_name, to_item_name, query)?;
         }
+        Statement::CreateFunction(CreateFunctionStatement { body, .. }) => {
+            rewrite_query(from_name, to_item_name, body)?;
+        }
         Statement::CreateSource(_)
         | Statement::CreateSubsource(_)
         | Statement::CreateTable(_)
@@ -35,8 +35,9 @@ use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::role_id::RoleId;
 use mz_repr::{
     CatalogItemId, ColumnName, GlobalId, RelationDesc, RelationVersion, RelationVersionSelector,
+    SqlScalarType,
 };
-use mz_sql_parser::ast::{Expr, QualifiedReplica, UnresolvedItemName};
+use mz_sql_parser::ast::{Expr, QualifiedReplica, Query, UnresolvedItemName};
 use mz_storage_types::connections::inline::{ConnectionResolver, ReferencedConnection};
 use mz_storage_types::connections::{Connection, ConnectionContext};
 use mz_storage_types::sources::{SourceDesc, SourceExportDataConfig, SourceExportDetails};
@@ -53,7 +54,10 @@ use crate::names::{
 };
 use crate::plan::statement::StatementDesc;
 use crate::plan::statement::ddl::PlannedRoleAttributes;
-use crate::plan::{ClusterSchedule, CreateClusterPlan, PlanError, PlanNotice, query};
+use crate::plan::{
+    ClusterSchedule, CreateClusterPlan, IdentityColumn, PlanError, PlanNotice, SequenceOptions,
+    query,
+};
 use crate::session::vars::{OwnedVarInput, SystemVars};
 
 /// A catalog keeps track of SQL objects and session state available to the
@@ -877,13 +881,39 @@ pub trait CatalogItem {
     /// catalog item is a table that accepts writes.
     fn writable_table_details(&self) -> Option<&[Expr<Aug>]>;
 
+    /// Returns the `GENERATED ... AS IDENTITY` columns of the catalog item, if
+    /// the catalog item is a table that accepts writes.
+    fn identity_columns(&self) -> &[IdentityColumn];
+
     /// The item this catalog item replaces, if any.
     fn replacement_target(&self) -> Option<CatalogItemId>;
 
+    /// Returns the channel on which changes to the catalog item are announced
+    /// to `LISTEN`ing sessions, if the catalog item is a materialized view
+    /// with a `NOTIFY CHANNEL`.
+    fn notify_channel(&self) -> Option<&str>;
+
     /// Returns the type information associated with the catalog item, if the
     /// catalog item is a type.
     fn type_details(&self) -> Option<&CatalogTypeDetails<IdReference>>;
 
+    /// Returns the definition of the catalog item, if the catalog item is a
+    /// user-defined SQL function.
+    fn func_details(&self) -> Option<&CatalogFuncDetails>;
+
+    /// Returns the options of the catalog item, if the catalog item is a
+    /// sequence.
+    fn sequence_details(&self) -> Option<&SequenceOptions>;
+
+    /// Returns the definition of the catalog item, if the catalog item is a
+    /// row-level security policy.
+    fn policy_details(&self) -> Option<&CatalogPolicyDetails>;
+
+    /// Reports whether row-level security is enabled on the catalog item.
+    /// Always false for items that are not tables, views or materialized
+    /// views.
+    fn row_level_security(&self) -> bool;
+
     /// Returns the ID of the owning role.
     fn owner_id(&self) -> RoleId;
 
@@ -950,6 +980,10 @@ pub enum CatalogItemType {
     Connection,
     /// A continual task.
     ContinualTask,
+    /// A sequence.
+    Sequence,
+    /// A row-level security policy.
+    Policy,
 }
 
 impl CatalogItemType {
@@ -984,6 +1018,8 @@ impl CatalogItemType {
             CatalogItemType::Secret => false,
             CatalogItemType::Connection => false,
             CatalogItemType::ContinualTask => true,
+            CatalogItemType::Sequence => true,
+            CatalogItemType::Policy => false,
         }
     }
 }
@@ -1002,6 +1038,8 @@ impl fmt::Display for CatalogItemType {
             CatalogItemType::Secret => f.write_str("secret"),
             CatalogItemType::Connection => f.write_str("connection"),
             CatalogItemType::ContinualTask => f.write_str("continual task"),
+            CatalogItemType::Sequence => f.write_str("sequence"),
+            CatalogItemType::Policy => f.write_str("policy"),
         }
     }
 }
@@ -1020,6 +1058,8 @@ impl From<CatalogItemType> for ObjectType {
             CatalogItemType::Secret => ObjectType::Secret,
             CatalogItemType::Connection => ObjectType::Connection,
             CatalogItemType::ContinualTask => ObjectType::ContinualTask,
+            CatalogItemType::Sequence => ObjectType::Sequence,
+            CatalogItemType::Policy => ObjectType::Policy,
         }
     }
 }
@@ -1038,6 +1078,10 @@ impl From<CatalogItemType> for mz_audit_log::ObjectType {
             CatalogItemType::Secret => mz_audit_log::ObjectType::Secret,
             CatalogItemType::Connection => mz_audit_log::ObjectType::Connection,
             CatalogItemType::ContinualTask => mz_audit_log::ObjectType::ContinualTask,
+            CatalogItemType::Sequence => mz_audit_log::ObjectType::Table,
+            // The audit log has no dedicated type for policies; they are
+            // recorded against the relations they protect.
+            CatalogItemType::Policy => mz_audit_log::ObjectType::Table,
         }
     }
 }
@@ -1062,6 +1106,33 @@ pub struct CatalogTypePgMetadata {
     pub typreceive_oid: u32,
 }
 
+/// Details about a user-defined SQL function in the catalog.
+#[derive(Clone, Debug)]
+pub struct CatalogFuncDetails {
+    /// The names and types of the function's parameters.
+    pub params: Vec<(ColumnName, SqlScalarType)>,
+    /// The names and types of the columns the function returns. Scalar
+    /// functions return a single column, named after the function.
+    pub returns: Vec<(ColumnName, SqlScalarType)>,
+    /// Whether the function returns a set of rows, i.e. was declared with
+    /// `RETURNS TABLE`.
+    pub returns_set: bool,
+    /// The body of the function.
+    pub body: Query<Aug>,
+}
+
+/// Details about a row-level security policy in the catalog.
+#[derive(Clone, Debug)]
+pub struct CatalogPolicyDetails {
+    /// The relation the policy applies to.
+    pub on: CatalogItemId,
+    /// The roles the policy applies to. Empty if the policy applies to all
+    /// roles.
+    pub roles: Vec<RoleId>,
+    /// The predicate that rows must satisfy to be visible to the roles.
+    pub using: Expr<Aug>,
+}
+
 /// Represents a reference to type in the catalog
 pub trait TypeReference {
     /// The actual type used to reference a `CatalogType`
@@ -1566,6 +1637,8 @@ pub enum ObjectType {
     Func,
     ContinualTask,
     NetworkPolicy,
+    Sequence,
+    Policy,
 }
 
 impl ObjectType {
@@ -1588,7 +1661,9 @@ impl ObjectType {
             | ObjectType::Cluster
             | ObjectType::ClusterReplica
             | ObjectType::Role
-            | ObjectType::NetworkPolicy => false,
+            | ObjectType::NetworkPolicy
+            | ObjectType::Sequence
+            | ObjectType::Policy => false,
         }
     }
 }
@@ -1614,6 +1689,8 @@ impl From<mz_sql_parser::ast::ObjectType> for ObjectType {
             mz_sql_parser::ast::ObjectType::Func => ObjectType::Func,
             mz_sql_parser::ast::ObjectType::ContinualTask => ObjectType::ContinualTask,
             mz_sql_parser::ast::ObjectType::NetworkPolicy => ObjectType::NetworkPolicy,
+            mz_sql_parser::ast::ObjectType::Sequence => ObjectType::Sequence,
+            mz_sql_parser::ast::ObjectType::Policy => ObjectType::Policy,
         }
     }
 }
@@ -1662,6 +1739,8 @@ impl Display for ObjectType {
             ObjectType::Func => "FUNCTION",
             ObjectType::ContinualTask => "CONTINUAL TASK",
             ObjectType::NetworkPolicy => "NETWORK POLICY",
+            ObjectType::Sequence => "SEQUENCE",
+            ObjectType::Policy => "POLICY",
         })
     }
 }
@@ -25,7 +25,7 @@ use mz_repr::role_id::RoleId;
 use mz_repr::{ColumnName, Datum, SqlRelationType, SqlScalarBaseType, SqlScalarType};
 
 use crate::ast::{SelectStatement, Statement};
-use crate::catalog::{CatalogType, TypeCategory, TypeReference};
+use crate::catalog::{CatalogFuncDetails, CatalogType, TypeCategory, TypeReference};
 use crate::names::{self, ResolvedItemName};
 use crate::plan::error::PlanError;
 use crate::plan::hir::{
@@ -311,7 +311,7 @@ impl<R> Operation<R> {
     /// Builds an operation that takes two arguments and an order_by.
     ///
     /// If returning an aggregate function, it should return `true` for
-    /// [`AggregateFunc::is_order_sensitive`].
+    /// [`AggregateFunc::is_order_sensitive`] or [`AggregateFunc::is_ordered_set`].
     fn binary_ordered<F>(f: F) -> Operation<R>
     where
         F: Fn(&ExprContext, HirScalarExpr, HirScalarExpr, Vec<ColumnOrder>) -> Result<R, PlanError>
@@ -492,6 +492,64 @@ fn experimental_sql_impl_table_func(
     sql_impl_table_func_inner(sql, Some(feature))
 }
 
+/// Constructs a definition for a user-defined function whose body is written in
+/// SQL, as created by `CREATE FUNCTION`.
+///
+/// The function has a single implementation whose parameters exactly match the
+/// declared parameter types. Each invocation inlines the function's body,
+/// splicing in the arguments in place of the parameters.
+pub fn sql_func(oid: u32, details: &CatalogFuncDetails) -> Func {
+    let params = ParamList::Exact(
+        details
+            .params
+            .iter()
+            .map(|(_, typ)| ParamType::Plain(typ.clone()))
+            .collect(),
+    );
+    let details = details.clone();
+    if details.returns_set {
+        let return_type = match details.returns.as_slice() {
+            [(_, typ)] => ReturnType::set_of(ParamType::Plain(typ.clone())),
+            _ => ReturnType::set_of(ParamType::RecordAny),
+        };
+        let op = Operation::variadic(move |ecx, args| {
+            let mut expr = query::plan_sql_table_function(ecx.qcx, &details)?;
+            expr.splice_parameters(&args, 0);
+            Ok(TableFuncPlan {
+                imp: TableFuncImpl::Expr(expr),
+                column_names: details
+                    .returns
+                    .iter()
+                    .map(|(name, _)| name.clone())
+                    .collect(),
+            })
+        });
+        Func::Table(vec![FuncImpl {
+            oid,
+            params,
+            return_type,
+            op,
+        }])
+    } else {
+        let (_, typ) = details
+            .returns
+            .first()
+            .expect("scalar functions return exactly one column");
+        let return_type = ReturnType::scalar(ParamType::Plain(typ.clone()));
+        let op = Operation::variadic(move |ecx, args| {
+            let mut expr = query::plan_sql_scalar_function(ecx, &details)?;
+            expr.splice_parameters(&args, 0);
+            Ok(expr)
+        });
+        Func::Scalar(vec![FuncImpl {
+            oid,
+            params,
+            return_type,
+            op,
+        }])
+    }
+}
+
 /// Describes a single function's implementation.
 pub struct FuncImpl<R> {
     pub oid: u32,
@@ -3827,6 +3885,82 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             }) => Interval, 2113;
         },
 
+        // Ordered-set aggregates, whose last argument is passed via `WITHIN
+        // GROUP (ORDER BY ...)`.
+        "mode" => Aggregate {
+            params!(AnyElement) => Operation::unary_ordered(|_ecx, e, order_by| {
+                let desc = order_by.first().map_or(false, |o| o.desc);
+                Ok((e, AggregateFunc::Mode { desc }))
+            }) => AnyElement, 3994;
+        },
+        "percentile_cont" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::binary_ordered(|_ecx, percentile, value, order_by| {
+                    let (e, desc) =
+                        plan_percentile("percentile_cont", percentile, value, order_by)?;
+                    Ok((e, AggregateFunc::PercentileCont { desc }))
+                }) => Float64, 3974;
+        },
+        "percentile_disc" => Aggregate {
+            params!(Float64, AnyElement) =>
+                Operation::binary_ordered(|_ecx, percentile, value, order_by| {
+                    let (e, desc) =
+                        plan_percentile("percentile_disc", percentile, value, order_by)?;
+                    Ok((e, AggregateFunc::PercentileDisc { desc }))
+                }) => AnyElement, 3972;
+        },
+
+        // Statistical aggregates over pairs of values, which are rewritten in
+        // terms of sums by `transform_ast`.
+        "corr" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("corr")) => Float64, 2829;
+        },
+        "covar_pop" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("covar_pop")) => Float64, 2827;
+        },
+        "covar_samp" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("covar_samp")) => Float64, 2828;
+        },
+        "regr_avgx" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("regr_avgx")) => Float64, 2822;
+        },
+        "regr_avgy" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("regr_avgy")) => Float64, 2823;
+        },
+        "regr_count" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("regr_count")) => Int64, 2818;
+        },
+        "regr_intercept" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("regr_intercept")) => Float64, 2826;
+        },
+        "regr_r2" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("regr_r2")) => Float64, 2824;
+        },
+        "regr_slope" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("regr_slope")) => Float64, 2825;
+        },
+        "regr_sxx" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("regr_sxx")) => Float64, 2819;
+        },
+        "regr_sxy" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("regr_sxy")) => Float64, 2821;
+        },
+        "regr_syy" => Aggregate {
+            params!(Float64, Float64) =>
+                Operation::nullary(|_ecx| catalog_name_only!("regr_syy")) => Float64, 2820;
+        },
+
         // Scalar window functions.
         "row_number" => ScalarWindow {
             params!() => ScalarWindowFunc::RowNumber => Int64, 3100;
@@ -3837,6 +3971,17 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "dense_rank" => ScalarWindow {
             params!() => ScalarWindowFunc::DenseRank => Int64, 3102;
         },
+        "percent_rank" => ScalarWindow {
+            params!() => ScalarWindowFunc::PercentRank => Float64, 3103;
+        },
+        "cume_dist" => ScalarWindow {
+            params!() => ScalarWindowFunc::CumeDist => Float64, 3104;
+        },
+        "ntile" => ValueWindow {
+            params!(Int32) => Operation::unary(|_ecx, num_buckets| {
+                Ok((positive_window_func_arg("ntile", num_buckets), ValueWindowFunc::Ntile))
+            }) => Int32, 3105;
+        },
         "lag" => ValueWindow {
             // All args are encoded into a single record to be handled later
             params!(AnyElement) => Operation::unary(|ecx, e| {
@@ -3939,6 +4084,18 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
         "last_value" => ValueWindow {
             params!(AnyElement) => ValueWindowFunc::LastValue => AnyElement, 3113;
         },
+        "nth_value" => ValueWindow {
+            // Both args are encoded into a single record to be handled later
+            params!(AnyElement, Int32) => Operation::binary(|_ecx, e, n| {
+                let e = HirScalarExpr::call_variadic(
+                    variadic::RecordCreate {
+                        field_names: vec![ColumnName::from("expr"), ColumnName::from("n")],
+                    },
+                    vec![e, positive_window_func_arg("nth_value", n)],
+                );
+                Ok((e, ValueWindowFunc::NthValue))
+            }) => AnyElement, 3114;
+        },
 
         // Table functions.
         "generate_series" => Table {
@@ -4177,28 +4334,31 @@ pub static PG_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
     };
 
     // Add side-effecting functions, which are defined in a separate module
-    // using a restricted set of function definition features (e.g., no
-    // overloads) to make them easier to plan.
+    // using a restricted set of function definition features (e.g., overloads
+    // only by arity) to make them easier to plan.
     for sef_builtin in PG_CATALOG_SEF_BUILTINS.values() {
-        builtins.insert(
-            sef_builtin.name,
-            Func::Scalar(vec![FuncImpl {
-                oid: sef_builtin.oid,
-                params: ParamList::Exact(
-                    sef_builtin
-                        .param_types
-                        .iter()
-                        .map(|t| ParamType::from(t.clone()))
-                        .collect(),
-                ),
-                return_type: ReturnType::scalar(ParamType::from(
-                    sef_builtin.return_type.scalar_type.clone(),
-                )),
-                op: Operation::variadic(|_ecx, _e| {
-                    bail_unsupported!(format!("{} in this position", sef_builtin.name))
-                }),
-            }]),
-        );
+        let func = builtins
+            .entry(sef_builtin.name)
+            .or_insert_with(|| Func::Scalar(vec![]));
+        let Func::Scalar(impls) = func else {
+            unreachable!("side-effecting functions are scalar functions");
+        };
+        impls.push(FuncImpl {
+            oid: sef_builtin.oid,
+            params: ParamList::Exact(
+                sef_builtin
+                    .param_types
+                    .iter()
+                    .map(|t| ParamType::from(t.clone()))
+                    .collect(),
+            ),
+            return_type: ReturnType::scalar(ParamType::from(
+                sef_builtin.return_type.scalar_type.clone(),
+            )),
+            op: Operation::variadic(|_ecx, _e| {
+                bail_unsupported!(format!("{} in this position", sef_builtin.name))
+            }),
+        });
     }
 
     builtins
@@ -4235,6 +4395,12 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(String, String) => BinaryFunc::from(func::ConstantTimeEqString)
                 => Bool, oid::FUNC_CONSTANT_TIME_EQ_STRING_OID;
         },
+        "approx_count_distinct" => Aggregate {
+            params!(Any) => Operation::unary(|_ecx, e| {
+                let e = e.call_unary(UnaryFunc::HllSingleton(func::HllSingleton));
+                Ok((e, AggregateFunc::ApproxCountDistinct))
+            }) => Int64, oid::FUNC_APPROX_COUNT_DISTINCT_OID;
+        },
         // Note: this is the original version of the AVG(...) function, as it existed prior to
         // v0.66. We updated the internal type promotion used when summing values to increase
         // precision, but objects (e.g. materialized views) that already used the AVG(...) function
@@ -4346,6 +4512,13 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
             params!(String, Time, Time) => VariadicFunc::from(variadic::DateDiffTime)
                 => Int64, oid::FUNC_DATEDIFF_TIME;
         },
+        // `grouping` is planned alongside the `GROUP BY` clause that it refers
+        // to, and never reaches function selection as an aggregate.
+        "grouping" => Aggregate {
+            params!(Any...) => Operation::variadic(|_ecx, _exprs| {
+                sql_bail!("grouping cannot be used as a window function")
+            }) => Int32, oid::FUNC_GROUPING_OID;
+        },
         // We can't use the `privilege_fn!` macro because the macro relies on the object having an
         // OID, and clusters do not have OIDs.
         "has_cluster_privilege" => Scalar {
@@ -4534,6 +4707,22 @@ pub static MZ_CATALOG_BUILTINS: LazyLock<BTreeMap<&'static str, Func>> = LazyLoc
                 "has_type_privilege(current_user, $1, $2)",
             ) => Bool, 3143;
         },
+        "hll_estimate" => Scalar {
+            params!(Bytes) => UnaryFunc::HllEstimate(func::HllEstimate)
+                => Int64, oid::FUNC_HLL_ESTIMATE_OID;
+        },
+        "hll_merge" => Aggregate {
+            params!(Bytes) => Operation::unary(|_ecx, e| {
+                let e = e.call_unary(UnaryFunc::HllValidateSketch(func::HllValidateSketch));
+                Ok((e, AggregateFunc::HllMerge))
+            }) => Bytes, oid::FUNC_HLL_MERGE_OID;
+        },
+        "hll_sketch" => Aggregate {
+            params!(Any) => Operation::unary(|_ecx, e| {
+                let e = e.call_unary(UnaryFunc::HllSingleton(func::HllSingleton));
+                Ok((e, AggregateFunc::HllMerge))
+            }) => Bytes, oid::FUNC_HLL_SKETCH_OID;
+        },
         "kafka_murmur2" => Scalar {
             params!(String) => UnaryFunc::KafkaMurmur2String(func::KafkaMurmur2String)
                 => Int32, oid::FUNC_KAFKA_MURMUR2_STRING_OID;
@@ -5349,6 +5538,94 @@ fn digest(algorithm: &'static str) -> Operation<HirScalarExpr> {
     })
 }
 
+/// Plans the input of `percentile_cont` or `percentile_disc`, which is a record
+/// of the value to aggregate and the requested percentile, and returns it along
+/// with whether the values are sorted in descending order.
+fn plan_percentile(
+    name: &str,
+    percentile: HirScalarExpr,
+    value: HirScalarExpr,
+    order_by: Vec<ColumnOrder>,
+) -> Result<(HirScalarExpr, bool), PlanError> {
+    // The percentile is evaluated once per group, so we require it to be a
+    // constant that we can validate up front.
+    if !percentile.is_constant() {
+        bail_unsupported!(format!("non-constant percentile in {}", name));
+    }
+    let percentile = match percentile.clone().into_literal_float64() {
+        Some(p) if !(0.0..=1.0).contains(&p) => {
+            sql_bail!("percentile value {} is not between 0 and 1", p);
+        }
+        Some(_) => percentile,
+        // Constants that aren't folded until optimization are checked when
+        // they're evaluated instead.
+        None => checked_percentile(percentile),
+    };
+    let desc = order_by.first().map_or(false, |o| o.desc);
+    let e = HirScalarExpr::call_variadic(
+        variadic::RecordCreate {
+            field_names: vec![ColumnName::from("value"), ColumnName::from("percentile")],
+        },
+        vec![value, percentile],
+    );
+    Ok((e, desc))
+}
+
+/// Wraps `percentile` so that it errors when it's not between 0 and 1, like in Postgres. A null
+/// percentile is let through.
+fn checked_percentile(percentile: HirScalarExpr) -> HirScalarExpr {
+    let out_of_range = percentile
+        .clone()
+        .call_binary(
+            HirScalarExpr::literal(Datum::Float64(0.0.into()), SqlScalarType::Float64),
+            func::Gte,
+        )
+        .and(percentile.clone().call_binary(
+            HirScalarExpr::literal(Datum::Float64(1.0.into()), SqlScalarType::Float64),
+            func::Lte,
+        ))
+        .call_unary(UnaryFunc::Not(func::Not));
+    let message = HirScalarExpr::literal(Datum::String("percentile value "), SqlScalarType::String)
+        .call_binary(
+            percentile
+                .clone()
+                .call_unary(UnaryFunc::CastFloat64ToString(func::CastFloat64ToString)),
+            func::TextConcatBinary,
+        )
+        .call_binary(
+            HirScalarExpr::literal(
+                Datum::String(" is not between 0 and 1"),
+                SqlScalarType::String,
+            ),
+            func::TextConcatBinary,
+        );
+    let error = HirScalarExpr::call_variadic(
+        variadic::ErrorIfNull,
+        vec![HirScalarExpr::literal_null(SqlScalarType::Float64), message],
+    );
+    HirScalarExpr::if_then_else(out_of_range, error, percentile)
+}
+
+/// Wraps the integer argument `n` of the window function `name` so that it errors when `n` is not
+/// positive, like in Postgres. A null `n` is let through.
+fn positive_window_func_arg(name: &str, n: HirScalarExpr) -> HirScalarExpr {
+    let is_not_positive = n.clone().call_binary(
+        HirScalarExpr::literal(Datum::Int32(0), SqlScalarType::Int32),
+        func::Lte,
+    );
+    let error = HirScalarExpr::call_variadic(
+        variadic::ErrorIfNull,
+        vec![
+            HirScalarExpr::literal_null(SqlScalarType::Int32),
+            HirScalarExpr::literal(
+                Datum::String(&format!("argument of {} must be greater than zero", name)),
+                SqlScalarType::String,
+            ),
+        ],
+    );
+    HirScalarExpr::if_then_else(is_not_positive, error, n)
+}
+
 fn array_to_string(
     ecx: &ExprContext,
     exprs: Vec<HirScalarExpr>,
@@ -2229,9 +2229,14 @@ impl<'a> Fold<Raw, Aug> for NameResolver<'a> {
     ) -> mz_sql_parser::ast::TableFactor<Aug> {
         use mz_sql_parser::ast::TableFactor::*;
         match node {
-            Table { name, alias } => Table {
+            Table {
+                name,
+                alias,
+                sample,
+            } => Table {
                 name: self.fold_item_name(name),
                 alias: alias.map(|alias| self.fold_table_alias(alias)),
+                sample: sample.map(|sample| self.fold_table_sample(sample)),
             },
             Function {
                 function,
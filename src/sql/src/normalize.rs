@@ -22,8 +22,9 @@ use mz_sql_parser::ast::display::AstDisplay;
 use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     ContinualTaskStmt, CreateConnectionStatement, CreateContinualTaskStatement,
-    CreateContinualTaskSugar, CreateIndexStatement, CreateMaterializedViewStatement,
-    CreateSecretStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
+    CreateContinualTaskSugar, CreateFunctionStatement, CreateIndexStatement,
+    CreateMaterializedViewStatement, CreatePolicyStatement, CreateSecretStatement,
+    CreateSequenceStatement, CreateSinkStatement, CreateSourceStatement, CreateSubsourceStatement,
     CreateTableFromSourceStatement, CreateTableStatement, CreateTypeStatement, CreateViewStatement,
     CreateWebhookSourceStatement, CteBlock, Function, FunctionArgs, Ident, IfExistsBehavior,
     MutRecBlock, Op, Query, Statement, TableFactor, TableFromSourceColumns, UnresolvedItemName,
@@ -221,7 +222,7 @@ pub fn create_statement(
         fn visit_function_mut(&mut self, func: &'ast mut Function<Aug>) {
             match &mut func.args {
                 FunctionArgs::Star => (),
-                FunctionArgs::Args { args, order_by } => {
+                FunctionArgs::Args { args, order_by, .. } => {
                     for arg in args {
                         self.visit_expr_mut(arg);
                     }
@@ -237,11 +238,18 @@ pub fn create_statement(
 
         fn visit_table_factor_mut(&mut self, table_factor: &'ast mut TableFactor<Aug>) {
             match table_factor {
-                TableFactor::Table { name, alias, .. } => {
+                TableFactor::Table {
+                    name,
+                    alias,
+                    sample,
+                } => {
                     self.visit_item_name_mut(name);
                     if let Some(alias) = alias {
                         self.visit_table_alias_mut(alias);
                     }
+                    if let Some(sample) = sample {
+                        self.visit_table_sample_mut(sample);
+                    }
                 }
                 // We only need special behavior for `TableFactor::Table`.
                 // Just visit the other types of table factors like normal.
@@ -382,6 +390,7 @@ pub fn create_statement(
                     name,
                     query,
                     columns: _,
+                    with_options: _,
                 },
         }) => {
             *name = if *temporary {
@@ -482,6 +491,46 @@ pub fn create_statement(
                 return Err(err);
             }
         }
+        Statement::CreateFunction(CreateFunctionStatement {
+            name,
+            if_exists,
+            params,
+            returns,
+            body,
+        }) => {
+            *name = allocate_name(name)?;
+            let mut normalizer = QueryNormalizer::new();
+            for param in params {
+                normalizer.visit_function_parameter_mut(param);
+            }
+            normalizer.visit_function_returns_mut(returns);
+            normalizer.visit_query_mut(body);
+            if let Some(err) = normalizer.err {
+                return Err(err);
+            }
+            *if_exists = IfExistsBehavior::Error;
+        }
+        Statement::CreateSequence(CreateSequenceStatement {
+            name,
+            if_not_exists,
+            options: _,
+        }) => {
+            *name = allocate_name(name)?;
+            *if_not_exists = false;
+        }
+        Statement::CreatePolicy(CreatePolicyStatement {
+            name: _,
+            on_name,
+            roles: _,
+            using,
+        }) => {
+            let mut normalizer = QueryNormalizer::new();
+            normalizer.visit_item_name_mut(on_name);
+            normalizer.visit_expr_mut(using);
+            if let Some(err) = normalizer.err {
+                return Err(err);
+            }
+        }
         Statement::CreateSecret(CreateSecretStatement {
             name,
             if_not_exists,
@@ -77,8 +77,8 @@ use crate::ast::{
     TransactionAccessMode,
 };
 use crate::catalog::{
-    CatalogType, DefaultPrivilegeAclItem, DefaultPrivilegeObject, IdReference, ObjectType,
-    RoleAttributesRaw,
+    CatalogFuncDetails, CatalogPolicyDetails, CatalogType, DefaultPrivilegeAclItem,
+    DefaultPrivilegeObject, IdReference, ObjectType, RoleAttributesRaw,
 };
 use crate::names::{
     Aug, CommentObjectId, DependencyIds, FullItemName, ObjectId, QualifiedItemName,
@@ -112,7 +112,7 @@ pub use hir::{
 };
 pub use lowering::Config as HirToMirConfig;
 pub use notice::PlanNotice;
-pub use query::{ExprContext, QueryContext, QueryLifetime};
+pub use query::{ExprContext, QueryContext, QueryLifetime, bypasses_row_level_security};
 pub use scope::Scope;
 pub use side_effecting_func::SideEffectingFunc;
 pub use statement::ddl::{
@@ -148,11 +148,16 @@ pub enum Plan {
     CreateNetworkPolicy(CreateNetworkPolicyPlan),
     CreateIndex(CreateIndexPlan),
     CreateType(CreateTypePlan),
+    CreateFunction(CreateFunctionPlan),
+    CreateSequence(CreateSequencePlan),
+    CreatePublication(CreatePublicationPlan),
+    CreatePolicy(CreatePolicyPlan),
     Comment(CommentPlan),
     DiscardTemp,
     DiscardAll,
     DropObjects(DropObjectsPlan),
     DropOwned(DropOwnedPlan),
+    DropPublication(DropPublicationPlan),
     EmptyQuery,
     ShowAllVariables,
     ShowCreate(ShowCreatePlan),
@@ -174,6 +179,7 @@ pub enum Plan {
     ExplainTimestamp(ExplainTimestampPlan),
     ExplainSinkSchema(ExplainSinkSchemaPlan),
     Insert(InsertPlan),
+    Truncate(TruncatePlan),
     AlterCluster(AlterClusterPlan),
     AlterClusterSwap(AlterClusterSwapPlan),
     AlterNoop(AlterNoopPlan),
@@ -193,6 +199,8 @@ pub enum Plan {
     AlterRole(AlterRolePlan),
     AlterOwner(AlterOwnerPlan),
     AlterTableAddColumn(AlterTablePlan),
+    AlterTableDropColumn(AlterTableDropColumnPlan),
+    AlterTableRenameColumn(AlterTableRenameColumnPlan),
     AlterMaterializedViewApplyReplacement(AlterMaterializedViewApplyReplacementPlan),
     AlterNetworkPolicy(AlterNetworkPolicyPlan),
     Declare(DeclarePlan),
@@ -203,6 +211,12 @@ pub enum Plan {
     Execute(ExecutePlan),
     Deallocate(DeallocatePlan),
     Raise(RaisePlan),
+    Listen(ListenPlan),
+    Unlisten(UnlistenPlan),
+    IdentifySystem,
+    CreateReplicationSlot(CreateReplicationSlotPlan),
+    DropReplicationSlot(DropReplicationSlotPlan),
+    StartReplication(StartReplicationPlan),
     GrantRole(GrantRolePlan),
     RevokeRole(RevokeRolePlan),
     GrantPrivileges(GrantPrivilegesPlan),
@@ -213,6 +227,7 @@ pub enum Plan {
     ValidateConnection(ValidateConnectionPlan),
     AlterRetainHistory(AlterRetainHistoryPlan),
     AlterSourceTimestampInterval(AlterSourceTimestampIntervalPlan),
+    AlterRowLevelSecurity(AlterRowLevelSecurityPlan),
 }
 
 impl Plan {
@@ -256,6 +271,12 @@ impl Plan {
             StatementKind::AlterTableAddColumn => {
                 &[PlanKind::AlterNoop, PlanKind::AlterTableAddColumn]
             }
+            StatementKind::AlterTableDropColumn => {
+                &[PlanKind::AlterNoop, PlanKind::AlterTableDropColumn]
+            }
+            StatementKind::AlterTableRenameColumn => {
+                &[PlanKind::AlterNoop, PlanKind::AlterTableRenameColumn]
+            }
             StatementKind::AlterMaterializedViewApplyReplacement => &[
                 PlanKind::AlterNoop,
                 PlanKind::AlterMaterializedViewApplyReplacement,
@@ -273,8 +294,13 @@ impl Plan {
             StatementKind::CreateClusterReplica => &[PlanKind::CreateClusterReplica],
             StatementKind::CreateConnection => &[PlanKind::CreateConnection],
             StatementKind::CreateDatabase => &[PlanKind::CreateDatabase],
+            StatementKind::CreateFunction => &[PlanKind::CreateFunction],
+            StatementKind::CreateSequence => &[PlanKind::CreateSequence],
             StatementKind::CreateIndex => &[PlanKind::CreateIndex],
             StatementKind::CreateNetworkPolicy => &[PlanKind::CreateNetworkPolicy],
+            StatementKind::CreatePublication => &[PlanKind::CreatePublication],
+            StatementKind::CreatePolicy => &[PlanKind::CreatePolicy],
+            StatementKind::CreateReplicationSlot => &[PlanKind::CreateReplicationSlot],
             StatementKind::CreateMaterializedView => &[PlanKind::CreateMaterializedView],
             StatementKind::CreateContinualTask => &[PlanKind::CreateContinualTask],
             StatementKind::CreateRole => &[PlanKind::CreateRole],
@@ -295,6 +321,8 @@ impl Plan {
             StatementKind::Discard => &[PlanKind::DiscardAll, PlanKind::DiscardTemp],
             StatementKind::DropObjects => &[PlanKind::DropObjects],
             StatementKind::DropOwned => &[PlanKind::DropOwned],
+            StatementKind::DropPublication => &[PlanKind::DropPublication],
+            StatementKind::DropReplicationSlot => &[PlanKind::DropReplicationSlot],
             StatementKind::Execute => &[PlanKind::Execute],
             StatementKind::ExplainPlan => &[PlanKind::ExplainPlan],
             StatementKind::ExplainPushdown => &[PlanKind::ExplainPushdown],
@@ -305,7 +333,9 @@ impl Plan {
             StatementKind::Fetch => &[PlanKind::Fetch],
             StatementKind::GrantPrivileges => &[PlanKind::GrantPrivileges],
             StatementKind::GrantRole => &[PlanKind::GrantRole],
+            StatementKind::IdentifySystem => &[PlanKind::IdentifySystem],
             StatementKind::Insert => &[PlanKind::Insert],
+            StatementKind::Listen => &[PlanKind::Listen],
             StatementKind::Prepare => &[PlanKind::Prepare],
             StatementKind::Raise => &[PlanKind::Raise],
             StatementKind::ReassignOwned => &[PlanKind::ReassignOwned],
@@ -324,11 +354,17 @@ impl Plan {
                 PlanKind::ShowAllVariables,
                 PlanKind::InspectShard,
             ],
+            StatementKind::StartReplication => &[PlanKind::StartReplication],
             StatementKind::StartTransaction => &[PlanKind::StartTransaction],
             StatementKind::Subscribe => &[PlanKind::Subscribe],
+            StatementKind::Truncate => &[PlanKind::Truncate],
+            StatementKind::Unlisten => &[PlanKind::Unlisten],
             StatementKind::Update => &[PlanKind::ReadThenWrite],
             StatementKind::ValidateConnection => &[PlanKind::ValidateConnection],
             StatementKind::AlterRetainHistory => &[PlanKind::AlterRetainHistory],
+            StatementKind::AlterRowLevelSecurity => {
+                &[PlanKind::AlterNoop, PlanKind::AlterRowLevelSecurity]
+            }
         }
     }
 
@@ -351,7 +387,11 @@ impl Plan {
             Plan::CreateContinualTask(_) => "create continual task",
             Plan::CreateIndex(_) => "create index",
             Plan::CreateType(_) => "create type",
+            Plan::CreateFunction(_) => "create function",
+            Plan::CreateSequence(_) => "create sequence",
             Plan::CreateNetworkPolicy(_) => "create network policy",
+            Plan::CreatePublication(_) => "create publication",
+            Plan::CreatePolicy(_) => "create policy",
             Plan::Comment(_) => "comment",
             Plan::DiscardTemp => "discard temp",
             Plan::DiscardAll => "discard all",
@@ -373,8 +413,11 @@ impl Plan {
                 ObjectType::Func => "drop function",
                 ObjectType::ContinualTask => "drop continual task",
                 ObjectType::NetworkPolicy => "drop network policy",
+                ObjectType::Sequence => "drop sequence",
+                ObjectType::Policy => "drop policy",
             },
             Plan::DropOwned(_) => "drop owned",
+            Plan::DropPublication(_) => "drop publication",
             Plan::EmptyQuery => "do nothing",
             Plan::ShowAllVariables => "show all variables",
             Plan::ShowCreate(_) => "show create",
@@ -396,6 +439,7 @@ impl Plan {
             Plan::ExplainTimestamp(_) => "explain timestamp",
             Plan::ExplainSinkSchema(_) => "explain schema",
             Plan::Insert(_) => "insert",
+            Plan::Truncate(_) => "truncate",
             Plan::AlterNoop(plan) => match plan.object_type {
                 ObjectType::Table => "alter table",
                 ObjectType::View => "alter view",
@@ -414,6 +458,8 @@ impl Plan {
                 ObjectType::Func => "alter function",
                 ObjectType::ContinualTask => "alter continual task",
                 ObjectType::NetworkPolicy => "alter network policy",
+                ObjectType::Sequence => "alter sequence",
+                ObjectType::Policy => "alter policy",
             },
             Plan::AlterCluster(_) => "alter cluster",
             Plan::AlterClusterRename(_) => "alter cluster rename",
@@ -450,8 +496,12 @@ impl Plan {
                 ObjectType::Func => "alter function owner",
                 ObjectType::ContinualTask => "alter continual task owner",
                 ObjectType::NetworkPolicy => "alter network policy owner",
+                ObjectType::Sequence => "alter sequence owner",
+                ObjectType::Policy => "alter policy owner",
             },
             Plan::AlterTableAddColumn(_) => "alter table add column",
+            Plan::AlterTableDropColumn(_) => "alter table drop column",
+            Plan::AlterTableRenameColumn(_) => "alter table rename column",
             Plan::AlterMaterializedViewApplyReplacement(_) => {
                 "alter materialized view apply replacement"
             }
@@ -467,6 +517,12 @@ impl Plan {
             Plan::Execute(_) => "execute",
             Plan::Deallocate(_) => "deallocate",
             Plan::Raise(_) => "raise",
+            Plan::Listen(_) => "listen",
+            Plan::Unlisten(_) => "unlisten",
+            Plan::IdentifySystem => "identify system",
+            Plan::CreateReplicationSlot(_) => "create replication slot",
+            Plan::DropReplicationSlot(_) => "drop replication slot",
+            Plan::StartReplication(_) => "start replication",
             Plan::GrantRole(_) => "grant role",
             Plan::RevokeRole(_) => "revoke role",
             Plan::GrantPrivileges(_) => "grant privilege",
@@ -477,6 +533,7 @@ impl Plan {
             Plan::ValidateConnection(_) => "validate connection",
             Plan::AlterRetainHistory(_) => "alter retain history",
             Plan::AlterSourceTimestampInterval(_) => "alter source timestamp interval",
+            Plan::AlterRowLevelSecurity(_) => "alter row level security",
         }
     }
 
@@ -806,6 +863,43 @@ pub struct CreateTypePlan {
     pub typ: Type,
 }
 
+#[derive(Debug, Clone)]
+pub struct CreateFunctionPlan {
+    pub name: QualifiedItemName,
+    pub function: Function,
+    /// The Catalog objects that this function is replacing, if any.
+    pub replace: Option<CatalogItemId>,
+    /// The Catalog objects that need to be dropped. This includes `replace` and any dependents.
+    pub drop_ids: Vec<CatalogItemId>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateSequencePlan {
+    pub name: QualifiedItemName,
+    pub sequence: Sequence,
+    pub if_not_exists: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreatePolicyPlan {
+    pub name: QualifiedItemName,
+    pub policy: Policy,
+}
+
+#[derive(Debug)]
+pub struct CreatePublicationPlan {
+    /// The name of the publication.
+    pub name: String,
+    /// The tables and materialized views included in the publication.
+    pub relations: Vec<CatalogItemId>,
+}
+
+#[derive(Debug)]
+pub struct DropPublicationPlan {
+    pub name: String,
+    pub if_exists: bool,
+}
+
 #[derive(Debug)]
 pub struct DropObjectsPlan {
     /// The IDs of only the objects directly referenced in the `DROP` statement.
@@ -1181,6 +1275,15 @@ pub struct InsertPlan {
     pub id: CatalogItemId,
     pub values: HirRelationExpr,
     pub returning: Vec<mz_expr::MirScalarExpr>,
+    /// The identity columns whose values must be generated for each inserted
+    /// row. `values` produces `NULL` for these columns.
+    pub identity_columns: Vec<IdentityColumn>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TruncatePlan {
+    /// The tables to truncate.
+    pub ids: Vec<CatalogItemId>,
 }
 
 #[derive(Debug)]
@@ -1191,6 +1294,9 @@ pub struct ReadThenWritePlan {
     pub assignments: BTreeMap<usize, mz_expr::MirScalarExpr>,
     pub kind: MutationKind,
     pub returning: Vec<mz_expr::MirScalarExpr>,
+    /// The identity columns whose values must be generated for each inserted
+    /// row. Only used by inserts.
+    pub identity_columns: Vec<IdentityColumn>,
 }
 
 /// Generated by `ALTER ... IF EXISTS` if the named object did not exist.
@@ -1213,6 +1319,13 @@ pub struct AlterRetainHistoryPlan {
     pub object_type: ObjectType,
 }
 
+#[derive(Debug)]
+pub struct AlterRowLevelSecurityPlan {
+    pub id: CatalogItemId,
+    pub object_type: ObjectType,
+    pub enabled: bool,
+}
+
 #[derive(Debug)]
 pub struct AlterSourceTimestampIntervalPlan {
     pub id: CatalogItemId,
@@ -1368,6 +1481,19 @@ pub struct AlterTablePlan {
     pub raw_sql_type: RawDataType,
 }
 
+#[derive(Debug)]
+pub struct AlterTableDropColumnPlan {
+    pub relation_id: CatalogItemId,
+    pub column_name: ColumnName,
+}
+
+#[derive(Debug)]
+pub struct AlterTableRenameColumnPlan {
+    pub relation_id: CatalogItemId,
+    pub column_name: ColumnName,
+    pub to_column_name: ColumnName,
+}
+
 #[derive(Debug, Clone)]
 pub struct AlterMaterializedViewApplyReplacementPlan {
     pub id: CatalogItemId,
@@ -1418,6 +1544,44 @@ pub struct RaisePlan {
     pub severity: NoticeSeverity,
 }
 
+#[derive(Debug)]
+pub struct ListenPlan {
+    /// The channel to listen on.
+    pub channel: String,
+    /// The materialized views bound to `channel` via `NOTIFY CHANNEL`.
+    pub relations: Vec<CatalogItemId>,
+}
+
+#[derive(Debug)]
+pub struct UnlistenPlan {
+    /// The channel to stop listening on, or `None` to stop listening on all
+    /// channels.
+    pub channel: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct CreateReplicationSlotPlan {
+    /// The name of the replication slot.
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct DropReplicationSlotPlan {
+    /// The name of the replication slot.
+    pub name: String,
+}
+
+#[derive(Debug)]
+pub struct StartReplicationPlan {
+    /// The replication slot to stream from.
+    pub slot: String,
+    /// The timestamp requested by the client, decoded from its LSN. Changes at
+    /// or before the slot's confirmed flush position are never resent.
+    pub start: Timestamp,
+    /// The names of the publications whose changes are streamed.
+    pub publications: Vec<String>,
+}
+
 #[derive(Debug)]
 pub struct GrantRolePlan {
     /// The roles that are gaining members.
@@ -1498,7 +1662,10 @@ pub struct CommentPlan {
 #[derive(Clone, Debug)]
 pub enum TableDataSource {
     /// The table owns data created via INSERT/UPDATE/DELETE statements.
-    TableWrites { defaults: Vec<Expr<Aug>> },
+    TableWrites {
+        defaults: Vec<Expr<Aug>>,
+        identity_columns: Vec<IdentityColumn>,
+    },
 
     /// The table receives its data from the identified `DataSourceDesc`.
     /// This table type does not support INSERT/UPDATE/DELETE statements.
@@ -1515,6 +1682,8 @@ pub struct Table {
     pub temporary: bool,
     pub compaction_window: Option<CompactionWindow>,
     pub data_source: TableDataSource,
+    /// Whether row-level security policies restrict the rows of the table.
+    pub row_level_security: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -1866,6 +2035,8 @@ pub struct View {
     pub column_names: Vec<ColumnName>,
     /// If this view is created in the temporary schema, e.g. `CREATE TEMPORARY ...`.
     pub temporary: bool,
+    /// Whether row-level security policies restrict the rows of the view.
+    pub row_level_security: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -1887,6 +2058,11 @@ pub struct MaterializedView {
     pub compaction_window: Option<CompactionWindow>,
     pub refresh_schedule: Option<RefreshSchedule>,
     pub as_of: Option<Timestamp>,
+    /// The channel on which `LISTEN`ing sessions are notified of changes.
+    pub notify_channel: Option<String>,
+    /// Whether row-level security policies restrict the rows of the
+    /// materialized view.
+    pub row_level_security: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -1906,6 +2082,139 @@ pub struct Type {
     pub inner: CatalogType<IdReference>,
 }
 
+#[derive(Clone, Debug)]
+pub struct Function {
+    /// Parse-able SQL that is stored durably and defines this function.
+    pub create_sql: String,
+    /// The signature and body of the function.
+    pub details: CatalogFuncDetails,
+}
+
+#[derive(Clone, Debug)]
+pub struct Policy {
+    /// Parse-able SQL that is stored durably and defines this policy.
+    pub create_sql: String,
+    /// The relation, roles and predicate of the policy.
+    pub details: CatalogPolicyDetails,
+}
+
+#[derive(Clone, Debug)]
+pub struct Sequence {
+    /// Parse-able SQL that is stored durably and defines this sequence.
+    pub create_sql: String,
+    pub options: SequenceOptions,
+}
+
+/// The options of a sequence, specified either in `CREATE SEQUENCE` or in the
+/// `GENERATED ... AS IDENTITY` option of a column.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct SequenceOptions {
+    /// The amount added to the last value to produce the next one. Never zero.
+    pub increment: i64,
+    /// The smallest value the sequence produces.
+    pub min_value: i64,
+    /// The largest value the sequence produces.
+    pub max_value: i64,
+    /// The first value the sequence produces.
+    pub start: i64,
+    /// Whether the sequence wraps around once it passes `min_value` or
+    /// `max_value`, rather than returning an error.
+    pub cycle: bool,
+}
+
+impl SequenceOptions {
+    /// Returns the state of a sequence that has never produced a value.
+    pub fn initial_state(&self) -> SequenceState {
+        SequenceState {
+            last_value: self.start,
+            is_called: false,
+        }
+    }
+
+    /// Advances `state` by one value, or returns `None` if the sequence is
+    /// exhausted.
+    pub fn next(&self, state: SequenceState) -> Option<SequenceState> {
+        if !state.is_called {
+            return Some(SequenceState {
+                last_value: state.last_value,
+                is_called: true,
+            });
+        }
+        let next = state
+            .last_value
+            .checked_add(self.increment)
+            .filter(|value| self.contains(*value));
+        let last_value = match next {
+            Some(value) => value,
+            None if !self.cycle => return None,
+            None if self.increment > 0 => self.min_value,
+            None => self.max_value,
+        };
+        Some(SequenceState {
+            last_value,
+            is_called: true,
+        })
+    }
+
+    /// Reports whether `value` lies within the bounds of the sequence.
+    pub fn contains(&self, value: i64) -> bool {
+        (self.min_value..=self.max_value).contains(&value)
+    }
+}
+
+/// The state of a sequence, which is stored durably.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SequenceState {
+    /// The value most recently produced by the sequence, or set by `setval`.
+    pub last_value: i64,
+    /// Whether `last_value` has been produced. If not, `last_value` is the
+    /// next value the sequence produces.
+    pub is_called: bool,
+}
+
+/// Identifies the durable state of a sequence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SequenceId {
+    /// A sequence created by `CREATE SEQUENCE`.
+    Sequence(CatalogItemId),
+    /// The sequence that generates the values of an identity column.
+    Identity {
+        table: CatalogItemId,
+        /// The position of the column in the `CREATE TABLE` statement.
+        column: usize,
+    },
+}
+
+impl SequenceId {
+    /// Returns the ID of the catalog item that owns the sequence.
+    pub fn item_id(&self) -> CatalogItemId {
+        match self {
+            SequenceId::Sequence(id) | SequenceId::Identity { table: id, .. } => *id,
+        }
+    }
+}
+
+impl std::fmt::Display for SequenceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SequenceId::Sequence(id) => write!(f, "{id}"),
+            SequenceId::Identity { table, column } => write!(f, "{table}.{column}"),
+        }
+    }
+}
+
+/// A column of a table that was declared `GENERATED ... AS IDENTITY`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct IdentityColumn {
+    /// The position of the column in the `CREATE TABLE` statement.
+    pub column: usize,
+    /// Whether the column was declared `GENERATED ALWAYS`, in which case
+    /// users may not write its values explicitly.
+    pub always: bool,
+    /// The options of the sequence that generates the column's values.
+    pub options: SequenceOptions,
+}
+
 /// Specifies when a `Peek` or `Subscribe` should occur.
 #[derive(Deserialize, Clone, Debug, PartialEq)]
 pub enum QueryWhen {
@@ -2001,6 +2310,9 @@ pub enum CopyFormat {
     Csv,
     Binary,
     Parquet,
+    Avro,
+    Orc,
+    Json,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -2020,6 +2332,8 @@ pub enum IndexOption {
 pub enum TableOption {
     /// Configures the logical compaction window for a table.
     RetainHistory(CompactionWindow),
+    /// Enables row-level security policies on a table.
+    RowLevelSecurity,
 }
 
 #[derive(Clone, Debug)]
@@ -43,8 +43,9 @@ use crate::plan::scope::ScopeItem;
 use crate::plan::typeconv::CastContext;
 use crate::pure::error::{
     CsrPurificationError, IcebergSinkPurificationError, KafkaSinkPurificationError,
-    KafkaSourcePurificationError, LoadGeneratorSourcePurificationError,
-    MySqlSourcePurificationError, PgSourcePurificationError, SqlServerSourcePurificationError,
+    KafkaSourcePurificationError, LoadGeneratorSourcePurificationError, MySqlSinkPurificationError,
+    MySqlSourcePurificationError, PgSourcePurificationError, PostgresSinkPurificationError,
+    S3SinkPurificationError, SqlServerSinkPurificationError, SqlServerSourcePurificationError,
 };
 use crate::session::vars::VarError;
 
@@ -159,6 +160,19 @@ pub enum PlanError {
         name: String,
         item_type: String,
     },
+    /// An object owned by `role` would read the relation `name` without
+    /// applying its row-level security policies.
+    RowLevelSecurityDependency {
+        name: String,
+        role: String,
+    },
+    /// `role` would `operation` the relation `name` without applying its
+    /// row-level security policies.
+    RowLevelSecurityBypassRequired {
+        name: String,
+        role: String,
+        operation: &'static str,
+    },
     MangedReplicaName(String),
     ParserStatement(ParserStatementError),
     Parser(ParserError),
@@ -169,6 +183,14 @@ pub enum PlanError {
         // (dependent type, name)
         dependents: Vec<(String, String)>,
     },
+    ColumnDependentObjectsStillExist {
+        /// The attempted alteration, e.g. "drop" or "rename".
+        action: &'static str,
+        column_name: ColumnName,
+        object_name: String,
+        // (dependent type, name)
+        dependents: Vec<(String, String)>,
+    },
     AlterViewOnMaterializedView(String),
     ShowCreateViewOnMaterializedView(String),
     ExplainViewOnMaterializedView(String),
@@ -267,6 +289,10 @@ pub enum PlanError {
     KafkaSourcePurification(KafkaSourcePurificationError),
     KafkaSinkPurification(KafkaSinkPurificationError),
     IcebergSinkPurification(IcebergSinkPurificationError),
+    PostgresSinkPurification(PostgresSinkPurificationError),
+    MySqlSinkPurification(MySqlSinkPurificationError),
+    SqlServerSinkPurification(SqlServerSinkPurificationError),
+    S3SinkPurification(S3SinkPurificationError),
     LoadGeneratorSourcePurification(LoadGeneratorSourcePurificationError),
     CsrPurification(CsrPurificationError),
     MySqlSourcePurification(MySqlSourcePurificationError),
@@ -374,6 +400,10 @@ impl PlanError {
             Self::CsrPurification(e) => e.detail(),
             Self::KafkaSinkPurification(e) => e.detail(),
             Self::IcebergSinkPurification(e) => e.detail(),
+            Self::PostgresSinkPurification(e) => e.detail(),
+            Self::MySqlSinkPurification(e) => e.detail(),
+            Self::SqlServerSinkPurification(e) => e.detail(),
+            Self::S3SinkPurification(e) => e.detail(),
             Self::CreateReplicaFailStorageObjects {
                 current_replica_count: current,
                 internal_replica_count: internal,
@@ -422,6 +452,16 @@ impl PlanError {
                 Some("Use DROP MATERIALIZED VIEW to remove a materialized view.".into())
             }
             Self::DependentObjectsStillExist {..} => Some("Use DROP ... CASCADE to drop the dependent objects too.".into()),
+            Self::RowLevelSecurityDependency { name, .. } => Some(format!(
+                "Views, materialized views, continual tasks and sinks are computed with the \
+                privileges of their owner, so only members of the role that owns {name} can own \
+                objects that depend on it."
+            )),
+            Self::RowLevelSecurityBypassRequired { name, operation, .. } => Some(format!(
+                "Only members of the role that owns {name} can {operation} it, because doing so \
+                exposes its rows or changes without applying its row-level security policies."
+            )),
+            Self::ColumnDependentObjectsStillExist {..} => Some("Drop the dependent objects first.".into()),
             Self::AlterViewOnMaterializedView(_) => {
                 Some("Use ALTER MATERIALIZED VIEW to rename a materialized view.".into())
             }
@@ -488,6 +528,10 @@ impl PlanError {
             Self::LoadGeneratorSourcePurification(e) => e.hint(),
             Self::CsrPurification(e) => e.hint(),
             Self::KafkaSinkPurification(e) => e.hint(),
+            Self::PostgresSinkPurification(e) => e.hint(),
+            Self::MySqlSinkPurification(e) => e.hint(),
+            Self::SqlServerSinkPurification(e) => e.hint(),
+            Self::S3SinkPurification(e) => e.hint(),
             Self::UnknownColumn { table, similar, .. } => {
                 let suffix = "Make sure to surround case sensitive names in double quotes.";
                 match &similar[..] {
@@ -673,6 +717,14 @@ impl fmt::Display for PlanError {
             Self::InvalidSinkFrom { name, item_type } => {
                 write!(f, "{item_type} {name} cannot be exported as a sink")
             },
+            Self::RowLevelSecurityDependency { name, role } => write!(
+                f,
+                "{name} has row-level security enabled, so objects owned by role {role} cannot depend on it"
+            ),
+            Self::RowLevelSecurityBypassRequired { name, role, operation } => write!(
+                f,
+                "{name} has row-level security enabled, so role {role} cannot {operation} it"
+            ),
             Self::InvalidDependency { name, item_type } => {
                 write!(f, "{item_type} {name} cannot be depended upon")
             },
@@ -722,6 +774,15 @@ impl fmt::Display for PlanError {
                 let object_name = object_name.quoted();
                 write!(f, "cannot drop {object_type} {object_name}{reason}")
             }
+            Self::ColumnDependentObjectsStillExist { action, column_name, object_name, dependents } => {
+                let dependents = dependents.iter().map(|(dependent_type, dependent_name)| format!("{} {}", dependent_type, dependent_name.quoted())).join(", ");
+                write!(
+                    f,
+                    "cannot {action} column {} of table {}: still depended upon by {dependents}",
+                    column_name.quoted(),
+                    object_name.quoted(),
+                )
+            }
             Self::InvalidOptionValue { option_name, err } => write!(f, "invalid {} option value: {}", option_name, err),
             Self::UnexpectedDuplicateReference { name } => write!(f, "unexpected multiple references to {}", name.to_ast_string_simple()),
             Self::RecursiveTypeMismatch(name, declared, inferred) => {
@@ -804,6 +865,10 @@ impl fmt::Display for PlanError {
             Self::LoadGeneratorSourcePurification(e) => write!(f, "LOAD GENERATOR source validation: {}", e),
             Self::KafkaSinkPurification(e) => write!(f, "KAFKA sink validation: {}", e),
             Self::IcebergSinkPurification(e) => write!(f, "ICEBERG sink validation: {}", e),
+            Self::PostgresSinkPurification(e) => write!(f, "POSTGRES sink validation: {}", e),
+            Self::MySqlSinkPurification(e) => write!(f, "MYSQL sink validation: {}", e),
+            Self::SqlServerSinkPurification(e) => write!(f, "SQL SERVER sink validation: {}", e),
+            Self::S3SinkPurification(e) => write!(f, "S3 sink validation: {}", e),
             Self::CsrPurification(e) => write!(f, "CONFLUENT SCHEMA REGISTRY validation: {}", e),
             Self::MySqlSourcePurification(e) => write!(f, "MYSQL source validation: {}", e),
             Self::SqlServerSourcePurificationError(e) => write!(f, "SQL SERVER source validation: {}", e),
@@ -1017,6 +1082,30 @@ impl From<IcebergSinkPurificationError> for PlanError {
     }
 }
 
+impl From<PostgresSinkPurificationError> for PlanError {
+    fn from(e: PostgresSinkPurificationError) -> Self {
+        PlanError::PostgresSinkPurification(e)
+    }
+}
+
+impl From<MySqlSinkPurificationError> for PlanError {
+    fn from(e: MySqlSinkPurificationError) -> Self {
+        PlanError::MySqlSinkPurification(e)
+    }
+}
+
+impl From<SqlServerSinkPurificationError> for PlanError {
+    fn from(e: SqlServerSinkPurificationError) -> Self {
+        PlanError::SqlServerSinkPurification(e)
+    }
+}
+
+impl From<S3SinkPurificationError> for PlanError {
+    fn from(e: S3SinkPurificationError) -> Self {
+        PlanError::S3SinkPurification(e)
+    }
+}
+
 impl From<CsrPurificationError> for PlanError {
     fn from(e: CsrPurificationError) -> Self {
         PlanError::CsrPurification(e)
@@ -529,6 +529,8 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::RowNumber => {}
             ScalarWindowFunc::Rank => {}
             ScalarWindowFunc::DenseRank => {}
+            ScalarWindowFunc::PercentRank => {}
+            ScalarWindowFunc::CumeDist => {}
         }
         Ok(())
     }
@@ -542,6 +544,8 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::RowNumber => {}
             ScalarWindowFunc::Rank => {}
             ScalarWindowFunc::DenseRank => {}
+            ScalarWindowFunc::PercentRank => {}
+            ScalarWindowFunc::CumeDist => {}
         }
         Ok(())
     }
@@ -566,6 +570,12 @@ impl ScalarWindowExpr {
             ScalarWindowFunc::DenseRank => mz_expr::AggregateFunc::DenseRank {
                 order_by: self.order_by,
             },
+            ScalarWindowFunc::PercentRank => mz_expr::AggregateFunc::PercentRank {
+                order_by: self.order_by,
+            },
+            ScalarWindowFunc::CumeDist => mz_expr::AggregateFunc::CumeDist {
+                order_by: self.order_by,
+            },
         }
     }
 }
@@ -586,6 +596,8 @@ pub enum ScalarWindowFunc {
     RowNumber,
     Rank,
     DenseRank,
+    PercentRank,
+    CumeDist,
 }
 
 impl Display for ScalarWindowFunc {
@@ -594,6 +606,8 @@ impl Display for ScalarWindowFunc {
             ScalarWindowFunc::RowNumber => write!(f, "row_number"),
             ScalarWindowFunc::Rank => write!(f, "rank"),
             ScalarWindowFunc::DenseRank => write!(f, "dense_rank"),
+            ScalarWindowFunc::PercentRank => write!(f, "percent_rank"),
+            ScalarWindowFunc::CumeDist => write!(f, "cume_dist"),
         }
     }
 }
@@ -604,6 +618,8 @@ impl ScalarWindowFunc {
             ScalarWindowFunc::RowNumber => SqlScalarType::Int64.nullable(false),
             ScalarWindowFunc::Rank => SqlScalarType::Int64.nullable(false),
             ScalarWindowFunc::DenseRank => SqlScalarType::Int64.nullable(false),
+            ScalarWindowFunc::PercentRank => SqlScalarType::Float64.nullable(false),
+            ScalarWindowFunc::CumeDist => SqlScalarType::Float64.nullable(false),
         }
     }
 }
@@ -622,8 +638,8 @@ impl ScalarWindowFunc {
 pub struct ValueWindowExpr {
     pub func: ValueWindowFunc,
     /// If the argument list has a single element (e.g., for `first_value`), then it's that element.
-    /// If the argument list has multiple elements (e.g., for `lag`), then it's encoded in a record,
-    /// e.g., `row(#1, 3, null)`.
+    /// If the argument list has multiple elements (e.g., for `lag` or `nth_value`), then it's
+    /// encoded in a record, e.g., `row(#1, 3, null)`.
     /// If it's a fused window function, then the arguments of each of the constituent function
     /// calls are wrapped in an outer record.
     pub args: Box<HirScalarExpr>,
@@ -640,6 +656,8 @@ impl Display for ValueWindowFunc {
             ValueWindowFunc::Lead => write!(f, "lead"),
             ValueWindowFunc::FirstValue => write!(f, "first_value"),
             ValueWindowFunc::LastValue => write!(f, "last_value"),
+            ValueWindowFunc::NthValue => write!(f, "nth_value"),
+            ValueWindowFunc::Ntile => write!(f, "ntile"),
             ValueWindowFunc::Fused(funcs) => write!(f, "fused[{}]", separated(", ", funcs)),
         }
     }
@@ -731,6 +749,8 @@ pub enum ValueWindowFunc {
     Lead,
     FirstValue,
     LastValue,
+    NthValue,
+    Ntile,
     Fused(Vec<ValueWindowFunc>),
 }
 
@@ -746,6 +766,13 @@ impl ValueWindowFunc {
             ValueWindowFunc::FirstValue | ValueWindowFunc::LastValue => {
                 input_type.scalar_type.nullable(true)
             }
+            ValueWindowFunc::NthValue => {
+                // The input is a (value, n) record, so extract the type of the first arg
+                input_type.scalar_type.unwrap_record_element_type()[0]
+                    .clone()
+                    .nullable(true)
+            }
+            ValueWindowFunc::Ntile => SqlScalarType::Int32.nullable(true),
             ValueWindowFunc::Fused(funcs) => {
                 let input_types = input_type.scalar_type.unwrap_record_element_column_type();
                 SqlScalarType::Record {
@@ -787,6 +814,12 @@ impl ValueWindowFunc {
                 order_by,
                 window_frame,
             },
+            ValueWindowFunc::NthValue => mz_expr::AggregateFunc::NthValue {
+                order_by,
+                window_frame,
+            },
+            // The window frame doesn't affect ntile.
+            ValueWindowFunc::Ntile => mz_expr::AggregateFunc::Ntile { order_by },
             ValueWindowFunc::Fused(funcs) => mz_expr::AggregateFunc::FusedValueWindowFunc {
                 funcs: funcs
                     .into_iter()
@@ -1341,6 +1374,25 @@ pub enum AggregateFunc {
     StringAgg {
         order_by: Vec<ColumnOrder>,
     },
+    /// Computes the continuous percentile of `(value, percentile)` records of
+    /// `Float64`s. Values are ordered descending if `desc` is set.
+    PercentileCont {
+        desc: bool,
+    },
+    /// Computes the discrete percentile of `(value, percentile)` records, whose
+    /// values may be of any type.
+    PercentileDisc {
+        desc: bool,
+    },
+    /// Returns the most frequent input value.
+    Mode {
+        desc: bool,
+    },
+    /// Merges serialized HyperLogLog sketches into a single sketch.
+    HllMerge,
+    /// Estimates the number of distinct values from serialized HyperLogLog
+    /// sketches.
+    ApproxCountDistinct,
     /// A bundle of fused window aggregations: its input is a record, whose each
     /// component will be the input to one of the `AggregateFunc`s.
     ///
@@ -1424,6 +1476,15 @@ impl AggregateFunc {
                 mz_expr::AggregateFunc::ListConcat { order_by }
             }
             AggregateFunc::StringAgg { order_by } => mz_expr::AggregateFunc::StringAgg { order_by },
+            AggregateFunc::PercentileCont { desc } => {
+                mz_expr::AggregateFunc::PercentileCont { desc }
+            }
+            AggregateFunc::PercentileDisc { desc } => {
+                mz_expr::AggregateFunc::PercentileDisc { desc }
+            }
+            AggregateFunc::Mode { desc } => mz_expr::AggregateFunc::Mode { desc },
+            AggregateFunc::HllMerge => mz_expr::AggregateFunc::HllMerge,
+            AggregateFunc::ApproxCountDistinct => mz_expr::AggregateFunc::ApproxCountDistinct,
             // `AggregateFunc::FusedWindowAgg` should be specially handled in
             // `AggregateWindowExpr::into_expr`.
             AggregateFunc::FusedWindowAgg { funcs: _ } => {
@@ -1493,7 +1554,12 @@ impl AggregateFunc {
             | AggregateFunc::JsonbAgg { .. }
             | AggregateFunc::JsonbObjectAgg { .. }
             | AggregateFunc::MapAgg { .. }
-            | AggregateFunc::StringAgg { .. } => Datum::Null,
+            | AggregateFunc::StringAgg { .. }
+            | AggregateFunc::PercentileCont { .. }
+            | AggregateFunc::PercentileDisc { .. }
+            | AggregateFunc::Mode { .. }
+            | AggregateFunc::HllMerge
+            | AggregateFunc::ApproxCountDistinct => Datum::Null,
             AggregateFunc::FusedWindowAgg { funcs: _ } => {
                 // `identity_datum` is used only in HIR planning, and `FusedWindowAgg` can't occur
                 // in HIR planning, because it is introduced only during HIR transformation.
@@ -1522,6 +1588,13 @@ impl AggregateFunc {
             AggregateFunc::JsonbAgg { .. } => SqlScalarType::Jsonb,
             AggregateFunc::JsonbObjectAgg { .. } => SqlScalarType::Jsonb,
             AggregateFunc::StringAgg { .. } => SqlScalarType::String,
+            AggregateFunc::PercentileCont { .. } => SqlScalarType::Float64,
+            AggregateFunc::PercentileDisc { .. } => {
+                // The input is a (value, percentile) record.
+                input_type.scalar_type.unwrap_record_element_type()[0].clone()
+            }
+            AggregateFunc::HllMerge => SqlScalarType::Bytes,
+            AggregateFunc::ApproxCountDistinct => SqlScalarType::Int64,
             AggregateFunc::SumInt16 | AggregateFunc::SumInt32 => SqlScalarType::Int64,
             AggregateFunc::SumInt64 => SqlScalarType::Numeric {
                 max_scale: Some(NumericMaxScale::ZERO),
@@ -1578,6 +1651,7 @@ impl AggregateFunc {
             | AggregateFunc::SumFloat32
             | AggregateFunc::SumFloat64
             | AggregateFunc::SumNumeric
+            | AggregateFunc::Mode { .. }
             | AggregateFunc::Dummy => input_type.scalar_type,
             AggregateFunc::FusedWindowAgg { funcs } => {
                 let input_types = input_type.scalar_type.unwrap_record_element_column_type();
@@ -1592,7 +1666,10 @@ impl AggregateFunc {
             }
         };
         // max/min/sum return null on empty sets
-        let nullable = !matches!(self, AggregateFunc::Count);
+        let nullable = !matches!(
+            self,
+            AggregateFunc::Count | AggregateFunc::ApproxCountDistinct
+        );
         scalar_type.nullable(nullable)
     }
 
@@ -1608,6 +1685,16 @@ impl AggregateFunc {
                 | StringAgg { .. }
         )
     }
+
+    /// Returns whether this is an ordered-set aggregate, which must be called
+    /// with a `WITHIN GROUP (ORDER BY ...)` clause.
+    pub fn is_ordered_set(&self) -> bool {
+        use AggregateFunc::*;
+        matches!(
+            self,
+            PercentileCont { .. } | PercentileDisc { .. } | Mode { .. }
+        )
+    }
 }
 
 impl HirRelationExpr {
@@ -3758,6 +3845,25 @@ impl HirScalarExpr {
         })
     }
 
+    /// Attempts to simplify this expression to a literal 64-bit float.
+    ///
+    /// Returns `None` if this expression cannot be simplified, e.g. because it
+    /// contains non-literal values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this expression does not have type [`SqlScalarType::Float64`].
+    pub fn into_literal_float64(self) -> Option<f64> {
+        self.simplify_to_literal().and_then(|row| {
+            let datum = row.unpack_first();
+            if datum.is_null() {
+                None
+            } else {
+                Some(datum.unwrap_float64())
+            }
+        })
+    }
+
     /// Attempts to simplify this expression to a literal MzTimestamp.
     ///
     /// Returns `None` if the expression simplifies to `null` or if the expression cannot be
@@ -37,6 +37,10 @@ pub enum PlanNotice {
         column_name: String,
         object_name: String,
     },
+    ColumnDoesNotExist {
+        column_name: String,
+        object_name: String,
+    },
     UpsertSinkKeyNotEnforced {
         key: Vec<ColumnName>,
         name: String,
@@ -96,6 +100,17 @@ impl fmt::Display for PlanNotice {
                     object_name.quoted()
                 )
             }
+            PlanNotice::ColumnDoesNotExist {
+                column_name,
+                object_name,
+            } => {
+                write!(
+                    f,
+                    "column {} of relation {} does not exist, skipping",
+                    column_name.quoted(),
+                    object_name.quoted()
+                )
+            }
             PlanNotice::UpsertSinkKeyNotEnforced { .. } => {
                 write!(f, "upsert key not validated to be unique")
             }
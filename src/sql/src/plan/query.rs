@@ -66,6 +66,8 @@ use mz_repr::adt::char::CharLength;
 use mz_repr::adt::numeric::{NUMERIC_DATUM_MAX_PRECISION, NumericMaxScale};
 use mz_repr::adt::timestamp::TimestampPrecision;
 use mz_repr::adt::varchar::VarCharMaxLength;
+use mz_repr::namespaces::MZ_CATALOG_SCHEMA;
+use mz_repr::role_id::RoleId;
 use mz_repr::{
     CatalogItemId, ColumnIndex, ColumnName, Datum, RelationDesc, RelationVersionSelector,
     ReprColumnType, Row, RowArena, SqlColumnType, SqlRelationType, SqlScalarType,
@@ -77,16 +79,19 @@ use mz_sql_parser::ast::visit_mut::{self, VisitMut};
 use mz_sql_parser::ast::{
     AsOf, Assignment, AstInfo, CreateWebhookSourceBody, CreateWebhookSourceCheck,
     CreateWebhookSourceHeader, CreateWebhookSourceSecret, CteBlock, DeleteStatement, Distinct,
-    Expr, Function, FunctionArgs, HomogenizingFunction, Ident, InsertSource, IsExprConstruct, Join,
-    JoinConstraint, JoinOperator, Limit, MapEntry, MutRecBlock, MutRecBlockOption,
-    MutRecBlockOptionName, OrderByExpr, Query, Select, SelectItem, SelectOption, SelectOptionName,
-    SetExpr, SetOperator, ShowStatement, SubscriptPosition, TableAlias, TableFactor,
-    TableWithJoins, UnresolvedItemName, UpdateStatement, Value, Values, WindowFrame,
-    WindowFrameBound, WindowFrameUnits, WindowSpec, visit,
+    Expr, Function, FunctionArgs, GroupingElement, HomogenizingFunction, Ident, InsertSource,
+    IsExprConstruct, Join, JoinConstraint, JoinOperator, Limit, MapEntry, MutRecBlock,
+    MutRecBlockOption, MutRecBlockOptionName, OrderByExpr, Query, Select, SelectItem, SelectOption,
+    SelectOptionName, SetExpr, SetOperator, ShowStatement, SubscriptPosition, TableAlias,
+    TableFactor, TableSample, TableSampleMethod, TableWithJoins, UnresolvedItemName,
+    UpdateStatement, Value, Values, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
+    visit,
 };
 use mz_sql_parser::ident;
 
-use crate::catalog::{CatalogItemType, CatalogType, SessionCatalog};
+use crate::catalog::{
+    CatalogFuncDetails, CatalogItem, CatalogItemType, CatalogType, SessionCatalog,
+};
 use crate::func::{self, Func, FuncSpec, TableFuncImpl};
 use crate::names::{
     Aug, FullItemName, PartialItemName, ResolvedDataType, ResolvedItemName, SchemaSpecifier,
@@ -107,6 +112,7 @@ use crate::plan::{
     Params, PlanContext, QueryWhen, ShowCreatePlan, WebhookValidation, WebhookValidationSecret,
     literal, transform_ast,
 };
+use crate::session::user::MZ_SYSTEM_ROLE_ID;
 use crate::session::vars::ENABLE_WITH_ORDINALITY_LEGACY_FALLBACK;
 use crate::session::vars::{self, FeatureFlag};
 use crate::{ORDINALITY_COL_NAME, normalize};
@@ -397,6 +403,17 @@ pub fn plan_insert_query(
     source_types.truncate(expr_arity);
     ordering.truncate(expr_arity);
 
+    // The values of `GENERATED ALWAYS` identity columns are always generated,
+    // so they may not be written explicitly.
+    for identity in table.identity_columns() {
+        if identity.always && ordering.contains(&identity.column) {
+            sql_bail!(
+                "cannot insert a non-DEFAULT value into column {}",
+                desc.get_name(identity.column).quoted()
+            );
+        }
+    }
+
     // Ensure the types of the source query match the types of the target table,
     // installing assignment casts where necessary and possible.
     let expr = cast_relation(&qcx, CastContext::Assignment, expr, source_types).map_err(|e| {
@@ -645,6 +662,10 @@ pub fn plan_copy_from(
         )
     })?;
 
+    if !table.identity_columns().is_empty() {
+        bail_unsupported!("COPY FROM into a table with identity columns");
+    }
+
     if table.id().is_system() {
         sql_bail!(
             "cannot insert into system table '{}'",
@@ -845,6 +866,14 @@ pub fn plan_mutation_query_inner(
         // Get the index and type of the column.
         let name = normalize::column_name(id);
         match desc.get_by_name(&name) {
+            Some((idx, _))
+                if item
+                    .identity_columns()
+                    .iter()
+                    .any(|identity| identity.always && identity.column == idx) =>
+            {
+                sql_bail!("column {} can only be updated to DEFAULT", name.quoted())
+            }
             Some((idx, typ)) => {
                 let ecx = &ExprContext {
                     qcx: &qcx,
@@ -2422,18 +2451,33 @@ fn plan_select_from_where(
         let mut group_scope = Scope::empty();
         let mut select_all_mapping = BTreeMap::new();
 
-        for group_expr in &s.group_by {
+        // Expand `ROLLUP`, `CUBE`, and `GROUPING SETS` into the grouping sets
+        // they denote. The grouping key is the union of all grouping sets.
+        let ast_grouping_sets = expand_grouping_sets(&s.group_by)?;
+        let mut ast_group_exprs: Vec<&Expr<Aug>> = vec![];
+        for group_expr in ast_grouping_sets.iter().flatten() {
+            if !ast_group_exprs.contains(group_expr) {
+                ast_group_exprs.push(*group_expr);
+            }
+        }
+        // The position in `group_hir_exprs` of each of `ast_group_exprs`.
+        let mut group_expr_positions = vec![];
+
+        for group_expr in ast_group_exprs.iter().copied() {
             let (group_expr, expr) = plan_group_by_expr(ecx, group_expr, &projection)?;
             let new_column = group_key.len();
 
-            if let Some(group_expr) = group_expr {
-                // Multiple AST expressions can map to the same HIR expression.
-                // If we already have a ScopeItem for this HIR, we can add this
-                // next AST expression to its set
-                if let Some(existing_scope_item) = group_exprs.get_mut(&expr) {
-                    existing_scope_item.exprs.insert(group_expr.clone());
-                    continue;
+            // Multiple AST expressions can map to the same HIR expression.
+            // If we already have a ScopeItem for this HIR, we can add this
+            // next AST expression to its set
+            if let Some(position) = group_hir_exprs.iter().position(|e| *e == expr) {
+                if let Some(group_expr) = group_expr {
+                    if let Some(existing_scope_item) = group_exprs.get_mut(&expr) {
+                        existing_scope_item.exprs.insert(group_expr.clone());
+                    }
                 }
+                group_expr_positions.push(position);
+                continue;
             }
 
             let mut scope_item = if let HirScalarExpr::Column(
@@ -2460,6 +2504,7 @@ fn plan_select_from_where(
                 scope_item.exprs.insert(group_expr);
             }
 
+            group_expr_positions.push(new_column);
             group_key.push(from_scope.len() + group_exprs.len());
             group_hir_exprs.push(expr.clone());
             group_exprs.insert(expr, scope_item);
@@ -2472,6 +2517,49 @@ fn plan_select_from_where(
             }
         }
 
+        let grouping_sets: Vec<BTreeSet<usize>> = ast_grouping_sets
+            .iter()
+            .map(|set| {
+                set.iter()
+                    .map(|e| {
+                        let i = ast_group_exprs.iter().position(|g| g == e).expect("known");
+                        group_expr_positions[i]
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // With more than one grouping set, each row of the reduction carries,
+        // after the grouping key, a flag per grouping expression indicating
+        // whether the expression is part of the row's grouping set, followed
+        // by the index of the row's grouping set.
+        let include_columns = if grouping_sets.len() > 1 {
+            let include_columns: Vec<_> = (0..group_hir_exprs.len())
+                .map(|i| group_hir_exprs.len() + i)
+                .collect();
+            for _ in 0..=group_hir_exprs.len() {
+                group_scope.items.push(ScopeItem::empty());
+            }
+            Some(include_columns)
+        } else {
+            None
+        };
+
+        // Plan calls to `grouping`, which are gathered along with the
+        // aggregates in Step 3, but whose value is computed from the
+        // grouping set of each row after the reduction.
+        let (grouping_funcs, aggregates): (Vec<_>, Vec<_>) =
+            aggregates.into_iter().partition(is_grouping_func);
+        let mut grouping_exprs = vec![];
+        for sql_function in &grouping_funcs {
+            grouping_exprs.push(plan_grouping_func(
+                ecx,
+                sql_function,
+                &group_hir_exprs,
+                include_columns.as_deref(),
+            )?);
+        }
+
         // Plan aggregates.
         let ecx = &ExprContext {
             qcx,
@@ -2495,13 +2583,42 @@ fn plan_select_from_where(
                 .items
                 .push(ScopeItem::from_expr(Expr::Function(sql_function.clone())));
         }
-        if !agg_exprs.is_empty() || !group_key.is_empty() || s.having.is_some() {
+        for sql_function in grouping_funcs {
+            group_scope
+                .items
+                .push(ScopeItem::from_expr(Expr::Function(sql_function)));
+        }
+        if !agg_exprs.is_empty()
+            || !grouping_exprs.is_empty()
+            || !group_key.is_empty()
+            || !s.group_by.is_empty()
+            || s.having.is_some()
+        {
             // apply GROUP BY / aggregates
-            relation_expr = relation_expr.map(group_hir_exprs).reduce(
-                group_key,
-                agg_exprs,
-                group_size_hints.aggregate_input_group_size,
-            );
+            let input = relation_expr.map(group_hir_exprs.clone());
+            relation_expr = if include_columns.is_some() {
+                let key_types = group_hir_exprs
+                    .iter()
+                    .map(|expr| ecx.scalar_type(expr))
+                    .collect::<Vec<_>>();
+                plan_grouping_sets(
+                    input,
+                    from_scope.len(),
+                    &key_types,
+                    &grouping_sets,
+                    agg_exprs,
+                    group_size_hints.aggregate_input_group_size,
+                )
+            } else {
+                input.reduce(
+                    group_key,
+                    agg_exprs,
+                    group_size_hints.aggregate_input_group_size,
+                )
+            };
+            if !grouping_exprs.is_empty() {
+                relation_expr = relation_expr.map(grouping_exprs);
+            }
 
             // For every old column that wasn't a group key, add a scope item
             // that errors when referenced. We can't simply drop these items
@@ -2922,6 +3039,258 @@ fn plan_group_by_expr<'a>(
     }
 }
 
+/// The maximum number of grouping sets that a `GROUP BY` clause may denote.
+const MAX_GROUPING_SETS: usize = 4096;
+
+/// The maximum number of elements in a `CUBE`.
+const MAX_CUBE_ELEMENTS: usize = 12;
+
+/// Expands the elements of a `GROUP BY` clause into the grouping sets they
+/// denote, each of which is a list of grouping expressions.
+///
+/// A list of plain expressions, as in `GROUP BY a, b`, denotes a single
+/// grouping set. Otherwise the clause denotes the cross product of the grouping
+/// sets of its elements, so that `GROUP BY a, ROLLUP (b, c)` is equivalent to
+/// `GROUP BY GROUPING SETS ((a, b, c), (a, b), (a))`.
+fn expand_grouping_sets(elems: &[GroupingElement<Aug>]) -> Result<Vec<Vec<&Expr<Aug>>>, PlanError> {
+    let mut sets = vec![vec![]];
+    for elem in elems {
+        let elem_sets = expand_grouping_element(elem)?;
+        if sets.len() * elem_sets.len() > MAX_GROUPING_SETS {
+            sql_bail!("too many grouping sets present");
+        }
+        sets = sets
+            .iter()
+            .cartesian_product(&elem_sets)
+            .map(|(l, r)| l.iter().chain(r).copied().collect())
+            .collect();
+    }
+    Ok(sets)
+}
+
+/// Expands a single element of a `GROUP BY` clause into its grouping sets.
+fn expand_grouping_element(elem: &GroupingElement<Aug>) -> Result<Vec<Vec<&Expr<Aug>>>, PlanError> {
+    // The elements of a `ROLLUP` or `CUBE` are each added to or removed from
+    // grouping sets as a unit.
+    fn units(elems: &[GroupingElement<Aug>]) -> Result<Vec<Vec<&Expr<Aug>>>, PlanError> {
+        let mut units = vec![];
+        for elem in elems {
+            match elem {
+                GroupingElement::Expr(expr) => units.push(vec![expr]),
+                GroupingElement::List(exprs) => units.push(exprs.iter().collect()),
+                _ => sql_bail!("ROLLUP and CUBE may only contain expressions and lists"),
+            }
+        }
+        Ok(units)
+    }
+    match elem {
+        GroupingElement::Expr(expr) => Ok(vec![vec![expr]]),
+        GroupingElement::List(exprs) => Ok(vec![exprs.iter().collect()]),
+        GroupingElement::Rollup(elems) => {
+            let units = units(elems)?;
+            Ok((0..=units.len())
+                .rev()
+                .map(|len| units[..len].concat())
+                .collect())
+        }
+        GroupingElement::Cube(elems) => {
+            let units = units(elems)?;
+            if units.len() > MAX_CUBE_ELEMENTS {
+                sql_bail!("CUBE is limited to {} elements", MAX_CUBE_ELEMENTS);
+            }
+            // Enumerate the subsets of `units` from the full set down to the
+            // empty set, where bit `i` of the mask counting from the most
+            // significant bit selects the `i`th unit.
+            Ok((0..1usize << units.len())
+                .rev()
+                .map(|mask| {
+                    units
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| mask & (1 << (units.len() - 1 - i)) != 0)
+                        .flat_map(|(_, unit)| unit.iter().copied())
+                        .collect()
+                })
+                .collect())
+        }
+        GroupingElement::GroupingSets(elems) => {
+            let mut sets = vec![];
+            for elem in elems {
+                sets.extend(expand_grouping_element(elem)?);
+            }
+            Ok(sets)
+        }
+    }
+}
+
+/// Reports whether `func` is a call to the `grouping` function.
+fn is_grouping_func(func: &Function<Aug>) -> bool {
+    match &func.name {
+        ResolvedItemName::Item { full_name, .. } => {
+            full_name.schema == MZ_CATALOG_SCHEMA && full_name.item == "grouping"
+        }
+        _ => false,
+    }
+}
+
+/// Plans a call to `grouping`, which returns a bit mask with a bit set for
+/// each of its arguments that is not part of the grouping set of the current
+/// row. The first argument corresponds to the most significant bit.
+///
+/// The arguments must be among `group_exprs`. `include_columns` gives, for
+/// each of `group_exprs`, the column of the reduction that indicates whether
+/// the expression is part of the row's grouping set; if absent, the query has
+/// a single grouping set that contains all of `group_exprs`.
+fn plan_grouping_func(
+    ecx: &ExprContext,
+    func: &Function<Aug>,
+    group_exprs: &[HirScalarExpr],
+    include_columns: Option<&[usize]>,
+) -> Result<HirScalarExpr, PlanError> {
+    let args = match &func.args {
+        FunctionArgs::Args {
+            args,
+            order_by,
+            within_group: false,
+        } if order_by.is_empty() && func.filter.is_none() && !func.distinct => args,
+        FunctionArgs::Star => sql_bail!("grouping requires at least one argument"),
+        _ => sql_bail!("grouping does not support DISTINCT, FILTER, or ORDER BY"),
+    };
+    if args.is_empty() {
+        sql_bail!("grouping requires at least one argument");
+    }
+    if args.len() > 31 {
+        sql_bail!("GROUPING must have fewer than 32 arguments");
+    }
+    let mut expr = HirScalarExpr::literal(Datum::Int32(0), SqlScalarType::Int32);
+    for (i, arg) in args.iter().enumerate() {
+        let arg = plan_expr(ecx, arg)?.type_as_any(ecx)?;
+        let Some(position) = group_exprs.iter().position(|e| *e == arg) else {
+            sql_bail!(
+                "arguments to GROUPING must be grouping expressions of the associated query level"
+            );
+        };
+        if let Some(include_columns) = include_columns {
+            let bit = 1 << (args.len() - 1 - i);
+            expr = expr.call_binary(
+                HirScalarExpr::if_then_else(
+                    HirScalarExpr::column(include_columns[position]),
+                    HirScalarExpr::literal(Datum::Int32(0), SqlScalarType::Int32),
+                    HirScalarExpr::literal(Datum::Int32(bit), SqlScalarType::Int32),
+                ),
+                expr_func::AddInt32,
+            );
+        }
+    }
+    Ok(expr)
+}
+
+/// Plans the reduction of `input` by several grouping sets.
+///
+/// `input` consists of `input_arity` columns followed by one column per
+/// grouping expression, whose types are `key_types`. Each grouping set is the
+/// set of grouping expressions that it groups by.
+///
+/// The non-empty grouping sets share a single reduction: each input row is
+/// paired with each grouping set, the grouping expressions that are not part of
+/// the set are replaced with `NULL`, and the index of the grouping set becomes
+/// part of the grouping key. Each empty grouping set is planned as its own
+/// global reduction, so that it produces a row even when the input is empty.
+///
+/// The result consists of the grouping key, a flag per grouping expression
+/// indicating whether it is part of the row's grouping set, the index of the
+/// row's grouping set, and the aggregates.
+fn plan_grouping_sets(
+    input: HirRelationExpr,
+    input_arity: usize,
+    key_types: &[SqlScalarType],
+    grouping_sets: &[BTreeSet<usize>],
+    aggregates: Vec<AggregateExpr>,
+    expected_group_size: Option<u64>,
+) -> HirRelationExpr {
+    let key_arity = key_types.len();
+    let gid = |i: usize| i32::try_from(i).expect("bounded by MAX_GROUPING_SETS");
+    let (empty_sets, sets): (Vec<_>, Vec<_>) = grouping_sets
+        .iter()
+        .enumerate()
+        .partition(|(_, set)| set.is_empty());
+
+    let mut terms = vec![];
+    if !sets.is_empty() {
+        let rows = sets
+            .iter()
+            .map(|(i, set)| {
+                iter::once(Datum::Int32(gid(*i)))
+                    .chain((0..key_arity).map(|j| Datum::from(set.contains(&j))))
+                    .collect()
+            })
+            .collect();
+        let typ = SqlRelationType::new(
+            iter::once(SqlScalarType::Int32.nullable(false))
+                .chain(iter::repeat_n(
+                    SqlScalarType::Bool.nullable(false),
+                    key_arity,
+                ))
+                .collect(),
+        );
+        let gid_column = input_arity + key_arity;
+        let include_start = gid_column + 1;
+        let key_start = include_start + key_arity;
+        let keys = key_types
+            .iter()
+            .enumerate()
+            .map(|(j, typ)| {
+                HirScalarExpr::if_then_else(
+                    HirScalarExpr::column(include_start + j),
+                    HirScalarExpr::column(input_arity + j),
+                    HirScalarExpr::literal_null(typ.clone()),
+                )
+            })
+            .collect();
+        let group_key = (key_start..key_start + key_arity)
+            .chain(include_start..include_start + key_arity)
+            .chain(iter::once(gid_column))
+            .collect();
+        terms.push(
+            input
+                .clone()
+                .join(
+                    HirRelationExpr::constant(rows, typ),
+                    HirScalarExpr::literal_true(),
+                    JoinKind::Inner,
+                )
+                .map(keys)
+                .reduce(group_key, aggregates.clone(), expected_group_size),
+        );
+    }
+    let agg_arity = aggregates.len();
+    for (i, _) in empty_sets {
+        let scalars = key_types
+            .iter()
+            .map(|typ| HirScalarExpr::literal_null(typ.clone()))
+            .chain(iter::repeat_n(HirScalarExpr::literal_false(), key_arity))
+            .chain(iter::once(HirScalarExpr::literal(
+                Datum::Int32(gid(i)),
+                SqlScalarType::Int32,
+            )))
+            .collect();
+        let outputs = (agg_arity..agg_arity + 2 * key_arity + 1)
+            .chain(0..agg_arity)
+            .collect();
+        terms.push(
+            input
+                .clone()
+                .reduce(vec![], aggregates.clone(), expected_group_size)
+                .map(scalars)
+                .project(outputs),
+        );
+    }
+    terms
+        .into_iter()
+        .reduce(HirRelationExpr::union)
+        .expect("at least one grouping set")
+}
+
 /// Plans a slice of `ORDER BY` expressions.
 ///
 /// See `plan_order_by_or_distinct_expr` for details on the `output_columns`
@@ -3015,9 +3384,16 @@ fn plan_table_factor(
     table_factor: &TableFactor<Aug>,
 ) -> Result<(HirRelationExpr, Scope), PlanError> {
     match table_factor {
-        TableFactor::Table { name, alias } => {
-            let (expr, scope) = qcx.resolve_table_name(name.clone())?;
+        TableFactor::Table {
+            name,
+            alias,
+            sample,
+        } => {
+            let (mut expr, scope) = qcx.resolve_table_name(name.clone())?;
             let scope = plan_table_alias(scope, alias.as_ref())?;
+            if let Some(sample) = sample {
+                expr = plan_table_sample(qcx, expr, name, sample)?;
+            }
             Ok((expr, scope))
         }
 
@@ -3320,7 +3696,7 @@ fn plan_table_function_internal(
 
     let scalar_args = match args {
         FunctionArgs::Star => sql_bail!("{} does not accept * as an argument", name),
-        FunctionArgs::Args { args, order_by } => {
+        FunctionArgs::Args { args, order_by, .. } => {
             if !order_by.is_empty() {
                 sql_bail!(
                     "ORDER BY specified, but {} is not an aggregate function",
@@ -3342,7 +3718,7 @@ fn plan_table_function_internal(
         item: table_name,
     });
 
-    let (expr, mut scope) = match resolve_func(ecx, name, args)? {
+    let (expr, mut scope) = match &*resolve_func(ecx, name, args)? {
         Func::Table(impls) => {
             let tf = func::select_impl(ecx, FuncSpec::Func(name), impls, scalar_args, vec![])?;
             let scope = Scope::from_source(scope_name.clone(), tf.column_names);
@@ -3441,6 +3817,79 @@ fn plan_table_function_internal(
     Ok((expr, scope))
 }
 
+/// Plans a `TABLESAMPLE` clause on `expr`, the relation named by `name`.
+///
+/// The sample is planned as a filter with a [`expr_func::TableSample`] predicate,
+/// which keeps a row if a hash of the row (seeded with the `REPEATABLE` seed, or a
+/// random one) falls below the sampling rate. Readers of monotonic persist shards
+/// replace a `SYSTEM` predicate by sampling entire parts of the shard instead.
+fn plan_table_sample(
+    qcx: &QueryContext,
+    expr: HirRelationExpr,
+    name: &ResolvedItemName,
+    sample: &TableSample<Aug>,
+) -> Result<HirRelationExpr, PlanError> {
+    // The sample is only meaningful at a single point in time: a maintained
+    // dataflow would have to decide again for every update.
+    if !qcx.lifetime.is_one_shot() {
+        sql_bail!("TABLESAMPLE is only supported in one-off queries");
+    }
+    let is_collection = match name {
+        ResolvedItemName::Item { id, .. } => matches!(
+            qcx.scx.get_item(id).item_type(),
+            CatalogItemType::Table | CatalogItemType::Source | CatalogItemType::MaterializedView
+        ),
+        _ => false,
+    };
+    if !is_collection {
+        sql_bail!(
+            "TABLESAMPLE clause can only be applied to tables, sources, and materialized views"
+        );
+    }
+
+    let ecx = &ExprContext {
+        qcx,
+        name: "TABLESAMPLE",
+        scope: &Scope::empty(),
+        relation_type: &SqlRelationType::empty(),
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    let percentage = plan_expr(ecx, &sample.percentage)?
+        .cast_to(ecx, CastContext::Explicit, &SqlScalarType::Float64)?
+        .into_literal_float64();
+    let percentage = match percentage {
+        Some(percentage) if (0.0..=100.0).contains(&percentage) => percentage,
+        Some(_) => sql_bail!("sample percentage must be between 0 and 100"),
+        None => sql_bail!("TABLESAMPLE parameter cannot be null"),
+    };
+    let seed = match &sample.repeatable {
+        Some(seed) => {
+            let seed = plan_expr(ecx, seed)?
+                .cast_to(ecx, CastContext::Explicit, &SqlScalarType::Float64)?
+                .into_literal_float64();
+            match seed {
+                Some(seed) => seed.to_bits(),
+                None => sql_bail!("TABLESAMPLE REPEATABLE parameter cannot be null"),
+            }
+        }
+        None => rand::random(),
+    };
+
+    let method = match sample.method {
+        TableSampleMethod::Bernoulli => expr_func::TableSampleMethod::Bernoulli,
+        TableSampleMethod::System => expr_func::TableSampleMethod::System,
+    };
+    let columns = (0..expr.arity()).map(HirScalarExpr::column).collect();
+    let predicate = HirScalarExpr::call_variadic(
+        expr_func::TableSample::new(method, percentage, seed),
+        columns,
+    );
+    Ok(expr.filter(vec![predicate]))
+}
+
 fn plan_table_alias(mut scope: Scope, alias: Option<&TableAlias>) -> Result<Scope, PlanError> {
     if let Some(TableAlias {
         name,
@@ -5186,7 +5635,8 @@ fn plan_aggregate_common(
     // prevents the projection into Record below from triggering on unsupported
     // functions.
 
-    let impls = match resolve_func(ecx, name, args)? {
+    let resolved = resolve_func(ecx, name, args)?;
+    let impls = match &*resolved {
         Func::Aggregate(impls) => impls,
         _ => unreachable!("plan_aggregate_common called on non-aggregate function,"),
     };
@@ -5199,10 +5649,14 @@ fn plan_aggregate_common(
     // rules to all aggregates, not just `count`, since we may one day support
     // user-defined aggregates, including user-defined aggregates that take no
     // parameters.
-    let (args, order_by) = match &args {
-        FunctionArgs::Star => (vec![], vec![]),
-        FunctionArgs::Args { args, order_by } => {
-            if args.is_empty() {
+    let (args, order_by, within_group) = match &args {
+        FunctionArgs::Star => (vec![], vec![], false),
+        FunctionArgs::Args {
+            args,
+            order_by,
+            within_group,
+        } => {
+            if args.is_empty() && !within_group {
                 sql_bail!(
                     "{}(*) must be used to call a parameterless aggregate function",
                     ecx.qcx
@@ -5212,13 +5666,43 @@ fn plan_aggregate_common(
                 );
             }
             let args = plan_exprs(ecx, args)?;
-            (args, order_by.clone())
+            (args, order_by.clone(), *within_group)
         }
     };
 
     let (order_by_exprs, col_orders) = plan_function_order_by(ecx, &order_by)?;
 
+    // Ordered-set aggregates, like `percentile_cont`, take their aggregated
+    // arguments from the `WITHIN GROUP (ORDER BY ...)` clause. Following
+    // PostgreSQL, these are passed to function selection after the direct
+    // arguments, and the sort order is provided via `col_orders`.
+    let (args, order_by_exprs) = if within_group {
+        let mut args = args;
+        args.extend(order_by_exprs);
+        (args, vec![])
+    } else {
+        (args, order_by_exprs)
+    };
+
     let (mut expr, func) = func::select_impl(ecx, FuncSpec::Func(name), impls, args, col_orders)?;
+    if func.is_ordered_set() != within_group {
+        let name = ecx
+            .qcx
+            .scx
+            .humanize_resolved_name(name)
+            .expect("name actually resolved");
+        if within_group {
+            sql_bail!(
+                "{} is not an ordered-set aggregate, so it cannot have WITHIN GROUP",
+                name
+            );
+        } else {
+            sql_bail!(
+                "WITHIN GROUP is required for ordered-set aggregate {}",
+                name
+            );
+        }
+    }
     if let Some(filter) = &filter {
         // If a filter is present, as in
         //
@@ -5315,12 +5799,17 @@ fn plan_identifier(ecx: &ExprContext, names: &[Ident]) -> Result<HirScalarExpr,
         },
     )?;
     match items.as_slice() {
-        // The name doesn't refer to a table either. Return an error.
-        [] => Err(PlanError::UnknownColumn {
-            table: None,
-            column: col_name,
-            similar: similar_names,
-        }),
+        // The name doesn't refer to a table either. If we're planning the body
+        // of a SQL function, it might refer to one of the function's named
+        // parameters. Otherwise, return an error.
+        [] => match ecx.qcx.scx.param_names.get(&col_name) {
+            Some(n) => Ok(HirScalarExpr::parameter(*n)),
+            None => Err(PlanError::UnknownColumn {
+                table: None,
+                column: col_name,
+                similar: similar_names,
+            }),
+        },
         // The name refers to a table that is the result of a function that
         // returned a single column. Per PostgreSQL, this is a special case
         // that returns the value directly.
@@ -5393,7 +5882,8 @@ fn plan_function<'a>(
         distinct,
     }: &'a Function<Aug>,
 ) -> Result<HirScalarExpr, PlanError> {
-    let impls = match resolve_func(ecx, name, args)? {
+    let resolved = resolve_func(ecx, name, args)?;
+    let impls = match &*resolved {
         Func::Table(_) => {
             sql_bail!(
                 "table functions are not allowed in {} (function {})",
@@ -5537,6 +6027,16 @@ fn plan_function<'a>(
 
                 let aggregate_expr = plan_aggregate_common(ecx, f)?;
 
+                if aggregate_expr.func.is_ordered_set() {
+                    sql_bail!(
+                        "OVER is not supported for ordered-set aggregate {}",
+                        ecx.qcx
+                            .scx
+                            .humanize_resolved_name(name)
+                            .expect("already resolved")
+                    );
+                }
+
                 if aggregate_expr.distinct {
                     // https://github.com/MaterializeInc/database-issues/issues/6626
                     bail_unsupported!("DISTINCT in window aggregates");
@@ -5588,7 +6088,7 @@ fn plan_function<'a>(
                     .expect("already resolved")
             )
         }
-        FunctionArgs::Args { args, order_by } => {
+        FunctionArgs::Args { args, order_by, .. } => {
             if !order_by.is_empty() {
                 sql_bail!(
                     "ORDER BY specified, but {} is not an aggregate function",
@@ -5608,17 +6108,41 @@ fn plan_function<'a>(
 pub const IGNORE_NULLS_ERROR_MSG: &str =
     "IGNORE NULLS and RESPECT NULLS options for functions other than LAG and LEAD";
 
+/// A function resolved by [`resolve_func`].
+pub enum ResolvedFunc {
+    /// A built-in function, whose definition is static.
+    Builtin(&'static Func),
+    /// A user-defined SQL function, whose definition is constructed from its
+    /// catalog entry.
+    Sql(Func),
+}
+
+impl std::ops::Deref for ResolvedFunc {
+    type Target = Func;
+
+    fn deref(&self) -> &Func {
+        match self {
+            ResolvedFunc::Builtin(func) => func,
+            ResolvedFunc::Sql(func) => func,
+        }
+    }
+}
+
 /// Resolves the name to a set of function implementations.
 ///
-/// If the name does not specify a known built-in function, returns an error.
+/// If the name does not specify a known built-in or user-defined function,
+/// returns an error.
 pub fn resolve_func(
     ecx: &ExprContext,
     name: &ResolvedItemName,
     args: &mz_sql_parser::ast::FunctionArgs<Aug>,
-) -> Result<&'static Func, PlanError> {
+) -> Result<ResolvedFunc, PlanError> {
     if let Ok(i) = ecx.qcx.scx.get_item_by_resolved_name(name) {
         if let Ok(f) = i.func() {
-            return Ok(f);
+            return Ok(ResolvedFunc::Builtin(f));
+        }
+        if let Some(details) = i.func_details() {
+            return Ok(ResolvedFunc::Sql(func::sql_func(i.oid(), details)));
         }
     }
 
@@ -5626,7 +6150,7 @@ pub fn resolve_func(
     // message.
     let cexprs = match args {
         mz_sql_parser::ast::FunctionArgs::Star => vec![],
-        mz_sql_parser::ast::FunctionArgs::Args { args, order_by } => {
+        mz_sql_parser::ast::FunctionArgs::Args { args, order_by, .. } => {
             if !order_by.is_empty() {
                 sql_bail!(
                     "ORDER BY specified, but {} is not an aggregate function",
@@ -5652,6 +6176,152 @@ pub fn resolve_func(
     })
 }
 
+/// Reports whether `item` is a function that returns a set of rows, i.e., a
+/// built-in or user-defined table function.
+fn is_table_func<I: CatalogItem + ?Sized>(item: &I) -> bool {
+    matches!(item.func(), Ok(Func::Table { .. }))
+        || item
+            .func_details()
+            .is_some_and(|details| details.returns_set)
+}
+
+/// Constructs a statement context for planning the body of the SQL function
+/// described by `details`. The function's parameters are bound to `$1`, `$2`,
+/// ..., and can also be referred to by name.
+fn sql_function_context<'a>(
+    scx: &StatementContext<'a>,
+    details: &CatalogFuncDetails,
+) -> StatementContext<'a> {
+    let mut scx = scx.clone();
+    scx.param_types = RefCell::new(
+        details
+            .params
+            .iter()
+            .enumerate()
+            .map(|(i, (_, typ))| (i + 1, typ.clone()))
+            .collect(),
+    );
+    scx.param_names = details
+        .params
+        .iter()
+        .enumerate()
+        .map(|(i, (name, _))| (name.clone(), i + 1))
+        .collect();
+    scx
+}
+
+/// Returns the expression computed by a scalar SQL function body of the form
+/// `SELECT <expr>`, which can be inlined directly into the calling expression.
+fn inlinable_function_body(query: &Query<Aug>) -> Option<&Expr<Aug>> {
+    if !query.ctes.is_empty()
+        || !query.order_by.is_empty()
+        || query.limit.is_some()
+        || query.offset.is_some()
+    {
+        return None;
+    }
+    let SetExpr::Select(select) = &query.body else {
+        return None;
+    };
+    match select.as_ref() {
+        Select {
+            distinct: None,
+            projection,
+            from,
+            selection: None,
+            group_by,
+            having: None,
+            qualify: None,
+            options,
+        } if from.is_empty() && group_by.is_empty() && options.is_empty() => {
+            match projection.as_slice() {
+                [SelectItem::Expr { expr, .. }] => Some(expr),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Plans the body of the scalar SQL function described by `details`.
+///
+/// The function's parameters are planned as `$1`, `$2`, ..., which the caller
+/// is expected to splice with the function's arguments.
+pub(crate) fn plan_sql_scalar_function(
+    ecx: &ExprContext,
+    details: &CatalogFuncDetails,
+) -> Result<HirScalarExpr, PlanError> {
+    let scx = sql_function_context(ecx.qcx.scx, details);
+    let qcx = QueryContext::root(&scx, ecx.qcx.lifetime);
+
+    let mut body = details.body.clone();
+    transform_ast::transform(&scx, &mut body)?;
+    // Bodies that compute a single expression are inlined directly, so that
+    // the function does not introduce a subquery into its caller.
+    let body = match inlinable_function_body(&body) {
+        Some(expr) => expr.clone(),
+        None => Expr::Subquery(Box::new(body)),
+    };
+
+    let ecx = ExprContext {
+        qcx: &qcx,
+        name: "SQL function body",
+        scope: &Scope::empty(),
+        relation_type: &SqlRelationType::empty(),
+        allow_aggregates: false,
+        allow_subqueries: true,
+        allow_parameters: true,
+        allow_windows: false,
+    };
+    let expr = plan_expr(&ecx, &body)?.type_as_any(&ecx)?;
+    let (_, return_type) = details
+        .returns
+        .first()
+        .expect("scalar functions return exactly one column");
+    typeconv::plan_cast(&ecx, CastContext::Assignment, expr, return_type).map_err(|_| {
+        sql_err!(
+            "return type mismatch in function declared to return {}",
+            ecx.humanize_sql_scalar_type(return_type, false)
+        )
+    })
+}
+
+/// Plans the body of the SQL table function described by `details`.
+///
+/// The function's parameters are planned as `$1`, `$2`, ..., which the caller
+/// is expected to splice with the function's arguments.
+pub(crate) fn plan_sql_table_function(
+    qcx: &QueryContext,
+    details: &CatalogFuncDetails,
+) -> Result<HirRelationExpr, PlanError> {
+    let scx = sql_function_context(qcx.scx, details);
+    let mut qcx = QueryContext::root(&scx, qcx.lifetime);
+
+    let mut body = details.body.clone();
+    transform_ast::transform(&scx, &mut body)?;
+    let (expr, _) = plan_nested_query(&mut qcx, &body)?;
+
+    let arity = qcx.relation_type(&expr).arity();
+    if arity != details.returns.len() {
+        sql_bail!(
+            "return type mismatch in function declared to return table: \
+            body returns {} columns, but {} columns were declared",
+            arity,
+            details.returns.len()
+        );
+    }
+    let return_types = details.returns.iter().map(|(_, typ)| typ);
+    cast_relation(&qcx, CastContext::Assignment, expr, return_types).map_err(|e| {
+        sql_err!(
+            "return type mismatch in function declared to return table: \
+            column {} is of type {} but body returns type {}",
+            details.returns[e.column].0.quoted(),
+            qcx.humanize_sql_scalar_type(&e.target_type, false),
+            qcx.humanize_sql_scalar_type(&e.source_type, false),
+        )
+    })
+}
+
 fn plan_is_expr<'a>(
     ecx: &ExprContext,
     expr: &'a Expr<Aug>,
@@ -5828,7 +6498,7 @@ fn plan_window_function_non_aggr<'a>(
         FunctionArgs::Star => {
             sql_bail!("* argument is invalid with non-aggregate function {}", name)
         }
-        FunctionArgs::Args { args, order_by } => {
+        FunctionArgs::Args { args, order_by, .. } => {
             if !order_by.is_empty() {
                 sql_bail!(
                     "ORDER BY specified, but {} is not an aggregate function",
@@ -6330,7 +7000,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                 self.within_aggregate = old_within_aggregate;
                 self.table_disallowed_context.pop();
             }
-            Ok(Func::Table { .. }) => {
+            _ if is_table_func(&*item) => {
                 self.table_disallowed_context.push("other table functions");
                 visit_mut::visit_function_mut(self, func);
                 self.table_disallowed_context.pop();
@@ -6355,7 +7025,7 @@ impl<'a> VisitMut<'_, Aug> for AggregateTableFuncVisitor<'a> {
                 // and save the table func so it can be planned elsewhere.
                 let mut table_func = None;
                 if let Ok(item) = self.scx.get_item_by_resolved_name(&func.name) {
-                    if let Ok(Func::Table { .. }) = item.func() {
+                    if is_table_func(&*item) {
                         if let Some(context) = self.table_disallowed_context.last() {
                             self.err = Some(sql_err!(
                                 "table functions are not allowed in {} (function {})",
@@ -6621,6 +7291,7 @@ impl<'a> QueryContext<'a> {
 
                 let name = full_name.into();
                 let scope = Scope::from_source(Some(name), desc.iter_names().cloned());
+                let expr = self.plan_row_level_security(&*item, expr, &scope)?;
 
                 Ok((expr, scope))
             }
@@ -6651,6 +7322,65 @@ impl<'a> QueryContext<'a> {
         }
     }
 
+    /// Applies the row-level security policies of `item` to `expr`, a `Get`
+    /// of `item` whose columns are described by `scope`.
+    ///
+    /// Roles that bypass row-level security (see
+    /// [`bypasses_row_level_security`]) see all rows. Other roles only see the
+    /// rows that satisfy at least one of the policies that apply to them, and
+    /// no rows at all if no policy applies.
+    ///
+    /// Policies are only applied to one-shot queries and `SUBSCRIBE`s.
+    /// Maintained objects, like views and indexes, are computed with the
+    /// privileges of their owner, so only roles that bypass row-level security
+    /// can create them.
+    fn plan_row_level_security<I: CatalogItem + ?Sized>(
+        &self,
+        item: &I,
+        expr: HirRelationExpr,
+        scope: &Scope,
+    ) -> Result<HirRelationExpr, PlanError> {
+        let role_id = self.scx.catalog.active_role_id();
+        if !item.row_level_security()
+            || bypasses_row_level_security(self.scx.catalog, role_id, &item.owner_id())
+        {
+            return Ok(expr);
+        }
+        match self.lifetime {
+            QueryLifetime::OneShot | QueryLifetime::Subscribe => {}
+            QueryLifetime::Index
+            | QueryLifetime::MaterializedView
+            | QueryLifetime::View
+            | QueryLifetime::Source => {
+                return Err(PlanError::RowLevelSecurityDependency {
+                    name: self.scx.catalog.resolve_full_name(item.name()).to_string(),
+                    role: self.scx.catalog.get_role(role_id).name().to_string(),
+                });
+            }
+        }
+
+        let role_membership = self.scx.catalog.collect_role_membership(role_id);
+        let relation_type = self.relation_type(&expr);
+        let mut predicates = vec![];
+        for id in item.used_by() {
+            let Some(policy) = self.scx.catalog.get_item(id).policy_details() else {
+                continue;
+            };
+            let applies = policy.on == item.id()
+                && (policy.roles.is_empty()
+                    || policy.roles.iter().any(|id| role_membership.contains(id)));
+            if applies {
+                predicates.push(plan_policy_using(
+                    self,
+                    scope,
+                    &relation_type,
+                    &policy.using,
+                )?);
+            }
+        }
+        Ok(expr.filter(vec![HirScalarExpr::variadic_or(predicates)]))
+    }
+
     /// The returned String is more detailed when the `postgres_compat` flag is not set. However,
     /// the flag should be set in, e.g., the implementation of the `pg_typeof` function.
     pub fn humanize_sql_scalar_type(&self, typ: &SqlScalarType, postgres_compat: bool) -> String {
@@ -6658,6 +7388,42 @@ impl<'a> QueryContext<'a> {
     }
 }
 
+/// Reports whether `role_id` sees all rows of a relation owned by `owner_id`,
+/// regardless of the row-level security policies on the relation.
+///
+/// Members of the owning role, and `mz_system`, bypass row-level security.
+/// Note that, unlike in PostgreSQL, superusers do not.
+pub fn bypasses_row_level_security(
+    catalog: &dyn SessionCatalog,
+    role_id: &RoleId,
+    owner_id: &RoleId,
+) -> bool {
+    *role_id == MZ_SYSTEM_ROLE_ID || catalog.collect_role_membership(role_id).contains(owner_id)
+}
+
+/// Plans the `USING` expression of a row-level security policy against a
+/// relation whose columns are described by `scope` and `relation_type`.
+pub(crate) fn plan_policy_using(
+    qcx: &QueryContext,
+    scope: &Scope,
+    relation_type: &SqlRelationType,
+    using: &Expr<Aug>,
+) -> Result<HirScalarExpr, PlanError> {
+    let mut using = using.clone();
+    transform_ast::transform(qcx.scx, &mut using)?;
+    let ecx = ExprContext {
+        qcx,
+        name: "USING clause",
+        scope,
+        relation_type,
+        allow_aggregates: false,
+        allow_subqueries: false,
+        allow_parameters: false,
+        allow_windows: false,
+    };
+    plan_expr(&ecx, &using)?.type_as(&ecx, &SqlScalarType::Bool)
+}
+
 /// A bundle of unrelated things that we need for planning `Expr`s.
 #[derive(Debug, Clone)]
 pub struct ExprContext<'a> {
@@ -35,13 +35,15 @@ use std::sync::LazyLock;
 use enum_kinds::EnumKind;
 use itertools::Itertools;
 use mz_ore::cast::ReinterpretCast;
-use mz_ore::collections::CollectionExt;
 use mz_ore::result::ResultExt;
-use mz_repr::SqlRelationType;
+use mz_repr::{CatalogItemId, SqlRelationType};
 use mz_repr::{Datum, RelationDesc, RowArena, SqlColumnType, SqlScalarType};
-use mz_sql_parser::ast::{CteBlock, Expr, Function, FunctionArgs, Select, SelectItem, SetExpr};
+use mz_sql_parser::ast::{
+    CteBlock, Expr, Function, FunctionArgs, RawItemName, Select, SelectItem, SetExpr,
+};
 
 use crate::ast::{Query, SelectStatement};
+use crate::catalog::CatalogItemType;
 use crate::func::Func;
 use crate::names::Aug;
 use crate::plan::query::{self, ExprContext, QueryLifetime};
@@ -63,6 +65,25 @@ pub enum SideEffectingFunc {
         // The ID of the connection to cancel.
         connection_id: u32,
     },
+    /// The `nextval` function.
+    NextVal {
+        /// The ID of the sequence to advance.
+        id: CatalogItemId,
+    },
+    /// The `currval` function.
+    CurrVal {
+        /// The ID of the sequence whose value to return.
+        id: CatalogItemId,
+    },
+    /// The `setval` function.
+    SetVal {
+        /// The ID of the sequence to set.
+        id: CatalogItemId,
+        /// The new value of the sequence.
+        value: i64,
+        /// Whether `value` counts as already returned by `nextval`.
+        is_called: bool,
+    },
 }
 
 /// Describes a `SELECT` if it contains calls to side-effecting functions.
@@ -113,7 +134,11 @@ pub fn plan_select_if_side_effecting(
         datums.push(datum);
     }
 
-    let func = (sef_call.imp.plan_fn)(&datums);
+    if datums.iter().any(|datum| datum.is_null()) {
+        sql_bail!("{} does not accept NULL arguments", sef_call.imp.name);
+    }
+
+    let func = (sef_call.imp.plan_fn)(scx, &datums)?;
 
     Ok(Some(func))
 }
@@ -163,7 +188,12 @@ fn extract_sef_call(
             expr:
                 Expr::Function(Function {
                     name,
-                    args: FunctionArgs::Args { args, order_by },
+                    args:
+                        FunctionArgs::Args {
+                            args,
+                            order_by,
+                            within_group: false,
+                        },
                     filter: None,
                     over: None,
                     distinct: false,
@@ -178,33 +208,31 @@ fn extract_sef_call(
         return Ok(None);
     }
 
-    // Check if the called function is a scalar function with exactly one
-    // implementation. All side-effecting functions have only a single
-    // implementation.
+    // Check if the called function is a scalar function.
     let Ok(func) = scx
         .get_item_by_resolved_name(name)
         .and_then(|item| item.func().err_into())
     else {
         return Ok(None);
     };
-    let func_impl = match func {
-        Func::Scalar(impls) if impls.len() == 1 => impls.into_element(),
-        _ => return Ok(None),
-    };
-
-    // Check whether the implementation is a known side-effecting function.
-    let Some(sef_impl) = PG_CATALOG_SEF_BUILTINS.get(&func_impl.oid) else {
+    let Func::Scalar(impls) = func else {
         return Ok(None);
     };
 
-    // Check that the number of provided arguments matches the function
-    // signature.
-    if args.len() != sef_impl.param_types.len() {
-        // We return `Ok(None)` instead of an error for the same reason to let
-        // the function selection code produce the standard "no function matches
-        // the given name and argument types" error.
+    // Find the known side-effecting implementation whose signature matches the
+    // number of provided arguments. Side-effecting functions are overloaded
+    // only by arity.
+    //
+    // If there is none, we return `Ok(None)` instead of an error to let the
+    // function selection code produce the standard "no function matches the
+    // given name and argument types" error.
+    let Some(sef_impl) = impls
+        .iter()
+        .filter_map(|func_impl| PG_CATALOG_SEF_BUILTINS.get(&func_impl.oid))
+        .find(|sef_impl| sef_impl.param_types.len() == args.len())
+    else {
         return Ok(None);
-    }
+    };
 
     // Plan and coerce all argument expressions.
     let mut args_out = vec![];
@@ -266,17 +294,23 @@ pub struct SideEffectingFuncImpl {
     pub return_type: SqlColumnType,
     /// A function that will produce a `SideEffectingFunc` given arguments
     /// that have been evaluated to `Datum`s.
-    pub plan_fn: fn(&[Datum]) -> SideEffectingFunc,
+    pub plan_fn: fn(&StatementContext, &[Datum]) -> Result<SideEffectingFunc, PlanError>,
 }
 
 /// A map of the side-effecting functions in the `pg_catalog` schema, keyed by
 /// OID.
 pub static PG_CATALOG_SEF_BUILTINS: LazyLock<BTreeMap<u32, SideEffectingFuncImpl>> =
     LazyLock::new(|| {
-        [PG_CANCEL_BACKEND]
-            .into_iter()
-            .map(|f| (f.oid, f))
-            .collect()
+        [
+            PG_CANCEL_BACKEND,
+            NEXTVAL,
+            CURRVAL,
+            SETVAL,
+            SETVAL_IS_CALLED,
+        ]
+        .into_iter()
+        .map(|f| (f.oid, f))
+        .collect()
     });
 
 // Implementations of each side-effecting function follow.
@@ -288,9 +322,81 @@ const PG_CANCEL_BACKEND: SideEffectingFuncImpl = SideEffectingFuncImpl {
     oid: 2171,
     param_types: &[SqlScalarType::Int32],
     return_type: SqlScalarType::Bool.nullable(false),
-    plan_fn: |datums| -> SideEffectingFunc {
-        SideEffectingFunc::PgCancelBackend {
+    plan_fn: |_scx, datums| -> Result<SideEffectingFunc, PlanError> {
+        Ok(SideEffectingFunc::PgCancelBackend {
             connection_id: u32::reinterpret_cast(datums[0].unwrap_int32()),
-        }
+        })
     },
 };
+
+// The sequence functions accept the name of the sequence as `text` rather than
+// `regclass`, as casts to `regclass` cannot be eagerly evaluated.
+
+const NEXTVAL: SideEffectingFuncImpl = SideEffectingFuncImpl {
+    name: "nextval",
+    oid: 1574,
+    param_types: &[SqlScalarType::String],
+    return_type: SqlScalarType::Int64.nullable(false),
+    plan_fn: |scx, datums| -> Result<SideEffectingFunc, PlanError> {
+        Ok(SideEffectingFunc::NextVal {
+            id: resolve_sequence(scx, datums[0].unwrap_str())?,
+        })
+    },
+};
+
+const CURRVAL: SideEffectingFuncImpl = SideEffectingFuncImpl {
+    name: "currval",
+    oid: 1575,
+    param_types: &[SqlScalarType::String],
+    return_type: SqlScalarType::Int64.nullable(false),
+    plan_fn: |scx, datums| -> Result<SideEffectingFunc, PlanError> {
+        Ok(SideEffectingFunc::CurrVal {
+            id: resolve_sequence(scx, datums[0].unwrap_str())?,
+        })
+    },
+};
+
+const SETVAL: SideEffectingFuncImpl = SideEffectingFuncImpl {
+    name: "setval",
+    oid: 1576,
+    param_types: &[SqlScalarType::String, SqlScalarType::Int64],
+    return_type: SqlScalarType::Int64.nullable(false),
+    plan_fn: |scx, datums| -> Result<SideEffectingFunc, PlanError> {
+        Ok(SideEffectingFunc::SetVal {
+            id: resolve_sequence(scx, datums[0].unwrap_str())?,
+            value: datums[1].unwrap_int64(),
+            is_called: true,
+        })
+    },
+};
+
+const SETVAL_IS_CALLED: SideEffectingFuncImpl = SideEffectingFuncImpl {
+    name: "setval",
+    oid: 1765,
+    param_types: &[
+        SqlScalarType::String,
+        SqlScalarType::Int64,
+        SqlScalarType::Bool,
+    ],
+    return_type: SqlScalarType::Int64.nullable(false),
+    plan_fn: |scx, datums| -> Result<SideEffectingFunc, PlanError> {
+        Ok(SideEffectingFunc::SetVal {
+            id: resolve_sequence(scx, datums[0].unwrap_str())?,
+            value: datums[1].unwrap_int64(),
+            is_called: datums[2].unwrap_bool(),
+        })
+    },
+};
+
+/// Resolves the sequence named by the argument of a sequence function.
+fn resolve_sequence(scx: &StatementContext, name: &str) -> Result<CatalogItemId, PlanError> {
+    let name = mz_sql_parser::parser::parse_item_name(name)?;
+    let item = scx.resolve_item(RawItemName::Name(name))?;
+    if item.item_type() != CatalogItemType::Sequence {
+        sql_bail!(
+            "\"{}\" is not a sequence",
+            scx.catalog.resolve_full_name(item.name())
+        );
+    }
+    Ok(item.id())
+}
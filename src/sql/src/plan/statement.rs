@@ -46,6 +46,7 @@ mod acl;
 pub(crate) mod ddl;
 mod dml;
 mod raise;
+mod replication;
 mod scl;
 pub(crate) mod show;
 mod tcl;
@@ -118,6 +119,7 @@ pub fn describe(
         pcx: Some(pcx),
         catalog,
         param_types: RefCell::new(param_types),
+        param_names: BTreeMap::new(),
         ambiguous_columns: RefCell::new(false),
     };
 
@@ -132,6 +134,9 @@ pub fn describe(
         Statement::AlterObjectRename(stmt) => ddl::describe_alter_object_rename(&scx, stmt)?,
         Statement::AlterObjectSwap(stmt) => ddl::describe_alter_object_swap(&scx, stmt)?,
         Statement::AlterRetainHistory(stmt) => ddl::describe_alter_retain_history(&scx, stmt)?,
+        Statement::AlterRowLevelSecurity(stmt) => {
+            ddl::describe_alter_row_level_security(&scx, stmt)?
+        }
         Statement::AlterRole(stmt) => ddl::describe_alter_role(&scx, stmt)?,
         Statement::AlterSecret(stmt) => ddl::describe_alter_secret_options(&scx, stmt)?,
         Statement::AlterSetCluster(stmt) => ddl::describe_alter_set_cluster(&scx, stmt)?,
@@ -141,6 +146,10 @@ pub fn describe(
         Statement::AlterSystemReset(stmt) => ddl::describe_alter_system_reset(&scx, stmt)?,
         Statement::AlterSystemResetAll(stmt) => ddl::describe_alter_system_reset_all(&scx, stmt)?,
         Statement::AlterTableAddColumn(stmt) => ddl::describe_alter_table_add_column(&scx, stmt)?,
+        Statement::AlterTableDropColumn(stmt) => ddl::describe_alter_table_drop_column(&scx, stmt)?,
+        Statement::AlterTableRenameColumn(stmt) => {
+            ddl::describe_alter_table_rename_column(&scx, stmt)?
+        }
         Statement::AlterNetworkPolicy(stmt) => ddl::describe_alter_network_policy(&scx, stmt)?,
         Statement::Comment(stmt) => ddl::describe_comment(&scx, stmt)?,
         Statement::CreateCluster(stmt) => ddl::describe_create_cluster(&scx, stmt)?,
@@ -160,6 +169,9 @@ pub fn describe(
             ddl::describe_create_table_from_source(&scx, stmt)?
         }
         Statement::CreateType(stmt) => ddl::describe_create_type(&scx, stmt)?,
+        Statement::CreateFunction(stmt) => ddl::describe_create_function(&scx, stmt)?,
+        Statement::CreateSequence(stmt) => ddl::describe_create_sequence(&scx, stmt)?,
+        Statement::CreatePolicy(stmt) => ddl::describe_create_policy(&scx, stmt)?,
         Statement::CreateView(stmt) => ddl::describe_create_view(&scx, stmt)?,
         Statement::CreateMaterializedView(stmt) => {
             ddl::describe_create_materialized_view(&scx, stmt)?
@@ -222,12 +234,14 @@ pub fn describe(
         Statement::Discard(stmt) => scl::describe_discard(&scx, stmt)?,
         Statement::Execute(stmt) => scl::describe_execute(&scx, stmt)?,
         Statement::Fetch(stmt) => scl::describe_fetch(&scx, stmt)?,
+        Statement::Listen(stmt) => scl::describe_listen(&scx, stmt)?,
         Statement::Prepare(stmt) => scl::describe_prepare(&scx, stmt)?,
         Statement::ResetVariable(stmt) => scl::describe_reset_variable(&scx, stmt)?,
         Statement::SetVariable(stmt) => scl::describe_set_variable(&scx, stmt)?,
         Statement::Show(ShowStatement::ShowVariable(stmt)) => {
             scl::describe_show_variable(&scx, stmt)?
         }
+        Statement::Unlisten(stmt) => scl::describe_unlisten(&scx, stmt)?,
 
         // DML statements.
         Statement::Copy(stmt) => dml::describe_copy(&scx, stmt)?,
@@ -243,6 +257,7 @@ pub fn describe(
         Statement::Insert(stmt) => dml::describe_insert(&scx, stmt)?,
         Statement::Select(stmt) => dml::describe_select(&scx, stmt)?,
         Statement::Subscribe(stmt) => dml::describe_subscribe(&scx, stmt)?,
+        Statement::Truncate(stmt) => dml::describe_truncate(&scx, stmt)?,
         Statement::Update(stmt) => dml::describe_update(&scx, stmt)?,
 
         // TCL statements.
@@ -253,6 +268,18 @@ pub fn describe(
 
         // Other statements.
         Statement::Raise(stmt) => raise::describe_raise(&scx, stmt)?,
+
+        // Logical replication statements.
+        Statement::CreatePublication(stmt) => replication::describe_create_publication(&scx, stmt)?,
+        Statement::DropPublication(stmt) => replication::describe_drop_publication(&scx, stmt)?,
+        Statement::IdentifySystem(stmt) => replication::describe_identify_system(&scx, stmt)?,
+        Statement::CreateReplicationSlot(stmt) => {
+            replication::describe_create_replication_slot(&scx, stmt)?
+        }
+        Statement::DropReplicationSlot(stmt) => {
+            replication::describe_drop_replication_slot(&scx, stmt)?
+        }
+        Statement::StartReplication(stmt) => replication::describe_start_replication(&scx, stmt)?,
         Statement::Show(ShowStatement::InspectShard(stmt)) => {
             scl::describe_inspect_shard(&scx, stmt)?
         }
@@ -304,6 +331,7 @@ pub fn plan(
         pcx,
         catalog,
         param_types: RefCell::new(param_types),
+        param_names: BTreeMap::new(),
         ambiguous_columns: RefCell::new(false),
     };
 
@@ -331,6 +359,7 @@ pub fn plan(
         Statement::AlterObjectRename(stmt) => ddl::plan_alter_object_rename(scx, stmt),
         Statement::AlterObjectSwap(stmt) => ddl::plan_alter_object_swap(scx, stmt),
         Statement::AlterRetainHistory(stmt) => ddl::plan_alter_retain_history(scx, stmt),
+        Statement::AlterRowLevelSecurity(stmt) => ddl::plan_alter_row_level_security(scx, stmt),
         Statement::AlterRole(stmt) => ddl::plan_alter_role(scx, stmt),
         Statement::AlterSecret(stmt) => ddl::plan_alter_secret(scx, stmt),
         Statement::AlterSetCluster(stmt) => ddl::plan_alter_item_set_cluster(scx, stmt),
@@ -340,6 +369,8 @@ pub fn plan(
         Statement::AlterSystemReset(stmt) => ddl::plan_alter_system_reset(scx, stmt),
         Statement::AlterSystemResetAll(stmt) => ddl::plan_alter_system_reset_all(scx, stmt),
         Statement::AlterTableAddColumn(stmt) => ddl::plan_alter_table_add_column(scx, stmt),
+        Statement::AlterTableDropColumn(stmt) => ddl::plan_alter_table_drop_column(scx, stmt),
+        Statement::AlterTableRenameColumn(stmt) => ddl::plan_alter_table_rename_column(scx, stmt),
         Statement::AlterNetworkPolicy(stmt) => ddl::plan_alter_network_policy(scx, stmt),
         Statement::Comment(stmt) => ddl::plan_comment(scx, stmt),
         Statement::CreateCluster(stmt) => ddl::plan_create_cluster(scx, stmt),
@@ -357,6 +388,9 @@ pub fn plan(
         Statement::CreateTable(stmt) => ddl::plan_create_table(scx, stmt),
         Statement::CreateTableFromSource(stmt) => ddl::plan_create_table_from_source(scx, stmt),
         Statement::CreateType(stmt) => ddl::plan_create_type(scx, stmt),
+        Statement::CreateFunction(stmt) => ddl::plan_create_function(scx, stmt),
+        Statement::CreateSequence(stmt) => ddl::plan_create_sequence(scx, stmt),
+        Statement::CreatePolicy(stmt) => ddl::plan_create_policy(scx, stmt),
         Statement::CreateView(stmt) => ddl::plan_create_view(scx, stmt),
         Statement::CreateMaterializedView(stmt) => ddl::plan_create_materialized_view(scx, stmt),
         Statement::CreateContinualTask(stmt) => ddl::plan_create_continual_task(scx, stmt),
@@ -389,6 +423,7 @@ pub fn plan(
         Statement::Insert(stmt) => dml::plan_insert(scx, stmt, params),
         Statement::Select(stmt) => dml::plan_select(scx, stmt, params, None),
         Statement::Subscribe(stmt) => dml::plan_subscribe(scx, stmt, params, None),
+        Statement::Truncate(stmt) => dml::plan_truncate(scx, stmt),
         Statement::Update(stmt) => dml::plan_update(scx, stmt, params),
 
         // `SHOW` statements.
@@ -429,10 +464,12 @@ pub fn plan(
         Statement::Discard(stmt) => scl::plan_discard(scx, stmt),
         Statement::Execute(stmt) => scl::plan_execute(scx, stmt),
         Statement::Fetch(stmt) => scl::plan_fetch(scx, stmt),
+        Statement::Listen(stmt) => scl::plan_listen(scx, stmt),
         Statement::Prepare(stmt) => scl::plan_prepare(scx, stmt),
         Statement::ResetVariable(stmt) => scl::plan_reset_variable(scx, stmt),
         Statement::SetVariable(stmt) => scl::plan_set_variable(scx, stmt),
         Statement::Show(ShowStatement::ShowVariable(stmt)) => scl::plan_show_variable(scx, stmt),
+        Statement::Unlisten(stmt) => scl::plan_unlisten(scx, stmt),
 
         // TCL statements.
         Statement::Commit(stmt) => tcl::plan_commit(scx, stmt),
@@ -442,6 +479,16 @@ pub fn plan(
 
         // Other statements.
         Statement::Raise(stmt) => raise::plan_raise(scx, stmt),
+
+        // Logical replication statements.
+        Statement::CreatePublication(stmt) => replication::plan_create_publication(scx, stmt),
+        Statement::DropPublication(stmt) => replication::plan_drop_publication(scx, stmt),
+        Statement::IdentifySystem(stmt) => replication::plan_identify_system(scx, stmt),
+        Statement::CreateReplicationSlot(stmt) => {
+            replication::plan_create_replication_slot(scx, stmt)
+        }
+        Statement::DropReplicationSlot(stmt) => replication::plan_drop_replication_slot(scx, stmt),
+        Statement::StartReplication(stmt) => replication::plan_start_replication(scx, stmt),
         Statement::Show(ShowStatement::InspectShard(stmt)) => scl::plan_inspect_shard(scx, stmt),
         Statement::ValidateConnection(stmt) => validate::plan_validate_connection(scx, stmt),
     };
@@ -486,7 +533,9 @@ impl PartialEq<ObjectType> for CatalogItemType {
             | (CatalogItemType::Index, ObjectType::Index)
             | (CatalogItemType::Type, ObjectType::Type)
             | (CatalogItemType::Secret, ObjectType::Secret)
-            | (CatalogItemType::Connection, ObjectType::Connection) => true,
+            | (CatalogItemType::Connection, ObjectType::Connection)
+            | (CatalogItemType::Sequence, ObjectType::Sequence)
+            | (CatalogItemType::Policy, ObjectType::Policy) => true,
             (_, _) => false,
         }
     }
@@ -510,6 +559,9 @@ pub struct StatementContext<'a> {
     /// The types of the parameters in the query. This is filled in as planning
     /// occurs.
     pub param_types: RefCell<BTreeMap<usize, SqlScalarType>>,
+    /// The names of the parameters in the query, which are only present when
+    /// planning the body of a SQL function.
+    pub param_names: BTreeMap<mz_repr::ColumnName, usize>,
     /// Whether the statement contains an expression that can make the exact column list
     /// ambiguous. For example `NATURAL JOIN` or `SELECT *`. This is filled in as planning occurs.
     pub ambiguous_columns: RefCell<bool>,
@@ -524,6 +576,7 @@ impl<'a> StatementContext<'a> {
             pcx,
             catalog,
             param_types: Default::default(),
+            param_names: BTreeMap::new(),
             ambiguous_columns: RefCell::new(false),
         }
     }
@@ -1056,6 +1109,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::AlterObjectSwap(_) => DDL,
             Statement::AlterNetworkPolicy(_) => DDL,
             Statement::AlterRetainHistory(_) => DDL,
+            Statement::AlterRowLevelSecurity(_) => DDL,
             Statement::AlterRole(_) => DDL,
             Statement::AlterSecret(_) => DDL,
             Statement::AlterSetCluster(_) => DDL,
@@ -1065,6 +1119,8 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::AlterSystemReset(_) => DDL,
             Statement::AlterSystemResetAll(_) => DDL,
             Statement::AlterTableAddColumn(_) => DDL,
+            Statement::AlterTableDropColumn(_) => DDL,
+            Statement::AlterTableRenameColumn(_) => DDL,
             Statement::Comment(_) => DDL,
             Statement::CreateCluster(_) => DDL,
             Statement::CreateClusterReplica(_) => DDL,
@@ -1082,11 +1138,16 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::CreateTable(_) => DDL,
             Statement::CreateTableFromSource(_) => DDL,
             Statement::CreateType(_) => DDL,
+            Statement::CreateFunction(_) => DDL,
+            Statement::CreateSequence(_) => DDL,
+            Statement::CreatePolicy(_) => DDL,
             Statement::CreateView(_) => DDL,
             Statement::CreateMaterializedView(_) => DDL,
             Statement::CreateNetworkPolicy(_) => DDL,
+            Statement::CreatePublication(_) => DDL,
             Statement::DropObjects(_) => DDL,
             Statement::DropOwned(_) => DDL,
+            Statement::DropPublication(_) => DDL,
 
             // `ACL` statements.
             Statement::AlterOwner(_) => ACL,
@@ -1109,6 +1170,7 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::Insert(_) => DML,
             Statement::Select(_) => DML,
             Statement::Subscribe(_) => DML,
+            Statement::Truncate(_) => DML,
             Statement::Update(_) => DML,
 
             // `SHOW` statements.
@@ -1131,10 +1193,12 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
             Statement::Discard(_) => SCL,
             Statement::Execute(_) => SCL,
             Statement::Fetch(_) => SCL,
+            Statement::Listen(_) => SCL,
             Statement::Prepare(_) => SCL,
             Statement::ResetVariable(_) => SCL,
             Statement::SetVariable(_) => SCL,
             Statement::Show(ShowStatement::ShowVariable(_)) => SCL,
+            Statement::Unlisten(_) => SCL,
 
             // TCL statements.
             Statement::Commit(_) => TCL,
@@ -1144,6 +1208,10 @@ impl<T: mz_sql_parser::ast::AstInfo> From<&Statement<T>> for StatementClassifica
 
             // Other statements.
             Statement::Raise(_) => Other,
+            Statement::IdentifySystem(_) => Other,
+            Statement::CreateReplicationSlot(_) => Other,
+            Statement::DropReplicationSlot(_) => Other,
+            Statement::StartReplication(_) => Other,
             Statement::Show(ShowStatement::InspectShard(_)) => Other,
             Statement::ValidateConnection(_) => Other,
         }
@@ -19,13 +19,14 @@ use mz_sql_parser::ast::display::AstDisplay;
 
 use crate::ast::{Ident, UnresolvedDatabaseName};
 use crate::catalog::{
-    DefaultPrivilegeAclItem, DefaultPrivilegeObject, ErrorMessageObjectDescription, ObjectType,
-    SystemObjectType,
+    CatalogItem, CatalogItemType, DefaultPrivilegeAclItem, DefaultPrivilegeObject,
+    ErrorMessageObjectDescription, ObjectType, SystemObjectType,
 };
 use crate::names::{
     Aug, ObjectId, ResolvedDatabaseSpecifier, ResolvedRoleName, SchemaSpecifier, SystemObjectId,
 };
 use crate::plan::error::PlanError;
+use crate::plan::query::bypasses_row_level_security;
 use crate::plan::statement::ddl::{
     resolve_cluster, resolve_database, resolve_item_or_type, resolve_network_policy, resolve_schema,
 };
@@ -36,6 +37,7 @@ use crate::plan::{
 };
 use crate::session::user::SYSTEM_USER;
 use mz_ore::str::StrExt;
+use mz_repr::CatalogItemId;
 use mz_repr::adt::mz_acl_item::AclMode;
 use mz_repr::role_id::RoleId;
 use mz_sql_parser::ast::{
@@ -226,6 +228,14 @@ fn plan_alter_item_owner(
                 );
             }
 
+            check_row_level_security_owners(scx, &[item.id()], |other| {
+                if other.id() == item.id() {
+                    new_owner
+                } else {
+                    other.owner_id()
+                }
+            })?;
+
             Ok(Plan::AlterOwner(AlterOwnerPlan {
                 id: ObjectId::Item(item.id()),
                 object_type,
@@ -243,6 +253,60 @@ fn plan_alter_item_owner(
     }
 }
 
+/// Checks that changing the owners of the items in `ids` does not allow any
+/// object to bypass row-level security, where `new_owner` returns the owner of
+/// an item after the change.
+///
+/// Views, materialized views, continual tasks and sinks are computed with the
+/// privileges of their owner, which therefore must bypass row-level security
+/// on every relation with row-level security enabled that they directly
+/// depend on.
+fn check_row_level_security_owners(
+    scx: &StatementContext,
+    ids: &[CatalogItemId],
+    new_owner: impl Fn(&dyn CatalogItem) -> RoleId,
+) -> Result<(), PlanError> {
+    let computed_with_owner = |item: &dyn CatalogItem| {
+        matches!(
+            item.item_type(),
+            CatalogItemType::View
+                | CatalogItemType::MaterializedView
+                | CatalogItemType::Sink
+                | CatalogItemType::ContinualTask
+        )
+    };
+    let check = |dependent: &dyn CatalogItem, relation: &dyn CatalogItem| {
+        let role_id = new_owner(dependent);
+        if relation.row_level_security()
+            && !bypasses_row_level_security(scx.catalog, &role_id, &new_owner(relation))
+        {
+            return Err(PlanError::RowLevelSecurityDependency {
+                name: scx.catalog.resolve_full_name(relation.name()).to_string(),
+                role: scx.catalog.get_role(&role_id).name().to_string(),
+            });
+        }
+        Ok(())
+    };
+
+    for id in ids {
+        let item = scx.catalog.get_item(id);
+        if computed_with_owner(item) {
+            for relation in item.uses() {
+                check(item, scx.catalog.get_item(&relation))?;
+            }
+        }
+        if item.row_level_security() {
+            for dependent in item.used_by() {
+                let dependent = scx.catalog.get_item(dependent);
+                if computed_with_owner(dependent) {
+                    check(dependent, item)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 fn plan_alter_network_policy_owner(
     scx: &StatementContext,
     if_exists: bool,
@@ -652,9 +716,14 @@ pub fn plan_alter_default_privileges(
         | ObjectType::ContinualTask => sql_bail!(
             "{object_type}S is not valid for ALTER DEFAULT PRIVILEGES, use TABLES instead"
         ),
-        ObjectType::Sink | ObjectType::ClusterReplica | ObjectType::Role | ObjectType::Func => {
+        ObjectType::Sink
+        | ObjectType::ClusterReplica
+        | ObjectType::Role
+        | ObjectType::Func
+        | ObjectType::Policy => {
             sql_bail!("{object_type}S do not have privileges")
         }
+        ObjectType::Sequence => bail_unsupported!("ALTER DEFAULT PRIVILEGES ON SEQUENCES"),
         ObjectType::Cluster | ObjectType::Database
             if matches!(
                 target_objects,
@@ -788,11 +857,20 @@ pub fn plan_reassign_owned(
         }
     }
     // Items
+    let mut reassign_item_ids = Vec::new();
     for item in scx.catalog.get_items() {
         if old_roles.contains(&item.owner_id()) {
             reassign_ids.push(item.id().into());
+            reassign_item_ids.push(item.id());
         }
     }
+    check_row_level_security_owners(scx, &reassign_item_ids, |item| {
+        if old_roles.contains(&item.owner_id()) {
+            new_role.id
+        } else {
+            item.owner_id()
+        }
+    })?;
     // Schemas
     for schema in scx.catalog.get_schemas() {
         if !schema.id().is_temporary() {
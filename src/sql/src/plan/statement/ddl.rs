@@ -16,23 +16,30 @@ use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Write;
 use std::iter;
 use std::num::NonZeroU32;
+use std::str::FromStr;
 use std::time::Duration;
 
 use itertools::{Either, Itertools};
 use mz_adapter_types::compaction::{CompactionWindow, DEFAULT_LOGICAL_COMPACTION_WINDOW_DURATION};
 use mz_adapter_types::dyncfgs::ENABLE_MULTI_REPLICA_SOURCES;
+use mz_arrow_util::builder::ArrowBuilder;
 use mz_auth::password::Password;
 use mz_controller_types::{ClusterId, DEFAULT_REPLICA_LOGGING_INTERVAL, ReplicaId};
 use mz_expr::{CollectionPlan, UnmaterializableFunc};
 use mz_interchange::avro::{AvroSchemaGenerator, DocTarget};
+use mz_interchange::envelopes::{
+    CHANGELOG_DIFF_COLUMN, CHANGELOG_TIMESTAMP_COLUMN, changelog_desc,
+};
 use mz_ore::cast::{CastFrom, TryCastFrom};
 use mz_ore::collections::{CollectionExt, HashSet};
 use mz_ore::num::NonNeg;
 use mz_ore::soft_panic_or_log;
 use mz_ore::str::StrExt;
+use mz_pgcopy::CopyFormatParams;
 use mz_proto::RustType;
 use mz_repr::adt::interval::Interval;
 use mz_repr::adt::mz_acl_item::{MzAclItem, PrivilegeMap};
+use mz_repr::bytes::ByteSize;
 use mz_repr::network_policy_id::NetworkPolicyId;
 use mz_repr::optimize::OptimizerFeatureOverrides;
 use mz_repr::refresh_schedule::{RefreshEvery, RefreshSchedule};
@@ -47,48 +54,55 @@ use mz_sql_parser::ast::{
     AlterConnectionOptionName, AlterConnectionStatement, AlterIndexAction, AlterIndexStatement,
     AlterMaterializedViewApplyReplacementStatement, AlterNetworkPolicyStatement,
     AlterObjectRenameStatement, AlterObjectSwapStatement, AlterRetainHistoryStatement,
-    AlterRoleOption, AlterRoleStatement, AlterSecretStatement, AlterSetClusterStatement,
-    AlterSinkAction, AlterSinkStatement, AlterSourceAction, AlterSourceAddSubsourceOption,
-    AlterSourceAddSubsourceOptionName, AlterSourceStatement, AlterSystemResetAllStatement,
-    AlterSystemResetStatement, AlterSystemSetStatement, AlterTableAddColumnStatement, AvroSchema,
-    AvroSchemaOption, AvroSchemaOptionName, ClusterAlterOption, ClusterAlterOptionName,
+    AlterRoleOption, AlterRoleStatement, AlterRowLevelSecurityStatement, AlterSecretStatement,
+    AlterSetClusterStatement, AlterSinkAction, AlterSinkStatement, AlterSourceAction,
+    AlterSourceAddSubsourceOption, AlterSourceAddSubsourceOptionName, AlterSourceStatement,
+    AlterSystemResetAllStatement, AlterSystemResetStatement, AlterSystemSetStatement,
+    AlterTableAddColumnStatement, AlterTableDropColumnStatement, AlterTableRenameColumnStatement,
+    AvroSchema, AvroSchemaOption, AvroSchemaOptionName, ClusterAlterOption, ClusterAlterOptionName,
     ClusterAlterOptionValue, ClusterAlterUntilReadyOption, ClusterAlterUntilReadyOptionName,
     ClusterFeature, ClusterFeatureName, ClusterOption, ClusterOptionName,
-    ClusterScheduleOptionValue, ColumnDef, ColumnOption, CommentObjectType, CommentStatement,
-    ConnectionOption, ConnectionOptionName, ContinualTaskOption, ContinualTaskOptionName,
-    CreateClusterReplicaStatement, CreateClusterStatement, CreateConnectionOption,
-    CreateConnectionOptionName, CreateConnectionStatement, CreateConnectionType,
-    CreateContinualTaskStatement, CreateDatabaseStatement, CreateIndexStatement,
-    CreateMaterializedViewStatement, CreateNetworkPolicyStatement, CreateRoleStatement,
-    CreateSchemaStatement, CreateSecretStatement, CreateSinkConnection, CreateSinkOption,
-    CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection, CreateSourceOption,
-    CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
+    ClusterScheduleOptionValue, ColumnDef, ColumnOption, ColumnVersioned, CommentObjectType,
+    CommentStatement, ConnectionOption, ConnectionOptionName, ContinualTaskOption,
+    ContinualTaskOptionName, CreateClusterReplicaStatement, CreateClusterStatement,
+    CreateConnectionOption, CreateConnectionOptionName, CreateConnectionStatement,
+    CreateConnectionType, CreateContinualTaskStatement, CreateDatabaseStatement,
+    CreateFunctionStatement, CreateIndexStatement, CreateMaterializedViewStatement,
+    CreateNetworkPolicyStatement, CreatePolicyStatement, CreateRoleStatement,
+    CreateSchemaStatement, CreateSecretStatement, CreateSequenceStatement, CreateSinkConnection,
+    CreateSinkOption, CreateSinkOptionName, CreateSinkStatement, CreateSourceConnection,
+    CreateSourceOption, CreateSourceOptionName, CreateSourceStatement, CreateSubsourceOption,
     CreateSubsourceOptionName, CreateSubsourceStatement, CreateTableFromSourceStatement,
     CreateTableStatement, CreateTypeAs, CreateTypeListOption, CreateTypeListOptionName,
     CreateTypeMapOption, CreateTypeMapOptionName, CreateTypeStatement, CreateViewStatement,
     CreateWebhookSourceStatement, CsrConfigOption, CsrConfigOptionName, CsrConnection,
     CsrConnectionAvro, CsrConnectionProtobuf, CsrSeedProtobuf, CsvColumns, DeferredItemName,
     DocOnIdentifier, DocOnSchema, DropObjectsStatement, DropOwnedStatement, Expr, Format,
-    FormatSpecifier, IcebergSinkConfigOption, Ident, IfExistsBehavior, IndexOption,
-    IndexOptionName, KafkaSinkConfigOption, KeyConstraint, LoadGeneratorOption,
-    LoadGeneratorOptionName, MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption,
-    MySqlConfigOptionName, NetworkPolicyOption, NetworkPolicyOptionName,
+    FormatSpecifier, FunctionParameter, FunctionReturns, HttpSinkConfigOption,
+    IcebergSinkConfigOption, Ident, IfExistsBehavior, IndexOption, IndexOptionName,
+    KafkaSinkConfigOption, KeyConstraint, LoadGeneratorOption, LoadGeneratorOptionName,
+    MaterializedViewOption, MaterializedViewOptionName, MySqlConfigOption, MySqlConfigOptionName,
+    MySqlSinkConfigOption, NetworkPolicyOption, NetworkPolicyOptionName,
     NetworkPolicyRuleDefinition, NetworkPolicyRuleOption, NetworkPolicyRuleOptionName,
-    PgConfigOption, PgConfigOptionName, ProtobufSchema, QualifiedReplica, RefreshAtOptionValue,
-    RefreshEveryOptionValue, RefreshOptionValue, ReplicaDefinition, ReplicaOption,
-    ReplicaOptionName, RoleAttribute, SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata,
-    SqlServerConfigOption, SqlServerConfigOptionName, Statement, TableConstraint,
+    PgConfigOption, PgConfigOptionName, PostgresSinkConfigOption, ProtobufSchema, QualifiedReplica,
+    RefreshAtOptionValue, RefreshEveryOptionValue, RefreshOptionValue, ReplicaDefinition,
+    ReplicaOption, ReplicaOptionName, RoleAttribute, S3SinkConfigOption, SequenceOption,
+    SetRoleVar, SourceErrorPolicy, SourceIncludeMetadata, SqlServerConfigOption,
+    SqlServerConfigOptionName, SqlServerSinkConfigOption, Statement, TableConstraint,
     TableFromSourceColumns, TableFromSourceOption, TableFromSourceOptionName, TableOption,
     TableOptionName, UnresolvedDatabaseName, UnresolvedItemName, UnresolvedObjectName,
-    UnresolvedSchemaName, Value, ViewDefinition, WithOptionValue,
+    UnresolvedSchemaName, Value, ViewDefinition, ViewOption, ViewOptionName, WithOptionValue,
 };
 use mz_sql_parser::ident;
 use mz_sql_parser::parser::StatementParseResult;
 use mz_storage_types::connections::inline::{ConnectionAccess, ReferencedConnection};
+use mz_storage_types::connections::string_or_secret::StringOrSecret;
 use mz_storage_types::connections::{Connection, KafkaTopicOptions};
 use mz_storage_types::sinks::{
-    IcebergSinkConnection, KafkaIdStyle, KafkaSinkConnection, KafkaSinkFormat, KafkaSinkFormatType,
-    SinkEnvelope, StorageSinkConnection,
+    DEFAULT_SINK_PROGRESS_TABLE, HttpSinkConnection, IcebergSinkConnection, KafkaIdStyle,
+    KafkaSinkConnection, KafkaSinkFormat, KafkaSinkFormatType, MAX_S3_SINK_FILE_SIZE,
+    MIN_S3_SINK_FILE_SIZE, MySqlSinkConnection, PostgresSinkConnection, S3SinkConnection,
+    S3SinkFormat, SinkEnvelope, SqlServerSinkConnection, StorageSinkConnection,
 };
 use mz_storage_types::sources::encoding::{
     AvroEncoding, ColumnSpec, CsvEncoding, DataEncoding, ProtobufEncoding, RegexEncoding,
@@ -123,16 +137,19 @@ use mz_storage_types::sources::{
 use prost::Message;
 
 use crate::ast::display::AstDisplay;
+use crate::ast::visit::{self, Visit};
 use crate::catalog::{
-    CatalogCluster, CatalogDatabase, CatalogError, CatalogItem, CatalogItemType,
-    CatalogRecordField, CatalogType, CatalogTypeDetails, ObjectType, SystemObjectType,
+    CatalogCluster, CatalogDatabase, CatalogError, CatalogFuncDetails, CatalogItem,
+    CatalogItemType, CatalogPolicyDetails, CatalogRecordField, CatalogType, CatalogTypeDetails,
+    ObjectType, SystemObjectType,
 };
 use crate::iceberg::IcebergSinkConfigOptionExtracted;
 use crate::kafka_util::{KafkaSinkConfigOptionExtracted, KafkaSourceConfigOptionExtracted};
 use crate::names::{
-    Aug, CommentObjectId, DatabaseId, DependencyIds, ObjectId, PartialItemName, QualifiedItemName,
-    ResolvedClusterName, ResolvedColumnReference, ResolvedDataType, ResolvedDatabaseSpecifier,
-    ResolvedItemName, ResolvedNetworkPolicyName, SchemaSpecifier, SystemObjectId,
+    Aug, CommentObjectId, DatabaseId, DependencyIds, FullItemName, ObjectId, PartialItemName,
+    QualifiedItemName, ResolvedClusterName, ResolvedColumnReference, ResolvedDataType,
+    ResolvedDatabaseSpecifier, ResolvedItemName, ResolvedNetworkPolicyName, SchemaSpecifier,
+    SystemObjectId,
 };
 use crate::normalize::{self, ident};
 use crate::plan::error::PlanError;
@@ -149,21 +166,23 @@ use crate::plan::{
     AlterClusterPlan, AlterClusterPlanStrategy, AlterClusterRenamePlan,
     AlterClusterReplicaRenamePlan, AlterClusterSwapPlan, AlterConnectionPlan, AlterItemRenamePlan,
     AlterMaterializedViewApplyReplacementPlan, AlterNetworkPolicyPlan, AlterNoopPlan,
-    AlterOptionParameter, AlterRetainHistoryPlan, AlterRolePlan, AlterSchemaRenamePlan,
-    AlterSchemaSwapPlan, AlterSecretPlan, AlterSetClusterPlan, AlterSinkPlan,
-    AlterSourceTimestampIntervalPlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
-    AlterSystemSetPlan, AlterTablePlan, ClusterSchedule, CommentPlan, ComputeReplicaConfig,
-    ComputeReplicaIntrospectionConfig, ConnectionDetails, CreateClusterManagedPlan,
-    CreateClusterPlan, CreateClusterReplicaPlan, CreateClusterUnmanagedPlan, CreateClusterVariant,
-    CreateConnectionPlan, CreateContinualTaskPlan, CreateDatabasePlan, CreateIndexPlan,
-    CreateMaterializedViewPlan, CreateNetworkPolicyPlan, CreateRolePlan, CreateSchemaPlan,
-    CreateSecretPlan, CreateSinkPlan, CreateSourcePlan, CreateTablePlan, CreateTypePlan,
-    CreateViewPlan, DataSourceDesc, DropObjectsPlan, DropOwnedPlan, HirRelationExpr, Index,
-    MaterializedView, NetworkPolicyRule, NetworkPolicyRuleAction, NetworkPolicyRuleDirection, Plan,
-    PlanClusterOption, PlanNotice, PolicyAddress, QueryContext, ReplicaConfig, Secret, Sink,
-    Source, Table, TableDataSource, Type, VariableValue, View, WebhookBodyFormat,
-    WebhookHeaderFilters, WebhookHeaders, WebhookValidation, literal, plan_utils, query,
-    transform_ast,
+    AlterOptionParameter, AlterRetainHistoryPlan, AlterRolePlan, AlterRowLevelSecurityPlan,
+    AlterSchemaRenamePlan, AlterSchemaSwapPlan, AlterSecretPlan, AlterSetClusterPlan,
+    AlterSinkPlan, AlterSourceTimestampIntervalPlan, AlterSystemResetAllPlan, AlterSystemResetPlan,
+    AlterSystemSetPlan, AlterTableDropColumnPlan, AlterTablePlan, AlterTableRenameColumnPlan,
+    ClusterSchedule, CommentPlan, ComputeReplicaConfig, ComputeReplicaIntrospectionConfig,
+    ConnectionDetails, CreateClusterManagedPlan, CreateClusterPlan, CreateClusterReplicaPlan,
+    CreateClusterUnmanagedPlan, CreateClusterVariant, CreateConnectionPlan,
+    CreateContinualTaskPlan, CreateDatabasePlan, CreateFunctionPlan, CreateIndexPlan,
+    CreateMaterializedViewPlan, CreateNetworkPolicyPlan, CreatePolicyPlan, CreateRolePlan,
+    CreateSchemaPlan, CreateSecretPlan, CreateSequencePlan, CreateSinkPlan, CreateSourcePlan,
+    CreateTablePlan, CreateTypePlan, CreateViewPlan, DataSourceDesc, DropObjectsPlan,
+    DropOwnedPlan, Function, HirRelationExpr, IdentityColumn, Index, MaterializedView,
+    NetworkPolicyRule, NetworkPolicyRuleAction, NetworkPolicyRuleDirection, Plan,
+    PlanClusterOption, PlanNotice, Policy, PolicyAddress, QueryContext, ReplicaConfig, Secret,
+    Sequence, SequenceOptions, Sink, Source, Table, TableDataSource, Type, VariableValue, View,
+    WebhookBodyFormat, WebhookHeaderFilters, WebhookHeaders, WebhookValidation, literal,
+    plan_utils, query, transform_ast,
 };
 use crate::session::vars::{
     self, ENABLE_CLUSTER_SCHEDULE_REFRESH, ENABLE_COLLECTION_PARTITION_BY,
@@ -292,19 +311,52 @@ pub fn plan_create_table(
         with_options,
     } = &stmt;
 
+    // Returns the name a column had at `version`, accounting for any later
+    // renames of the column.
+    let column_name_at = |c: &ColumnDef<Aug>, version: RelationVersion| {
+        let renames = c.options.iter().filter_map(|o| match &o.option {
+            ColumnOption::Versioned {
+                action: ColumnVersioned::Renamed { from },
+                version: renamed_at,
+            } => Some((RelationVersion::from(*renamed_at), from)),
+            _ => None,
+        });
+        let name = renames
+            .filter(|(renamed_at, _)| *renamed_at > version)
+            .min_by_key(|(renamed_at, _)| *renamed_at)
+            .map_or(&c.name, |(_, from)| from);
+        normalize::column_name(name.clone())
+    };
+    let is_added = |c: &ColumnDef<Aug>| {
+        c.options.iter().any(|o| {
+            matches!(
+                o.option,
+                ColumnOption::Versioned {
+                    action: ColumnVersioned::Added,
+                    ..
+                }
+            )
+        })
+    };
+    let is_dropped = |c: &ColumnDef<Aug>| {
+        c.options.iter().any(|o| {
+            matches!(
+                o.option,
+                ColumnOption::Versioned {
+                    action: ColumnVersioned::Dropped,
+                    ..
+                }
+            )
+        })
+    };
+
+    // This set of `names` is used to create the initial RelationDesc. Columns
+    // that have been added, dropped, or renamed at later versions of the table
+    // get applied further below.
     let names: Vec<_> = columns
         .iter()
-        .filter(|c| {
-            // This set of `names` is used to create the initial RelationDesc.
-            // Columns that have been added at later versions of the table will
-            // get added further below.
-            let is_versioned = c
-                .options
-                .iter()
-                .any(|o| matches!(o.option, ColumnOption::Versioned { .. }));
-            !is_versioned
-        })
-        .map(|c| normalize::column_name(c.name.clone()))
+        .filter(|c| !is_added(c))
+        .map(|c| column_name_at(c, RelationVersion::root()))
         .collect();
 
     if let Some(dup) = names.iter().duplicates().next() {
@@ -315,19 +367,66 @@ pub fn plan_create_table(
     // and NOT NULL constraints.
     let mut column_types = Vec::with_capacity(columns.len());
     let mut defaults = Vec::with_capacity(columns.len());
+    let mut identity_columns = Vec::new();
     let mut changes = BTreeMap::new();
     let mut keys = Vec::new();
+    // The position of the next column in the latest version of the table,
+    // which excludes dropped columns.
+    let mut latest_idx = 0;
 
     for (i, c) in columns.into_iter().enumerate() {
         let aug_data_type = &c.data_type;
         let ty = query::scalar_type_from_sql(scx, aug_data_type)?;
         let mut nullable = true;
         let mut default = Expr::null();
-        let mut versioned = false;
+        let mut added = false;
+        let mut identity = None;
+        let column_name = normalize::column_name(c.name.clone());
+        let has_default = c
+            .options
+            .iter()
+            .any(|o| matches!(o.option, ColumnOption::Default(_)));
         for option in &c.options {
             match &option.option {
                 ColumnOption::NotNull => nullable = false,
+                ColumnOption::Identity { always, options } => {
+                    if has_default {
+                        sql_bail!(
+                            "both default and identity specified for column {}",
+                            column_name.quoted()
+                        );
+                    }
+                    if identity.is_some() {
+                        sql_bail!(
+                            "multiple identity specifications for column {}",
+                            column_name.quoted()
+                        );
+                    }
+                    let (type_min, type_max) = match ty {
+                        SqlScalarType::Int16 => (i16::MIN.into(), i16::MAX.into()),
+                        SqlScalarType::Int32 => (i32::MIN.into(), i32::MAX.into()),
+                        SqlScalarType::Int64 => (i64::MIN, i64::MAX),
+                        _ => sql_bail!("identity column type must be smallint, integer, or bigint"),
+                    };
+                    // Identity columns are implicitly `NOT NULL`, as in
+                    // PostgreSQL.
+                    nullable = false;
+                    identity = Some(IdentityColumn {
+                        column: latest_idx,
+                        always: *always,
+                        options: plan_sequence_options(options, type_min, type_max)?,
+                    });
+                }
                 ColumnOption::Default(expr) => {
+                    // Tables are loaded before functions when the catalog is
+                    // opened, so defaults cannot call user-defined functions.
+                    let dependencies = names::visit_dependencies(scx.catalog, expr);
+                    if dependencies
+                        .items()
+                        .any(|id| scx.catalog.get_item(id).func_details().is_some())
+                    {
+                        bail_unsupported!("user-defined functions in DEFAULT expressions");
+                    }
                     // Ensure expression can be planned and yields the correct
                     // type.
                     let mut expr = expr.clone();
@@ -343,10 +442,10 @@ pub fn plan_create_table(
                 }
                 ColumnOption::Versioned { action, version } => {
                     let version = RelationVersion::from(*version);
-                    versioned = true;
+                    added |= matches!(action, ColumnVersioned::Added);
 
-                    let name = normalize::column_name(c.name.clone());
                     let typ = ty.clone().nullable(nullable);
+                    let name = column_name_at(c, version);
 
                     changes.insert(version, (action.clone(), name, typ));
                 }
@@ -355,12 +454,18 @@ pub fn plan_create_table(
                 }
             }
         }
-        // TODO(alter_table): This assumes all versioned columns are at the
-        // end. This will no longer be true when we support dropping columns.
-        if !versioned {
+        // N.B. This assumes all added columns are at the end, which holds
+        // because columns are only ever appended to a table.
+        if !added {
             column_types.push(ty.nullable(nullable));
         }
-        defaults.push(default);
+        // Defaults and identity columns describe the latest version of the
+        // table, which does not include dropped columns.
+        if !is_dropped(c) {
+            defaults.push(default);
+            identity_columns.extend(identity);
+            latest_idx += 1;
+        }
     }
 
     let mut seen_primary = false;
@@ -457,8 +562,14 @@ pub fn plan_create_table(
 
     let desc = RelationDesc::new(typ, names);
     let mut desc = VersionedRelationDesc::new(desc);
-    for (version, (_action, name, typ)) in changes.into_iter() {
-        let new_version = desc.add_column(name, typ);
+    for (version, (action, name, typ)) in changes.into_iter() {
+        let new_version = match action {
+            ColumnVersioned::Added => desc.add_column(name, typ),
+            ColumnVersioned::Dropped => desc.drop_column(name),
+            ColumnVersioned::Renamed { from } => {
+                desc.rename_column(normalize::column_name(from), name)
+            }
+        };
         if version != new_version {
             return Err(PlanError::InvalidTable {
                 name: full_name.item,
@@ -477,20 +588,26 @@ pub fn plan_create_table(
     let options = plan_table_options(scx, &original_desc, with_options.clone())?;
 
     let compaction_window = options.iter().find_map(|o| {
-        #[allow(irrefutable_let_patterns)]
         if let crate::plan::TableOption::RetainHistory(lcw) = o {
             Some(lcw.clone())
         } else {
             None
         }
     });
+    let row_level_security = options
+        .iter()
+        .any(|o| matches!(o, crate::plan::TableOption::RowLevelSecurity));
 
     let table = Table {
         create_sql,
         desc,
         temporary,
         compaction_window,
-        data_source: TableDataSource::TableWrites { defaults },
+        data_source: TableDataSource::TableWrites {
+            defaults,
+            identity_columns,
+        },
+        row_level_security,
     };
     Ok(Plan::CreateTable(CreateTablePlan {
         name,
@@ -726,6 +843,7 @@ pub fn plan_create_webhook_source(
                 temporary: false,
                 compaction_window: None,
                 data_source,
+                row_level_security: false,
             },
         })
     } else {
@@ -1994,6 +2112,7 @@ pub fn plan_create_table_from_source(
             desc: data_source,
             timeline,
         },
+        row_level_security: false,
     };
 
     Ok(Plan::CreateTable(CreateTablePlan {
@@ -2568,9 +2687,19 @@ pub fn plan_view(
     let ViewDefinition {
         name,
         columns,
+        with_options,
         query,
     } = def;
 
+    let ViewOptionExtracted {
+        row_level_security,
+        seen: _,
+    }: ViewOptionExtracted = with_options.clone().try_into()?;
+
+    if row_level_security {
+        scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+    }
+
     let query::PlannedRootQuery {
         expr,
         mut desc,
@@ -2619,11 +2748,14 @@ pub fn plan_view(
         dependencies,
         column_names: names,
         temporary,
+        row_level_security,
     };
 
     Ok((name, view))
 }
 
+generate_extracted_config!(ViewOption, (RowLevelSecurity, bool, Default(false)));
+
 pub fn plan_create_view(
     scx: &StatementContext,
     mut stmt: CreateViewStatement<Aug>,
@@ -2820,9 +2952,19 @@ pub fn plan_create_materialized_view(
         partition_by,
         retain_history,
         refresh,
+        notify_channel,
+        row_level_security,
         seen: _,
     }: MaterializedViewOptionExtracted = stmt.with_options.try_into()?;
 
+    if notify_channel.is_some() {
+        scx.require_feature_flag(&vars::ENABLE_LISTEN_NOTIFY)?;
+    }
+
+    if row_level_security {
+        scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+    }
+
     if let Some(partition_by) = partition_by {
         scx.require_feature_flag(&ENABLE_COLLECTION_PARTITION_BY)?;
         check_partition_by(&desc, partition_by)?;
@@ -3105,6 +3247,8 @@ pub fn plan_create_materialized_view(
             compaction_window,
             refresh_schedule,
             as_of,
+            notify_channel,
+            row_level_security,
         },
         replace,
         drop_ids,
@@ -3118,7 +3262,9 @@ generate_extracted_config!(
     (AssertNotNull, Ident, AllowMultiple),
     (PartitionBy, Vec<Ident>),
     (RetainHistory, OptionalDuration),
-    (Refresh, RefreshOptionValue<Aug>, AllowMultiple)
+    (Refresh, RefreshOptionValue<Aug>, AllowMultiple),
+    (NotifyChannel, String),
+    (RowLevelSecurity, bool, Default(false))
 );
 
 pub fn plan_create_continual_task(
@@ -3182,7 +3328,9 @@ pub fn plan_create_continual_task(
         | CatalogItemType::Type
         | CatalogItemType::Func
         | CatalogItemType::Secret
-        | CatalogItemType::Connection => {
+        | CatalogItemType::Connection
+        | CatalogItemType::Sequence
+        | CatalogItemType::Policy => {
             sql_bail!(
                 "CONTINUAL TASK cannot use {} as an input",
                 input.item_type()
@@ -3356,6 +3504,8 @@ pub fn plan_create_continual_task(
             compaction_window: None,
             refresh_schedule: None,
             as_of,
+            notify_channel: None,
+            row_level_security: false,
         },
     }))
 }
@@ -3393,6 +3543,7 @@ fn continual_task_query<'a>(
                 relation: ast::TableFactor::Table {
                     name: ct_name.clone(),
                     alias: alias.clone(),
+                    sample: None,
                 },
                 joins: Vec::new(),
             };
@@ -3428,6 +3579,41 @@ pub fn describe_create_sink(
     Ok(StatementDesc::new(None))
 }
 
+generate_extracted_config!(
+    PostgresSinkConfigOption,
+    (Table, String),
+    (ProgressTable, String)
+);
+
+generate_extracted_config!(
+    MySqlSinkConfigOption,
+    (Table, String),
+    (ProgressTable, String)
+);
+
+generate_extracted_config!(
+    SqlServerSinkConfigOption,
+    (Table, String),
+    (ProgressTable, String)
+);
+
+generate_extracted_config!(
+    S3SinkConfigOption,
+    (Url, String),
+    (Format, String),
+    (MaxFileSize, ByteSize, Default(ByteSize::mb(256)))
+);
+
+generate_extracted_config!(
+    HttpSinkConfigOption,
+    (Url, String),
+    (
+        Headers,
+        BTreeMap<String, StringOrSecret>,
+        Default(BTreeMap::new())
+    )
+);
+
 generate_extracted_config!(
     CreateSinkOption,
     (Snapshot, bool),
@@ -3490,6 +3676,10 @@ fn plan_sink(
         (CreateSinkConnection::Kafka { .. }, Some(ast::SinkEnvelope::Debezium), None) => {
             SinkEnvelope::Debezium
         }
+        (CreateSinkConnection::Kafka { .. }, Some(ast::SinkEnvelope::Changelog), None) => {
+            scx.require_feature_flag(&vars::ENABLE_SINK_ENVELOPE_CHANGELOG)?;
+            SinkEnvelope::Changelog
+        }
         (CreateSinkConnection::Kafka { .. }, None, None) => {
             sql_bail!("ENVELOPE clause is required")
         }
@@ -3497,15 +3687,100 @@ fn plan_sink(
             sql_bail!("MODE is not supported for Kafka sinks, use ENVELOPE instead")
         }
         // Iceberg sinks use MODE
-        (CreateSinkConnection::Iceberg { .. }, None, Some(ast::IcebergSinkMode::Upsert)) => {
+        (CreateSinkConnection::Iceberg { .. }, None, Some(ast::SinkMode::Upsert)) => {
             SinkEnvelope::Upsert
         }
+        (CreateSinkConnection::Iceberg { .. }, None, Some(ast::SinkMode::Append)) => {
+            sql_bail!("MODE APPEND is not supported for Iceberg sinks")
+        }
+        (CreateSinkConnection::Iceberg { .. }, None, Some(ast::SinkMode::Changelog)) => {
+            scx.require_feature_flag(&vars::ENABLE_SINK_ENVELOPE_CHANGELOG)?;
+            SinkEnvelope::Changelog
+        }
         (CreateSinkConnection::Iceberg { .. }, None, None) => {
             sql_bail!("MODE clause is required")
         }
         (CreateSinkConnection::Iceberg { .. }, Some(_), _) => {
             sql_bail!("ENVELOPE is not supported for Iceberg sinks, use MODE instead")
         }
+        // Postgres sinks use ENVELOPE, but only support UPSERT
+        (CreateSinkConnection::Postgres { .. }, Some(ast::SinkEnvelope::Upsert), None) => {
+            SinkEnvelope::Upsert
+        }
+        (CreateSinkConnection::Postgres { .. }, Some(ast::SinkEnvelope::Debezium), None) => {
+            sql_bail!("ENVELOPE DEBEZIUM is not supported for Postgres sinks")
+        }
+        (CreateSinkConnection::Postgres { .. }, Some(ast::SinkEnvelope::Changelog), None) => {
+            sql_bail!("ENVELOPE CHANGELOG is not supported for Postgres sinks")
+        }
+        (CreateSinkConnection::Postgres { .. }, None, None) => {
+            sql_bail!("ENVELOPE clause is required")
+        }
+        (CreateSinkConnection::Postgres { .. }, _, Some(_)) => {
+            sql_bail!("MODE is not supported for Postgres sinks, use ENVELOPE instead")
+        }
+        // MySQL and SQL Server sinks use MODE
+        (
+            CreateSinkConnection::MySql { .. } | CreateSinkConnection::SqlServer { .. },
+            None,
+            Some(ast::SinkMode::Upsert),
+        ) => SinkEnvelope::Upsert,
+        (
+            CreateSinkConnection::MySql { .. } | CreateSinkConnection::SqlServer { .. },
+            None,
+            Some(ast::SinkMode::Append),
+        ) => SinkEnvelope::Append,
+        (CreateSinkConnection::MySql { .. }, None, Some(ast::SinkMode::Changelog)) => {
+            sql_bail!("MODE CHANGELOG is not supported for MySQL sinks")
+        }
+        (CreateSinkConnection::SqlServer { .. }, None, Some(ast::SinkMode::Changelog)) => {
+            sql_bail!("MODE CHANGELOG is not supported for SQL Server sinks")
+        }
+        (
+            CreateSinkConnection::MySql { .. } | CreateSinkConnection::SqlServer { .. },
+            None,
+            None,
+        ) => {
+            sql_bail!("MODE clause is required")
+        }
+        (CreateSinkConnection::MySql { .. }, Some(_), _) => {
+            sql_bail!("ENVELOPE is not supported for MySQL sinks, use MODE instead")
+        }
+        (CreateSinkConnection::SqlServer { .. }, Some(_), _) => {
+            sql_bail!("ENVELOPE is not supported for SQL Server sinks, use MODE instead")
+        }
+        // S3 sinks use MODE, but only support APPEND
+        (CreateSinkConnection::S3 { .. }, None, Some(ast::SinkMode::Append)) => {
+            SinkEnvelope::Append
+        }
+        (CreateSinkConnection::S3 { .. }, None, Some(ast::SinkMode::Upsert)) => {
+            sql_bail!("MODE UPSERT is not supported for S3 sinks")
+        }
+        (CreateSinkConnection::S3 { .. }, None, Some(ast::SinkMode::Changelog)) => {
+            sql_bail!("MODE CHANGELOG is not supported for S3 sinks")
+        }
+        (CreateSinkConnection::S3 { .. }, None, None) => {
+            sql_bail!("MODE clause is required")
+        }
+        (CreateSinkConnection::S3 { .. }, Some(_), _) => {
+            sql_bail!("ENVELOPE is not supported for S3 sinks, use MODE instead")
+        }
+        // HTTP sinks use ENVELOPE
+        (CreateSinkConnection::Http { .. }, Some(ast::SinkEnvelope::Upsert), None) => {
+            SinkEnvelope::Upsert
+        }
+        (CreateSinkConnection::Http { .. }, Some(ast::SinkEnvelope::Debezium), None) => {
+            SinkEnvelope::Debezium
+        }
+        (CreateSinkConnection::Http { .. }, Some(ast::SinkEnvelope::Changelog), None) => {
+            sql_bail!("ENVELOPE CHANGELOG is not supported for HTTP sinks")
+        }
+        (CreateSinkConnection::Http { .. }, None, None) => {
+            sql_bail!("ENVELOPE clause is required")
+        }
+        (CreateSinkConnection::Http { .. }, _, Some(_)) => {
+            sql_bail!("MODE is not supported for HTTP sinks, use ENVELOPE instead")
+        }
     };
 
     let from_name = &from;
@@ -3523,7 +3798,7 @@ fn plan_sink(
                     });
                 }
             }
-            Sink | View | Index | Type | Func | Secret | Connection => {
+            Sink | View | Index | Type | Func | Secret | Connection | Sequence | Policy => {
                 let name = scx.catalog.minimal_qualification(from.name());
                 return Err(PlanError::InvalidSinkFrom {
                     name: name.to_string(),
@@ -3537,10 +3812,37 @@ fn plan_sink(
         bail_unsupported!("creating a sink directly on a catalog object");
     }
 
+    // Sinks export every row of their input, so they cannot apply row-level
+    // security policies.
+    let role_id = scx.catalog.active_role_id();
+    if from.row_level_security()
+        && !query::bypasses_row_level_security(scx.catalog, role_id, &from.owner_id())
+    {
+        return Err(PlanError::RowLevelSecurityDependency {
+            name: scx.catalog.resolve_full_name(from.name()).to_string(),
+            role: scx.catalog.get_role(role_id).name().to_string(),
+        });
+    }
+
     let desc = from.relation_desc().expect("item type checked above");
+    if envelope == SinkEnvelope::Changelog {
+        for column in [CHANGELOG_TIMESTAMP_COLUMN, CHANGELOG_DIFF_COLUMN] {
+            if desc.get_by_name(&column.into()).is_some() {
+                sql_bail!(
+                    "ENVELOPE CHANGELOG cannot be used with {} because it has a column named {}",
+                    from_name.full_name_str().quoted(),
+                    column.quoted()
+                );
+            }
+        }
+    }
     let key_indices = match &connection {
         CreateSinkConnection::Kafka { key: Some(key), .. }
-        | CreateSinkConnection::Iceberg { key: Some(key), .. } => {
+        | CreateSinkConnection::Iceberg { key: Some(key), .. }
+        | CreateSinkConnection::Postgres { key: Some(key), .. }
+        | CreateSinkConnection::MySql { key: Some(key), .. }
+        | CreateSinkConnection::SqlServer { key: Some(key), .. }
+        | CreateSinkConnection::Http { key: Some(key), .. } => {
             let key_columns = key
                 .key_columns
                 .clone()
@@ -3601,7 +3903,12 @@ fn plan_sink(
             Some(indices)
         }
         CreateSinkConnection::Kafka { key: None, .. }
-        | CreateSinkConnection::Iceberg { key: None, .. } => None,
+        | CreateSinkConnection::Iceberg { key: None, .. }
+        | CreateSinkConnection::Postgres { key: None, .. }
+        | CreateSinkConnection::MySql { key: None, .. }
+        | CreateSinkConnection::SqlServer { key: None, .. }
+        | CreateSinkConnection::Http { key: None, .. }
+        | CreateSinkConnection::S3 { .. } => None,
     };
 
     let headers_index = match &connection {
@@ -3612,10 +3919,11 @@ fn plan_sink(
             scx.require_feature_flag(&ENABLE_KAFKA_SINK_HEADERS)?;
 
             match envelope {
-                SinkEnvelope::Upsert => (),
+                SinkEnvelope::Upsert | SinkEnvelope::Changelog => (),
                 SinkEnvelope::Debezium => {
                     sql_bail!("HEADERS option is not supported with ENVELOPE DEBEZIUM")
                 }
+                SinkEnvelope::Append => unreachable!("Kafka sinks do not use the append envelope"),
             };
 
             let headers = normalize::column_name(headers.clone());
@@ -3698,6 +4006,53 @@ fn plan_sink(
             key_desc_and_indices,
             commit_interval,
         )?,
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            ..
+        } => postgres_sink_builder(
+            scx,
+            connection,
+            options,
+            relation_key_indices,
+            key_desc_and_indices,
+            commit_interval,
+        )?,
+        CreateSinkConnection::MySql {
+            connection,
+            options,
+            ..
+        } => mysql_sink_builder(
+            scx,
+            connection,
+            options,
+            relation_key_indices,
+            key_desc_and_indices,
+            commit_interval,
+        )?,
+        CreateSinkConnection::SqlServer {
+            connection,
+            options,
+            ..
+        } => sql_server_sink_builder(
+            scx,
+            connection,
+            options,
+            relation_key_indices,
+            key_desc_and_indices,
+            commit_interval,
+        )?,
+        CreateSinkConnection::S3 {
+            connection,
+            options,
+        } => s3_sink_builder(scx, connection, options, &desc, commit_interval)?,
+        CreateSinkConnection::Http { options, .. } => http_sink_builder(
+            scx,
+            options,
+            relation_key_indices,
+            key_desc_and_indices,
+            commit_interval,
+        )?,
     };
 
     // WITH SNAPSHOT defaults to true
@@ -3924,102 +4279,465 @@ fn iceberg_sink_builder(
     }))
 }
 
-fn kafka_sink_builder(
+/// Splits the `TABLE` option of a Postgres, MySQL, or SQL Server sink into its
+/// schema and table name.
+///
+/// The upstream table is named by a string so that it follows the upstream's
+/// naming rules rather than ours. If `default_schema` is `None`, the schema
+/// must be specified; this is the case for MySQL, whose connections are not
+/// tied to a database.
+pub(crate) fn upstream_sink_table_name(
+    name: &str,
+    default_schema: Option<&str>,
+) -> Result<(String, String), PlanError> {
+    let form = match default_schema {
+        Some(_) => "[<schema>.]<table>",
+        None => "<schema>.<table>",
+    };
+    let (schema, table) = match (name.split('.').collect::<Vec<_>>()[..], default_schema) {
+        ([table], Some(schema)) => (schema, table),
+        ([schema, table], _) => (schema, table),
+        _ => sql_bail!(
+            "invalid TABLE {}: must be of the form {form}",
+            name.quoted()
+        ),
+    };
+    if schema.is_empty() || table.is_empty() {
+        sql_bail!(
+            "invalid TABLE {}: must be of the form {form}",
+            name.quoted()
+        );
+    }
+    Ok((schema.to_string(), table.to_string()))
+}
+
+fn postgres_sink_builder(
     scx: &StatementContext,
     connection: ResolvedItemName,
-    options: Vec<KafkaSinkConfigOption<Aug>>,
-    format: Option<FormatSpecifier<Aug>>,
+    options: Vec<PostgresSinkConfigOption<Aug>>,
     relation_key_indices: Option<Vec<usize>>,
     key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
-    headers_index: Option<usize>,
-    value_desc: RelationDesc,
-    envelope: SinkEnvelope,
-    sink_from: CatalogItemId,
     commit_interval: Option<Duration>,
 ) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
-    // Get Kafka connection.
+    scx.require_feature_flag(&vars::ENABLE_POSTGRES_SINK)?;
     let connection_item = scx.get_item_by_resolved_name(&connection)?;
     let connection_id = connection_item.id();
-    match connection_item.connection()? {
-        Connection::Kafka(_) => (),
-        _ => sql_bail!(
-            "{} is not a kafka connection",
+    if !matches!(connection_item.connection()?, Connection::Postgres(_)) {
+        sql_bail!(
+            "{} is not a postgres connection",
             scx.catalog.resolve_full_name(connection_item.name())
-        ),
-    };
+        );
+    }
 
     if commit_interval.is_some() {
-        sql_bail!("COMMIT INTERVAL option is not supported with KAFKA sinks");
+        sql_bail!("COMMIT INTERVAL option is not supported with POSTGRES sinks");
     }
 
-    let KafkaSinkConfigOptionExtracted {
-        topic,
-        compression_type,
-        partition_by,
-        progress_group_id_prefix,
-        transactional_id_prefix,
-        legacy_ids,
-        topic_config,
-        topic_metadata_refresh_interval,
-        topic_partition_count,
-        topic_replication_factor,
+    let PostgresSinkConfigOptionExtracted {
+        table,
+        progress_table,
         seen: _,
-    }: KafkaSinkConfigOptionExtracted = options.try_into()?;
+    } = options.try_into()?;
 
-    let transactional_id = match (transactional_id_prefix, legacy_ids) {
-        (Some(_), Some(true)) => {
-            sql_bail!("LEGACY IDS cannot be used at the same time as TRANSACTIONAL ID PREFIX")
-        }
-        (None, Some(true)) => KafkaIdStyle::Legacy,
-        (prefix, _) => KafkaIdStyle::Prefix(prefix),
+    let Some(table) = table else {
+        sql_bail!("Postgres sink must specify TABLE");
     };
+    let (schema, table) = upstream_sink_table_name(&table, Some("public"))?;
+    let progress_table = progress_table.unwrap_or_else(|| DEFAULT_SINK_PROGRESS_TABLE.to_string());
+    if progress_table == table {
+        sql_bail!("PROGRESS TABLE must differ from TABLE");
+    }
 
-    let progress_group_id = match (progress_group_id_prefix, legacy_ids) {
-        (Some(_), Some(true)) => {
-            sql_bail!("LEGACY IDS cannot be used at the same time as PROGRESS GROUP ID PREFIX")
-        }
-        (None, Some(true)) => KafkaIdStyle::Legacy,
-        (prefix, _) => KafkaIdStyle::Prefix(prefix),
-    };
+    Ok(StorageSinkConnection::Postgres(PostgresSinkConnection {
+        connection_id,
+        connection: connection_id,
+        relation_key_indices,
+        key_desc_and_indices,
+        schema,
+        table,
+        progress_table,
+    }))
+}
 
-    let topic_name = topic.ok_or_else(|| sql_err!("KAFKA CONNECTION must specify TOPIC"))?;
+fn mysql_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<MySqlSinkConfigOption<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    commit_interval: Option<Duration>,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_MYSQL_SINK)?;
+    let connection_item = scx.get_item_by_resolved_name(&connection)?;
+    let connection_id = connection_item.id();
+    if !matches!(connection_item.connection()?, Connection::MySql(_)) {
+        sql_bail!(
+            "{} is not a mysql connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        );
+    }
 
-    if topic_metadata_refresh_interval > Duration::from_secs(60 * 60) {
-        // This is a librdkafka-enforced restriction that, if violated,
-        // would result in a runtime error for the source.
-        sql_bail!("TOPIC METADATA REFRESH INTERVAL cannot be greater than 1 hour");
+    if commit_interval.is_some() {
+        sql_bail!("COMMIT INTERVAL option is not supported with MYSQL sinks");
     }
 
-    let assert_positive = |val: Option<i32>, name: &str| {
-        if let Some(val) = val {
-            if val <= 0 {
-                sql_bail!("{} must be a positive integer", name);
-            }
-        }
-        val.map(NonNeg::try_from)
-            .transpose()
-            .map_err(|_| PlanError::Unstructured(format!("{} must be a positive integer", name)))
+    let MySqlSinkConfigOptionExtracted {
+        table,
+        progress_table,
+        seen: _,
+    } = options.try_into()?;
+
+    let Some(table) = table else {
+        sql_bail!("MySQL sink must specify TABLE");
     };
-    let topic_partition_count = assert_positive(topic_partition_count, "TOPIC PARTITION COUNT")?;
-    let topic_replication_factor =
-        assert_positive(topic_replication_factor, "TOPIC REPLICATION FACTOR")?;
+    let (database, table) = upstream_sink_table_name(&table, None)?;
+    let progress_table = progress_table.unwrap_or_else(|| DEFAULT_SINK_PROGRESS_TABLE.to_string());
+    if progress_table == table {
+        sql_bail!("PROGRESS TABLE must differ from TABLE");
+    }
 
-    // Helper method to parse avro connection options for format specifiers that use avro
-    // for either key or value encoding.
-    let gen_avro_schema_options = |conn| {
-        let CsrConnectionAvro {
-            connection:
-                CsrConnection {
-                    connection,
-                    options,
-                },
-            seed,
-            key_strategy,
-            value_strategy,
-        } = conn;
-        if seed.is_some() {
-            sql_bail!("SEED option does not make sense with sinks");
-        }
+    Ok(StorageSinkConnection::MySql(MySqlSinkConnection {
+        connection_id,
+        connection: connection_id,
+        relation_key_indices,
+        key_desc_and_indices,
+        database,
+        table,
+        progress_table,
+    }))
+}
+
+fn sql_server_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<SqlServerSinkConfigOption<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    commit_interval: Option<Duration>,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_SQL_SERVER_SINK)?;
+    let connection_item = scx.get_item_by_resolved_name(&connection)?;
+    let connection_id = connection_item.id();
+    if !matches!(connection_item.connection()?, Connection::SqlServer(_)) {
+        sql_bail!(
+            "{} is not a sql server connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        );
+    }
+
+    if commit_interval.is_some() {
+        sql_bail!("COMMIT INTERVAL option is not supported with SQL SERVER sinks");
+    }
+
+    let SqlServerSinkConfigOptionExtracted {
+        table,
+        progress_table,
+        seen: _,
+    } = options.try_into()?;
+
+    let Some(table) = table else {
+        sql_bail!("SQL Server sink must specify TABLE");
+    };
+    let (schema, table) = upstream_sink_table_name(&table, Some("dbo"))?;
+    let progress_table = progress_table.unwrap_or_else(|| DEFAULT_SINK_PROGRESS_TABLE.to_string());
+    if progress_table == table {
+        sql_bail!("PROGRESS TABLE must differ from TABLE");
+    }
+
+    Ok(StorageSinkConnection::SqlServer(SqlServerSinkConnection {
+        connection_id,
+        connection: connection_id,
+        relation_key_indices,
+        key_desc_and_indices,
+        schema,
+        table,
+        progress_table,
+    }))
+}
+
+fn s3_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<S3SinkConfigOption<Aug>>,
+    desc: &RelationDesc,
+    commit_interval: Option<Duration>,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_S3_SINK)?;
+    let connection_item = scx.get_item_by_resolved_name(&connection)?;
+    let aws_connection_id = connection_item.id();
+    if !matches!(connection_item.connection()?, Connection::Aws(_)) {
+        sql_bail!(
+            "{} is not an AWS connection",
+            scx.catalog
+                .resolve_full_name(connection_item.name())
+                .to_string()
+                .quoted()
+        );
+    }
+
+    let S3SinkConfigOptionExtracted {
+        url,
+        format,
+        max_file_size,
+        seen: _,
+    } = options.try_into()?;
+
+    let Some(url) = url else {
+        sql_bail!("S3 sink must specify URL");
+    };
+    let uri = match http::Uri::from_str(&url) {
+        Ok(uri) => uri,
+        Err(e) => sql_bail!("could not parse S3 sink URL {}: {}", url.quoted(), e),
+    };
+    if uri.scheme_str() != Some("s3") {
+        sql_bail!("only 's3://...' urls are supported as S3 sink URL");
+    }
+    if uri.host().map_or(true, |host| host.is_empty()) {
+        sql_bail!("missing bucket name from 's3://...' url");
+    }
+    // The sink writes its manifest next to the directories of its batches, so
+    // it needs a path to write them under.
+    if uri.path().trim_matches('/').is_empty() {
+        sql_bail!("missing path from 's3://...' url");
+    }
+
+    let format = match format.as_deref().map(|f| f.to_lowercase()).as_deref() {
+        Some("csv") => S3SinkFormat::PgCopy(CopyFormatParams::Csv(Default::default())),
+        Some("parquet") => {
+            // Validate that the sinked relation can be formatted as parquet
+            ArrowBuilder::validate_desc(desc).map_err(|e| sql_err!("{}", e))?;
+            S3SinkFormat::Parquet
+        }
+        Some("avro") => {
+            // Validate that the sinked relation can be formatted as avro
+            S3SinkFormat::avro_schema_generator(desc.clone()).map_err(|e| sql_err!("{}", e))?;
+            S3SinkFormat::Avro
+        }
+        Some("orc") => {
+            validate_orc_desc(desc)?;
+            S3SinkFormat::Orc
+        }
+        Some(format) => sql_bail!("unsupported FORMAT {} for S3 sinks", format.quoted()),
+        None => sql_bail!("S3 sink must specify FORMAT"),
+    };
+
+    if max_file_size.as_bytes() < MIN_S3_SINK_FILE_SIZE.as_bytes() {
+        sql_bail!(
+            "MAX FILE SIZE cannot be less than {}",
+            MIN_S3_SINK_FILE_SIZE
+        );
+    }
+    if max_file_size.as_bytes() > MAX_S3_SINK_FILE_SIZE.as_bytes() {
+        sql_bail!(
+            "MAX FILE SIZE cannot be greater than {}",
+            MAX_S3_SINK_FILE_SIZE
+        );
+    }
+
+    if commit_interval.is_none() {
+        sql_bail!("S3 sink must specify COMMIT INTERVAL");
+    }
+
+    Ok(StorageSinkConnection::S3(S3SinkConnection {
+        aws_connection_id,
+        aws_connection: aws_connection_id,
+        uri: url,
+        format,
+        max_file_size: max_file_size.as_bytes(),
+    }))
+}
+
+/// Validates that the columns of `desc` can be written to ORC files.
+///
+/// ORC files are written from the same Arrow representation as Parquet files,
+/// but ORC has no counterpart for some of the Arrow types we use, like
+/// unsigned integers, decimals and nested types.
+pub(super) fn validate_orc_desc(desc: &RelationDesc) -> Result<(), PlanError> {
+    ArrowBuilder::validate_desc(desc).map_err(|e| sql_err!("{}", e))?;
+    let errs: Vec<_> = desc
+        .iter()
+        .filter(|(_, col_type)| {
+            !matches!(
+                col_type.scalar_type,
+                SqlScalarType::Bool
+                    | SqlScalarType::Int16
+                    | SqlScalarType::Int32
+                    | SqlScalarType::Int64
+                    | SqlScalarType::Float32
+                    | SqlScalarType::Float64
+                    | SqlScalarType::Date
+                    | SqlScalarType::Timestamp { .. }
+                    | SqlScalarType::TimestampTz { .. }
+                    | SqlScalarType::Bytes
+                    | SqlScalarType::Char { .. }
+                    | SqlScalarType::VarChar { .. }
+                    | SqlScalarType::String
+                    | SqlScalarType::Jsonb
+            )
+        })
+        .map(|(col_name, col_type)| format!("{}: {:?}", col_name, col_type.scalar_type))
+        .collect();
+    if !errs.is_empty() {
+        sql_bail!(
+            "Cannot encode the following columns/types as ORC: {:?}",
+            errs
+        );
+    }
+    Ok(())
+}
+
+fn http_sink_builder(
+    scx: &StatementContext,
+    options: Vec<HttpSinkConfigOption<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    commit_interval: Option<Duration>,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_HTTP_SINK)?;
+
+    if commit_interval.is_some() {
+        sql_bail!("COMMIT INTERVAL option is not supported with HTTP sinks");
+    }
+
+    let HttpSinkConfigOptionExtracted {
+        url,
+        headers,
+        seen: _,
+    } = options.try_into()?;
+
+    let Some(url) = url else {
+        sql_bail!("HTTP sink must specify URL");
+    };
+    let url: reqwest::Url = url
+        .parse()
+        .map_err(|e| sql_err!("parsing HTTP sink URL {}: {e}", url.quoted()))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        sql_bail!("only 'http://...' and 'https://...' urls are supported as HTTP sink URL");
+    }
+    if url.host_str().is_none() {
+        sql_bail!("HTTP sink URL must specify a host");
+    }
+
+    for name in headers.keys() {
+        let name = http::HeaderName::from_str(name)
+            .map_err(|_| sql_err!("invalid header name {}", name.quoted()))?;
+        // These headers are set by the sink itself.
+        if name == http::header::CONTENT_TYPE || name.as_str() == "idempotency-key" {
+            sql_bail!("header {} cannot be overridden", name.as_str().quoted());
+        }
+    }
+
+    Ok(StorageSinkConnection::Http(HttpSinkConnection {
+        url,
+        headers,
+        relation_key_indices,
+        key_desc_and_indices,
+    }))
+}
+
+fn kafka_sink_builder(
+    scx: &StatementContext,
+    connection: ResolvedItemName,
+    options: Vec<KafkaSinkConfigOption<Aug>>,
+    format: Option<FormatSpecifier<Aug>>,
+    relation_key_indices: Option<Vec<usize>>,
+    key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    headers_index: Option<usize>,
+    value_desc: RelationDesc,
+    envelope: SinkEnvelope,
+    sink_from: CatalogItemId,
+    commit_interval: Option<Duration>,
+) -> Result<StorageSinkConnection<ReferencedConnection>, PlanError> {
+    // Get Kafka connection.
+    let connection_item = scx.get_item_by_resolved_name(&connection)?;
+    let connection_id = connection_item.id();
+    match connection_item.connection()? {
+        Connection::Kafka(_) => (),
+        _ => sql_bail!(
+            "{} is not a kafka connection",
+            scx.catalog.resolve_full_name(connection_item.name())
+        ),
+    };
+
+    if commit_interval.is_some() {
+        sql_bail!("COMMIT INTERVAL option is not supported with KAFKA sinks");
+    }
+
+    let KafkaSinkConfigOptionExtracted {
+        topic,
+        compression_type,
+        partition_by,
+        progress_group_id_prefix,
+        transactional_id_prefix,
+        legacy_ids,
+        topic_config,
+        topic_metadata_refresh_interval,
+        topic_partition_count,
+        topic_replication_factor,
+        seen: _,
+    }: KafkaSinkConfigOptionExtracted = options.try_into()?;
+
+    let transactional_id = match (transactional_id_prefix, legacy_ids) {
+        (Some(_), Some(true)) => {
+            sql_bail!("LEGACY IDS cannot be used at the same time as TRANSACTIONAL ID PREFIX")
+        }
+        (None, Some(true)) => KafkaIdStyle::Legacy,
+        (prefix, _) => KafkaIdStyle::Prefix(prefix),
+    };
+
+    let progress_group_id = match (progress_group_id_prefix, legacy_ids) {
+        (Some(_), Some(true)) => {
+            sql_bail!("LEGACY IDS cannot be used at the same time as PROGRESS GROUP ID PREFIX")
+        }
+        (None, Some(true)) => KafkaIdStyle::Legacy,
+        (prefix, _) => KafkaIdStyle::Prefix(prefix),
+    };
+
+    let topic_name = topic.ok_or_else(|| sql_err!("KAFKA CONNECTION must specify TOPIC"))?;
+
+    // The changelog envelope appends the timestamp and diff of each update to
+    // the value.
+    let value_desc = match envelope {
+        SinkEnvelope::Changelog => changelog_desc(&value_desc),
+        SinkEnvelope::Debezium | SinkEnvelope::Upsert | SinkEnvelope::Append => value_desc,
+    };
+
+    if topic_metadata_refresh_interval > Duration::from_secs(60 * 60) {
+        // This is a librdkafka-enforced restriction that, if violated,
+        // would result in a runtime error for the source.
+        sql_bail!("TOPIC METADATA REFRESH INTERVAL cannot be greater than 1 hour");
+    }
+
+    let assert_positive = |val: Option<i32>, name: &str| {
+        if let Some(val) = val {
+            if val <= 0 {
+                sql_bail!("{} must be a positive integer", name);
+            }
+        }
+        val.map(NonNeg::try_from)
+            .transpose()
+            .map_err(|_| PlanError::Unstructured(format!("{} must be a positive integer", name)))
+    };
+    let topic_partition_count = assert_positive(topic_partition_count, "TOPIC PARTITION COUNT")?;
+    let topic_replication_factor =
+        assert_positive(topic_replication_factor, "TOPIC REPLICATION FACTOR")?;
+
+    // Helper method to parse avro connection options for format specifiers that use avro
+    // for either key or value encoding.
+    let gen_avro_schema_options = |conn| {
+        let CsrConnectionAvro {
+            connection:
+                CsrConnection {
+                    connection,
+                    options,
+                },
+            seed,
+            key_strategy,
+            value_strategy,
+        } = conn;
+        if seed.is_some() {
+            sql_bail!("SEED option does not make sense with sinks");
+        }
         if key_strategy.is_some() {
             sql_bail!("KEY STRATEGY option does not make sense with sinks");
         }
@@ -4121,7 +4839,7 @@ fn kafka_sink_builder(
             let mut scope = Scope::from_source(None, value_desc.iter_names());
 
             match envelope {
-                SinkEnvelope::Upsert => (),
+                SinkEnvelope::Upsert | SinkEnvelope::Changelog => (),
                 SinkEnvelope::Debezium => {
                     let key_indices: HashSet<_> = key_desc_and_indices
                         .as_ref()
@@ -4139,6 +4857,7 @@ fn kafka_sink_builder(
                         }
                     }
                 }
+                SinkEnvelope::Append => unreachable!("Kafka sinks do not use the append envelope"),
             };
 
             let ecx = &ExprContext {
@@ -4243,7 +4962,7 @@ pub fn plan_create_index(
                     );
                 }
             }
-            Sink | Index | Type | Func | Secret | Connection => {
+            Sink | Index | Type | Func | Secret | Connection | Sequence | Policy => {
                 sql_bail!(
                     "index cannot be created on {} because it is a {}",
                     on_name.full_name_str(),
@@ -4431,88 +5150,476 @@ pub fn plan_create_type(
                 // Validate that the modifiers are actually valid.
                 scalar_type_from_catalog(scx.catalog, id, &modifiers)?;
 
-                Ok((id, modifiers))
-            }
-        }
+                Ok((id, modifiers))
+            }
+        }
+    }
+
+    let inner = match as_type {
+        CreateTypeAs::List { options } => {
+            let CreateTypeListOptionExtracted {
+                element_type,
+                seen: _,
+            } = CreateTypeListOptionExtracted::try_from(options)?;
+            let element_type =
+                element_type.ok_or_else(|| sql_err!("ELEMENT TYPE option is required"))?;
+            let (id, modifiers) = validate_data_type(scx, element_type, "LIST ", "ELEMENT TYPE")?;
+            CatalogType::List {
+                element_reference: id,
+                element_modifiers: modifiers,
+            }
+        }
+        CreateTypeAs::Map { options } => {
+            let CreateTypeMapOptionExtracted {
+                key_type,
+                value_type,
+                seen: _,
+            } = CreateTypeMapOptionExtracted::try_from(options)?;
+            let key_type = key_type.ok_or_else(|| sql_err!("KEY TYPE option is required"))?;
+            let value_type = value_type.ok_or_else(|| sql_err!("VALUE TYPE option is required"))?;
+            let (key_id, key_modifiers) = validate_data_type(scx, key_type, "MAP ", "KEY TYPE")?;
+            let (value_id, value_modifiers) =
+                validate_data_type(scx, value_type, "MAP ", "VALUE TYPE")?;
+            CatalogType::Map {
+                key_reference: key_id,
+                key_modifiers,
+                value_reference: value_id,
+                value_modifiers,
+            }
+        }
+        CreateTypeAs::Record { column_defs } => {
+            let mut fields = vec![];
+            for column_def in column_defs {
+                let data_type = column_def.data_type;
+                let key = ident(column_def.name.clone());
+                let (id, modifiers) = validate_data_type(scx, data_type, "", &key)?;
+                fields.push(CatalogRecordField {
+                    name: ColumnName::from(key.clone()),
+                    type_reference: id,
+                    type_modifiers: modifiers,
+                });
+            }
+            CatalogType::Record { fields }
+        }
+    };
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
+
+    // Check for an object in the catalog with this same name
+    let full_name = scx.catalog.resolve_full_name(&name);
+    let partial_name = PartialItemName::from(full_name.clone());
+    // For PostgreSQL compatibility, we need to prevent creating types when
+    // there is an existing object *or* type of the same name.
+    if let Ok(item) = scx.catalog.resolve_item_or_type(&partial_name) {
+        if item.item_type().conflicts_with_type() {
+            return Err(PlanError::ItemAlreadyExists {
+                name: full_name.to_string(),
+                item_type: item.item_type(),
+            });
+        }
+    }
+
+    Ok(Plan::CreateType(CreateTypePlan {
+        name,
+        typ: Type { create_sql, inner },
+    }))
+}
+
+generate_extracted_config!(CreateTypeListOption, (ElementType, ResolvedDataType));
+
+generate_extracted_config!(
+    CreateTypeMapOption,
+    (KeyType, ResolvedDataType),
+    (ValueType, ResolvedDataType)
+);
+
+pub fn describe_create_function(
+    _: &StatementContext,
+    _: CreateFunctionStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_function(
+    scx: &StatementContext,
+    stmt: CreateFunctionStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let create_sql = normalize::create_statement(scx, Statement::CreateFunction(stmt.clone()))?;
+    let CreateFunctionStatement {
+        name: unresolved_name,
+        if_exists,
+        params: param_defs,
+        returns,
+        body,
+    } = stmt;
+
+    let mut params = vec![];
+    for FunctionParameter { name, data_type } in param_defs {
+        params.push((
+            normalize::column_name(name),
+            scalar_type_from_sql(scx, &data_type)?,
+        ));
+    }
+    if let Some(dup) = params.iter().map(|(name, _)| name).duplicates().next() {
+        sql_bail!("parameter name {} used more than once", dup.quoted());
+    }
+
+    let name =
+        scx.allocate_qualified_name(normalize::unresolved_item_name(unresolved_name.clone())?)?;
+    let full_name = scx.catalog.resolve_full_name(&name);
+
+    let (returns, returns_set) = match returns {
+        FunctionReturns::Scalar(data_type) => {
+            let column_name = ColumnName::from(full_name.item.as_str());
+            (
+                vec![(column_name, scalar_type_from_sql(scx, &data_type)?)],
+                false,
+            )
+        }
+        FunctionReturns::Table(column_defs) => {
+            let mut returns = vec![];
+            for ColumnDef {
+                name, data_type, ..
+            } in column_defs
+            {
+                returns.push((
+                    normalize::column_name(name),
+                    scalar_type_from_sql(scx, &data_type)?,
+                ));
+            }
+            if let Some(dup) = returns.iter().map(|(name, _)| name).duplicates().next() {
+                sql_bail!("column {} specified more than once", dup.quoted());
+            }
+            (returns, true)
+        }
+    };
+
+    let details = CatalogFuncDetails {
+        params,
+        returns,
+        returns_set,
+        body,
+    };
+
+    // Plan the body once up front, so that invalid definitions are rejected at
+    // creation time rather than when the function is first called.
+    let qcx = QueryContext::root(scx, QueryLifetime::View);
+    if details.returns_set {
+        query::plan_sql_table_function(&qcx, &details)?;
+    } else {
+        let ecx = ExprContext {
+            qcx: &qcx,
+            name: "CREATE FUNCTION",
+            scope: &Scope::empty(),
+            relation_type: &SqlRelationType::empty(),
+            allow_aggregates: false,
+            allow_subqueries: true,
+            allow_parameters: true,
+            allow_windows: false,
+        };
+        query::plan_sql_scalar_function(&ecx, &details)?;
+    }
+
+    let replace = if if_exists == IfExistsBehavior::Replace {
+        let if_exists = true;
+        let cascade = false;
+        let maybe_item_to_drop =
+            plan_drop_item(scx, ObjectType::Func, if_exists, unresolved_name, cascade)?;
+
+        // Check if the new function depends on the function that we would be
+        // replacing.
+        if let Some(id) = maybe_item_to_drop {
+            if names::visit_dependencies(scx.catalog, &details.body).contains_item(&id) {
+                sql_bail!(
+                    "cannot replace function {0}: depended upon by new {0} definition",
+                    full_name
+                );
+            }
+        }
+        maybe_item_to_drop
+    } else {
+        None
+    };
+    let drop_ids = replace
+        .map(|id| {
+            scx.catalog
+                .item_dependents(id)
+                .into_iter()
+                .map(|id| id.unwrap_item_id())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Check for a function in the catalog with this same name. Functions live
+    // in a separate namespace from other items, as in PostgreSQL.
+    let partial_name = PartialItemName::from(full_name.clone());
+    if let (Ok(item), IfExistsBehavior::Error) =
+        (scx.catalog.resolve_function(&partial_name), if_exists)
+    {
+        return Err(PlanError::ItemAlreadyExists {
+            name: full_name.to_string(),
+            item_type: item.item_type(),
+        });
+    }
+
+    Ok(Plan::CreateFunction(CreateFunctionPlan {
+        name,
+        function: Function {
+            create_sql,
+            details,
+        },
+        replace,
+        drop_ids,
+    }))
+}
+
+pub fn describe_create_sequence(
+    _: &StatementContext,
+    _: CreateSequenceStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_sequence(
+    scx: &StatementContext,
+    stmt: CreateSequenceStatement,
+) -> Result<Plan, PlanError> {
+    let create_sql = normalize::create_statement(scx, Statement::CreateSequence(stmt.clone()))?;
+    let CreateSequenceStatement {
+        name,
+        if_not_exists,
+        options,
+    } = stmt;
+
+    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
+    // Sequences produce `bigint` values.
+    let options = plan_sequence_options(&options, i64::MIN, i64::MAX)?;
+
+    Ok(Plan::CreateSequence(CreateSequencePlan {
+        name,
+        sequence: Sequence {
+            create_sql,
+            options,
+        },
+        if_not_exists,
+    }))
+}
+
+pub fn describe_create_policy(
+    _: &StatementContext,
+    _: CreatePolicyStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_policy(
+    scx: &StatementContext,
+    stmt: CreatePolicyStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+    let create_sql = normalize::create_statement(scx, Statement::CreatePolicy(stmt.clone()))?;
+    let CreatePolicyStatement {
+        name,
+        on_name,
+        roles,
+        using,
+    } = stmt;
+
+    let on = scx.get_item_by_resolved_name(&on_name)?;
+    let full_name = scx.catalog.resolve_full_name(on.name());
+    if on.id().is_system() {
+        sql_bail!(
+            "cannot create policy on {} because it is required by the database system",
+            full_name
+        );
+    }
+    if matches!(on.name().qualifiers.schema_spec, SchemaSpecifier::Temporary) {
+        sql_bail!("cannot create policy on temporary item {}", full_name);
+    }
+    let desc = match on.item_type() {
+        CatalogItemType::Table | CatalogItemType::View | CatalogItemType::MaterializedView => {
+            on.relation_desc().expect("relations have a desc")
+        }
+        item_type => sql_bail!(
+            "{} is a {}, but policies can only be created on tables, views and materialized views",
+            full_name.to_string().quoted(),
+            item_type
+        ),
+    };
+
+    // Policies live in the schema of the relation they protect.
+    let name = QualifiedItemName {
+        qualifiers: on.name().qualifiers.clone(),
+        item: normalize::ident(name),
+    };
+
+    // Validate the predicate now, so that invalid policies are rejected when
+    // they are created rather than when the relation is queried.
+    let qcx = QueryContext::root(scx, QueryLifetime::OneShot);
+    let scope = Scope::from_source(Some(full_name.clone().into()), desc.iter_names().cloned());
+    let relation_type = desc.typ();
+    query::plan_policy_using(&qcx, &scope, relation_type, &using)?;
+
+    Ok(Plan::CreatePolicy(CreatePolicyPlan {
+        name,
+        policy: Policy {
+            create_sql,
+            details: CatalogPolicyDetails {
+                on: on.id(),
+                roles: roles.into_iter().map(|role| role.id).collect(),
+                using,
+            },
+        },
+    }))
+}
+
+pub fn describe_alter_row_level_security(
+    _: &StatementContext,
+    _: AlterRowLevelSecurityStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_row_level_security(
+    scx: &StatementContext,
+    AlterRowLevelSecurityStatement {
+        object_type,
+        if_exists,
+        name,
+        enabled,
+    }: AlterRowLevelSecurityStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+    let object_type = object_type.into();
+    match object_type {
+        ObjectType::Table | ObjectType::View | ObjectType::MaterializedView => {}
+        object_type => sql_bail!("{object_type} does not support ROW LEVEL SECURITY"),
     }
 
-    let inner = match as_type {
-        CreateTypeAs::List { options } => {
-            let CreateTypeListOptionExtracted {
-                element_type,
-                seen: _,
-            } = CreateTypeListOptionExtracted::try_from(options)?;
-            let element_type =
-                element_type.ok_or_else(|| sql_err!("ELEMENT TYPE option is required"))?;
-            let (id, modifiers) = validate_data_type(scx, element_type, "LIST ", "ELEMENT TYPE")?;
-            CatalogType::List {
-                element_reference: id,
-                element_modifiers: modifiers,
-            }
-        }
-        CreateTypeAs::Map { options } => {
-            let CreateTypeMapOptionExtracted {
-                key_type,
-                value_type,
-                seen: _,
-            } = CreateTypeMapOptionExtracted::try_from(options)?;
-            let key_type = key_type.ok_or_else(|| sql_err!("KEY TYPE option is required"))?;
-            let value_type = value_type.ok_or_else(|| sql_err!("VALUE TYPE option is required"))?;
-            let (key_id, key_modifiers) = validate_data_type(scx, key_type, "MAP ", "KEY TYPE")?;
-            let (value_id, value_modifiers) =
-                validate_data_type(scx, value_type, "MAP ", "VALUE TYPE")?;
-            CatalogType::Map {
-                key_reference: key_id,
-                key_modifiers,
-                value_reference: value_id,
-                value_modifiers,
-            }
-        }
-        CreateTypeAs::Record { column_defs } => {
-            let mut fields = vec![];
-            for column_def in column_defs {
-                let data_type = column_def.data_type;
-                let key = ident(column_def.name.clone());
-                let (id, modifiers) = validate_data_type(scx, data_type, "", &key)?;
-                fields.push(CatalogRecordField {
-                    name: ColumnName::from(key.clone()),
-                    type_reference: id,
-                    type_modifiers: modifiers,
-                });
-            }
-            CatalogType::Record { fields }
+    let entry = match resolve_item_or_type(scx, object_type, name.clone(), if_exists) {
+        Ok(entry) => entry,
+        // Return a more helpful error on `ALTER VIEW <materialized-view>`.
+        Err(PlanError::MismatchedObjectType {
+            name,
+            is_type: ObjectType::MaterializedView,
+            expected_type: ObjectType::View,
+        }) => {
+            return Err(PlanError::AlterViewOnMaterializedView(name.to_string()));
         }
+        e => e?,
+    };
+    let Some(entry) = entry else {
+        scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+            name: name.to_ast_string_simple(),
+            object_type,
+        });
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
     };
 
-    let name = scx.allocate_qualified_name(normalize::unresolved_item_name(name)?)?;
+    let full_name = scx.catalog.resolve_full_name(entry.name());
+    if entry.id().is_system() {
+        sql_bail!(
+            "cannot alter item {} because it is required by the database system",
+            full_name
+        );
+    }
 
-    // Check for an object in the catalog with this same name
-    let full_name = scx.catalog.resolve_full_name(&name);
-    let partial_name = PartialItemName::from(full_name.clone());
-    // For PostgreSQL compatibility, we need to prevent creating types when
-    // there is an existing object *or* type of the same name.
-    if let Ok(item) = scx.catalog.resolve_item_or_type(&partial_name) {
-        if item.item_type().conflicts_with_type() {
-            return Err(PlanError::ItemAlreadyExists {
-                name: full_name.to_string(),
-                item_type: item.item_type(),
-            });
+    // Objects that depend on the relation are computed with the privileges of
+    // their owner. Refuse to enable row-level security if that would allow
+    // them to expose rows that their owner can no longer see.
+    if enabled {
+        for id in entry.used_by() {
+            let dependent = scx.catalog.get_item(id);
+            match dependent.item_type() {
+                CatalogItemType::View
+                | CatalogItemType::MaterializedView
+                | CatalogItemType::Sink
+                | CatalogItemType::ContinualTask => {}
+                _ => continue,
+            }
+            let owner_id = dependent.owner_id();
+            if !query::bypasses_row_level_security(scx.catalog, &owner_id, &entry.owner_id()) {
+                return Err(PlanError::RowLevelSecurityDependency {
+                    name: full_name.to_string(),
+                    role: scx.catalog.get_role(&owner_id).name().to_string(),
+                });
+            }
         }
     }
 
-    Ok(Plan::CreateType(CreateTypePlan {
-        name,
-        typ: Type { create_sql, inner },
+    Ok(Plan::AlterRowLevelSecurity(AlterRowLevelSecurityPlan {
+        id: entry.id(),
+        object_type,
+        enabled,
     }))
 }
 
-generate_extracted_config!(CreateTypeListOption, (ElementType, ResolvedDataType));
+/// Plans the options of a sequence whose values must lie within
+/// `type_min..=type_max`, filling in the defaults that PostgreSQL uses for
+/// unspecified options.
+pub(crate) fn plan_sequence_options(
+    options: &[SequenceOption],
+    type_min: i64,
+    type_max: i64,
+) -> Result<SequenceOptions, PlanError> {
+    let mut increment = None;
+    let mut min_value = None;
+    let mut max_value = None;
+    let mut start = None;
+    let mut cycle = None;
+    for option in options {
+        let seen = match option {
+            SequenceOption::IncrementBy(n) => increment.replace(*n).is_some(),
+            SequenceOption::MinValue(n) => min_value.replace(*n).is_some(),
+            SequenceOption::MaxValue(n) => max_value.replace(*n).is_some(),
+            SequenceOption::StartWith(n) => start.replace(*n).is_some(),
+            SequenceOption::Cycle(c) => cycle.replace(*c).is_some(),
+        };
+        if seen {
+            sql_bail!("conflicting or redundant options");
+        }
+    }
 
-generate_extracted_config!(
-    CreateTypeMapOption,
-    (KeyType, ResolvedDataType),
-    (ValueType, ResolvedDataType)
-);
+    let increment = increment.unwrap_or(1);
+    if increment == 0 {
+        sql_bail!("INCREMENT must not be zero");
+    }
+    let ascending = increment > 0;
+    let min_value = match min_value.flatten() {
+        Some(min_value) => min_value,
+        None if ascending => 1,
+        None => type_min,
+    };
+    let max_value = match max_value.flatten() {
+        Some(max_value) => max_value,
+        None if ascending => type_max,
+        None => -1,
+    };
+    if min_value < type_min || min_value > type_max {
+        sql_bail!("MINVALUE ({min_value}) is out of range for sequence data type");
+    }
+    if max_value < type_min || max_value > type_max {
+        sql_bail!("MAXVALUE ({max_value}) is out of range for sequence data type");
+    }
+    if min_value >= max_value {
+        sql_bail!("MINVALUE ({min_value}) must be less than MAXVALUE ({max_value})");
+    }
+    let start = start.unwrap_or(if ascending { min_value } else { max_value });
+    if start < min_value {
+        sql_bail!("START value ({start}) cannot be less than MINVALUE ({min_value})");
+    }
+    if start > max_value {
+        sql_bail!("START value ({start}) cannot be greater than MAXVALUE ({max_value})");
+    }
+
+    Ok(SequenceOptions {
+        increment,
+        min_value,
+        max_value,
+        start,
+        cycle: cycle.unwrap_or(false),
+    })
+}
 
 #[derive(Debug)]
 pub enum PlannedAlterRoleOption {
@@ -5551,11 +6658,6 @@ pub fn plan_drop_objects(
         cascade,
     }: DropObjectsStatement,
 ) -> Result<Plan, PlanError> {
-    assert_ne!(
-        object_type,
-        mz_sql_parser::ast::ObjectType::Func,
-        "rejected in parser"
-    );
     let object_type = object_type.into();
 
     let mut referenced_ids = Vec::new();
@@ -5814,7 +6916,9 @@ fn dependency_prevents_drop(object_type: ObjectType, dep: &dyn CatalogItem) -> b
         | ObjectType::Schema
         | ObjectType::Func
         | ObjectType::ContinualTask
-        | ObjectType::NetworkPolicy => match dep.item_type() {
+        | ObjectType::NetworkPolicy
+        | ObjectType::Sequence
+        | ObjectType::Policy => match dep.item_type() {
             CatalogItemType::Func
             | CatalogItemType::Table
             | CatalogItemType::Source
@@ -5824,8 +6928,11 @@ fn dependency_prevents_drop(object_type: ObjectType, dep: &dyn CatalogItem) -> b
             | CatalogItemType::Type
             | CatalogItemType::Secret
             | CatalogItemType::Connection
-            | CatalogItemType::ContinualTask => true,
-            CatalogItemType::Index => false,
+            | CatalogItemType::ContinualTask
+            | CatalogItemType::Sequence => true,
+            // Dropping a policy only ever hides rows, so policies never
+            // prevent a drop.
+            CatalogItemType::Index | CatalogItemType::Policy => false,
         },
     }
 }
@@ -6169,6 +7276,7 @@ generate_extracted_config!(
     TableOption,
     (PartitionBy, Vec<Ident>),
     (RetainHistory, OptionalDuration),
+    (RowLevelSecurity, bool, Default(false)),
     (RedactedTest, String)
 );
 
@@ -6180,10 +7288,15 @@ fn plan_table_options(
     let TableOptionExtracted {
         partition_by,
         retain_history,
+        row_level_security,
         redacted_test,
         ..
     }: TableOptionExtracted = with_opts.try_into()?;
 
+    if row_level_security {
+        scx.require_feature_flag(&vars::ENABLE_ROW_LEVEL_SECURITY)?;
+    }
+
     if let Some(partition_by) = partition_by {
         scx.require_feature_flag(&ENABLE_COLLECTION_PARTITION_BY)?;
         check_partition_by(desc, partition_by)?;
@@ -6193,10 +7306,13 @@ fn plan_table_options(
         scx.require_feature_flag(&vars::ENABLE_REDACTED_TEST_OPTION)?;
     }
 
-    let mut out = Vec::with_capacity(1);
+    let mut out = Vec::with_capacity(2);
     if let Some(cw) = plan_retain_history_option(scx, retain_history)? {
         out.push(crate::plan::TableOption::RetainHistory(cw));
     }
+    if row_level_security {
+        out.push(crate::plan::TableOption::RowLevelSecurity);
+    }
     Ok(out)
 }
 
@@ -6551,7 +7667,9 @@ pub fn plan_alter_item_set_cluster(
         | ObjectType::Schema
         | ObjectType::Func
         | ObjectType::ContinualTask
-        | ObjectType::NetworkPolicy => {
+        | ObjectType::NetworkPolicy
+        | ObjectType::Sequence
+        | ObjectType::Policy => {
             bail_never_supported!(
                 format!("ALTER {object_type} SET CLUSTER"),
                 "sql/alter-set-cluster/",
@@ -6963,7 +8081,9 @@ pub fn plan_alter_object_swap(
             | ObjectType::Database
             | ObjectType::Func
             | ObjectType::ContinualTask
-            | ObjectType::NetworkPolicy,
+            | ObjectType::NetworkPolicy
+            | ObjectType::Sequence
+            | ObjectType::Policy,
             _,
             _,
         ) => Err(PlanError::Unsupported {
@@ -7673,6 +8793,311 @@ pub fn plan_alter_table_add_column(
     }))
 }
 
+pub fn describe_alter_table_drop_column(
+    _: &StatementContext,
+    _: AlterTableDropColumnStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_table_drop_column(
+    scx: &StatementContext,
+    stmt: AlterTableDropColumnStatement,
+) -> Result<Plan, PlanError> {
+    let AlterTableDropColumnStatement {
+        if_exists,
+        name,
+        if_col_exists,
+        column_name,
+    } = stmt;
+    let object_type = ObjectType::Table;
+
+    scx.require_feature_flag(&vars::ENABLE_ALTER_TABLE_ADD_COLUMN)?;
+
+    let Some((item, item_name, desc)) = resolve_alterable_table(scx, name, if_exists)? else {
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+    };
+
+    let column_name = ColumnName::from(column_name.as_str());
+    let Some((column_idx, _)) = desc.get_by_name(&column_name) else {
+        return unknown_alter_table_column(scx, item_name, column_name, if_col_exists);
+    };
+
+    // Dependents planned against earlier versions of the table read data
+    // written without the column with the column filled with NULLs.
+    let nullable = desc.typ().column_types[column_idx].nullable;
+    check_no_column_dependents(scx, item, "drop", &column_name, &item_name, !nullable)?;
+
+    if desc.typ().keys.iter().any(|key| key.contains(&column_idx)) {
+        sql_bail!(
+            "cannot drop column {} of table {} because it is part of a key",
+            column_name.quoted(),
+            item_name.item.quoted()
+        );
+    }
+    // Identity columns are identified by their position, which dropping an
+    // earlier column would change.
+    if item
+        .identity_columns()
+        .iter()
+        .any(|identity| identity.column >= column_idx)
+    {
+        bail_unsupported!("dropping an identity column or a column that precedes one");
+    }
+
+    Ok(Plan::AlterTableDropColumn(AlterTableDropColumnPlan {
+        relation_id: item.id(),
+        column_name,
+    }))
+}
+
+pub fn describe_alter_table_rename_column(
+    _: &StatementContext,
+    _: AlterTableRenameColumnStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_alter_table_rename_column(
+    scx: &StatementContext,
+    stmt: AlterTableRenameColumnStatement,
+) -> Result<Plan, PlanError> {
+    let AlterTableRenameColumnStatement {
+        if_exists,
+        name,
+        column_name,
+        to_column_name,
+    } = stmt;
+    let object_type = ObjectType::Table;
+
+    scx.require_feature_flag(&vars::ENABLE_ALTER_TABLE_ADD_COLUMN)?;
+
+    let Some((item, item_name, desc)) = resolve_alterable_table(scx, name, if_exists)? else {
+        return Ok(Plan::AlterNoop(AlterNoopPlan { object_type }));
+    };
+
+    let column_name = ColumnName::from(column_name.as_str());
+    if desc.get_by_name(&column_name).is_none() {
+        return unknown_alter_table_column(scx, item_name, column_name, false);
+    }
+    let to_column_name = ColumnName::from(to_column_name.as_str());
+    if desc.get_by_name(&to_column_name).is_some() {
+        return Err(PlanError::ColumnAlreadyExists {
+            column_name: to_column_name,
+            object_name: item_name.item,
+        });
+    }
+
+    check_no_column_dependents(scx, item, "rename", &column_name, &item_name, false)?;
+
+    Ok(Plan::AlterTableRenameColumn(AlterTableRenameColumnPlan {
+        relation_id: item.id(),
+        column_name,
+        to_column_name,
+    }))
+}
+
+/// Resolves the writable table `name` that is the target of an `ALTER TABLE`
+/// that changes its columns, along with its latest [`RelationDesc`].
+///
+/// Returns `None`, after emitting a notice, if the table does not exist and
+/// `if_exists` is set.
+fn resolve_alterable_table<'a>(
+    scx: &'a StatementContext,
+    name: UnresolvedItemName,
+    if_exists: bool,
+) -> Result<Option<(&'a dyn CatalogItem, FullItemName, RelationDesc)>, PlanError> {
+    let object_type = ObjectType::Table;
+    let Some(item) = resolve_item_or_type(scx, object_type, name.clone(), if_exists)? else {
+        scx.catalog.add_notice(PlanNotice::ObjectDoesNotExist {
+            name: name.to_ast_string_simple(),
+            object_type,
+        });
+        return Ok(None);
+    };
+    let item_name = scx.catalog.resolve_full_name(item.name());
+    if item.writable_table_details().is_none() || item.id().is_system() {
+        sql_bail!(
+            "cannot alter the columns of non-writeable table {}",
+            item_name.to_string().quoted()
+        );
+    }
+    let desc = item
+        .at_version(RelationVersionSelector::Latest)
+        .relation_desc()
+        .expect("table has desc")
+        .into_owned();
+    Ok(Some((item, item_name, desc)))
+}
+
+/// Reports that `column_name` does not exist in the table `item_name`, either
+/// as an error or, if `if_col_exists` is set, as a notice.
+fn unknown_alter_table_column(
+    scx: &StatementContext,
+    item_name: FullItemName,
+    column_name: ColumnName,
+    if_col_exists: bool,
+) -> Result<Plan, PlanError> {
+    if if_col_exists {
+        scx.catalog.add_notice(PlanNotice::ColumnDoesNotExist {
+            column_name: column_name.to_string(),
+            object_name: item_name.item,
+        });
+        return Ok(Plan::AlterNoop(AlterNoopPlan {
+            object_type: ObjectType::Table,
+        }));
+    }
+    Err(PlanError::UnknownColumn {
+        table: Some(item_name.into()),
+        column: column_name,
+        similar: Box::new([]),
+    })
+}
+
+/// Ensures that no objects that depend on the table `item` refer to its column
+/// `column_name`, which is about to be dropped or renamed.
+///
+/// Dependent objects are stored as SQL that refers to columns by name, so those
+/// that refer to the column could no longer be planned after such a change.
+/// Other dependents keep reading the version of the table they were planned
+/// against, which must be able to represent data written after the change. If
+/// it can't, e.g. because a dropped column can't be filled with NULLs, every
+/// dependent is rejected by passing `reject_all`.
+fn check_no_column_dependents(
+    scx: &StatementContext,
+    item: &dyn CatalogItem,
+    action: &'static str,
+    column_name: &ColumnName,
+    item_name: &FullItemName,
+    reject_all: bool,
+) -> Result<(), PlanError> {
+    let dependents: Vec<_> = item
+        .used_by()
+        .iter()
+        .map(|id| scx.catalog.get_item(id))
+        .filter(|dependent| {
+            reject_all || refers_to_column(dependent.create_sql(), item, column_name, item_name)
+        })
+        .map(|dependent| {
+            let name = scx.catalog.resolve_full_name(dependent.name());
+            (dependent.item_type().to_string(), name.to_string())
+        })
+        .collect();
+    if dependents.is_empty() {
+        return Ok(());
+    }
+    Err(PlanError::ColumnDependentObjectsStillExist {
+        action,
+        column_name: column_name.clone(),
+        object_name: item_name.item.clone(),
+        dependents,
+    })
+}
+
+/// Reports whether the object created by `create_sql` may refer to the column
+/// `column_name` of the table `item`.
+///
+/// Names are not resolved, so any use of an identifier spelled like the column
+/// counts as a reference. So do wildcards, natural joins and whole-row
+/// references, which implicitly refer to every column, and objects other than
+/// views, materialized views and indexes with explicit keys.
+fn refers_to_column(
+    create_sql: &str,
+    item: &dyn CatalogItem,
+    column_name: &ColumnName,
+    item_name: &FullItemName,
+) -> bool {
+    let Ok(stmts) = mz_sql_parser::parser::parse_statements(create_sql) else {
+        return true;
+    };
+    let [stmt]: [StatementParseResult; 1] = match stmts.try_into() {
+        Ok(stmts) => stmts,
+        Err(_) => return true,
+    };
+    match &stmt.ast {
+        Statement::CreateView(_) | Statement::CreateMaterializedView(_) => {}
+        Statement::CreateIndex(CreateIndexStatement {
+            key_parts: Some(_), ..
+        }) => {}
+        _ => return true,
+    }
+
+    let mut visitor = ColumnReferenceVisitor {
+        item_id: item.id().to_string(),
+        column_name,
+        found: false,
+        row_references: BTreeSet::new(),
+        table_names: BTreeSet::from([item_name.item.clone()]),
+    };
+    visitor.visit_statement(&stmt.ast);
+    visitor.found
+        || visitor
+            .row_references
+            .iter()
+            .any(|name| visitor.table_names.contains(name))
+}
+
+/// Looks for references to a column of a table. See [`refers_to_column`].
+struct ColumnReferenceVisitor<'a> {
+    /// The ID of the table, as printed in `create_sql`.
+    item_id: String,
+    column_name: &'a ColumnName,
+    /// Whether a reference to the column was found.
+    found: bool,
+    /// Unqualified identifiers used as expressions, which may refer to a whole
+    /// row.
+    row_references: BTreeSet<String>,
+    /// The names that the table is referred to by.
+    table_names: BTreeSet<String>,
+}
+
+impl<'ast> Visit<'ast, ast::Raw> for ColumnReferenceVisitor<'_> {
+    fn visit_ident(&mut self, ident: &'ast Ident) {
+        if ident.as_str() == self.column_name.as_str() {
+            self.found = true;
+        }
+    }
+
+    fn visit_select_item(&mut self, item: &'ast ast::SelectItem<ast::Raw>) {
+        if matches!(item, ast::SelectItem::Wildcard) {
+            self.found = true;
+        }
+        visit::visit_select_item(self, item);
+    }
+
+    fn visit_join_constraint(&mut self, constraint: &'ast ast::JoinConstraint<ast::Raw>) {
+        if matches!(constraint, ast::JoinConstraint::Natural) {
+            self.found = true;
+        }
+        visit::visit_join_constraint(self, constraint);
+    }
+
+    fn visit_expr(&mut self, expr: &'ast Expr<ast::Raw>) {
+        match expr {
+            Expr::QualifiedWildcard(_) | Expr::WildcardAccess(_) => self.found = true,
+            Expr::Identifier(names) if names.len() == 1 => {
+                self.row_references.insert(names[0].as_str().to_string());
+            }
+            _ => {}
+        }
+        visit::visit_expr(self, expr);
+    }
+
+    fn visit_table_factor(&mut self, factor: &'ast ast::TableFactor<ast::Raw>) {
+        if let ast::TableFactor::Table {
+            name: ast::RawItemName::Id(id, _, _),
+            alias: Some(alias),
+            ..
+        } = factor
+        {
+            if *id == self.item_id {
+                self.table_names.insert(alias.name.as_str().to_string());
+            }
+        }
+        visit::visit_table_factor(self, factor);
+    }
+}
+
 pub fn describe_alter_materialized_view_apply_replacement(
     _: &StatementContext,
     _: AlterMaterializedViewApplyReplacementStatement,
@@ -7972,6 +9397,7 @@ pub(crate) fn resolve_item_or_type<'a>(
     let name = normalize::unresolved_item_name(name)?;
     let catalog_item = match object_type {
         ObjectType::Type => scx.catalog.resolve_type(&name),
+        ObjectType::Func => scx.catalog.resolve_function(&name),
         ObjectType::Table
         | ObjectType::View
         | ObjectType::MaterializedView
@@ -7985,9 +9411,10 @@ pub(crate) fn resolve_item_or_type<'a>(
         | ObjectType::Connection
         | ObjectType::Database
         | ObjectType::Schema
-        | ObjectType::Func
         | ObjectType::ContinualTask
-        | ObjectType::NetworkPolicy => scx.catalog.resolve_item(&name),
+        | ObjectType::NetworkPolicy
+        | ObjectType::Sequence
+        | ObjectType::Policy => scx.catalog.resolve_item(&name),
     };
 
     match catalog_item {
@@ -47,7 +47,7 @@ use crate::ast::{
     AstInfo, CopyDirection, CopyOption, CopyOptionName, CopyRelation, CopyStatement, CopyTarget,
     DeleteStatement, ExplainPlanStatement, ExplainStage, Explainee, Ident, InsertStatement, Query,
     SelectStatement, SubscribeOption, SubscribeOptionName, SubscribeRelation, SubscribeStatement,
-    UpdateStatement,
+    TruncateStatement, UpdateStatement,
 };
 use crate::catalog::CatalogItemType;
 use crate::names::{Aug, ResolvedItemName};
@@ -64,7 +64,7 @@ use crate::plan::{
 };
 use crate::plan::{
     CopyFormat, CopyFromPlan, ExplainPlanPlan, InsertPlan, MutationKind, Params, Plan, PlanError,
-    QueryContext, ReadThenWritePlan, SelectPlan, SubscribeFrom, SubscribePlan, query,
+    QueryContext, ReadThenWritePlan, SelectPlan, SubscribeFrom, SubscribePlan, TruncatePlan, query,
 };
 use crate::plan::{CopyFromSource, with_options};
 use crate::session::vars::{
@@ -117,10 +117,13 @@ pub fn plan_insert(
         })
         .collect::<Result<Vec<_>, _>>()?;
 
+    let identity_columns = scx.get_item(&id).identity_columns().to_vec();
+
     Ok(Plan::Insert(InsertPlan {
         id,
         values: expr,
         returning,
+        identity_columns,
     }))
 }
 
@@ -158,6 +161,51 @@ pub fn plan_update(
     plan_read_then_write(scx, MutationKind::Update, params, rtw_plan)
 }
 
+pub fn describe_truncate(
+    _: &StatementContext,
+    _: TruncateStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_truncate(
+    scx: &StatementContext,
+    TruncateStatement { table_names }: TruncateStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    let mut ids = Vec::with_capacity(table_names.len());
+    for table_name in table_names {
+        let id = match &table_name {
+            ResolvedItemName::Item { id, .. } => *id,
+            _ => sql_bail!("cannot truncate non-user table"),
+        };
+        let item = scx.get_item(&id);
+        if item.item_type() != CatalogItemType::Table {
+            sql_bail!(
+                "cannot truncate {} '{}'",
+                item.item_type(),
+                table_name.full_name_str()
+            );
+        }
+        if item.writable_table_details().is_none() {
+            sql_bail!(
+                "cannot truncate non-writeable table '{}'",
+                table_name.full_name_str()
+            );
+        }
+        if id.is_system() {
+            sql_bail!(
+                "cannot truncate system table '{}'",
+                table_name.full_name_str()
+            );
+        }
+        // Naming a table more than once truncates it once.
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    Ok(Plan::Truncate(TruncatePlan { ids }))
+}
+
 pub fn plan_read_then_write(
     scx: &StatementContext,
     kind: MutationKind,
@@ -184,6 +232,7 @@ pub fn plan_read_then_write(
         assignments: assignments_outer,
         kind,
         returning: Vec::new(),
+        identity_columns: Vec::new(),
     }))
 }
 
@@ -1656,16 +1705,36 @@ pub fn plan_subscribe(
                     item.item_type(),
                 );
             };
-            let item_name = match name {
-                ResolvedItemName::Item { full_name, .. } => Some(full_name.into()),
-                _ => None,
-            };
-            let scope = Scope::from_source(item_name, desc.iter().map(|(name, _type)| name));
-            (
-                SubscribeFrom::Id(item.global_id()),
-                desc.into_owned(),
-                scope,
-            )
+            let role_id = scx.catalog.active_role_id();
+            if item.row_level_security()
+                && !query::bypasses_row_level_security(scx.catalog, role_id, &item.owner_id())
+            {
+                // The rows of the item must be filtered by its row-level
+                // security policies, so subscribe to a query instead.
+                let desc = desc.into_owned();
+                let qcx = QueryContext::root(scx, QueryLifetime::Subscribe);
+                let (expr, scope) = qcx.resolve_table_name(name)?;
+                let expr = expr.lower(scx.catalog.system_vars(), None)?;
+                (
+                    SubscribeFrom::Query {
+                        expr,
+                        desc: desc.clone(),
+                    },
+                    desc,
+                    scope,
+                )
+            } else {
+                let item_name = match name {
+                    ResolvedItemName::Item { full_name, .. } => Some(full_name.into()),
+                    _ => None,
+                };
+                let scope = Scope::from_source(item_name, desc.iter().map(|(name, _type)| name));
+                (
+                    SubscribeFrom::Id(item.global_id()),
+                    desc.into_owned(),
+                    scope,
+                )
+            }
         }
         SubscribeRelation::Query(query) => {
             #[allow(deprecated)] // TODO(aalexandrov): Use HirRelationExpr in Subscribe
@@ -1886,8 +1955,18 @@ fn plan_copy_to_expr(
             ArrowBuilder::validate_desc(&desc).map_err(|e| sql_err!("{}", e))?;
             S3SinkFormat::Parquet
         }
+        CopyFormat::Avro => {
+            // Validate that the output desc can be formatted as avro
+            S3SinkFormat::avro_schema_generator(desc.clone()).map_err(|e| sql_err!("{}", e))?;
+            S3SinkFormat::Avro
+        }
+        CopyFormat::Orc => {
+            ddl::validate_orc_desc(&desc)?;
+            S3SinkFormat::Orc
+        }
         CopyFormat::Binary => bail_unsupported!("FORMAT BINARY"),
         CopyFormat::Text => bail_unsupported!("FORMAT TEXT"),
+        CopyFormat::Json => bail_unsupported!("FORMAT JSON"),
     };
 
     // Converting the to expr to a HirScalarExpr
@@ -2019,6 +2098,9 @@ fn plan_copy_from(
         }
         CopyFormat::Binary => bail_unsupported!("FORMAT BINARY"),
         CopyFormat::Parquet => CopyFormatParams::Parquet,
+        CopyFormat::Json => CopyFormatParams::Json,
+        CopyFormat::Avro => CopyFormatParams::Avro,
+        CopyFormat::Orc => bail_unsupported!("FORMAT ORC"),
     };
 
     let filter = match (options.files, options.pattern) {
@@ -2094,6 +2176,9 @@ pub fn plan_copy(
             "csv" => Ok(CopyFormat::Csv),
             "binary" => Ok(CopyFormat::Binary),
             "parquet" => Ok(CopyFormat::Parquet),
+            "avro" => Ok(CopyFormat::Avro),
+            "orc" => Ok(CopyFormat::Orc),
+            "json" | "ndjson" => Ok(CopyFormat::Json),
             _ => sql_bail!("unknown FORMAT: {}", format),
         })
         .transpose()?;
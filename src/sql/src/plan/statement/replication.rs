@@ -0,0 +1,296 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Logical replication.
+//!
+//! This module houses the handlers for publications and for the commands of
+//! PostgreSQL's streaming replication protocol, like `IDENTIFY_SYSTEM` and
+//! `START_REPLICATION`.
+
+use mz_repr::{RelationDesc, SqlScalarType, Timestamp, strconv};
+
+use crate::ast::{
+    CreatePublicationStatement, CreateReplicationSlotStatement, DropPublicationStatement,
+    DropReplicationSlotStatement, IdentifySystemStatement, ReplicationOption,
+    StartReplicationStatement, Value,
+};
+use crate::catalog::CatalogItemType;
+use crate::names::Aug;
+use crate::plan::statement::{StatementContext, StatementDesc};
+use crate::plan::{
+    CreatePublicationPlan, CreateReplicationSlotPlan, DropPublicationPlan, DropReplicationSlotPlan,
+    Plan, PlanError, StartReplicationPlan, bypasses_row_level_security,
+};
+use crate::session::vars;
+
+/// The only output plugin that replication slots support.
+const PGOUTPUT: &str = "pgoutput";
+
+pub fn describe_create_publication(
+    _: &StatementContext,
+    _: CreatePublicationStatement<Aug>,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_create_publication(
+    scx: &StatementContext,
+    CreatePublicationStatement { name, relations }: CreatePublicationStatement<Aug>,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LOGICAL_REPLICATION)?;
+    let mut ids = Vec::with_capacity(relations.len());
+    for relation in &relations {
+        let item = scx.get_item_by_resolved_name(relation)?;
+        match item.item_type() {
+            CatalogItemType::Table | CatalogItemType::MaterializedView => {}
+            ty => sql_bail!(
+                "{} is a {ty}, but publications may only include tables and materialized views",
+                scx.catalog.resolve_full_name(item.name())
+            ),
+        }
+        // Replication streams every change to the published relations, so
+        // they cannot apply row-level security policies.
+        let role_id = scx.catalog.active_role_id();
+        if item.row_level_security()
+            && !bypasses_row_level_security(scx.catalog, role_id, &item.owner_id())
+        {
+            return Err(PlanError::RowLevelSecurityBypassRequired {
+                name: scx.catalog.resolve_full_name(item.name()).to_string(),
+                role: scx.catalog.get_role(role_id).name().to_string(),
+                operation: "publish",
+            });
+        }
+        if !ids.contains(&item.id()) {
+            ids.push(item.id());
+        }
+    }
+    Ok(Plan::CreatePublication(CreatePublicationPlan {
+        name: name.into_string(),
+        relations: ids,
+    }))
+}
+
+pub fn describe_drop_publication(
+    _: &StatementContext,
+    _: DropPublicationStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_drop_publication(
+    scx: &StatementContext,
+    DropPublicationStatement { name, if_exists }: DropPublicationStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LOGICAL_REPLICATION)?;
+    Ok(Plan::DropPublication(DropPublicationPlan {
+        name: name.into_string(),
+        if_exists,
+    }))
+}
+
+pub fn describe_identify_system(
+    _: &StatementContext,
+    _: IdentifySystemStatement,
+) -> Result<StatementDesc, PlanError> {
+    let desc = RelationDesc::builder()
+        .with_column("systemid", SqlScalarType::String.nullable(false))
+        .with_column("timeline", SqlScalarType::Int32.nullable(false))
+        .with_column("xlogpos", SqlScalarType::String.nullable(false))
+        .with_column("dbname", SqlScalarType::String.nullable(true))
+        .finish();
+    Ok(StatementDesc::new(Some(desc)))
+}
+
+pub fn plan_identify_system(
+    scx: &StatementContext,
+    _: IdentifySystemStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LOGICAL_REPLICATION)?;
+    Ok(Plan::IdentifySystem)
+}
+
+pub fn describe_create_replication_slot(
+    _: &StatementContext,
+    _: CreateReplicationSlotStatement,
+) -> Result<StatementDesc, PlanError> {
+    let desc = RelationDesc::builder()
+        .with_column("slot_name", SqlScalarType::String.nullable(false))
+        .with_column("consistent_point", SqlScalarType::String.nullable(false))
+        .with_column("snapshot_name", SqlScalarType::String.nullable(true))
+        .with_column("output_plugin", SqlScalarType::String.nullable(true))
+        .finish();
+    Ok(StatementDesc::new(Some(desc)))
+}
+
+pub fn plan_create_replication_slot(
+    scx: &StatementContext,
+    CreateReplicationSlotStatement {
+        name,
+        temporary,
+        plugin,
+        options,
+    }: CreateReplicationSlotStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LOGICAL_REPLICATION)?;
+    if temporary {
+        bail_unsupported!("temporary replication slots");
+    }
+    if plugin.as_str() != PGOUTPUT {
+        bail_unsupported!(format!("output plugin {plugin}"));
+    }
+    for option in &options {
+        match option.name.as_str() {
+            // Slots never export a snapshot: changes are only streamed from
+            // the point at which the slot was created.
+            "noexport_snapshot" => {}
+            "snapshot" => match option_value(option)? {
+                "nothing" => {}
+                "export" | "use" => bail_unsupported!("replication slot snapshots"),
+                value => sql_bail!(
+                    "unrecognized value for CREATE_REPLICATION_SLOT option \"snapshot\": \"{value}\""
+                ),
+            },
+            "export_snapshot" | "use_snapshot" => bail_unsupported!("replication slot snapshots"),
+            "two_phase" | "failover" => {
+                if parse_bool_option(option)? {
+                    bail_unsupported!(format!("replication slot option {}", option.name));
+                }
+            }
+            name => sql_bail!("unrecognized CREATE_REPLICATION_SLOT option \"{name}\""),
+        }
+    }
+    Ok(Plan::CreateReplicationSlot(CreateReplicationSlotPlan {
+        name: name.into_string(),
+    }))
+}
+
+pub fn describe_drop_replication_slot(
+    _: &StatementContext,
+    _: DropReplicationSlotStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_drop_replication_slot(
+    scx: &StatementContext,
+    DropReplicationSlotStatement { name, wait }: DropReplicationSlotStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LOGICAL_REPLICATION)?;
+    if wait {
+        bail_unsupported!("DROP_REPLICATION_SLOT ... WAIT");
+    }
+    Ok(Plan::DropReplicationSlot(DropReplicationSlotPlan {
+        name: name.into_string(),
+    }))
+}
+
+pub fn describe_start_replication(
+    _: &StatementContext,
+    _: StartReplicationStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_start_replication(
+    scx: &StatementContext,
+    StartReplicationStatement { slot, lsn, options }: StartReplicationStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LOGICAL_REPLICATION)?;
+    let mut publications = None;
+    for option in &options {
+        match option.name.as_str() {
+            "proto_version" => match option_value(option)?.parse::<u32>() {
+                Ok(1..=4) => {}
+                _ => sql_bail!(
+                    "client sent proto_version={} but server only supports protocol 1 to 4",
+                    option_value(option)?
+                ),
+            },
+            "publication_names" => {
+                publications = Some(parse_publication_names(option_value(option)?)?);
+            }
+            "binary" => {
+                if parse_bool_option(option)? {
+                    bail_unsupported!("binary logical replication");
+                }
+            }
+            // Materialize never emits logical decoding messages, streams
+            // in-progress transactions, or tracks replication origins, so
+            // these options have no effect.
+            "messages" | "streaming" | "origin" => {}
+            name => sql_bail!("unrecognized pgoutput option: {name}"),
+        }
+    }
+    let Some(publications) = publications else {
+        sql_bail!("publication_names parameter missing");
+    };
+    Ok(Plan::StartReplication(StartReplicationPlan {
+        slot: slot.into_string(),
+        start: Timestamp::from(lsn),
+        publications,
+    }))
+}
+
+/// Returns the value of a replication option as a string.
+///
+/// Options without a value are treated like booleans set to true.
+fn option_value(option: &ReplicationOption) -> Result<&str, PlanError> {
+    match &option.value {
+        None => Ok("true"),
+        Some(Value::String(s) | Value::Number(s)) => Ok(s),
+        Some(Value::Boolean(true)) => Ok("true"),
+        Some(Value::Boolean(false)) => Ok("false"),
+        Some(value) => sql_bail!("invalid value for option \"{}\": {value}", option.name),
+    }
+}
+
+fn parse_bool_option(option: &ReplicationOption) -> Result<bool, PlanError> {
+    let value = option_value(option)?;
+    match strconv::parse_bool(value) {
+        Ok(b) => Ok(b),
+        Err(_) => sql_bail!("{} requires a Boolean value", option.name),
+    }
+}
+
+/// Parses a comma-separated list of publication names, like
+/// `pub1, "Pub2"`. As with SQL identifiers, unquoted names are folded to
+/// lowercase.
+fn parse_publication_names(s: &str) -> Result<Vec<String>, PlanError> {
+    let mut names = vec![];
+    let mut chars = s.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        let mut name = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next() {
+                    Some('"') if chars.next_if_eq(&'"').is_some() => name.push('"'),
+                    Some('"') => break,
+                    Some(c) => name.push(c),
+                    None => sql_bail!("invalid publication_names syntax"),
+                }
+            }
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+        } else {
+            while let Some(c) = chars.next_if(|c| *c != ',') {
+                name.push(c);
+            }
+            name = name.trim_end().to_lowercase();
+        }
+        if name.is_empty() {
+            sql_bail!("invalid publication_names syntax");
+        }
+        names.push(name);
+        match chars.next() {
+            None => return Ok(names),
+            Some(',') => {}
+            Some(_) => sql_bail!("invalid publication_names syntax"),
+        }
+    }
+}
@@ -20,15 +20,16 @@ use uncased::UncasedStr;
 use crate::ast::display::AstDisplay;
 use crate::ast::{
     CloseStatement, DeallocateStatement, DeclareStatement, DiscardStatement, DiscardTarget,
-    ExecuteStatement, FetchOption, FetchOptionName, FetchStatement, PrepareStatement,
-    ResetVariableStatement, SetVariableStatement, SetVariableTo, ShowVariableStatement,
+    ExecuteStatement, FetchOption, FetchOptionName, FetchStatement, ListenStatement,
+    PrepareStatement, ResetVariableStatement, SetVariableStatement, SetVariableTo,
+    ShowVariableStatement, UnlistenStatement,
 };
 use crate::names::{self, Aug};
 use crate::plan::statement::{StatementContext, StatementDesc};
 use crate::plan::{
     ClosePlan, DeallocatePlan, DeclarePlan, ExecutePlan, ExecuteTimeout, FetchPlan,
-    InspectShardPlan, Params, Plan, PlanError, PreparePlan, ResetVariablePlan, SetVariablePlan,
-    ShowVariablePlan, VariableValue, describe, query,
+    InspectShardPlan, ListenPlan, Params, Plan, PlanError, PreparePlan, ResetVariablePlan,
+    SetVariablePlan, ShowVariablePlan, UnlistenPlan, VariableValue, describe, query,
 };
 use crate::session::vars;
 use crate::session::vars::{IsolationLevel, SCHEMA_ALIAS, TRANSACTION_ISOLATION_VAR_NAME};
@@ -350,3 +351,56 @@ pub fn plan_deallocate(
         name: name.map(|name| name.to_string()),
     }))
 }
+
+pub fn describe_listen(
+    _: &StatementContext,
+    _: ListenStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_listen(
+    scx: &StatementContext,
+    ListenStatement { channel }: ListenStatement,
+) -> Result<Plan, PlanError> {
+    scx.require_feature_flag(&vars::ENABLE_LISTEN_NOTIFY)?;
+    let channel = channel.into_string();
+    // As in PostgreSQL, listening on a channel that nothing notifies is not an
+    // error; the session simply never receives any notifications on it.
+    let role_id = scx.catalog.active_role_id();
+    let mut relations = Vec::new();
+    for item in scx.catalog.get_items() {
+        if item.notify_channel() != Some(channel.as_str()) || item.replacement_target().is_some() {
+            continue;
+        }
+        // Notifications report every change to the relation, so they cannot
+        // apply row-level security policies.
+        if item.row_level_security()
+            && !query::bypasses_row_level_security(scx.catalog, role_id, &item.owner_id())
+        {
+            return Err(PlanError::RowLevelSecurityBypassRequired {
+                name: scx.catalog.resolve_full_name(item.name()).to_string(),
+                role: scx.catalog.get_role(role_id).name().to_string(),
+                operation: "listen to",
+            });
+        }
+        relations.push(item.id());
+    }
+    Ok(Plan::Listen(ListenPlan { channel, relations }))
+}
+
+pub fn describe_unlisten(
+    _: &StatementContext,
+    _: UnlistenStatement,
+) -> Result<StatementDesc, PlanError> {
+    Ok(StatementDesc::new(None))
+}
+
+pub fn plan_unlisten(
+    _: &StatementContext,
+    UnlistenStatement { channel }: UnlistenStatement,
+) -> Result<Plan, PlanError> {
+    Ok(Plan::Unlisten(UnlistenPlan {
+        channel: channel.map(|channel| channel.into_string()),
+    }))
+}
@@ -721,7 +721,9 @@ pub fn show_columns<'a>(
         | ty @ CatalogItemType::Func
         | ty @ CatalogItemType::Secret
         | ty @ CatalogItemType::Type
-        | ty @ CatalogItemType::Sink => {
+        | ty @ CatalogItemType::Sink
+        | ty @ CatalogItemType::Sequence
+        | ty @ CatalogItemType::Policy => {
             sql_bail!("{full_name} is a {ty} and so does not have columns");
         }
     }
@@ -60,6 +60,10 @@ where
 //   * Rewrites the suite of standard deviation and variance functions in a
 //     manner similar to `avg`.
 //
+//   * Rewrites the `regr_*`, `covar_*`, and `corr` family of statistical
+//     aggregates in terms of sums over their inputs, their squares, and their
+//     products, in a manner similar to `variance`.
+//
 // TODO(sploiselle): rewrite these in terms of func::sql_op!
 struct FuncRewriter<'a> {
     scx: &'a StatementContext<'a>,
@@ -100,6 +104,14 @@ impl<'a> FuncRewriter<'a> {
         })
     }
 
+    fn float64_data_type(&self) -> ResolvedDataType {
+        self.resolve_known_valid_data_type(&PartialItemName {
+            database: None,
+            schema: Some(PG_CATALOG_SCHEMA.into()),
+            item: "float8".into(),
+        })
+    }
+
     // Divides `lhs` by `rhs` but replaces division-by-zero errors with NULL;
     // note that this is semantically equivalent to `NULLIF(rhs, 0)`.
     fn plan_divide(lhs: Expr<Aug>, rhs: Expr<Aug>) -> Expr<Aug> {
@@ -130,6 +142,7 @@ impl<'a> FuncRewriter<'a> {
             args: FunctionArgs::Args {
                 args: vec![expr],
                 order_by,
+                within_group: false,
             },
             filter,
             over,
@@ -312,6 +325,111 @@ impl<'a> FuncRewriter<'a> {
             )
     }
 
+    fn plan_regression(
+        &mut self,
+        name: &str,
+        y: Expr<Aug>,
+        x: Expr<Aug>,
+        filter: Option<Box<Expr<Aug>>>,
+        over: Option<WindowSpec<Aug>>,
+    ) -> Expr<Aug> {
+        // N.B. like `plan_variance`, this uses the "textbook" algorithms, which
+        // accumulate more error than the Youngs-Cramer algorithm PostgreSQL
+        // uses, but which can be maintained incrementally as sums.
+        //
+        // Only rows where both `y` and `x` are non-null participate, so each
+        // input is masked out when the other is null:
+        //
+        //     y' = CASE WHEN x IS NULL THEN NULL ELSE y::float8 END
+        //     x' = CASE WHEN y IS NULL THEN NULL ELSE x::float8 END
+        //
+        // and then, writing N for count(y'):
+        //
+        //     Sxx = sum(x'²) - sum(x')² / N
+        //     Syy = sum(y'²) - sum(y')² / N
+        //     Sxy = sum(x'y') - sum(x') * sum(y') / N
+        //
+        let float64 = self.float64_data_type();
+        let mask = |expr: Expr<Aug>, other: &Expr<Aug>| Expr::Case {
+            operand: None,
+            conditions: vec![Expr::IsExpr {
+                expr: Box::new(other.clone()),
+                construct: IsExprConstruct::Null,
+                negated: false,
+            }],
+            results: vec![Expr::null()],
+            else_result: Some(Box::new(expr.cast(float64.clone()))),
+        };
+        let (y, x) = (mask(y.clone(), &x), mask(x, &y));
+        let mut agg = |name: &str, expr: Expr<Aug>| {
+            let name = self
+                .scx
+                .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, name]);
+            self.plan_agg(name, expr, vec![], filter.clone(), false, over.clone())
+        };
+        let count = agg("count", y.clone());
+        let sum_x = agg("sum", x.clone());
+        let sum_y = agg("sum", y.clone());
+        let sum_xx = agg("sum", x.clone().multiply(x.clone()));
+        let sum_yy = agg("sum", y.clone().multiply(y.clone()));
+        let sum_xy = agg("sum", x.multiply(y));
+        let sxx = sum_xx.minus(Self::plan_divide(
+            sum_x.clone().multiply(sum_x.clone()),
+            count.clone(),
+        ));
+        let syy = sum_yy.minus(Self::plan_divide(
+            sum_y.clone().multiply(sum_y.clone()),
+            count.clone(),
+        ));
+        let sxy = sum_xy.minus(Self::plan_divide(
+            sum_x.clone().multiply(sum_y.clone()),
+            count.clone(),
+        ));
+        // Due to numerical inaccuracy, `Sxx` and `Syy` may be negative numbers
+        // very close to zero, so clamp them before taking square roots. Note
+        // that `greatest(NULL, 0)` is 0, which `plan_divide` maps to NULL.
+        let sqrt = |expr: Expr<Aug>| {
+            Expr::HomogenizingFunction {
+                function: HomogenizingFunction::Greatest,
+                exprs: vec![expr, Expr::number("0")],
+            }
+            .call_unary(
+                self.scx
+                    .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "sqrt"]),
+            )
+        };
+        match name {
+            "regr_count" => count,
+            "regr_avgx" => Self::plan_divide(sum_x, count),
+            "regr_avgy" => Self::plan_divide(sum_y, count),
+            "regr_sxx" => sxx,
+            "regr_syy" => syy,
+            "regr_sxy" => sxy,
+            "regr_slope" => Self::plan_divide(sxy, sxx),
+            "regr_intercept" => Self::plan_divide(
+                sum_y.minus(sum_x.multiply(Self::plan_divide(sxy, sxx))),
+                count,
+            ),
+            // Like PostgreSQL, this is NULL if `Sxx` is zero and 1 if only
+            // `Syy` is zero.
+            "regr_r2" => Expr::Case {
+                operand: None,
+                conditions: vec![
+                    sxx.clone().equals(Expr::number("0")),
+                    syy.clone().equals(Expr::number("0")),
+                ],
+                results: vec![Expr::null(), Expr::number("1")],
+                else_result: Some(Box::new(
+                    sxy.clone().multiply(sxy).divide(sxx.multiply(syy)),
+                )),
+            },
+            "covar_pop" => Self::plan_divide(sxy, count),
+            "covar_samp" => Self::plan_divide(sxy, count.minus(Expr::number("1"))),
+            "corr" => Self::plan_divide(sxy, sqrt(sxx).multiply(sqrt(syy))),
+            _ => unreachable!("unknown regression function {name}"),
+        }
+    }
+
     fn plan_bool_and(
         &mut self,
         expr: Expr<Aug>,
@@ -376,7 +494,12 @@ impl<'a> FuncRewriter<'a> {
     fn rewrite_function(&mut self, func: &Function<Aug>) -> Option<(Ident, Expr<Aug>)> {
         if let Function {
             name,
-            args: FunctionArgs::Args { args, order_by: _ },
+            args:
+                FunctionArgs::Args {
+                    args,
+                    order_by,
+                    within_group,
+                },
             filter,
             distinct,
             over,
@@ -411,6 +534,12 @@ impl<'a> FuncRewriter<'a> {
             let filter = filter.clone();
             let distinct = *distinct;
             let over = over.clone();
+            // The arguments in a `WITHIN GROUP` clause are passed after the
+            // direct arguments, as in `plan_aggregate_common`.
+            let mut args = args.clone();
+            if *within_group {
+                args.extend(order_by.iter().map(|o| o.expr.clone()));
+            }
             let expr = if args.len() == 1 {
                 let arg = args[0].clone();
                 match name.as_str() {
@@ -435,11 +564,40 @@ impl<'a> FuncRewriter<'a> {
                             .dangerous_resolve_name(vec![PG_CATALOG_SCHEMA, "power"]),
                         vec![lhs, rhs],
                     ),
+                    "regr_count" | "regr_avgx" | "regr_avgy" | "regr_sxx" | "regr_syy"
+                    | "regr_sxy" | "regr_slope" | "regr_intercept" | "regr_r2" | "covar_pop"
+                    | "covar_samp" | "corr" => {
+                        // The aggregates below are planned as separate sums over
+                        // each input, so `DISTINCT` would deduplicate each input
+                        // on its own rather than the `(y, x)` pairs.
+                        if distinct {
+                            if self.status.is_ok() {
+                                self.status = Err(PlanError::Unstructured(format!(
+                                    "DISTINCT is not supported for {}.{}",
+                                    PG_CATALOG_SCHEMA, name
+                                )));
+                            }
+                            return None;
+                        }
+                        self.plan_regression(&name, lhs, rhs, filter, over)
+                    }
                     _ => return None,
                 }
             } else {
                 return None;
             };
+            // None of the rewritten functions are ordered-set aggregates, and
+            // their catalog entries cannot be planned, so reject `WITHIN GROUP`
+            // here rather than during function selection.
+            if *within_group {
+                if self.status.is_ok() {
+                    self.status = Err(PlanError::Unstructured(format!(
+                        "{}.{} is not an ordered-set aggregate, so it cannot have WITHIN GROUP",
+                        PG_CATALOG_SCHEMA, name
+                    )));
+                }
+                return None;
+            }
             Some((Ident::new_unchecked(name), expr))
         } else {
             None
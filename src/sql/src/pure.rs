@@ -19,6 +19,7 @@ use std::sync::Arc;
 
 use anyhow::anyhow;
 use itertools::Itertools;
+use mysql_async::prelude::Queryable;
 use mz_adapter_types::dyncfgs::ENABLE_S3_TABLES_REGION_CHECK;
 use mz_ccsr::{Client, GetBySubjectError};
 use mz_cloud_provider::CloudProvider;
@@ -81,9 +82,15 @@ use crate::names::{
     ResolvedItemName,
 };
 use crate::plan::error::PlanError;
-use crate::plan::statement::ddl::load_generator_ast_to_generator;
+use crate::plan::statement::ddl::{
+    MySqlSinkConfigOptionExtracted, PostgresSinkConfigOptionExtracted,
+    SqlServerSinkConfigOptionExtracted, load_generator_ast_to_generator, upstream_sink_table_name,
+};
 use crate::plan::{SourceReferences, StatementContext};
-use crate::pure::error::{IcebergSinkPurificationError, SqlServerSourcePurificationError};
+use crate::pure::error::{
+    IcebergSinkPurificationError, MySqlSinkPurificationError, PostgresSinkPurificationError,
+    S3SinkPurificationError, SqlServerSinkPurificationError, SqlServerSourcePurificationError,
+};
 use crate::session::vars::ENABLE_SQL_SERVER_SOURCE;
 use crate::{kafka_util, normalize};
 
@@ -611,6 +618,199 @@ async fn purify_create_sink(
                 .await
                 .map_err(|e| IcebergSinkPurificationError::AwsSdkContextError(Arc::new(e)))?;
         }
+        CreateSinkConnection::Postgres {
+            connection,
+            options,
+            key: _,
+        } => {
+            let scx = StatementContext::new(None, &catalog);
+            let connection = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                // Get Postgres connection
+                match item.connection()? {
+                    Connection::Postgres(connection) => {
+                        connection.clone().into_inline_connection(scx.catalog)
+                    }
+                    _ => sql_bail!(
+                        "{} is not a postgres connection",
+                        scx.catalog.resolve_full_name(item.name())
+                    ),
+                }
+            };
+
+            let extracted_options: PostgresSinkConfigOptionExtracted =
+                options.clone().try_into()?;
+
+            let config = connection
+                .config(
+                    &storage_configuration.connection_context.secrets_reader,
+                    storage_configuration,
+                    InTask::No,
+                )
+                .await?;
+            let client = config
+                .connect(
+                    "postgres sink purification",
+                    &storage_configuration.connection_context.ssh_tunnel_manager,
+                )
+                .await?;
+
+            // We only check that the table exists. Its shape is validated
+            // against the sinked relation when the sink starts up, because the
+            // upstream table can be altered at any point after this check.
+            if let Some(table) = extracted_options.table {
+                let (schema, table) = upstream_sink_table_name(&table, Some("public"))?;
+                let exists = client
+                    .query_opt(
+                        "SELECT 1 FROM pg_catalog.pg_tables \
+                         WHERE schemaname = $1 AND tablename = $2",
+                        &[&schema, &table],
+                    )
+                    .await
+                    .map_err(mz_postgres_util::PostgresError::from)?
+                    .is_some();
+                if !exists {
+                    Err(PostgresSinkPurificationError::UpstreamTableMissing { schema, table })?;
+                }
+            }
+        }
+        CreateSinkConnection::MySql {
+            connection,
+            options,
+            key: _,
+        } => {
+            let scx = StatementContext::new(None, &catalog);
+            let connection = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                // Get MySQL connection
+                match item.connection()? {
+                    Connection::MySql(connection) => {
+                        connection.clone().into_inline_connection(scx.catalog)
+                    }
+                    _ => sql_bail!(
+                        "{} is not a mysql connection",
+                        scx.catalog.resolve_full_name(item.name())
+                    ),
+                }
+            };
+
+            let extracted_options: MySqlSinkConfigOptionExtracted = options.clone().try_into()?;
+
+            let config = connection
+                .config(
+                    &storage_configuration.connection_context.secrets_reader,
+                    storage_configuration,
+                    InTask::No,
+                )
+                .await?;
+            let mut conn = config
+                .connect(
+                    "mysql sink purification",
+                    &storage_configuration.connection_context.ssh_tunnel_manager,
+                )
+                .await?;
+
+            // As with Postgres sinks, the table's shape is validated when the
+            // sink starts up.
+            if let Some(table) = extracted_options.table {
+                let (database, table) = upstream_sink_table_name(&table, None)?;
+                let exists = conn
+                    .exec_first::<u8, _, _>(
+                        "SELECT 1 FROM information_schema.tables \
+                         WHERE table_schema = ? AND table_name = ?",
+                        (&database, &table),
+                    )
+                    .await
+                    .map_err(mz_mysql_util::MySqlError::from)?
+                    .is_some();
+                if !exists {
+                    Err(MySqlSinkPurificationError::UpstreamTableMissing { database, table })?;
+                }
+            }
+        }
+        CreateSinkConnection::SqlServer {
+            connection,
+            options,
+            key: _,
+        } => {
+            let scx = StatementContext::new(None, &catalog);
+            let connection = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                // Get SQL Server connection
+                match item.connection()? {
+                    Connection::SqlServer(connection) => {
+                        connection.clone().into_inline_connection(scx.catalog)
+                    }
+                    _ => sql_bail!(
+                        "{} is not a sql server connection",
+                        scx.catalog.resolve_full_name(item.name())
+                    ),
+                }
+            };
+
+            let extracted_options: SqlServerSinkConfigOptionExtracted =
+                options.clone().try_into()?;
+
+            let config = connection
+                .resolve_config(
+                    &storage_configuration.connection_context.secrets_reader,
+                    storage_configuration,
+                    InTask::No,
+                )
+                .await?;
+            let mut client = mz_sql_server_util::Client::connect(config).await?;
+
+            // As with Postgres sinks, the table's shape is validated when the
+            // sink starts up.
+            if let Some(table) = extracted_options.table {
+                let (schema, table) = upstream_sink_table_name(&table, Some("dbo"))?;
+                let exists = !client
+                    .query(
+                        "SELECT 1 FROM INFORMATION_SCHEMA.TABLES \
+                         WHERE TABLE_SCHEMA = @P1 AND TABLE_NAME = @P2",
+                        &[&schema, &table],
+                    )
+                    .await?
+                    .is_empty();
+                if !exists {
+                    Err(SqlServerSinkPurificationError::UpstreamTableMissing { schema, table })?;
+                }
+            }
+        }
+        CreateSinkConnection::S3 {
+            connection,
+            options: _,
+        } => {
+            let scx = StatementContext::new(None, &catalog);
+            let connection_id = connection.item_id();
+            let aws_connection = {
+                let item = scx.get_item_by_resolved_name(connection)?;
+                // Get AWS connection
+                match item.connection()? {
+                    Connection::Aws(aws_connection) => aws_connection.clone(),
+                    _ => sql_bail!(
+                        "{} is not an aws connection",
+                        scx.catalog.resolve_full_name(item.name())
+                    ),
+                }
+            };
+
+            // Unlike for COPY ... TO, the S3 path is not required to be
+            // empty, since it contains the files written by previous
+            // incarnations of the sink. We only check the credentials.
+            let _sdk_config = aws_connection
+                .load_sdk_config(
+                    &storage_configuration.connection_context,
+                    connection_id.clone(),
+                    InTask::No,
+                )
+                .await
+                .map_err(|e| S3SinkPurificationError::AwsSdkContextError(Arc::new(e)))?;
+        }
+        CreateSinkConnection::Http { .. } => {
+            // There is no way to check that the endpoint accepts our requests
+            // without sending it one, so we leave that to the sink.
+        }
     }
 
     let mut csr_connection_ids = BTreeSet::new();
@@ -2655,6 +2855,7 @@ pub fn purify_create_materialized_view_options(
                 args: FunctionArgs::Args {
                     args: Vec::new(),
                     order_by: Vec::new(),
+                    within_group: false,
                 },
                 filter: None,
                 over: None,
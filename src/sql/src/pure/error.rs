@@ -212,6 +212,91 @@ impl KafkaSinkPurificationError {
     }
 }
 
+/// Logical errors detectable during purification for a POSTGRES SINK.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum PostgresSinkPurificationError {
+    #[error("table {schema}.{table} does not exist in the upstream database")]
+    UpstreamTableMissing { schema: String, table: String },
+}
+
+impl PostgresSinkPurificationError {
+    pub fn detail(&self) -> Option<String> {
+        None
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::UpstreamTableMissing { .. } => Some(
+                "Postgres sinks write into an existing table; create it in the upstream database first."
+                    .into(),
+            ),
+        }
+    }
+}
+
+/// Logical errors detectable during purification for a MYSQL SINK.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum MySqlSinkPurificationError {
+    #[error("table {database}.{table} does not exist in the upstream database")]
+    UpstreamTableMissing { database: String, table: String },
+}
+
+impl MySqlSinkPurificationError {
+    pub fn detail(&self) -> Option<String> {
+        None
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::UpstreamTableMissing { .. } => Some(
+                "MySQL sinks write into an existing table; create it in the upstream database first."
+                    .into(),
+            ),
+        }
+    }
+}
+
+/// Logical errors detectable during purification for a SQL SERVER SINK.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum SqlServerSinkPurificationError {
+    #[error("table {schema}.{table} does not exist in the upstream database")]
+    UpstreamTableMissing { schema: String, table: String },
+}
+
+impl SqlServerSinkPurificationError {
+    pub fn detail(&self) -> Option<String> {
+        None
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        match self {
+            Self::UpstreamTableMissing { .. } => Some(
+                "SQL Server sinks write into an existing table; create it in the upstream database first."
+                    .into(),
+            ),
+        }
+    }
+}
+
+/// Logical errors detectable during purification for an S3 SINK.
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum S3SinkPurificationError {
+    #[error("error loading aws sdk context")]
+    AwsSdkContextError(Arc<anyhow::Error>),
+}
+
+impl S3SinkPurificationError {
+    pub fn detail(&self) -> Option<String> {
+        match self {
+            Self::AwsSdkContextError(e) => Some(e.to_string_with_causes()),
+        }
+    }
+
+    pub fn hint(&self) -> Option<String> {
+        None
+    }
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum IcebergSinkPurificationError {
     #[error("catalog connection errored")]
@@ -663,6 +663,46 @@ fn generate_rbac_requirements(
                 ..Default::default()
             }
         }
+        Plan::CreateFunction(plan::CreateFunctionPlan {
+            name,
+            function: _,
+            replace,
+            drop_ids: _,
+        }) => RbacRequirements {
+            ownership: replace
+                .map(|id| vec![ObjectId::Item(id)])
+                .unwrap_or_default(),
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
+        Plan::CreateSequence(plan::CreateSequencePlan {
+            name,
+            sequence: _,
+            if_not_exists: _,
+        }) => RbacRequirements {
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
+        Plan::CreatePolicy(plan::CreatePolicyPlan { name, policy }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(policy.details.on)],
+            privileges: vec![(
+                SystemObjectId::Object(name.qualifiers.clone().into()),
+                AclMode::CREATE,
+                role_id,
+            )],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::CreateType(plan::CreateTypePlan { name, typ: _ }) => RbacRequirements {
             privileges: vec![(
                 SystemObjectId::Object(name.qualifiers.clone().into()),
@@ -849,6 +889,26 @@ fn generate_rbac_requirements(
                 ..Default::default()
             }
         }
+        Plan::Listen(plan::ListenPlan {
+            channel: _,
+            relations,
+        }) => {
+            let mut privileges =
+                generate_read_privileges(catalog, relations.iter().copied(), role_id);
+            // Listen subscribes only run on the active cluster if something is bound to the
+            // channel.
+            if let Some(cluster_id) = target_cluster_id.filter(|_| !relations.is_empty()) {
+                privileges.push((
+                    SystemObjectId::Object(cluster_id.into()),
+                    AclMode::USAGE,
+                    role_id,
+                ));
+            }
+            RbacRequirements {
+                privileges,
+                ..Default::default()
+            }
+        }
         Plan::CopyFrom(plan::CopyFromPlan {
             target_name: _,
             target_id,
@@ -972,6 +1032,7 @@ fn generate_rbac_requirements(
             id,
             values,
             returning,
+            identity_columns: _,
         }) => {
             let schema_id: ObjectId = catalog.get_item(id).name().qualifiers.clone().into();
             let mut privileges = vec![
@@ -1063,6 +1124,15 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::AlterRowLevelSecurity(plan::AlterRowLevelSecurityPlan {
+            id,
+            object_type: _,
+            enabled: _,
+        }) => RbacRequirements {
+            ownership: vec![ObjectId::Item(*id)],
+            item_usage: &CREATE_ITEM_USAGE,
+            ..Default::default()
+        },
         Plan::AlterConnection(plan::AlterConnectionPlan { id, action: _ }) => RbacRequirements {
             ownership: vec![ObjectId::Item(*id)],
             ..Default::default()
@@ -1277,6 +1347,14 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::AlterTableDropColumn(plan::AlterTableDropColumnPlan { relation_id, .. })
+        | Plan::AlterTableRenameColumn(plan::AlterTableRenameColumnPlan { relation_id, .. }) => {
+            RbacRequirements {
+                ownership: vec![ObjectId::Item(*relation_id)],
+                item_usage: &CREATE_ITEM_USAGE,
+                ..Default::default()
+            }
+        }
         Plan::AlterMaterializedViewApplyReplacement(
             plan::AlterMaterializedViewApplyReplacementPlan { id, replacement_id },
         ) => RbacRequirements {
@@ -1289,6 +1367,22 @@ fn generate_rbac_requirements(
             item_usage: &CREATE_ITEM_USAGE,
             ..Default::default()
         },
+        Plan::Truncate(plan::TruncatePlan { ids }) => {
+            // Truncating a table is equivalent to deleting all of its rows.
+            let mut privileges = Vec::new();
+            let mut seen = BTreeSet::new();
+            for id in ids {
+                let schema_id: ObjectId = catalog.get_item(id).name().qualifiers.clone().into();
+                if seen.insert(schema_id.clone()) {
+                    privileges.push((SystemObjectId::Object(schema_id), AclMode::USAGE, role_id));
+                }
+                privileges.push((SystemObjectId::Object(id.into()), AclMode::DELETE, role_id));
+            }
+            RbacRequirements {
+                privileges,
+                ..Default::default()
+            }
+        }
         Plan::ReadThenWrite(plan::ReadThenWritePlan {
             id,
             selection,
@@ -1296,6 +1390,7 @@ fn generate_rbac_requirements(
             assignments,
             kind,
             returning,
+            identity_columns: _,
         }) => {
             let acl_mode = match kind {
                 MutationKind::Insert => AclMode::INSERT,
@@ -1482,20 +1577,29 @@ fn generate_rbac_requirements(
                 .collect(),
             ..Default::default()
         },
-        Plan::SideEffectingFunc(func) => {
-            let role_membership = match func {
-                SideEffectingFunc::PgCancelBackend { connection_id } => active_conns
+        Plan::SideEffectingFunc(func) => match func {
+            SideEffectingFunc::PgCancelBackend { connection_id } => RbacRequirements {
+                role_membership: active_conns
                     .expect("active_conns is required for Plan::SideEffectingFunc")(
                     *connection_id
                 )
                 .map(|x| [x].into())
                 .unwrap_or_default(),
-            };
-            RbacRequirements {
-                role_membership,
                 ..Default::default()
-            }
-        }
+            },
+            SideEffectingFunc::NextVal { id } => RbacRequirements {
+                privileges: generate_sequence_privileges(catalog, *id, AclMode::USAGE, role_id),
+                ..Default::default()
+            },
+            SideEffectingFunc::CurrVal { id } => RbacRequirements {
+                privileges: generate_sequence_privileges(catalog, *id, AclMode::SELECT, role_id),
+                ..Default::default()
+            },
+            SideEffectingFunc::SetVal { id, .. } => RbacRequirements {
+                privileges: generate_sequence_privileges(catalog, *id, AclMode::UPDATE, role_id),
+                ..Default::default()
+            },
+        },
         Plan::ValidateConnection(plan::ValidateConnectionPlan { id, connection: _ }) => {
             let schema_id: ObjectId = catalog.get_item(id).name().qualifiers.clone().into();
             RbacRequirements {
@@ -1506,6 +1610,34 @@ fn generate_rbac_requirements(
                 ..Default::default()
             }
         }
+        // Publications and replication slots expose the changes of every
+        // relation they cover without regard to privileges, so only superusers
+        // may manage or consume them.
+        Plan::CreatePublication(plan::CreatePublicationPlan {
+            name: _,
+            relations: _,
+        }) => RbacRequirements {
+            superuser_action: Some("create publication".to_string()),
+            ..Default::default()
+        },
+        Plan::DropPublication(plan::DropPublicationPlan {
+            name: _,
+            if_exists: _,
+        }) => RbacRequirements {
+            superuser_action: Some("drop publication".to_string()),
+            ..Default::default()
+        },
+        Plan::IdentifySystem
+        | Plan::CreateReplicationSlot(plan::CreateReplicationSlotPlan { name: _ })
+        | Plan::DropReplicationSlot(plan::DropReplicationSlotPlan { name: _ })
+        | Plan::StartReplication(plan::StartReplicationPlan {
+            slot: _,
+            start: _,
+            publications: _,
+        }) => RbacRequirements {
+            superuser_action: Some("start replication".to_string()),
+            ..Default::default()
+        },
         Plan::DiscardTemp
         | Plan::DiscardAll
         | Plan::EmptyQuery
@@ -1553,7 +1685,8 @@ fn generate_rbac_requirements(
         })
         | Plan::Execute(plan::ExecutePlan { name: _, params: _ })
         | Plan::Deallocate(plan::DeallocatePlan { name: _ })
-        | Plan::Raise(plan::RaisePlan { severity: _ }) => Default::default(),
+        | Plan::Raise(plan::RaisePlan { severity: _ })
+        | Plan::Unlisten(plan::UnlistenPlan { channel: _ }) => Default::default(),
     }
 }
 
@@ -1647,6 +1780,20 @@ fn generate_required_source_privileges(
     privileges
 }
 
+/// Generates the privileges required to call a sequence function on the sequence `id`.
+fn generate_sequence_privileges(
+    catalog: &impl SessionCatalog,
+    id: CatalogItemId,
+    acl_mode: AclMode,
+    role_id: RoleId,
+) -> Vec<(SystemObjectId, AclMode, RoleId)> {
+    let schema_id: ObjectId = catalog.get_item(&id).name().qualifiers.clone().into();
+    vec![
+        (SystemObjectId::Object(schema_id), AclMode::USAGE, role_id),
+        (SystemObjectId::Object(id.into()), acl_mode, role_id),
+    ]
+}
+
 /// Generates all the privileges required to execute a read that includes the objects in `ids`.
 ///
 /// Not only do we need to validate that `role_id` has read privileges on all relations in `ids`,
@@ -1691,7 +1838,16 @@ fn generate_read_privileges_inner(
                 CatalogItemType::Type | CatalogItemType::Secret | CatalogItemType::Connection => {
                     privileges.push((SystemObjectId::Object(id.into()), AclMode::USAGE, role_id));
                 }
-                CatalogItemType::Sink | CatalogItemType::Index | CatalogItemType::Func => {}
+                CatalogItemType::Func => {
+                    // User-defined functions are inlined into their callers and run with the
+                    // privileges of the caller, so the caller must be able to read everything
+                    // that the function's body reads. Built-in functions have no references.
+                    views.push((item.references().items().copied(), role_id));
+                }
+                CatalogItemType::Sequence => {
+                    privileges.push((SystemObjectId::Object(id.into()), AclMode::SELECT, role_id));
+                }
+                CatalogItemType::Sink | CatalogItemType::Index | CatalogItemType::Policy => {}
             }
         }
     }
@@ -1799,6 +1955,7 @@ pub const fn all_object_privileges(object_type: SystemObjectType) -> AclMode {
         .union(AclMode::UPDATE)
         .union(AclMode::DELETE);
     const USAGE_CREATE_ACL_MODE: AclMode = AclMode::USAGE.union(AclMode::CREATE);
+    const SEQUENCE_ACL_MODE: AclMode = AclMode::USAGE.union(AclMode::SELECT).union(AclMode::UPDATE);
     const ALL_SYSTEM_PRIVILEGES: AclMode = AclMode::CREATE_ROLE
         .union(AclMode::CREATE_DB)
         .union(AclMode::CREATE_CLUSTER)
@@ -1823,6 +1980,8 @@ pub const fn all_object_privileges(object_type: SystemObjectType) -> AclMode {
         SystemObjectType::Object(ObjectType::Schema) => USAGE_CREATE_ACL_MODE,
         SystemObjectType::Object(ObjectType::Func) => EMPTY_ACL_MODE,
         SystemObjectType::Object(ObjectType::ContinualTask) => AclMode::SELECT,
+        SystemObjectType::Object(ObjectType::Sequence) => SEQUENCE_ACL_MODE,
+        SystemObjectType::Object(ObjectType::Policy) => EMPTY_ACL_MODE,
         SystemObjectType::System => ALL_SYSTEM_PRIVILEGES,
     }
 }
@@ -1852,7 +2011,9 @@ const fn default_builtin_object_acl_mode(object_type: ObjectType) -> AclMode {
         | ObjectType::Connection
         | ObjectType::Database
         | ObjectType::Func
-        | ObjectType::NetworkPolicy => AclMode::empty(),
+        | ObjectType::NetworkPolicy
+        | ObjectType::Sequence
+        | ObjectType::Policy => AclMode::empty(),
     }
 }
 
@@ -2123,7 +2123,7 @@ feature_flags!(
     },
     {
         name: enable_alter_table_add_column,
-        desc: "Enable ALTER TABLE ... ADD COLUMN ...",
+        desc: "Enable ALTER TABLE ... ADD, DROP, and RENAME COLUMN ...",
         default: false,
         enable_for_item_parsing: false,
     },
@@ -2229,6 +2229,60 @@ feature_flags!(
         default: true,
         enable_for_item_parsing: true,
     },
+    {
+        name: enable_postgres_sink,
+        desc: "Whether to enable the Postgres sink.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_mysql_sink,
+        desc: "Whether to enable the MySQL sink.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_sql_server_sink,
+        desc: "Whether to enable the SQL Server sink.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_s3_sink,
+        desc: "Whether to enable the S3 sink.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_http_sink,
+        desc: "Whether to enable the HTTP sink.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_sink_envelope_changelog,
+        desc: "Enable ENVELOPE CHANGELOG for Kafka sinks and MODE CHANGELOG for Iceberg sinks.",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_listen_notify,
+        desc: "LISTEN and NOTIFY CHANNEL",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_logical_replication,
+        desc: "logical replication",
+        default: false,
+        enable_for_item_parsing: true,
+    },
+    {
+        name: enable_row_level_security,
+        desc: "row-level security",
+        default: false,
+        enable_for_item_parsing: true,
+    },
     {
         name: enable_frontend_peek_sequencing, // currently, changes only take effect for new sessions
         desc: "Enables the new peek sequencing code, which does most of its work in the Adapter Frontend instead of the Coordinator main task.",
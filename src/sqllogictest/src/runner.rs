@@ -2631,6 +2631,7 @@ fn generate_view_sql(
         definition: ViewDefinition {
             name: name.clone(),
             columns: columns.clone(),
+            with_options: vec![],
             query,
         },
     })
@@ -2687,6 +2688,7 @@ fn generate_view_sql(
                     relation: TableFactor::Table {
                         name: RawItemName::Name(name.clone()),
                         alias: None,
+                        sample: None,
                     },
                     joins: vec![],
                 }],
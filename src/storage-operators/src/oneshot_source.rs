@@ -99,12 +99,16 @@ use timely::dataflow::{Scope, StreamVec};
 use timely::progress::Antichain;
 use tracing::info;
 
+use crate::oneshot_source::avro::{AvroBlock, AvroFormat, AvroWorkRequest};
 use crate::oneshot_source::aws_source::{AwsS3Source, S3Checksum, S3Object};
 use crate::oneshot_source::csv::{CsvDecoder, CsvRecord, CsvWorkRequest};
 use crate::oneshot_source::http_source::{HttpChecksum, HttpObject, HttpOneshotSource};
+use crate::oneshot_source::json::{JsonDecoder, JsonRecord, JsonWorkRequest};
 use crate::oneshot_source::parquet::{ParquetFormat, ParquetRowGroup, ParquetWorkRequest};
 
+pub mod avro;
 pub mod csv;
+pub mod json;
 pub mod parquet;
 
 pub mod aws_source;
@@ -174,6 +178,14 @@ where
             let format = ParquetFormat::new(shape.source_desc);
             FormatKind::Parquet(format)
         }
+        ContentFormat::Json => {
+            let format = JsonDecoder::new(&shape.source_desc);
+            FormatKind::Json(format)
+        }
+        ContentFormat::Avro => {
+            let format = AvroFormat::new(shape.source_desc);
+            FormatKind::Avro(format)
+        }
     };
 
     // Discover what objects are available to copy.
@@ -873,6 +885,8 @@ pub trait OneshotFormat: Clone {
 pub(crate) enum FormatKind {
     Csv(CsvDecoder),
     Parquet(ParquetFormat),
+    Json(JsonDecoder),
+    Avro(AvroFormat),
 }
 
 impl OneshotFormat for FormatKind {
@@ -909,6 +923,26 @@ impl OneshotFormat for FormatKind {
                     .collect();
                 Ok(work)
             }
+            FormatKind::Json(json) => {
+                let work = json
+                    .split_work(source, object, checksum)
+                    .await
+                    .context("json")?
+                    .into_iter()
+                    .map(RequestKind::Json)
+                    .collect();
+                Ok(work)
+            }
+            FormatKind::Avro(avro) => {
+                let work = avro
+                    .split_work(source, object, checksum)
+                    .await
+                    .context("avro")?
+                    .into_iter()
+                    .map(RequestKind::Avro)
+                    .collect();
+                Ok(work)
+            }
         }
     }
 
@@ -928,8 +962,23 @@ impl OneshotFormat for FormatKind {
                 .map_ok(RecordChunkKind::Parquet)
                 .map(|result| result.context("parquet"))
                 .boxed(),
-            (FormatKind::Parquet(_), RequestKind::Csv(_))
-            | (FormatKind::Csv(_), RequestKind::Parquet(_)) => {
+            (FormatKind::Json(json), RequestKind::Json(request)) => json
+                .fetch_work(source, request)
+                .map_ok(RecordChunkKind::Json)
+                .map(|result| result.context("json"))
+                .boxed(),
+            (FormatKind::Avro(avro), RequestKind::Avro(request)) => avro
+                .fetch_work(source, request)
+                .map_ok(RecordChunkKind::Avro)
+                .map(|result| result.context("avro"))
+                .boxed(),
+            (
+                FormatKind::Csv(_)
+                | FormatKind::Parquet(_)
+                | FormatKind::Json(_)
+                | FormatKind::Avro(_),
+                _,
+            ) => {
                 unreachable!("programming error, {self:?}")
             }
         }
@@ -947,8 +996,19 @@ impl OneshotFormat for FormatKind {
             (FormatKind::Parquet(parquet), RecordChunkKind::Parquet(chunk)) => {
                 parquet.decode_chunk(chunk, rows).context("parquet")
             }
-            (FormatKind::Parquet(_), RecordChunkKind::Csv(_))
-            | (FormatKind::Csv(_), RecordChunkKind::Parquet(_)) => {
+            (FormatKind::Json(json), RecordChunkKind::Json(chunk)) => {
+                json.decode_chunk(chunk, rows).context("json")
+            }
+            (FormatKind::Avro(avro), RecordChunkKind::Avro(chunk)) => {
+                avro.decode_chunk(chunk, rows).context("avro")
+            }
+            (
+                FormatKind::Csv(_)
+                | FormatKind::Parquet(_)
+                | FormatKind::Json(_)
+                | FormatKind::Avro(_),
+                _,
+            ) => {
                 unreachable!("programming error, {self:?}")
             }
         }
@@ -959,12 +1019,16 @@ impl OneshotFormat for FormatKind {
 pub(crate) enum RequestKind<O, C> {
     Csv(CsvWorkRequest<O, C>),
     Parquet(ParquetWorkRequest<O, C>),
+    Json(JsonWorkRequest<O, C>),
+    Avro(AvroWorkRequest<O, C>),
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub(crate) enum RecordChunkKind {
     Csv(CsvRecord),
     Parquet(ParquetRowGroup),
+    Json(JsonRecord),
+    Avro(AvroBlock),
 }
 
 pub(crate) enum ObjectFilter {
@@ -1025,6 +1089,10 @@ pub enum StorageErrorXKind {
     CsvDecoding(Arc<str>),
     #[error("parquet error: {0}")]
     ParquetError(Arc<str>),
+    #[error("json decoding error: {0}")]
+    JsonDecoding(Arc<str>),
+    #[error("avro decoding error: {0}")]
+    AvroDecoding(Arc<str>),
     #[error("reqwest error: {0}")]
     Reqwest(Arc<str>),
     #[error("aws s3 request error: {0}")]
@@ -1079,6 +1147,12 @@ impl From<::parquet::errors::ParquetError> for StorageErrorXKind {
     }
 }
 
+impl From<mz_avro::error::Error> for StorageErrorXKind {
+    fn from(err: mz_avro::error::Error) -> Self {
+        StorageErrorXKind::AvroDecoding(err.to_string().into())
+    }
+}
+
 impl StorageErrorXKind {
     pub fn with_context<C: Display>(self, context: C) -> StorageErrorX {
         StorageErrorX {
@@ -0,0 +1,207 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Avro Object Container File (OCF) [`OneshotFormat`].
+
+use std::io::{self, BufReader, Read};
+use std::str::FromStr;
+
+use futures::stream::{BoxStream, StreamExt};
+use mz_avro::{BlockIter, Schema, Skip};
+use mz_interchange::avro::{decode_datum, schema_to_relationdesc};
+use mz_repr::{DatumVec, RelationDesc, Row};
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::io::SyncIoBridge;
+
+use crate::oneshot_source::util::{decompressed_reader, encodings_from_name};
+use crate::oneshot_source::{
+    Encoding, OneshotFormat, OneshotSource, StorageErrorX, StorageErrorXContext, StorageErrorXKind,
+};
+
+/// Decodes Avro Object Container Files.
+///
+/// The fields of the top-level record of the file's schema are matched to columns by name, and
+/// must have the same types as the columns they are copied into.
+#[derive(Debug, Clone)]
+pub struct AvroFormat {
+    desc: RelationDesc,
+}
+
+impl AvroFormat {
+    pub fn new(desc: RelationDesc) -> Self {
+        AvroFormat { desc }
+    }
+}
+
+/// Instructions on how to parse a single Avro OCF.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AvroWorkRequest<O, C> {
+    object: O,
+    checksum: C,
+    encodings: SmallVec<[Encoding; 1]>,
+}
+
+/// A single, decompressed, block of an Avro OCF.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AvroBlock {
+    /// The writer schema of the file this block belongs to, as JSON.
+    schema: String,
+    /// The encoded datums of the block.
+    bytes: Vec<u8>,
+    /// The number of datums in the block.
+    len: usize,
+}
+
+impl OneshotFormat for AvroFormat {
+    type WorkRequest<S>
+        = AvroWorkRequest<S::Object, S::Checksum>
+    where
+        S: OneshotSource;
+    type RecordChunk = AvroBlock;
+
+    async fn split_work<S: OneshotSource + Send>(
+        &self,
+        _source: S,
+        object: S::Object,
+        checksum: S::Checksum,
+    ) -> Result<Vec<Self::WorkRequest<S>>, StorageErrorX> {
+        // The boundaries of blocks are only known once we've read the
+        // preceding blocks, so a single worker reads the entire file and
+        // distributes the blocks for parallel decoding.
+        let encodings = encodings_from_name(&object);
+
+        let request = AvroWorkRequest {
+            object,
+            checksum,
+            encodings,
+        };
+        Ok(vec![request])
+    }
+
+    fn fetch_work<'a, S: OneshotSource + Sync + 'static>(
+        &'a self,
+        source: &'a S,
+        request: Self::WorkRequest<S>,
+    ) -> BoxStream<'a, Result<Self::RecordChunk, StorageErrorX>> {
+        let AvroWorkRequest {
+            object,
+            checksum,
+            encodings,
+        } = request;
+
+        // Our Avro reader is synchronous, so we read the file on a blocking
+        // thread and send the blocks back to this task.
+        let (tx, rx) = tokio::sync::mpsc::channel(1);
+        let source = source.clone();
+        let handle = tokio::runtime::Handle::current();
+
+        mz_ore::task::spawn_blocking(
+            || "oneshot-avro-fetch",
+            move || {
+                let reader = decompressed_reader(&source, object, checksum, encodings);
+                let reader = SkipReader(BufReader::new(SyncIoBridge::new_with_handle(
+                    reader, handle,
+                )));
+
+                let blocks = match BlockIter::new(reader) {
+                    Ok(blocks) => blocks,
+                    Err(err) => {
+                        let err = StorageErrorXKind::from(err).with_context("read header");
+                        let _ = tx.blocking_send(Err(err));
+                        return;
+                    }
+                };
+                let schema = serde_json::to_string(blocks.writer_schema())
+                    .expect("schemas can be serialized");
+
+                for result in blocks {
+                    let result = result
+                        .map(|block| AvroBlock {
+                            schema: schema.clone(),
+                            bytes: block.bytes,
+                            len: block.len,
+                        })
+                        .context("read block");
+                    let is_err = result.is_err();
+                    // Stop reading if the receiver has gone away or we hit an error.
+                    if tx.blocking_send(result).is_err() || is_err {
+                        return;
+                    }
+                }
+            },
+        );
+
+        ReceiverStream::new(rx).boxed()
+    }
+
+    fn decode_chunk(
+        &self,
+        chunk: Self::RecordChunk,
+        rows: &mut Vec<Row>,
+    ) -> Result<usize, StorageErrorX> {
+        let AvroBlock { schema, bytes, len } = chunk;
+
+        let schema = Schema::from_str(&schema).context("parse schema")?;
+        let file_desc = schema_to_relationdesc(schema.clone())
+            .map_err(|err| StorageErrorXKind::AvroDecoding(err.to_string().into()))
+            .context("schema")?;
+
+        // Match the columns of the file to the columns of the table.
+        if file_desc.arity() != self.desc.arity() {
+            let msg = format!(
+                "wrong number of columns, desc: {} record: {}",
+                self.desc.arity(),
+                file_desc.arity()
+            );
+            return Err(StorageErrorXKind::invalid_record_batch(msg).into());
+        }
+        let mut projection = Vec::with_capacity(self.desc.arity());
+        for (name, typ) in self.desc.iter() {
+            let Some((idx, file_typ)) = file_desc.get_by_name(name) else {
+                let msg = format!("column {name} not found in the Avro schema");
+                return Err(StorageErrorXKind::invalid_record_batch(msg).into());
+            };
+            if !file_typ.scalar_type.base_eq(&typ.scalar_type) {
+                let msg = format!(
+                    "column {name} has type {} in the Avro schema, expected {}",
+                    mz_pgrepr::Type::from(&file_typ.scalar_type).name(),
+                    mz_pgrepr::Type::from(&typ.scalar_type).name(),
+                );
+                return Err(StorageErrorXKind::invalid_record_batch(msg).into());
+            }
+            projection.push(idx);
+        }
+
+        // Decode each datum of the block into a Row.
+        let mut bytes = &bytes[..];
+        let mut file_row = Row::default();
+        let mut buf = Vec::new();
+        let mut datum_vec = DatumVec::new();
+        for _ in 0..len {
+            decode_datum(&schema, &mut bytes, &mut file_row, &mut buf).context("decode")?;
+            let datums = datum_vec.borrow_with(&file_row);
+            rows.push(Row::pack(projection.iter().map(|idx| datums[*idx])));
+        }
+
+        Ok(len)
+    }
+}
+
+/// Wraps a reader so that it implements [`Skip`], which the Avro reader requires.
+struct SkipReader<R>(R);
+
+impl<R: Read> Read for SkipReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.read(buf)
+    }
+}
+
+impl<R: Read> Skip for SkipReader<R> {}
@@ -10,8 +10,6 @@
 //! CSV to Row Decoder.
 
 use std::fmt::Debug;
-use std::io;
-use std::pin::Pin;
 use std::sync::Arc;
 
 use futures::TryStreamExt;
@@ -20,11 +18,11 @@ use itertools::Itertools;
 use mz_pgcopy::CopyCsvFormatParams;
 use mz_repr::{Datum, RelationDesc, Row, RowArena};
 use serde::{Deserialize, Serialize};
-use smallvec::{SmallVec, smallvec};
-use tokio_util::io::StreamReader;
+use smallvec::SmallVec;
 
+use crate::oneshot_source::util::{decompressed_reader, encodings_from_name};
 use crate::oneshot_source::{
-    Encoding, OneshotFormat, OneshotObject, OneshotSource, StorageErrorX, StorageErrorXKind,
+    Encoding, OneshotFormat, OneshotSource, StorageErrorX, StorageErrorXKind,
 };
 
 #[derive(Debug, Clone)]
@@ -83,21 +81,7 @@ impl OneshotFormat for CsvDecoder {
         //
         // See <https://badrish.net/papers/dp-sigmod19.pdf> for general parallelization strategies.
 
-        // TODO(cf1): Check the encodings from the object to determine
-        // what decompression to apply. Also support the user manually
-        // specifying certain encodings.
-
-        let encodings = if object.name().ends_with(".gz") {
-            smallvec![Encoding::Gzip]
-        } else if object.name().ends_with(".bz2") {
-            smallvec![Encoding::Bzip2]
-        } else if object.name().ends_with(".xz") {
-            smallvec![Encoding::Xz]
-        } else if object.name().ends_with(".zst") {
-            smallvec![Encoding::Zstd]
-        } else {
-            smallvec![]
-        };
+        let encodings = encodings_from_name(&object);
 
         let request = CsvWorkRequest {
             object,
@@ -118,43 +102,7 @@ impl OneshotFormat for CsvDecoder {
             encodings,
         } = request;
 
-        // Wrap our `Stream<Bytes>` into a type that implements `tokio::io::AsyncRead`.
-        let raw_byte_stream = source
-            .get(object, checksum, None)
-            .map_err(|e| io::Error::new(io::ErrorKind::Interrupted, format!("{e:?}")));
-        let stream_reader = StreamReader::new(raw_byte_stream);
-
-        // TODO(cf3): Support multiple encodings.
-        assert!(encodings.len() <= 1, "TODO support multiple encodings");
-
-        // Decompress the byte stream, if necessary.
-        let reader: Pin<Box<dyn tokio::io::AsyncRead + Send>> = if let Some(encoding) =
-            encodings.into_iter().next()
-        {
-            tracing::info!(?encoding, "decompressing byte stream");
-            match encoding {
-                Encoding::Bzip2 => {
-                    let decoder = async_compression::tokio::bufread::BzDecoder::new(stream_reader);
-                    Box::pin(decoder)
-                }
-                Encoding::Gzip => {
-                    let decoder =
-                        async_compression::tokio::bufread::GzipDecoder::new(stream_reader);
-                    Box::pin(decoder)
-                }
-                Encoding::Xz => {
-                    let decoder = async_compression::tokio::bufread::XzDecoder::new(stream_reader);
-                    Box::pin(decoder)
-                }
-                Encoding::Zstd => {
-                    let decoder =
-                        async_compression::tokio::bufread::ZstdDecoder::new(stream_reader);
-                    Box::pin(decoder)
-                }
-            }
-        } else {
-            Box::pin(stream_reader)
-        };
+        let reader = decompressed_reader(source, object, checksum, encodings);
 
         let (double_quote, escape) = if self.params.quote == self.params.escape {
             (true, None)
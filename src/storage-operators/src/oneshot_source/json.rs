@@ -0,0 +1,264 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Newline delimited JSON (aka JSON Lines) to Row Decoder.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use futures::stream::{BoxStream, StreamExt};
+use mz_pgrepr::Type;
+use mz_repr::{ColumnName, Datum, RelationDesc, Row, RowArena};
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio_stream::wrappers::SplitStream;
+
+use crate::oneshot_source::util::{decompressed_reader, encodings_from_name};
+use crate::oneshot_source::{
+    Encoding, OneshotFormat, OneshotSource, StorageErrorX, StorageErrorXKind,
+};
+
+/// Decodes objects that contain a single JSON object per line.
+///
+/// The fields of each object are matched to columns by name. A column whose field is missing, or
+/// whose field is `null`, is decoded as `NULL`. All other values are decoded from their text
+/// representation, with the exception of `jsonb` columns, which store the value as-is. JSON
+/// arrays are converted to array literals for array and list columns, e.g. `["a", null]` is
+/// decoded from `{"a",NULL}`.
+#[derive(Debug, Clone)]
+pub struct JsonDecoder {
+    /// Names of the columns of the table we're copying into.
+    column_names: Arc<[ColumnName]>,
+    /// Types of the table we're copying into.
+    column_types: Arc<[mz_pgrepr::Type]>,
+}
+
+impl JsonDecoder {
+    pub fn new(desc: &RelationDesc) -> Self {
+        let column_names = desc.iter_names().cloned().collect();
+        let column_types = desc
+            .iter_types()
+            .map(|x| &x.scalar_type)
+            .map(mz_pgrepr::Type::from)
+            .collect();
+        JsonDecoder {
+            column_names,
+            column_types,
+        }
+    }
+}
+
+/// Instructions on how to parse a single JSON Lines file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonWorkRequest<O, C> {
+    object: O,
+    checksum: C,
+    encodings: SmallVec<[Encoding; 1]>,
+}
+
+/// A single line of a JSON Lines file.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JsonRecord {
+    bytes: Vec<u8>,
+}
+
+impl OneshotFormat for JsonDecoder {
+    type WorkRequest<S>
+        = JsonWorkRequest<S::Object, S::Checksum>
+    where
+        S: OneshotSource;
+    type RecordChunk = JsonRecord;
+
+    async fn split_work<S: OneshotSource + Send>(
+        &self,
+        _source: S,
+        object: S::Object,
+        checksum: S::Checksum,
+    ) -> Result<Vec<Self::WorkRequest<S>>, StorageErrorX> {
+        // Like with CSV we don't know where lines start without reading the
+        // entire object, which is also the case for any compressed object.
+        let encodings = encodings_from_name(&object);
+
+        let request = JsonWorkRequest {
+            object,
+            checksum,
+            encodings,
+        };
+        Ok(vec![request])
+    }
+
+    fn fetch_work<'a, S: OneshotSource + Sync + 'static>(
+        &'a self,
+        source: &'a S,
+        request: Self::WorkRequest<S>,
+    ) -> BoxStream<'a, Result<Self::RecordChunk, StorageErrorX>> {
+        let JsonWorkRequest {
+            object,
+            checksum,
+            encodings,
+        } = request;
+
+        let reader = decompressed_reader(source, object, checksum, encodings);
+        let lines = SplitStream::new(BufReader::new(reader).split(b'\n'));
+
+        // Return a stream of records, skipping any blank lines.
+        lines
+            .try_filter(|line| futures::future::ready(!line.trim_ascii().is_empty()))
+            .map_ok(|bytes| JsonRecord { bytes })
+            .map_err(|err| {
+                StorageErrorXKind::JsonDecoding(err.to_string().into()).with_context("read line")
+            })
+            .boxed()
+    }
+
+    fn decode_chunk(
+        &self,
+        chunk: Self::RecordChunk,
+        rows: &mut Vec<Row>,
+    ) -> Result<usize, StorageErrorX> {
+        let JsonRecord { bytes } = chunk;
+
+        let mut object: serde_json::Map<String, serde_json::Value> = serde_json::from_slice(&bytes)
+            .map_err(|err| {
+                StorageErrorXKind::JsonDecoding(err.to_string().into()).with_context("parse")
+            })?;
+
+        // Decode a Row from the JSON object.
+        let mut row = Row::default();
+        let mut packer = row.packer();
+        let arena = RowArena::new();
+
+        for (name, typ) in self.column_names.iter().zip(self.column_types.iter()) {
+            let raw_value = match object.remove(name.as_str()) {
+                None | Some(serde_json::Value::Null) => {
+                    packer.push(Datum::Null);
+                    continue;
+                }
+                Some(value) => json_to_text(value, typ),
+            };
+
+            let value =
+                mz_pgrepr::Value::decode_text(typ, raw_value.as_bytes()).map_err(|err| {
+                    StorageErrorXKind::invalid_record_batch(format!("column {name}: {err}"))
+                        .with_context("decode_text")
+                })?;
+            let datum = value
+                .into_datum_decode_error(&arena, typ, "record field")
+                .map_err(|msg| {
+                    StorageErrorXKind::invalid_record_batch(msg).with_context("into_datum")
+                })?;
+            packer.push(datum);
+        }
+
+        // Make sure every field of the object was copied into a column.
+        if let Some(field) = object.keys().next() {
+            let msg = format!("field {field:?} does not match any column");
+            return Err(StorageErrorXKind::invalid_record_batch(msg).into());
+        }
+
+        rows.push(row);
+
+        Ok(1)
+    }
+}
+
+/// Returns the text representation of a JSON value, to be decoded as a value of type `typ`.
+fn json_to_text(value: serde_json::Value, typ: &Type) -> String {
+    match (value, typ) {
+        // Strings are decoded from their contents, unless the column stores JSON.
+        (serde_json::Value::String(s), typ) if *typ != Type::Jsonb => s,
+        (serde_json::Value::Array(elements), Type::Array(elem_type) | Type::List(elem_type)) => {
+            let mut text = String::new();
+            write_array_literal(&mut text, &elements, elem_type);
+            text
+        }
+        (value, _) => value.to_string(),
+    }
+}
+
+/// Writes the elements of a JSON array as an array or list literal, e.g. `{"a",NULL}`.
+fn write_array_literal(buf: &mut String, elements: &[serde_json::Value], elem_type: &Type) {
+    buf.push('{');
+    for (i, element) in elements.iter().enumerate() {
+        if i > 0 {
+            buf.push(',');
+        }
+        let text = match element {
+            serde_json::Value::Null => {
+                buf.push_str("NULL");
+                continue;
+            }
+            // Nested arrays are further dimensions of an array, or the elements of a nested
+            // list, unless the elements store JSON.
+            serde_json::Value::Array(inner) if *elem_type != Type::Jsonb => {
+                let inner_type = match elem_type {
+                    Type::List(inner_type) => &**inner_type,
+                    elem_type => elem_type,
+                };
+                write_array_literal(buf, inner, inner_type);
+                continue;
+            }
+            serde_json::Value::String(s) if *elem_type != Type::Jsonb => s.clone(),
+            element => element.to_string(),
+        };
+        buf.push('"');
+        for c in text.chars() {
+            if c == '"' || c == '\\' {
+                buf.push('\\');
+            }
+            buf.push(c);
+        }
+        buf.push('"');
+    }
+    buf.push('}');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[mz_ore::test]
+    fn test_json_to_text() {
+        let int_array = Type::Array(Box::new(Type::Int4));
+        let cases = [
+            (r#"[1, 2]"#, int_array.clone(), r#"{"1","2"}"#),
+            (
+                r#"[[1, null], [3, 4]]"#,
+                int_array.clone(),
+                r#"{{"1",NULL},{"3","4"}}"#,
+            ),
+            (
+                r#"["a\"b", "c\\d", null]"#,
+                Type::Array(Box::new(Type::Text)),
+                r#"{"a\"b","c\\d",NULL}"#,
+            ),
+            (
+                r#"[[1], [2, 3]]"#,
+                Type::List(Box::new(Type::List(Box::new(Type::Int4)))),
+                r#"{{"1"},{"2","3"}}"#,
+            ),
+            (
+                r#"[{"a": 1}, [2]]"#,
+                Type::Array(Box::new(Type::Jsonb)),
+                r#"{"{\"a\":1}","[2]"}"#,
+            ),
+            (r#"[1, 2]"#, Type::Jsonb, "[1,2]"),
+            (r#""x""#, Type::Text, "x"),
+        ];
+        for (json, typ, expected) in cases {
+            let value = serde_json::from_str(json).unwrap();
+            let text = json_to_text(value, &typ);
+            assert_eq!(text, expected, "{json} as {typ:?}");
+            mz_pgrepr::Value::decode_text(&typ, text.as_bytes())
+                .unwrap_or_else(|err| panic!("decoding {text} as {typ:?}: {err}"));
+        }
+    }
+}
@@ -25,7 +25,7 @@ use mz_ore::collections::CollectionExt;
 use mz_persist_client::cache::PersistClientCache;
 use mz_persist_client::cfg::{PersistConfig, RetryParameters};
 use mz_persist_client::fetch::{ExchangeableBatchPart, ShardSourcePart};
-use mz_persist_client::fetch::{FetchedBlob, FetchedPart};
+use mz_persist_client::fetch::{FetchedBlob, FetchedPart, PartSample};
 use mz_persist_client::operators::shard_source::{
     ErrorHandler, FilterResult, SnapshotMode, shard_source,
 };
@@ -129,6 +129,8 @@ impl Subtime {
 /// All updates at times greater or equal to `until` will be suppressed.
 /// The `map_filter_project` argument, if supplied, may be partially applied,
 /// and any un-applied part of the argument will be left behind in the argument.
+/// The `part_sample` argument, if supplied, restricts the read to the parts of
+/// the shard that are included in the sample.
 ///
 /// Users of this function have the ability to apply flow control to the output
 /// to limit the in-flight data (measured in bytes) it can emit. The flow control
@@ -155,6 +157,7 @@ pub fn persist_source<G>(
     snapshot_mode: SnapshotMode,
     until: Antichain<Timestamp>,
     map_filter_project: Option<&mut MfpPlan>,
+    part_sample: Option<PartSample>,
     max_inflight_bytes: Option<usize>,
     start_signal: impl Future<Output = ()> + 'static,
     error_handler: ErrorHandler,
@@ -219,6 +222,7 @@ where
             snapshot_mode,
             until.clone(),
             map_filter_project,
+            part_sample,
             flow_control,
             subscribe_sleep,
             start_signal,
@@ -289,6 +293,7 @@ pub fn persist_source_core<'g, G>(
     snapshot_mode: SnapshotMode,
     until: Antichain<Timestamp>,
     map_filter_project: Option<&mut MfpPlan>,
+    part_sample: Option<PartSample>,
     flow_control: Option<FlowControl<RefinedScope<'g, G>>>,
     // If Some, an override for the default listen sleep retry parameters.
     listen_sleep: Option<impl Fn() -> RetryParameters + 'static>,
@@ -379,6 +384,7 @@ where
                 FilterResult::Keep
             }
         },
+        part_sample,
         listen_sleep,
         start_signal,
         error_handler,
@@ -39,6 +39,8 @@ use timely::dataflow::{Scope, StreamVec};
 use timely::progress::Antichain;
 use tracing::debug;
 
+mod avro;
+mod orc;
 mod parquet;
 mod pgcopy;
 
@@ -109,6 +111,32 @@ where
             params,
             output_batch_count,
         ),
+        S3SinkFormat::Avro => render_upload_operator::<G, avro::AvroUploader>(
+            scope.clone(),
+            connection_context.clone(),
+            aws_connection.clone(),
+            connection_id,
+            connection_details,
+            sink_id,
+            input_collection,
+            up_to,
+            start_stream,
+            params,
+            output_batch_count,
+        ),
+        S3SinkFormat::Orc => render_upload_operator::<G, orc::OrcUploader>(
+            scope.clone(),
+            connection_context.clone(),
+            aws_connection.clone(),
+            connection_id,
+            connection_details,
+            sink_id,
+            input_collection,
+            up_to,
+            start_stream,
+            params,
+            output_batch_count,
+        ),
     };
 
     let completion_token = render_completion_operator(
@@ -432,7 +460,10 @@ pub struct CopyToParameters {
     // The ratio (defined as a percentage) of row-group size to max-file-size.
     // See the `parquet` module for more details on how this is used.
     pub parquet_row_group_ratio: usize,
-    // The ratio (defined as a percentage) of arrow-builder size to row-group size.
+    // The ratio (defined as a percentage) of ORC stripe size to max-file-size.
+    // See the `orc` module for more details on how this is used.
+    pub orc_stripe_ratio: usize,
+    // The ratio (defined as a percentage) of arrow-builder size to row-group or stripe size.
     // See the `parquet` module for more details on how this is used.
     pub arrow_builder_buffer_ratio: usize,
     // The size of each part in the multi-part upload to use when uploading files to S3.
@@ -460,3 +491,79 @@ trait CopyToS3Uploader: Sized {
     /// This will be called when the input stream is finished.
     async fn finish(&mut self) -> Result<(), anyhow::Error>;
 }
+
+/// Uploads a single batch of rows to S3, in the format described by the
+/// [`S3UploadInfo`] it is created with.
+///
+/// This exposes the per-format uploaders used by [`copy_to`] to sinks that
+/// drive their own upload protocol, like the continuous S3 sink in storage.
+pub struct S3BatchUploader(BatchUploader);
+
+enum BatchUploader {
+    PgCopy(pgcopy::PgCopyUploader),
+    Parquet(parquet::ParquetUploader),
+    Avro(avro::AvroUploader),
+    Orc(orc::OrcUploader),
+}
+
+impl S3BatchUploader {
+    pub fn new(
+        sdk_config: SdkConfig,
+        connection_details: S3UploadInfo,
+        sink_id: &GlobalId,
+        batch: u64,
+        params: CopyToParameters,
+    ) -> Result<Self, anyhow::Error> {
+        let uploader = match connection_details.format {
+            S3SinkFormat::PgCopy(_) => BatchUploader::PgCopy(pgcopy::PgCopyUploader::new(
+                sdk_config,
+                connection_details,
+                sink_id,
+                batch,
+                params,
+            )?),
+            S3SinkFormat::Parquet => BatchUploader::Parquet(parquet::ParquetUploader::new(
+                sdk_config,
+                connection_details,
+                sink_id,
+                batch,
+                params,
+            )?),
+            S3SinkFormat::Avro => BatchUploader::Avro(avro::AvroUploader::new(
+                sdk_config,
+                connection_details,
+                sink_id,
+                batch,
+                params,
+            )?),
+            S3SinkFormat::Orc => BatchUploader::Orc(orc::OrcUploader::new(
+                sdk_config,
+                connection_details,
+                sink_id,
+                batch,
+                params,
+            )?),
+        };
+        Ok(S3BatchUploader(uploader))
+    }
+
+    /// Append a row to the internal buffer, and optionally flush the buffer to S3.
+    pub async fn append_row(&mut self, row: &Row) -> Result<(), anyhow::Error> {
+        match &mut self.0 {
+            BatchUploader::PgCopy(uploader) => uploader.append_row(row).await,
+            BatchUploader::Parquet(uploader) => uploader.append_row(row).await,
+            BatchUploader::Avro(uploader) => uploader.append_row(row).await,
+            BatchUploader::Orc(uploader) => uploader.append_row(row).await,
+        }
+    }
+
+    /// Flush the full remaining internal buffer to S3, and close all open resources.
+    pub async fn finish(&mut self) -> Result<(), anyhow::Error> {
+        match &mut self.0 {
+            BatchUploader::PgCopy(uploader) => uploader.finish().await,
+            BatchUploader::Parquet(uploader) => uploader.finish().await,
+            BatchUploader::Avro(uploader) => uploader.finish().await,
+            BatchUploader::Orc(uploader) => uploader.finish().await,
+        }
+    }
+}
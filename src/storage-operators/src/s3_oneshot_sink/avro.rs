@@ -0,0 +1,239 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::sync::Arc;
+
+use aws_types::sdk_config::SdkConfig;
+use mz_avro::{Codec, Schema, Writer};
+use mz_aws_util::s3_uploader::{
+    AWS_S3_MAX_PART_COUNT, CompletedUpload, S3MultiPartUploader, S3MultiPartUploaderConfig,
+};
+use mz_interchange::avro::encode_datums_as_avro;
+use mz_ore::cast::CastFrom;
+use mz_ore::future::OreFutureExt;
+use mz_repr::{ColumnName, GlobalId, Row, SqlColumnType};
+use mz_storage_types::sinks::s3_oneshot_sink::S3KeyManager;
+use mz_storage_types::sinks::{S3SinkFormat, S3UploadInfo};
+use tracing::{debug, info};
+
+use super::{CopyToParameters, CopyToS3Uploader};
+
+/// An [`AvroUploader`] that writes rows to Avro Object Container Files and uploads them to S3.
+///
+/// The files use the same schema as the values of Kafka sinks with `FORMAT AVRO`, see
+/// [`S3SinkFormat::avro_schema_generator`], and their blocks are compressed with snappy.
+///
+/// Spawns all S3 operations in tokio tasks to avoid blocking the surrounding timely context.
+///
+/// ## Buffering
+///
+/// The [`Writer`] of the active [`AvroFile`] buffers encoded rows until it has enough of them to
+/// write a block. Blocks are handed to the file's [`S3MultiPartUploader`] as soon as they are
+/// written, which uploads parts to S3 once its upload buffer exceeds the configured part size.
+///
+/// ## File Size
+///
+/// The size of a file is only known once its blocks are written, so a new file is started when
+/// the bytes handed to the uploader exceed the `max_file_size`. A file may therefore exceed the
+/// `max_file_size` by about the size of a block.
+pub(super) struct AvroUploader {
+    /// The schema of the files.
+    schema: Schema,
+    /// The names and types of the columns of the rows, in the order of the schema's fields.
+    columns: Arc<[(ColumnName, SqlColumnType)]>,
+    /// The index of the next file to upload within the batch.
+    next_file_index: usize,
+    /// Provides the appropriate bucket and object keys to use for uploads.
+    key_manager: S3KeyManager,
+    /// Identifies the batch that files uploaded by this uploader belong to.
+    batch: u64,
+    /// The desired file size. A new file upload will be started
+    /// when the size exceeds this amount.
+    max_file_size: u64,
+    /// The aws sdk config.
+    sdk_config: Arc<SdkConfig>,
+    /// The active avro file being written to, stored in an option to make it
+    /// easier to take ownership when calling in spawned tokio tasks.
+    active_file: Option<AvroFile>,
+    /// Upload parameters.
+    params: CopyToParameters,
+}
+
+impl CopyToS3Uploader for AvroUploader {
+    fn new(
+        sdk_config: SdkConfig,
+        connection_details: S3UploadInfo,
+        sink_id: &GlobalId,
+        batch: u64,
+        params: CopyToParameters,
+    ) -> Result<AvroUploader, anyhow::Error> {
+        match connection_details.format {
+            S3SinkFormat::Avro => {
+                let generator = S3SinkFormat::avro_schema_generator(connection_details.desc)?;
+                Ok(AvroUploader {
+                    schema: generator.schema().clone(),
+                    columns: generator.columns().into(),
+                    sdk_config: Arc::new(sdk_config),
+                    key_manager: S3KeyManager::new(sink_id, &connection_details.uri),
+                    batch,
+                    max_file_size: connection_details.max_file_size,
+                    next_file_index: 0,
+                    active_file: None,
+                    params,
+                })
+            }
+            S3SinkFormat::PgCopy(_) | S3SinkFormat::Parquet | S3SinkFormat::Orc => {
+                anyhow::bail!("Expected Avro format")
+            }
+        }
+    }
+
+    async fn append_row(&mut self, row: &Row) -> Result<(), anyhow::Error> {
+        let active_file = match self.active_file {
+            Some(ref mut file) => file,
+            None => self.start_new_file().await?,
+        };
+
+        active_file.add_row(row)?;
+
+        // If this file has gone over the max size, start a new one
+        if active_file.size_estimate() >= self.max_file_size {
+            debug!("file size limit exceeded, starting new file");
+            self.start_new_file().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn finish(&mut self) -> Result<(), anyhow::Error> {
+        if let Some(active_file) = self.active_file.take() {
+            active_file
+                .finish()
+                .run_in_task(|| "AvroFile::finish")
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn force_new_file(&mut self) -> Result<(), anyhow::Error> {
+        self.start_new_file().await?;
+        Ok(())
+    }
+}
+
+impl AvroUploader {
+    /// Start a new avro file for upload. Will finish the current file if one is active.
+    async fn start_new_file(&mut self) -> Result<&mut AvroFile, anyhow::Error> {
+        if let Some(active_file) = self.active_file.take() {
+            active_file
+                .finish()
+                .run_in_task(|| "AvroFile::finish")
+                .await?;
+        }
+        let object_key = self
+            .key_manager
+            .data_key(self.batch, self.next_file_index, "avro");
+        self.next_file_index += 1;
+
+        let bucket = self.key_manager.bucket.clone();
+        info!("starting upload: bucket {}, key {}", bucket, object_key);
+        let new_file = AvroFile::new(
+            bucket,
+            object_key,
+            self.schema.clone(),
+            Arc::clone(&self.columns),
+            Arc::clone(&self.sdk_config),
+            u64::cast_from(self.params.s3_multipart_part_size_bytes),
+        )
+        .run_in_task(|| "AvroFile::new")
+        .await?;
+
+        self.active_file = Some(new_file);
+        Ok(self.active_file.as_mut().unwrap())
+    }
+}
+
+/// Helper to tie the lifecycle of the avro `Writer` and `S3MultiPartUploader` together for a
+/// single avro file.
+struct AvroFile {
+    writer: Writer<Vec<u8>>,
+    uploader: S3MultiPartUploader,
+    columns: Arc<[(ColumnName, SqlColumnType)]>,
+}
+
+impl AvroFile {
+    async fn new(
+        bucket: String,
+        key: String,
+        schema: Schema,
+        columns: Arc<[(ColumnName, SqlColumnType)]>,
+        sdk_config: Arc<SdkConfig>,
+        part_size_limit: u64,
+    ) -> Result<Self, anyhow::Error> {
+        let writer = Writer::with_codec(schema, Vec::new(), Codec::Snappy);
+        let uploader = S3MultiPartUploader::try_new(
+            sdk_config.as_ref(),
+            bucket,
+            key,
+            S3MultiPartUploaderConfig {
+                part_size_limit,
+                // We are already enforcing the max size ourselves so we set the max size enforced
+                // by the uploader to the max file size it will allow based on the part size limit.
+                file_size_limit: part_size_limit
+                    .checked_mul(AWS_S3_MAX_PART_COUNT.try_into().expect("known safe"))
+                    .expect("known safe"),
+            },
+        )
+        .await?;
+
+        Ok(Self {
+            writer,
+            uploader,
+            columns,
+        })
+    }
+
+    fn add_row(&mut self, row: &Row) -> Result<(), anyhow::Error> {
+        let value = encode_datums_as_avro(row.iter(), &self.columns);
+        self.writer.append_value_ref(&value)?;
+        self.flush_writer()
+    }
+
+    /// Flush the remaining rows of the writer and the uploader. Writes the header of the file
+    /// even if it has no rows, so that it is a valid avro file.
+    async fn finish(mut self) -> Result<CompletedUpload, anyhow::Error> {
+        self.writer.write_header()?;
+        self.writer.flush()?;
+        self.flush_writer()?;
+        let res = self.uploader.finish().await?;
+        info!(
+            "finished upload: bucket {}, key {}, bytes_uploaded {}, parts_uploaded {}",
+            res.bucket, res.key, res.total_bytes_uploaded, res.part_count
+        );
+        Ok(res)
+    }
+
+    /// Hands the blocks the writer has written so far to the uploader, which may trigger an
+    /// upload.
+    fn flush_writer(&mut self) -> Result<(), anyhow::Error> {
+        let buffer = self.writer.get_mut();
+        if !buffer.is_empty() {
+            self.uploader.buffer_chunk(buffer.as_slice())?;
+            // reuse the buffer in the writer
+            buffer.clear();
+        }
+        Ok(())
+    }
+
+    /// Returns an approximate size estimate of the file being written, which excludes the rows
+    /// buffered by the writer.
+    fn size_estimate(&self) -> u64 {
+        self.uploader.added_bytes()
+    }
+}
@@ -0,0 +1,328 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use aws_types::sdk_config::SdkConfig;
+use mz_arrow_util::builder::ArrowBuilder;
+use mz_aws_util::s3_uploader::{
+    AWS_S3_MAX_PART_COUNT, CompletedUpload, S3MultiPartUploader, S3MultiPartUploaderConfig,
+};
+use mz_ore::cast::CastFrom;
+use mz_ore::future::OreFutureExt;
+use mz_repr::{GlobalId, RelationDesc, Row};
+use mz_storage_types::sinks::s3_oneshot_sink::S3KeyManager;
+use mz_storage_types::sinks::{S3SinkFormat, S3UploadInfo};
+use orc_rust::{ArrowWriter, ArrowWriterBuilder};
+use tracing::{debug, info};
+
+use super::{CopyToParameters, CopyToS3Uploader};
+
+/// Set the default capacity for the array builders inside the ArrowBuilder. This is the
+/// number of items each builder can hold before it needs to allocate more memory.
+const DEFAULT_ARRAY_BUILDER_ITEM_CAPACITY: usize = 1024;
+/// Set the default buffer capacity for the string and binary array builders inside the
+/// ArrowBuilder. This is the number of bytes each builder can hold before it needs to allocate
+/// more memory.
+const DEFAULT_ARRAY_BUILDER_DATA_CAPACITY: usize = 1024;
+
+/// An [`OrcUploader`] that writes rows to ORC files and uploads them to S3.
+///
+/// Spawns all S3 operations in tokio tasks to avoid blocking the surrounding timely context.
+///
+/// ## Buffering
+///
+/// This works like the `ParquetUploader`, with ORC stripes taking the place of parquet row
+/// groups:
+///
+/// - Rows are added to an [`ArrowBuilder`], which is flushed to the [`ArrowWriter`] of the
+///   active [`OrcFile`] once it holds more than the configured arrow_builder_buffer_bytes.
+///
+/// - The [`ArrowWriter`] buffers until its in-progress stripe exceeds the stripe size, which we
+///   derive from the max file size using the configured orc_stripe_ratio, in the same way as the
+///   parquet row group size.
+///
+/// - Once a stripe is written out, it is handed to the file's [`S3MultiPartUploader`], which
+///   uploads parts to S3 once its upload buffer exceeds the configured part size.
+///
+/// ## File Size
+///
+/// Like for parquet files, the size of a file is only known once its stripes are written, so a
+/// new file is started when the bytes handed to the uploader exceed the `max_file_size`. A file
+/// may therefore exceed the `max_file_size` by about the size of a stripe.
+pub(super) struct OrcUploader {
+    /// The output description.
+    desc: Arc<RelationDesc>,
+    /// The index of the next file to upload within the batch.
+    next_file_index: usize,
+    /// Provides the appropriate bucket and object keys to use for uploads.
+    key_manager: S3KeyManager,
+    /// Identifies the batch that files uploaded by this uploader belong to.
+    batch: u64,
+    /// The desired file size. A new file upload will be started
+    /// when the size exceeds this amount.
+    max_file_size: u64,
+    /// The aws sdk config.
+    sdk_config: Arc<SdkConfig>,
+    stripe_size_bytes: u64,
+    arrow_builder_buffer_bytes: u64,
+    /// The active orc file being written to, stored in an option to make it
+    /// easier to take ownership when calling in spawned tokio tasks.
+    active_file: Option<OrcFile>,
+    /// Upload and buffer params
+    params: CopyToParameters,
+}
+
+impl CopyToS3Uploader for OrcUploader {
+    fn new(
+        sdk_config: SdkConfig,
+        connection_details: S3UploadInfo,
+        sink_id: &GlobalId,
+        batch: u64,
+        params: CopyToParameters,
+    ) -> Result<OrcUploader, anyhow::Error> {
+        if params.orc_stripe_ratio > 100 {
+            anyhow::bail!("orc_stripe_ratio must be <= 100");
+        }
+        if params.arrow_builder_buffer_ratio < 100 {
+            anyhow::bail!("arrow_builder_buffer_ratio must be >= 100");
+        }
+        let stripe_size_bytes =
+            connection_details.max_file_size * u64::cast_from(params.orc_stripe_ratio) / 100;
+        let arrow_builder_buffer_bytes =
+            stripe_size_bytes * u64::cast_from(params.arrow_builder_buffer_ratio) / 100;
+
+        match connection_details.format {
+            S3SinkFormat::Orc => Ok(OrcUploader {
+                desc: Arc::new(connection_details.desc),
+                sdk_config: Arc::new(sdk_config),
+                key_manager: S3KeyManager::new(sink_id, &connection_details.uri),
+                batch,
+                max_file_size: connection_details.max_file_size,
+                next_file_index: 0,
+                stripe_size_bytes,
+                arrow_builder_buffer_bytes,
+                active_file: None,
+                params,
+            }),
+            S3SinkFormat::PgCopy(_) | S3SinkFormat::Parquet | S3SinkFormat::Avro => {
+                anyhow::bail!("Expected ORC format")
+            }
+        }
+    }
+
+    async fn append_row(&mut self, row: &Row) -> Result<(), anyhow::Error> {
+        let active_file = match self.active_file {
+            Some(ref mut file) => file,
+            None => self.start_new_file().await?,
+        };
+
+        active_file.add_row(row)?;
+
+        // If this file has gone over the max size, start a new one
+        if active_file.size_estimate() >= self.max_file_size {
+            debug!("file size limit exceeded, starting new file");
+            self.start_new_file().await?;
+        }
+
+        Ok(())
+    }
+
+    async fn finish(&mut self) -> Result<(), anyhow::Error> {
+        if let Some(active_file) = self.active_file.take() {
+            active_file
+                .finish()
+                .run_in_task(|| "OrcFile::finish")
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn force_new_file(&mut self) -> Result<(), anyhow::Error> {
+        self.start_new_file().await?;
+        Ok(())
+    }
+}
+
+impl OrcUploader {
+    /// Start a new orc file for upload. Will finish the current file if one is active.
+    async fn start_new_file(&mut self) -> Result<&mut OrcFile, anyhow::Error> {
+        if let Some(active_file) = self.active_file.take() {
+            active_file
+                .finish()
+                .run_in_task(|| "OrcFile::finish")
+                .await?;
+        }
+        let object_key = self
+            .key_manager
+            .data_key(self.batch, self.next_file_index, "orc");
+        self.next_file_index += 1;
+
+        let bucket = self.key_manager.bucket.clone();
+        info!("starting upload: bucket {}, key {}", bucket, object_key);
+        let new_file = OrcFile::new(
+            bucket,
+            object_key,
+            Arc::clone(&self.desc),
+            Arc::clone(&self.sdk_config),
+            self.arrow_builder_buffer_bytes,
+            self.stripe_size_bytes,
+            u64::cast_from(self.params.s3_multipart_part_size_bytes),
+        )
+        .run_in_task(|| "OrcFile::new")
+        .await?;
+
+        self.active_file = Some(new_file);
+        Ok(self.active_file.as_mut().unwrap())
+    }
+}
+
+/// The destination of an [`ArrowWriter`], from which the stripes it has written can be taken
+/// while it is still writing.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().expect("lock poisoned").extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Helper to tie the lifecycle of the `ArrowBuilder`, `ArrowWriter`, and `S3MultiPartUploader`
+/// together for a single orc file.
+struct OrcFile {
+    /// The active arrow builder.
+    builder: ArrowBuilder,
+    writer: ArrowWriter<SharedBuffer>,
+    /// The buffer the `writer` writes to.
+    buffer: SharedBuffer,
+    uploader: S3MultiPartUploader,
+    arrow_builder_buffer_bytes: u64,
+    desc: Arc<RelationDesc>,
+}
+
+impl OrcFile {
+    async fn new(
+        bucket: String,
+        key: String,
+        desc: Arc<RelationDesc>,
+        sdk_config: Arc<SdkConfig>,
+        arrow_builder_buffer_bytes: u64,
+        stripe_size: u64,
+        part_size_limit: u64,
+    ) -> Result<Self, anyhow::Error> {
+        let builder = ArrowBuilder::new(
+            &desc,
+            DEFAULT_ARRAY_BUILDER_ITEM_CAPACITY,
+            DEFAULT_ARRAY_BUILDER_DATA_CAPACITY,
+        )?;
+
+        let buffer = SharedBuffer::default();
+        let writer = ArrowWriterBuilder::new(buffer.clone(), builder.schema().into())
+            .with_stripe_byte_size(usize::cast_from(stripe_size))
+            .try_build()?;
+        let uploader = S3MultiPartUploader::try_new(
+            sdk_config.as_ref(),
+            bucket,
+            key,
+            S3MultiPartUploaderConfig {
+                part_size_limit,
+                // We are already enforcing the max size ourselves so we set the max size enforced
+                // by the uploader to the max file size it will allow based on the part size limit.
+                file_size_limit: part_size_limit
+                    .checked_mul(AWS_S3_MAX_PART_COUNT.try_into().expect("known safe"))
+                    .expect("known safe"),
+            },
+        )
+        .await?;
+
+        Ok(Self {
+            builder,
+            writer,
+            buffer,
+            uploader,
+            arrow_builder_buffer_bytes,
+            desc,
+        })
+    }
+
+    fn add_row(&mut self, row: &Row) -> Result<(), anyhow::Error> {
+        self.builder.add_row(row)?;
+
+        if u64::cast_from(self.builder.row_size_bytes()) > self.arrow_builder_buffer_bytes {
+            self.flush_builder()?;
+        }
+
+        Ok(())
+    }
+
+    /// Flush the current arrow builder, the orc writer, and the uploader.
+    async fn finish(mut self) -> Result<CompletedUpload, anyhow::Error> {
+        self.flush_builder()?;
+        let OrcFile {
+            writer,
+            buffer,
+            mut uploader,
+            ..
+        } = self;
+        // Closing the writer writes out the last stripe and the footer of the file.
+        writer.close()?;
+        uploader.buffer_chunk(buffer.0.lock().expect("lock poisoned").as_slice())?;
+        let res = uploader.finish().await?;
+        info!(
+            "finished upload: bucket {}, key {}, bytes_uploaded {}, parts_uploaded {}",
+            res.bucket, res.key, res.total_bytes_uploaded, res.part_count
+        );
+        Ok(res)
+    }
+
+    /// Flush the current arrow builder to the orc writer, then hands any stripes the writer has
+    /// written to the uploader which may trigger an upload.
+    fn flush_builder(&mut self) -> Result<(), anyhow::Error> {
+        let builder = std::mem::replace(
+            &mut self.builder,
+            ArrowBuilder::new(
+                &self.desc,
+                DEFAULT_ARRAY_BUILDER_ITEM_CAPACITY,
+                DEFAULT_ARRAY_BUILDER_DATA_CAPACITY,
+            )?,
+        );
+        let arrow_batch = builder.to_record_batch()?;
+
+        if arrow_batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        // The writer writes out a stripe once its in-progress stripe exceeds the stripe size.
+        self.writer.write(&arrow_batch)?;
+        self.flush_buffer()
+    }
+
+    /// Hands the bytes the writer has written so far to the uploader.
+    fn flush_buffer(&mut self) -> Result<(), anyhow::Error> {
+        let mut buffer = self.buffer.0.lock().expect("lock poisoned");
+        if !buffer.is_empty() {
+            self.uploader.buffer_chunk(buffer.as_slice())?;
+            buffer.clear();
+        }
+        Ok(())
+    }
+
+    /// Returns an approximate size estimate of the file being written, which excludes the rows
+    /// of the in-progress stripe.
+    fn size_estimate(&self) -> u64 {
+        self.uploader.added_bytes()
+    }
+}
@@ -184,7 +184,9 @@ impl CopyToS3Uploader for ParquetUploader {
                 active_file: None,
                 params,
             }),
-            S3SinkFormat::PgCopy(_) => anyhow::bail!("Expected Parquet format"),
+            S3SinkFormat::PgCopy(_) | S3SinkFormat::Avro | S3SinkFormat::Orc => {
+                anyhow::bail!("Expected Parquet format")
+            }
         }
     }
 
@@ -68,7 +68,9 @@ impl CopyToS3Uploader for PgCopyUploader {
                 current_file_uploader: None,
                 params,
             }),
-            S3SinkFormat::Parquet => anyhow::bail!("Expected PgCopy format"),
+            S3SinkFormat::Parquet | S3SinkFormat::Avro | S3SinkFormat::Orc => {
+                anyhow::bail!("Expected PgCopy format")
+            }
         }
     }
 
@@ -243,6 +245,7 @@ mod tests {
                 s3_multipart_part_size_bytes: 10 * 1024 * 1024,
                 arrow_builder_buffer_ratio: 100,
                 parquet_row_group_ratio: 100,
+                orc_stripe_ratio: 100,
             },
         )?;
         let mut row = Row::default();
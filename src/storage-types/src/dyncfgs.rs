@@ -299,6 +299,65 @@ pub const SINK_ENSURE_TOPIC_CONFIG: Config<&'static str> = Config::new(
     match the expected configs.",
 );
 
+/// The ratio (defined as a percentage) of row-group size to max-file-size for
+/// Parquet files written by S3 sinks. See
+/// `src/storage-operators/src/s3_oneshot_sink/parquet.rs` for more details.
+pub const S3_SINK_PARQUET_ROW_GROUP_FILE_RATIO: Config<usize> = Config::new(
+    "storage_s3_sink_parquet_row_group_file_ratio",
+    20,
+    "The ratio (defined as a percentage) of row-group size to max-file-size. \
+        Must be <= 100.",
+);
+
+/// The ratio (defined as a percentage) of stripe size to max-file-size for
+/// ORC files written by S3 sinks. See
+/// `src/storage-operators/src/s3_oneshot_sink/orc.rs` for more details.
+pub const S3_SINK_ORC_STRIPE_FILE_RATIO: Config<usize> = Config::new(
+    "storage_s3_sink_orc_stripe_file_ratio",
+    20,
+    "The ratio (defined as a percentage) of ORC stripe size to max-file-size. \
+        Must be <= 100.",
+);
+
+/// The ratio (defined as a percentage) of arrow-builder size to row-group or
+/// stripe size for Parquet and ORC files written by S3 sinks.
+pub const S3_SINK_ARROW_BUILDER_BUFFER_RATIO: Config<usize> = Config::new(
+    "storage_s3_sink_arrow_builder_buffer_ratio",
+    150,
+    "The ratio (defined as a percentage) of arrow-builder size to row-group or stripe size. \
+        Must be >= 100.",
+);
+
+/// The maximum number of batches listed in the manifest of an S3 sink, before
+/// they are moved into a manifest segment.
+pub const S3_SINK_MANIFEST_MAX_BATCHES: Config<usize> = Config::new(
+    "storage_s3_sink_manifest_max_batches",
+    1000,
+    "The maximum number of batches listed in the manifest of an S3 sink, before \
+        they are moved into a manifest segment.",
+);
+
+/// The size of each part in the multi-part uploads of S3 sinks.
+pub const S3_SINK_MULTIPART_PART_SIZE_BYTES: Config<usize> = Config::new(
+    "storage_s3_sink_multipart_part_size_bytes",
+    1024 * 1024 * 8,
+    "The size of each part in a multipart upload to S3.",
+);
+
+/// The timeout of each request made by HTTP sinks.
+pub const HTTP_SINK_REQUEST_TIMEOUT: Config<Duration> = Config::new(
+    "storage_http_sink_request_timeout",
+    Duration::from_secs(30),
+    "The timeout of each request made by HTTP sinks.",
+);
+
+/// For how long HTTP sinks retry delivering a batch before they halt.
+pub const HTTP_SINK_RETRY_MAX_DURATION: Config<Duration> = Config::new(
+    "storage_http_sink_retry_max_duration",
+    Duration::from_secs(5 * 60),
+    "For how long HTTP sinks retry delivering a batch of updates before halting.",
+);
+
 /// Configure mz-ore overflowing type behavior.
 pub const ORE_OVERFLOWING_BEHAVIOR: Config<&'static str> = Config::new(
     "ore_overflowing_behavior",
@@ -323,6 +382,8 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&CLUSTER_SHUTDOWN_GRACE_PERIOD)
         .add(&DELAY_SOURCES_PAST_REHYDRATION)
         .add(&ENFORCE_EXTERNAL_ADDRESSES)
+        .add(&HTTP_SINK_REQUEST_TIMEOUT)
+        .add(&HTTP_SINK_RETRY_MAX_DURATION)
         .add(&KAFKA_BUFFERED_EVENT_RESIZE_THRESHOLD_ELEMENTS)
         .add(&KAFKA_CLIENT_ID_ENRICHMENT_RULES)
         .add(&KAFKA_DEFAULT_AWS_PRIVATELINK_ENDPOINT_IDENTIFICATION_ALGORITHM)
@@ -337,6 +398,11 @@ pub fn all_dyncfgs(configs: ConfigSet) -> ConfigSet {
         .add(&PG_SCHEMA_VALIDATION_INTERVAL)
         .add(&PG_SOURCE_VALIDATE_TIMELINE)
         .add(&REPLICA_METRICS_HISTORY_RETENTION_INTERVAL)
+        .add(&S3_SINK_ARROW_BUILDER_BUFFER_RATIO)
+        .add(&S3_SINK_MANIFEST_MAX_BATCHES)
+        .add(&S3_SINK_MULTIPART_PART_SIZE_BYTES)
+        .add(&S3_SINK_ORC_STRIPE_FILE_RATIO)
+        .add(&S3_SINK_PARQUET_ROW_GROUP_FILE_RATIO)
         .add(&SINK_ENSURE_TOPIC_CONFIG)
         .add(&SINK_PROGRESS_SEARCH)
         .add(&SQL_SERVER_SOURCE_VALIDATE_RESTORE_HISTORY)
@@ -55,6 +55,10 @@ pub enum ContentSource {
 pub enum ContentFormat {
     Csv(CopyCsvFormatParams<'static>),
     Parquet,
+    /// Newline delimited JSON, one object per row.
+    Json,
+    /// Avro Object Container Files.
+    Avro,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
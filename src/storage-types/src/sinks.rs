@@ -10,11 +10,13 @@
 //! Types and traits related to reporting changing collections out of `dataflow`.
 
 use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
 use std::time::Duration;
 
 use mz_dyncfg::ConfigSet;
 use mz_expr::MirScalarExpr;
+use mz_interchange::avro::AvroSchemaGenerator;
 use mz_pgcopy::CopyFormatParams;
 use mz_repr::bytes::ByteSize;
 use mz_repr::{CatalogItemId, GlobalId, RelationDesc};
@@ -22,12 +24,14 @@ use proptest_derive::Arbitrary;
 use serde::{Deserialize, Serialize};
 use timely::PartialOrder;
 use timely::progress::frontier::Antichain;
+use url::Url;
 
 use crate::AlterCompatible;
 use crate::connections::inline::{
     ConnectionAccess, ConnectionResolver, InlinedConnection, IntoInlineConnection,
     ReferencedConnection,
 };
+use crate::connections::string_or_secret::StringOrSecret;
 use crate::connections::{ConnectionContext, KafkaConnection, KafkaTopicOptions};
 use crate::controller::AlterError;
 
@@ -126,12 +130,24 @@ impl<S: Debug + PartialEq, T: Debug + PartialEq + PartialOrder> AlterCompatible
 pub enum SinkEnvelope {
     Debezium,
     Upsert,
+    /// Every insertion or update of a row is appended to the output as a new
+    /// record, and deletions are dropped.
+    Append,
+    /// Every update is appended to the output as a new record that carries the
+    /// update's row along with its timestamp and diff, in the `mz_timestamp`
+    /// and `mz_diff` columns, like the output of `SUBSCRIBE`.
+    Changelog,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum StorageSinkConnection<C: ConnectionAccess = InlinedConnection> {
     Kafka(KafkaSinkConnection<C>),
     Iceberg(IcebergSinkConnection<C>),
+    Postgres(PostgresSinkConnection<C>),
+    MySql(MySqlSinkConnection<C>),
+    SqlServer(SqlServerSinkConnection<C>),
+    S3(S3SinkConnection<C>),
+    Http(HttpSinkConnection),
 }
 
 impl<C: ConnectionAccess> StorageSinkConnection<C> {
@@ -154,6 +170,21 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
             (StorageSinkConnection::Iceberg(s), StorageSinkConnection::Iceberg(o)) => {
                 s.alter_compatible(id, o)?
             }
+            (StorageSinkConnection::Postgres(s), StorageSinkConnection::Postgres(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::MySql(s), StorageSinkConnection::MySql(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::SqlServer(s), StorageSinkConnection::SqlServer(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::S3(s), StorageSinkConnection::S3(o)) => {
+                s.alter_compatible(id, o)?
+            }
+            (StorageSinkConnection::Http(s), StorageSinkConnection::Http(o)) => {
+                s.alter_compatible(id, o)?
+            }
             _ => {
                 tracing::warn!(
                     "StorageSinkConnection incompatible:\nself:\n{:#?}\n\nother\n{:#?}",
@@ -175,6 +206,13 @@ impl<R: ConnectionResolver> IntoInlineConnection<StorageSinkConnection, R>
         match self {
             Self::Kafka(conn) => StorageSinkConnection::Kafka(conn.into_inline_connection(r)),
             Self::Iceberg(conn) => StorageSinkConnection::Iceberg(conn.into_inline_connection(r)),
+            Self::Postgres(conn) => StorageSinkConnection::Postgres(conn.into_inline_connection(r)),
+            Self::MySql(conn) => StorageSinkConnection::MySql(conn.into_inline_connection(r)),
+            Self::SqlServer(conn) => {
+                StorageSinkConnection::SqlServer(conn.into_inline_connection(r))
+            }
+            Self::S3(conn) => StorageSinkConnection::S3(conn.into_inline_connection(r)),
+            Self::Http(conn) => StorageSinkConnection::Http(conn),
         }
     }
 }
@@ -189,6 +227,14 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
                 catalog_connection_id: connection_id,
                 ..
             }) => Some(*connection_id),
+            Postgres(PostgresSinkConnection { connection_id, .. })
+            | MySql(MySqlSinkConnection { connection_id, .. })
+            | SqlServer(SqlServerSinkConnection { connection_id, .. }) => Some(*connection_id),
+            S3(S3SinkConnection {
+                aws_connection_id: connection_id,
+                ..
+            }) => Some(*connection_id),
+            Http(_) => None,
         }
     }
 
@@ -198,6 +244,11 @@ impl<C: ConnectionAccess> StorageSinkConnection<C> {
         match self {
             Kafka(_) => "kafka",
             Iceberg(_) => "iceberg",
+            Postgres(_) => "postgres",
+            MySql(_) => "mysql",
+            SqlServer(_) => "sql-server",
+            S3(_) => "s3",
+            Http(_) => "http",
         }
     }
 }
@@ -618,6 +669,29 @@ pub enum S3SinkFormat {
     PgCopy(CopyFormatParams<'static>),
     /// Encoded as Parquet.
     Parquet,
+    /// Encoded as Avro Object Container Files.
+    Avro,
+    /// Encoded as ORC.
+    Orc,
+}
+
+impl S3SinkFormat {
+    /// Returns the name of the format, as reported in the catalog.
+    pub fn name(&self) -> &str {
+        match self {
+            S3SinkFormat::PgCopy(params) => params.file_extension(),
+            S3SinkFormat::Parquet => "parquet",
+            S3SinkFormat::Avro => "avro",
+            S3SinkFormat::Orc => "orc",
+        }
+    }
+
+    /// Returns the generator of the schema with which rows of `desc` are written in the
+    /// [`S3SinkFormat::Avro`] format. The schema is the same as the value schema of a Kafka
+    /// sink with `FORMAT AVRO` and `ENVELOPE UPSERT`.
+    pub fn avro_schema_generator(desc: RelationDesc) -> Result<AvroSchemaGenerator, anyhow::Error> {
+        AvroSchemaGenerator::new(desc, false, BTreeMap::new(), "envelope", false, None, true)
+    }
 }
 
 /// Info required to copy the data to s3.
@@ -746,3 +820,511 @@ impl<R: ConnectionResolver> IntoInlineConnection<IcebergSinkConnection, R>
         }
     }
 }
+
+/// The name of the table in which a Postgres, MySQL, or SQL Server sink records
+/// its progress, if the user does not specify one.
+pub const DEFAULT_SINK_PROGRESS_TABLE: &str = "mz_sink_progress";
+
+/// Returns the key under which a sink that writes into an upstream database
+/// records its progress in the progress table.
+///
+/// The key embeds the environment ID so that sinks from different Materialize
+/// environments can share a progress table without clobbering each other's
+/// progress.
+fn upstream_sink_progress_key(
+    connection_context: &ConnectionContext,
+    connection_id: CatalogItemId,
+    sink_id: GlobalId,
+) -> String {
+    format!(
+        "materialize-{}-{}-{}",
+        connection_context.environment_id, connection_id, sink_id
+    )
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PostgresSinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub connection_id: CatalogItemId,
+    pub connection: C::Pg,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink.
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    /// The schema of the upstream table into which the sink is writing.
+    pub schema: String,
+    /// The upstream table into which the sink is writing.
+    pub table: String,
+    /// The upstream table, in the same schema as `table`, in which the sink
+    /// records the frontier up to which it has committed data.
+    pub progress_table: String,
+}
+
+impl PostgresSinkConnection {
+    /// Returns the key under which this sink records its progress in the
+    /// progress table.
+    pub fn progress_key(
+        &self,
+        connection_context: &ConnectionContext,
+        sink_id: GlobalId,
+    ) -> String {
+        upstream_sink_progress_key(connection_context, self.connection_id, sink_id)
+    }
+}
+
+impl<C: ConnectionAccess> PostgresSinkConnection<C> {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+        let PostgresSinkConnection {
+            connection_id,
+            connection,
+            relation_key_indices,
+            key_desc_and_indices,
+            schema,
+            table,
+            progress_table,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (
+                connection.alter_compatible(id, &other.connection).is_ok(),
+                "connection",
+            ),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (schema == &other.schema, "schema"),
+            (table == &other.table, "table"),
+            (progress_table == &other.progress_table, "progress_table"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "PostgresSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<PostgresSinkConnection, R>
+    for PostgresSinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> PostgresSinkConnection {
+        let PostgresSinkConnection {
+            connection_id,
+            connection,
+            relation_key_indices,
+            key_desc_and_indices,
+            schema,
+            table,
+            progress_table,
+        } = self;
+        PostgresSinkConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_pg(),
+            relation_key_indices,
+            key_desc_and_indices,
+            schema,
+            table,
+            progress_table,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct MySqlSinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub connection_id: CatalogItemId,
+    pub connection: C::MySql,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink.
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    /// The database of the upstream table into which the sink is writing.
+    pub database: String,
+    /// The upstream table into which the sink is writing.
+    pub table: String,
+    /// The upstream table, in the same database as `table`, in which the sink
+    /// records the frontier up to which it has committed data.
+    pub progress_table: String,
+}
+
+impl MySqlSinkConnection {
+    /// Returns the key under which this sink records its progress in the
+    /// progress table.
+    pub fn progress_key(
+        &self,
+        connection_context: &ConnectionContext,
+        sink_id: GlobalId,
+    ) -> String {
+        upstream_sink_progress_key(connection_context, self.connection_id, sink_id)
+    }
+}
+
+impl<C: ConnectionAccess> MySqlSinkConnection<C> {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+        let MySqlSinkConnection {
+            connection_id,
+            connection,
+            relation_key_indices,
+            key_desc_and_indices,
+            database,
+            table,
+            progress_table,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (
+                connection.alter_compatible(id, &other.connection).is_ok(),
+                "connection",
+            ),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (database == &other.database, "database"),
+            (table == &other.table, "table"),
+            (progress_table == &other.progress_table, "progress_table"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "MySqlSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<MySqlSinkConnection, R>
+    for MySqlSinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> MySqlSinkConnection {
+        let MySqlSinkConnection {
+            connection_id,
+            connection,
+            relation_key_indices,
+            key_desc_and_indices,
+            database,
+            table,
+            progress_table,
+        } = self;
+        MySqlSinkConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_mysql(),
+            relation_key_indices,
+            key_desc_and_indices,
+            database,
+            table,
+            progress_table,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SqlServerSinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub connection_id: CatalogItemId,
+    pub connection: C::SqlServer,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink.
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+    /// The schema of the upstream table into which the sink is writing.
+    pub schema: String,
+    /// The upstream table into which the sink is writing.
+    pub table: String,
+    /// The upstream table, in the same schema as `table`, in which the sink
+    /// records the frontier up to which it has committed data.
+    pub progress_table: String,
+}
+
+impl SqlServerSinkConnection {
+    /// Returns the key under which this sink records its progress in the
+    /// progress table.
+    pub fn progress_key(
+        &self,
+        connection_context: &ConnectionContext,
+        sink_id: GlobalId,
+    ) -> String {
+        upstream_sink_progress_key(connection_context, self.connection_id, sink_id)
+    }
+}
+
+impl<C: ConnectionAccess> SqlServerSinkConnection<C> {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+        let SqlServerSinkConnection {
+            connection_id,
+            connection,
+            relation_key_indices,
+            key_desc_and_indices,
+            schema,
+            table,
+            progress_table,
+        } = self;
+
+        let compatibility_checks = [
+            (connection_id == &other.connection_id, "connection_id"),
+            (
+                connection.alter_compatible(id, &other.connection).is_ok(),
+                "connection",
+            ),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+            (schema == &other.schema, "schema"),
+            (table == &other.table, "table"),
+            (progress_table == &other.progress_table, "progress_table"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "SqlServerSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<SqlServerSinkConnection, R>
+    for SqlServerSinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> SqlServerSinkConnection {
+        let SqlServerSinkConnection {
+            connection_id,
+            connection,
+            relation_key_indices,
+            key_desc_and_indices,
+            schema,
+            table,
+            progress_table,
+        } = self;
+        SqlServerSinkConnection {
+            connection_id,
+            connection: r.resolve_connection(connection).unwrap_sql_server(),
+            relation_key_indices,
+            key_desc_and_indices,
+            schema,
+            table,
+            progress_table,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct S3SinkConnection<C: ConnectionAccess = InlinedConnection> {
+    pub aws_connection_id: CatalogItemId,
+    pub aws_connection: C::Aws,
+    /// The S3 URI under which the sink writes its data files and manifest.
+    pub uri: String,
+    /// The format in which data files are written.
+    pub format: S3SinkFormat,
+    /// The max file size of each data file uploaded to S3.
+    pub max_file_size: u64,
+}
+
+impl S3SinkConnection {
+    /// Returns the [`S3UploadInfo`] used to write the data files of the batch
+    /// that starts at `lower`.
+    ///
+    /// Each batch is written under its own `{uri}/{lower}` prefix, so that the
+    /// files of a batch can be listed, and cleaned up after a failed attempt to
+    /// commit it, independently of every other batch. The lower of a batch
+    /// does not change when the sink restarts, unlike its upper.
+    pub fn batch_upload_info(&self, desc: RelationDesc, lower: mz_repr::Timestamp) -> S3UploadInfo {
+        S3UploadInfo {
+            uri: format!("{}/{}", self.uri.trim_end_matches('/'), lower),
+            max_file_size: self.max_file_size,
+            desc,
+            format: self.format.clone(),
+        }
+    }
+}
+
+impl<C: ConnectionAccess> S3SinkConnection<C> {
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+        let S3SinkConnection {
+            aws_connection_id,
+            aws_connection,
+            uri,
+            format,
+            max_file_size,
+        } = self;
+
+        let compatibility_checks = [
+            (
+                aws_connection_id == &other.aws_connection_id,
+                "aws_connection_id",
+            ),
+            (
+                aws_connection
+                    .alter_compatible(id, &other.aws_connection)
+                    .is_ok(),
+                "aws_connection",
+            ),
+            (uri == &other.uri, "uri"),
+            (format == &other.format, "format"),
+            (max_file_size == &other.max_file_size, "max_file_size"),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "S3SinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<R: ConnectionResolver> IntoInlineConnection<S3SinkConnection, R>
+    for S3SinkConnection<ReferencedConnection>
+{
+    fn into_inline_connection(self, r: R) -> S3SinkConnection {
+        let S3SinkConnection {
+            aws_connection_id,
+            aws_connection,
+            uri,
+            format,
+            max_file_size,
+        } = self;
+        S3SinkConnection {
+            aws_connection_id,
+            aws_connection: r.resolve_connection(aws_connection).unwrap_aws(),
+            uri,
+            format,
+            max_file_size,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct HttpSinkConnection {
+    /// The URL to which the sink posts its batches.
+    pub url: Url,
+    /// Additional headers sent with every request, whose values may be taken
+    /// from secrets.
+    pub headers: BTreeMap<String, StringOrSecret>,
+    /// A natural key of the sinked relation (view or source).
+    pub relation_key_indices: Option<Vec<usize>>,
+    /// The user-specified key for the sink.
+    pub key_desc_and_indices: Option<(RelationDesc, Vec<usize>)>,
+}
+
+impl HttpSinkConnection {
+    /// Returns the idempotency key of the request that delivers the updates of
+    /// the sink at timestamp `ts`.
+    ///
+    /// A sink delivers the updates at each timestamp in one request, and
+    /// delivers them again with the same key if it restarts before recording
+    /// its progress, which lets receivers discard the duplicates.
+    pub fn idempotency_key(sink_id: GlobalId, ts: mz_repr::Timestamp) -> String {
+        format!("{sink_id}-{ts}")
+    }
+
+    /// Determines if `self` is compatible with another `StorageSinkConnection`,
+    /// in such a way that it is possible to turn `self` into `other` through a
+    /// valid series of transformations (e.g. no transformation or `ALTER
+    /// CONNECTION`).
+    pub fn alter_compatible(&self, id: GlobalId, other: &Self) -> Result<(), AlterError> {
+        if self == other {
+            return Ok(());
+        }
+        let HttpSinkConnection {
+            url,
+            headers,
+            relation_key_indices,
+            key_desc_and_indices,
+        } = self;
+
+        let compatibility_checks = [
+            (url == &other.url, "url"),
+            (headers == &other.headers, "headers"),
+            (
+                relation_key_indices == &other.relation_key_indices,
+                "relation_key_indices",
+            ),
+            (
+                key_desc_and_indices == &other.key_desc_and_indices,
+                "key_desc_and_indices",
+            ),
+        ];
+        for (compatible, field) in compatibility_checks {
+            if !compatible {
+                tracing::warn!(
+                    "HttpSinkConnection incompatible at {field}:\nself:\n{:#?}\n\nother\n{:#?}",
+                    self,
+                    other
+                );
+
+                return Err(AlterError { id });
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -142,6 +142,18 @@ impl S3KeyManager {
         format!("{}INCOMPLETE", self.object_key_prefix)
     }
 
+    /// The S3 key to use for the manifest file, which continuous S3 sinks use
+    /// to record the batches they have committed.
+    pub fn manifest_key(&self) -> String {
+        format!("{}manifest.json", self.object_key_prefix)
+    }
+
+    /// The S3 key to use for a segment of the manifest file, which holds
+    /// batches that were committed before `upper`.
+    pub fn manifest_segment_key(&self, upper: u64) -> String {
+        format!("{}manifest-{}.json", self.object_key_prefix, upper)
+    }
+
     /// The key prefix based on the URI provided by the user. NOTE this doesn't
     /// contain the additional prefix we include on all keys written by the sink
     /// e.g. `mz-{sink_id}-batch-...`
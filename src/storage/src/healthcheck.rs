@@ -64,6 +64,8 @@ pub enum StatusNamespace {
     Upsert,
     Decode,
     Iceberg,
+    S3,
+    Http,
     Internal,
 }
 
@@ -87,6 +89,8 @@ impl fmt::Display for StatusNamespace {
             Decode => write!(f, "decode"),
             Internal => write!(f, "internal"),
             Iceberg => write!(f, "iceberg"),
+            S3 => write!(f, "s3"),
+            Http => write!(f, "http"),
         }
     }
 }
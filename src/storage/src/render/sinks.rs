@@ -18,13 +18,13 @@ use differential_dataflow::trace::implementations::ord_neu::{
 };
 use differential_dataflow::{AsCollection, Hashable, VecCollection};
 use mz_interchange::avro::DiffPair;
-use mz_interchange::envelopes::combine_at_timestamp;
+use mz_interchange::envelopes::{changelog_at_timestamp, combine_at_timestamp};
 use mz_persist_client::operators::shard_source::SnapshotMode;
 use mz_repr::{Datum, Diff, GlobalId, Row, Timestamp};
 use mz_storage_operators::persist_source;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::errors::DataflowError;
-use mz_storage_types::sinks::{StorageSinkConnection, StorageSinkDesc};
+use mz_storage_types::sinks::{SinkEnvelope, StorageSinkConnection, StorageSinkDesc};
 use mz_timely_util::builder_async::PressOnDropButton;
 use timely::dataflow::operators::Leave;
 use timely::dataflow::{Scope, StreamVec};
@@ -71,6 +71,7 @@ where
             timely::progress::Antichain::new(),
             None,
             None,
+            None,
             async {},
             error_handler,
         );
@@ -93,6 +94,9 @@ where
 
 /// Zip the input to a sink so that updates to the same key appear as
 /// `DiffPair`s.
+///
+/// With the changelog envelope, updates are not zipped together. Each update
+/// instead appears as its own `DiffPair`, carrying its timestamp and diff.
 fn zip_into_diff_pairs<G>(
     sink_id: GlobalId,
     sink: &StorageSinkDesc<CollectionMetadata, mz_repr::Timestamp>,
@@ -139,8 +143,15 @@ where
     // Allow access to `arrange_named` because we cannot access Mz's wrapper
     // from here. TODO(database-issues#5046): Revisit with cluster unification.
     #[allow(clippy::disallowed_methods)]
-    let mut collection =
-        combine_at_timestamp(collection.arrange_named::<ColValBatcher<_,_,_,_>, ColValBuilder<_,_,_,_>, ColValSpine<_, _, _, _>>("Arrange Sink"));
+    let arranged = collection.arrange_named::<ColValBatcher<_,_,_,_>, ColValBuilder<_,_,_,_>, ColValSpine<_, _, _, _>>("Arrange Sink");
+    // A key legitimately appears in several updates at the same timestamp in
+    // the changelog envelope, so there are no key violations to report.
+    let is_changelog = sink.envelope == SinkEnvelope::Changelog;
+    let mut collection = if is_changelog {
+        changelog_at_timestamp(arranged)
+    } else {
+        combine_at_timestamp(arranged)
+    };
 
     // If there is no user-specified key, remove the synthetic key.
     //
@@ -160,7 +171,7 @@ where
             // TODO: put the sink in a user-visible errored state instead of
             // only logging internally. See:
             // https://github.com/MaterializeInc/database-issues/issues/5099.
-            if !key_is_synthetic && vs.len() > 1 {
+            if !key_is_synthetic && !is_changelog && vs.len() > 1 {
                 // We rate limit how often we emit this warning to avoid
                 // flooding logs.
                 let now = Instant::now();
@@ -216,5 +227,10 @@ where
     match connection {
         StorageSinkConnection::Kafka(connection) => Box::new(connection.clone()),
         StorageSinkConnection::Iceberg(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::Postgres(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::MySql(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::SqlServer(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::S3(connection) => Box::new(connection.clone()),
+        StorageSinkConnection::Http(connection) => Box::new(connection.clone()),
     }
 }
@@ -307,6 +307,7 @@ where
                             SnapshotMode::Include,
                             Antichain::new(),
                             None,
+                            None,
                             flow_control,
                             false.then_some(|| unreachable!()),
                             async {},
@@ -9,5 +9,11 @@
 
 //! Moving data to external systems
 
+mod http;
 mod iceberg;
 mod kafka;
+mod mysql;
+mod postgres;
+mod s3;
+mod sql_server;
+mod upstream_table;
@@ -0,0 +1,324 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of an [`HttpSinkConnection`]. The dataflow consists of a
+//! single operator that posts the updates of each timestamp to a URL.
+//!
+//! # Requests
+//!
+//! All updates at a timestamp are delivered in one `POST` request, whose body is a JSON object of
+//! the form:
+//!
+//! ```json
+//! {"timestamp": "1700000000000", "updates": [{"key": {"id": 1}, "value": {"id": 1, "v": "a"}}]}
+//! ```
+//!
+//! The `key` of each update is only present if the sink has a key. In upsert mode the `value` of
+//! an update is the new value of its key, or `null` if the key was deleted. In debezium mode it is
+//! an object with the `before` and `after` values of the update. Rows are encoded in the same way
+//! as by Kafka sinks with `FORMAT JSON`.
+//!
+//! Requests that fail with a connection error, a timeout, `429 Too Many Requests` or a server
+//! error are retried with exponential backoff. Any other response halts the sink.
+//!
+//! # Delivery guarantees
+//!
+//! Unlike the sinks in [`super::upstream_table`], the receiver can't record the progress of the
+//! sink together with its updates. Instead the sink records its progress in the upper of its own
+//! persist shard, which only advances once all updates before it have been acknowledged. A sink
+//! that restarts resumes from that upper, and may therefore deliver the updates at some
+//! timestamps more than once. Every request carries an `Idempotency-Key` header that is derived
+//! from the ID of the sink and the timestamp of its updates, so that receivers can recognize and
+//! discard these duplicates.
+
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, anyhow};
+use differential_dataflow::VecCollection;
+use mz_interchange::avro::DiffPair;
+use mz_interchange::envelopes::dbz_format;
+use mz_interchange::json::JsonEncoder;
+use mz_ore::cast::CastFrom;
+use mz_ore::future::InTask;
+use mz_ore::netio::resolve_address;
+use mz_ore::retry::{Retry, RetryResult};
+use mz_persist_client::Diagnostics;
+use mz_persist_client::cache::PersistClientCache;
+use mz_persist_client::write::WriteHandle;
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::{Diff, GlobalId, RelationDesc, Row, Timestamp};
+use mz_storage_types::StorageDiff;
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::dyncfgs::{
+    ENFORCE_EXTERNAL_ADDRESSES, HTTP_SINK_REQUEST_TIMEOUT, HTTP_SINK_RETRY_MAX_DURATION,
+};
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{HttpSinkConnection, SinkEnvelope, StorageSinkDesc};
+use mz_storage_types::sources::SourceData;
+use mz_timely_util::builder_async::PressOnDropButton;
+use reqwest::StatusCode;
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue};
+use serde_json::json;
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+use tracing::warn;
+
+use crate::healthcheck::{HealthStatusMessage, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::sink::upstream_table::{SinkUpdate, UpstreamTableWriter, render_upstream_table_sink};
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The header that carries the idempotency key of each request.
+const IDEMPOTENCY_KEY: &str = "idempotency-key";
+
+impl<G: Scope<Timestamp = Timestamp>> SinkRender<G> for HttpSinkConnection {
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+        sink_id: GlobalId,
+        input: VecCollection<G, (Option<Row>, DiffPair<Row>), Diff>,
+        // TODO(benesch): errors should stream out through the sink,
+        // if we figure out a protocol for that.
+        _err_collection: VecCollection<G, DataflowError, Diff>,
+    ) -> (StreamVec<G, HealthStatusMessage>, Vec<PressOnDropButton>) {
+        let connection = self.clone();
+        let storage_configuration = storage_state.storage_configuration.clone();
+        let persist_clients = Arc::clone(&storage_state.persist_clients);
+        let shard_meta = sink.to_storage_metadata.clone();
+        let value_desc = sink.from_desc.clone();
+        let envelope = sink.envelope;
+        render_upstream_table_sink(
+            storage_state,
+            sink,
+            sink_id,
+            input,
+            StatusNamespace::Http,
+            move |statistics| async move {
+                let writer = HttpSinkWriter::new(
+                    sink_id,
+                    &connection,
+                    value_desc,
+                    envelope,
+                    &storage_configuration,
+                    statistics,
+                )
+                .await?;
+                let resume_upper = fetch_persist_upper(&persist_clients, shard_meta).await?;
+                Ok((writer, resume_upper))
+            },
+        )
+    }
+}
+
+/// Returns the upper of the sink's persist shard, which is where the sink records its progress.
+async fn fetch_persist_upper(
+    persist_clients: &PersistClientCache,
+    shard_meta: CollectionMetadata,
+) -> Result<Antichain<Timestamp>, anyhow::Error> {
+    let client = persist_clients.open(shard_meta.persist_location).await?;
+    let mut handle: WriteHandle<SourceData, (), Timestamp, StorageDiff> = client
+        .open_writer(
+            shard_meta.data_shard,
+            Arc::new(shard_meta.relation_desc),
+            Arc::new(UnitSchema),
+            Diagnostics::from_purpose("http sink resume upper"),
+        )
+        .await?;
+    Ok(handle.fetch_recent_upper().await.clone())
+}
+
+/// A client that delivers the updates of a sink to an HTTP endpoint.
+struct HttpSinkWriter {
+    /// The ID of the sink, from which the idempotency keys of its requests are derived.
+    sink_id: GlobalId,
+    /// The client used to send requests, with the sink's custom headers as default headers.
+    client: reqwest::Client,
+    /// The URL to which requests are sent.
+    url: reqwest::Url,
+    /// Whether updates are delivered as upserts or as debezium-style diffs.
+    envelope: SinkEnvelope,
+    /// The encoder for the key of each update, if the sink has a key.
+    key_encoder: Option<JsonEncoder>,
+    /// The encoder for the value of each update.
+    value_encoder: JsonEncoder,
+    /// For how long to retry delivering a batch before giving up.
+    retry_max_duration: Duration,
+    /// A handle to the statistics associated with this sink.
+    statistics: SinkStatistics,
+}
+
+impl HttpSinkWriter {
+    async fn new(
+        sink_id: GlobalId,
+        connection: &HttpSinkConnection,
+        value_desc: RelationDesc,
+        envelope: SinkEnvelope,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+    ) -> Result<Self, anyhow::Error> {
+        let secrets_reader = &storage_configuration.connection_context.secrets_reader;
+        let mut headers = HeaderMap::new();
+        for (name, value) in &connection.headers {
+            let name = HeaderName::from_str(name)
+                .with_context(|| format!("invalid header name {name}"))?;
+            let value = value.get_string(InTask::Yes, secrets_reader).await?;
+            let mut value = HeaderValue::from_str(&value)
+                .with_context(|| format!("invalid value for header {name}"))?;
+            value.set_sensitive(true);
+            headers.insert(name, value);
+        }
+
+        let config_set = storage_configuration.config_set();
+        let url = connection.url.clone();
+        let host = url.host_str().ok_or_else(|| anyhow!("url missing host"))?;
+        // Ensure the host we connect to is resolved to an external address.
+        let resolved = resolve_address(host, ENFORCE_EXTERNAL_ADDRESSES.get(config_set)).await?;
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(HTTP_SINK_REQUEST_TIMEOUT.get(config_set))
+            .resolve_to_addrs(
+                host,
+                &resolved
+                    .iter()
+                    .map(|addr| SocketAddr::new(*addr, 0))
+                    .collect::<Vec<_>>(),
+            )
+            .build()?;
+
+        let key_encoder = connection
+            .key_desc_and_indices
+            .as_ref()
+            .map(|(desc, _indices)| JsonEncoder::new(desc.clone(), false));
+        let value_encoder = JsonEncoder::new(value_desc, envelope == SinkEnvelope::Debezium);
+
+        Ok(HttpSinkWriter {
+            sink_id,
+            client,
+            url,
+            envelope,
+            key_encoder,
+            value_encoder,
+            retry_max_duration: HTTP_SINK_RETRY_MAX_DURATION.get(config_set),
+            statistics,
+        })
+    }
+
+    /// Encodes `updates` into the body of the request that delivers them.
+    fn encode_body(&self, ts: Timestamp, updates: &[SinkUpdate]) -> String {
+        let mut row_buf = Row::default();
+        let updates: Vec<_> = updates
+            .iter()
+            .map(|((key, diff_pair), _time, diff)| {
+                assert_eq!(*diff, Diff::ONE, "invalid sink update");
+                let mut update = serde_json::Map::new();
+                if let Some(key_encoder) = &self.key_encoder {
+                    let key = key.as_ref().expect("keyed sinks produce keys");
+                    update.insert("key".into(), key_encoder.encode_value(key));
+                }
+                let value = match self.envelope {
+                    SinkEnvelope::Upsert => diff_pair.after.as_ref(),
+                    SinkEnvelope::Debezium => {
+                        dbz_format(&mut row_buf.packer(), diff_pair.clone());
+                        Some(&row_buf)
+                    }
+                    SinkEnvelope::Append | SinkEnvelope::Changelog => {
+                        unreachable!("HTTP sinks do not support the {:?} envelope", self.envelope)
+                    }
+                };
+                let value = value.map_or(serde_json::Value::Null, |value| {
+                    self.value_encoder.encode_value(value)
+                });
+                update.insert("value".into(), value);
+                serde_json::Value::Object(update)
+            })
+            .collect();
+        json!({
+            "timestamp": ts.to_string(),
+            "updates": updates,
+        })
+        .to_string()
+    }
+}
+
+impl UpstreamTableWriter for HttpSinkWriter {
+    async fn commit(
+        &mut self,
+        updates: &[SinkUpdate],
+        _upper: Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        // The progress of the sink is recorded in persist, so there is nothing to deliver for
+        // timestamps without updates.
+        let Some((_, ts, _)) = updates.first() else {
+            return Ok(());
+        };
+        let body = self.encode_body(*ts, updates);
+        let idempotency_key = HttpSinkConnection::idempotency_key(self.sink_id, *ts);
+
+        let staged_messages = u64::cast_from(updates.len());
+        let staged_bytes = u64::cast_from(body.len());
+        self.statistics.inc_messages_staged_by(staged_messages);
+        self.statistics.inc_bytes_staged_by(staged_bytes);
+
+        Retry::default()
+            .clamp_backoff(Duration::from_secs(30))
+            .max_duration(self.retry_max_duration)
+            .retry_async(|_| async {
+                let response = self
+                    .client
+                    .post(self.url.clone())
+                    .header(CONTENT_TYPE, "application/json")
+                    .header(IDEMPOTENCY_KEY, &idempotency_key)
+                    .body(body.clone())
+                    .send()
+                    .await;
+                match response {
+                    Ok(response) if response.status().is_success() => RetryResult::Ok(()),
+                    Ok(response) => {
+                        let status = response.status();
+                        let err = anyhow!("delivering updates at {ts} failed: {status}");
+                        if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+                            warn!("{}: {err}, retrying", self.sink_id);
+                            RetryResult::RetryableErr(err)
+                        } else {
+                            RetryResult::FatalErr(err)
+                        }
+                    }
+                    Err(e) => {
+                        let err = anyhow::Error::new(e)
+                            .context(format!("delivering updates at {ts} failed"));
+                        warn!("{}: {err:#}, retrying", self.sink_id);
+                        RetryResult::RetryableErr(err)
+                    }
+                }
+            })
+            .await?;
+
+        fail::fail_point!("http_sink_commit_request");
+
+        self.statistics.inc_messages_committed_by(staged_messages);
+        self.statistics.inc_bytes_committed_by(staged_bytes);
+        Ok(())
+    }
+}
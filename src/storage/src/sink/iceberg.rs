@@ -60,7 +60,8 @@
 //! It matches rows to batches by timestamp; if a batch description hasn't arrived yet,
 //! rows are stashed until it does. This allows batches to be minted ahead of data arrival.
 //! The operator uses an Iceberg `DeltaWriter` to write Parquet data files
-//! (and position delete files if necessary) to object storage.
+//! (and position delete files if necessary) to object storage. Sinks in
+//! `MODE CHANGELOG` only ever append, and use a plain `DataFileWriter` instead.
 //! It outputs metadata about the written files along with their batch descriptions
 //! for the commit operator to consume.
 //!
@@ -112,6 +113,7 @@ use iceberg::{Catalog, NamespaceIdent, TableCreation, TableIdent};
 use itertools::Itertools;
 use mz_arrow_util::builder::{ARROW_EXTENSION_NAME_KEY, ArrowBuilder};
 use mz_interchange::avro::DiffPair;
+use mz_interchange::envelopes::changelog_desc;
 use mz_ore::cast::CastFrom;
 use mz_ore::error::ErrorExt;
 use mz_ore::future::InTask;
@@ -125,7 +127,7 @@ use mz_storage_types::StorageDiff;
 use mz_storage_types::configuration::StorageConfiguration;
 use mz_storage_types::controller::CollectionMetadata;
 use mz_storage_types::errors::DataflowError;
-use mz_storage_types::sinks::{IcebergSinkConnection, StorageSinkDesc};
+use mz_storage_types::sinks::{IcebergSinkConnection, SinkEnvelope, StorageSinkDesc};
 use mz_storage_types::sources::SourceData;
 use mz_timely_util::antichain::AntichainExt;
 use mz_timely_util::builder_async::{Event, OperatorBuilder, PressOnDropButton};
@@ -161,8 +163,11 @@ const PARQUET_FILE_PREFIX: &str = "mz_data";
 /// many batches we have in-flight at any given time.
 const INITIAL_DESCRIPTIONS_TO_MINT: u64 = 3;
 
+type DataFileWriterType =
+    DataFileWriter<ParquetWriterBuilder, DefaultLocationGenerator, DefaultFileNameGenerator>;
+
 type DeltaWriterType = DeltaWriter<
-    DataFileWriter<ParquetWriterBuilder, DefaultLocationGenerator, DefaultFileNameGenerator>,
+    DataFileWriterType,
     PositionDeleteFileWriter<
         ParquetWriterBuilder,
         DefaultLocationGenerator,
@@ -175,6 +180,30 @@ type DeltaWriterType = DeltaWriter<
     >,
 >;
 
+/// Writes the updates of a single batch to Iceberg data and delete files.
+enum BatchWriter {
+    /// Writes upserts, retracting earlier versions of each key with delete files.
+    Delta(DeltaWriterType),
+    /// Writes changelog updates, which are only ever appended.
+    Append(DataFileWriterType),
+}
+
+impl BatchWriter {
+    async fn write(&mut self, batch: RecordBatch) -> iceberg::Result<()> {
+        match self {
+            BatchWriter::Delta(writer) => writer.write(batch).await,
+            BatchWriter::Append(writer) => writer.write(batch).await,
+        }
+    }
+
+    async fn close(&mut self) -> iceberg::Result<Vec<DataFile>> {
+        match self {
+            BatchWriter::Delta(writer) => writer.close().await,
+            BatchWriter::Append(writer) => writer.close().await,
+        }
+    }
+}
+
 /// The precision needed to store all UInt64 values in a Decimal128.
 /// UInt64 max value is 18,446,744,073,709,551,615 which has 20 digits.
 const ICEBERG_UINT64_DECIMAL_PRECISION: u8 = 20;
@@ -721,10 +750,13 @@ fn build_schema_with_op_column(schema: &ArrowSchema) -> ArrowSchema {
 /// Convert a Materialize DiffPair into an Arrow RecordBatch with an __op column.
 /// The __op column indicates whether each row is an insert (1) or delete (-1), which
 /// the DeltaWriter uses to generate the appropriate Iceberg data/delete files.
+///
+/// If `schema_with_op` is `None`, the batch is written by an append-only writer and
+/// no __op column is added.
 fn row_to_recordbatch(
     row: DiffPair<Row>,
     schema: ArrowSchemaRef,
-    schema_with_op: ArrowSchemaRef,
+    schema_with_op: Option<ArrowSchemaRef>,
 ) -> anyhow::Result<RecordBatch> {
     let mut builder = ArrowBuilder::new_with_schema(
         Arc::clone(&schema),
@@ -751,6 +783,9 @@ fn row_to_recordbatch(
     let batch = builder
         .to_record_batch()
         .context("Failed to create record batch")?;
+    let Some(schema_with_op) = schema_with_op else {
+        return Ok(batch);
+    };
 
     let mut columns: Vec<ArrayRef> = batch.columns().to_vec();
     let op_column = Arc::new(Int32Array::from(op_values));
@@ -1195,6 +1230,7 @@ fn write_data_files<G>(
     table_ready_stream: StreamVec<G, Infallible>,
     as_of: Antichain<Timestamp>,
     connection: IcebergSinkConnection,
+    envelope: SinkEnvelope,
     storage_configuration: StorageConfiguration,
     materialize_arrow_schema: Arc<ArrowSchema>,
     metrics: Arc<IcebergSinkMetrics>,
@@ -1261,8 +1297,10 @@ where
                 .context("Failed to merge Materialize metadata into Iceberg schema")?,
             );
 
-            // Build schema_with_op by adding the __op column used by DeltaWriter.
-            let schema_with_op = Arc::new(build_schema_with_op_column(&arrow_schema));
+            // Build schema_with_op by adding the __op column used by DeltaWriter. Changelog
+            // updates are written without it.
+            let schema_with_op = (envelope != SinkEnvelope::Changelog)
+                .then(|| Arc::new(build_schema_with_op_column(&arrow_schema)));
 
             // WORKAROUND: S3 Tables catalog incorrectly sets location to the metadata file path
             // instead of the warehouse root. Strip off the /metadata/*.metadata.json suffix.
@@ -1286,51 +1324,58 @@ where
 
             let file_io = table.file_io().clone();
 
-            let Some((_, equality_indices)) = connection.key_desc_and_indices else {
-                return Err(anyhow::anyhow!(
-                    "Iceberg sink requires key columns for equality deletes"
-                ));
-            };
+            // Upserts are written with a DeltaWriter, which retracts earlier versions of each
+            // key with delete files. Changelog updates are only ever appended, and so are
+            // written as plain data files, without the need for a key.
+            let delta_config = match envelope {
+                SinkEnvelope::Changelog => None,
+                _ => {
+                    let Some((_, equality_indices)) = connection.key_desc_and_indices else {
+                        return Err(anyhow::anyhow!(
+                            "Iceberg sink requires key columns for equality deletes"
+                        ));
+                    };
 
-            let equality_ids = equality_ids_for_indices(
-                current_schema.as_ref(),
-                materialize_arrow_schema.as_ref(),
-                &equality_indices,
-            )?;
+                    let equality_ids = equality_ids_for_indices(
+                        current_schema.as_ref(),
+                        materialize_arrow_schema.as_ref(),
+                        &equality_indices,
+                    )?;
 
-            let writer_properties = WriterProperties::new();
+                    // Precompute schemas that don't change between writer instances.
+                    let pos_arrow_schema = PositionDeleteWriterConfig::arrow_schema();
+                    let pos_schema = Arc::new(arrow_schema_to_schema(&pos_arrow_schema).context(
+                        "Failed to convert position delete Arrow schema to Iceberg schema",
+                    )?);
+
+                    let eq_config = EqualityDeleteWriterConfig::new(
+                        equality_ids.clone(),
+                        Arc::clone(&current_schema),
+                    )
+                    .context("Failed to create EqualityDeleteWriterConfig")?;
+                    let eq_schema = Arc::new(
+                        arrow_schema_to_schema(eq_config.projected_arrow_schema_ref()).context(
+                            "Failed to convert equality delete Arrow schema to Iceberg schema",
+                        )?,
+                    );
 
-            // Precompute schemas that don't change between writer instances.
-            let pos_arrow_schema = PositionDeleteWriterConfig::arrow_schema();
-            let pos_schema = Arc::new(arrow_schema_to_schema(&pos_arrow_schema).context(
-                "Failed to convert position delete Arrow schema to Iceberg schema",
-            )?);
+                    Some((equality_ids, pos_schema, eq_schema, eq_config))
+                }
+            };
 
-            let eq_config = EqualityDeleteWriterConfig::new(
-                equality_ids.clone(),
-                Arc::clone(&current_schema),
-            )
-            .context("Failed to create EqualityDeleteWriterConfig")?;
-            let eq_schema = Arc::new(
-                arrow_schema_to_schema(eq_config.projected_arrow_schema_ref()).context(
-                    "Failed to convert equality delete Arrow schema to Iceberg schema",
-                )?,
-            );
+            let writer_properties = WriterProperties::new();
 
             let arrow_schema_for_closure = Arc::clone(&arrow_schema);
             let current_schema_for_closure = Arc::clone(&current_schema);
 
-            let create_delta_writer = move |disable_seen_rows: bool| {
+            let create_batch_writer = move |disable_seen_rows: bool| {
                 let arrow_schema = Arc::clone(&arrow_schema_for_closure);
                 let current_schema = Arc::clone(&current_schema_for_closure);
                 let file_io = file_io.clone();
                 let location_generator = location_generator.clone();
                 let file_name_generator = file_name_generator.clone();
-                let equality_ids = equality_ids.clone();
                 let writer_properties = writer_properties.clone();
-                let pos_schema = Arc::clone(&pos_schema);
-                let eq_schema = Arc::clone(&eq_schema);
-                let eq_config = eq_config.clone();
+                let delta_config = delta_config.clone();
 
                 async move {
                     let data_parquet_writer = ParquetWriterBuilder::new(
@@ -1348,6 +1393,15 @@ where
                     );
                     let data_writer_builder = DataFileWriterBuilder::new(data_rolling_writer);
 
+                    let Some((equality_ids, pos_schema, eq_schema, eq_config)) = delta_config
+                    else {
+                        let writer = data_writer_builder
+                            .build(None)
+                            .await
+                            .context("Failed to create DataFileWriter")?;
+                        return Ok::<_, anyhow::Error>(BatchWriter::Append(writer));
+                    };
+
                     let pos_config = PositionDeleteWriterConfig::new(None, 0, None);
                     let pos_parquet_writer =
                         ParquetWriterBuilder::new(writer_properties.clone(), pos_schema);
@@ -1377,20 +1431,20 @@ where
                         data_writer_builder,
                         pos_delete_writer_builder,
                         eq_delete_writer_builder,
-                        equality_ids.clone(),
+                        equality_ids,
                     );
 
                     if disable_seen_rows {
                         builder = builder.with_max_seen_rows(0);
                     }
 
-                    builder
+                    let writer = builder
                         .build(None)
                         .await
-                        .context("Failed to create DeltaWriter")
+                        .context("Failed to create DeltaWriter")?;
+                    Ok::<_, anyhow::Error>(BatchWriter::Delta(writer))
                 }
             };
-
             // Rows can arrive before their batch description due to dataflow parallelism.
             // Stash them until we know which batch they belong to.
             let mut stashed_rows: BTreeMap<Timestamp, Vec<(Option<Row>, DiffPair<Row>)>> =
@@ -1403,7 +1457,7 @@ where
             #[allow(clippy::disallowed_types)]
             let mut in_flight_batches: std::collections::HashMap<
                 (Antichain<Timestamp>, Antichain<Timestamp>),
-                Box<DeltaWriterType>,
+                Box<BatchWriter>,
             > = std::collections::HashMap::new();
 
             let mut batch_description_frontier = Antichain::from_elem(Timestamp::minimum());
@@ -1474,7 +1528,7 @@ where
                                     upper.pretty(),
                                     is_snapshot
                                 );
-                                let mut delta_writer = create_delta_writer(is_snapshot).await?;
+                                let mut batch_writer = create_batch_writer(is_snapshot).await?;
                                 // Drain any stashed rows that belong to this batch
                                 let row_ts_keys: Vec<_> = stashed_rows.keys().cloned().collect();
                                 let mut drained_count = 0;
@@ -1490,11 +1544,11 @@ where
                                                 let record_batch = row_to_recordbatch(
                                                     diff_pair.clone(),
                                                     Arc::clone(&arrow_schema),
-                                                    Arc::clone(&schema_with_op),
+                                                    schema_with_op.clone(),
                                                 )
                                                 .context("failed to convert row to recordbatch")?;
-                                                delta_writer.write(record_batch).await.context(
-                                                    "Failed to write row to DeltaWriter",
+                                                batch_writer.write(record_batch).await.context(
+                                                    "Failed to write row to Iceberg writer",
                                                 )?;
                                                 staged_messages_since_flush += 1;
                                                 if staged_messages_since_flush >= 10_000 {
@@ -1518,7 +1572,7 @@ where
                                 }
                                 let prev =
                                     in_flight_batches.insert(
-                                        batch_desc.clone(), Box::new(delta_writer)
+                                        batch_desc.clone(), Box::new(batch_writer)
                                     );
                                 if prev.is_some() {
                                     anyhow::bail!(
@@ -1545,7 +1599,7 @@ where
                                 let ts_antichain = Antichain::from_elem(row_ts.clone());
                                 let mut written = false;
                                 // Try writing the row to any in-flight batch it belongs to...
-                                for (batch_desc, delta_writer) in in_flight_batches.iter_mut() {
+                                for (batch_desc, batch_writer) in in_flight_batches.iter_mut() {
                                     let (lower, upper) = batch_desc;
                                     if PartialOrder::less_equal(lower, &ts_antichain)
                                         && PartialOrder::less_than(&ts_antichain, upper)
@@ -1553,13 +1607,13 @@ where
                                         let record_batch = row_to_recordbatch(
                                             diff_pair.clone(),
                                             Arc::clone(&arrow_schema),
-                                            Arc::clone(&schema_with_op),
+                                            schema_with_op.clone(),
                                         )
                                         .context("failed to convert row to recordbatch")?;
-                                        delta_writer
+                                        batch_writer
                                             .write(record_batch)
                                             .await
-                                            .context("Failed to write row to DeltaWriter")?;
+                                            .context("Failed to write row to Iceberg writer")?;
                                         staged_messages_since_flush += 1;
                                         if staged_messages_since_flush >= 10_000 {
                                             statistics.inc_messages_staged_by(
@@ -1640,13 +1694,13 @@ where
                             input_frontier.pretty()
                         );
                         let mut max_upper = Antichain::from_elem(Timestamp::minimum());
-                        for (desc, mut delta_writer) in ready_batches {
+                        for (desc, mut batch_writer) in ready_batches {
                             let close_started_at = Instant::now();
-                            let data_files = delta_writer.close().await;
+                            let data_files = batch_writer.close().await;
                             metrics
                                 .writer_close_duration_seconds
                                 .observe(close_started_at.elapsed().as_secs_f64());
-                            let data_files = data_files.context("Failed to close DeltaWriter")?;
+                            let data_files = data_files.context("Failed to close Iceberg writer")?;
                             debug!(
                                 "{}: closed batch [{}, {}), wrote {} files",
                                 name_for_logging,
@@ -2120,22 +2174,26 @@ impl<G: Scope<Timestamp = Timestamp>> SinkRender<G> for IcebergSinkConnection {
             .sink_write_frontiers
             .insert(sink_id, Rc::clone(&write_frontier));
 
-        let (arrow_schema_with_ids, iceberg_schema) =
-            match relation_desc_to_iceberg_schema(&sink.from_desc) {
-                Ok(schemas) => schemas,
-                Err(err) => {
-                    let error_stream = std::iter::once(HealthStatusMessage {
-                        id: None,
-                        update: HealthStatusUpdate::halting(
-                            format!("{}", err.display_with_causes()),
-                            None,
-                        ),
-                        namespace: StatusNamespace::Iceberg,
-                    })
-                    .to_stream(&mut scope);
-                    return (error_stream, vec![]);
-                }
-            };
+        // The changelog mode appends the timestamp and diff of each update to its row.
+        let desc = match sink.envelope {
+            SinkEnvelope::Changelog => changelog_desc(&sink.from_desc),
+            _ => sink.from_desc.clone(),
+        };
+        let (arrow_schema_with_ids, iceberg_schema) = match relation_desc_to_iceberg_schema(&desc) {
+            Ok(schemas) => schemas,
+            Err(err) => {
+                let error_stream = std::iter::once(HealthStatusMessage {
+                    id: None,
+                    update: HealthStatusUpdate::halting(
+                        format!("{}", err.display_with_causes()),
+                        None,
+                    ),
+                    namespace: StatusNamespace::Iceberg,
+                })
+                .to_stream(&mut scope);
+                return (error_stream, vec![]);
+            }
+        };
 
         let metrics = Arc::new(
             storage_state
@@ -2169,6 +2227,7 @@ impl<G: Scope<Timestamp = Timestamp>> SinkRender<G> for IcebergSinkConnection {
             table_ready.clone(),
             sink.as_of.clone(),
             connection_for_writer,
+            sink.envelope,
             storage_state.storage_configuration.clone(),
             Arc::new(arrow_schema_with_ids.clone()),
             Arc::clone(&metrics),
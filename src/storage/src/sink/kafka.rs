@@ -1514,11 +1514,16 @@ fn encode_collection<G: Scope>(
                             None => (None, hash.unwrap_or(0))
                         };
                         let value = match envelope {
-                            SinkEnvelope::Upsert => value.after,
+                            // Changelog updates carry their timestamp and diff
+                            // in the `after` row.
+                            SinkEnvelope::Upsert | SinkEnvelope::Changelog => value.after,
                             SinkEnvelope::Debezium => {
                                 dbz_format(&mut row_buf.packer(), value);
                                 Some(row_buf.clone())
                             }
+                            SinkEnvelope::Append => {
+                                unreachable!("kafka sinks do not support the append envelope")
+                            }
                         };
                         let value = value.map(|value| value_encoder.encode_unchecked(value));
                         let message = KafkaMessage {
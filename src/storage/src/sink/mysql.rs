@@ -0,0 +1,426 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of a [`MySqlSinkConnection`]. The dataflow consists of a
+//! single operator that writes into an existing table of an upstream MySQL database.
+//!
+//! # Applying updates
+//!
+//! All updates at a timestamp are applied in one upstream transaction, using multi-row
+//! statements to keep the number of round trips low.
+//!
+//! In upsert mode the rows of all deleted keys are deleted from the target table and the new
+//! values of all other updated keys are written with `INSERT ... ON DUPLICATE KEY UPDATE`. This
+//! requires the target table to have a primary key or unique index on the columns of the sink's
+//! key. In append mode the new value of every update is inserted and deletions are ignored.
+//!
+//! # Exactly once
+//!
+//! Each transaction starts by locking the sink's row in the progress table and checking that it
+//! still contains the expected upper and sink version, and ends by advancing it to the new upper.
+//! See [`super::upstream_table`] for how this is used to resume without duplicates.
+
+use anyhow::{Context, anyhow, bail};
+use differential_dataflow::VecCollection;
+use itertools::Itertools;
+use mysql_async::prelude::Queryable;
+use mysql_async::{TxOpts, Value};
+use mz_interchange::avro::DiffPair;
+use mz_mysql_util::{MySqlConn, quote_identifier};
+use mz_ore::cast::CastFrom;
+use mz_ore::future::InTask;
+use mz_repr::{Diff, GlobalId, RelationDesc, Row, Timestamp};
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{MySqlSinkConnection, SinkEnvelope, StorageSinkDesc};
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::PressOnDropButton;
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+
+use crate::healthcheck::{HealthStatusMessage, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::sink::upstream_table::{
+    SinkUpdate, UpstreamTableWriter, UpstreamValue, decode_upper, encode_upper,
+    render_upstream_table_sink,
+};
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The maximum number of placeholders MySQL allows in a prepared statement.
+const MAX_PARAMS: usize = 65_535;
+
+impl<G: Scope<Timestamp = Timestamp>> SinkRender<G> for MySqlSinkConnection {
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+        sink_id: GlobalId,
+        input: VecCollection<G, (Option<Row>, DiffPair<Row>), Diff>,
+        // TODO(benesch): errors should stream out through the sink,
+        // if we figure out a protocol for that.
+        _err_collection: VecCollection<G, DataflowError, Diff>,
+    ) -> (StreamVec<G, HealthStatusMessage>, Vec<PressOnDropButton>) {
+        let connection = self.clone();
+        let storage_configuration = storage_state.storage_configuration.clone();
+        let value_desc = sink.from_desc.clone();
+        let envelope = sink.envelope;
+        let sink_version = sink.version;
+        render_upstream_table_sink(
+            storage_state,
+            sink,
+            sink_id,
+            input,
+            StatusNamespace::MySql,
+            move |statistics| async move {
+                MySqlSinkWriter::new(
+                    sink_id,
+                    &connection,
+                    &value_desc,
+                    envelope,
+                    &storage_configuration,
+                    statistics,
+                    sink_version,
+                )
+                .await
+            },
+        )
+    }
+}
+
+/// A connection to the upstream database that transactionally applies the updates of a sink
+/// together with its progress.
+struct MySqlSinkWriter {
+    /// The connection to the upstream database.
+    conn: MySqlConn,
+    /// The quoted, fully qualified name of the target table.
+    table: String,
+    /// The quoted, fully qualified name of the progress table.
+    progress_table: String,
+    /// The key of this sink's row in the progress table.
+    progress_key: String,
+    /// The version of this sink, used to fence out previous versions from writing.
+    sink_version: i64,
+    /// The upper recorded in the progress table, as of the last successful commit.
+    committed_upper: Antichain<Timestamp>,
+    /// Whether updates are upserted into or appended to the target table.
+    envelope: SinkEnvelope,
+    /// The description of the key of each update, if any.
+    key_desc: Option<RelationDesc>,
+    /// The description of the value of each update.
+    value_desc: RelationDesc,
+    /// A handle to the statistics associated with this sink.
+    statistics: SinkStatistics,
+}
+
+impl MySqlSinkWriter {
+    /// Connects to the upstream database, validates the target table and registers this sink
+    /// version in the progress table. After this call returns it is guaranteed that all previous
+    /// versions of this sink have been fenced out.
+    async fn new(
+        sink_id: GlobalId,
+        connection: &MySqlSinkConnection,
+        value_desc: &RelationDesc,
+        envelope: SinkEnvelope,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+        sink_version: u64,
+    ) -> Result<(Self, Antichain<Timestamp>), anyhow::Error> {
+        let sink_version = i64::try_from(sink_version).context("sink version out of range")?;
+        let key_desc = connection
+            .key_desc_and_indices
+            .as_ref()
+            .map(|(desc, _indices)| desc.clone());
+        if envelope == SinkEnvelope::Upsert && key_desc.is_none() {
+            bail!("mysql sinks in upsert mode require a key");
+        }
+
+        let config = connection
+            .connection
+            .config(
+                &storage_configuration.connection_context.secrets_reader,
+                storage_configuration,
+                InTask::Yes,
+            )
+            .await?;
+        let mut conn = config
+            .connect(
+                "mysql sink",
+                &storage_configuration.connection_context.ssh_tunnel_manager,
+            )
+            .await?;
+
+        // Timestamps with time zones are sent as UTC wall clock times, so make sure that the
+        // upstream interprets them as such when writing into TIMESTAMP columns.
+        conn.query_drop("SET @@session.time_zone = '+00:00'")
+            .await?;
+
+        let database = quote_identifier(&connection.database);
+        let table = format!("{database}.{}", quote_identifier(&connection.table));
+        let progress_table = format!(
+            "{database}.{}",
+            quote_identifier(&connection.progress_table)
+        );
+
+        // To handle columns that were dropped or renamed upstream with a useful error, we check
+        // that every column of the sinked relation exists in the target table. The types are
+        // checked implicitly by the upstream when coercing the values we send.
+        let upstream_columns: Vec<String> = conn
+            .exec(
+                "SELECT column_name FROM information_schema.columns \
+                 WHERE table_schema = ? AND table_name = ?",
+                (&connection.database, &connection.table),
+            )
+            .await
+            .with_context(|| format!("looking up columns of upstream table {table}"))?;
+        if upstream_columns.is_empty() {
+            bail!("upstream table {table} does not exist");
+        }
+        for name in value_desc.iter_names() {
+            // MySQL column names are case insensitive.
+            if !upstream_columns
+                .iter()
+                .any(|c| c.eq_ignore_ascii_case(name.as_str()))
+            {
+                bail!("column {name} does not exist in upstream table {table}");
+            }
+        }
+
+        conn.query_drop(format!(
+            "CREATE TABLE IF NOT EXISTS {progress_table} (
+                sink_id VARCHAR(255) NOT NULL PRIMARY KEY,
+                version BIGINT NOT NULL,
+                upper DECIMAL(20,0) NULL
+            )"
+        ))
+        .await
+        .context("preparing upstream progress table")?;
+
+        let progress_key =
+            connection.progress_key(&storage_configuration.connection_context, sink_id);
+
+        // Register our version, fencing out any previous versions of this sink.
+        let mut txn = conn.start_transaction(TxOpts::default()).await?;
+        txn.exec_drop(
+            format!(
+                "INSERT IGNORE INTO {progress_table} (sink_id, version, upper) VALUES (?, ?, 0)"
+            ),
+            (&progress_key, sink_version),
+        )
+        .await?;
+        let (version, upper): (i64, Option<String>) = txn
+            .exec_first(
+                format!(
+                    "SELECT version, CAST(upper AS CHAR) FROM {progress_table} \
+                     WHERE sink_id = ? FOR UPDATE"
+                ),
+                (&progress_key,),
+            )
+            .await?
+            .ok_or_else(|| anyhow!("progress row for {progress_key} disappeared"))?;
+        if version > sink_version {
+            bail!(
+                "sink was fenced out by a newer version: current version {sink_version}, \
+                 upstream version {version}"
+            );
+        }
+        let resume_upper = decode_upper(upper)?;
+        txn.exec_drop(
+            format!("UPDATE {progress_table} SET version = ? WHERE sink_id = ?"),
+            (sink_version, &progress_key),
+        )
+        .await?;
+        txn.commit().await?;
+
+        let writer = MySqlSinkWriter {
+            conn,
+            table,
+            progress_table,
+            progress_key,
+            sink_version,
+            committed_upper: resume_upper.clone(),
+            envelope,
+            key_desc,
+            value_desc: value_desc.clone(),
+            statistics,
+        };
+        Ok((writer, resume_upper))
+    }
+
+    /// Returns the statements, along with their parameters, that delete the rows with the given
+    /// keys from the target table.
+    fn delete_stmts(&self, keys: &[&Row]) -> Vec<(String, Vec<Value>)> {
+        if keys.is_empty() {
+            return vec![];
+        }
+        let key_desc = self.key_desc.as_ref().expect("deletes require a key");
+        // Keys can contain NULLs, so compare them with the null-safe equality operator.
+        let key_predicate = format!(
+            "({})",
+            key_desc
+                .iter_names()
+                .map(|c| format!("{} <=> ?", quote_identifier(c.as_str())))
+                .join(" AND ")
+        );
+        let rows_per_stmt = MAX_PARAMS / key_desc.arity().max(1);
+        keys.chunks(rows_per_stmt)
+            .map(|chunk| {
+                let stmt = format!(
+                    "DELETE FROM {} WHERE {}",
+                    self.table,
+                    std::iter::repeat_n(key_predicate.as_str(), chunk.len()).join(" OR ")
+                );
+                (stmt, encode_rows(chunk, key_desc))
+            })
+            .collect()
+    }
+
+    /// Returns the statements, along with their parameters, that write the given rows into the
+    /// target table.
+    fn insert_stmts(&self, rows: &[&Row]) -> Vec<(String, Vec<Value>)> {
+        let columns = self
+            .value_desc
+            .iter_names()
+            .map(|c| quote_identifier(c.as_str()))
+            .collect_vec();
+        let tuple = format!("({})", std::iter::repeat_n("?", columns.len()).join(", "));
+        let on_conflict = match self.envelope {
+            SinkEnvelope::Upsert => format!(
+                " ON DUPLICATE KEY UPDATE {}",
+                columns
+                    .iter()
+                    .map(|c| format!("{c} = VALUES({c})"))
+                    .join(", ")
+            ),
+            _ => String::new(),
+        };
+        let rows_per_stmt = MAX_PARAMS / columns.len().max(1);
+        rows.chunks(rows_per_stmt)
+            .map(|chunk| {
+                let stmt = format!(
+                    "INSERT INTO {} ({}) VALUES {}{on_conflict}",
+                    self.table,
+                    columns.iter().join(", "),
+                    std::iter::repeat_n(tuple.as_str(), chunk.len()).join(", ")
+                );
+                (stmt, encode_rows(chunk, &self.value_desc))
+            })
+            .collect()
+    }
+}
+
+impl UpstreamTableWriter for MySqlSinkWriter {
+    async fn commit(
+        &mut self,
+        updates: &[SinkUpdate],
+        upper: Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        let mut deletes = vec![];
+        let mut inserts = vec![];
+        let mut staged_bytes = 0;
+        for ((key, diff_pair), _time, diff) in updates {
+            assert_eq!(*diff, Diff::ONE, "invalid sink update");
+            match (&diff_pair.after, self.envelope) {
+                (Some(after), _) => {
+                    staged_bytes += after.byte_len();
+                    inserts.push(after);
+                }
+                (None, SinkEnvelope::Upsert) => {
+                    let key = key.as_ref().expect("mysql upsert sinks require a key");
+                    staged_bytes += key.byte_len();
+                    deletes.push(key);
+                }
+                (None, _) => {}
+            }
+        }
+        let staged_messages = u64::cast_from(updates.len());
+        let staged_bytes = u64::cast_from(staged_bytes);
+        self.statistics.inc_messages_staged_by(staged_messages);
+        self.statistics.inc_bytes_staged_by(staged_bytes);
+
+        let mut stmts = self.delete_stmts(&deletes);
+        stmts.extend(self.insert_stmts(&inserts));
+
+        let mut txn = self.conn.start_transaction(TxOpts::default()).await?;
+
+        // Lock our progress row first so that we never apply updates on behalf of a sink that
+        // has been fenced out. We can't rely on the number of rows affected by the final update
+        // for this, because MySQL does not count rows whose values didn't change.
+        let (version, committed_upper): (i64, Option<String>) = txn
+            .exec_first(
+                format!(
+                    "SELECT version, CAST(upper AS CHAR) FROM {} WHERE sink_id = ? FOR UPDATE",
+                    self.progress_table
+                ),
+                (&self.progress_key,),
+            )
+            .await?
+            .ok_or_else(|| anyhow!("progress row for {} disappeared", self.progress_key))?;
+        if version != self.sink_version || decode_upper(committed_upper)? != self.committed_upper {
+            // Dropping the transaction rolls it back.
+            bail!(
+                "sink was fenced out: progress for {} no longer at {}",
+                self.progress_key,
+                self.committed_upper.pretty()
+            );
+        }
+
+        for (stmt, params) in stmts {
+            txn.exec_drop(stmt, params).await?;
+        }
+
+        txn.exec_drop(
+            format!(
+                "UPDATE {} SET upper = CAST(? AS DECIMAL(20,0)) WHERE sink_id = ?",
+                self.progress_table
+            ),
+            (encode_upper(&upper), &self.progress_key),
+        )
+        .await?;
+
+        fail::fail_point!("mysql_sink_commit_transaction");
+
+        txn.commit().await?;
+        self.statistics.inc_messages_committed_by(staged_messages);
+        self.statistics.inc_bytes_committed_by(staged_bytes);
+        self.committed_upper = upper;
+        Ok(())
+    }
+}
+
+/// Flattens the datums of `rows` into statement parameters.
+fn encode_rows(rows: &[&Row], desc: &RelationDesc) -> Vec<Value> {
+    let column_types = &desc.typ().column_types;
+    rows.iter()
+        .flat_map(|row| {
+            row.iter().zip_eq(column_types).map(|(datum, typ)| {
+                match UpstreamValue::from_datum(datum, &typ.scalar_type) {
+                    UpstreamValue::Null => Value::NULL,
+                    UpstreamValue::Bool(b) => Value::Int(b.into()),
+                    UpstreamValue::Int(i) => Value::Int(i),
+                    UpstreamValue::UInt(i) => Value::UInt(i),
+                    UpstreamValue::Float(f) => Value::Double(f),
+                    UpstreamValue::Bytes(b) => Value::Bytes(b),
+                    UpstreamValue::Text(s) => Value::Bytes(s.into_bytes()),
+                }
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,383 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of a [`PostgresSinkConnection`]. The dataflow consists of a
+//! single operator that writes into an existing table of an upstream PostgreSQL database.
+//!
+//! ```text
+//!        ┏━━━━━━━━━━━━━━┓
+//!        ┃   persist    ┃
+//!        ┃    source    ┃
+//!        ┗━━━━━━┯━━━━━━━┛
+//!               │ row data, the input to this module
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃  postgres   ┃ (single worker)
+//!        ┃    sink     ┃
+//!        ┗━━┯━━━━━━━━┯━┛
+//!      rows │        │ uppers
+//!      ╭────v──╮ ╭───v──────╮
+//!      │ data  │ │ progress │  <- rows and uppers are written
+//!      │ table │ │  table   │     in the same transaction
+//!      ╰───────╯ ╰──────────╯
+//! ```
+//!
+//! # Applying updates
+//!
+//! The sink only supports the upsert envelope. All updates at a timestamp are applied in one
+//! upstream transaction: the keys of all updated rows are copied into a temporary table and
+//! deleted from the target table, and then the new values of all rows that were not deleted are
+//! copied into a second temporary table and inserted into the target table. Going through `COPY`
+//! and temporary tables keeps the number of round trips per timestamp constant, regardless of the
+//! number of updates.
+//!
+//! # Exactly once
+//!
+//! Each transaction also advances the sink's row in the progress table from the upper it
+//! resumed at to the new upper. This is a compare-and-set: the row is only updated if it still
+//! contains the expected upper and sink version, which ensures that a sink that has been fenced
+//! out by a newer version, or that raced with another instance of itself, aborts its transaction
+//! instead of applying the same updates twice. On startup the sink reads its upper back from the
+//! progress table and resumes from there, the same way the Kafka sink does with its progress
+//! topic. The operator that drives these transactions is shared with the other upstream table
+//! sinks and lives in [`super::upstream_table`].
+
+use std::pin::pin;
+
+use anyhow::{Context, anyhow, bail};
+use bytes::Bytes;
+use differential_dataflow::VecCollection;
+use futures::SinkExt;
+use itertools::Itertools;
+use mz_interchange::avro::DiffPair;
+use mz_ore::cast::CastFrom;
+use mz_ore::future::InTask;
+use mz_pgcopy::{CopyFormatParams, CopyTextFormatParams};
+use mz_repr::{Diff, GlobalId, RelationDesc, Row, Timestamp};
+use mz_sql_parser::ast::Ident;
+use mz_sql_parser::ast::display::AstDisplay;
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{PostgresSinkConnection, StorageSinkDesc};
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::PressOnDropButton;
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+
+use crate::healthcheck::{HealthStatusMessage, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::sink::upstream_table::{
+    SinkUpdate, UpstreamTableWriter, decode_upper, encode_upper, render_upstream_table_sink,
+};
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The temporary table into which the keys of all rows updated at a timestamp are copied.
+const KEYS_TABLE: &str = "mz_sink_keys";
+/// The temporary table into which the new values of all rows updated at a timestamp are copied.
+const ROWS_TABLE: &str = "mz_sink_rows";
+
+impl<G: Scope<Timestamp = Timestamp>> SinkRender<G> for PostgresSinkConnection {
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+        sink_id: GlobalId,
+        input: VecCollection<G, (Option<Row>, DiffPair<Row>), Diff>,
+        // TODO(benesch): errors should stream out through the sink,
+        // if we figure out a protocol for that.
+        _err_collection: VecCollection<G, DataflowError, Diff>,
+    ) -> (StreamVec<G, HealthStatusMessage>, Vec<PressOnDropButton>) {
+        let connection = self.clone();
+        let storage_configuration = storage_state.storage_configuration.clone();
+        let value_desc = sink.from_desc.clone();
+        let sink_version = sink.version;
+        render_upstream_table_sink(
+            storage_state,
+            sink,
+            sink_id,
+            input,
+            StatusNamespace::Postgres,
+            move |statistics| async move {
+                PostgresSinkWriter::new(
+                    sink_id,
+                    &connection,
+                    &value_desc,
+                    &storage_configuration,
+                    statistics,
+                    sink_version,
+                )
+                .await
+            },
+        )
+    }
+}
+
+/// A connection to the upstream database that transactionally applies the updates of a sink
+/// together with its progress.
+struct PostgresSinkWriter {
+    /// The connection to the upstream database.
+    client: mz_postgres_util::Client,
+    /// The quoted, fully qualified name of the progress table.
+    progress_table: String,
+    /// The key of this sink's row in the progress table.
+    progress_key: String,
+    /// The version of this sink, used to fence out previous versions from writing.
+    sink_version: i64,
+    /// The upper recorded in the progress table, as of the last successful commit.
+    committed_upper: Antichain<Timestamp>,
+    /// The description of the key of each update.
+    key_desc: RelationDesc,
+    /// The description of the value of each update.
+    value_desc: RelationDesc,
+    /// The statement that copies keys into the keys table.
+    copy_keys_stmt: String,
+    /// The statement that copies rows into the rows table.
+    copy_rows_stmt: String,
+    /// The statements that apply the contents of the temporary tables to the target table.
+    apply_stmt: String,
+    /// A handle to the statistics associated with this sink.
+    statistics: SinkStatistics,
+}
+
+impl PostgresSinkWriter {
+    /// Connects to the upstream database, validates the target table and registers this sink
+    /// version in the progress table. After this call returns it is guaranteed that all previous
+    /// versions of this sink have been fenced out.
+    async fn new(
+        sink_id: GlobalId,
+        connection: &PostgresSinkConnection,
+        value_desc: &RelationDesc,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+        sink_version: u64,
+    ) -> Result<(Self, Antichain<Timestamp>), anyhow::Error> {
+        let sink_version = i64::try_from(sink_version).context("sink version out of range")?;
+        let (key_desc, _) = connection
+            .key_desc_and_indices
+            .clone()
+            .ok_or_else(|| anyhow!("postgres sinks require a key"))?;
+
+        let config = connection
+            .connection
+            .config(
+                &storage_configuration.connection_context.secrets_reader,
+                storage_configuration,
+                InTask::Yes,
+            )
+            .await?;
+        let mut client = config
+            .connect(
+                "postgres sink",
+                &storage_configuration.connection_context.ssh_tunnel_manager,
+            )
+            .await?;
+
+        let quote = |name: &str| Ident::new_unchecked(name).to_ast_string_stable();
+        let schema = quote(&connection.schema);
+        let table = format!("{schema}.{}", quote(&connection.table));
+        let progress_table = format!("{schema}.{}", quote(&connection.progress_table));
+
+        // To handle columns that were dropped or renamed upstream with a useful error, we check
+        // that every column of the sinked relation exists in the target table. The types are
+        // checked implicitly by the upstream when parsing the rows we copy in.
+        let upstream_columns: Vec<String> = client
+            .query(
+                "SELECT a.attname::text FROM pg_catalog.pg_attribute a \
+                 WHERE a.attrelid = $1::text::regclass AND a.attnum > 0 AND NOT a.attisdropped",
+                &[&table],
+            )
+            .await
+            .with_context(|| format!("looking up columns of upstream table {table}"))?
+            .into_iter()
+            .map(|row| row.get(0))
+            .collect();
+        for name in value_desc.iter_names() {
+            if !upstream_columns.iter().any(|c| c == name.as_str()) {
+                bail!("column {name} does not exist in upstream table {table}");
+            }
+        }
+
+        let key_columns = key_desc.iter_names().map(|c| quote(c.as_str())).join(", ");
+        let value_columns = value_desc
+            .iter_names()
+            .map(|c| quote(c.as_str()))
+            .join(", ");
+        // Only use `IS NOT DISTINCT FROM` where it is needed, because PostgreSQL cannot use an
+        // index to evaluate it.
+        let key_predicate = key_desc
+            .iter()
+            .map(|(name, typ)| {
+                let name = quote(name.as_str());
+                if typ.nullable {
+                    format!("t.{name} IS NOT DISTINCT FROM k.{name}")
+                } else {
+                    format!("t.{name} = k.{name}")
+                }
+            })
+            .join(" AND ");
+
+        client
+            .batch_execute(&format!(
+                "CREATE TABLE IF NOT EXISTS {progress_table} (
+                    sink_id text NOT NULL PRIMARY KEY,
+                    version bigint NOT NULL,
+                    upper numeric(20,0)
+                );
+                CREATE TEMPORARY TABLE IF NOT EXISTS {KEYS_TABLE} ON COMMIT DELETE ROWS
+                    AS SELECT {key_columns} FROM {table} WITH NO DATA;
+                CREATE TEMPORARY TABLE IF NOT EXISTS {ROWS_TABLE} ON COMMIT DELETE ROWS
+                    AS SELECT {value_columns} FROM {table} WITH NO DATA;"
+            ))
+            .await
+            .context("preparing upstream tables")?;
+
+        let progress_key =
+            connection.progress_key(&storage_configuration.connection_context, sink_id);
+
+        // Register our version, fencing out any previous versions of this sink.
+        let txn = client.transaction().await?;
+        txn.execute(
+            &format!(
+                "INSERT INTO {progress_table} (sink_id, version, upper) VALUES ($1, $2, 0) \
+                 ON CONFLICT (sink_id) DO NOTHING"
+            ),
+            &[&progress_key, &sink_version],
+        )
+        .await?;
+        let row = txn
+            .query_one(
+                &format!(
+                    "SELECT version, upper::text FROM {progress_table} \
+                     WHERE sink_id = $1 FOR UPDATE"
+                ),
+                &[&progress_key],
+            )
+            .await?;
+        let version: i64 = row.get(0);
+        if version > sink_version {
+            bail!(
+                "sink was fenced out by a newer version: current version {sink_version}, \
+                 upstream version {version}"
+            );
+        }
+        let resume_upper = decode_upper(row.get(1))?;
+        txn.execute(
+            &format!("UPDATE {progress_table} SET version = $2 WHERE sink_id = $1"),
+            &[&progress_key, &sink_version],
+        )
+        .await?;
+        txn.commit().await?;
+
+        let writer = PostgresSinkWriter {
+            client,
+            progress_table,
+            progress_key,
+            sink_version,
+            committed_upper: resume_upper.clone(),
+            key_desc,
+            value_desc: value_desc.clone(),
+            copy_keys_stmt: format!("COPY pg_temp.{KEYS_TABLE} ({key_columns}) FROM STDIN"),
+            copy_rows_stmt: format!("COPY pg_temp.{ROWS_TABLE} ({value_columns}) FROM STDIN"),
+            apply_stmt: format!(
+                "DELETE FROM {table} AS t USING pg_temp.{KEYS_TABLE} AS k WHERE {key_predicate};
+                 INSERT INTO {table} ({value_columns})
+                    SELECT {value_columns} FROM pg_temp.{ROWS_TABLE};"
+            ),
+            statistics,
+        };
+        Ok((writer, resume_upper))
+    }
+}
+
+impl UpstreamTableWriter for PostgresSinkWriter {
+    async fn commit(
+        &mut self,
+        updates: &[SinkUpdate],
+        upper: Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        let txn = self.client.transaction().await?;
+
+        let mut staged_messages = 0;
+        let mut staged_bytes = 0;
+        if !updates.is_empty() {
+            let params = CopyFormatParams::Text(CopyTextFormatParams::default());
+            let mut keys = vec![];
+            let mut rows = vec![];
+            for ((key, diff_pair), _time, diff) in updates {
+                assert_eq!(*diff, Diff::ONE, "invalid sink update");
+                let key = key.as_ref().expect("postgres sinks require a key");
+                mz_pgcopy::encode_copy_format(&params, key, self.key_desc.typ(), &mut keys)?;
+                if let Some(after) = &diff_pair.after {
+                    mz_pgcopy::encode_copy_format(
+                        &params,
+                        after,
+                        self.value_desc.typ(),
+                        &mut rows,
+                    )?;
+                }
+                staged_messages += 1;
+            }
+            staged_bytes = u64::cast_from(keys.len() + rows.len());
+            self.statistics.inc_messages_staged_by(staged_messages);
+            self.statistics.inc_bytes_staged_by(staged_bytes);
+
+            for (stmt, data) in [(&self.copy_keys_stmt, keys), (&self.copy_rows_stmt, rows)] {
+                let mut sink = pin!(txn.copy_in::<_, Bytes>(stmt.as_str()).await?);
+                sink.send(Bytes::from(data)).await?;
+                sink.as_mut().finish().await?;
+            }
+            txn.batch_execute(&self.apply_stmt).await?;
+        }
+
+        let updated = txn
+            .execute(
+                &format!(
+                    "UPDATE {} SET upper = $3::text::numeric \
+                     WHERE sink_id = $1 AND version = $2 \
+                     AND upper IS NOT DISTINCT FROM $4::text::numeric",
+                    self.progress_table
+                ),
+                &[
+                    &self.progress_key,
+                    &self.sink_version,
+                    &encode_upper(&upper),
+                    &encode_upper(&self.committed_upper),
+                ],
+            )
+            .await?;
+        if updated != 1 {
+            // Dropping the transaction rolls it back.
+            bail!(
+                "sink was fenced out: progress for {} no longer at {}",
+                self.progress_key,
+                self.committed_upper.pretty()
+            );
+        }
+
+        fail::fail_point!("postgres_sink_commit_transaction");
+
+        txn.commit().await?;
+        self.statistics.inc_messages_committed_by(staged_messages);
+        self.statistics.inc_bytes_committed_by(staged_bytes);
+        self.committed_upper = upper;
+        Ok(())
+    }
+}
@@ -0,0 +1,743 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of an [`S3SinkConnection`]. The dataflow consists of a
+//! single operator that writes the appended rows of its input to S3 in batches of Parquet or CSV
+//! files.
+//!
+//! ```text
+//!        ┏━━━━━━━━━━━━━━┓
+//!        ┃   persist    ┃
+//!        ┃    source    ┃
+//!        ┗━━━━━━┯━━━━━━━┛
+//!               │ row data, the input to this module
+//!               │
+//!        ┏━━━━━━v━━━━━━┓
+//!        ┃     s3      ┃ (single worker)
+//!        ┃    sink     ┃
+//!        ┗━━┯━━━━━━━━┯━┛
+//!     files │        │ batches and uppers
+//!   ╭───────v─────╮ ╭v─────────╮
+//!   │ {path}/     │ │ manifest │
+//!   │   {lower}/* │ ╰──────────╯
+//!   ╰─────────────╯
+//! ```
+//!
+//! # Batches
+//!
+//! Whenever the frontier of the input has advanced by at least the sink's commit interval, the
+//! rows of all complete timestamps are written as a new batch of files under
+//! `{path}/{lower}/`, where `lower` is the frontier up to which the sink had previously
+//! committed. The files are named by the same [`S3KeyManager`] that `COPY ... TO 's3://...'`
+//! uses and, like for `COPY ... TO`, an `INCOMPLETE` sentinel is present next to the files while
+//! they are being uploaded. Only appended rows are written. Retractions are dropped.
+//!
+//! # Manifest
+//!
+//! A batch is committed by recording it, along with the new upper of the sink, in the manifest
+//! at `{path}/mz-{sink_id}-manifest.json`. Downstream readers must only read the files listed in
+//! the manifest: files of a batch that is not in the manifest may be incomplete and are removed
+//! the next time the sink attempts to commit that batch.
+//!
+//! The `INCOMPLETE` sentinel of a batch is removed once the batch is committed. A failure to
+//! remove it doesn't fail the sink: the removal is retried with the next commit.
+//!
+//! To keep the cost of rewriting the manifest bounded, the manifest only lists the most recently
+//! committed batches. When a commit would make it list more than [`S3_SINK_MANIFEST_MAX_BATCHES`]
+//! batches, they are first moved into an immutable manifest segment at
+//! `{path}/mz-{sink_id}-manifest-{upper}.json`, where `upper` is the frontier up to which the
+//! segment's batches contain updates. The manifest and every segment name the segment that holds
+//! the batches committed before their own, so readers find all committed batches by following
+//! these links from the manifest.
+//!
+//! The manifest is always replaced with a conditional write that only succeeds if it has not
+//! changed since the sink last read or wrote it. On startup the sink records its version in the
+//! manifest and resumes from the upper found there, which fences out all previous versions of
+//! the sink and ensures that no batch is committed twice.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use anyhow::{Context, anyhow, bail};
+use aws_sdk_s3::primitives::ByteStream;
+use aws_types::sdk_config::SdkConfig;
+use differential_dataflow::{Hashable, VecCollection};
+use futures::StreamExt;
+use mz_aws_util::s3::Client;
+use mz_interchange::avro::DiffPair;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_ore::future::InTask;
+use mz_persist_client::Diagnostics;
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::{Diff, GlobalId, RelationDesc, Row, Timestamp};
+use mz_storage_operators::s3_oneshot_sink::{CopyToParameters, S3BatchUploader};
+use mz_storage_types::StorageDiff;
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::dyncfgs::{
+    S3_SINK_ARROW_BUILDER_BUFFER_RATIO, S3_SINK_MANIFEST_MAX_BATCHES,
+    S3_SINK_MULTIPART_PART_SIZE_BYTES, S3_SINK_ORC_STRIPE_FILE_RATIO,
+    S3_SINK_PARQUET_ROW_GROUP_FILE_RATIO,
+};
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::s3_oneshot_sink::S3KeyManager;
+use mz_storage_types::sinks::{S3SinkConnection, StorageSinkDesc};
+use mz_storage_types::sources::SourceData;
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::{
+    Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use serde::{Deserialize, Serialize};
+use timely::PartialOrder;
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::operators::Concatenate;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::{Antichain, Timestamp as _};
+use tracing::{debug, error, info, warn};
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// An update to the sinked collection.
+type SinkUpdate = ((Option<Row>, DiffPair<Row>), Timestamp, Diff);
+
+impl<G: Scope<Timestamp = Timestamp>> SinkRender<G> for S3SinkConnection {
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        None
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        None
+    }
+
+    fn render_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+        sink_id: GlobalId,
+        input: VecCollection<G, (Option<Row>, DiffPair<Row>), Diff>,
+        // TODO(benesch): errors should stream out through the sink,
+        // if we figure out a protocol for that.
+        _err_collection: VecCollection<G, DataflowError, Diff>,
+    ) -> (StreamVec<G, HealthStatusMessage>, Vec<PressOnDropButton>) {
+        render_s3_sink(storage_state, sink, sink_id, self.clone(), input)
+    }
+}
+
+fn render_s3_sink<G>(
+    storage_state: &mut StorageState,
+    sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+    sink_id: GlobalId,
+    connection: S3SinkConnection,
+    input: VecCollection<G, (Option<Row>, DiffPair<Row>), Diff>,
+) -> (StreamVec<G, HealthStatusMessage>, Vec<PressOnDropButton>)
+where
+    G: Scope<Timestamp = Timestamp>,
+{
+    let mut scope = input.scope();
+    let name = format!("s3-{sink_id}-sink");
+
+    let write_handle = {
+        let persist = Arc::clone(&storage_state.persist_clients);
+        let shard_meta = sink.to_storage_metadata.clone();
+        async move {
+            let client = persist.open(shard_meta.persist_location).await?;
+            let handle = client
+                .open_writer(
+                    shard_meta.data_shard,
+                    Arc::new(shard_meta.relation_desc),
+                    Arc::new(UnitSchema),
+                    Diagnostics::from_purpose("sink handle"),
+                )
+                .await?;
+            Ok::<_, anyhow::Error>(handle)
+        }
+    };
+
+    let write_frontier = Rc::new(RefCell::new(Antichain::from_elem(Timestamp::minimum())));
+    storage_state
+        .sink_write_frontiers
+        .insert(sink_id, Rc::clone(&write_frontier));
+
+    let statistics = storage_state
+        .aggregated_statistics
+        .get_sink(&sink_id)
+        .expect("statistics initialized")
+        .clone();
+
+    let storage_configuration = storage_state.storage_configuration.clone();
+    let desc = sink.from_desc.clone();
+    let sink_version = sink.version;
+    let commit_interval = sink
+        .commit_interval
+        .expect("the planner should have enforced this");
+    let commit_interval = Timestamp::new(
+        commit_interval
+            .as_millis()
+            .try_into()
+            .expect("commit interval too large for u64"),
+    );
+
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), input.inner.scope());
+
+    // We want exactly one worker to write all the data to S3.
+    let hashed_id = sink_id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % scope.peers() == scope.index();
+
+    let mut input = builder.new_disconnected_input(input.inner, Exchange::new(move |_| hashed_id));
+
+    let as_of = sink.as_of.clone();
+    let (button, errors) = builder.build_fallible(move |_caps| {
+        Box::pin(async move {
+            if !is_active_worker {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            }
+
+            let mut write_handle = write_handle.await?;
+            let (mut writer, resume_upper) = S3SinkWriter::new(
+                sink_id,
+                connection,
+                desc,
+                &storage_configuration,
+                statistics,
+                sink_version,
+            )
+            .await?;
+
+            // The input has overcompacted if
+            let overcompacted =
+                // ..we have made some progress in the past
+                *resume_upper != [Timestamp::minimum()] &&
+                // ..but the since frontier is now beyond that
+                !PartialOrder::less_equal(&as_of, &resume_upper);
+            if overcompacted {
+                let err = format!(
+                    "{name}: input compacted past resume upper: as_of {}, resume_upper: {}",
+                    as_of.pretty(),
+                    resume_upper.pretty()
+                );
+                // This would normally be an assertion but because it can happen after a
+                // Materialize backup/restore we log an error so that it appears on Sentry but
+                // leaves the rest of the objects in the cluster unaffected.
+                error!("{err}");
+                return Err(anyhow!("{err}"));
+            }
+
+            info!(
+                "{name}: as_of: {}, resume upper: {}",
+                as_of.pretty(),
+                resume_upper.pretty()
+            );
+
+            // The section below relies on TotalOrder for correctness so we'll work with timestamps
+            // directly to make sure this doesn't compile if someone attempts to make this operator
+            // generic over partial orders in the future.
+            let Some(upper) = resume_upper.clone().into_option() else {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            };
+
+            let mut deferred_updates = vec![];
+            while let Some(event) = input.next().await {
+                match event {
+                    Event::Data(_cap, batch) => {
+                        // Updates before `upper` have already been committed by a previous
+                        // incarnation of this sink.
+                        deferred_updates.extend(batch.into_iter().filter(|(_, t, _)| upper <= *t));
+                    }
+                    Event::Progress(progress) => {
+                        // Ignore progress updates before our resumption frontier
+                        if !PartialOrder::less_equal(&resume_upper, &progress) {
+                            continue;
+                        }
+                        // Also ignore progress updates until we are past the as_of frontier. See
+                        // the Kafka sink for a description of the scenario this prevents.
+                        if !as_of.iter().all(|t| !progress.less_equal(t)) {
+                            continue;
+                        }
+                        // Accumulate at least a commit interval's worth of timestamps in each
+                        // batch, so that we don't write a tiny batch of files every time the
+                        // frontier advances.
+                        let lower = writer.lower();
+                        if let Some(progress) = progress.as_option() {
+                            if *progress < lower.step_forward_by(&commit_interval) {
+                                continue;
+                            }
+                        }
+
+                        let ready_updates: Vec<_> = deferred_updates
+                            .extract_if(.., |(_, time, _)| !progress.less_equal(time))
+                            .collect();
+                        debug!("{name}: committing batch for {}", progress.pretty());
+                        writer.commit(&ready_updates, progress.clone()).await?;
+
+                        let mut expect_upper = write_handle.shared_upper();
+                        loop {
+                            if PartialOrder::less_equal(&progress, &expect_upper) {
+                                // The frontier has already been advanced as far as necessary.
+                                break;
+                            }
+                            const EMPTY: &[((SourceData, ()), Timestamp, StorageDiff)] = &[];
+                            match write_handle
+                                .compare_and_append(EMPTY, expect_upper, progress.clone())
+                                .await
+                                .expect("valid usage")
+                            {
+                                Ok(()) => break,
+                                Err(mismatch) => {
+                                    expect_upper = mismatch.current;
+                                }
+                            }
+                        }
+                        write_frontier.borrow_mut().clone_from(&progress);
+                        if progress.is_empty() {
+                            break;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    });
+
+    let statuses = errors.map(|error: Rc<anyhow::Error>| HealthStatusMessage {
+        id: None,
+        update: HealthStatusUpdate::halting(format!("{}", error.display_with_causes()), None),
+        namespace: StatusNamespace::S3,
+    });
+
+    let running_status = Some(HealthStatusMessage {
+        id: None,
+        update: HealthStatusUpdate::running(),
+        namespace: StatusNamespace::S3,
+    })
+    .to_stream(&mut scope);
+
+    let status = scope.concatenate([running_status, statuses]);
+
+    (status, vec![button.press_on_drop()])
+}
+
+/// The contents of the manifest of an S3 sink.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Manifest {
+    /// The version of the sink that last wrote the manifest.
+    version: u64,
+    /// The frontier up to which all updates have been committed, or `None` if the sink has
+    /// committed all of its input.
+    upper: Option<u64>,
+    /// The key of the segment that holds the batches committed before those in `batches`, if
+    /// any.
+    #[serde(default)]
+    previous_segment: Option<String>,
+    /// The most recently committed batches, in the order in which they were committed.
+    batches: Vec<ManifestBatch>,
+}
+
+/// An immutable segment of the manifest of an S3 sink, holding batches that were moved out of
+/// the manifest.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ManifestSegment {
+    /// The key of the segment that holds the batches committed before those in `batches`, if
+    /// any.
+    previous_segment: Option<String>,
+    /// The batches of the segment, in the order in which they were committed.
+    batches: Vec<ManifestBatch>,
+}
+
+/// A committed batch of files in the manifest of an S3 sink.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ManifestBatch {
+    /// The frontier from which the batch contains updates.
+    lower: u64,
+    /// The frontier up to which the batch contains updates, or `None` if the batch contains all
+    /// remaining updates of the sink's input.
+    upper: Option<u64>,
+    /// The keys of the files that make up the batch.
+    files: Vec<String>,
+}
+
+/// A connection to S3 that commits batches of files to the manifest of a sink.
+struct S3SinkWriter {
+    sink_id: GlobalId,
+    client: Client,
+    sdk_config: SdkConfig,
+    connection: S3SinkConnection,
+    /// The description of the sinked relation.
+    desc: RelationDesc,
+    params: CopyToParameters,
+    /// The maximum number of batches listed in the manifest itself.
+    manifest_max_batches: usize,
+    /// The bucket of all objects written by the sink.
+    bucket: String,
+    /// The key manager for the sink's manifest and its segments.
+    key_manager: S3KeyManager,
+    /// The key of the sink's manifest.
+    manifest_key: String,
+    /// The manifest, as of the last successful write.
+    manifest: Manifest,
+    /// The entity tag of the manifest, as of the last successful write.
+    manifest_etag: String,
+    /// The `INCOMPLETE` sentinels of committed batches that have yet to be removed.
+    pending_sentinels: Vec<String>,
+    /// A handle to the statistics associated with this sink.
+    statistics: SinkStatistics,
+}
+
+impl S3SinkWriter {
+    /// Connects to S3 and registers this sink version in the manifest. After this call returns
+    /// it is guaranteed that all previous versions of this sink have been fenced out.
+    async fn new(
+        sink_id: GlobalId,
+        connection: S3SinkConnection,
+        desc: RelationDesc,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+        sink_version: u64,
+    ) -> Result<(Self, Antichain<Timestamp>), anyhow::Error> {
+        let sdk_config = connection
+            .aws_connection
+            .load_sdk_config(
+                &storage_configuration.connection_context,
+                connection.aws_connection_id,
+                InTask::Yes,
+            )
+            .await?;
+        let client = mz_aws_util::s3::new_client(&sdk_config);
+
+        let config_set = storage_configuration.config_set();
+        let params = CopyToParameters {
+            parquet_row_group_ratio: S3_SINK_PARQUET_ROW_GROUP_FILE_RATIO.get(config_set),
+            orc_stripe_ratio: S3_SINK_ORC_STRIPE_FILE_RATIO.get(config_set),
+            arrow_builder_buffer_ratio: S3_SINK_ARROW_BUILDER_BUFFER_RATIO.get(config_set),
+            s3_multipart_part_size_bytes: S3_SINK_MULTIPART_PART_SIZE_BYTES.get(config_set),
+        };
+        let manifest_max_batches = S3_SINK_MANIFEST_MAX_BATCHES.get(config_set);
+
+        let key_manager = S3KeyManager::new(&sink_id, &connection.uri);
+        let bucket = key_manager.bucket.clone();
+        let manifest_key = key_manager.manifest_key();
+
+        let existing = read_manifest(&client, &bucket, &manifest_key).await?;
+        let (manifest, etag) = match existing {
+            Some((manifest, _)) if manifest.version > sink_version => {
+                bail!(
+                    "sink was fenced out: manifest {manifest_key} was written by version {} of \
+                     the sink, but this is version {sink_version}",
+                    manifest.version
+                );
+            }
+            Some((manifest, etag)) => (manifest, Some(etag)),
+            None => {
+                let manifest = Manifest {
+                    version: sink_version,
+                    upper: Some(Timestamp::minimum().into()),
+                    previous_segment: None,
+                    batches: vec![],
+                };
+                (manifest, None)
+            }
+        };
+
+        let mut writer = S3SinkWriter {
+            sink_id,
+            client,
+            sdk_config,
+            connection,
+            desc,
+            params,
+            manifest_max_batches,
+            bucket,
+            key_manager,
+            manifest_key,
+            manifest,
+            manifest_etag: String::new(),
+            pending_sentinels: vec![],
+            statistics,
+        };
+        // A previous version of the sink may have failed to remove the sentinel of the last batch
+        // it committed.
+        if let Some(batch) = writer.manifest.batches.last() {
+            let batch_keys = writer.batch_key_manager(Timestamp::from(batch.lower));
+            writer
+                .pending_sentinels
+                .push(batch_keys.incomplete_sentinel_key());
+        }
+        let manifest = Manifest {
+            version: sink_version,
+            ..writer.manifest.clone()
+        };
+        writer.write_manifest(manifest, etag.as_deref()).await?;
+
+        let resume_upper = match writer.manifest.upper {
+            Some(upper) => Antichain::from_elem(Timestamp::from(upper)),
+            None => Antichain::new(),
+        };
+        Ok((writer, resume_upper))
+    }
+
+    /// Returns the frontier up to which all updates have been committed.
+    ///
+    /// Panics if all updates have been committed.
+    fn lower(&self) -> Timestamp {
+        let upper = self
+            .manifest
+            .upper
+            .expect("sink has not committed all of its input");
+        Timestamp::from(upper)
+    }
+
+    /// Writes the rows appended by `updates` as a new batch of files and commits the batch, along
+    /// with the new `upper`, to the manifest.
+    async fn commit(
+        &mut self,
+        updates: &[SinkUpdate],
+        upper: Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        let lower = self.lower();
+        let rows: Vec<_> = updates
+            .iter()
+            .filter_map(|((_key, diff_pair), _time, diff)| {
+                assert_eq!(*diff, Diff::ONE, "invalid sink update");
+                diff_pair.after.as_ref()
+            })
+            .collect();
+        let staged_messages = u64::cast_from(rows.len());
+        let staged_bytes = u64::cast_from(rows.iter().map(|row| row.byte_len()).sum::<usize>());
+        self.statistics.inc_messages_staged_by(staged_messages);
+        self.statistics.inc_bytes_staged_by(staged_bytes);
+
+        let mut manifest = self.manifest.clone();
+        manifest.upper = upper.as_option().map(|ts| u64::from(*ts));
+        // There is no point in writing a batch without any files, so if no rows were appended we
+        // only advance the upper in the manifest.
+        if !rows.is_empty() {
+            let files = self.upload_batch(lower, &rows).await?;
+            if manifest.batches.len() >= self.manifest_max_batches {
+                manifest.previous_segment = Some(self.write_segment(&mut manifest, lower).await?);
+            }
+            manifest.batches.push(ManifestBatch {
+                lower: lower.into(),
+                upper: manifest.upper,
+                files,
+            });
+        }
+
+        fail::fail_point!("s3_sink_commit_manifest");
+
+        let etag = self.manifest_etag.clone();
+        self.write_manifest(manifest, Some(&etag)).await?;
+
+        if !rows.is_empty() {
+            let batch_keys = self.batch_key_manager(lower);
+            self.pending_sentinels
+                .push(batch_keys.incomplete_sentinel_key());
+        }
+        self.remove_pending_sentinels().await;
+
+        self.statistics.inc_messages_committed_by(staged_messages);
+        self.statistics.inc_bytes_committed_by(staged_bytes);
+        Ok(())
+    }
+
+    /// Moves the batches of `manifest` into a new manifest segment whose batches contain updates
+    /// up to `upper`, returning the key of the segment.
+    async fn write_segment(
+        &self,
+        manifest: &mut Manifest,
+        upper: Timestamp,
+    ) -> Result<String, anyhow::Error> {
+        let segment = ManifestSegment {
+            previous_segment: manifest.previous_segment.take(),
+            batches: std::mem::take(&mut manifest.batches),
+        };
+        let key = self.key_manager.manifest_segment_key(upper.into());
+        // The segment only becomes visible to readers once the manifest links to it, so it
+        // doesn't need a conditional write. A previous attempt to commit the same batch may have
+        // written the same segment already.
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(&key)
+            .content_type("application/json")
+            .body(ByteStream::from(serde_json::to_vec(&segment)?))
+            .send()
+            .await
+            .context("writing manifest segment")?;
+        Ok(key)
+    }
+
+    /// Removes the `INCOMPLETE` sentinels of committed batches, which notifies readers that are
+    /// waiting on them that the batches are complete.
+    ///
+    /// The batches are committed regardless of whether this succeeds, so failures are only
+    /// logged and the sentinels are retried with the next commit.
+    async fn remove_pending_sentinels(&mut self) {
+        let mut failed = vec![];
+        for key in std::mem::take(&mut self.pending_sentinels) {
+            let result = self
+                .client
+                .delete_object()
+                .bucket(&self.bucket)
+                .key(&key)
+                .send()
+                .await;
+            if let Err(err) = result {
+                warn!(
+                    sink_id = %self.sink_id,
+                    "failed to remove sentinel {key}: {}",
+                    err.display_with_causes()
+                );
+                failed.push(key);
+            }
+        }
+        self.pending_sentinels = failed;
+    }
+
+    /// Returns the key manager for the files of the batch that starts at `lower`.
+    fn batch_key_manager(&self, lower: Timestamp) -> S3KeyManager {
+        let upload_info = self.connection.batch_upload_info(self.desc.clone(), lower);
+        S3KeyManager::new(&self.sink_id, &upload_info.uri)
+    }
+
+    /// Uploads `rows` as the files of the batch that starts at `lower`, returning the keys of
+    /// the uploaded files.
+    async fn upload_batch(
+        &self,
+        lower: Timestamp,
+        rows: &[&Row],
+    ) -> Result<Vec<String>, anyhow::Error> {
+        let upload_info = self.connection.batch_upload_info(self.desc.clone(), lower);
+        let batch_keys = self.batch_key_manager(lower);
+        let sentinel_key = batch_keys.incomplete_sentinel_key();
+
+        // Remove any files left behind by a previous attempt to commit this batch. They were
+        // never committed to the manifest, so no reader can depend on them.
+        let leftovers = mz_aws_util::s3::list_bucket_path(
+            &self.client,
+            &self.bucket,
+            &batch_keys.object_key_prefix,
+        )
+        .await?;
+        for key in leftovers.into_iter().flatten() {
+            debug!(sink_id = %self.sink_id, "removing leftover object {key}");
+            self.client
+                .delete_object()
+                .bucket(&self.bucket)
+                .key(key)
+                .send()
+                .await?;
+        }
+
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(&sentinel_key)
+            .send()
+            .await?;
+
+        let mut uploader = S3BatchUploader::new(
+            self.sdk_config.clone(),
+            upload_info,
+            &self.sink_id,
+            0,
+            self.params.clone(),
+        )?;
+        for row in rows {
+            uploader.append_row(row).await?;
+        }
+        uploader.finish().await?;
+
+        let mut files: Vec<_> = mz_aws_util::s3::list_bucket_path(
+            &self.client,
+            &self.bucket,
+            &batch_keys.object_key_prefix,
+        )
+        .await?
+        .into_iter()
+        .flatten()
+        .filter(|key| *key != sentinel_key)
+        .collect();
+        files.sort();
+        Ok(files)
+    }
+
+    /// Replaces the manifest with `manifest`, if the manifest in S3 still has the entity tag
+    /// `expected_etag` or, if `expected_etag` is `None`, does not exist.
+    async fn write_manifest(
+        &mut self,
+        manifest: Manifest,
+        expected_etag: Option<&str>,
+    ) -> Result<(), anyhow::Error> {
+        let body = serde_json::to_vec(&manifest)?;
+        let request = self
+            .client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(&self.manifest_key)
+            .content_type("application/json")
+            .body(ByteStream::from(body));
+        let request = match expected_etag {
+            Some(etag) => request.if_match(etag),
+            None => request.if_none_match("*"),
+        };
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(err) => {
+                let status = err.raw_response().map(|r| r.status().as_u16());
+                // 409 is returned when a conflicting conditional write is in flight.
+                if matches!(status, Some(409 | 412)) {
+                    bail!(
+                        "sink was fenced out: manifest {} was concurrently modified",
+                        self.manifest_key
+                    );
+                }
+                return Err(err).context("writing manifest");
+            }
+        };
+        let etag = response
+            .e_tag()
+            .ok_or_else(|| anyhow!("PutObject response for manifest is missing an ETag"))?;
+        self.manifest = manifest;
+        self.manifest_etag = etag.to_string();
+        Ok(())
+    }
+}
+
+/// Reads the manifest at `key`, along with its entity tag, if it exists.
+async fn read_manifest(
+    client: &Client,
+    bucket: &str,
+    key: &str,
+) -> Result<Option<(Manifest, String)>, anyhow::Error> {
+    let response = match client.get_object().bucket(bucket).key(key).send().await {
+        Ok(response) => response,
+        Err(err) => {
+            let err = err.into_service_error();
+            if err.is_no_such_key() {
+                return Ok(None);
+            }
+            return Err(err).context("reading manifest");
+        }
+    };
+    let etag = response
+        .e_tag()
+        .ok_or_else(|| anyhow!("GetObject response for manifest is missing an ETag"))?
+        .to_string();
+    let body = response.body.collect().await?.into_bytes();
+    let manifest =
+        serde_json::from_slice(&body).with_context(|| format!("parsing manifest {key}"))?;
+    Ok(Some((manifest, etag)))
+}
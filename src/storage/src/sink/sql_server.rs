@@ -0,0 +1,500 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Code to render the sink dataflow of a [`SqlServerSinkConnection`]. The dataflow consists of a
+//! single operator that writes into an existing table of an upstream SQL Server database.
+//!
+//! # Applying updates
+//!
+//! All updates at a timestamp are applied in one upstream transaction, using multi-row
+//! statements to keep the number of round trips low. SQL Server limits statements to 2100
+//! parameters, so large timestamps are split over several statements within the transaction.
+//!
+//! In upsert mode the rows of all deleted keys are deleted from the target table and the new
+//! values of all other updated keys are written with a `MERGE` on the columns of the sink's key.
+//! In append mode the new value of every update is inserted and deletions are ignored.
+//!
+//! # Exactly once
+//!
+//! Each transaction ends by advancing the sink's row in the progress table with a compare-and-set
+//! from the expected upper and sink version to the new upper. See [`super::upstream_table`] for
+//! how this is used to resume without duplicates.
+
+use std::borrow::Cow;
+
+use anyhow::{Context, anyhow, bail};
+use differential_dataflow::VecCollection;
+use itertools::Itertools;
+use mz_interchange::avro::DiffPair;
+use mz_ore::cast::CastFrom;
+use mz_ore::future::InTask;
+use mz_repr::{Diff, GlobalId, RelationDesc, Row, Timestamp};
+use mz_sql_server_util::quote_identifier;
+use mz_storage_types::configuration::StorageConfiguration;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::errors::DataflowError;
+use mz_storage_types::sinks::{SinkEnvelope, SqlServerSinkConnection, StorageSinkDesc};
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::PressOnDropButton;
+use tiberius::{ColumnData, ToSql};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::Antichain;
+
+use crate::healthcheck::{HealthStatusMessage, StatusNamespace};
+use crate::render::sinks::SinkRender;
+use crate::sink::upstream_table::{
+    SinkUpdate, UpstreamTableWriter, UpstreamValue, decode_upper, encode_upper,
+    render_upstream_table_sink,
+};
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// The maximum number of parameters SQL Server allows in a statement.
+const MAX_PARAMS: usize = 2_100;
+/// The maximum number of rows SQL Server allows in a table value constructor.
+const MAX_ROWS: usize = 1_000;
+
+impl<G: Scope<Timestamp = Timestamp>> SinkRender<G> for SqlServerSinkConnection {
+    fn get_key_indices(&self) -> Option<&[usize]> {
+        self.key_desc_and_indices
+            .as_ref()
+            .map(|(_desc, indices)| indices.as_slice())
+    }
+
+    fn get_relation_key_indices(&self) -> Option<&[usize]> {
+        self.relation_key_indices.as_deref()
+    }
+
+    fn render_sink(
+        &self,
+        storage_state: &mut StorageState,
+        sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+        sink_id: GlobalId,
+        input: VecCollection<G, (Option<Row>, DiffPair<Row>), Diff>,
+        // TODO(benesch): errors should stream out through the sink,
+        // if we figure out a protocol for that.
+        _err_collection: VecCollection<G, DataflowError, Diff>,
+    ) -> (StreamVec<G, HealthStatusMessage>, Vec<PressOnDropButton>) {
+        let connection = self.clone();
+        let storage_configuration = storage_state.storage_configuration.clone();
+        let value_desc = sink.from_desc.clone();
+        let envelope = sink.envelope;
+        let sink_version = sink.version;
+        render_upstream_table_sink(
+            storage_state,
+            sink,
+            sink_id,
+            input,
+            StatusNamespace::SqlServer,
+            move |statistics| async move {
+                SqlServerSinkWriter::new(
+                    sink_id,
+                    &connection,
+                    &value_desc,
+                    envelope,
+                    &storage_configuration,
+                    statistics,
+                    sink_version,
+                )
+                .await
+            },
+        )
+    }
+}
+
+/// A statement parameter, converted from a datum.
+struct SqlServerParam(UpstreamValue);
+
+impl ToSql for SqlServerParam {
+    fn to_sql(&self) -> ColumnData<'_> {
+        match &self.0 {
+            UpstreamValue::Null => ColumnData::String(None),
+            UpstreamValue::Bool(b) => ColumnData::Bit(Some(*b)),
+            UpstreamValue::Int(i) => ColumnData::I64(Some(*i)),
+            // SQL Server has no unsigned types, so values that don't fit into a bigint are sent
+            // as text for the upstream to coerce into the type of the target column.
+            UpstreamValue::UInt(i) => match i64::try_from(*i) {
+                Ok(i) => ColumnData::I64(Some(i)),
+                Err(_) => ColumnData::String(Some(Cow::Owned(i.to_string()))),
+            },
+            UpstreamValue::Float(f) => ColumnData::F64(Some(*f)),
+            UpstreamValue::Bytes(b) => ColumnData::Binary(Some(Cow::Borrowed(b))),
+            UpstreamValue::Text(s) => ColumnData::String(Some(Cow::Borrowed(s))),
+        }
+    }
+}
+
+/// A statement along with its parameters.
+struct Statement {
+    sql: String,
+    params: Vec<SqlServerParam>,
+}
+
+/// A connection to the upstream database that transactionally applies the updates of a sink
+/// together with its progress.
+struct SqlServerSinkWriter {
+    /// The connection to the upstream database.
+    client: mz_sql_server_util::Client,
+    /// The quoted, fully qualified name of the target table.
+    table: String,
+    /// The quoted, fully qualified name of the progress table.
+    progress_table: String,
+    /// The key of this sink's row in the progress table.
+    progress_key: String,
+    /// The version of this sink, used to fence out previous versions from writing.
+    sink_version: i64,
+    /// The upper recorded in the progress table, as of the last successful commit.
+    committed_upper: Antichain<Timestamp>,
+    /// Whether updates are upserted into or appended to the target table.
+    envelope: SinkEnvelope,
+    /// The description of the key of each update, if any.
+    key_desc: Option<RelationDesc>,
+    /// The description of the value of each update.
+    value_desc: RelationDesc,
+    /// A handle to the statistics associated with this sink.
+    statistics: SinkStatistics,
+}
+
+impl SqlServerSinkWriter {
+    /// Connects to the upstream database, validates the target table and registers this sink
+    /// version in the progress table. After this call returns it is guaranteed that all previous
+    /// versions of this sink have been fenced out.
+    async fn new(
+        sink_id: GlobalId,
+        connection: &SqlServerSinkConnection,
+        value_desc: &RelationDesc,
+        envelope: SinkEnvelope,
+        storage_configuration: &StorageConfiguration,
+        statistics: SinkStatistics,
+        sink_version: u64,
+    ) -> Result<(Self, Antichain<Timestamp>), anyhow::Error> {
+        let sink_version = i64::try_from(sink_version).context("sink version out of range")?;
+        let key_desc = connection
+            .key_desc_and_indices
+            .as_ref()
+            .map(|(desc, _indices)| desc.clone());
+        if envelope == SinkEnvelope::Upsert && key_desc.is_none() {
+            bail!("sql server sinks in upsert mode require a key");
+        }
+
+        let config = connection
+            .connection
+            .resolve_config(
+                &storage_configuration.connection_context.secrets_reader,
+                storage_configuration,
+                InTask::Yes,
+            )
+            .await?;
+        let mut client = mz_sql_server_util::Client::connect(config).await?;
+
+        let schema = quote_identifier(&connection.schema);
+        let table = format!("{schema}.{}", quote_identifier(&connection.table));
+        let progress_table = format!("{schema}.{}", quote_identifier(&connection.progress_table));
+
+        // To handle columns that were dropped or renamed upstream with a useful error, we check
+        // that every column of the sinked relation exists in the target table. The types are
+        // checked implicitly by the upstream when coercing the values we send.
+        let upstream_columns: Vec<String> = client
+            .query(
+                "SELECT COLUMN_NAME FROM INFORMATION_SCHEMA.COLUMNS \
+                 WHERE TABLE_SCHEMA = @P1 AND TABLE_NAME = @P2",
+                &[&connection.schema, &connection.table],
+            )
+            .await
+            .with_context(|| format!("looking up columns of upstream table {table}"))?
+            .into_iter()
+            .map(|row| {
+                let name: Option<&str> = row.try_get(0)?;
+                Ok::<_, tiberius::error::Error>(name.unwrap_or_default().to_owned())
+            })
+            .try_collect()?;
+        if upstream_columns.is_empty() {
+            bail!("upstream table {table} does not exist");
+        }
+        for name in value_desc.iter_names() {
+            if !upstream_columns.iter().any(|c| c == name.as_str()) {
+                bail!("column {name} does not exist in upstream table {table}");
+            }
+        }
+
+        client
+            .execute(
+                format!(
+                    "IF OBJECT_ID(@P1, N'U') IS NULL
+                    CREATE TABLE {progress_table} (
+                        sink_id NVARCHAR(255) NOT NULL PRIMARY KEY,
+                        version BIGINT NOT NULL,
+                        upper DECIMAL(20,0) NULL
+                    )"
+                ),
+                &[&progress_table],
+            )
+            .await
+            .context("preparing upstream progress table")?;
+
+        let progress_key =
+            connection.progress_key(&storage_configuration.connection_context, sink_id);
+
+        // Register our version, fencing out any previous versions of this sink.
+        let mut txn = client.transaction().await?;
+        txn.execute(
+            format!(
+                "INSERT INTO {progress_table} (sink_id, version, upper) SELECT @P1, @P2, 0 \
+                 WHERE NOT EXISTS ( \
+                    SELECT 1 FROM {progress_table} WITH (UPDLOCK, HOLDLOCK) WHERE sink_id = @P1 \
+                 )"
+            ),
+            &[&progress_key, &sink_version],
+        )
+        .await?;
+        let rows = txn
+            .query(
+                format!(
+                    "SELECT version, CAST(upper AS VARCHAR(20)) \
+                     FROM {progress_table} WITH (UPDLOCK, HOLDLOCK) WHERE sink_id = @P1"
+                ),
+                &[&progress_key],
+            )
+            .await?;
+        let row = rows
+            .first()
+            .ok_or_else(|| anyhow!("progress row for {progress_key} disappeared"))?;
+        let version: i64 = row
+            .try_get(0)?
+            .ok_or_else(|| anyhow!("progress row for {progress_key} has no version"))?;
+        if version > sink_version {
+            bail!(
+                "sink was fenced out by a newer version: current version {sink_version}, \
+                 upstream version {version}"
+            );
+        }
+        let upper: Option<&str> = row.try_get(1)?;
+        let resume_upper = decode_upper(upper.map(str::to_owned))?;
+        txn.execute(
+            format!("UPDATE {progress_table} SET version = @P2 WHERE sink_id = @P1"),
+            &[&progress_key, &sink_version],
+        )
+        .await?;
+        txn.commit().await?;
+
+        let writer = SqlServerSinkWriter {
+            client,
+            table,
+            progress_table,
+            progress_key,
+            sink_version,
+            committed_upper: resume_upper.clone(),
+            envelope,
+            key_desc,
+            value_desc: value_desc.clone(),
+            statistics,
+        };
+        Ok((writer, resume_upper))
+    }
+
+    /// Returns the statements that delete the rows with the given keys from the target table.
+    fn delete_stmts(&self, keys: &[&Row]) -> Vec<Statement> {
+        if keys.is_empty() {
+            return vec![];
+        }
+        let key_desc = self.key_desc.as_ref().expect("deletes require a key");
+        let rows_per_stmt = (MAX_PARAMS / key_desc.arity().max(1)).min(MAX_ROWS);
+        keys.chunks(rows_per_stmt)
+            .map(|chunk| {
+                let mut param = 0;
+                let predicate = chunk
+                    .iter()
+                    .map(|_| {
+                        let row_predicate = key_desc
+                            .iter()
+                            .map(|(name, typ)| {
+                                param += 1;
+                                let name = quote_identifier(name.as_str());
+                                if typ.nullable {
+                                    format!(
+                                        "({name} = @P{param} OR ({name} IS NULL AND @P{param} IS NULL))"
+                                    )
+                                } else {
+                                    format!("{name} = @P{param}")
+                                }
+                            })
+                            .join(" AND ");
+                        format!("({row_predicate})")
+                    })
+                    .join(" OR ");
+                Statement {
+                    sql: format!("DELETE FROM {} WHERE {predicate}", self.table),
+                    params: encode_rows(chunk, key_desc),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the statements that write the given rows into the target table.
+    fn insert_stmts(&self, rows: &[&Row]) -> Vec<Statement> {
+        let columns = self
+            .value_desc
+            .iter_names()
+            .map(|c| quote_identifier(c.as_str()))
+            .collect_vec();
+        let rows_per_stmt = (MAX_PARAMS / columns.len().max(1)).min(MAX_ROWS);
+        rows.chunks(rows_per_stmt)
+            .map(|chunk| {
+                let mut param = 0;
+                let values = chunk
+                    .iter()
+                    .map(|_| {
+                        let tuple = columns
+                            .iter()
+                            .map(|_| {
+                                param += 1;
+                                format!("@P{param}")
+                            })
+                            .join(", ");
+                        format!("({tuple})")
+                    })
+                    .join(", ");
+                let column_list = columns.iter().join(", ");
+                let sql = match (self.envelope, &self.key_desc) {
+                    (SinkEnvelope::Upsert, Some(key_desc)) => {
+                        let key_predicate = key_desc
+                            .iter()
+                            .map(|(name, typ)| {
+                                let name = quote_identifier(name.as_str());
+                                if typ.nullable {
+                                    format!(
+                                        "(t.{name} = s.{name} OR (t.{name} IS NULL AND s.{name} IS NULL))"
+                                    )
+                                } else {
+                                    format!("t.{name} = s.{name}")
+                                }
+                            })
+                            .join(" AND ");
+                        let assignments = columns
+                            .iter()
+                            .map(|c| format!("t.{c} = s.{c}"))
+                            .join(", ");
+                        let source_columns = columns.iter().map(|c| format!("s.{c}")).join(", ");
+                        format!(
+                            "MERGE INTO {table} WITH (HOLDLOCK) AS t \
+                             USING (VALUES {values}) AS s ({column_list}) ON {key_predicate} \
+                             WHEN MATCHED THEN UPDATE SET {assignments} \
+                             WHEN NOT MATCHED THEN INSERT ({column_list}) \
+                             VALUES ({source_columns});",
+                            table = self.table,
+                        )
+                    }
+                    _ => format!(
+                        "INSERT INTO {} ({column_list}) VALUES {values}",
+                        self.table
+                    ),
+                };
+                Statement {
+                    sql,
+                    params: encode_rows(chunk, &self.value_desc),
+                }
+            })
+            .collect()
+    }
+}
+
+impl UpstreamTableWriter for SqlServerSinkWriter {
+    async fn commit(
+        &mut self,
+        updates: &[SinkUpdate],
+        upper: Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error> {
+        let mut deletes = vec![];
+        let mut inserts = vec![];
+        let mut staged_bytes = 0;
+        for ((key, diff_pair), _time, diff) in updates {
+            assert_eq!(*diff, Diff::ONE, "invalid sink update");
+            match (&diff_pair.after, self.envelope) {
+                (Some(after), _) => {
+                    staged_bytes += after.byte_len();
+                    inserts.push(after);
+                }
+                (None, SinkEnvelope::Upsert) => {
+                    let key = key.as_ref().expect("sql server upsert sinks require a key");
+                    staged_bytes += key.byte_len();
+                    deletes.push(key);
+                }
+                (None, _) => {}
+            }
+        }
+        let staged_messages = u64::cast_from(updates.len());
+        let staged_bytes = u64::cast_from(staged_bytes);
+        self.statistics.inc_messages_staged_by(staged_messages);
+        self.statistics.inc_bytes_staged_by(staged_bytes);
+
+        let mut stmts = self.delete_stmts(&deletes);
+        stmts.extend(self.insert_stmts(&inserts));
+
+        let mut txn = self.client.transaction().await?;
+        for stmt in &stmts {
+            let params = stmt.params.iter().map(|p| p as &dyn ToSql).collect_vec();
+            txn.execute(stmt.sql.as_str(), &params).await?;
+        }
+
+        let new_upper = SqlServerParam(match encode_upper(&upper) {
+            Some(upper) => UpstreamValue::Text(upper),
+            None => UpstreamValue::Null,
+        });
+        let expected_upper = SqlServerParam(match encode_upper(&self.committed_upper) {
+            Some(upper) => UpstreamValue::Text(upper),
+            None => UpstreamValue::Null,
+        });
+        let updated: u64 = txn
+            .execute(
+                format!(
+                    "UPDATE {} SET upper = CAST(@P3 AS DECIMAL(20,0)) \
+                     WHERE sink_id = @P1 AND version = @P2 \
+                     AND ((upper IS NULL AND @P4 IS NULL) OR upper = CAST(@P4 AS DECIMAL(20,0)))",
+                    self.progress_table
+                ),
+                &[
+                    &self.progress_key,
+                    &self.sink_version,
+                    &new_upper,
+                    &expected_upper,
+                ],
+            )
+            .await?
+            .into_iter()
+            .sum();
+        if updated != 1 {
+            // Dropping the transaction rolls it back.
+            bail!(
+                "sink was fenced out: progress for {} no longer at {}",
+                self.progress_key,
+                self.committed_upper.pretty()
+            );
+        }
+
+        fail::fail_point!("sql_server_sink_commit_transaction");
+
+        txn.commit().await?;
+        self.statistics.inc_messages_committed_by(staged_messages);
+        self.statistics.inc_bytes_committed_by(staged_bytes);
+        self.committed_upper = upper;
+        Ok(())
+    }
+}
+
+/// Flattens the datums of `rows` into statement parameters.
+fn encode_rows(rows: &[&Row], desc: &RelationDesc) -> Vec<SqlServerParam> {
+    let column_types = &desc.typ().column_types;
+    rows.iter()
+        .flat_map(|row| {
+            row.iter().zip_eq(column_types).map(|(datum, typ)| {
+                SqlServerParam(UpstreamValue::from_datum(datum, &typ.scalar_type))
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,334 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Use of this software is governed by the Business Source License
+// included in the LICENSE file.
+//
+// As of the Change Date specified in that file, in accordance with
+// the Business Source License, use of this software will be governed
+// by the Apache License, Version 2.0.
+
+//! Machinery shared by the sinks that write into a table of a transactional upstream database,
+//! i.e. the PostgreSQL, MySQL and SQL Server sinks.
+//!
+//! All of these sinks are rendered as a single operator, running on a single worker, that commits
+//! the updates of each timestamp in one upstream transaction. The same transaction advances the
+//! sink's row in a progress table with a compare-and-set from the previously committed upper to
+//! the new one, so that a restarted sink can resume without applying any update twice and a
+//! fenced out sink cannot apply any update at all. The details of how updates are applied and
+//! how the progress table is laid out are left to each [`UpstreamTableWriter`].
+//!
+//! The HTTP sink reuses this machinery even though its receiver has no transactions. Its writer
+//! resumes from the upper of the sink's persist shard instead of a progress table, which gives
+//! at-least-once rather than exactly-once delivery.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use anyhow::{Context, anyhow};
+use differential_dataflow::{Hashable, VecCollection};
+use futures::StreamExt;
+use itertools::Itertools;
+use mz_interchange::avro::DiffPair;
+use mz_ore::cast::CastFrom;
+use mz_ore::error::ErrorExt;
+use mz_persist_client::Diagnostics;
+use mz_persist_types::codec_impls::UnitSchema;
+use mz_repr::adt::jsonb::JsonbRef;
+use mz_repr::{Datum, Diff, GlobalId, Row, SqlScalarType, Timestamp};
+use mz_storage_types::StorageDiff;
+use mz_storage_types::controller::CollectionMetadata;
+use mz_storage_types::sinks::StorageSinkDesc;
+use mz_storage_types::sources::SourceData;
+use mz_timely_util::antichain::AntichainExt;
+use mz_timely_util::builder_async::{
+    Event, OperatorBuilder as AsyncOperatorBuilder, PressOnDropButton,
+};
+use timely::PartialOrder;
+use timely::dataflow::channels::pact::Exchange;
+use timely::dataflow::operators::Concatenate;
+use timely::dataflow::operators::vec::{Map, ToStream};
+use timely::dataflow::{Scope, StreamVec};
+use timely::progress::{Antichain, Timestamp as _};
+use tracing::{debug, error, info};
+
+use crate::healthcheck::{HealthStatusMessage, HealthStatusUpdate, StatusNamespace};
+use crate::statistics::SinkStatistics;
+use crate::storage_state::StorageState;
+
+/// An update to the sinked collection, as presented to an [`UpstreamTableWriter`].
+pub(super) type SinkUpdate = ((Option<Row>, DiffPair<Row>), Timestamp, Diff);
+
+/// A connection to an upstream database that transactionally applies the updates of a sink
+/// together with its progress.
+pub(super) trait UpstreamTableWriter {
+    /// Applies `updates`, which all share the same timestamp, and advances the sink's progress
+    /// to `upper` in a single upstream transaction.
+    ///
+    /// Implementations must fail without applying any update if the sink's progress is no longer
+    /// at the upper of the previous successful commit.
+    async fn commit(
+        &mut self,
+        updates: &[SinkUpdate],
+        upper: Antichain<Timestamp>,
+    ) -> Result<(), anyhow::Error>;
+}
+
+/// Renders a sink that writes its input through an [`UpstreamTableWriter`].
+///
+/// The `connect` closure is only invoked on the worker that writes to the upstream database. It
+/// must connect to the upstream database, fence out all previous versions of the sink, and
+/// return the writer along with the upper that the sink has committed so far.
+pub(super) fn render_upstream_table_sink<G, W, F, Fut>(
+    storage_state: &mut StorageState,
+    sink: &StorageSinkDesc<CollectionMetadata, Timestamp>,
+    sink_id: GlobalId,
+    input: VecCollection<G, (Option<Row>, DiffPair<Row>), Diff>,
+    namespace: StatusNamespace,
+    connect: F,
+) -> (StreamVec<G, HealthStatusMessage>, Vec<PressOnDropButton>)
+where
+    G: Scope<Timestamp = Timestamp>,
+    W: UpstreamTableWriter + 'static,
+    F: FnOnce(SinkStatistics) -> Fut + 'static,
+    Fut: Future<Output = Result<(W, Antichain<Timestamp>), anyhow::Error>> + 'static,
+{
+    let mut scope = input.scope();
+    let name = format!("{namespace}-{sink_id}-sink");
+
+    let write_handle = {
+        let persist = Arc::clone(&storage_state.persist_clients);
+        let shard_meta = sink.to_storage_metadata.clone();
+        async move {
+            let client = persist.open(shard_meta.persist_location).await?;
+            let handle = client
+                .open_writer(
+                    shard_meta.data_shard,
+                    Arc::new(shard_meta.relation_desc),
+                    Arc::new(UnitSchema),
+                    Diagnostics::from_purpose("sink handle"),
+                )
+                .await?;
+            Ok::<_, anyhow::Error>(handle)
+        }
+    };
+
+    let write_frontier = Rc::new(RefCell::new(Antichain::from_elem(Timestamp::minimum())));
+    storage_state
+        .sink_write_frontiers
+        .insert(sink_id, Rc::clone(&write_frontier));
+
+    let statistics = storage_state
+        .aggregated_statistics
+        .get_sink(&sink_id)
+        .expect("statistics initialized")
+        .clone();
+
+    let mut builder = AsyncOperatorBuilder::new(name.clone(), input.inner.scope());
+
+    // We want exactly one worker to write all the data to the upstream database.
+    let hashed_id = sink_id.hashed();
+    let is_active_worker = usize::cast_from(hashed_id) % scope.peers() == scope.index();
+
+    let mut input = builder.new_disconnected_input(input.inner, Exchange::new(move |_| hashed_id));
+
+    let as_of = sink.as_of.clone();
+    let (button, errors) = builder.build_fallible(move |_caps| {
+        Box::pin(async move {
+            if !is_active_worker {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            }
+
+            let mut write_handle = write_handle.await?;
+            let (mut writer, resume_upper) = connect(statistics).await?;
+
+            // The input has overcompacted if
+            let overcompacted =
+                // ..we have made some progress in the past
+                *resume_upper != [Timestamp::minimum()] &&
+                // ..but the since frontier is now beyond that
+                !PartialOrder::less_equal(&as_of, &resume_upper);
+            if overcompacted {
+                let err = format!(
+                    "{name}: input compacted past resume upper: as_of {}, resume_upper: {}",
+                    as_of.pretty(),
+                    resume_upper.pretty()
+                );
+                // This would normally be an assertion but because it can happen after a
+                // Materialize backup/restore we log an error so that it appears on Sentry but
+                // leaves the rest of the objects in the cluster unaffected.
+                error!("{err}");
+                return Err(anyhow!("{err}"));
+            }
+
+            info!(
+                "{name}: as_of: {}, resume upper: {}",
+                as_of.pretty(),
+                resume_upper.pretty()
+            );
+
+            // The section below relies on TotalOrder for correctness so we'll work with timestamps
+            // directly to make sure this doesn't compile if someone attempts to make this operator
+            // generic over partial orders in the future.
+            let Some(upper) = resume_upper.clone().into_option() else {
+                write_frontier.borrow_mut().clear();
+                return Ok(());
+            };
+
+            let mut deferred_updates = vec![];
+            let mut ready_updates = vec![];
+            while let Some(event) = input.next().await {
+                match event {
+                    Event::Data(_cap, batch) => {
+                        // Updates before `upper` have already been committed by a previous
+                        // incarnation of this sink.
+                        deferred_updates.extend(batch.into_iter().filter(|(_, t, _)| upper <= *t));
+                    }
+                    Event::Progress(progress) => {
+                        // Ignore progress updates before our resumption frontier
+                        if !PartialOrder::less_equal(&resume_upper, &progress) {
+                            continue;
+                        }
+                        // Also ignore progress updates until we are past the as_of frontier. See
+                        // the Kafka sink for a description of the scenario this prevents.
+                        if !as_of.iter().all(|t| !progress.less_equal(t)) {
+                            continue;
+                        }
+
+                        ready_updates.extend(
+                            deferred_updates
+                                .extract_if(.., |(_, time, _)| !progress.less_equal(time)),
+                        );
+                        ready_updates.sort_unstable_by(|a, b| a.1.cmp(&b.1));
+
+                        // Commit each timestamp in its own transaction. Nothing happens between
+                        // two consecutive timestamps with updates, so each transaction advances
+                        // the upper straight to the next timestamp with updates and the final
+                        // one to `progress`.
+                        let chunks = ready_updates.chunk_by(|a, b| a.1 == b.1).collect_vec();
+                        for (i, chunk) in chunks.iter().enumerate() {
+                            let chunk_upper = match chunks.get(i + 1) {
+                                Some(next) => Antichain::from_elem(next[0].1),
+                                None => progress.clone(),
+                            };
+                            debug!(
+                                "{name}: committing transaction for {}",
+                                chunk_upper.pretty()
+                            );
+                            writer.commit(chunk, chunk_upper).await?;
+                        }
+                        if chunks.is_empty() {
+                            debug!("{name}: committing transaction for {}", progress.pretty());
+                            writer.commit(&[], progress.clone()).await?;
+                        }
+                        ready_updates.clear();
+
+                        let mut expect_upper = write_handle.shared_upper();
+                        loop {
+                            if PartialOrder::less_equal(&progress, &expect_upper) {
+                                // The frontier has already been advanced as far as necessary.
+                                break;
+                            }
+                            const EMPTY: &[((SourceData, ()), Timestamp, StorageDiff)] = &[];
+                            match write_handle
+                                .compare_and_append(EMPTY, expect_upper, progress.clone())
+                                .await
+                                .expect("valid usage")
+                            {
+                                Ok(()) => break,
+                                Err(mismatch) => {
+                                    expect_upper = mismatch.current;
+                                }
+                            }
+                        }
+                        write_frontier.borrow_mut().clone_from(&progress);
+                        if progress.is_empty() {
+                            break;
+                        }
+                    }
+                }
+            }
+            Ok(())
+        })
+    });
+
+    let statuses = errors.map(move |error: Rc<anyhow::Error>| HealthStatusMessage {
+        id: None,
+        update: HealthStatusUpdate::halting(format!("{}", error.display_with_causes()), None),
+        namespace,
+    });
+
+    let running_status = Some(HealthStatusMessage {
+        id: None,
+        update: HealthStatusUpdate::running(),
+        namespace,
+    })
+    .to_stream(&mut scope);
+
+    let status = scope.concatenate([running_status, statuses]);
+
+    (status, vec![button.press_on_drop()])
+}
+
+/// Encodes an upper for storage in a progress table, where `NULL` represents the empty frontier.
+pub(super) fn encode_upper(upper: &Antichain<Timestamp>) -> Option<String> {
+    upper.as_option().map(|ts| ts.to_string())
+}
+
+/// Decodes an upper stored in a progress table by [`encode_upper`].
+pub(super) fn decode_upper(upper: Option<String>) -> Result<Antichain<Timestamp>, anyhow::Error> {
+    match upper {
+        Some(upper) => {
+            let ts: u64 = upper
+                .parse()
+                .with_context(|| format!("invalid upper in progress table: {upper}"))?;
+            Ok(Antichain::from_elem(Timestamp::from(ts)))
+        }
+        None => Ok(Antichain::new()),
+    }
+}
+
+/// A datum converted into a form that upstream databases without a text encoding compatible
+/// with PostgreSQL can coerce into the type of the target column.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum UpstreamValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Bytes(Vec<u8>),
+    Text(String),
+}
+
+impl UpstreamValue {
+    pub(super) fn from_datum(datum: Datum, typ: &SqlScalarType) -> Self {
+        match (datum, typ) {
+            (Datum::Null, _) => UpstreamValue::Null,
+            // JSON values are spread over several datum variants, so they must be recognized by
+            // their type rather than their datum.
+            (datum, SqlScalarType::Jsonb) => {
+                UpstreamValue::Text(JsonbRef::from_datum(datum).to_string())
+            }
+            (Datum::True, _) => UpstreamValue::Bool(true),
+            (Datum::False, _) => UpstreamValue::Bool(false),
+            (Datum::Int16(i), _) => UpstreamValue::Int(i.into()),
+            (Datum::Int32(i), _) => UpstreamValue::Int(i.into()),
+            (Datum::Int64(i), _) => UpstreamValue::Int(i),
+            (Datum::UInt8(i), _) => UpstreamValue::UInt(i.into()),
+            (Datum::UInt16(i), _) => UpstreamValue::UInt(i.into()),
+            (Datum::UInt32(i), _) => UpstreamValue::UInt(i.into()),
+            (Datum::UInt64(i), _) => UpstreamValue::UInt(i),
+            (Datum::Float32(f), _) => UpstreamValue::Float(f.into_inner().into()),
+            (Datum::Float64(f), _) => UpstreamValue::Float(f.into_inner()),
+            (Datum::Bytes(b), _) => UpstreamValue::Bytes(b.to_vec()),
+            (Datum::String(s), _) => UpstreamValue::Text(s.to_owned()),
+            // Neither MySQL nor SQL Server understand time zone names in timestamps, so we send
+            // the UTC wall clock time instead.
+            (Datum::TimestampTz(ts), _) => UpstreamValue::Text(ts.naive_utc().to_string()),
+            (datum, _) => UpstreamValue::Text(datum.to_string()),
+        }
+    }
+}
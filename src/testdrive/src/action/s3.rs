@@ -113,6 +113,8 @@ pub async fn run_verify_data(
                 actual_body.lines().map(|l| l.to_string()).collect()
             }
             key if key.ends_with(".parquet") => rows_from_parquet(bytes),
+            key if key.ends_with(".avro") => rows_from_avro(bytes)?,
+            key if key.ends_with(".orc") => rows_from_orc(bytes)?,
             key => bail!("unexpected file type: {key}"),
         };
         rows.extend(new_rows);
@@ -177,11 +179,54 @@ pub async fn run_verify_keys(
 fn rows_from_parquet(bytes: bytes::Bytes) -> Vec<String> {
     let reader =
         parquet::arrow::arrow_reader::ParquetRecordBatchReader::try_new(bytes, 1_000_000).unwrap();
+    rows_from_record_batches(reader.map(|batch| batch.unwrap()))
+}
+
+fn rows_from_orc(bytes: bytes::Bytes) -> Result<Vec<String>, anyhow::Error> {
+    let reader = orc_rust::ArrowReaderBuilder::try_new(bytes)?.build();
+    let batches = reader.collect::<Result<Vec<_>, _>>()?;
+    Ok(rows_from_record_batches(batches))
+}
+
+/// Formats the records of an Avro Object Container File like the rows of
+/// parquet files, with the fields of each record separated by spaces.
+fn rows_from_avro(bytes: bytes::Bytes) -> Result<Vec<String>, anyhow::Error> {
+    use mz_avro::types::Value;
+
+    fn format_value(value: Value) -> String {
+        match value {
+            Value::Union { inner, .. } => format_value(*inner),
+            Value::Null => String::new(),
+            Value::Boolean(b) => b.to_string(),
+            Value::Int(i) => i.to_string(),
+            Value::Long(i) => i.to_string(),
+            Value::Float(f) => f.to_string(),
+            Value::Double(f) => f.to_string(),
+            Value::String(s) => s,
+            Value::Json(j) => j.to_string(),
+            value => format!("{:?}", crate::format::avro::DebugValue(value)),
+        }
+    }
+
+    let reader = mz_avro::Reader::new(bytes.as_ref())?;
+    let mut ret = vec![];
+    for record in reader {
+        let Value::Record(fields) = record? else {
+            bail!("expected avro records");
+        };
+        let fields: Vec<_> = fields
+            .into_iter()
+            .map(|(_name, value)| format_value(value))
+            .collect();
+        ret.push(fields.join(" "));
+    }
+    Ok(ret)
+}
 
+fn rows_from_record_batches(batches: impl IntoIterator<Item = RecordBatch>) -> Vec<String> {
     let mut ret = vec![];
     let format_options = FormatOptions::default();
-    for batch in reader {
-        let batch = batch.unwrap();
+    for batch in batches {
         let converters = batch
             .columns()
             .iter()
@@ -69,6 +69,8 @@ pub async fn run_sql(mut cmd: SqlCommand, state: &mut State) -> Result<ControlFl
         | CreateTableFromSource(_)
         | CreateIndex(_)
         | CreateType(_)
+        | CreateFunction(_)
+        | CreateSequence(_)
         | CreateRole(_)
         | AlterObjectRename(_)
         | AlterIndex(_)
@@ -20,7 +20,7 @@ use itertools::Itertools;
 use mz_compute_types::dataflows::{BuildDesc, DataflowDesc, DataflowDescription, IndexImport};
 use mz_expr::{
     AccessStrategy, CollectionPlan, Id, JoinImplementation, LocalId, MapFilterProject,
-    MirRelationExpr, MirScalarExpr, RECURSION_LIMIT,
+    MirRelationExpr, MirScalarExpr, OptimizedMirRelationExpr, RECURSION_LIMIT,
 };
 use mz_ore::stack::{CheckedRecursion, RecursionGuard, RecursionLimitError};
 use mz_ore::{assert_none, soft_assert_eq_or_log, soft_assert_or_log, soft_panic_or_log};
@@ -363,12 +363,30 @@ fn optimize_dataflow_filters(dataflow: &mut DataflowDesc) -> Result<(), Transfor
 
     // Push predicate information into the SourceDesc.
     for (source_id, source_import) in dataflow.source_imports.iter_mut() {
+        let monotonic = source_import.monotonic;
         let source = &mut source_import.desc;
         if let Some(list) = predicates.remove(&Id::Global(*source_id)) {
             if !list.is_empty() {
                 // Canonicalize the order of predicates, for stable plans.
                 let mut list = list.into_iter().collect::<Vec<_>>();
                 list.sort();
+                // A `TABLESAMPLE SYSTEM` predicate pushed to the source is applied by
+                // sampling parts of the source rather than rows, and so must not also be
+                // applied by the views. If it cannot be removed from them, leave it for
+                // the views to evaluate on rows instead.
+                //
+                // Parts are only a sample of the source's contents if the source is
+                // monotonic: otherwise the sample could contain an update without the
+                // retraction that cancels it, so such sources are sampled by row.
+                list.retain(|predicate| {
+                    !predicate.is_system_sample()
+                        || (monotonic
+                            && remove_system_sample(
+                                &mut dataflow.objects_to_build,
+                                *source_id,
+                                predicate,
+                            ))
+                });
                 // Install no-op predicate information if none exists.
                 if source.arguments.operators.is_none() {
                     source.arguments.operators = Some(MapFilterProject::new(source.typ.arity()));
@@ -387,6 +405,48 @@ fn optimize_dataflow_filters(dataflow: &mut DataflowDesc) -> Result<(), Transfor
     Ok(())
 }
 
+/// Removes the `TABLESAMPLE SYSTEM` predicate `sample` from all filters on `source_id`.
+///
+/// Returns `false`, leaving `objects_to_build` unchanged, if the predicate also occurs
+/// anywhere it cannot be removed from.
+fn remove_system_sample(
+    objects_to_build: &mut [BuildDesc<OptimizedMirRelationExpr>],
+    source_id: GlobalId,
+    sample: &MirScalarExpr,
+) -> bool {
+    let is_source = |expr: &MirRelationExpr| matches!(expr, MirRelationExpr::Get { id: Id::Global(id), .. } if *id == source_id);
+
+    let (mut removable, mut total) = (0, 0);
+    for build_desc in objects_to_build.iter() {
+        build_desc.plan.as_inner().visit_pre(|expr| {
+            if let MirRelationExpr::Filter { input, predicates } = expr {
+                let count = predicates.iter().filter(|p| *p == sample).count();
+                total += count;
+                if is_source(input) {
+                    removable += count;
+                }
+            }
+        });
+    }
+    if removable < total {
+        return false;
+    }
+
+    for build_desc in objects_to_build.iter_mut() {
+        build_desc.plan.as_inner_mut().visit_pre_mut(|expr| {
+            if let MirRelationExpr::Filter { input, predicates } = expr {
+                if is_source(input) {
+                    predicates.retain(|p| p != sample);
+                    if predicates.is_empty() {
+                        *expr = input.take_dangerous();
+                    }
+                }
+            }
+        });
+    }
+    true
+}
+
 /// Pushes filters down through views in `view_sequence` in order.
 ///
 /// This method is made public for the sake of testing.
@@ -540,6 +540,7 @@ impl DataSubscribe {
                     Arc::new(StringSchema),
                     Arc::new(UnitSchema),
                     FilterResult::keep_all,
+                    None,
                     false.then_some(|| unreachable!()),
                     async {},
                     ErrorHandler::Halt("data_subscribe"),
@@ -71,6 +71,7 @@ def workflow_smoke(c: Composition) -> None:
         "--var=aws-endpoint=minio:9000",
         "catalog.td",
         "nested-records.td",
+        "changelog.td",
     )
 
 
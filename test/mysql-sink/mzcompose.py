@@ -0,0 +1,71 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""
+Functional tests for the MySQL sink. The upstream tables are read back into
+Materialize through a MySQL source.
+"""
+
+from materialize.mzcompose.composition import Composition, WorkflowArgumentParser
+from materialize.mzcompose.services.materialized import Materialized
+from materialize.mzcompose.services.mysql import MySql
+from materialize.mzcompose.services.testdrive import Testdrive
+
+SERVICES = [
+    Materialized(),
+    MySql(),
+    Testdrive(default_timeout="60s"),
+]
+
+
+def run_testdrive_files(c: Composition, *files: str) -> None:
+    c.run_testdrive_files(
+        "--no-reset",
+        f"--var=mysql-root-password={MySql.DEFAULT_ROOT_PASSWORD}",
+        f"--var=default-replica-size=scale={Materialized.Size.DEFAULT_SIZE},workers={Materialized.Size.DEFAULT_SIZE}",
+        *files,
+    )
+
+
+def workflow_default(c: Composition, parser: WorkflowArgumentParser) -> None:
+    def process(name: str) -> None:
+        if name == "default":
+            return
+        with c.test_case(name):
+            c.workflow(name, *parser.args)
+
+    c.test_parts(list(c.workflows.keys()), process)
+
+
+def workflow_sink(c: Composition) -> None:
+    """
+    Test upsert and append modes, progress tracking and fencing.
+    """
+    c.down(destroy_volumes=True)
+    c.up("materialized", "mysql")
+    run_testdrive_files(c, "sink.td")
+
+
+def workflow_commit_failure(c: Composition) -> None:
+    """
+    Test that a sink that crashes before committing a transaction applies
+    nothing, and that it resumes without duplicates after a restart.
+    """
+    c.down(destroy_volumes=True)
+    with c.override(
+        Materialized(
+            environment_extra=["FAILPOINTS=mysql_sink_commit_transaction=panic"],
+        )
+    ):
+        c.up("materialized", "mysql")
+        run_testdrive_files(c, "commit-failure/before-restart.td")
+        c.kill("materialized")
+
+    c.up("materialized")
+    run_testdrive_files(c, "commit-failure/after-restart.td")
@@ -0,0 +1,33 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""
+Verify that the Postgres sink writes and upserts rows into an upstream table,
+and that it applies every update exactly once, even after restarting
+Materialize.
+"""
+
+from materialize.mzcompose.composition import Composition
+from materialize.mzcompose.services.materialized import Materialized
+from materialize.mzcompose.services.postgres import Postgres
+from materialize.mzcompose.services.testdrive import Testdrive
+
+SERVICES = [
+    Materialized(),
+    Postgres(),
+    Testdrive(),
+]
+
+
+def workflow_default(c: Composition) -> None:
+    c.up("materialized", "postgres")
+    c.run_testdrive_files("--no-reset", "before-restart.td")
+    c.kill("materialized")
+    c.up("materialized")
+    c.run_testdrive_files("--no-reset", "after-restart.td")
@@ -0,0 +1,34 @@
+# Copyright Materialize, Inc. and contributors. All rights reserved.
+#
+# Use of this software is governed by the Business Source License
+# included in the LICENSE file at the root of this repository.
+#
+# As of the Change Date specified in that file, in accordance with
+# the Business Source License, use of this software will be governed
+# by the Apache License, Version 2.0.
+
+"""
+Functional tests for the SQL Server sink. The upstream tables are read back
+into Materialize through a SQL Server source.
+"""
+
+from materialize.mzcompose.composition import Composition
+from materialize.mzcompose.services.materialized import Materialized
+from materialize.mzcompose.services.sql_server import SqlServer
+from materialize.mzcompose.services.testdrive import Testdrive
+
+SERVICES = [
+    Materialized(),
+    SqlServer(),
+    Testdrive(default_timeout="60s"),
+]
+
+
+def workflow_default(c: Composition) -> None:
+    c.up("materialized", "sql-server")
+    c.run_testdrive_files(
+        f"--var=default-sql-server-user={SqlServer.DEFAULT_USER}",
+        f"--var=default-sql-server-password={SqlServer.DEFAULT_SA_PASSWORD}",
+        f"--var=default-replica-size=scale={Materialized.Size.DEFAULT_SIZE},workers={Materialized.Size.DEFAULT_SIZE}",
+        "sink.td",
+    )